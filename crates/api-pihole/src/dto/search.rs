@@ -4,6 +4,8 @@ use serde::Serialize;
 #[derive(Debug, Serialize)]
 pub struct SearchResponse {
     pub results: Vec<SearchResult>,
+    /// Ferrous extension: every entry behind the decision.
+    pub explain: SearchExplanation,
 }
 
 /// A single search result showing which list matched.
@@ -15,3 +17,26 @@ pub struct SearchResult {
     pub source: String,
     pub blocked: bool,
 }
+
+/// Decision trace for the searched domain, as returned by the Ferrous
+/// `/block-filter/explain` endpoint.
+#[derive(Debug, Serialize)]
+pub struct SearchExplanation {
+    pub group_id: i64,
+    pub blocking_enabled: bool,
+    pub schedule: Option<&'static str>,
+    pub safe_search_cname: Option<&'static str>,
+    pub cname_parent: Option<String>,
+    pub group_sources: Vec<String>,
+    pub matches: Vec<SearchMatch>,
+}
+
+/// An entry that matched the searched domain.
+#[derive(Debug, Serialize)]
+pub struct SearchMatch {
+    /// `deny` or `allow`.
+    pub r#type: &'static str,
+    pub kind: &'static str,
+    pub rule: String,
+    pub sources: Vec<String>,
+}
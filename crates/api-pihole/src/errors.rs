@@ -33,6 +33,14 @@ fn pihole_status_and_key(err: &DomainError) -> (StatusCode, &'static str) {
         | DomainError::BlocklistSourceNotFound(_)
         | DomainError::WhitelistSourceNotFound(_)
         | DomainError::ManagedDomainNotFound(_)
+        | DomainError::ForwardingRuleNotFound(_)
+        | DomainError::DnsRewriteRuleNotFound(_)
+        | DomainError::LocalZoneNotFound(_)
+        | DomainError::ZoneRecordNotFound(_)
+        | DomainError::TrustAnchorNotFound(_)
+        | DomainError::NegativeTrustAnchorNotFound(_)
+        | DomainError::TsigKeyNotFound(_)
+        | DomainError::AcmeRegistrationNotFound(_)
         | DomainError::RegexFilterNotFound(_)
         | DomainError::CustomServiceNotFound(_)
         | DomainError::ClientNotFound(_)
@@ -58,6 +66,14 @@ fn pihole_status_and_key(err: &DomainError) -> (StatusCode, &'static str) {
         DomainError::InvalidBlocklistSource(_)
         | DomainError::InvalidWhitelistSource(_)
         | DomainError::InvalidManagedDomain(_)
+        | DomainError::InvalidForwardingRule(_)
+        | DomainError::InvalidDnsRewriteRule(_)
+        | DomainError::InvalidNegativeTrustAnchor(_)
+        | DomainError::InvalidTsigKey(_)
+        | DomainError::InvalidAcmeAllowFrom(_)
+        | DomainError::InvalidAcmeSubdomain
+        | DomainError::InvalidAcmeTxt
+        | DomainError::InvalidZoneRecord(_)
         | DomainError::InvalidRegexFilter(_)
         | DomainError::InvalidGroupName(_) => (StatusCode::UNPROCESSABLE_ENTITY, "bad_request"),
 
@@ -104,6 +104,7 @@ pub async fn create_list(
                 group_ids,
                 body.comment,
                 enabled,
+                false,
             )
             .await?;
         Ok((StatusCode::CREATED, Json(blocklist_to_entry(&result)?)))
@@ -146,6 +147,7 @@ pub async fn update_list(
                 group_ids,
                 body.comment,
                 body.enabled,
+                None,
             )
             .await?;
         return Ok(Json(blocklist_to_entry(&result)?));
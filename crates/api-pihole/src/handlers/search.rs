@@ -1,11 +1,12 @@
 use axum::extract::{Path, Query, State};
 use axum::Json;
-use ferrous_dns_application::ports::FilterDecision;
-use ferrous_dns_domain::BlockSource;
+use ferrous_dns_application::ports::{FilterDecision, TraceMatch};
+use ferrous_dns_application::use_cases::ExplainSubject;
+use ferrous_dns_domain::{BlockSource, GroupOverride, RecordType};
 use serde::Deserialize;
 
 use crate::{
-    dto::search::{SearchResponse, SearchResult},
+    dto::search::{SearchExplanation, SearchMatch, SearchResponse, SearchResult},
     errors::PiholeApiError,
     state::PiholeAppState,
 };
@@ -17,25 +18,29 @@ pub struct SearchParams {
 
 /// Pi-hole v6 GET /api/search/:domain
 ///
-/// Checks whether a domain would be blocked by the current filter configuration.
+/// Checks whether a domain would be blocked by the current filter configuration
+/// and explains which entries led to the decision.
 pub async fn search_domain(
     State(state): State<PiholeAppState>,
     Path(domain): Path<String>,
     Query(params): Query<SearchParams>,
 ) -> Result<Json<SearchResponse>, PiholeApiError> {
-    let group_id = if let Some(ref client_ip) = params.client {
-        if let Ok(ip) = client_ip.parse::<std::net::IpAddr>() {
-            state.blocking.block_filter_engine.resolve_group(ip)
-        } else {
-            1
-        }
-    } else {
-        1
+    let subject = match params
+        .client
+        .as_deref()
+        .and_then(|ip| ip.parse::<std::net::IpAddr>().ok())
+    {
+        Some(ip) => ExplainSubject::Client(ip),
+        None => ExplainSubject::Group(1),
     };
 
-    let decision = state.blocking.block_filter_engine.check(&domain, group_id);
+    let explanation = state
+        .blocking
+        .explain_filter_decision
+        .execute(&domain, subject, RecordType::A)
+        .await?;
 
-    let (r#type, kind, source, blocked) = match &decision {
+    let (r#type, kind, source, blocked) = match &explanation.decision {
         FilterDecision::Block(block_source) => {
             let kind = match block_source {
                 BlockSource::RegexFilter => "regex",
@@ -54,5 +59,36 @@ pub async fn search_domain(
         blocked,
     }];
 
-    Ok(Json(SearchResponse { results }))
+    let trace = explanation.trace;
+    let to_match = |r#type: &'static str| {
+        move |m: TraceMatch| SearchMatch {
+            r#type,
+            kind: m.kind.as_str(),
+            rule: m.rule,
+            sources: m.sources,
+        }
+    };
+    let matches = trace
+        .block_matches
+        .into_iter()
+        .map(to_match("deny"))
+        .chain(trace.allow_matches.into_iter().map(to_match("allow")))
+        .collect();
+
+    let explain = SearchExplanation {
+        group_id: explanation.group_id,
+        blocking_enabled: explanation.blocking_enabled,
+        schedule: explanation.schedule.map(|state| match state {
+            GroupOverride::BlockAll => "block_all",
+            GroupOverride::AllowAll => "allow_all",
+            GroupOverride::TimedBypassUntil(_) => "timed_bypass",
+            GroupOverride::TimedBlockUntil(_) => "timed_block",
+        }),
+        safe_search_cname: explanation.safe_search_cname,
+        cname_parent: trace.cname_parent,
+        group_sources: trace.group_sources,
+        matches,
+    };
+
+    Ok(Json(SearchResponse { results, explain }))
 }
@@ -5,12 +5,13 @@ use ferrous_dns_application::use_cases::{
     CreateManagedDomainUseCase, CreateManualClientUseCase, CreateRegexFilterUseCase,
     CreateWhitelistSourceUseCase, DeleteBlocklistSourceUseCase, DeleteClientUseCase,
     DeleteGroupUseCase, DeleteManagedDomainUseCase, DeleteRegexFilterUseCase,
-    DeleteWhitelistSourceUseCase, GetBlockFilterStatsUseCase, GetBlocklistSourcesUseCase,
-    GetCacheStatsUseCase, GetClientsUseCase, GetGroupsUseCase, GetManagedDomainsUseCase,
-    GetQueryStatsUseCase, GetRecentQueriesUseCase, GetRegexFiltersUseCase, GetTimelineUseCase,
-    GetTopBlockedDomainsUseCase, GetTopClientsUseCase, GetWhitelistSourcesUseCase,
-    UpdateBlocklistSourceUseCase, UpdateClientUseCase, UpdateGroupUseCase,
-    UpdateManagedDomainUseCase, UpdateRegexFilterUseCase, UpdateWhitelistSourceUseCase,
+    DeleteWhitelistSourceUseCase, ExplainFilterDecisionUseCase, GetBlockFilterStatsUseCase,
+    GetBlocklistSourcesUseCase, GetCacheStatsUseCase, GetClientsUseCase, GetGroupsUseCase,
+    GetManagedDomainsUseCase, GetQueryStatsUseCase, GetRecentQueriesUseCase,
+    GetRegexFiltersUseCase, GetTimelineUseCase, GetTopBlockedDomainsUseCase, GetTopClientsUseCase,
+    GetWhitelistSourcesUseCase, UpdateBlocklistSourceUseCase, UpdateClientUseCase,
+    UpdateGroupUseCase, UpdateManagedDomainUseCase, UpdateRegexFilterUseCase,
+    UpdateWhitelistSourceUseCase,
 };
 use ferrous_dns_domain::Config;
 use std::sync::Arc;
@@ -51,6 +52,7 @@ pub struct PiholeQueryState {
 #[derive(Clone)]
 pub struct PiholeBlockingState {
     pub block_filter_engine: Arc<dyn BlockFilterEnginePort>,
+    pub explain_filter_decision: Arc<ExplainFilterDecisionUseCase>,
     pub get_managed_domains: Arc<GetManagedDomainsUseCase>,
     pub create_managed_domain: Arc<CreateManagedDomainUseCase>,
     pub update_managed_domain: Arc<UpdateManagedDomainUseCase>,
@@ -8,20 +8,21 @@ use ferrous_dns_api_pihole::state::{
 };
 use ferrous_dns_api_pihole::{create_pihole_routes, PiholeAppState};
 use ferrous_dns_application::ports::{
-    BlockFilterEnginePort, FilterDecision, UpstreamGroupHealth, UpstreamHealthPort, UpstreamStatus,
+    BlockFilterEnginePort, FilterDecision, SafeSearchEnginePort, UpstreamGroupHealth,
+    UpstreamHealthPort, UpstreamStatus,
 };
 use ferrous_dns_application::use_cases::{
     AssignClientGroupUseCase, CleanupOldQueryLogsUseCase, CreateBlocklistSourceUseCase,
     CreateGroupUseCase, CreateManagedDomainUseCase, CreateManualClientUseCase,
     CreateRegexFilterUseCase, CreateWhitelistSourceUseCase, DeleteBlocklistSourceUseCase,
     DeleteClientUseCase, DeleteGroupUseCase, DeleteManagedDomainUseCase, DeleteRegexFilterUseCase,
-    DeleteWhitelistSourceUseCase, GetBlockFilterStatsUseCase, GetBlocklistSourcesUseCase,
-    GetCacheStatsUseCase, GetClientsUseCase, GetGroupsUseCase, GetManagedDomainsUseCase,
-    GetQueryStatsUseCase, GetRecentQueriesUseCase, GetRegexFiltersUseCase, GetTimelineUseCase,
-    GetTopAllowedDomainsUseCase, GetTopBlockedDomainsUseCase, GetTopClientsUseCase,
-    GetWhitelistSourcesUseCase, UpdateBlocklistSourceUseCase, UpdateClientUseCase,
-    UpdateGroupUseCase, UpdateManagedDomainUseCase, UpdateRegexFilterUseCase,
-    UpdateWhitelistSourceUseCase,
+    DeleteWhitelistSourceUseCase, ExplainFilterDecisionUseCase, GetBlockFilterStatsUseCase,
+    GetBlocklistSourcesUseCase, GetCacheStatsUseCase, GetClientsUseCase, GetGroupsUseCase,
+    GetManagedDomainsUseCase, GetQueryStatsUseCase, GetRecentQueriesUseCase,
+    GetRegexFiltersUseCase, GetTimelineUseCase, GetTopAllowedDomainsUseCase,
+    GetTopBlockedDomainsUseCase, GetTopClientsUseCase, GetWhitelistSourcesUseCase,
+    UpdateBlocklistSourceUseCase, UpdateClientUseCase, UpdateGroupUseCase,
+    UpdateManagedDomainUseCase, UpdateRegexFilterUseCase, UpdateWhitelistSourceUseCase,
 };
 use ferrous_dns_domain::config::DatabaseConfig;
 use ferrous_dns_domain::Config;
@@ -34,6 +35,7 @@ use ferrous_dns_infrastructure::repositories::{
     regex_filter_repository::SqliteRegexFilterRepository,
     whitelist_source_repository::SqliteWhitelistSourceRepository,
 };
+use ferrous_dns_infrastructure::schedule::ScheduleStateStore;
 use sqlx::sqlite::SqlitePoolOptions;
 use std::net::IpAddr;
 use std::sync::Arc;
@@ -55,7 +57,14 @@ impl BlockFilterEnginePort for MockBlockFilterEngine {
         FilterDecision::Allow
     }
 
-    fn store_cname_decision(&self, _domain: &str, _group_id: i64, _ttl_secs: u64) {}
+    fn store_cname_decision(
+        &self,
+        _domain: &str,
+        _blocked_cname: &str,
+        _group_id: i64,
+        _ttl_secs: u64,
+    ) {
+    }
 
     async fn reload(&self) -> Result<(), DomainError> {
         Ok(())
@@ -76,6 +85,23 @@ impl BlockFilterEnginePort for MockBlockFilterEngine {
     fn set_blocking_enabled(&self, _enabled: bool) {}
 }
 
+// ---------------------------------------------------------------------------
+// Mock: SafeSearchEnginePort
+// ---------------------------------------------------------------------------
+
+struct MockSafeSearchEngine;
+
+#[async_trait]
+impl SafeSearchEnginePort for MockSafeSearchEngine {
+    fn cname_for(&self, _domain: &str, _group_id: i64) -> Option<&'static str> {
+        None
+    }
+
+    async fn reload(&self) -> Result<(), DomainError> {
+        Ok(())
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Mock: UpstreamHealthPort
 // ---------------------------------------------------------------------------
@@ -109,6 +135,10 @@ pub async fn create_test_db() -> sqlx::SqlitePool {
             enabled BOOLEAN NOT NULL DEFAULT 1,
             comment TEXT,
             is_default BOOLEAN NOT NULL DEFAULT 0,
+            blocking_mode TEXT,
+            blocking_ipv4 TEXT,
+            blocking_ipv6 TEXT,
+            upstream_pools TEXT,
             created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
             updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
         )",
@@ -162,6 +192,7 @@ pub async fn create_test_db() -> sqlx::SqlitePool {
             query_source TEXT NOT NULL DEFAULT 'client',
             group_id INTEGER,
             block_source TEXT,
+            blocklist_source_ids TEXT,
             created_at DATETIME NOT NULL DEFAULT (datetime('now'))
         )",
     )
@@ -212,6 +243,7 @@ pub async fn create_test_db() -> sqlx::SqlitePool {
             group_id    INTEGER NOT NULL DEFAULT 1 REFERENCES groups(id) ON DELETE RESTRICT,
             comment     TEXT,
             enabled     BOOLEAN NOT NULL DEFAULT 1,
+            observe_only INTEGER NOT NULL DEFAULT 0,
             created_at  DATETIME DEFAULT CURRENT_TIMESTAMP,
             updated_at  DATETIME DEFAULT CURRENT_TIMESTAMP
         )",
@@ -258,6 +290,37 @@ pub async fn create_test_db() -> sqlx::SqlitePool {
     .await
     .expect("Failed to create whitelist_source_groups table");
 
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS list_source_fetches (
+            url              TEXT    PRIMARY KEY,
+            etag             TEXT,
+            last_modified    TEXT,
+            content          TEXT,
+            last_attempt_at  TEXT    NOT NULL,
+            last_success_at  TEXT,
+            last_http_status INTEGER,
+            entry_count      INTEGER NOT NULL DEFAULT 0,
+            parse_errors     INTEGER NOT NULL DEFAULT 0,
+            last_error       TEXT
+        )",
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create list_source_fetches table");
+
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS blocklist_source_hits (
+            source_id INTEGER NOT NULL,
+            hour      INTEGER NOT NULL,
+            domain    TEXT    NOT NULL,
+            hits      INTEGER NOT NULL DEFAULT 0,
+            PRIMARY KEY (source_id, hour, domain)
+        )",
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create blocklist_source_hits table");
+
     pool
 }
 
@@ -344,6 +407,12 @@ async fn build_pihole_state(pool: sqlx::SqlitePool) -> PiholeAppState {
                 regex_filter_repo,
                 block_filter_engine.clone(),
             )),
+            explain_filter_decision: Arc::new(ExplainFilterDecisionUseCase::new(
+                block_filter_engine.clone(),
+                Arc::new(ScheduleStateStore::new()),
+                Arc::new(MockSafeSearchEngine),
+                group_repo.clone(),
+            )),
             blocking_timer: Arc::new(tokio::sync::Mutex::new(None)),
         },
         lists: PiholeListsState {
@@ -62,3 +62,29 @@ async fn search_unknown_domain_returns_empty_results() {
         );
     }
 }
+
+#[tokio::test]
+async fn search_includes_decision_explanation() {
+    let pool = helpers::create_test_db().await;
+    let app = helpers::create_pihole_test_app(pool, None).await;
+
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .uri("/search/Example.COM?client=192.168.1.10")
+                .body(Body::empty())
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let body = resp.into_body().collect().await.expect("body").to_bytes();
+    let json: Value = serde_json::from_slice(&body).expect("json");
+
+    assert_eq!(json["explain"]["group_id"], 1);
+    assert_eq!(json["explain"]["blocking_enabled"], true);
+    assert!(json["explain"]["schedule"].is_null());
+    assert!(json["explain"]["matches"].as_array().unwrap().is_empty());
+}
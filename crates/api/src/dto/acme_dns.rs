@@ -0,0 +1,77 @@
+use ferrous_dns_application::use_cases::RegisteredAcmeAccount;
+use ferrous_dns_domain::AcmeRegistration;
+use serde::{Deserialize, Serialize};
+
+/// Body of acme-dns `/register`. May be omitted entirely.
+#[derive(Debug, Default, Deserialize)]
+pub struct AcmeRegisterRequest {
+    /// Networks allowed to call `/update` for the new registration.
+    #[serde(default)]
+    pub allowfrom: Vec<String>,
+}
+
+/// Response of acme-dns `/register`. The password is never shown again.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AcmeRegisterResponse {
+    pub username: String,
+    pub password: String,
+    pub fulldomain: String,
+    pub subdomain: String,
+    pub allowfrom: Vec<String>,
+}
+
+impl AcmeRegisterResponse {
+    pub fn from_account(account: RegisteredAcmeAccount) -> Self {
+        Self {
+            username: account.registration.username.to_string(),
+            password: account.password,
+            fulldomain: account.fulldomain,
+            subdomain: account.registration.subdomain.to_string(),
+            allowfrom: account
+                .registration
+                .allow_from
+                .iter()
+                .map(|cidr| cidr.to_string())
+                .collect(),
+        }
+    }
+}
+
+/// Body of acme-dns `/update`.
+#[derive(Debug, Deserialize)]
+pub struct AcmeUpdateRequest {
+    #[serde(default)]
+    pub subdomain: String,
+    #[serde(default)]
+    pub txt: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AcmeUpdateResponse {
+    pub txt: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AcmeRegistrationResponse {
+    pub id: i64,
+    pub username: String,
+    pub subdomain: String,
+    pub allow_from: Vec<String>,
+    pub txt: Vec<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+impl AcmeRegistrationResponse {
+    pub fn from_registration(r: AcmeRegistration) -> Self {
+        Self {
+            id: r.id.unwrap_or(0),
+            username: r.username.to_string(),
+            subdomain: r.subdomain.to_string(),
+            allow_from: r.allow_from.iter().map(|c| c.to_string()).collect(),
+            txt: r.txt.iter().map(|t| t.to_string()).collect(),
+            created_at: r.created_at,
+            updated_at: r.updated_at,
+        }
+    }
+}
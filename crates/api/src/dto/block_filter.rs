@@ -1,6 +1,100 @@
-use serde::Serialize;
+use ferrous_dns_application::ports::{FilterDecision, TraceMatch};
+use ferrous_dns_application::use_cases::FilterExplanation;
+use ferrous_dns_domain::GroupOverride;
+use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Debug, Clone)]
 pub struct BlockFilterStatsResponse {
     pub total_blocked_domains: usize,
 }
+
+/// Query for `GET /block-filter/explain`. `client` takes precedence over
+/// `group_id`; with neither, the default group is used.
+#[derive(Deserialize, Debug)]
+pub struct ExplainDecisionQuery {
+    pub domain: String,
+    pub client: Option<String>,
+    pub group_id: Option<i64>,
+    /// Query type considered by `$dnstype` rules; defaults to `A`.
+    #[serde(rename = "type")]
+    pub record_type: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct TraceMatchResponse {
+    pub kind: &'static str,
+    pub rule: String,
+    pub sources: Vec<String>,
+}
+
+impl From<TraceMatch> for TraceMatchResponse {
+    fn from(m: TraceMatch) -> Self {
+        Self {
+            kind: m.kind.as_str(),
+            rule: m.rule,
+            sources: m.sources,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ScheduleStateResponse {
+    pub state: &'static str,
+    /// Unix time the timed override ends at.
+    pub until: Option<u64>,
+}
+
+impl From<GroupOverride> for ScheduleStateResponse {
+    fn from(state: GroupOverride) -> Self {
+        let (state, until) = match state {
+            GroupOverride::BlockAll => ("block_all", None),
+            GroupOverride::AllowAll => ("allow_all", None),
+            GroupOverride::TimedBypassUntil(t) => ("timed_bypass", Some(t)),
+            GroupOverride::TimedBlockUntil(t) => ("timed_block", Some(t)),
+        };
+        Self { state, until }
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ExplainDecisionResponse {
+    pub domain: String,
+    pub group_id: i64,
+    pub group_name: Option<String>,
+    pub client_ip: Option<String>,
+    pub record_type: String,
+    pub blocked: bool,
+    pub block_source: Option<&'static str>,
+    pub blocking_enabled: bool,
+    pub schedule: Option<ScheduleStateResponse>,
+    pub safe_search_cname: Option<&'static str>,
+    pub cname_parent: Option<String>,
+    pub group_sources: Vec<String>,
+    pub block_matches: Vec<TraceMatchResponse>,
+    pub allow_matches: Vec<TraceMatchResponse>,
+}
+
+impl From<FilterExplanation> for ExplainDecisionResponse {
+    fn from(e: FilterExplanation) -> Self {
+        let block_source = match e.decision {
+            FilterDecision::Block(source) => Some(source.to_str()),
+            FilterDecision::Allow => None,
+        };
+        Self {
+            domain: e.domain,
+            group_id: e.group_id,
+            group_name: e.group_name,
+            client_ip: e.client_ip.map(|ip| ip.to_string()),
+            record_type: e.record_type.to_string(),
+            blocked: block_source.is_some(),
+            block_source,
+            blocking_enabled: e.blocking_enabled,
+            schedule: e.schedule.map(ScheduleStateResponse::from),
+            safe_search_cname: e.safe_search_cname,
+            cname_parent: e.trace.cname_parent,
+            group_sources: e.trace.group_sources,
+            block_matches: e.trace.block_matches.into_iter().map(Into::into).collect(),
+            allow_matches: e.trace.allow_matches.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -1,4 +1,9 @@
-use ferrous_dns_domain::BlocklistSource;
+use super::list_source_status::ListSourceStatusResponse;
+use chrono::{DateTime, Duration, Utc};
+use ferrous_dns_application::ports::{
+    BlocklistSimulation, CandidateList, SimulationImpact, SimulationReport,
+};
+use ferrous_dns_domain::{BlocklistSource, BlocklistSourceHits, DomainError};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -9,8 +14,11 @@ pub struct BlocklistSourceResponse {
     pub group_ids: Vec<i64>,
     pub comment: Option<String>,
     pub enabled: bool,
+    pub observe_only: bool,
     pub created_at: Option<String>,
     pub updated_at: Option<String>,
+    /// Outcome of the last download; `null` until the list has been fetched.
+    pub status: Option<ListSourceStatusResponse>,
 }
 
 impl BlocklistSourceResponse {
@@ -22,12 +30,51 @@ impl BlocklistSourceResponse {
             group_ids: source.group_ids,
             comment: source.comment.as_ref().map(|s| s.to_string()),
             enabled: source.enabled,
+            observe_only: source.observe_only,
             created_at: source.created_at,
             updated_at: source.updated_at,
+            status: source.status.map(ListSourceStatusResponse::from_status),
         }
     }
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct BlocklistSourceHitsResponse {
+    pub id: i64,
+    pub name: String,
+    pub enabled: bool,
+    pub hits_24h: u64,
+    pub hits_7d: u64,
+    pub hits_30d: u64,
+    pub unique_domains_24h: u64,
+    pub unique_domains_7d: u64,
+    pub unique_domains_30d: u64,
+}
+
+impl BlocklistSourceHitsResponse {
+    pub fn from_hits(hits: BlocklistSourceHits) -> Self {
+        Self {
+            id: hits.source_id,
+            name: hits.name.to_string(),
+            enabled: hits.enabled,
+            hits_24h: hits.hits_24h,
+            hits_7d: hits.hits_7d,
+            hits_30d: hits.hits_30d,
+            unique_domains_24h: hits.unique_domains_24h,
+            unique_domains_7d: hits.unique_domains_7d,
+            unique_domains_30d: hits.unique_domains_30d,
+        }
+    }
+}
+
+/// Query for `GET /blocklist-sources/hits`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct BlocklistSourceHitsQuery {
+    /// Only list sources without hits in the last 30 days.
+    #[serde(default)]
+    pub zero_hits: bool,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct CreateBlocklistSourceRequest {
     pub name: String,
@@ -37,6 +84,8 @@ pub struct CreateBlocklistSourceRequest {
     pub group_ids: Option<Vec<i64>>,
     pub comment: Option<String>,
     pub enabled: Option<bool>,
+    /// Log matches as would-block instead of blocking them.
+    pub observe_only: Option<bool>,
 }
 
 impl CreateBlocklistSourceRequest {
@@ -65,6 +114,7 @@ pub struct UpdateBlocklistSourceRequest {
     pub group_ids: Option<Vec<i64>>,
     pub comment: Option<String>,
     pub enabled: Option<bool>,
+    pub observe_only: Option<bool>,
 }
 
 impl UpdateBlocklistSourceRequest {
@@ -95,3 +145,125 @@ where
         )),
     }
 }
+
+/// Body of `POST /blocklist-sources/simulate`. Exactly one of `url` and
+/// `text` is required.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SimulateBlocklistRequest {
+    pub url: Option<String>,
+    pub text: Option<String>,
+    /// RFC 3339 start of the replayed range; defaults to 24 hours before `to`.
+    pub from: Option<String>,
+    /// RFC 3339 end of the replayed range; defaults to now.
+    pub to: Option<String>,
+    /// Groups the list would be assigned to; empty means every group.
+    #[serde(default)]
+    pub group_ids: Vec<i64>,
+    /// Domains and clients listed per outcome (default 100, max 1000).
+    pub limit: Option<usize>,
+}
+
+impl SimulateBlocklistRequest {
+    pub fn into_simulation(self) -> Result<BlocklistSimulation, DomainError> {
+        let list = match (self.url, self.text) {
+            (Some(url), None) => CandidateList::Url(url),
+            (None, Some(text)) => CandidateList::Text(text),
+            _ => {
+                return Err(DomainError::InvalidInput(
+                    "Provide exactly one of 'url' and 'text'".to_string(),
+                ))
+            }
+        };
+        let to = match self.to {
+            Some(to) => parse_timestamp("to", &to)?,
+            None => Utc::now(),
+        };
+        let from = match self.from {
+            Some(from) => parse_timestamp("from", &from)?,
+            None => to - Duration::hours(24),
+        };
+        Ok(BlocklistSimulation {
+            list,
+            from,
+            to,
+            group_ids: self.group_ids,
+            limit: self.limit.unwrap_or(100),
+        })
+    }
+}
+
+fn parse_timestamp(field: &str, value: &str) -> Result<DateTime<Utc>, DomainError> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|t| t.with_timezone(&Utc))
+        .map_err(|e| DomainError::InvalidInput(format!("Invalid '{field}' timestamp: {e}")))
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SimulatedDomainResponse {
+    pub domain: String,
+    pub queries: u64,
+    pub clients: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SimulatedClientResponse {
+    pub client: String,
+    pub queries: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SimulationImpactResponse {
+    pub queries: u64,
+    pub total_domains: u64,
+    pub total_clients: u64,
+    pub domains: Vec<SimulatedDomainResponse>,
+    pub clients: Vec<SimulatedClientResponse>,
+}
+
+impl SimulationImpactResponse {
+    fn from_impact(impact: SimulationImpact) -> Self {
+        Self {
+            queries: impact.queries,
+            total_domains: impact.total_domains,
+            total_clients: impact.total_clients,
+            domains: impact
+                .domains
+                .into_iter()
+                .map(|d| SimulatedDomainResponse {
+                    domain: d.domain,
+                    queries: d.queries,
+                    clients: d.clients,
+                })
+                .collect(),
+            clients: impact
+                .clients
+                .into_iter()
+                .map(|c| SimulatedClientResponse {
+                    client: c.client_ip.to_string(),
+                    queries: c.queries,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SimulationReportResponse {
+    pub entry_count: usize,
+    pub parse_errors: usize,
+    pub queries_examined: u64,
+    pub newly_blocked: SimulationImpactResponse,
+    pub newly_unblocked: SimulationImpactResponse,
+}
+
+impl SimulationReportResponse {
+    pub fn from_report(report: SimulationReport) -> Self {
+        Self {
+            entry_count: report.entry_count,
+            parse_errors: report.parse_errors,
+            queries_examined: report.queries_examined,
+            newly_blocked: SimulationImpactResponse::from_impact(report.newly_blocked),
+            newly_unblocked: SimulationImpactResponse::from_impact(report.newly_unblocked),
+        }
+    }
+}
@@ -34,4 +34,6 @@ pub struct CacheMetricsResponse {
     pub batch_evictions: u64,
     pub hit_rate: f64,
     pub transient_upstream_errors: u64,
+    pub aggressive_nxdomain_hits: u64,
+    pub aggressive_nodata_hits: u64,
 }
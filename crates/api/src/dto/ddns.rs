@@ -0,0 +1,50 @@
+use ferrous_dns_domain::DdnsUpdate;
+use serde::{Deserialize, Serialize};
+
+/// Query string of `/nic/update`, as sent by dyndns2 clients.
+#[derive(Debug, Default, Deserialize)]
+pub struct DdnsUpdateQuery {
+    /// One hostname or a comma-separated list.
+    #[serde(default)]
+    pub hostname: String,
+    /// Address to publish; the client address is used when missing or invalid.
+    pub myip: Option<String>,
+    /// IPv6 address to publish alongside `myip`.
+    pub myipv6: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DdnsHistoryQuery {
+    pub hostname: Option<String>,
+    #[serde(default = "default_limit")]
+    pub limit: u32,
+}
+
+fn default_limit() -> u32 {
+    100
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DdnsUpdateResponse {
+    pub id: i64,
+    pub hostname: String,
+    pub ip: Option<String>,
+    pub result: String,
+    pub token_id: Option<i64>,
+    pub client_ip: Option<String>,
+    pub created_at: Option<String>,
+}
+
+impl DdnsUpdateResponse {
+    pub fn from_update(u: DdnsUpdate) -> Self {
+        Self {
+            id: u.id.unwrap_or(0),
+            hostname: u.hostname.to_string(),
+            ip: u.ip.map(|ip| ip.to_string()),
+            result: u.result.as_str().to_string(),
+            token_id: u.token_id,
+            client_ip: u.client_ip.map(|ip| ip.to_string()),
+            created_at: u.created_at,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use ferrous_dns_domain::DnsRewriteRule;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DnsRewriteRuleResponse {
+    pub id: i64,
+    pub domain: String,
+    pub rewrite: String,
+    pub group_id: i64,
+    pub enabled: bool,
+    pub comment: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+impl DnsRewriteRuleResponse {
+    pub fn from_rule(r: DnsRewriteRule) -> Self {
+        Self {
+            id: r.id.unwrap_or(0),
+            domain: r.domain.to_string(),
+            rewrite: r.answer.to_string(),
+            group_id: r.group_id,
+            enabled: r.enabled,
+            comment: r.comment.as_ref().map(|s| s.to_string()),
+            created_at: r.created_at,
+            updated_at: r.updated_at,
+        }
+    }
+}
+
+/// `rewrite` uses the `$dnsrewrite` value syntax, e.g. `NOERROR;A;10.0.0.5`,
+/// `lan.example` or `NXDOMAIN`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateDnsRewriteRuleRequest {
+    pub domain: String,
+    pub rewrite: String,
+    pub group_id: Option<i64>,
+    pub enabled: Option<bool>,
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateDnsRewriteRuleRequest {
+    pub domain: Option<String>,
+    pub rewrite: Option<String>,
+    pub group_id: Option<i64>,
+    pub enabled: Option<bool>,
+    pub comment: Option<String>,
+}
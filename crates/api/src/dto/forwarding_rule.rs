@@ -0,0 +1,47 @@
+use ferrous_dns_domain::ForwardingRule;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForwardingRuleResponse {
+    pub id: i64,
+    pub domain: String,
+    pub target_type: String,
+    pub target: String,
+    pub enabled: bool,
+    pub comment: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+impl ForwardingRuleResponse {
+    pub fn from_rule(r: ForwardingRule) -> Self {
+        Self {
+            id: r.id.unwrap_or(0),
+            domain: r.domain.to_string(),
+            target_type: r.target.type_str().to_string(),
+            target: r.target.value().to_string(),
+            enabled: r.enabled,
+            comment: r.comment.as_ref().map(|s| s.to_string()),
+            created_at: r.created_at,
+            updated_at: r.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateForwardingRuleRequest {
+    pub domain: String,
+    pub target_type: String,
+    pub target: String,
+    pub enabled: Option<bool>,
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateForwardingRuleRequest {
+    pub domain: Option<String>,
+    pub target_type: Option<String>,
+    pub target: Option<String>,
+    pub enabled: Option<bool>,
+    pub comment: Option<String>,
+}
@@ -1,5 +1,6 @@
-use ferrous_dns_domain::Group;
+use ferrous_dns_domain::{BlockingMode, Group};
 use serde::{Deserialize, Serialize};
+use std::net::{Ipv4Addr, Ipv6Addr};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GroupResponse {
@@ -9,6 +10,10 @@ pub struct GroupResponse {
     pub comment: Option<String>,
     pub is_default: bool,
     pub client_count: Option<u64>,
+    pub blocking_mode: Option<BlockingMode>,
+    pub blocking_ipv4: Option<Ipv4Addr>,
+    pub blocking_ipv6: Option<Ipv6Addr>,
+    pub upstream_pools: Vec<String>,
     pub created_at: Option<String>,
     pub updated_at: Option<String>,
 }
@@ -22,6 +27,10 @@ impl GroupResponse {
             comment: group.comment.as_ref().map(|s| s.to_string()),
             is_default: group.is_default,
             client_count,
+            blocking_mode: group.blocking_mode,
+            blocking_ipv4: group.blocking_ipv4,
+            blocking_ipv6: group.blocking_ipv6,
+            upstream_pools: group.upstream_pools.iter().map(|p| p.to_string()).collect(),
             created_at: group.created_at,
             updated_at: group.updated_at,
         }
@@ -42,6 +51,23 @@ pub struct UpdateGroupRequest {
     pub comment: Option<String>,
 }
 
+/// Body of `PUT /groups/{id}/blocking-mode`. A missing or null `mode`
+/// removes the override so the group follows the global blocking mode.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateGroupBlockingModeRequest {
+    pub mode: Option<BlockingMode>,
+    pub ipv4: Option<Ipv4Addr>,
+    pub ipv6: Option<Ipv6Addr>,
+}
+
+/// Body of `PUT /groups/{id}/upstream-pools`. An empty or missing `pools`
+/// list puts the group back on the global upstream pools.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateGroupUpstreamPoolsRequest {
+    #[serde(default)]
+    pub pools: Vec<String>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct AssignGroupRequest {
     pub group_id: i64,
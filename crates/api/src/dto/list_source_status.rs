@@ -0,0 +1,25 @@
+use ferrous_dns_domain::ListSourceStatus;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListSourceStatusResponse {
+    pub last_attempt_at: String,
+    pub last_success_at: Option<String>,
+    pub last_http_status: Option<u16>,
+    pub entry_count: u64,
+    pub parse_errors: u64,
+    pub last_error: Option<String>,
+}
+
+impl ListSourceStatusResponse {
+    pub fn from_status(status: ListSourceStatus) -> Self {
+        Self {
+            last_attempt_at: status.last_attempt_at,
+            last_success_at: status.last_success_at,
+            last_http_status: status.last_http_status,
+            entry_count: status.entry_count,
+            parse_errors: status.parse_errors,
+            last_error: status.last_error.as_ref().map(|s| s.to_string()),
+        }
+    }
+}
@@ -50,3 +50,58 @@ pub struct UpdateLocalRecordRequest {
     pub record_type: String,
     pub ttl: Option<u32>,
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ZoneRecordDto {
+    pub id: usize,
+    pub name: String,
+    pub ttl: u32,
+    pub record_type: String,
+    pub data: String,
+}
+
+impl ZoneRecordDto {
+    pub fn from_domain(record: &ferrous_dns_domain::ZoneRecord, index: usize) -> Self {
+        Self {
+            id: index,
+            name: record.name.to_string(),
+            ttl: record.ttl,
+            record_type: record.record_type.to_string(),
+            data: record.data.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LocalZoneDto {
+    pub origin: String,
+    pub file: String,
+    pub serial: Option<u32>,
+    pub records: Vec<ZoneRecordDto>,
+}
+
+impl LocalZoneDto {
+    pub fn from_domain(zone: &ferrous_dns_domain::LocalZone) -> Self {
+        Self {
+            origin: zone.origin.to_string(),
+            file: zone.file.to_string(),
+            serial: zone.serial(),
+            records: zone
+                .records
+                .iter()
+                .enumerate()
+                .map(|(idx, record)| ZoneRecordDto::from_domain(record, idx))
+                .collect(),
+        }
+    }
+}
+
+/// Body for creating or replacing a zone record. `name` is relative to the
+/// zone unless it ends with a dot; `@` is the apex.
+#[derive(Debug, Deserialize)]
+pub struct ZoneRecordRequest {
+    pub name: String,
+    pub ttl: Option<u32>,
+    pub record_type: String,
+    pub data: String,
+}
@@ -1,3 +1,4 @@
+pub mod acme_dns;
 pub mod api_token;
 pub mod auth;
 pub mod backup;
@@ -11,10 +12,15 @@ pub mod client_subnet;
 pub mod config;
 pub mod custom_service;
 pub mod dashboard;
+pub mod ddns;
+pub mod dns_rewrite;
+pub mod forwarding_rule;
 pub mod group;
 pub mod hostname;
+pub mod list_source_status;
 pub mod local_record;
 pub mod managed_domain;
+pub mod negative_trust_anchor;
 pub mod query;
 pub mod rate;
 pub mod regex_filter;
@@ -24,24 +30,36 @@ pub mod stats;
 pub mod system_info;
 pub mod timeline;
 pub mod tls;
+pub mod trust_anchor;
+pub mod tsig_key;
 pub mod user;
 pub mod whitelist;
 pub mod whitelist_source;
 
+pub use acme_dns::{
+    AcmeRegisterRequest, AcmeRegisterResponse, AcmeRegistrationResponse, AcmeUpdateRequest,
+    AcmeUpdateResponse,
+};
 pub use blocked_service::{BlockServiceRequest, BlockedServiceResponse, ServiceDefinitionResponse};
 pub use custom_service::{
     CreateCustomServiceRequest, CustomServiceResponse, UpdateCustomServiceRequest,
 };
-pub use local_record::{CreateLocalRecordRequest, LocalRecordDto};
+pub use local_record::{
+    CreateLocalRecordRequest, LocalRecordDto, LocalZoneDto, ZoneRecordDto, ZoneRecordRequest,
+};
 pub use managed_domain::{
     CreateManagedDomainRequest, ManagedDomainQuery, ManagedDomainResponse, PaginatedManagedDomains,
     UpdateManagedDomainRequest,
 };
+pub use negative_trust_anchor::{CreateNegativeTrustAnchorRequest, NegativeTrustAnchorResponse};
 pub use regex_filter::{CreateRegexFilterRequest, RegexFilterResponse, UpdateRegexFilterRequest};
+pub use tsig_key::{CreateTsigKeyRequest, CreatedTsigKeyResponse, TsigKeyResponse};
 
 pub use blocklist::{BlocklistQuery, BlocklistResponse, PaginatedBlocklist};
 pub use blocklist_source::{
-    BlocklistSourceResponse, CreateBlocklistSourceRequest, UpdateBlocklistSourceRequest,
+    BlocklistSourceHitsQuery, BlocklistSourceHitsResponse, BlocklistSourceResponse,
+    CreateBlocklistSourceRequest, SimulateBlocklistRequest, SimulationReportResponse,
+    UpdateBlocklistSourceRequest,
 };
 pub use cache::{CacheMetricsResponse, CacheStatsQuery, CacheStatsResponse};
 pub use client::{ClientResponse, ClientStatsResponse, ClientsQuery, UpdateClientRequest};
@@ -50,15 +68,27 @@ pub use client_subnet::{
 };
 pub use config::*;
 pub use dashboard::{DashboardQuery, DashboardResponse, TopBlockedDomain, TopClient};
-pub use group::{AssignGroupRequest, CreateGroupRequest, GroupResponse, UpdateGroupRequest};
+pub use ddns::{DdnsHistoryQuery, DdnsUpdateQuery, DdnsUpdateResponse};
+pub use dns_rewrite::{
+    CreateDnsRewriteRuleRequest, DnsRewriteRuleResponse, UpdateDnsRewriteRuleRequest,
+};
+pub use forwarding_rule::{
+    CreateForwardingRuleRequest, ForwardingRuleResponse, UpdateForwardingRuleRequest,
+};
+pub use group::{
+    AssignGroupRequest, CreateGroupRequest, GroupResponse, UpdateGroupBlockingModeRequest,
+    UpdateGroupRequest, UpdateGroupUpstreamPoolsRequest,
+};
 pub use hostname::HostnameResponse;
-pub use query::{PaginatedQueries, QueryParams, QueryResponse};
+pub use list_source_status::ListSourceStatusResponse;
+pub use query::{PaginatedQueries, QueryParams, QueryResponse, QueryStreamParams};
 pub use rate::{QueryRateResponse, RateQuery};
 pub use safe_search::{SafeSearchConfigResponse, ToggleSafeSearchRequest};
 pub use stats::{QuerySourceStats, StatsQuery, StatsResponse, TopType, TypeDistribution};
 pub use system_info::SystemInfoResponse;
 pub use timeline::{TimelineBucket, TimelineQuery, TimelineResponse};
 pub use tls::{GenerateQuery, TlsStatusResponse, TlsUploadResponse};
+pub use trust_anchor::TrustAnchorResponse;
 pub use whitelist::WhitelistResponse;
 pub use whitelist_source::{
     CreateWhitelistSourceRequest, UpdateWhitelistSourceRequest, WhitelistSourceResponse,
@@ -0,0 +1,31 @@
+use ferrous_dns_domain::NegativeTrustAnchor;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NegativeTrustAnchorResponse {
+    pub id: i64,
+    pub domain: String,
+    pub expires_at: Option<String>,
+    pub comment: Option<String>,
+    pub created_at: Option<String>,
+}
+
+impl NegativeTrustAnchorResponse {
+    pub fn from_anchor(a: NegativeTrustAnchor) -> Self {
+        Self {
+            id: a.id.unwrap_or(0),
+            domain: a.domain.to_string(),
+            expires_at: a.expires_at,
+            comment: a.comment.as_ref().map(|s| s.to_string()),
+            created_at: a.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateNegativeTrustAnchorRequest {
+    pub domain: String,
+    /// Seconds until the anchor expires; omit to keep it until deleted.
+    pub duration_secs: Option<u64>,
+    pub comment: Option<String>,
+}
@@ -1,3 +1,4 @@
+use ferrous_dns_domain::QueryLog;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
@@ -18,6 +19,18 @@ pub struct QueryParams {
     pub upstream: Option<String>,
 }
 
+/// Filters of `GET /queries/stream`.
+#[derive(Deserialize, Debug)]
+pub struct QueryStreamParams {
+    /// Exact client IP.
+    pub client: Option<String>,
+    /// Case-insensitive substring of the domain.
+    pub domain: Option<String>,
+    /// Only stream blocked queries.
+    #[serde(default)]
+    pub blocked: bool,
+}
+
 fn default_limit() -> u32 {
     100
 }
@@ -47,6 +60,8 @@ pub struct QueryResponse {
     #[serde(rename = "type")]
     pub record_type: &'static str,
     pub blocked: bool,
+    /// Allowed, but an observe-only blocklist source would have blocked it.
+    pub would_block: bool,
     pub response_time_us: Option<u64>,
     pub cache_hit: bool,
     pub cache_refresh: bool,
@@ -55,5 +70,31 @@ pub struct QueryResponse {
     pub upstream_pool: Option<Arc<str>>,
     pub query_source: &'static str,
     pub block_source: Option<&'static str>,
+    /// Blocklist sources whose entries blocked the query.
+    pub blocklist_source_ids: Vec<i64>,
     pub response_status: Option<&'static str>,
 }
+
+impl From<&QueryLog> for QueryResponse {
+    fn from(q: &QueryLog) -> Self {
+        Self {
+            would_block: q.would_block(),
+            timestamp: q.timestamp.clone().unwrap_or_default(),
+            domain: Arc::clone(&q.domain),
+            client: q.client_ip.to_string(),
+            client_hostname: q.client_hostname.clone(),
+            record_type: q.record_type.as_str(),
+            blocked: q.blocked,
+            response_time_us: q.response_time_us,
+            cache_hit: q.cache_hit,
+            cache_refresh: q.cache_refresh,
+            dnssec_status: q.dnssec_status,
+            upstream_server: q.upstream_server.clone(),
+            upstream_pool: q.upstream_pool.clone(),
+            query_source: q.query_source.as_str(),
+            block_source: q.block_source.map(|s| s.to_str()),
+            blocklist_source_ids: q.blocklist_source_ids.clone(),
+            response_status: q.response_status,
+        }
+    }
+}
@@ -7,7 +7,16 @@ pub struct TlsStatusResponse {
     pub key_exists: bool,
     pub cert_subject: Option<String>,
     pub cert_not_after: Option<String>,
+    /// Expiry as a Unix timestamp.
+    pub cert_expires_at: Option<i64>,
     pub cert_valid: bool,
+    pub cert_dns_names: Vec<String>,
+    /// SHA-256 fingerprint of the certificate file.
+    pub cert_fingerprint_sha256: Option<String>,
+    /// SHA-256 fingerprint of the certificate new handshakes receive.
+    /// Differs from `cert_fingerprint_sha256` while a changed file has
+    /// not been picked up or was rejected.
+    pub active_fingerprint_sha256: Option<String>,
 }
 
 #[derive(Serialize, Debug, Clone)]
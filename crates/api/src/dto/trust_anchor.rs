@@ -0,0 +1,44 @@
+use ferrous_dns_domain::{TrustAnchor, TrustAnchorData};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TrustAnchorResponse {
+    pub id: i64,
+    pub zone: String,
+    #[serde(rename = "type")]
+    pub record_type: String,
+    pub key_tag: u16,
+    pub algorithm: u8,
+    pub flags: Option<u16>,
+    pub digest_type: Option<u8>,
+    pub state: String,
+    pub source: String,
+    pub managed: bool,
+    pub first_seen: i64,
+    pub last_seen: Option<i64>,
+    pub hold_down_until: Option<i64>,
+}
+
+impl TrustAnchorResponse {
+    pub fn from_anchor(a: TrustAnchor) -> Self {
+        let (flags, digest_type) = match a.data {
+            TrustAnchorData::Dnskey { flags, .. } => (Some(flags), None),
+            TrustAnchorData::Ds { digest_type, .. } => (None, Some(digest_type)),
+        };
+        Self {
+            id: a.id.unwrap_or(0),
+            zone: a.zone.to_string(),
+            record_type: a.data.type_str().to_string(),
+            key_tag: a.key_tag(),
+            algorithm: a.data.algorithm(),
+            flags,
+            digest_type,
+            state: a.state.as_str().to_string(),
+            source: a.source.as_str().to_string(),
+            managed: a.managed,
+            first_seen: a.first_seen,
+            last_seen: a.last_seen,
+            hold_down_until: a.hold_down_until,
+        }
+    }
+}
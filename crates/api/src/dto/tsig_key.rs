@@ -0,0 +1,54 @@
+use ferrous_dns_domain::TsigKey;
+use serde::{Deserialize, Serialize};
+
+/// A TSIG key as listed or fetched. The secret is only ever returned once,
+/// in [`CreatedTsigKeyResponse`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TsigKeyResponse {
+    pub id: i64,
+    pub name: String,
+    pub algorithm: String,
+    pub created_at: Option<String>,
+}
+
+impl TsigKeyResponse {
+    pub fn from_key(k: TsigKey) -> Self {
+        Self {
+            id: k.id.unwrap_or(0),
+            name: k.name.to_string(),
+            algorithm: k.algorithm.to_string(),
+            created_at: k.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatedTsigKeyResponse {
+    pub id: i64,
+    pub name: String,
+    pub algorithm: String,
+    /// Base64-encoded secret to configure on the updating client.
+    pub secret: String,
+    pub created_at: Option<String>,
+}
+
+impl CreatedTsigKeyResponse {
+    pub fn from_key(k: TsigKey) -> Self {
+        Self {
+            id: k.id.unwrap_or(0),
+            name: k.name.to_string(),
+            algorithm: k.algorithm.to_string(),
+            secret: k.secret.to_string(),
+            created_at: k.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateTsigKeyRequest {
+    pub name: String,
+    /// Defaults to `hmac-sha256`.
+    pub algorithm: Option<String>,
+    /// Base64-encoded secret; omit to have one generated.
+    pub secret: Option<String>,
+}
@@ -1,3 +1,4 @@
+use super::list_source_status::ListSourceStatusResponse;
 use ferrous_dns_domain::WhitelistSource;
 use serde::{Deserialize, Serialize};
 
@@ -11,6 +12,8 @@ pub struct WhitelistSourceResponse {
     pub enabled: bool,
     pub created_at: Option<String>,
     pub updated_at: Option<String>,
+    /// Outcome of the last download; `null` until the list has been fetched.
+    pub status: Option<ListSourceStatusResponse>,
 }
 
 impl WhitelistSourceResponse {
@@ -24,6 +27,7 @@ impl WhitelistSourceResponse {
             enabled: source.enabled,
             created_at: source.created_at,
             updated_at: source.updated_at,
+            status: source.status.map(ListSourceStatusResponse::from_status),
         }
     }
 }
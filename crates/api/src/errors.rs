@@ -21,6 +21,14 @@ impl IntoResponse for ApiError {
             | DomainError::BlocklistSourceNotFound(_)
             | DomainError::WhitelistSourceNotFound(_)
             | DomainError::ManagedDomainNotFound(_)
+            | DomainError::ForwardingRuleNotFound(_)
+            | DomainError::DnsRewriteRuleNotFound(_)
+            | DomainError::LocalZoneNotFound(_)
+            | DomainError::ZoneRecordNotFound(_)
+            | DomainError::TrustAnchorNotFound(_)
+            | DomainError::NegativeTrustAnchorNotFound(_)
+            | DomainError::TsigKeyNotFound(_)
+            | DomainError::AcmeRegistrationNotFound(_)
             | DomainError::RegexFilterNotFound(_)
             | DomainError::CustomServiceNotFound(_)
             | DomainError::ClientNotFound(_)
@@ -64,6 +72,8 @@ impl IntoResponse for ApiError {
 
             DomainError::GroupNotFound(_) => (StatusCode::NOT_FOUND, self.0.to_string()),
 
+            DomainError::BlockFilterFetchError(_) => (StatusCode::BAD_GATEWAY, self.0.to_string()),
+
             DomainError::InvalidDomainName(_)
             | DomainError::InvalidIpAddress(_)
             | DomainError::InvalidCidr(_)
@@ -79,6 +89,14 @@ impl IntoResponse for ApiError {
             DomainError::InvalidBlocklistSource(_)
             | DomainError::InvalidWhitelistSource(_)
             | DomainError::InvalidManagedDomain(_)
+            | DomainError::InvalidForwardingRule(_)
+            | DomainError::InvalidDnsRewriteRule(_)
+            | DomainError::InvalidNegativeTrustAnchor(_)
+            | DomainError::InvalidTsigKey(_)
+            | DomainError::InvalidAcmeAllowFrom(_)
+            | DomainError::InvalidAcmeSubdomain
+            | DomainError::InvalidAcmeTxt
+            | DomainError::InvalidZoneRecord(_)
             | DomainError::InvalidRegexFilter(_)
             | DomainError::InvalidGroupName(_)
             | DomainError::DuplicateScheduleProfileName(_)
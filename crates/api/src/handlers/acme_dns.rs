@@ -0,0 +1,168 @@
+use std::net::SocketAddr;
+
+use axum::{
+    body::Bytes,
+    extract::{ConnectInfo, Path, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Json, Response},
+    routing::{delete, get, post},
+    Extension, Router,
+};
+use ferrous_dns_domain::DomainError;
+use serde_json::json;
+use tracing::debug;
+
+use crate::{
+    dto::{
+        AcmeRegisterRequest, AcmeRegisterResponse, AcmeRegistrationResponse, AcmeUpdateRequest,
+        AcmeUpdateResponse,
+    },
+    errors::ApiError,
+    state::AppState,
+    utils::client_ip,
+};
+
+/// Registration management under the API prefix, behind the usual
+/// authentication.
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/acme/registrations", get(get_all_acme_registrations))
+        .route("/acme/registrations/{id}", get(get_acme_registration_by_id))
+        .route("/acme/registrations/{id}", delete(delete_acme_registration))
+}
+
+/// The acme-dns `/register` and `/update` endpoints. ACME clients expect
+/// them at the server root and authenticate per registration, so they sit
+/// outside the API router.
+pub fn acme_dns_routes() -> Router<AppState> {
+    Router::new()
+        .route("/register", post(register))
+        .route("/update", post(update))
+}
+
+async fn register(
+    State(state): State<AppState>,
+    connect_info: Option<Extension<ConnectInfo<SocketAddr>>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let trust_proxy_headers = state.config.read().await.dns.acme_dns.trust_proxy_headers;
+    let client_ip = client_ip(&headers, connect_info, trust_proxy_headers);
+
+    let req = if body.iter().all(u8::is_ascii_whitespace) {
+        AcmeRegisterRequest::default()
+    } else {
+        match serde_json::from_slice::<AcmeRegisterRequest>(&body) {
+            Ok(req) => req,
+            Err(_) => return acme_error(StatusCode::BAD_REQUEST, "malformed_json_payload"),
+        }
+    };
+
+    match state
+        .dns
+        .register_acme_account
+        .execute(&req.allowfrom, client_ip)
+        .await
+    {
+        Ok(account) => (
+            StatusCode::CREATED,
+            Json(AcmeRegisterResponse::from_account(account)),
+        )
+            .into_response(),
+        Err(e) => acme_domain_error(e),
+    }
+}
+
+async fn update(
+    State(state): State<AppState>,
+    connect_info: Option<Extension<ConnectInfo<SocketAddr>>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let trust_proxy_headers = state.config.read().await.dns.acme_dns.trust_proxy_headers;
+    let client_ip = client_ip(&headers, connect_info, trust_proxy_headers);
+
+    let (Some(username), Some(password)) = (
+        headers.get("X-Api-User").and_then(|v| v.to_str().ok()),
+        headers.get("X-Api-Key").and_then(|v| v.to_str().ok()),
+    ) else {
+        return acme_error(StatusCode::UNAUTHORIZED, "forbidden");
+    };
+
+    let Ok(req) = serde_json::from_slice::<AcmeUpdateRequest>(&body) else {
+        return acme_error(StatusCode::BAD_REQUEST, "malformed_json_payload");
+    };
+
+    match state
+        .dns
+        .update_acme_challenge
+        .execute(username, password, &req.subdomain, &req.txt, client_ip)
+        .await
+    {
+        Ok(txt) => {
+            debug!(subdomain = %req.subdomain, "ACME challenge published");
+            Json(AcmeUpdateResponse {
+                txt: txt.to_string(),
+            })
+            .into_response()
+        }
+        Err(e) => acme_domain_error(e),
+    }
+}
+
+async fn get_all_acme_registrations(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<AcmeRegistrationResponse>>, ApiError> {
+    let registrations = state.dns.get_acme_registrations.get_all().await?;
+    debug!(
+        count = registrations.len(),
+        "ACME registrations retrieved successfully"
+    );
+    Ok(Json(
+        registrations
+            .into_iter()
+            .map(AcmeRegistrationResponse::from_registration)
+            .collect(),
+    ))
+}
+
+async fn get_acme_registration_by_id(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<AcmeRegistrationResponse>, ApiError> {
+    let registration = state
+        .dns
+        .get_acme_registrations
+        .get_by_id(id)
+        .await?
+        .ok_or(ApiError(DomainError::AcmeRegistrationNotFound(id)))?;
+    Ok(Json(AcmeRegistrationResponse::from_registration(
+        registration,
+    )))
+}
+
+async fn delete_acme_registration(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, ApiError> {
+    state.dns.delete_acme_registration.execute(id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// acme-dns reports errors as `{"error": "<code>"}` with its own codes,
+/// which ACME client hooks match on.
+fn acme_domain_error(err: DomainError) -> Response {
+    match err {
+        DomainError::InvalidCredentials => acme_error(StatusCode::UNAUTHORIZED, "forbidden"),
+        DomainError::InvalidAcmeSubdomain => acme_error(StatusCode::BAD_REQUEST, "bad_subdomain"),
+        DomainError::InvalidAcmeTxt => acme_error(StatusCode::BAD_REQUEST, "bad_txt"),
+        DomainError::InvalidAcmeAllowFrom(_) => {
+            acme_error(StatusCode::BAD_REQUEST, "invalid_allowfrom_cidr")
+        }
+        other => ApiError(other).into_response(),
+    }
+}
+
+fn acme_error(status: StatusCode, code: &str) -> Response {
+    (status, Json(json!({ "error": code }))).into_response()
+}
@@ -1,9 +1,22 @@
-use axum::{extract::State, routing::get, Json, Router};
+use axum::{
+    extract::{Query, State},
+    routing::get,
+    Json, Router,
+};
+use ferrous_dns_application::use_cases::ExplainSubject;
+use ferrous_dns_domain::{DomainError, RecordType};
+use std::net::IpAddr;
 
-use crate::{dto::block_filter::BlockFilterStatsResponse, state::AppState};
+use crate::{
+    dto::block_filter::{BlockFilterStatsResponse, ExplainDecisionQuery, ExplainDecisionResponse},
+    errors::ApiError,
+    state::AppState,
+};
 
 pub fn routes() -> Router<AppState> {
-    Router::new().route("/block-filter/stats", get(get_block_filter_stats))
+    Router::new()
+        .route("/block-filter/stats", get(get_block_filter_stats))
+        .route("/block-filter/explain", get(explain_decision))
 }
 
 pub async fn get_block_filter_stats(
@@ -14,3 +27,31 @@ pub async fn get_block_filter_stats(
         total_blocked_domains: total,
     })
 }
+
+pub async fn explain_decision(
+    State(state): State<AppState>,
+    Query(params): Query<ExplainDecisionQuery>,
+) -> Result<Json<ExplainDecisionResponse>, ApiError> {
+    let subject = match (&params.client, params.group_id) {
+        (Some(client), _) => ExplainSubject::Client(
+            client
+                .parse::<IpAddr>()
+                .map_err(|_| ApiError(DomainError::InvalidIpAddress(client.clone())))?,
+        ),
+        (None, Some(group_id)) => ExplainSubject::Group(group_id),
+        (None, None) => ExplainSubject::Group(1),
+    };
+    let record_type = match &params.record_type {
+        Some(t) => t
+            .parse::<RecordType>()
+            .map_err(|e| ApiError(DomainError::InvalidInput(e)))?,
+        None => RecordType::A,
+    };
+
+    let explanation = state
+        .blocking
+        .explain_filter_decision
+        .execute(&params.domain, subject, record_type)
+        .await?;
+    Ok(Json(explanation.into()))
+}
@@ -1,5 +1,5 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::Json,
     routing::{delete, get, post, put},
@@ -9,7 +9,11 @@ use ferrous_dns_domain::DomainError;
 use tracing::debug;
 
 use crate::{
-    dto::{BlocklistSourceResponse, CreateBlocklistSourceRequest, UpdateBlocklistSourceRequest},
+    dto::{
+        BlocklistSourceHitsQuery, BlocklistSourceHitsResponse, BlocklistSourceResponse,
+        CreateBlocklistSourceRequest, SimulateBlocklistRequest, SimulationReportResponse,
+        UpdateBlocklistSourceRequest,
+    },
     errors::ApiError,
     state::AppState,
 };
@@ -18,6 +22,8 @@ pub fn routes() -> Router<AppState> {
     Router::new()
         .route("/blocklist-sources", get(get_all_blocklist_sources))
         .route("/blocklist-sources", post(create_blocklist_source))
+        .route("/blocklist-sources/hits", get(get_blocklist_source_hits))
+        .route("/blocklist-sources/simulate", post(simulate_blocklist))
         .route("/blocklist-sources/{id}", get(get_blocklist_source_by_id))
         .route("/blocklist-sources/{id}", put(update_blocklist_source))
         .route("/blocklist-sources/{id}", delete(delete_blocklist_source))
@@ -39,6 +45,36 @@ async fn get_all_blocklist_sources(
     ))
 }
 
+async fn get_blocklist_source_hits(
+    State(state): State<AppState>,
+    Query(params): Query<BlocklistSourceHitsQuery>,
+) -> Result<Json<Vec<BlocklistSourceHitsResponse>>, ApiError> {
+    let use_case = &state.blocking.get_blocklist_source_hits;
+    let hits = if params.zero_hits {
+        use_case.zero_hit_sources().await?
+    } else {
+        use_case.execute().await?
+    };
+    Ok(Json(
+        hits.into_iter()
+            .map(BlocklistSourceHitsResponse::from_hits)
+            .collect(),
+    ))
+}
+
+async fn simulate_blocklist(
+    State(state): State<AppState>,
+    Json(req): Json<SimulateBlocklistRequest>,
+) -> Result<Json<SimulationReportResponse>, ApiError> {
+    let simulation = req.into_simulation()?;
+    let report = state
+        .blocking
+        .simulate_blocklist
+        .execute(simulation)
+        .await?;
+    Ok(Json(SimulationReportResponse::from_report(report)))
+}
+
 async fn get_blocklist_source_by_id(
     State(state): State<AppState>,
     Path(id): Path<i64>,
@@ -63,11 +99,19 @@ async fn create_blocklist_source(
 ) -> Result<(StatusCode, Json<BlocklistSourceResponse>), ApiError> {
     let group_ids = req.resolved_group_ids(1);
     let enabled = req.enabled.unwrap_or(true);
+    let observe_only = req.observe_only.unwrap_or(false);
 
     let source = state
         .blocking
         .create_blocklist_source
-        .execute(req.name, req.url, group_ids, req.comment, enabled)
+        .execute(
+            req.name,
+            req.url,
+            group_ids,
+            req.comment,
+            enabled,
+            observe_only,
+        )
         .await?;
 
     Ok((
@@ -85,7 +129,15 @@ async fn update_blocklist_source(
     let source = state
         .blocking
         .update_blocklist_source
-        .execute(id, req.name, req.url, group_ids, req.comment, req.enabled)
+        .execute(
+            id,
+            req.name,
+            req.url,
+            group_ids,
+            req.comment,
+            req.enabled,
+            req.observe_only,
+        )
         .await?;
     Ok(Json(BlocklistSourceResponse::from_source(source)))
 }
@@ -73,5 +73,7 @@ pub async fn get_cache_metrics(State(state): State<AppState>) -> Json<CacheMetri
         batch_evictions: snapshot.batch_evictions,
         hit_rate: snapshot.hit_rate,
         transient_upstream_errors: snapshot.transient_upstream_errors,
+        aggressive_nxdomain_hits: snapshot.aggressive_nxdomain_hits,
+        aggressive_nodata_hits: snapshot.aggressive_nodata_hits,
     })
 }
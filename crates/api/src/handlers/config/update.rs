@@ -71,6 +71,8 @@ pub async fn update_config(
                         UpstreamStrategy::Failover
                     } else if p.strategy.eq_ignore_ascii_case("balanced") {
                         UpstreamStrategy::Balanced
+                    } else if p.strategy.eq_ignore_ascii_case("recursive") {
+                        UpstreamStrategy::Recursive
                     } else {
                         UpstreamStrategy::Parallel
                     };
@@ -0,0 +1,148 @@
+use std::net::{IpAddr, SocketAddr};
+
+use axum::{
+    extract::{ConnectInfo, Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Json, Response},
+    routing::get,
+    Extension, Router,
+};
+use base64::Engine;
+use ferrous_dns_domain::DdnsResult;
+use tracing::debug;
+
+use crate::{
+    dto::{DdnsHistoryQuery, DdnsUpdateQuery, DdnsUpdateResponse},
+    errors::ApiError,
+    state::AppState,
+    utils::client_ip,
+};
+
+/// Routes under the API prefix, behind the usual authentication.
+pub fn routes() -> Router<AppState> {
+    Router::new().route("/ddns/history", get(get_ddns_history))
+}
+
+/// The dyndns2 endpoint. Clients expect it at the server root and
+/// authenticate with HTTP Basic, so it sits outside the API router.
+pub fn update_routes() -> Router<AppState> {
+    Router::new().route("/nic/update", get(nic_update))
+}
+
+async fn nic_update(
+    State(state): State<AppState>,
+    connect_info: Option<Extension<ConnectInfo<SocketAddr>>>,
+    headers: HeaderMap,
+    Query(params): Query<DdnsUpdateQuery>,
+) -> Response {
+    let trust_proxy_headers = state.config.read().await.dns.ddns_trust_proxy_headers;
+    let client_ip = client_ip(&headers, connect_info, trust_proxy_headers);
+
+    // dyndns2 clients send junk such as "auto" or an empty value when they
+    // want the server to detect the address.
+    let requested: Vec<IpAddr> = params
+        .myip
+        .iter()
+        .flat_map(|ips| ips.split(','))
+        .chain(params.myipv6.as_deref())
+        .filter_map(|ip| ip.trim().parse().ok())
+        .collect();
+    let v4 = requested.iter().copied().find(IpAddr::is_ipv4);
+    let v6 = requested.iter().copied().find(IpAddr::is_ipv6);
+    let ips: Vec<IpAddr> = if requested.is_empty() {
+        client_ip.into_iter().collect()
+    } else {
+        v4.into_iter().chain(v6).collect()
+    };
+
+    let outcomes = state
+        .dns
+        .apply_ddns_update
+        .execute(
+            extract_token(&headers).as_deref(),
+            &params.hostname,
+            &ips,
+            client_ip,
+        )
+        .await;
+
+    let body = outcomes
+        .iter()
+        .map(|outcome| outcome.response_line())
+        .collect::<Vec<_>>()
+        .join("\n");
+    debug!(hostname = %params.hostname, response = %body, "DDNS update handled");
+
+    if outcomes
+        .iter()
+        .all(|outcome| outcome.result == DdnsResult::BadAuth)
+    {
+        return (
+            StatusCode::UNAUTHORIZED,
+            [
+                (header::WWW_AUTHENTICATE, "Basic realm=\"ferrous-dns\""),
+                (header::CONTENT_TYPE, "text/plain; charset=utf-8"),
+            ],
+            body,
+        )
+            .into_response();
+    }
+
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "text/plain; charset=utf-8")],
+        body,
+    )
+        .into_response()
+}
+
+async fn get_ddns_history(
+    State(state): State<AppState>,
+    Query(params): Query<DdnsHistoryQuery>,
+) -> Result<Json<Vec<DdnsUpdateResponse>>, ApiError> {
+    let updates = state
+        .dns
+        .get_ddns_history
+        .execute(params.hostname.as_deref(), params.limit)
+        .await?;
+    debug!(count = updates.len(), "DDNS history retrieved successfully");
+    Ok(Json(
+        updates
+            .into_iter()
+            .map(DdnsUpdateResponse::from_update)
+            .collect(),
+    ))
+}
+
+/// The API token from HTTP Basic credentials (the password, or the
+/// username when the password is empty) or the `X-Api-Key` header.
+fn extract_token(headers: &HeaderMap) -> Option<String> {
+    if let Some(credentials) = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Basic "))
+        .and_then(|v| {
+            base64::engine::general_purpose::STANDARD
+                .decode(v.trim())
+                .ok()
+        })
+        .and_then(|v| String::from_utf8(v).ok())
+    {
+        let (username, password) = credentials
+            .split_once(':')
+            .unwrap_or((credentials.as_str(), ""));
+        let token = if password.is_empty() {
+            username
+        } else {
+            password
+        };
+        if !token.is_empty() {
+            return Some(token.to_string());
+        }
+    }
+
+    headers
+        .get("X-Api-Key")
+        .and_then(|v| v.to_str().ok())
+        .map(String::from)
+}
@@ -0,0 +1,114 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    routing::{delete, get, post, put},
+    Router,
+};
+use ferrous_dns_domain::{DnsRewriteAnswer, DomainError};
+use tracing::debug;
+
+use crate::{
+    dto::{CreateDnsRewriteRuleRequest, DnsRewriteRuleResponse, UpdateDnsRewriteRuleRequest},
+    errors::ApiError,
+    state::AppState,
+};
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/dns-rewrites", get(get_all_dns_rewrites))
+        .route("/dns-rewrites", post(create_dns_rewrite))
+        .route("/dns-rewrites/{id}", get(get_dns_rewrite_by_id))
+        .route("/dns-rewrites/{id}", put(update_dns_rewrite))
+        .route("/dns-rewrites/{id}", delete(delete_dns_rewrite))
+}
+
+fn parse_rewrite(rewrite: &str) -> Result<DnsRewriteAnswer, ApiError> {
+    rewrite
+        .parse()
+        .map_err(|e| ApiError(DomainError::InvalidDnsRewriteRule(e)))
+}
+
+async fn get_all_dns_rewrites(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<DnsRewriteRuleResponse>>, ApiError> {
+    let rules = state.dns.get_dns_rewrite_rules.get_all().await?;
+    debug!(
+        count = rules.len(),
+        "DNS rewrite rules retrieved successfully"
+    );
+    Ok(Json(
+        rules
+            .into_iter()
+            .map(DnsRewriteRuleResponse::from_rule)
+            .collect(),
+    ))
+}
+
+async fn get_dns_rewrite_by_id(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<DnsRewriteRuleResponse>, ApiError> {
+    let rule = state
+        .dns
+        .get_dns_rewrite_rules
+        .get_by_id(id)
+        .await?
+        .ok_or(ApiError(DomainError::DnsRewriteRuleNotFound(id)))?;
+    Ok(Json(DnsRewriteRuleResponse::from_rule(rule)))
+}
+
+async fn create_dns_rewrite(
+    State(state): State<AppState>,
+    Json(req): Json<CreateDnsRewriteRuleRequest>,
+) -> Result<(StatusCode, Json<DnsRewriteRuleResponse>), ApiError> {
+    let answer = parse_rewrite(&req.rewrite)?;
+
+    let rule = state
+        .dns
+        .create_dns_rewrite_rule
+        .execute(
+            req.domain,
+            answer,
+            req.group_id.unwrap_or(1),
+            req.enabled.unwrap_or(true),
+            req.comment,
+        )
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(DnsRewriteRuleResponse::from_rule(rule)),
+    ))
+}
+
+async fn update_dns_rewrite(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(req): Json<UpdateDnsRewriteRuleRequest>,
+) -> Result<Json<DnsRewriteRuleResponse>, ApiError> {
+    let answer = req.rewrite.as_deref().map(parse_rewrite).transpose()?;
+
+    let rule = state
+        .dns
+        .update_dns_rewrite_rule
+        .execute(
+            id,
+            req.domain,
+            answer,
+            req.group_id,
+            req.enabled,
+            req.comment,
+        )
+        .await?;
+
+    Ok(Json(DnsRewriteRuleResponse::from_rule(rule)))
+}
+
+async fn delete_dns_rewrite(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, ApiError> {
+    state.dns.delete_dns_rewrite_rule.execute(id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,130 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    routing::{delete, get, post, put},
+    Router,
+};
+use ferrous_dns_domain::{DomainError, ForwardingTarget};
+use tracing::debug;
+
+use crate::{
+    dto::{CreateForwardingRuleRequest, ForwardingRuleResponse, UpdateForwardingRuleRequest},
+    errors::ApiError,
+    state::AppState,
+};
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/forwarding-rules", get(get_all_forwarding_rules))
+        .route("/forwarding-rules", post(create_forwarding_rule))
+        .route("/forwarding-rules/{id}", get(get_forwarding_rule_by_id))
+        .route("/forwarding-rules/{id}", put(update_forwarding_rule))
+        .route("/forwarding-rules/{id}", delete(delete_forwarding_rule))
+}
+
+/// Builds a target from the request fields, rejecting pools that are not
+/// configured under `[[dns.pools]]`.
+async fn parse_target(
+    state: &AppState,
+    target_type: &str,
+    target: &str,
+) -> Result<ForwardingTarget, ApiError> {
+    let parsed = ForwardingTarget::from_parts(target_type, target).ok_or_else(|| {
+        ApiError(DomainError::InvalidInput(format!(
+            "Invalid target_type '{}': must be 'pool' or 'server'",
+            target_type
+        )))
+    })?;
+
+    if let ForwardingTarget::Pool(ref name) = parsed {
+        let config = state.config.read().await;
+        if !config.dns.pools.iter().any(|p| p.name == name.as_ref()) {
+            return Err(ApiError(DomainError::InvalidForwardingRule(format!(
+                "Upstream pool '{}' is not configured",
+                name
+            ))));
+        }
+    }
+
+    Ok(parsed)
+}
+
+async fn get_all_forwarding_rules(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<ForwardingRuleResponse>>, ApiError> {
+    let rules = state.dns.get_forwarding_rules.get_all().await?;
+    debug!(
+        count = rules.len(),
+        "Forwarding rules retrieved successfully"
+    );
+    Ok(Json(
+        rules
+            .into_iter()
+            .map(ForwardingRuleResponse::from_rule)
+            .collect(),
+    ))
+}
+
+async fn get_forwarding_rule_by_id(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<ForwardingRuleResponse>, ApiError> {
+    let rule = state
+        .dns
+        .get_forwarding_rules
+        .get_by_id(id)
+        .await?
+        .ok_or(ApiError(DomainError::ForwardingRuleNotFound(id)))?;
+    Ok(Json(ForwardingRuleResponse::from_rule(rule)))
+}
+
+async fn create_forwarding_rule(
+    State(state): State<AppState>,
+    Json(req): Json<CreateForwardingRuleRequest>,
+) -> Result<(StatusCode, Json<ForwardingRuleResponse>), ApiError> {
+    let target = parse_target(&state, &req.target_type, &req.target).await?;
+
+    let rule = state
+        .dns
+        .create_forwarding_rule
+        .execute(req.domain, target, req.enabled.unwrap_or(true), req.comment)
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ForwardingRuleResponse::from_rule(rule)),
+    ))
+}
+
+async fn update_forwarding_rule(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(req): Json<UpdateForwardingRuleRequest>,
+) -> Result<Json<ForwardingRuleResponse>, ApiError> {
+    let target = match (req.target_type.as_deref(), req.target.as_deref()) {
+        (Some(target_type), Some(target)) => Some(parse_target(&state, target_type, target).await?),
+        (None, None) => None,
+        _ => {
+            return Err(ApiError(DomainError::InvalidInput(
+                "target_type and target must be provided together".to_string(),
+            )))
+        }
+    };
+
+    let rule = state
+        .dns
+        .update_forwarding_rule
+        .execute(id, req.domain, target, req.enabled, req.comment)
+        .await?;
+
+    Ok(Json(ForwardingRuleResponse::from_rule(rule)))
+}
+
+async fn delete_forwarding_rule(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, ApiError> {
+    state.dns.delete_forwarding_rule.execute(id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -8,7 +8,10 @@ use axum::{
 use tracing::debug;
 
 use crate::{
-    dto::{ClientResponse, CreateGroupRequest, GroupResponse, UpdateGroupRequest},
+    dto::{
+        ClientResponse, CreateGroupRequest, GroupResponse, UpdateGroupBlockingModeRequest,
+        UpdateGroupRequest, UpdateGroupUpstreamPoolsRequest,
+    },
     errors::ApiError,
     state::AppState,
 };
@@ -20,6 +23,14 @@ pub fn routes() -> Router<AppState> {
         .route("/groups/{id}", get(get_group_by_id))
         .route("/groups/{id}", put(update_group))
         .route("/groups/{id}", delete(delete_group))
+        .route(
+            "/groups/{id}/blocking-mode",
+            put(update_group_blocking_mode),
+        )
+        .route(
+            "/groups/{id}/upstream-pools",
+            put(update_group_upstream_pools),
+        )
         .route("/groups/{id}/clients", get(get_group_clients))
 }
 
@@ -99,6 +110,44 @@ async fn update_group(
     Ok(Json(GroupResponse::from_group(group, client_count)))
 }
 
+async fn update_group_blocking_mode(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(req): Json<UpdateGroupBlockingModeRequest>,
+) -> Result<Json<GroupResponse>, ApiError> {
+    let group = state
+        .groups
+        .update_group_blocking_mode
+        .execute(id, req.mode, req.ipv4, req.ipv6)
+        .await?;
+    let client_count = state
+        .groups
+        .get_groups
+        .count_clients_in_group(id)
+        .await
+        .ok();
+    Ok(Json(GroupResponse::from_group(group, client_count)))
+}
+
+async fn update_group_upstream_pools(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(req): Json<UpdateGroupUpstreamPoolsRequest>,
+) -> Result<Json<GroupResponse>, ApiError> {
+    let group = state
+        .groups
+        .update_group_upstream_pools
+        .execute(id, req.pools)
+        .await?;
+    let client_count = state
+        .groups
+        .get_groups
+        .count_clients_in_group(id)
+        .await
+        .ok();
+    Ok(Json(GroupResponse::from_group(group, client_count)))
+}
+
 async fn delete_group(
     State(state): State<AppState>,
     Path(id): Path<i64>,
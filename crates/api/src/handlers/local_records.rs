@@ -17,6 +17,16 @@ pub fn routes() -> Router<AppState> {
             "/local-records/{id}",
             put(update_record).delete(delete_record),
         )
+        .route("/local-records/zones", get(get_all_zones))
+        .route("/local-records/zones/{zone}", get(get_zone))
+        .route(
+            "/local-records/zones/{zone}/records",
+            post(create_zone_record),
+        )
+        .route(
+            "/local-records/zones/{zone}/records/{index}",
+            put(update_zone_record).delete(delete_zone_record),
+        )
 }
 
 async fn get_all_records(
@@ -105,3 +115,62 @@ async fn delete_record(
 
     Ok(StatusCode::NO_CONTENT)
 }
+
+async fn get_all_zones(State(state): State<AppState>) -> Result<Json<Vec<LocalZoneDto>>, ApiError> {
+    let zones = state.dns.get_local_zones.get_all().await?;
+    Ok(Json(zones.iter().map(LocalZoneDto::from_domain).collect()))
+}
+
+async fn get_zone(
+    State(state): State<AppState>,
+    Path(zone): Path<String>,
+) -> Result<Json<LocalZoneDto>, ApiError> {
+    let zone = state.dns.get_local_zones.get(&zone).await?;
+    Ok(Json(LocalZoneDto::from_domain(&zone)))
+}
+
+async fn create_zone_record(
+    State(state): State<AppState>,
+    Path(zone): Path<String>,
+    Json(req): Json<ZoneRecordRequest>,
+) -> Result<(StatusCode, Json<ZoneRecordDto>), ApiError> {
+    let (record, index) = state
+        .dns
+        .create_zone_record
+        .execute(&zone, &req.name, req.ttl, &req.record_type, &req.data)
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ZoneRecordDto::from_domain(&record, index)),
+    ))
+}
+
+async fn update_zone_record(
+    State(state): State<AppState>,
+    Path((zone, index)): Path<(String, usize)>,
+    Json(req): Json<ZoneRecordRequest>,
+) -> Result<Json<ZoneRecordDto>, ApiError> {
+    let (record, index) = state
+        .dns
+        .update_zone_record
+        .execute(
+            &zone,
+            index,
+            &req.name,
+            req.ttl,
+            &req.record_type,
+            &req.data,
+        )
+        .await?;
+
+    Ok(Json(ZoneRecordDto::from_domain(&record, index)))
+}
+
+async fn delete_zone_record(
+    State(state): State<AppState>,
+    Path((zone, index)): Path<(String, usize)>,
+) -> Result<StatusCode, ApiError> {
+    state.dns.delete_zone_record.execute(&zone, index).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
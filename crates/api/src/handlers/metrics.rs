@@ -0,0 +1,448 @@
+use crate::state::MetricsState;
+use axum::{
+    extract::State,
+    http::header,
+    response::{IntoResponse, Response},
+};
+use ferrous_dns_application::ports::UpstreamStatus;
+use std::fmt::{Display, Write};
+use tracing::instrument;
+
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Prometheus text exposition of the in-memory counters. Nothing here
+/// touches the database, so scraping is safe at any interval.
+#[instrument(skip(state), name = "api_get_metrics")]
+pub async fn get_metrics(State(state): State<MetricsState>) -> Response {
+    ([(header::CONTENT_TYPE, CONTENT_TYPE)], render(&state)).into_response()
+}
+
+fn render(state: &MetricsState) -> String {
+    let mut w = MetricsWriter::default();
+    write_queries(&mut w, state);
+    write_cache(&mut w, state);
+    write_upstreams(&mut w, state);
+    write_detectors(&mut w, state);
+    write_dnstap(&mut w, state);
+    write_jobs(&mut w, state);
+    w.out
+}
+
+fn write_queries(w: &mut MetricsWriter, state: &MetricsState) {
+    let dns = state.dns.snapshot();
+
+    w.header(
+        "ferrous_dns_queries_total",
+        "counter",
+        "Client queries answered, by outcome.",
+    );
+    for &(status, count) in &dns.results {
+        let result = status.to_ascii_lowercase();
+        w.sample("ferrous_dns_queries_total", &[("result", &result)], count);
+    }
+
+    w.header(
+        "ferrous_dns_queries_by_type_total",
+        "counter",
+        "Client queries answered, by record type.",
+    );
+    for &(record_type, count) in &dns.record_types {
+        w.sample(
+            "ferrous_dns_queries_by_type_total",
+            &[("type", record_type.as_str())],
+            count,
+        );
+    }
+
+    w.header(
+        "ferrous_dns_queries_received_total",
+        "counter",
+        "Client queries received, by transport.",
+    );
+    for &(transport, count) in &dns.transports {
+        w.sample(
+            "ferrous_dns_queries_received_total",
+            &[("protocol", transport.as_str())],
+            count,
+        );
+    }
+
+    w.header(
+        "ferrous_dns_query_duration_seconds",
+        "histogram",
+        "Time to answer a client query.",
+    );
+    for &(le_us, count) in &dns.latency_buckets {
+        let le = seconds(le_us).to_string();
+        w.sample(
+            "ferrous_dns_query_duration_seconds_bucket",
+            &[("le", &le)],
+            count,
+        );
+    }
+    w.sample(
+        "ferrous_dns_query_duration_seconds_bucket",
+        &[("le", "+Inf")],
+        dns.latency_count,
+    );
+    w.sample(
+        "ferrous_dns_query_duration_seconds_sum",
+        &[],
+        seconds(dns.latency_sum_us),
+    );
+    w.sample(
+        "ferrous_dns_query_duration_seconds_count",
+        &[],
+        dns.latency_count,
+    );
+
+    w.header(
+        "ferrous_dns_rate_limited_total",
+        "counter",
+        "Client queries dropped by the rate limiter, by action.",
+    );
+    for (status, action) in [
+        ("RATE_LIMITED", "refused"),
+        ("RATE_LIMITED_TC", "truncated"),
+    ] {
+        let count = dns
+            .results
+            .iter()
+            .find(|(s, _)| *s == status)
+            .map_or(0, |&(_, count)| count);
+        w.sample(
+            "ferrous_dns_rate_limited_total",
+            &[("action", action)],
+            count,
+        );
+    }
+
+    w.header(
+        "ferrous_dns_query_log_dropped_total",
+        "counter",
+        "Query log entries discarded because the write channel was full.",
+    );
+    w.sample(
+        "ferrous_dns_query_log_dropped_total",
+        &[],
+        state.query_log.dropped_entries(),
+    );
+}
+
+fn write_cache(w: &mut MetricsWriter, state: &MetricsState) {
+    let cache = state.cache.cache_metrics_snapshot();
+
+    w.header(
+        "ferrous_dns_cache_entries",
+        "gauge",
+        "Records currently held in the DNS cache.",
+    );
+    w.sample("ferrous_dns_cache_entries", &[], cache.total_entries);
+
+    let counters = [
+        ("hits", "Cache lookups answered from the cache.", cache.hits),
+        ("misses", "Cache lookups that missed.", cache.misses),
+        (
+            "insertions",
+            "Records inserted into the cache.",
+            cache.insertions,
+        ),
+        (
+            "evictions",
+            "Records evicted from the cache.",
+            cache.evictions,
+        ),
+        (
+            "optimistic_refreshes",
+            "Records refreshed ahead of expiry.",
+            cache.optimistic_refreshes,
+        ),
+        (
+            "stale_hits",
+            "Lookups answered with an expired record.",
+            cache.stale_hits,
+        ),
+        (
+            "lazy_deletions",
+            "Expired records removed on lookup.",
+            cache.lazy_deletions,
+        ),
+        (
+            "compactions",
+            "Compaction cycles run on the cache.",
+            cache.compactions,
+        ),
+        (
+            "batch_evictions",
+            "Batch eviction passes run on the cache.",
+            cache.batch_evictions,
+        ),
+        (
+            "transient_upstream_errors",
+            "Upstream failures that were not cached as negative answers.",
+            cache.transient_upstream_errors,
+        ),
+    ];
+    for (name, help, value) in counters {
+        let name = format!("ferrous_dns_cache_{name}_total");
+        w.header(&name, "counter", help);
+        w.sample(&name, &[], value);
+    }
+
+    w.header(
+        "ferrous_dns_cache_aggressive_nsec_hits_total",
+        "counter",
+        "Negative answers synthesised from cached NSEC/NSEC3 ranges, by kind.",
+    );
+    w.sample(
+        "ferrous_dns_cache_aggressive_nsec_hits_total",
+        &[("kind", "nxdomain")],
+        cache.aggressive_nxdomain_hits,
+    );
+    w.sample(
+        "ferrous_dns_cache_aggressive_nsec_hits_total",
+        &[("kind", "nodata")],
+        cache.aggressive_nodata_hits,
+    );
+}
+
+fn write_upstreams(w: &mut MetricsWriter, state: &MetricsState) {
+    let groups = state.upstream_health.get_grouped_upstream_health();
+
+    w.header(
+        "ferrous_dns_upstream_up",
+        "gauge",
+        "Whether an upstream endpoint passed its last health check.",
+    );
+    for group in &groups {
+        for endpoint in &group.resolved {
+            let up = match endpoint.status {
+                UpstreamStatus::Healthy => 1,
+                UpstreamStatus::Unhealthy => 0,
+                UpstreamStatus::Unknown => continue,
+            };
+            w.sample(
+                "ferrous_dns_upstream_up",
+                &upstream_labels(&group.pool_name, &group.address, &endpoint.address),
+                up,
+            );
+        }
+    }
+
+    w.header(
+        "ferrous_dns_upstream_latency_seconds",
+        "gauge",
+        "Latency of an upstream endpoint's last health check.",
+    );
+    for group in &groups {
+        for endpoint in &group.resolved {
+            if let Some(latency_ms) = endpoint.latency_ms {
+                w.sample(
+                    "ferrous_dns_upstream_latency_seconds",
+                    &upstream_labels(&group.pool_name, &group.address, &endpoint.address),
+                    latency_ms as f64 / 1_000.0,
+                );
+            }
+        }
+    }
+
+    w.header(
+        "ferrous_dns_upstream_consecutive_failures",
+        "gauge",
+        "Health checks an upstream endpoint has failed in a row.",
+    );
+    for group in &groups {
+        for endpoint in &group.resolved {
+            w.sample(
+                "ferrous_dns_upstream_consecutive_failures",
+                &upstream_labels(&group.pool_name, &group.address, &endpoint.address),
+                endpoint.consecutive_failures,
+            );
+        }
+    }
+}
+
+fn upstream_labels<'a>(
+    pool: &'a str,
+    upstream: &'a str,
+    endpoint: &'a str,
+) -> [(&'a str, &'a str); 3] {
+    [
+        ("pool", pool),
+        ("upstream", upstream),
+        ("endpoint", endpoint),
+    ]
+}
+
+fn write_detectors(w: &mut MetricsWriter, state: &MetricsState) {
+    if let Some(tunneling) = &state.tunneling {
+        w.header(
+            "ferrous_dns_tunneling_flagged_domains",
+            "gauge",
+            "Domains currently flagged as DNS tunneling endpoints.",
+        );
+        w.sample(
+            "ferrous_dns_tunneling_flagged_domains",
+            &[],
+            tunneling.flagged_count(),
+        );
+    }
+
+    if let Some(dga) = &state.dga {
+        w.header(
+            "ferrous_dns_dga_flagged_domains",
+            "gauge",
+            "Domains currently flagged as algorithmically generated.",
+        );
+        w.sample("ferrous_dns_dga_flagged_domains", &[], dga.flagged_count());
+    }
+
+    if let Some(hijack) = &state.nxdomain_hijack {
+        w.header(
+            "ferrous_dns_nxdomain_hijack_ips",
+            "gauge",
+            "Addresses currently known to answer hijacked NXDOMAIN responses.",
+        );
+        w.sample(
+            "ferrous_dns_nxdomain_hijack_ips",
+            &[],
+            hijack.hijack_ip_count(),
+        );
+        w.header(
+            "ferrous_dns_nxdomain_hijacking_upstreams",
+            "gauge",
+            "Upstreams currently detected as hijacking NXDOMAIN responses.",
+        );
+        w.sample(
+            "ferrous_dns_nxdomain_hijacking_upstreams",
+            &[],
+            hijack.hijacking_upstream_count(),
+        );
+    }
+
+    if let Some(response_ip_filter) = &state.response_ip_filter {
+        w.header(
+            "ferrous_dns_response_ip_filter_blocked_ips",
+            "gauge",
+            "Command-and-control addresses currently loaded from threat feeds.",
+        );
+        w.sample(
+            "ferrous_dns_response_ip_filter_blocked_ips",
+            &[],
+            response_ip_filter.blocked_ip_count(),
+        );
+    }
+}
+
+fn write_dnstap(w: &mut MetricsWriter, state: &MetricsState) {
+    let Some(dnstap) = &state.dnstap else {
+        return;
+    };
+    w.header(
+        "ferrous_dns_dnstap_messages_total",
+        "counter",
+        "dnstap messages, by whether they were written or dropped.",
+    );
+    w.sample(
+        "ferrous_dns_dnstap_messages_total",
+        &[("outcome", "written")],
+        dnstap.written_messages(),
+    );
+    w.sample(
+        "ferrous_dns_dnstap_messages_total",
+        &[("outcome", "dropped")],
+        dnstap.dropped_messages(),
+    );
+}
+
+fn write_jobs(w: &mut MetricsWriter, state: &MetricsState) {
+    let jobs = state.jobs.snapshot();
+
+    w.header(
+        "ferrous_dns_job_runs_total",
+        "counter",
+        "Completed runs of a background job.",
+    );
+    for (job, stats) in &jobs {
+        w.sample("ferrous_dns_job_runs_total", &[("job", job)], stats.runs);
+    }
+
+    w.header(
+        "ferrous_dns_job_duration_seconds_total",
+        "counter",
+        "Total time spent running a background job.",
+    );
+    for (job, stats) in &jobs {
+        w.sample(
+            "ferrous_dns_job_duration_seconds_total",
+            &[("job", job)],
+            stats.total_duration.as_secs_f64(),
+        );
+    }
+
+    w.header(
+        "ferrous_dns_job_last_duration_seconds",
+        "gauge",
+        "Duration of the last run of a background job.",
+    );
+    for (job, stats) in &jobs {
+        w.sample(
+            "ferrous_dns_job_last_duration_seconds",
+            &[("job", job)],
+            stats.last_duration.as_secs_f64(),
+        );
+    }
+
+    w.header(
+        "ferrous_dns_job_last_run_timestamp_seconds",
+        "gauge",
+        "Unix time the last run of a background job finished.",
+    );
+    for (job, stats) in &jobs {
+        w.sample(
+            "ferrous_dns_job_last_run_timestamp_seconds",
+            &[("job", job)],
+            stats.last_run_secs,
+        );
+    }
+}
+
+fn seconds(us: u64) -> f64 {
+    us as f64 / 1_000_000.0
+}
+
+#[derive(Default)]
+struct MetricsWriter {
+    out: String,
+}
+
+impl MetricsWriter {
+    fn header(&mut self, name: &str, kind: &str, help: &str) {
+        let _ = writeln!(self.out, "# HELP {name} {help}");
+        let _ = writeln!(self.out, "# TYPE {name} {kind}");
+    }
+
+    fn sample(&mut self, name: &str, labels: &[(&str, &str)], value: impl Display) {
+        self.out.push_str(name);
+        if !labels.is_empty() {
+            self.out.push('{');
+            for (i, (key, val)) in labels.iter().enumerate() {
+                if i > 0 {
+                    self.out.push(',');
+                }
+                let _ = write!(self.out, "{key}=\"");
+                for c in val.chars() {
+                    match c {
+                        '\\' => self.out.push_str("\\\\"),
+                        '"' => self.out.push_str("\\\""),
+                        '\n' => self.out.push_str("\\n"),
+                        c => self.out.push(c),
+                    }
+                }
+                self.out.push('"');
+            }
+            self.out.push('}');
+        }
+        let _ = writeln!(self.out, " {value}");
+    }
+}
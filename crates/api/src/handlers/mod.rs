@@ -1,3 +1,4 @@
+pub mod acme_dns;
 pub mod api_tokens;
 pub mod auth;
 pub mod backup;
@@ -12,19 +13,27 @@ pub mod clients;
 pub mod config;
 pub mod custom_services;
 pub mod dashboard;
+pub mod ddns;
+pub mod dns_rewrites;
+pub mod forwarding_rules;
 pub mod groups;
 pub mod health;
 pub mod hostname;
 pub mod local_records;
 pub mod managed_domains;
 pub mod manual_clients;
+pub mod metrics;
+pub mod negative_trust_anchors;
 pub mod queries;
+pub mod query_stream;
 pub mod rate;
 pub mod regex_filters;
 pub mod stats;
 pub mod system_info;
 pub mod timeline;
 pub mod tls;
+pub mod trust_anchors;
+pub mod tsig_keys;
 pub mod whitelist;
 pub mod whitelist_sources;
 
@@ -38,6 +47,7 @@ pub use health::health_check;
 pub use hostname::get_hostname;
 pub use manual_clients::{create_manual_client, delete_manual_client, update_manual_client};
 pub use queries::get_queries;
+pub use query_stream::stream_queries;
 pub use rate::get_query_rate;
 pub use stats::get_stats;
 pub use system_info::get_system_info;
@@ -0,0 +1,88 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    routing::{delete, get, post},
+    Router,
+};
+use ferrous_dns_domain::DomainError;
+use tracing::debug;
+
+use crate::{
+    dto::{CreateNegativeTrustAnchorRequest, NegativeTrustAnchorResponse},
+    errors::ApiError,
+    state::AppState,
+};
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/dnssec/negative-trust-anchors",
+            get(get_all_negative_trust_anchors),
+        )
+        .route(
+            "/dnssec/negative-trust-anchors",
+            post(create_negative_trust_anchor),
+        )
+        .route(
+            "/dnssec/negative-trust-anchors/{id}",
+            get(get_negative_trust_anchor_by_id),
+        )
+        .route(
+            "/dnssec/negative-trust-anchors/{id}",
+            delete(delete_negative_trust_anchor),
+        )
+}
+
+async fn get_all_negative_trust_anchors(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<NegativeTrustAnchorResponse>>, ApiError> {
+    let anchors = state.dns.get_negative_trust_anchors.get_all().await?;
+    debug!(
+        count = anchors.len(),
+        "Negative trust anchors retrieved successfully"
+    );
+    Ok(Json(
+        anchors
+            .into_iter()
+            .map(NegativeTrustAnchorResponse::from_anchor)
+            .collect(),
+    ))
+}
+
+async fn get_negative_trust_anchor_by_id(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<NegativeTrustAnchorResponse>, ApiError> {
+    let anchor = state
+        .dns
+        .get_negative_trust_anchors
+        .get_by_id(id)
+        .await?
+        .ok_or(ApiError(DomainError::NegativeTrustAnchorNotFound(id)))?;
+    Ok(Json(NegativeTrustAnchorResponse::from_anchor(anchor)))
+}
+
+async fn create_negative_trust_anchor(
+    State(state): State<AppState>,
+    Json(req): Json<CreateNegativeTrustAnchorRequest>,
+) -> Result<(StatusCode, Json<NegativeTrustAnchorResponse>), ApiError> {
+    let anchor = state
+        .dns
+        .create_negative_trust_anchor
+        .execute(req.domain, req.duration_secs, req.comment)
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(NegativeTrustAnchorResponse::from_anchor(anchor)),
+    ))
+}
+
+async fn delete_negative_trust_anchor(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, ApiError> {
+    state.dns.delete_negative_trust_anchor.execute(id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -47,27 +47,7 @@ pub async fn get_queries(
 
     let result = state.query.get_queries.execute_paged(&input).await?;
 
-    let data: Vec<QueryResponse> = result
-        .queries
-        .into_iter()
-        .map(|q| QueryResponse {
-            timestamp: q.timestamp.unwrap_or_default(),
-            domain: q.domain,
-            client: q.client_ip.to_string(),
-            client_hostname: q.client_hostname,
-            record_type: q.record_type.as_str(),
-            blocked: q.blocked,
-            response_time_us: q.response_time_us,
-            cache_hit: q.cache_hit,
-            cache_refresh: q.cache_refresh,
-            dnssec_status: q.dnssec_status,
-            upstream_server: q.upstream_server,
-            upstream_pool: q.upstream_pool,
-            query_source: q.query_source.as_str(),
-            block_source: q.block_source.map(|s| s.to_str()),
-            response_status: q.response_status,
-        })
-        .collect();
+    let data: Vec<QueryResponse> = result.queries.iter().map(QueryResponse::from).collect();
 
     debug!(
         count = data.len(),
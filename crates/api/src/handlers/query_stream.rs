@@ -0,0 +1,71 @@
+use crate::{
+    dto::{QueryResponse, QueryStreamParams},
+    errors::ApiError,
+    state::AppState,
+};
+use axum::{
+    extract::{Query, State},
+    response::sse::{Event, KeepAlive, Sse},
+};
+use ferrous_dns_application::services::QueryStreamFilter;
+use ferrous_dns_domain::DomainError;
+use futures::stream::{self, Stream};
+use serde_json::json;
+use std::convert::Infallible;
+use std::net::IpAddr;
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{debug, instrument};
+
+/// Streams logged queries as server-sent events.
+///
+/// Each matching entry is sent as a `query` event carrying the same JSON as
+/// an item of `GET /queries`. A subscriber that cannot keep up skips the
+/// entries it missed and receives a `lagged` event with the number skipped.
+#[instrument(skip(state), name = "api_stream_queries")]
+pub async fn stream_queries(
+    State(state): State<AppState>,
+    Query(params): Query<QueryStreamParams>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, ApiError> {
+    let client = params
+        .client
+        .as_deref()
+        .map(|ip| {
+            ip.parse::<IpAddr>()
+                .map_err(|_| DomainError::InvalidInput(format!("Invalid client IP: {ip}")))
+        })
+        .transpose()?;
+    let filter = QueryStreamFilter {
+        client,
+        domain: params
+            .domain
+            .filter(|d| !d.is_empty())
+            .map(|d| d.to_ascii_lowercase()),
+        blocked_only: params.blocked,
+    };
+
+    let receiver = state.query.query_stream.subscribe();
+    debug!(
+        subscribers = state.query.query_stream.subscriber_count(),
+        "Live query subscriber connected"
+    );
+
+    let events = stream::unfold((receiver, filter), |(mut receiver, filter)| async move {
+        loop {
+            let event = match receiver.recv().await {
+                Ok(entry) if filter.matches(&entry) => {
+                    let body = QueryResponse::from(entry.as_ref());
+                    Event::default().event("query").json_data(&body).ok()?
+                }
+                Ok(_) => continue,
+                Err(RecvError::Lagged(skipped)) => Event::default()
+                    .event("lagged")
+                    .json_data(json!({ "skipped": skipped }))
+                    .ok()?,
+                Err(RecvError::Closed) => return None,
+            };
+            return Some((Ok(event), (receiver, filter)));
+        }
+    });
+
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
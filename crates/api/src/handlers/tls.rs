@@ -11,7 +11,8 @@ use ferrous_dns_domain::DomainError;
 use std::path::Path;
 use tracing::info;
 
-/// GET /tls/status — returns certificate status information.
+/// GET /tls/status — returns certificate status information, including the
+/// fingerprint of the certificate currently served.
 pub async fn get_tls_status(State(state): State<AppState>) -> Json<TlsStatusResponse> {
     let config = state.config.read().await;
     let web_tls = &config.server.web_tls;
@@ -21,17 +22,29 @@ pub async fn get_tls_status(State(state): State<AppState>) -> Json<TlsStatusResp
         .get_status(&web_tls.tls_cert_path, &web_tls.tls_key_path)
         .await;
 
+    let active_fingerprint = state
+        .tls_reloader
+        .active_fingerprint(&web_tls.tls_cert_path, &web_tls.tls_key_path);
+
     Json(TlsStatusResponse {
         enabled: web_tls.enabled,
         cert_exists: status.cert_exists,
         key_exists: status.key_exists,
         cert_subject: status.cert_subject,
         cert_not_after: status.cert_not_after,
+        cert_expires_at: status.cert_not_after_unix,
         cert_valid: status.cert_valid,
+        cert_dns_names: status.cert_dns_names,
+        cert_fingerprint_sha256: status.cert_fingerprint_sha256,
+        active_fingerprint_sha256: active_fingerprint,
     })
 }
 
 /// POST /tls/upload — receives multipart with `cert` and `key` PEM files.
+///
+/// A key that does not belong to the certificate is rejected before
+/// anything is written. Listeners serving the files switch to the new
+/// certificate for new handshakes.
 pub async fn upload_tls_certs(
     State(state): State<AppState>,
     mut multipart: Multipart,
@@ -78,12 +91,14 @@ pub async fn upload_tls_certs(
         .save_certificates(&cert_bytes, &key_bytes, &cert_path, &key_path)
         .await?;
 
-    info!("TLS certificate and key uploaded successfully");
+    let reloaded = state.tls_reloader.reload(&cert_path, &key_path)?;
+
+    info!(reloaded, "TLS certificate and key uploaded successfully");
 
     Ok(Json(TlsUploadResponse {
         success: true,
         message: "Certificate and key uploaded successfully".to_string(),
-        restart_required: true,
+        restart_required: !reloaded,
     }))
 }
 
@@ -108,11 +123,16 @@ pub async fn generate_self_signed(
         .generate_self_signed(&cert_path, &key_path)
         .await?;
 
-    info!("Self-signed TLS certificate generated successfully");
+    let reloaded = state.tls_reloader.reload(&cert_path, &key_path)?;
+
+    info!(
+        reloaded,
+        "Self-signed TLS certificate generated successfully"
+    );
 
     Ok(Json(TlsUploadResponse {
         success: true,
         message: "Self-signed certificate generated successfully".to_string(),
-        restart_required: true,
+        restart_required: !reloaded,
     }))
 }
@@ -0,0 +1,24 @@
+use axum::{extract::State, response::Json, routing::get, Router};
+use tracing::debug;
+
+use crate::{dto::TrustAnchorResponse, errors::ApiError, state::AppState};
+
+pub fn routes() -> Router<AppState> {
+    Router::new().route("/dnssec/trust-anchors", get(get_trust_anchors))
+}
+
+async fn get_trust_anchors(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<TrustAnchorResponse>>, ApiError> {
+    let anchors = state.dns.get_trust_anchors.get_all().await?;
+    debug!(
+        count = anchors.len(),
+        "Trust anchors retrieved successfully"
+    );
+    Ok(Json(
+        anchors
+            .into_iter()
+            .map(TrustAnchorResponse::from_anchor)
+            .collect(),
+    ))
+}
@@ -0,0 +1,70 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    routing::{delete, get, post},
+    Router,
+};
+use ferrous_dns_domain::DomainError;
+use tracing::debug;
+
+use crate::{
+    dto::{CreateTsigKeyRequest, CreatedTsigKeyResponse, TsigKeyResponse},
+    errors::ApiError,
+    state::AppState,
+};
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/tsig-keys", get(get_all_tsig_keys))
+        .route("/tsig-keys", post(create_tsig_key))
+        .route("/tsig-keys/{id}", get(get_tsig_key_by_id))
+        .route("/tsig-keys/{id}", delete(delete_tsig_key))
+}
+
+async fn get_all_tsig_keys(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<TsigKeyResponse>>, ApiError> {
+    let keys = state.dns.get_tsig_keys.get_all().await?;
+    debug!(count = keys.len(), "TSIG keys retrieved successfully");
+    Ok(Json(
+        keys.into_iter().map(TsigKeyResponse::from_key).collect(),
+    ))
+}
+
+async fn get_tsig_key_by_id(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<TsigKeyResponse>, ApiError> {
+    let key = state
+        .dns
+        .get_tsig_keys
+        .get_by_id(id)
+        .await?
+        .ok_or(ApiError(DomainError::TsigKeyNotFound(id)))?;
+    Ok(Json(TsigKeyResponse::from_key(key)))
+}
+
+async fn create_tsig_key(
+    State(state): State<AppState>,
+    Json(req): Json<CreateTsigKeyRequest>,
+) -> Result<(StatusCode, Json<CreatedTsigKeyResponse>), ApiError> {
+    let key = state
+        .dns
+        .create_tsig_key
+        .execute(req.name, req.algorithm, req.secret)
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(CreatedTsigKeyResponse::from_key(key)),
+    ))
+}
+
+async fn delete_tsig_key(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, ApiError> {
+    state.dns.delete_tsig_key.execute(id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -69,6 +69,7 @@ pub async fn get_upstream_health_detail(
                 UpstreamStrategy::Parallel => "Parallel",
                 UpstreamStrategy::Failover => "Failover",
                 UpstreamStrategy::Balanced => "Balanced",
+                UpstreamStrategy::Recursive => "Recursive",
             }
             .to_string(),
             resolved: g
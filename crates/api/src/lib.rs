@@ -7,8 +7,11 @@ pub mod state;
 pub mod utils;
 
 pub use errors::ApiError;
-pub use routes::create_api_routes;
+pub use routes::{
+    create_acme_dns_routes, create_api_routes, create_ddns_routes, create_metrics_routes,
+};
 pub use state::{
     AppState, AuthUseCases, BackupUseCases, BlockingUseCases, ClientUseCases, DnsUseCases,
-    GroupUseCases, QueryUseCases, SafeSearchUseCases, ScheduleUseCases, ServiceUseCases,
+    GroupUseCases, MetricsState, QueryUseCases, SafeSearchUseCases, ScheduleUseCases,
+    ServiceUseCases,
 };
@@ -1,4 +1,6 @@
 pub mod api_key;
 pub mod require_auth;
+pub mod require_metrics_token;
 
 pub use require_auth::require_auth;
+pub use require_metrics_token::require_metrics_token;
@@ -0,0 +1,36 @@
+use crate::state::MetricsState;
+use axum::{
+    extract::{Request, State},
+    http::{header, StatusCode},
+    middleware::Next,
+    response::Response,
+};
+use subtle::ConstantTimeEq;
+
+/// Middleware guarding `/metrics` with the configured bearer token.
+///
+/// Scrapes pass through untouched when no token is configured. The check
+/// is a constant-time comparison against the config value, so a scrape
+/// never reaches the session or API token stores.
+pub async fn require_metrics_token(
+    State(state): State<MetricsState>,
+    request: Request,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    let Some(expected) = state.bearer_token.as_deref() else {
+        return Ok(next.run(request).await);
+    };
+
+    let presented = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "));
+
+    match presented {
+        Some(token) if bool::from(token.as_bytes().ct_eq(expected.as_bytes())) => {
+            Ok(next.run(request).await)
+        }
+        _ => Err(StatusCode::UNAUTHORIZED),
+    }
+}
@@ -1,6 +1,6 @@
 use crate::handlers;
-use crate::middleware::require_auth;
-use crate::state::AppState;
+use crate::middleware::{require_auth, require_metrics_token};
+use crate::state::{AppState, MetricsState};
 use axum::{
     middleware,
     routing::{delete, get, patch, post, put},
@@ -21,6 +21,7 @@ pub fn create_api_routes(state: AppState) -> Router {
         .route("/stats/rate", get(handlers::get_query_rate))
         .route("/queries/timeline", get(handlers::get_timeline))
         .route("/queries", get(handlers::get_queries))
+        .route("/queries/stream", get(handlers::stream_queries))
         .route("/blocklist", get(handlers::get_blocklist))
         .route("/whitelist", get(handlers::get_whitelist))
         .route("/cache/stats", get(handlers::get_cache_stats))
@@ -46,6 +47,13 @@ pub fn create_api_routes(state: AppState) -> Router {
         .route("/settings", get(handlers::get_settings))
         .route("/settings", post(handlers::update_settings))
         .merge(handlers::local_records::routes())
+        .merge(handlers::forwarding_rules::routes())
+        .merge(handlers::dns_rewrites::routes())
+        .merge(handlers::trust_anchors::routes())
+        .merge(handlers::negative_trust_anchors::routes())
+        .merge(handlers::tsig_keys::routes())
+        .merge(handlers::ddns::routes())
+        .merge(handlers::acme_dns::routes())
         .merge(handlers::block_filter::routes())
         .merge(handlers::safe_search::routes())
         .merge(handlers::schedule_profiles::routes())
@@ -72,3 +80,25 @@ pub fn create_api_routes(state: AppState) -> Router {
         .merge(protected_routes)
         .with_state(state)
 }
+
+/// Routes for the dyndns2 `/nic/update` endpoint, mounted at the server root.
+pub fn create_ddns_routes(state: AppState) -> Router {
+    handlers::ddns::update_routes().with_state(state)
+}
+
+/// Routes for the acme-dns `/register` and `/update` endpoints, mounted at
+/// the server root when `acme_dns` is enabled.
+pub fn create_acme_dns_routes(state: AppState) -> Router {
+    handlers::acme_dns::acme_dns_routes().with_state(state)
+}
+
+/// Routes for the Prometheus `/metrics` endpoint.
+pub fn create_metrics_routes(state: MetricsState) -> Router {
+    Router::new()
+        .route("/metrics", get(handlers::metrics::get_metrics))
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            require_metrics_token,
+        ))
+        .with_state(state)
+}
@@ -1,31 +1,44 @@
 use ferrous_dns_application::ports::{
-    ConfigFilePersistence, DnsCachePort, TlsCertificatePort, UpstreamHealthPort,
+    ConfigFilePersistence, DgaEvictionTarget, DnsCachePort, DnstapSink, NxdomainHijackProbeTarget,
+    QueryLogRepository, ResponseIpFilterEvictionTarget, TlsCertificatePort, TlsCertificateReloader,
+    TunnelingEvictionTarget, UpstreamHealthPort,
+};
+use ferrous_dns_application::services::{
+    DnsMetrics, JobMetrics, QueryStream, SubnetMatcherService,
 };
-use ferrous_dns_application::services::SubnetMatcherService;
 use ferrous_dns_application::use_cases::{
-    AssignClientGroupUseCase, AssignScheduleProfileUseCase, BlockServiceUseCase,
-    ChangePasswordUseCase, CreateApiTokenUseCase, CreateBlocklistSourceUseCase,
-    CreateClientSubnetUseCase, CreateCustomServiceUseCase, CreateGroupUseCase,
+    ApplyDdnsUpdateUseCase, AssignClientGroupUseCase, AssignScheduleProfileUseCase,
+    BlockServiceUseCase, ChangePasswordUseCase, CreateApiTokenUseCase,
+    CreateBlocklistSourceUseCase, CreateClientSubnetUseCase, CreateCustomServiceUseCase,
+    CreateDnsRewriteRuleUseCase, CreateForwardingRuleUseCase, CreateGroupUseCase,
     CreateLocalRecordUseCase, CreateManagedDomainUseCase, CreateManualClientUseCase,
-    CreateRegexFilterUseCase, CreateScheduleProfileUseCase, CreateUserUseCase,
-    CreateWhitelistSourceUseCase, DeleteApiTokenUseCase, DeleteBlocklistSourceUseCase,
-    DeleteClientSubnetUseCase, DeleteClientUseCase, DeleteCustomServiceUseCase, DeleteGroupUseCase,
-    DeleteLocalRecordUseCase, DeleteManagedDomainUseCase, DeleteRegexFilterUseCase,
-    DeleteSafeSearchConfigsUseCase, DeleteScheduleProfileUseCase, DeleteUserUseCase,
-    DeleteWhitelistSourceUseCase, ExportConfigUseCase, GetActiveSessionsUseCase,
-    GetApiTokensUseCase, GetAuthStatusUseCase, GetBlockFilterStatsUseCase,
-    GetBlockedServicesUseCase, GetBlocklistSourcesUseCase, GetBlocklistUseCase,
-    GetCacheStatsUseCase, GetClientSubnetsUseCase, GetClientsUseCase, GetCustomServicesUseCase,
-    GetGroupsUseCase, GetManagedDomainsUseCase, GetQueryRateUseCase, GetQueryStatsUseCase,
+    CreateNegativeTrustAnchorUseCase, CreateRegexFilterUseCase, CreateScheduleProfileUseCase,
+    CreateTsigKeyUseCase, CreateUserUseCase, CreateWhitelistSourceUseCase, CreateZoneRecordUseCase,
+    DeleteAcmeRegistrationUseCase, DeleteApiTokenUseCase, DeleteBlocklistSourceUseCase,
+    DeleteClientSubnetUseCase, DeleteClientUseCase, DeleteCustomServiceUseCase,
+    DeleteDnsRewriteRuleUseCase, DeleteForwardingRuleUseCase, DeleteGroupUseCase,
+    DeleteLocalRecordUseCase, DeleteManagedDomainUseCase, DeleteNegativeTrustAnchorUseCase,
+    DeleteRegexFilterUseCase, DeleteSafeSearchConfigsUseCase, DeleteScheduleProfileUseCase,
+    DeleteTsigKeyUseCase, DeleteUserUseCase, DeleteWhitelistSourceUseCase, DeleteZoneRecordUseCase,
+    ExplainFilterDecisionUseCase, ExportConfigUseCase, GetAcmeRegistrationsUseCase,
+    GetActiveSessionsUseCase, GetApiTokensUseCase, GetAuthStatusUseCase,
+    GetBlockFilterStatsUseCase, GetBlockedServicesUseCase, GetBlocklistSourceHitsUseCase,
+    GetBlocklistSourcesUseCase, GetBlocklistUseCase, GetCacheStatsUseCase, GetClientSubnetsUseCase,
+    GetClientsUseCase, GetCustomServicesUseCase, GetDdnsHistoryUseCase, GetDnsRewriteRulesUseCase,
+    GetForwardingRulesUseCase, GetGroupsUseCase, GetLocalZonesUseCase, GetManagedDomainsUseCase,
+    GetNegativeTrustAnchorsUseCase, GetQueryRateUseCase, GetQueryStatsUseCase,
     GetRecentQueriesUseCase, GetRegexFiltersUseCase, GetSafeSearchConfigsUseCase,
     GetScheduleProfilesUseCase, GetServiceCatalogUseCase, GetTimelineUseCase,
-    GetTopBlockedDomainsUseCase, GetTopClientsUseCase, GetUsersUseCase, GetWhitelistSourcesUseCase,
-    GetWhitelistUseCase, ImportConfigUseCase, LoginUseCase, LogoutUseCase, ManageTimeSlotsUseCase,
-    SetupPasswordUseCase, ToggleSafeSearchUseCase, UnblockServiceUseCase, UpdateApiTokenUseCase,
-    UpdateBlocklistSourceUseCase, UpdateClientUseCase, UpdateCustomServiceUseCase,
+    GetTopBlockedDomainsUseCase, GetTopClientsUseCase, GetTrustAnchorsUseCase, GetTsigKeysUseCase,
+    GetUsersUseCase, GetWhitelistSourcesUseCase, GetWhitelistUseCase, ImportConfigUseCase,
+    LoginUseCase, LogoutUseCase, ManageTimeSlotsUseCase, RegisterAcmeAccountUseCase,
+    SetupPasswordUseCase, SimulateBlocklistUseCase, ToggleSafeSearchUseCase, UnblockServiceUseCase,
+    UpdateAcmeChallengeUseCase, UpdateApiTokenUseCase, UpdateBlocklistSourceUseCase,
+    UpdateClientUseCase, UpdateCustomServiceUseCase, UpdateDnsRewriteRuleUseCase,
+    UpdateForwardingRuleUseCase, UpdateGroupBlockingModeUseCase, UpdateGroupUpstreamPoolsUseCase,
     UpdateGroupUseCase, UpdateLocalRecordUseCase, UpdateManagedDomainUseCase,
     UpdateRegexFilterUseCase, UpdateScheduleProfileUseCase, UpdateWhitelistSourceUseCase,
-    ValidateApiTokenUseCase, ValidateSessionUseCase,
+    UpdateZoneRecordUseCase, ValidateApiTokenUseCase, ValidateSessionUseCase,
 };
 use ferrous_dns_domain::Config;
 use std::sync::Arc;
@@ -40,6 +53,7 @@ pub struct QueryUseCases {
     pub get_cache_stats: Arc<GetCacheStatsUseCase>,
     pub get_top_blocked_domains: Arc<GetTopBlockedDomainsUseCase>,
     pub get_top_clients: Arc<GetTopClientsUseCase>,
+    pub query_stream: Arc<QueryStream>,
 }
 
 #[derive(Clone)]
@@ -48,7 +62,32 @@ pub struct DnsUseCases {
     pub create_local_record: Arc<CreateLocalRecordUseCase>,
     pub update_local_record: Arc<UpdateLocalRecordUseCase>,
     pub delete_local_record: Arc<DeleteLocalRecordUseCase>,
+    pub apply_ddns_update: Arc<ApplyDdnsUpdateUseCase>,
+    pub get_ddns_history: Arc<GetDdnsHistoryUseCase>,
     pub upstream_health: Arc<dyn UpstreamHealthPort>,
+    pub get_forwarding_rules: Arc<GetForwardingRulesUseCase>,
+    pub create_forwarding_rule: Arc<CreateForwardingRuleUseCase>,
+    pub update_forwarding_rule: Arc<UpdateForwardingRuleUseCase>,
+    pub delete_forwarding_rule: Arc<DeleteForwardingRuleUseCase>,
+    pub get_dns_rewrite_rules: Arc<GetDnsRewriteRulesUseCase>,
+    pub create_dns_rewrite_rule: Arc<CreateDnsRewriteRuleUseCase>,
+    pub update_dns_rewrite_rule: Arc<UpdateDnsRewriteRuleUseCase>,
+    pub delete_dns_rewrite_rule: Arc<DeleteDnsRewriteRuleUseCase>,
+    pub get_local_zones: Arc<GetLocalZonesUseCase>,
+    pub create_zone_record: Arc<CreateZoneRecordUseCase>,
+    pub update_zone_record: Arc<UpdateZoneRecordUseCase>,
+    pub delete_zone_record: Arc<DeleteZoneRecordUseCase>,
+    pub get_trust_anchors: Arc<GetTrustAnchorsUseCase>,
+    pub get_negative_trust_anchors: Arc<GetNegativeTrustAnchorsUseCase>,
+    pub create_negative_trust_anchor: Arc<CreateNegativeTrustAnchorUseCase>,
+    pub delete_negative_trust_anchor: Arc<DeleteNegativeTrustAnchorUseCase>,
+    pub get_tsig_keys: Arc<GetTsigKeysUseCase>,
+    pub create_tsig_key: Arc<CreateTsigKeyUseCase>,
+    pub delete_tsig_key: Arc<DeleteTsigKeyUseCase>,
+    pub get_acme_registrations: Arc<GetAcmeRegistrationsUseCase>,
+    pub register_acme_account: Arc<RegisterAcmeAccountUseCase>,
+    pub update_acme_challenge: Arc<UpdateAcmeChallengeUseCase>,
+    pub delete_acme_registration: Arc<DeleteAcmeRegistrationUseCase>,
 }
 
 #[derive(Clone)]
@@ -56,6 +95,8 @@ pub struct GroupUseCases {
     pub get_groups: Arc<GetGroupsUseCase>,
     pub create_group: Arc<CreateGroupUseCase>,
     pub update_group: Arc<UpdateGroupUseCase>,
+    pub update_group_blocking_mode: Arc<UpdateGroupBlockingModeUseCase>,
+    pub update_group_upstream_pools: Arc<UpdateGroupUpstreamPoolsUseCase>,
     pub delete_group: Arc<DeleteGroupUseCase>,
     pub assign_client_group: Arc<AssignClientGroupUseCase>,
 }
@@ -79,6 +120,8 @@ pub struct BlockingUseCases {
     pub create_blocklist_source: Arc<CreateBlocklistSourceUseCase>,
     pub update_blocklist_source: Arc<UpdateBlocklistSourceUseCase>,
     pub delete_blocklist_source: Arc<DeleteBlocklistSourceUseCase>,
+    pub get_blocklist_source_hits: Arc<GetBlocklistSourceHitsUseCase>,
+    pub simulate_blocklist: Arc<SimulateBlocklistUseCase>,
     pub get_whitelist: Arc<GetWhitelistUseCase>,
     pub get_whitelist_sources: Arc<GetWhitelistSourcesUseCase>,
     pub create_whitelist_source: Arc<CreateWhitelistSourceUseCase>,
@@ -93,6 +136,7 @@ pub struct BlockingUseCases {
     pub update_regex_filter: Arc<UpdateRegexFilterUseCase>,
     pub delete_regex_filter: Arc<DeleteRegexFilterUseCase>,
     pub get_block_filter_stats: Arc<GetBlockFilterStatsUseCase>,
+    pub explain_filter_decision: Arc<ExplainFilterDecisionUseCase>,
 }
 
 #[derive(Clone)]
@@ -165,6 +209,7 @@ pub struct AppState {
     pub config_file_persistence: Arc<dyn ConfigFilePersistence>,
     pub config_path: Option<Arc<str>>,
     pub tls_cert: Arc<dyn TlsCertificatePort>,
+    pub tls_reloader: Arc<dyn TlsCertificateReloader>,
     pub tls_enabled: bool,
 }
 
@@ -182,3 +227,21 @@ impl AppState {
         self.auth.get_auth_status.execute().await.auth_enabled
     }
 }
+
+/// State of the `/metrics` endpoint, kept apart from [`AppState`] so it can
+/// be served on its own listener. Every source is an in-memory counter.
+#[derive(Clone)]
+pub struct MetricsState {
+    pub dns: Arc<DnsMetrics>,
+    pub jobs: Arc<JobMetrics>,
+    pub cache: Arc<dyn DnsCachePort>,
+    pub upstream_health: Arc<dyn UpstreamHealthPort>,
+    pub query_log: Arc<dyn QueryLogRepository>,
+    pub tunneling: Option<Arc<dyn TunnelingEvictionTarget>>,
+    pub dga: Option<Arc<dyn DgaEvictionTarget>>,
+    pub nxdomain_hijack: Option<Arc<dyn NxdomainHijackProbeTarget>>,
+    pub response_ip_filter: Option<Arc<dyn ResponseIpFilterEvictionTarget>>,
+    pub dnstap: Option<Arc<dyn DnstapSink>>,
+    /// When set, scrapes must present it as a bearer token.
+    pub bearer_token: Option<Arc<str>>,
+}
@@ -0,0 +1,24 @@
+use std::net::{IpAddr, SocketAddr};
+
+use axum::{extract::ConnectInfo, http::HeaderMap, Extension};
+
+/// The peer address, or the forwarded client address (the first
+/// `X-Forwarded-For` hop, then `X-Real-IP`) when `trust_proxy_headers` is
+/// set and the request carries one.
+pub fn client_ip(
+    headers: &HeaderMap,
+    connect_info: Option<Extension<ConnectInfo<SocketAddr>>>,
+    trust_proxy_headers: bool,
+) -> Option<IpAddr> {
+    let peer = connect_info.map(|Extension(ConnectInfo(addr))| addr.ip());
+    if !trust_proxy_headers {
+        return peer;
+    }
+    headers
+        .get("x-forwarded-for")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.split(',').next())
+        .or_else(|| headers.get("x-real-ip").and_then(|v| v.to_str().ok()))
+        .and_then(|v| v.trim().parse().ok())
+        .or(peer)
+}
@@ -1,3 +1,5 @@
+pub mod client_ip;
 pub mod period;
 
+pub use client_ip::client_ip;
 pub use period::{parse_period, validate_period};
@@ -0,0 +1,1144 @@
+use async_trait::async_trait;
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use base64::Engine;
+use ferrous_dns_api::{
+    create_acme_dns_routes, create_api_routes, create_ddns_routes, AppState, BlockingUseCases,
+    ClientUseCases, DnsUseCases, GroupUseCases, QueryUseCases, SafeSearchUseCases,
+    ScheduleUseCases, ServiceUseCases,
+};
+use ferrous_dns_application::{
+    ports::{
+        BlockFilterEnginePort, BlockedServiceRepository, ConfigRepository, DnsResolution,
+        DnsResolver, FilterDecision, LocalZoneStore, SafeSearchConfigRepository,
+        SafeSearchEnginePort, ServiceCatalogPort,
+    },
+    services::SubnetMatcherService,
+    use_cases::{
+        AssignScheduleProfileUseCase, CreateScheduleProfileUseCase, DeleteScheduleProfileUseCase,
+        GetBlockFilterStatsUseCase, GetScheduleProfilesUseCase, ManageTimeSlotsUseCase,
+        UpdateScheduleProfileUseCase, *,
+    },
+};
+use ferrous_dns_domain::{config::DatabaseConfig, Config, DnsQuery, DomainError, RecordType};
+use ferrous_dns_infrastructure::{
+    dns::{
+        authority::{parse_zone_text, LocalAuthority},
+        cache::DnsCache,
+        resolver::LocalAuthorityResolver,
+        AcmeChallengeTable,
+    },
+    repositories::{
+        client_repository::SqliteClientRepository,
+        client_subnet_repository::SqliteClientSubnetRepository,
+        group_repository::SqliteGroupRepository,
+        managed_domain_repository::SqliteManagedDomainRepository,
+        regex_filter_repository::SqliteRegexFilterRepository, SqliteAcmeRegistrationRepository,
+        SqliteApiTokenRepository, SqliteDdnsHistoryRepository,
+    },
+};
+use hickory_proto::op::Message;
+use hickory_proto::rr::RData;
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use sqlx::sqlite::SqlitePoolOptions;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tower::ServiceExt;
+
+mod helpers;
+
+struct NullBlockFilterEngine;
+
+#[async_trait::async_trait]
+impl BlockFilterEnginePort for NullBlockFilterEngine {
+    fn resolve_group(&self, _ip: std::net::IpAddr) -> i64 {
+        1
+    }
+    fn check(&self, _domain: &str, _group_id: i64) -> FilterDecision {
+        FilterDecision::Allow
+    }
+    async fn reload(&self) -> Result<(), ferrous_dns_domain::DomainError> {
+        Ok(())
+    }
+    async fn load_client_groups(&self) -> Result<(), ferrous_dns_domain::DomainError> {
+        Ok(())
+    }
+    fn compiled_domain_count(&self) -> usize {
+        0
+    }
+    fn store_cname_decision(
+        &self,
+        _domain: &str,
+        _blocked_cname: &str,
+        _group_id: i64,
+        _ttl_secs: u64,
+    ) {
+    }
+    fn is_blocking_enabled(&self) -> bool {
+        true
+    }
+    fn set_blocking_enabled(&self, _enabled: bool) {}
+}
+
+struct NullBlockedServiceRepository;
+
+#[async_trait::async_trait]
+impl BlockedServiceRepository for NullBlockedServiceRepository {
+    async fn block_service(
+        &self,
+        _service_id: &str,
+        _group_id: i64,
+    ) -> Result<ferrous_dns_domain::BlockedService, ferrous_dns_domain::DomainError> {
+        unimplemented!()
+    }
+    async fn unblock_service(
+        &self,
+        _service_id: &str,
+        _group_id: i64,
+    ) -> Result<(), ferrous_dns_domain::DomainError> {
+        Ok(())
+    }
+    async fn get_blocked_for_group(
+        &self,
+        _group_id: i64,
+    ) -> Result<Vec<ferrous_dns_domain::BlockedService>, ferrous_dns_domain::DomainError> {
+        Ok(vec![])
+    }
+    async fn get_all_blocked(
+        &self,
+    ) -> Result<Vec<ferrous_dns_domain::BlockedService>, ferrous_dns_domain::DomainError> {
+        Ok(vec![])
+    }
+    async fn delete_all_for_service(
+        &self,
+        _service_id: &str,
+    ) -> Result<u64, ferrous_dns_domain::DomainError> {
+        Ok(0)
+    }
+}
+
+struct NullCustomServiceRepository;
+
+#[async_trait::async_trait]
+impl ferrous_dns_application::ports::CustomServiceRepository for NullCustomServiceRepository {
+    async fn create(
+        &self,
+        _service_id: &str,
+        _name: &str,
+        _category_name: &str,
+        _domains: &[String],
+    ) -> Result<ferrous_dns_domain::CustomService, ferrous_dns_domain::DomainError> {
+        unimplemented!()
+    }
+    async fn get_by_service_id(
+        &self,
+        _service_id: &str,
+    ) -> Result<Option<ferrous_dns_domain::CustomService>, ferrous_dns_domain::DomainError> {
+        Ok(None)
+    }
+    async fn get_all(
+        &self,
+    ) -> Result<Vec<ferrous_dns_domain::CustomService>, ferrous_dns_domain::DomainError> {
+        Ok(vec![])
+    }
+    async fn update(
+        &self,
+        _service_id: &str,
+        _name: Option<String>,
+        _category_name: Option<String>,
+        _domains: Option<Vec<String>>,
+    ) -> Result<ferrous_dns_domain::CustomService, ferrous_dns_domain::DomainError> {
+        unimplemented!()
+    }
+    async fn delete(&self, _service_id: &str) -> Result<(), ferrous_dns_domain::DomainError> {
+        Ok(())
+    }
+}
+
+struct NullServiceCatalog;
+
+impl ServiceCatalogPort for NullServiceCatalog {
+    fn get_by_id(&self, _id: &str) -> Option<ferrous_dns_domain::ServiceDefinition> {
+        None
+    }
+    fn all(&self) -> Vec<ferrous_dns_domain::ServiceDefinition> {
+        vec![]
+    }
+    fn normalized_rules_for(&self, _service_id: &str) -> Vec<String> {
+        vec![]
+    }
+    fn reload_custom(&self, _custom: Vec<ferrous_dns_domain::ServiceDefinition>) {}
+}
+struct NullConfigRepository;
+#[async_trait::async_trait]
+impl ConfigRepository for NullConfigRepository {
+    async fn save_local_records(
+        &self,
+        _config: &Config,
+    ) -> Result<(), ferrous_dns_domain::DomainError> {
+        Ok(())
+    }
+}
+
+struct NullSafeSearchConfigRepository;
+#[async_trait::async_trait]
+impl SafeSearchConfigRepository for NullSafeSearchConfigRepository {
+    async fn get_all(
+        &self,
+    ) -> Result<Vec<ferrous_dns_domain::SafeSearchConfig>, ferrous_dns_domain::DomainError> {
+        Ok(vec![])
+    }
+    async fn get_by_group(
+        &self,
+        _group_id: i64,
+    ) -> Result<Vec<ferrous_dns_domain::SafeSearchConfig>, ferrous_dns_domain::DomainError> {
+        Ok(vec![])
+    }
+    async fn upsert(
+        &self,
+        _group_id: i64,
+        _engine: ferrous_dns_domain::SafeSearchEngine,
+        _enabled: bool,
+        _youtube_mode: ferrous_dns_domain::YouTubeMode,
+    ) -> Result<ferrous_dns_domain::SafeSearchConfig, ferrous_dns_domain::DomainError> {
+        unimplemented!()
+    }
+    async fn delete_by_group(&self, _group_id: i64) -> Result<(), ferrous_dns_domain::DomainError> {
+        Ok(())
+    }
+}
+
+struct NullSafeSearchEnginePort;
+#[async_trait::async_trait]
+impl SafeSearchEnginePort for NullSafeSearchEnginePort {
+    fn cname_for(&self, _domain: &str, _group_id: i64) -> Option<&'static str> {
+        None
+    }
+    async fn reload(&self) -> Result<(), ferrous_dns_domain::DomainError> {
+        Ok(())
+    }
+}
+
+struct NullScheduleProfileRepository;
+
+#[async_trait::async_trait]
+impl ferrous_dns_application::ports::ScheduleProfileRepository for NullScheduleProfileRepository {
+    async fn create(
+        &self,
+        _name: String,
+        _tz: String,
+        _comment: Option<String>,
+    ) -> Result<ferrous_dns_domain::ScheduleProfile, ferrous_dns_domain::DomainError> {
+        unimplemented!()
+    }
+    async fn get_by_id(
+        &self,
+        _id: i64,
+    ) -> Result<Option<ferrous_dns_domain::ScheduleProfile>, ferrous_dns_domain::DomainError> {
+        Ok(None)
+    }
+    async fn get_all(
+        &self,
+    ) -> Result<Vec<ferrous_dns_domain::ScheduleProfile>, ferrous_dns_domain::DomainError> {
+        Ok(vec![])
+    }
+    async fn update(
+        &self,
+        _id: i64,
+        _name: Option<String>,
+        _tz: Option<String>,
+        _comment: Option<String>,
+    ) -> Result<ferrous_dns_domain::ScheduleProfile, ferrous_dns_domain::DomainError> {
+        unimplemented!()
+    }
+    async fn delete(&self, _id: i64) -> Result<(), ferrous_dns_domain::DomainError> {
+        Ok(())
+    }
+    async fn get_slots(
+        &self,
+        _profile_id: i64,
+    ) -> Result<Vec<ferrous_dns_domain::TimeSlot>, ferrous_dns_domain::DomainError> {
+        Ok(vec![])
+    }
+    async fn add_slot(
+        &self,
+        _pid: i64,
+        _days: u8,
+        _start: String,
+        _end: String,
+        _action: ferrous_dns_domain::ScheduleAction,
+    ) -> Result<ferrous_dns_domain::TimeSlot, ferrous_dns_domain::DomainError> {
+        unimplemented!()
+    }
+    async fn delete_slot(&self, _slot_id: i64) -> Result<(), ferrous_dns_domain::DomainError> {
+        Ok(())
+    }
+    async fn assign_to_group(
+        &self,
+        _group_id: i64,
+        _profile_id: i64,
+    ) -> Result<(), ferrous_dns_domain::DomainError> {
+        Ok(())
+    }
+    async fn unassign_from_group(
+        &self,
+        _group_id: i64,
+    ) -> Result<(), ferrous_dns_domain::DomainError> {
+        Ok(())
+    }
+    async fn get_group_assignment(
+        &self,
+        _group_id: i64,
+    ) -> Result<Option<i64>, ferrous_dns_domain::DomainError> {
+        Ok(None)
+    }
+    async fn get_all_group_assignments(
+        &self,
+    ) -> Result<Vec<(i64, i64)>, ferrous_dns_domain::DomainError> {
+        Ok(vec![])
+    }
+}
+
+async fn create_test_db() -> sqlx::SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE groups (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL UNIQUE,
+            enabled BOOLEAN NOT NULL DEFAULT 1,
+            comment TEXT,
+            is_default BOOLEAN NOT NULL DEFAULT 0,
+            blocking_mode TEXT,
+            blocking_ipv4 TEXT,
+            blocking_ipv6 TEXT,
+            upstream_pools TEXT,
+            created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        "INSERT INTO groups (id, name, is_default) VALUES (1, 'Protected', 1), (2, 'Office', 0)",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE clients (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            ip_address TEXT NOT NULL UNIQUE,
+            mac_address TEXT,
+            hostname TEXT,
+            first_seen DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            last_seen DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            query_count INTEGER NOT NULL DEFAULT 0,
+            last_mac_update DATETIME,
+            last_hostname_update DATETIME,
+            group_id INTEGER NOT NULL DEFAULT 1 REFERENCES groups(id) ON DELETE RESTRICT,
+            created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE client_subnets (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            subnet_cidr TEXT NOT NULL UNIQUE,
+            group_id INTEGER NOT NULL,
+            comment TEXT,
+            created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            FOREIGN KEY (group_id) REFERENCES groups(id) ON DELETE CASCADE
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE managed_domains (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL UNIQUE,
+            domain TEXT NOT NULL,
+            action TEXT NOT NULL CHECK(action IN ('allow', 'deny')),
+            group_id INTEGER NOT NULL DEFAULT 1 REFERENCES groups(id),
+            comment TEXT,
+            enabled INTEGER NOT NULL DEFAULT 1,
+            service_id TEXT,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE regex_filters (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL UNIQUE,
+            pattern TEXT NOT NULL,
+            action TEXT NOT NULL CHECK(action IN ('allow', 'deny')),
+            group_id INTEGER NOT NULL DEFAULT 1,
+            comment TEXT,
+            enabled INTEGER NOT NULL DEFAULT 1,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE api_tokens (
+            id           INTEGER PRIMARY KEY AUTOINCREMENT,
+            name         TEXT    NOT NULL UNIQUE,
+            key_prefix   TEXT    NOT NULL,
+            key_hash     TEXT    NOT NULL,
+            created_at   TEXT    NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%S', 'now')),
+            last_used_at TEXT,
+            key_raw      TEXT
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE ddns_updates (
+            id          INTEGER PRIMARY KEY AUTOINCREMENT,
+            hostname    TEXT    NOT NULL,
+            ip          TEXT,
+            result      TEXT    NOT NULL,
+            token_id    INTEGER,
+            client_ip   TEXT,
+            created_at  TEXT    NOT NULL
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE tsig_keys (
+            id          INTEGER PRIMARY KEY AUTOINCREMENT,
+            name        TEXT    NOT NULL UNIQUE,
+            algorithm   TEXT    NOT NULL,
+            secret      TEXT    NOT NULL,
+            created_at  TEXT    NOT NULL
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE acme_registrations (
+            id             INTEGER PRIMARY KEY AUTOINCREMENT,
+            username       TEXT    NOT NULL UNIQUE,
+            password_hash  TEXT    NOT NULL,
+            subdomain      TEXT    NOT NULL UNIQUE,
+            allow_from     TEXT    NOT NULL DEFAULT '[]',
+            txt            TEXT    NOT NULL DEFAULT '[]',
+            created_at     TEXT    NOT NULL,
+            updated_at     TEXT    NOT NULL
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    pool
+}
+
+const DDNS_TOKEN: &str = "ddns-test-token";
+
+const ACME_ZONE: &str = "acme.example.com";
+
+async fn create_test_app_with_acme() -> (Router, Arc<RwLock<Config>>, Arc<AcmeChallengeTable>) {
+    let pool = create_test_db().await;
+
+    let acme_registration_repo = Arc::new(SqliteAcmeRegistrationRepository::new(pool.clone()));
+    let acme_challenges = Arc::new(AcmeChallengeTable::new(
+        ACME_ZONE,
+        &format!("ns.{ACME_ZONE}"),
+        10,
+    ));
+
+    let api_token_repo = Arc::new(SqliteApiTokenRepository::new(Arc::new(pool.clone())));
+    CreateApiTokenUseCase::new(api_token_repo.clone())
+        .execute("router", Some(DDNS_TOKEN))
+        .await
+        .unwrap();
+    let ddns_history_repo = Arc::new(SqliteDdnsHistoryRepository::new(pool.clone()));
+
+    let client_repo = Arc::new(SqliteClientRepository::new(
+        pool.clone(),
+        &DatabaseConfig::default(),
+    ));
+    let group_repo = Arc::new(SqliteGroupRepository::new(pool.clone()));
+    let subnet_repo = Arc::new(SqliteClientSubnetRepository::new(pool.clone()));
+    let managed_domain_repo = Arc::new(SqliteManagedDomainRepository::new(pool.clone()));
+    let regex_filter_repo = Arc::new(SqliteRegexFilterRepository::new(pool.clone()));
+    let null_engine: Arc<dyn BlockFilterEnginePort> = Arc::new(NullBlockFilterEngine);
+
+    let config = Arc::new(RwLock::new(Config::default()));
+    let cache = Arc::new(DnsCache::new(
+        ferrous_dns_infrastructure::dns::DnsCacheConfig {
+            max_entries: 0,
+            eviction_strategy: ferrous_dns_infrastructure::dns::EvictionStrategy::LRU,
+            min_threshold: 0.0,
+            refresh_threshold: 0.0,
+            batch_eviction_percentage: 0.0,
+            adaptive_thresholds: false,
+            min_frequency: 0,
+            min_lfuk_score: 0.0,
+            shard_amount: 4,
+            access_window_secs: 7200,
+            eviction_sample_size: 8,
+            lfuk_k_value: 0.5,
+            refresh_sample_rate: 1.0,
+            min_ttl: 0,
+            max_ttl: 86_400,
+        },
+    ));
+
+    use ferrous_dns_domain::config::upstream::{UpstreamPool, UpstreamStrategy};
+    use ferrous_dns_infrastructure::dns::{PoolManager, QueryEventEmitter};
+
+    let event_emitter = QueryEventEmitter::new_disabled();
+    let test_pool = UpstreamPool {
+        name: "test".to_string(),
+        strategy: UpstreamStrategy::Parallel,
+        priority: 1,
+        servers: vec!["8.8.8.8:53".to_string()],
+        weight: None,
+    };
+
+    let pool_manager = Arc::new(
+        PoolManager::new(vec![test_pool], None, event_emitter)
+            .await
+            .expect("Failed to create PoolManager"),
+    );
+
+    let forwarding_rule_repo = Arc::new(
+        ferrous_dns_infrastructure::repositories::SqliteForwardingRuleRepository::new(pool.clone()),
+    );
+    let forwarding_rule_table =
+        Arc::new(ferrous_dns_infrastructure::dns::resolver::ForwardingRuleTable::new());
+    let local_zone_repo =
+        Arc::new(ferrous_dns_infrastructure::repositories::FileLocalZoneRepository::new(vec![]));
+    let local_authority = Arc::new(ferrous_dns_infrastructure::dns::LocalAuthority::new());
+    let state = AppState {
+        query: QueryUseCases {
+            get_stats: Arc::new(GetQueryStatsUseCase::new(Arc::new(
+                ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &DatabaseConfig::default()),
+            ), client_repo.clone())),
+            get_queries: Arc::new(GetRecentQueriesUseCase::new(Arc::new(
+                ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &DatabaseConfig::default()),
+            ))),
+            get_timeline: Arc::new(ferrous_dns_application::use_cases::GetTimelineUseCase::new(Arc::new(
+                ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &DatabaseConfig::default()),
+            ))),
+            get_query_rate: Arc::new(ferrous_dns_application::use_cases::GetQueryRateUseCase::new(Arc::new(
+                ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &DatabaseConfig::default()),
+            ))),
+            get_cache_stats: Arc::new(ferrous_dns_application::use_cases::GetCacheStatsUseCase::new(Arc::new(
+                ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &DatabaseConfig::default()),
+            ))),
+            get_top_blocked_domains: Arc::new(ferrous_dns_application::use_cases::GetTopBlockedDomainsUseCase::new(Arc::new(
+                ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &DatabaseConfig::default()),
+            ))),
+            get_top_clients: Arc::new(ferrous_dns_application::use_cases::GetTopClientsUseCase::new(Arc::new(
+                ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &DatabaseConfig::default()),
+            ))),
+            query_stream: Arc::new(ferrous_dns_application::services::QueryStream::default()),
+        },
+        dns: DnsUseCases {
+            cache: cache as Arc<dyn ferrous_dns_application::ports::DnsCachePort>,
+            create_local_record: Arc::new(CreateLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))),
+            update_local_record: Arc::new(UpdateLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))),
+            delete_local_record: Arc::new(DeleteLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))),
+            apply_ddns_update: Arc::new(ApplyDdnsUpdateUseCase::new(
+                Arc::new(CreateLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))),
+                Arc::new(ValidateApiTokenUseCase::new(api_token_repo)),
+                ddns_history_repo.clone(),
+                &["home.lan".to_string()],
+            )),
+            get_ddns_history: Arc::new(GetDdnsHistoryUseCase::new(ddns_history_repo)),
+            upstream_health: Arc::new(ferrous_dns_infrastructure::dns::UpstreamHealthAdapter::new(
+                pool_manager,
+                None,
+            )),
+            get_forwarding_rules: Arc::new(ferrous_dns_application::use_cases::GetForwardingRulesUseCase::new(forwarding_rule_repo.clone())),
+            create_forwarding_rule: Arc::new(ferrous_dns_application::use_cases::CreateForwardingRuleUseCase::new(forwarding_rule_repo.clone(), forwarding_rule_table.clone())),
+            update_forwarding_rule: Arc::new(ferrous_dns_application::use_cases::UpdateForwardingRuleUseCase::new(forwarding_rule_repo.clone(), forwarding_rule_table.clone())),
+            delete_forwarding_rule: Arc::new(ferrous_dns_application::use_cases::DeleteForwardingRuleUseCase::new(forwarding_rule_repo.clone(), forwarding_rule_table.clone())),
+            get_local_zones: Arc::new(ferrous_dns_application::use_cases::GetLocalZonesUseCase::new(local_zone_repo.clone())),
+            create_zone_record: Arc::new(ferrous_dns_application::use_cases::CreateZoneRecordUseCase::new(local_zone_repo.clone(), local_authority.clone())),
+            update_zone_record: Arc::new(ferrous_dns_application::use_cases::UpdateZoneRecordUseCase::new(local_zone_repo.clone(), local_authority.clone())),
+            delete_zone_record: Arc::new(ferrous_dns_application::use_cases::DeleteZoneRecordUseCase::new(local_zone_repo.clone(), local_authority.clone())),
+            get_trust_anchors: Arc::new(ferrous_dns_application::use_cases::GetTrustAnchorsUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteTrustAnchorRepository::new(pool.clone())))),
+            get_negative_trust_anchors: Arc::new(ferrous_dns_application::use_cases::GetNegativeTrustAnchorsUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteNegativeTrustAnchorRepository::new(pool.clone())))),
+            create_negative_trust_anchor: Arc::new(ferrous_dns_application::use_cases::CreateNegativeTrustAnchorUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteNegativeTrustAnchorRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::dnssec::NegativeTrustAnchorTable::new()))),
+            delete_negative_trust_anchor: Arc::new(ferrous_dns_application::use_cases::DeleteNegativeTrustAnchorUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteNegativeTrustAnchorRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::dnssec::NegativeTrustAnchorTable::new()))),
+            get_tsig_keys: Arc::new(ferrous_dns_application::use_cases::GetTsigKeysUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteTsigKeyRepository::new(pool.clone())))),
+            create_tsig_key: Arc::new(ferrous_dns_application::use_cases::CreateTsigKeyUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteTsigKeyRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::TsigKeyTable::new()))),
+            delete_tsig_key: Arc::new(ferrous_dns_application::use_cases::DeleteTsigKeyUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteTsigKeyRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::TsigKeyTable::new()))),
+            get_acme_registrations: Arc::new(GetAcmeRegistrationsUseCase::new(acme_registration_repo.clone())),
+            register_acme_account: Arc::new(RegisterAcmeAccountUseCase::new(acme_registration_repo.clone(), acme_challenges.clone(), ACME_ZONE, vec!["10.0.0.0/8".parse().unwrap()])),
+            update_acme_challenge: Arc::new(UpdateAcmeChallengeUseCase::new(acme_registration_repo.clone(), acme_challenges.clone())),
+            delete_acme_registration: Arc::new(DeleteAcmeRegistrationUseCase::new(acme_registration_repo, acme_challenges.clone())),
+            get_dns_rewrite_rules: Arc::new(ferrous_dns_application::use_cases::GetDnsRewriteRulesUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteDnsRewriteRuleRepository::new(pool.clone())))),
+            create_dns_rewrite_rule: Arc::new(ferrous_dns_application::use_cases::CreateDnsRewriteRuleUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteDnsRewriteRuleRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::repositories::SqliteGroupRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::DnsRewriteTable::new()))),
+            update_dns_rewrite_rule: Arc::new(ferrous_dns_application::use_cases::UpdateDnsRewriteRuleUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteDnsRewriteRuleRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::repositories::SqliteGroupRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::DnsRewriteTable::new()))),
+            delete_dns_rewrite_rule: Arc::new(ferrous_dns_application::use_cases::DeleteDnsRewriteRuleUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteDnsRewriteRuleRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::DnsRewriteTable::new()))),
+        },
+        groups: GroupUseCases {
+            get_groups: Arc::new(GetGroupsUseCase::new(group_repo.clone())),
+            create_group: Arc::new(CreateGroupUseCase::new(group_repo.clone())),
+            update_group: Arc::new(UpdateGroupUseCase::new(group_repo.clone())),
+            update_group_blocking_mode: Arc::new(UpdateGroupBlockingModeUseCase::new(group_repo.clone(), Arc::new(NullBlockFilterEngine))),
+            update_group_upstream_pools: Arc::new(ferrous_dns_application::use_cases::UpdateGroupUpstreamPoolsUseCase::new(group_repo.clone(), Arc::new(ferrous_dns_infrastructure::dns::GroupUpstreamTable::new()), config.clone())),
+            delete_group: Arc::new(DeleteGroupUseCase::new(group_repo.clone())),
+            assign_client_group: Arc::new(AssignClientGroupUseCase::new(client_repo.clone(), group_repo.clone(), Arc::new(NullBlockFilterEngine))),
+        },
+        clients: ClientUseCases {
+            get_clients: Arc::new(GetClientsUseCase::new(client_repo.clone())),
+            get_client_subnets: Arc::new(GetClientSubnetsUseCase::new(subnet_repo.clone())),
+            create_client_subnet: Arc::new(CreateClientSubnetUseCase::new(subnet_repo.clone(), group_repo.clone(), Arc::new(NullBlockFilterEngine))),
+            delete_client_subnet: Arc::new(DeleteClientSubnetUseCase::new(subnet_repo.clone(), Arc::new(NullBlockFilterEngine))),
+            create_manual_client: Arc::new(CreateManualClientUseCase::new(client_repo.clone(), group_repo.clone())),
+            update_client: Arc::new(UpdateClientUseCase::new(client_repo.clone())),
+            delete_client: Arc::new(DeleteClientUseCase::new(client_repo.clone())),
+            subnet_matcher: Arc::new(SubnetMatcherService::new(subnet_repo.clone())),
+        },
+        blocking: BlockingUseCases {
+            get_blocklist: Arc::new(GetBlocklistUseCase::new(Arc::new(
+                ferrous_dns_infrastructure::repositories::blocklist_repository::SqliteBlocklistRepository::new(pool.clone()),
+            ))),
+            get_blocklist_sources: Arc::new(GetBlocklistSourcesUseCase::new(Arc::new(
+                ferrous_dns_infrastructure::repositories::blocklist_source_repository::SqliteBlocklistSourceRepository::new(pool.clone()),
+            ))),
+            create_blocklist_source: Arc::new(CreateBlocklistSourceUseCase::new(
+                Arc::new(ferrous_dns_infrastructure::repositories::blocklist_source_repository::SqliteBlocklistSourceRepository::new(pool.clone())),
+                group_repo.clone(),
+            )),
+            update_blocklist_source: Arc::new(UpdateBlocklistSourceUseCase::new(
+                Arc::new(ferrous_dns_infrastructure::repositories::blocklist_source_repository::SqliteBlocklistSourceRepository::new(pool.clone())),
+                group_repo.clone(),
+            )),
+            delete_blocklist_source: Arc::new(DeleteBlocklistSourceUseCase::new(Arc::new(
+                ferrous_dns_infrastructure::repositories::blocklist_source_repository::SqliteBlocklistSourceRepository::new(pool.clone()),
+            ))),
+            get_whitelist: Arc::new(ferrous_dns_application::use_cases::GetWhitelistUseCase::new(Arc::new(
+                ferrous_dns_infrastructure::repositories::whitelist_repository::SqliteWhitelistRepository::new(pool.clone()),
+            ))),
+            get_whitelist_sources: Arc::new(ferrous_dns_application::use_cases::GetWhitelistSourcesUseCase::new(Arc::new(
+                ferrous_dns_infrastructure::repositories::whitelist_source_repository::SqliteWhitelistSourceRepository::new(pool.clone()),
+            ))),
+            create_whitelist_source: Arc::new(ferrous_dns_application::use_cases::CreateWhitelistSourceUseCase::new(
+                Arc::new(ferrous_dns_infrastructure::repositories::whitelist_source_repository::SqliteWhitelistSourceRepository::new(pool.clone())),
+                group_repo.clone(),
+            )),
+            update_whitelist_source: Arc::new(ferrous_dns_application::use_cases::UpdateWhitelistSourceUseCase::new(
+                Arc::new(ferrous_dns_infrastructure::repositories::whitelist_source_repository::SqliteWhitelistSourceRepository::new(pool.clone())),
+                group_repo.clone(),
+            )),
+            delete_whitelist_source: Arc::new(ferrous_dns_application::use_cases::DeleteWhitelistSourceUseCase::new(Arc::new(
+                ferrous_dns_infrastructure::repositories::whitelist_source_repository::SqliteWhitelistSourceRepository::new(pool.clone()),
+            ))),
+            get_managed_domains: Arc::new(GetManagedDomainsUseCase::new(managed_domain_repo.clone())),
+            create_managed_domain: Arc::new(CreateManagedDomainUseCase::new(
+                managed_domain_repo.clone(),
+                group_repo.clone(),
+                null_engine.clone(),
+            )),
+            update_managed_domain: Arc::new(UpdateManagedDomainUseCase::new(
+                managed_domain_repo.clone(),
+                group_repo.clone(),
+                null_engine.clone(),
+            )),
+            delete_managed_domain: Arc::new(DeleteManagedDomainUseCase::new(
+                managed_domain_repo.clone(),
+                null_engine.clone(),
+            )),
+            get_regex_filters: Arc::new(ferrous_dns_application::use_cases::GetRegexFiltersUseCase::new(
+                regex_filter_repo.clone(),
+            )),
+            create_regex_filter: Arc::new(ferrous_dns_application::use_cases::CreateRegexFilterUseCase::new(
+                regex_filter_repo.clone(),
+                group_repo.clone(),
+                null_engine.clone(),
+            )),
+            update_regex_filter: Arc::new(ferrous_dns_application::use_cases::UpdateRegexFilterUseCase::new(
+                regex_filter_repo.clone(),
+                group_repo.clone(),
+                null_engine.clone(),
+            )),
+            delete_regex_filter: Arc::new(ferrous_dns_application::use_cases::DeleteRegexFilterUseCase::new(
+                regex_filter_repo.clone(),
+                null_engine.clone(),
+            )),
+            get_block_filter_stats: Arc::new(GetBlockFilterStatsUseCase::new(Arc::new(NullBlockFilterEngine))),
+            explain_filter_decision: Arc::new(ferrous_dns_application::use_cases::ExplainFilterDecisionUseCase::new(Arc::new(NullBlockFilterEngine), Arc::new(ferrous_dns_infrastructure::schedule::ScheduleStateStore::new()), Arc::new(NullSafeSearchEnginePort), Arc::new(ferrous_dns_infrastructure::repositories::SqliteGroupRepository::new(pool.clone())))),
+            get_blocklist_source_hits: Arc::new(ferrous_dns_application::use_cases::GetBlocklistSourceHitsUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &ferrous_dns_domain::config::DatabaseConfig::default())))),
+            simulate_blocklist: Arc::new(ferrous_dns_application::use_cases::SimulateBlocklistUseCase::new(Arc::new(ferrous_dns_infrastructure::dns::SqliteBlocklistSimulator::new(pool.clone(), Arc::new(arc_swap::ArcSwap::from_pointee(ferrous_dns_infrastructure::dns::block_filter::BlockIndex::empty()))).unwrap()), Arc::new(ferrous_dns_infrastructure::repositories::group_repository::SqliteGroupRepository::new(pool.clone())))),
+        },
+        services: ServiceUseCases {
+            get_service_catalog: Arc::new(GetServiceCatalogUseCase::new(Arc::new(NullServiceCatalog))),
+            get_blocked_services: Arc::new(GetBlockedServicesUseCase::new(Arc::new(NullBlockedServiceRepository))),
+            block_service: Arc::new(BlockServiceUseCase::new(
+                Arc::new(NullBlockedServiceRepository),
+                managed_domain_repo.clone(),
+                group_repo.clone(),
+                null_engine.clone(),
+                Arc::new(NullServiceCatalog),
+            )),
+            unblock_service: Arc::new(UnblockServiceUseCase::new(
+                Arc::new(NullBlockedServiceRepository),
+                managed_domain_repo.clone(),
+                null_engine.clone(),
+            )),
+            create_custom_service: Arc::new(ferrous_dns_application::use_cases::CreateCustomServiceUseCase::new(Arc::new(NullCustomServiceRepository), Arc::new(NullServiceCatalog))),
+            get_custom_services: Arc::new(ferrous_dns_application::use_cases::GetCustomServicesUseCase::new(Arc::new(NullCustomServiceRepository))),
+            update_custom_service: Arc::new(ferrous_dns_application::use_cases::UpdateCustomServiceUseCase::new(Arc::new(NullCustomServiceRepository), Arc::new(NullServiceCatalog), managed_domain_repo.clone(), Arc::new(NullBlockedServiceRepository), null_engine.clone())),
+            delete_custom_service: Arc::new(ferrous_dns_application::use_cases::DeleteCustomServiceUseCase::new(Arc::new(NullCustomServiceRepository), Arc::new(NullServiceCatalog), Arc::new(NullBlockedServiceRepository), managed_domain_repo.clone(), null_engine.clone())),
+        },
+        safe_search: SafeSearchUseCases {
+            get_configs: Arc::new(GetSafeSearchConfigsUseCase::new(
+                Arc::new(NullSafeSearchConfigRepository),
+                group_repo.clone(),
+            )),
+            toggle: Arc::new(ToggleSafeSearchUseCase::new(
+                Arc::new(NullSafeSearchConfigRepository),
+                group_repo.clone(),
+                Arc::new(NullSafeSearchEnginePort),
+            )),
+            delete_configs: Arc::new(DeleteSafeSearchConfigsUseCase::new(
+                Arc::new(NullSafeSearchConfigRepository),
+                group_repo.clone(),
+                Arc::new(NullSafeSearchEnginePort),
+            )),
+        },
+        schedule: ScheduleUseCases {
+            get_profiles: Arc::new(GetScheduleProfilesUseCase::new(Arc::new(NullScheduleProfileRepository))),
+            create_profile: Arc::new(CreateScheduleProfileUseCase::new(Arc::new(NullScheduleProfileRepository))),
+            update_profile: Arc::new(UpdateScheduleProfileUseCase::new(Arc::new(NullScheduleProfileRepository))),
+            delete_profile: Arc::new(DeleteScheduleProfileUseCase::new(Arc::new(NullScheduleProfileRepository))),
+            manage_slots: Arc::new(ManageTimeSlotsUseCase::new(Arc::new(NullScheduleProfileRepository))),
+            assign_profile: Arc::new(AssignScheduleProfileUseCase::new(Arc::new(NullScheduleProfileRepository), group_repo.clone())),
+        },
+        auth: helpers::build_test_auth_use_cases(),
+        backup: helpers::build_test_backup_use_cases(config.clone()),
+        config: config.clone(),
+        config_file_persistence: Arc::new(ferrous_dns_infrastructure::repositories::TomlConfigFilePersistence),
+        config_path: None,
+        tls_cert: Arc::new(helpers::MockTlsCertificateService),
+        tls_reloader: Arc::new(helpers::MockTlsCertificateReloader),
+        tls_enabled: false,
+    };
+
+    let app = create_api_routes(state.clone())
+        .merge(create_ddns_routes(state.clone()))
+        .merge(create_acme_dns_routes(state));
+    (app, config, acme_challenges)
+}
+
+const ACME_TXT: &str = "LHDhK3oGRvkiefQnx7OOczTY5Tic_xZ6HcMOc_gmtoM";
+
+async fn acme_request(
+    app: &Router,
+    uri: &str,
+    peer: &str,
+    headers: &[(&str, &str)],
+    body: Value,
+) -> (StatusCode, Value) {
+    let mut builder = Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("content-type", "application/json")
+        .extension(axum::extract::ConnectInfo(
+            format!("{peer}:40000")
+                .parse::<std::net::SocketAddr>()
+                .unwrap(),
+        ));
+    for (name, value) in headers {
+        builder = builder.header(*name, *value);
+    }
+    let response = app
+        .clone()
+        .oneshot(builder.body(Body::from(body.to_string())).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+}
+
+fn served_txt(table: &AcmeChallengeTable, domain: &str) -> Vec<String> {
+    table
+        .answer(domain, hickory_proto::rr::RecordType::TXT)
+        .map(|response| {
+            response
+                .answers
+                .iter()
+                .filter_map(|record| match record.data() {
+                    hickory_proto::rr::RData::TXT(txt) => Some(txt.to_string()),
+                    _ => None,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[tokio::test]
+async fn test_acme_register_then_update_serves_txt() {
+    let (app, _config, table) = create_test_app_with_acme().await;
+
+    let (status, account) = acme_request(
+        &app,
+        "/register",
+        "10.0.0.5",
+        &[],
+        json!({ "allowfrom": ["10.0.0.0/24"] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let subdomain = account["subdomain"].as_str().unwrap();
+    let fulldomain = account["fulldomain"].as_str().unwrap();
+    assert_eq!(fulldomain, format!("{subdomain}.{ACME_ZONE}"));
+    assert_eq!(account["allowfrom"], json!(["10.0.0.0/24"]));
+
+    let (status, body) = acme_request(
+        &app,
+        "/update",
+        "10.0.0.5",
+        &[
+            ("X-Api-User", account["username"].as_str().unwrap()),
+            ("X-Api-Key", account["password"].as_str().unwrap()),
+        ],
+        json!({ "subdomain": subdomain, "txt": ACME_TXT }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, json!({ "txt": ACME_TXT }));
+
+    assert_eq!(served_txt(&table, fulldomain), vec![ACME_TXT.to_string()]);
+}
+
+#[tokio::test]
+async fn test_acme_register_refuses_source_outside_allowed_networks() {
+    let (app, _config, _table) = create_test_app_with_acme().await;
+
+    let (status, body) = acme_request(&app, "/register", "192.0.2.1", &[], json!({})).await;
+
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(body, json!({ "error": "forbidden" }));
+}
+
+#[tokio::test]
+async fn test_acme_register_rejects_invalid_allowfrom() {
+    let (app, _config, _table) = create_test_app_with_acme().await;
+
+    let (status, body) = acme_request(
+        &app,
+        "/register",
+        "10.0.0.5",
+        &[],
+        json!({ "allowfrom": ["bogus"] }),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body, json!({ "error": "invalid_allowfrom_cidr" }));
+}
+
+#[tokio::test]
+async fn test_acme_update_reports_acme_dns_error_codes() {
+    let (app, _config, table) = create_test_app_with_acme().await;
+    let (_, account) = acme_request(
+        &app,
+        "/register",
+        "10.0.0.5",
+        &[],
+        json!({ "allowfrom": ["10.0.0.0/24"] }),
+    )
+    .await;
+    let subdomain = account["subdomain"].as_str().unwrap();
+    let username = account["username"].as_str().unwrap();
+    let password = account["password"].as_str().unwrap();
+    let auth = [("X-Api-User", username), ("X-Api-Key", password)];
+
+    let (status, body) = acme_request(
+        &app,
+        "/update",
+        "10.0.0.5",
+        &[("X-Api-User", username), ("X-Api-Key", "wrong")],
+        json!({ "subdomain": subdomain, "txt": ACME_TXT }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(body, json!({ "error": "forbidden" }));
+
+    let (status, body) = acme_request(
+        &app,
+        "/update",
+        "10.0.1.5",
+        &auth,
+        json!({ "subdomain": subdomain, "txt": ACME_TXT }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(body, json!({ "error": "forbidden" }));
+
+    let (status, body) = acme_request(
+        &app,
+        "/update",
+        "10.0.0.5",
+        &auth,
+        json!({ "subdomain": "nope", "txt": ACME_TXT }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body, json!({ "error": "bad_subdomain" }));
+
+    let (status, body) = acme_request(
+        &app,
+        "/update",
+        "10.0.0.5",
+        &auth,
+        json!({ "subdomain": subdomain, "txt": "too-short" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body, json!({ "error": "bad_txt" }));
+
+    assert!(served_txt(&table, &format!("{subdomain}.{ACME_ZONE}")).is_empty());
+}
+
+#[tokio::test]
+async fn test_acme_registrations_list_and_delete() {
+    let (app, _config, table) = create_test_app_with_acme().await;
+    let (_, account) = acme_request(&app, "/register", "10.0.0.5", &[], json!({})).await;
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/acme/registrations")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    let entries = json.as_array().unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["subdomain"], account["subdomain"]);
+    assert!(entries[0].get("password_hash").is_none());
+    let id = entries[0]["id"].as_i64().unwrap();
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("DELETE")
+                .uri(format!("/acme/registrations/{id}"))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert!(table.is_empty());
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri(format!("/acme/registrations/{id}"))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+// ── DNS-01 issuance ───────────────────────────────────────────────────────────
+
+const ACCOUNT_THUMBPRINT: &str = "NzbLsXh8uDCcd-6MNwXF4W_7noWXFZAfHkxZsRGC9Xs";
+
+struct NxInner;
+
+#[async_trait]
+impl DnsResolver for NxInner {
+    async fn resolve(&self, _query: &DnsQuery) -> Result<DnsResolution, DomainError> {
+        Err(DomainError::NxDomain)
+    }
+}
+
+/// Stands in for an ACME CA (RFC 8555) issuing through DNS-01. Every
+/// identifier of an order gets its own token; an authorization is valid
+/// once `_acme-challenge.<name>` resolves, through the given resolver, to a
+/// TXT record holding the key authorization digest (§8.4), and the order
+/// can only be finalized when every authorization is valid.
+struct StandInCa {
+    resolver: LocalAuthorityResolver,
+    authorizations: Vec<(String, String, bool)>,
+}
+
+impl StandInCa {
+    fn new_order(resolver: LocalAuthorityResolver, identifiers: &[&str]) -> Self {
+        let authorizations = identifiers
+            .iter()
+            .enumerate()
+            .map(|(i, identifier)| (identifier.to_string(), format!("token-{i}"), false))
+            .collect();
+        Self {
+            resolver,
+            authorizations,
+        }
+    }
+
+    /// What the ACME client publishes for `token`.
+    fn digest(token: &str) -> String {
+        let key_authorization = format!("{token}.{ACCOUNT_THUMBPRINT}");
+        let digest = ring::digest::digest(&ring::digest::SHA256, key_authorization.as_bytes());
+        base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(digest)
+    }
+
+    fn tokens(&self) -> Vec<String> {
+        self.authorizations
+            .iter()
+            .map(|(_, token, _)| token.clone())
+            .collect()
+    }
+
+    async fn validate(&mut self) {
+        for (identifier, token, valid) in &mut self.authorizations {
+            let name = format!("_acme-challenge.{}", identifier.trim_start_matches("*."));
+            let Ok(resolution) = self
+                .resolver
+                .resolve(&DnsQuery::new(name.as_str(), RecordType::TXT))
+                .await
+            else {
+                continue;
+            };
+            let message =
+                Message::from_vec(resolution.upstream_wire_data.as_ref().unwrap()).unwrap();
+            let expected = Self::digest(token);
+            *valid = message.answers().iter().any(|record| match record.data() {
+                RData::TXT(txt) => txt.to_string() == expected,
+                _ => false,
+            });
+        }
+    }
+
+    fn finalize(&self) -> Result<(), Vec<String>> {
+        let invalid: Vec<String> = self
+            .authorizations
+            .iter()
+            .filter(|(_, _, valid)| !valid)
+            .map(|(identifier, _, _)| identifier.clone())
+            .collect();
+        if invalid.is_empty() {
+            Ok(())
+        } else {
+            Err(invalid)
+        }
+    }
+}
+
+/// The acme-dns flow end to end: register, delegate the challenge name with
+/// a CNAME, publish the digests of a wildcard and a base name order through
+/// `/update` as an acme-dns client plugin does, and let the CA validate
+/// them over DNS.
+#[tokio::test]
+async fn test_acme_dns_dns01_issuance_end_to_end() {
+    let (app, _config, table) = create_test_app_with_acme().await;
+
+    let (status, account) = acme_request(&app, "/register", "10.0.0.5", &[], json!({})).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let fulldomain = account["fulldomain"].as_str().unwrap();
+
+    let home_zone = format!(
+        "$ORIGIN home.lan.\n\
+         $TTL 300\n\
+         @ IN SOA ns1 admin 2026101701 7200 3600 1209600 60\n\
+         @ IN NS ns1\n\
+         ns1 IN A 192.168.1.1\n\
+         _acme-challenge.nas IN CNAME {fulldomain}.\n"
+    );
+    let authority = LocalAuthority::new();
+    authority.replace_zones(&[parse_zone_text(&home_zone, None, "home.lan").unwrap()]);
+    let resolver = LocalAuthorityResolver::new(Arc::new(NxInner), Arc::new(authority))
+        .with_acme_challenges(table);
+
+    let mut ca = StandInCa::new_order(resolver, &["nas.home.lan", "*.nas.home.lan"]);
+    ca.validate().await;
+    assert_eq!(
+        ca.finalize(),
+        Err(vec![
+            "nas.home.lan".to_string(),
+            "*.nas.home.lan".to_string()
+        ])
+    );
+
+    for token in ca.tokens() {
+        let (status, _) = acme_request(
+            &app,
+            "/update",
+            "10.0.0.5",
+            &[
+                ("X-Api-User", account["username"].as_str().unwrap()),
+                ("X-Api-Key", account["password"].as_str().unwrap()),
+            ],
+            json!({
+                "subdomain": account["subdomain"],
+                "txt": StandInCa::digest(&token),
+            }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    ca.validate().await;
+    assert_eq!(ca.finalize(), Ok(()));
+}
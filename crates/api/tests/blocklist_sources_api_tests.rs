@@ -39,7 +39,14 @@ impl BlockFilterEnginePort for NullBlockFilterEngine {
     fn compiled_domain_count(&self) -> usize {
         0
     }
-    fn store_cname_decision(&self, _domain: &str, _group_id: i64, _ttl_secs: u64) {}
+    fn store_cname_decision(
+        &self,
+        _domain: &str,
+        _blocked_cname: &str,
+        _group_id: i64,
+        _ttl_secs: u64,
+    ) {
+    }
     fn is_blocking_enabled(&self) -> bool {
         true
     }
@@ -299,6 +306,10 @@ async fn create_test_db() -> sqlx::SqlitePool {
             enabled BOOLEAN NOT NULL DEFAULT 1,
             comment TEXT,
             is_default BOOLEAN NOT NULL DEFAULT 0,
+            blocking_mode TEXT,
+            blocking_ipv4 TEXT,
+            blocking_ipv6 TEXT,
+            upstream_pools TEXT,
             created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
             updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
         )
@@ -363,6 +374,7 @@ async fn create_test_db() -> sqlx::SqlitePool {
             group_id    INTEGER NOT NULL DEFAULT 1 REFERENCES groups(id) ON DELETE RESTRICT,
             comment     TEXT,
             enabled     BOOLEAN NOT NULL DEFAULT 1,
+            observe_only INTEGER NOT NULL DEFAULT 0,
             created_at  DATETIME DEFAULT CURRENT_TIMESTAMP,
             updated_at  DATETIME DEFAULT CURRENT_TIMESTAMP
         )
@@ -385,6 +397,61 @@ async fn create_test_db() -> sqlx::SqlitePool {
     .await
     .unwrap();
 
+    sqlx::query(
+        r#"
+        CREATE TABLE list_source_fetches (
+            url              TEXT    PRIMARY KEY,
+            etag             TEXT,
+            last_modified    TEXT,
+            content          TEXT,
+            last_attempt_at  TEXT    NOT NULL,
+            last_success_at  TEXT,
+            last_http_status INTEGER,
+            entry_count      INTEGER NOT NULL DEFAULT 0,
+            parse_errors     INTEGER NOT NULL DEFAULT 0,
+            last_error       TEXT
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE blocklist_source_hits (
+            source_id INTEGER NOT NULL,
+            hour      INTEGER NOT NULL,
+            domain    TEXT    NOT NULL,
+            hits      INTEGER NOT NULL DEFAULT 0,
+            PRIMARY KEY (source_id, hour, domain)
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE query_log (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            domain TEXT NOT NULL,
+            record_type TEXT NOT NULL DEFAULT 'A',
+            client_ip TEXT NOT NULL DEFAULT '127.0.0.1',
+            blocked INTEGER NOT NULL DEFAULT 0,
+            query_source TEXT NOT NULL DEFAULT 'client',
+            group_id INTEGER,
+            block_source TEXT,
+            blocklist_source_ids TEXT,
+            created_at DATETIME NOT NULL DEFAULT (datetime('now'))
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
     sqlx::query(
         r#"
         CREATE TABLE regex_filters (
@@ -458,6 +525,14 @@ async fn create_test_app() -> (Router, sqlx::SqlitePool) {
             .expect("Failed to create PoolManager"),
     );
 
+    let forwarding_rule_repo = Arc::new(
+        ferrous_dns_infrastructure::repositories::SqliteForwardingRuleRepository::new(pool.clone()),
+    );
+    let forwarding_rule_table =
+        Arc::new(ferrous_dns_infrastructure::dns::resolver::ForwardingRuleTable::new());
+    let local_zone_repo =
+        Arc::new(ferrous_dns_infrastructure::repositories::FileLocalZoneRepository::new(vec![]));
+    let local_authority = Arc::new(ferrous_dns_infrastructure::dns::LocalAuthority::new());
     let state = AppState {
         query: QueryUseCases {
             get_stats: Arc::new(GetQueryStatsUseCase::new(Arc::new(
@@ -481,21 +556,49 @@ async fn create_test_app() -> (Router, sqlx::SqlitePool) {
             get_top_clients: Arc::new(ferrous_dns_application::use_cases::GetTopClientsUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &DatabaseConfig::default()),
             ))),
+            query_stream: Arc::new(ferrous_dns_application::services::QueryStream::default()),
         },
         dns: DnsUseCases {
             cache: cache as Arc<dyn ferrous_dns_application::ports::DnsCachePort>,
             create_local_record: Arc::new(CreateLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))),
             update_local_record: Arc::new(UpdateLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))),
             delete_local_record: Arc::new(DeleteLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))),
+            apply_ddns_update: Arc::new(ferrous_dns_application::use_cases::ApplyDdnsUpdateUseCase::new(Arc::new(CreateLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))), Arc::new(ferrous_dns_application::use_cases::ValidateApiTokenUseCase::new(Arc::new(helpers::mock_auth::NullApiTokenRepository))), Arc::new(ferrous_dns_infrastructure::repositories::SqliteDdnsHistoryRepository::new(pool.clone())), &[])),
+            get_ddns_history: Arc::new(ferrous_dns_application::use_cases::GetDdnsHistoryUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteDdnsHistoryRepository::new(pool.clone())))),
             upstream_health: Arc::new(ferrous_dns_infrastructure::dns::UpstreamHealthAdapter::new(
                 pool_manager,
                 None,
             )),
+            get_forwarding_rules: Arc::new(ferrous_dns_application::use_cases::GetForwardingRulesUseCase::new(forwarding_rule_repo.clone())),
+            create_forwarding_rule: Arc::new(ferrous_dns_application::use_cases::CreateForwardingRuleUseCase::new(forwarding_rule_repo.clone(), forwarding_rule_table.clone())),
+            update_forwarding_rule: Arc::new(ferrous_dns_application::use_cases::UpdateForwardingRuleUseCase::new(forwarding_rule_repo.clone(), forwarding_rule_table.clone())),
+            delete_forwarding_rule: Arc::new(ferrous_dns_application::use_cases::DeleteForwardingRuleUseCase::new(forwarding_rule_repo.clone(), forwarding_rule_table.clone())),
+            get_local_zones: Arc::new(ferrous_dns_application::use_cases::GetLocalZonesUseCase::new(local_zone_repo.clone())),
+            create_zone_record: Arc::new(ferrous_dns_application::use_cases::CreateZoneRecordUseCase::new(local_zone_repo.clone(), local_authority.clone())),
+            update_zone_record: Arc::new(ferrous_dns_application::use_cases::UpdateZoneRecordUseCase::new(local_zone_repo.clone(), local_authority.clone())),
+            delete_zone_record: Arc::new(ferrous_dns_application::use_cases::DeleteZoneRecordUseCase::new(local_zone_repo.clone(), local_authority.clone())),
+            get_trust_anchors: Arc::new(ferrous_dns_application::use_cases::GetTrustAnchorsUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteTrustAnchorRepository::new(pool.clone())))),
+            get_negative_trust_anchors: Arc::new(ferrous_dns_application::use_cases::GetNegativeTrustAnchorsUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteNegativeTrustAnchorRepository::new(pool.clone())))),
+            create_negative_trust_anchor: Arc::new(ferrous_dns_application::use_cases::CreateNegativeTrustAnchorUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteNegativeTrustAnchorRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::dnssec::NegativeTrustAnchorTable::new()))),
+            delete_negative_trust_anchor: Arc::new(ferrous_dns_application::use_cases::DeleteNegativeTrustAnchorUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteNegativeTrustAnchorRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::dnssec::NegativeTrustAnchorTable::new()))),
+            get_tsig_keys: Arc::new(ferrous_dns_application::use_cases::GetTsigKeysUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteTsigKeyRepository::new(pool.clone())))),
+            create_tsig_key: Arc::new(ferrous_dns_application::use_cases::CreateTsigKeyUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteTsigKeyRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::TsigKeyTable::new()))),
+            delete_tsig_key: Arc::new(ferrous_dns_application::use_cases::DeleteTsigKeyUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteTsigKeyRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::TsigKeyTable::new()))),
+            get_acme_registrations: Arc::new(ferrous_dns_application::use_cases::GetAcmeRegistrationsUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteAcmeRegistrationRepository::new(pool.clone())))),
+            register_acme_account: Arc::new(ferrous_dns_application::use_cases::RegisterAcmeAccountUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteAcmeRegistrationRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::AcmeChallengeTable::disabled()), "", Vec::new())),
+            update_acme_challenge: Arc::new(ferrous_dns_application::use_cases::UpdateAcmeChallengeUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteAcmeRegistrationRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::AcmeChallengeTable::disabled()))),
+            delete_acme_registration: Arc::new(ferrous_dns_application::use_cases::DeleteAcmeRegistrationUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteAcmeRegistrationRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::AcmeChallengeTable::disabled()))),
+            get_dns_rewrite_rules: Arc::new(ferrous_dns_application::use_cases::GetDnsRewriteRulesUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteDnsRewriteRuleRepository::new(pool.clone())))),
+            create_dns_rewrite_rule: Arc::new(ferrous_dns_application::use_cases::CreateDnsRewriteRuleUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteDnsRewriteRuleRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::repositories::SqliteGroupRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::DnsRewriteTable::new()))),
+            update_dns_rewrite_rule: Arc::new(ferrous_dns_application::use_cases::UpdateDnsRewriteRuleUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteDnsRewriteRuleRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::repositories::SqliteGroupRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::DnsRewriteTable::new()))),
+            delete_dns_rewrite_rule: Arc::new(ferrous_dns_application::use_cases::DeleteDnsRewriteRuleUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteDnsRewriteRuleRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::DnsRewriteTable::new()))),
         },
         groups: GroupUseCases {
             get_groups: Arc::new(GetGroupsUseCase::new(group_repo.clone())),
             create_group: Arc::new(CreateGroupUseCase::new(group_repo.clone())),
             update_group: Arc::new(UpdateGroupUseCase::new(group_repo.clone())),
+            update_group_blocking_mode: Arc::new(UpdateGroupBlockingModeUseCase::new(group_repo.clone(), Arc::new(NullBlockFilterEngine))),
+            update_group_upstream_pools: Arc::new(ferrous_dns_application::use_cases::UpdateGroupUpstreamPoolsUseCase::new(group_repo.clone(), Arc::new(ferrous_dns_infrastructure::dns::GroupUpstreamTable::new()), config.clone())),
             delete_group: Arc::new(DeleteGroupUseCase::new(group_repo.clone())),
             assign_client_group: Arc::new(AssignClientGroupUseCase::new(client_repo.clone(), group_repo.clone(), Arc::new(NullBlockFilterEngine))),
         },
@@ -577,6 +680,9 @@ async fn create_test_app() -> (Router, sqlx::SqlitePool) {
                 Arc::new(NullBlockFilterEngine),
             )),
             get_block_filter_stats: Arc::new(GetBlockFilterStatsUseCase::new(Arc::new(NullBlockFilterEngine))),
+            explain_filter_decision: Arc::new(ferrous_dns_application::use_cases::ExplainFilterDecisionUseCase::new(Arc::new(NullBlockFilterEngine), Arc::new(ferrous_dns_infrastructure::schedule::ScheduleStateStore::new()), Arc::new(NullSafeSearchEnginePort), Arc::new(ferrous_dns_infrastructure::repositories::SqliteGroupRepository::new(pool.clone())))),
+            get_blocklist_source_hits: Arc::new(ferrous_dns_application::use_cases::GetBlocklistSourceHitsUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &ferrous_dns_domain::config::DatabaseConfig::default())))),
+            simulate_blocklist: Arc::new(ferrous_dns_application::use_cases::SimulateBlocklistUseCase::new(Arc::new(ferrous_dns_infrastructure::dns::SqliteBlocklistSimulator::new(pool.clone(), Arc::new(arc_swap::ArcSwap::from_pointee(ferrous_dns_infrastructure::dns::block_filter::BlockIndex::empty()))).unwrap()), Arc::new(ferrous_dns_infrastructure::repositories::group_repository::SqliteGroupRepository::new(pool.clone())))),
         },
         services: ServiceUseCases {
             get_service_catalog: Arc::new(GetServiceCatalogUseCase::new(Arc::new(NullServiceCatalog))),
@@ -628,6 +734,7 @@ async fn create_test_app() -> (Router, sqlx::SqlitePool) {
         config_file_persistence: Arc::new(ferrous_dns_infrastructure::repositories::TomlConfigFilePersistence),
         config_path: None,
         tls_cert: Arc::new(helpers::MockTlsCertificateService),
+        tls_reloader: Arc::new(helpers::MockTlsCertificateReloader),
         tls_enabled: false,
     };
 
@@ -722,9 +829,37 @@ async fn test_create_source_defaults() {
     assert_eq!(json["name"], "Minimal List");
     assert_eq!(json["group_ids"][0], 1);
     assert_eq!(json["enabled"], true);
+    assert_eq!(json["observe_only"], false);
     assert!(json["url"].is_null());
 }
 
+#[tokio::test]
+async fn test_create_observe_only_source() {
+    let (app, _pool) = create_test_app().await;
+
+    let payload = json!({ "name": "Trial List", "observe_only": true });
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/blocklist-sources")
+                .method("POST")
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_string(&payload).unwrap()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(json["observe_only"], true);
+    assert_eq!(json["enabled"], true);
+}
+
 #[tokio::test]
 async fn test_create_source_duplicate_name() {
     let (app, _pool) = create_test_app().await;
@@ -1040,3 +1175,189 @@ async fn test_get_all_sources_after_create() {
     assert!(json.is_array());
     assert_eq!(json.as_array().unwrap().len(), 2);
 }
+
+async fn create_source(app: &Router, name: &str) -> i64 {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/blocklist-sources")
+                .method("POST")
+                .header("content-type", "application/json")
+                .body(Body::from(json!({ "name": name }).to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let created: Value = serde_json::from_slice(&body).unwrap();
+    created["id"].as_i64().unwrap()
+}
+
+async fn insert_hits(
+    pool: &sqlx::SqlitePool,
+    source_id: i64,
+    hours_ago: i64,
+    domain: &str,
+    hits: i64,
+) {
+    let hour = chrono::Utc::now().timestamp() / 3600 - hours_ago;
+    sqlx::query(
+        "INSERT INTO blocklist_source_hits (source_id, hour, domain, hits) VALUES (?, ?, ?, ?)",
+    )
+    .bind(source_id)
+    .bind(hour)
+    .bind(domain)
+    .bind(hits)
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+async fn get_hits(app: &Router, uri: &str) -> Vec<Value> {
+    let response = app
+        .clone()
+        .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    json.as_array().unwrap().clone()
+}
+
+#[tokio::test]
+async fn test_source_hits_per_window() {
+    let (app, pool) = create_test_app().await;
+    let busy = create_source(&app, "Busy").await;
+    let idle = create_source(&app, "Idle").await;
+
+    insert_hits(&pool, busy, 0, "ads.example", 5).await;
+    insert_hits(&pool, busy, 2, "ads.example", 1).await;
+    insert_hits(&pool, busy, 48, "tracker.example", 3).await;
+    insert_hits(&pool, busy, 20 * 24, "old.example", 2).await;
+    insert_hits(&pool, busy, 40 * 24, "expired.example", 100).await;
+
+    let hits = get_hits(&app, "/blocklist-sources/hits").await;
+    assert_eq!(hits.len(), 2);
+
+    let busy_hits = &hits[0];
+    assert_eq!(busy_hits["id"], busy);
+    assert_eq!(busy_hits["name"], "Busy");
+    assert_eq!(busy_hits["hits_24h"], 6);
+    assert_eq!(busy_hits["hits_7d"], 9);
+    assert_eq!(busy_hits["hits_30d"], 11);
+    assert_eq!(busy_hits["unique_domains_24h"], 1);
+    assert_eq!(busy_hits["unique_domains_7d"], 2);
+    assert_eq!(busy_hits["unique_domains_30d"], 3);
+
+    let idle_hits = &hits[1];
+    assert_eq!(idle_hits["id"], idle);
+    assert_eq!(idle_hits["hits_30d"], 0);
+    assert_eq!(idle_hits["unique_domains_30d"], 0);
+}
+
+#[tokio::test]
+async fn test_zero_hit_sources() {
+    let (app, pool) = create_test_app().await;
+    let busy = create_source(&app, "Busy").await;
+    let idle = create_source(&app, "Idle").await;
+    let stale = create_source(&app, "Stale").await;
+
+    insert_hits(&pool, busy, 1, "ads.example", 1).await;
+    insert_hits(&pool, stale, 31 * 24, "ads.example", 7).await;
+
+    let hits = get_hits(&app, "/blocklist-sources/hits?zero_hits=true").await;
+    let ids: Vec<i64> = hits.iter().map(|h| h["id"].as_i64().unwrap()).collect();
+    assert_eq!(ids, vec![idle, stale]);
+}
+
+async fn simulate(app: &Router, payload: Value) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/blocklist-sources/simulate")
+                .method("POST")
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn test_simulate_uploaded_list() {
+    let (app, pool) = create_test_app().await;
+    for (domain, client_ip) in [
+        ("ads.example", "192.168.1.10"),
+        ("ads.example", "192.168.1.10"),
+        ("ads.example", "192.168.1.11"),
+        ("news.example", "192.168.1.10"),
+    ] {
+        sqlx::query("INSERT INTO query_log (domain, client_ip, group_id) VALUES (?, ?, 1)")
+            .bind(domain)
+            .bind(client_ip)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+
+    let (status, json) = simulate(&app, json!({ "text": "||ads.example^\n" })).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["entry_count"], 1);
+    assert_eq!(json["queries_examined"], 4);
+    assert_eq!(json["newly_blocked"]["queries"], 3);
+    assert_eq!(json["newly_blocked"]["domains"][0]["domain"], "ads.example");
+    assert_eq!(json["newly_blocked"]["domains"][0]["clients"], 2);
+    assert_eq!(
+        json["newly_blocked"]["clients"][0]["client"],
+        "192.168.1.10"
+    );
+    assert_eq!(json["newly_blocked"]["clients"][0]["queries"], 2);
+    assert_eq!(json["newly_unblocked"]["queries"], 0);
+}
+
+#[tokio::test]
+async fn test_simulate_requires_exactly_one_list() {
+    let (app, _pool) = create_test_app().await;
+
+    let (status, _) = simulate(&app, json!({})).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, _) = simulate(
+        &app,
+        json!({ "text": "ads.example", "url": "https://lists.example/ads.txt" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_simulate_rejects_invalid_time_range() {
+    let (app, _pool) = create_test_app().await;
+
+    let (status, _) = simulate(
+        &app,
+        json!({ "text": "ads.example", "from": "2026-03-02T00:00:00Z", "to": "2026-03-01T00:00:00Z" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, _) = simulate(&app, json!({ "text": "ads.example", "from": "yesterday" })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_simulate_unknown_group() {
+    let (app, _pool) = create_test_app().await;
+
+    let (status, _) = simulate(&app, json!({ "text": "ads.example", "group_ids": [99] })).await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
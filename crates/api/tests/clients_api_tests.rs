@@ -42,7 +42,14 @@ impl BlockFilterEnginePort for NullBlockFilterEngine {
     fn compiled_domain_count(&self) -> usize {
         0
     }
-    fn store_cname_decision(&self, _domain: &str, _group_id: i64, _ttl_secs: u64) {}
+    fn store_cname_decision(
+        &self,
+        _domain: &str,
+        _blocked_cname: &str,
+        _group_id: i64,
+        _ttl_secs: u64,
+    ) {
+    }
     fn is_blocking_enabled(&self) -> bool {
         true
     }
@@ -300,6 +307,10 @@ async fn create_test_db() -> sqlx::SqlitePool {
             enabled BOOLEAN NOT NULL DEFAULT 1,
             comment TEXT,
             is_default BOOLEAN NOT NULL DEFAULT 0,
+            blocking_mode TEXT,
+            blocking_ipv4 TEXT,
+            blocking_ipv6 TEXT,
+            upstream_pools TEXT,
             created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
             updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
         )
@@ -416,6 +427,14 @@ async fn create_test_app() -> (Router, Arc<SqliteClientRepository>, sqlx::Sqlite
             .expect("Failed to create PoolManager"),
     );
 
+    let forwarding_rule_repo = Arc::new(
+        ferrous_dns_infrastructure::repositories::SqliteForwardingRuleRepository::new(pool.clone()),
+    );
+    let forwarding_rule_table =
+        Arc::new(ferrous_dns_infrastructure::dns::resolver::ForwardingRuleTable::new());
+    let local_zone_repo =
+        Arc::new(ferrous_dns_infrastructure::repositories::FileLocalZoneRepository::new(vec![]));
+    let local_authority = Arc::new(ferrous_dns_infrastructure::dns::LocalAuthority::new());
     let state = AppState {
         query: QueryUseCases {
             get_stats: Arc::new(GetQueryStatsUseCase::new(Arc::new(
@@ -443,16 +462,42 @@ async fn create_test_app() -> (Router, Arc<SqliteClientRepository>, sqlx::Sqlite
             get_top_clients: Arc::new(ferrous_dns_application::use_cases::GetTopClientsUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &DatabaseConfig::default()),
             ))),
+            query_stream: Arc::new(ferrous_dns_application::services::QueryStream::default()),
         },
         dns: DnsUseCases {
             cache: cache as Arc<dyn ferrous_dns_application::ports::DnsCachePort>,
             create_local_record: Arc::new(CreateLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))),
             update_local_record: Arc::new(UpdateLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))),
             delete_local_record: Arc::new(DeleteLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))),
+            apply_ddns_update: Arc::new(ferrous_dns_application::use_cases::ApplyDdnsUpdateUseCase::new(Arc::new(CreateLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))), Arc::new(ferrous_dns_application::use_cases::ValidateApiTokenUseCase::new(Arc::new(helpers::mock_auth::NullApiTokenRepository))), Arc::new(ferrous_dns_infrastructure::repositories::SqliteDdnsHistoryRepository::new(pool.clone())), &[])),
+            get_ddns_history: Arc::new(ferrous_dns_application::use_cases::GetDdnsHistoryUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteDdnsHistoryRepository::new(pool.clone())))),
             upstream_health: Arc::new(ferrous_dns_infrastructure::dns::UpstreamHealthAdapter::new(
                 pool_manager,
                 None,
             )),
+            get_forwarding_rules: Arc::new(ferrous_dns_application::use_cases::GetForwardingRulesUseCase::new(forwarding_rule_repo.clone())),
+            create_forwarding_rule: Arc::new(ferrous_dns_application::use_cases::CreateForwardingRuleUseCase::new(forwarding_rule_repo.clone(), forwarding_rule_table.clone())),
+            update_forwarding_rule: Arc::new(ferrous_dns_application::use_cases::UpdateForwardingRuleUseCase::new(forwarding_rule_repo.clone(), forwarding_rule_table.clone())),
+            delete_forwarding_rule: Arc::new(ferrous_dns_application::use_cases::DeleteForwardingRuleUseCase::new(forwarding_rule_repo.clone(), forwarding_rule_table.clone())),
+            get_local_zones: Arc::new(ferrous_dns_application::use_cases::GetLocalZonesUseCase::new(local_zone_repo.clone())),
+            create_zone_record: Arc::new(ferrous_dns_application::use_cases::CreateZoneRecordUseCase::new(local_zone_repo.clone(), local_authority.clone())),
+            update_zone_record: Arc::new(ferrous_dns_application::use_cases::UpdateZoneRecordUseCase::new(local_zone_repo.clone(), local_authority.clone())),
+            delete_zone_record: Arc::new(ferrous_dns_application::use_cases::DeleteZoneRecordUseCase::new(local_zone_repo.clone(), local_authority.clone())),
+            get_trust_anchors: Arc::new(ferrous_dns_application::use_cases::GetTrustAnchorsUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteTrustAnchorRepository::new(pool.clone())))),
+            get_negative_trust_anchors: Arc::new(ferrous_dns_application::use_cases::GetNegativeTrustAnchorsUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteNegativeTrustAnchorRepository::new(pool.clone())))),
+            create_negative_trust_anchor: Arc::new(ferrous_dns_application::use_cases::CreateNegativeTrustAnchorUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteNegativeTrustAnchorRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::dnssec::NegativeTrustAnchorTable::new()))),
+            delete_negative_trust_anchor: Arc::new(ferrous_dns_application::use_cases::DeleteNegativeTrustAnchorUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteNegativeTrustAnchorRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::dnssec::NegativeTrustAnchorTable::new()))),
+            get_tsig_keys: Arc::new(ferrous_dns_application::use_cases::GetTsigKeysUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteTsigKeyRepository::new(pool.clone())))),
+            create_tsig_key: Arc::new(ferrous_dns_application::use_cases::CreateTsigKeyUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteTsigKeyRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::TsigKeyTable::new()))),
+            delete_tsig_key: Arc::new(ferrous_dns_application::use_cases::DeleteTsigKeyUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteTsigKeyRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::TsigKeyTable::new()))),
+            get_acme_registrations: Arc::new(ferrous_dns_application::use_cases::GetAcmeRegistrationsUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteAcmeRegistrationRepository::new(pool.clone())))),
+            register_acme_account: Arc::new(ferrous_dns_application::use_cases::RegisterAcmeAccountUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteAcmeRegistrationRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::AcmeChallengeTable::disabled()), "", Vec::new())),
+            update_acme_challenge: Arc::new(ferrous_dns_application::use_cases::UpdateAcmeChallengeUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteAcmeRegistrationRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::AcmeChallengeTable::disabled()))),
+            delete_acme_registration: Arc::new(ferrous_dns_application::use_cases::DeleteAcmeRegistrationUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteAcmeRegistrationRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::AcmeChallengeTable::disabled()))),
+            get_dns_rewrite_rules: Arc::new(ferrous_dns_application::use_cases::GetDnsRewriteRulesUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteDnsRewriteRuleRepository::new(pool.clone())))),
+            create_dns_rewrite_rule: Arc::new(ferrous_dns_application::use_cases::CreateDnsRewriteRuleUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteDnsRewriteRuleRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::repositories::SqliteGroupRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::DnsRewriteTable::new()))),
+            update_dns_rewrite_rule: Arc::new(ferrous_dns_application::use_cases::UpdateDnsRewriteRuleUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteDnsRewriteRuleRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::repositories::SqliteGroupRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::DnsRewriteTable::new()))),
+            delete_dns_rewrite_rule: Arc::new(ferrous_dns_application::use_cases::DeleteDnsRewriteRuleUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteDnsRewriteRuleRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::DnsRewriteTable::new()))),
         },
         groups: GroupUseCases {
             get_groups: Arc::new(ferrous_dns_application::use_cases::GetGroupsUseCase::new(Arc::new(
@@ -464,6 +509,11 @@ async fn create_test_app() -> (Router, Arc<SqliteClientRepository>, sqlx::Sqlite
             update_group: Arc::new(ferrous_dns_application::use_cases::UpdateGroupUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::group_repository::SqliteGroupRepository::new(pool.clone()),
             ))),
+            update_group_blocking_mode: Arc::new(ferrous_dns_application::use_cases::UpdateGroupBlockingModeUseCase::new(
+                Arc::new(ferrous_dns_infrastructure::repositories::group_repository::SqliteGroupRepository::new(pool.clone())),
+                Arc::new(NullBlockFilterEngine),
+            )),
+            update_group_upstream_pools: Arc::new(ferrous_dns_application::use_cases::UpdateGroupUpstreamPoolsUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::group_repository::SqliteGroupRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::GroupUpstreamTable::new()), config.clone())),
             delete_group: Arc::new(ferrous_dns_application::use_cases::DeleteGroupUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::group_repository::SqliteGroupRepository::new(pool.clone()),
             ))),
@@ -567,6 +617,9 @@ async fn create_test_app() -> (Router, Arc<SqliteClientRepository>, sqlx::Sqlite
                 Arc::new(NullBlockFilterEngine),
             )),
             get_block_filter_stats: Arc::new(GetBlockFilterStatsUseCase::new(Arc::new(NullBlockFilterEngine))),
+            explain_filter_decision: Arc::new(ferrous_dns_application::use_cases::ExplainFilterDecisionUseCase::new(Arc::new(NullBlockFilterEngine), Arc::new(ferrous_dns_infrastructure::schedule::ScheduleStateStore::new()), Arc::new(NullSafeSearchEnginePort), Arc::new(ferrous_dns_infrastructure::repositories::SqliteGroupRepository::new(pool.clone())))),
+            get_blocklist_source_hits: Arc::new(ferrous_dns_application::use_cases::GetBlocklistSourceHitsUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &ferrous_dns_domain::config::DatabaseConfig::default())))),
+            simulate_blocklist: Arc::new(ferrous_dns_application::use_cases::SimulateBlocklistUseCase::new(Arc::new(ferrous_dns_infrastructure::dns::SqliteBlocklistSimulator::new(pool.clone(), Arc::new(arc_swap::ArcSwap::from_pointee(ferrous_dns_infrastructure::dns::block_filter::BlockIndex::empty()))).unwrap()), Arc::new(ferrous_dns_infrastructure::repositories::group_repository::SqliteGroupRepository::new(pool.clone())))),
         },
         services: ServiceUseCases {
             get_service_catalog: Arc::new(ferrous_dns_application::use_cases::GetServiceCatalogUseCase::new(Arc::new(NullServiceCatalog))),
@@ -618,6 +671,7 @@ async fn create_test_app() -> (Router, Arc<SqliteClientRepository>, sqlx::Sqlite
         config_file_persistence: Arc::new(ferrous_dns_infrastructure::repositories::TomlConfigFilePersistence),
         config_path: None,
         tls_cert: Arc::new(helpers::MockTlsCertificateService),
+        tls_reloader: Arc::new(helpers::MockTlsCertificateReloader),
         tls_enabled: false,
     };
 
@@ -41,6 +41,22 @@ impl GroupRepository for NullGroupRepository {
     ) -> Result<Group, DomainError> {
         Err(DomainError::IoError("test stub".to_string()))
     }
+    async fn update_blocking_mode(
+        &self,
+        _id: i64,
+        _mode: Option<ferrous_dns_domain::BlockingMode>,
+        _ipv4: Option<std::net::Ipv4Addr>,
+        _ipv6: Option<std::net::Ipv6Addr>,
+    ) -> Result<Group, DomainError> {
+        Err(DomainError::IoError("test stub".to_string()))
+    }
+    async fn update_upstream_pools(
+        &self,
+        _id: i64,
+        _pools: &[String],
+    ) -> Result<Group, DomainError> {
+        Err(DomainError::IoError("test stub".to_string()))
+    }
     async fn delete(&self, _id: i64) -> Result<(), DomainError> {
         Ok(())
     }
@@ -63,6 +79,7 @@ impl BlocklistSourceRepository for NullBlocklistSourceRepository {
         _group_ids: Vec<i64>,
         _comment: Option<String>,
         _enabled: bool,
+        _observe_only: bool,
     ) -> Result<BlocklistSource, DomainError> {
         Err(DomainError::IoError("test stub".to_string()))
     }
@@ -80,6 +97,7 @@ impl BlocklistSourceRepository for NullBlocklistSourceRepository {
         _group_ids: Option<Vec<i64>>,
         _comment: Option<String>,
         _enabled: Option<bool>,
+        _observe_only: Option<bool>,
     ) -> Result<BlocklistSource, DomainError> {
         Err(DomainError::IoError("test stub".to_string()))
     }
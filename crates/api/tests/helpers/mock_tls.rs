@@ -1,4 +1,6 @@
-use ferrous_dns_application::ports::{TlsCertificateInfo, TlsCertificatePort};
+use ferrous_dns_application::ports::{
+    TlsCertificateInfo, TlsCertificatePort, TlsCertificateReloader,
+};
 use ferrous_dns_domain::DomainError;
 
 pub struct MockTlsCertificateService;
@@ -12,6 +14,9 @@ impl TlsCertificatePort for MockTlsCertificateService {
             cert_subject: None,
             cert_not_after: None,
             cert_valid: false,
+            cert_not_after_unix: None,
+            cert_dns_names: Vec::new(),
+            cert_fingerprint_sha256: None,
         }
     }
 
@@ -33,3 +38,19 @@ impl TlsCertificatePort for MockTlsCertificateService {
         Ok(())
     }
 }
+
+pub struct MockTlsCertificateReloader;
+
+impl TlsCertificateReloader for MockTlsCertificateReloader {
+    fn reload(&self, _cert_path: &str, _key_path: &str) -> Result<bool, DomainError> {
+        Ok(false)
+    }
+
+    fn reload_changed(&self) -> usize {
+        0
+    }
+
+    fn active_fingerprint(&self, _cert_path: &str, _key_path: &str) -> Option<String> {
+        None
+    }
+}
@@ -5,4 +5,4 @@ pub mod mock_tls;
 
 pub use mock_auth::build_test_auth_use_cases;
 pub use mock_backup::build_test_backup_use_cases;
-pub use mock_tls::MockTlsCertificateService;
+pub use mock_tls::{MockTlsCertificateReloader, MockTlsCertificateService};
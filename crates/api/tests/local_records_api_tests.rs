@@ -4,8 +4,9 @@ use axum::{
     Router,
 };
 use ferrous_dns_api::{
-    create_api_routes, AppState, BlockingUseCases, ClientUseCases, DnsUseCases, GroupUseCases,
-    QueryUseCases, SafeSearchUseCases, ScheduleUseCases, ServiceUseCases,
+    create_acme_dns_routes, create_api_routes, create_ddns_routes, AppState, BlockingUseCases,
+    ClientUseCases, DnsUseCases, GroupUseCases, QueryUseCases, SafeSearchUseCases,
+    ScheduleUseCases, ServiceUseCases,
 };
 use ferrous_dns_application::{
     ports::{
@@ -21,13 +22,14 @@ use ferrous_dns_application::{
 };
 use ferrous_dns_domain::{config::DatabaseConfig, Config, LocalDnsRecord};
 use ferrous_dns_infrastructure::{
-    dns::cache::DnsCache,
+    dns::{cache::DnsCache, AcmeChallengeTable},
     repositories::{
         client_repository::SqliteClientRepository,
         client_subnet_repository::SqliteClientSubnetRepository,
         group_repository::SqliteGroupRepository,
         managed_domain_repository::SqliteManagedDomainRepository,
-        regex_filter_repository::SqliteRegexFilterRepository,
+        regex_filter_repository::SqliteRegexFilterRepository, SqliteAcmeRegistrationRepository,
+        SqliteApiTokenRepository, SqliteDdnsHistoryRepository,
     },
 };
 use http_body_util::BodyExt;
@@ -58,7 +60,14 @@ impl BlockFilterEnginePort for NullBlockFilterEngine {
     fn compiled_domain_count(&self) -> usize {
         0
     }
-    fn store_cname_decision(&self, _domain: &str, _group_id: i64, _ttl_secs: u64) {}
+    fn store_cname_decision(
+        &self,
+        _domain: &str,
+        _blocked_cname: &str,
+        _group_id: i64,
+        _ttl_secs: u64,
+    ) {
+    }
     fn is_blocking_enabled(&self) -> bool {
         true
     }
@@ -298,6 +307,10 @@ async fn create_test_db() -> sqlx::SqlitePool {
             enabled BOOLEAN NOT NULL DEFAULT 1,
             comment TEXT,
             is_default BOOLEAN NOT NULL DEFAULT 0,
+            blocking_mode TEXT,
+            blocking_ipv4 TEXT,
+            blocking_ipv6 TEXT,
+            upstream_pools TEXT,
             created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
             updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
         )
@@ -392,12 +405,102 @@ async fn create_test_db() -> sqlx::SqlitePool {
     .await
     .unwrap();
 
+    sqlx::query(
+        r#"
+        CREATE TABLE api_tokens (
+            id           INTEGER PRIMARY KEY AUTOINCREMENT,
+            name         TEXT    NOT NULL UNIQUE,
+            key_prefix   TEXT    NOT NULL,
+            key_hash     TEXT    NOT NULL,
+            created_at   TEXT    NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%S', 'now')),
+            last_used_at TEXT,
+            key_raw      TEXT
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE ddns_updates (
+            id          INTEGER PRIMARY KEY AUTOINCREMENT,
+            hostname    TEXT    NOT NULL,
+            ip          TEXT,
+            result      TEXT    NOT NULL,
+            token_id    INTEGER,
+            client_ip   TEXT,
+            created_at  TEXT    NOT NULL
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE tsig_keys (
+            id          INTEGER PRIMARY KEY AUTOINCREMENT,
+            name        TEXT    NOT NULL UNIQUE,
+            algorithm   TEXT    NOT NULL,
+            secret      TEXT    NOT NULL,
+            created_at  TEXT    NOT NULL
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE acme_registrations (
+            id             INTEGER PRIMARY KEY AUTOINCREMENT,
+            username       TEXT    NOT NULL UNIQUE,
+            password_hash  TEXT    NOT NULL,
+            subdomain      TEXT    NOT NULL UNIQUE,
+            allow_from     TEXT    NOT NULL DEFAULT '[]',
+            txt            TEXT    NOT NULL DEFAULT '[]',
+            created_at     TEXT    NOT NULL,
+            updated_at     TEXT    NOT NULL
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
     pool
 }
 
+const DDNS_TOKEN: &str = "ddns-test-token";
+
+const ACME_ZONE: &str = "acme.example.com";
+
 async fn create_test_app() -> (Router, Arc<RwLock<Config>>) {
+    let (app, config, _acme_challenges) = create_test_app_with_acme().await;
+    (app, config)
+}
+
+async fn create_test_app_with_acme() -> (Router, Arc<RwLock<Config>>, Arc<AcmeChallengeTable>) {
     let pool = create_test_db().await;
 
+    let acme_registration_repo = Arc::new(SqliteAcmeRegistrationRepository::new(pool.clone()));
+    let acme_challenges = Arc::new(AcmeChallengeTable::new(
+        ACME_ZONE,
+        &format!("ns.{ACME_ZONE}"),
+        10,
+    ));
+
+    let api_token_repo = Arc::new(SqliteApiTokenRepository::new(Arc::new(pool.clone())));
+    CreateApiTokenUseCase::new(api_token_repo.clone())
+        .execute("router", Some(DDNS_TOKEN))
+        .await
+        .unwrap();
+    let ddns_history_repo = Arc::new(SqliteDdnsHistoryRepository::new(pool.clone()));
+
     let client_repo = Arc::new(SqliteClientRepository::new(
         pool.clone(),
         &DatabaseConfig::default(),
@@ -447,6 +550,14 @@ async fn create_test_app() -> (Router, Arc<RwLock<Config>>) {
             .expect("Failed to create PoolManager"),
     );
 
+    let forwarding_rule_repo = Arc::new(
+        ferrous_dns_infrastructure::repositories::SqliteForwardingRuleRepository::new(pool.clone()),
+    );
+    let forwarding_rule_table =
+        Arc::new(ferrous_dns_infrastructure::dns::resolver::ForwardingRuleTable::new());
+    let local_zone_repo =
+        Arc::new(ferrous_dns_infrastructure::repositories::FileLocalZoneRepository::new(vec![]));
+    let local_authority = Arc::new(ferrous_dns_infrastructure::dns::LocalAuthority::new());
     let state = AppState {
         query: QueryUseCases {
             get_stats: Arc::new(GetQueryStatsUseCase::new(Arc::new(
@@ -470,21 +581,54 @@ async fn create_test_app() -> (Router, Arc<RwLock<Config>>) {
             get_top_clients: Arc::new(ferrous_dns_application::use_cases::GetTopClientsUseCase::new(Arc::new(
                 ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &DatabaseConfig::default()),
             ))),
+            query_stream: Arc::new(ferrous_dns_application::services::QueryStream::default()),
         },
         dns: DnsUseCases {
             cache: cache as Arc<dyn ferrous_dns_application::ports::DnsCachePort>,
             create_local_record: Arc::new(CreateLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))),
             update_local_record: Arc::new(UpdateLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))),
             delete_local_record: Arc::new(DeleteLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))),
+            apply_ddns_update: Arc::new(ApplyDdnsUpdateUseCase::new(
+                Arc::new(CreateLocalRecordUseCase::new(config.clone(), Arc::new(NullConfigRepository))),
+                Arc::new(ValidateApiTokenUseCase::new(api_token_repo)),
+                ddns_history_repo.clone(),
+                &["home.lan".to_string()],
+            )),
+            get_ddns_history: Arc::new(GetDdnsHistoryUseCase::new(ddns_history_repo)),
             upstream_health: Arc::new(ferrous_dns_infrastructure::dns::UpstreamHealthAdapter::new(
                 pool_manager,
                 None,
             )),
+            get_forwarding_rules: Arc::new(ferrous_dns_application::use_cases::GetForwardingRulesUseCase::new(forwarding_rule_repo.clone())),
+            create_forwarding_rule: Arc::new(ferrous_dns_application::use_cases::CreateForwardingRuleUseCase::new(forwarding_rule_repo.clone(), forwarding_rule_table.clone())),
+            update_forwarding_rule: Arc::new(ferrous_dns_application::use_cases::UpdateForwardingRuleUseCase::new(forwarding_rule_repo.clone(), forwarding_rule_table.clone())),
+            delete_forwarding_rule: Arc::new(ferrous_dns_application::use_cases::DeleteForwardingRuleUseCase::new(forwarding_rule_repo.clone(), forwarding_rule_table.clone())),
+            get_local_zones: Arc::new(ferrous_dns_application::use_cases::GetLocalZonesUseCase::new(local_zone_repo.clone())),
+            create_zone_record: Arc::new(ferrous_dns_application::use_cases::CreateZoneRecordUseCase::new(local_zone_repo.clone(), local_authority.clone())),
+            update_zone_record: Arc::new(ferrous_dns_application::use_cases::UpdateZoneRecordUseCase::new(local_zone_repo.clone(), local_authority.clone())),
+            delete_zone_record: Arc::new(ferrous_dns_application::use_cases::DeleteZoneRecordUseCase::new(local_zone_repo.clone(), local_authority.clone())),
+            get_trust_anchors: Arc::new(ferrous_dns_application::use_cases::GetTrustAnchorsUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteTrustAnchorRepository::new(pool.clone())))),
+            get_negative_trust_anchors: Arc::new(ferrous_dns_application::use_cases::GetNegativeTrustAnchorsUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteNegativeTrustAnchorRepository::new(pool.clone())))),
+            create_negative_trust_anchor: Arc::new(ferrous_dns_application::use_cases::CreateNegativeTrustAnchorUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteNegativeTrustAnchorRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::dnssec::NegativeTrustAnchorTable::new()))),
+            delete_negative_trust_anchor: Arc::new(ferrous_dns_application::use_cases::DeleteNegativeTrustAnchorUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteNegativeTrustAnchorRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::dnssec::NegativeTrustAnchorTable::new()))),
+            get_tsig_keys: Arc::new(ferrous_dns_application::use_cases::GetTsigKeysUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteTsigKeyRepository::new(pool.clone())))),
+            create_tsig_key: Arc::new(ferrous_dns_application::use_cases::CreateTsigKeyUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteTsigKeyRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::TsigKeyTable::new()))),
+            delete_tsig_key: Arc::new(ferrous_dns_application::use_cases::DeleteTsigKeyUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteTsigKeyRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::TsigKeyTable::new()))),
+            get_acme_registrations: Arc::new(GetAcmeRegistrationsUseCase::new(acme_registration_repo.clone())),
+            register_acme_account: Arc::new(RegisterAcmeAccountUseCase::new(acme_registration_repo.clone(), acme_challenges.clone(), ACME_ZONE, vec!["10.0.0.0/8".parse().unwrap()])),
+            update_acme_challenge: Arc::new(UpdateAcmeChallengeUseCase::new(acme_registration_repo.clone(), acme_challenges.clone())),
+            delete_acme_registration: Arc::new(DeleteAcmeRegistrationUseCase::new(acme_registration_repo, acme_challenges.clone())),
+            get_dns_rewrite_rules: Arc::new(ferrous_dns_application::use_cases::GetDnsRewriteRulesUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteDnsRewriteRuleRepository::new(pool.clone())))),
+            create_dns_rewrite_rule: Arc::new(ferrous_dns_application::use_cases::CreateDnsRewriteRuleUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteDnsRewriteRuleRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::repositories::SqliteGroupRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::DnsRewriteTable::new()))),
+            update_dns_rewrite_rule: Arc::new(ferrous_dns_application::use_cases::UpdateDnsRewriteRuleUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteDnsRewriteRuleRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::repositories::SqliteGroupRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::DnsRewriteTable::new()))),
+            delete_dns_rewrite_rule: Arc::new(ferrous_dns_application::use_cases::DeleteDnsRewriteRuleUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::SqliteDnsRewriteRuleRepository::new(pool.clone())), Arc::new(ferrous_dns_infrastructure::dns::DnsRewriteTable::new()))),
         },
         groups: GroupUseCases {
             get_groups: Arc::new(GetGroupsUseCase::new(group_repo.clone())),
             create_group: Arc::new(CreateGroupUseCase::new(group_repo.clone())),
             update_group: Arc::new(UpdateGroupUseCase::new(group_repo.clone())),
+            update_group_blocking_mode: Arc::new(UpdateGroupBlockingModeUseCase::new(group_repo.clone(), Arc::new(NullBlockFilterEngine))),
+            update_group_upstream_pools: Arc::new(ferrous_dns_application::use_cases::UpdateGroupUpstreamPoolsUseCase::new(group_repo.clone(), Arc::new(ferrous_dns_infrastructure::dns::GroupUpstreamTable::new()), config.clone())),
             delete_group: Arc::new(DeleteGroupUseCase::new(group_repo.clone())),
             assign_client_group: Arc::new(AssignClientGroupUseCase::new(client_repo.clone(), group_repo.clone(), Arc::new(NullBlockFilterEngine))),
         },
@@ -566,6 +710,9 @@ async fn create_test_app() -> (Router, Arc<RwLock<Config>>) {
                 null_engine.clone(),
             )),
             get_block_filter_stats: Arc::new(GetBlockFilterStatsUseCase::new(Arc::new(NullBlockFilterEngine))),
+            explain_filter_decision: Arc::new(ferrous_dns_application::use_cases::ExplainFilterDecisionUseCase::new(Arc::new(NullBlockFilterEngine), Arc::new(ferrous_dns_infrastructure::schedule::ScheduleStateStore::new()), Arc::new(NullSafeSearchEnginePort), Arc::new(ferrous_dns_infrastructure::repositories::SqliteGroupRepository::new(pool.clone())))),
+            get_blocklist_source_hits: Arc::new(ferrous_dns_application::use_cases::GetBlocklistSourceHitsUseCase::new(Arc::new(ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository::new(pool.clone(), pool.clone(), pool.clone(), &ferrous_dns_domain::config::DatabaseConfig::default())))),
+            simulate_blocklist: Arc::new(ferrous_dns_application::use_cases::SimulateBlocklistUseCase::new(Arc::new(ferrous_dns_infrastructure::dns::SqliteBlocklistSimulator::new(pool.clone(), Arc::new(arc_swap::ArcSwap::from_pointee(ferrous_dns_infrastructure::dns::block_filter::BlockIndex::empty()))).unwrap()), Arc::new(ferrous_dns_infrastructure::repositories::group_repository::SqliteGroupRepository::new(pool.clone())))),
         },
         services: ServiceUseCases {
             get_service_catalog: Arc::new(GetServiceCatalogUseCase::new(Arc::new(NullServiceCatalog))),
@@ -617,11 +764,14 @@ async fn create_test_app() -> (Router, Arc<RwLock<Config>>) {
         config_file_persistence: Arc::new(ferrous_dns_infrastructure::repositories::TomlConfigFilePersistence),
         config_path: None,
         tls_cert: Arc::new(helpers::MockTlsCertificateService),
+        tls_reloader: Arc::new(helpers::MockTlsCertificateReloader),
         tls_enabled: false,
     };
 
-    let app = create_api_routes(state);
-    (app, config)
+    let app = create_api_routes(state.clone())
+        .merge(create_ddns_routes(state.clone()))
+        .merge(create_acme_dns_routes(state));
+    (app, config, acme_challenges)
 }
 
 #[tokio::test]
@@ -872,3 +1022,84 @@ async fn test_list_local_records_fqdn_without_domain_uses_hostname() {
     assert_eq!(json[0]["fqdn"], "standalone");
     assert_eq!(json[0]["ttl"], 60);
 }
+
+#[tokio::test]
+async fn test_list_local_zones_returns_empty_list() {
+    let (app, _config) = create_test_app().await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/local-records/zones")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json.as_array().unwrap().len(), 0);
+}
+
+#[tokio::test]
+async fn test_create_zone_record_in_unknown_zone_returns_not_found() {
+    let (app, _config) = create_test_app().await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/local-records/zones/home.lan/records")
+                .method("POST")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    r#"{"name":"nas","record_type":"A","data":"192.168.1.10"}"#,
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+// ── TSIG keys ─────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_tsig_key_secret_is_only_returned_on_create() {
+    let (app, _config) = create_test_app().await;
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/tsig-keys")
+                .header("content-type", "application/json")
+                .body(Body::from(json!({ "name": "kea" }).to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let created: Value = serde_json::from_slice(&body).unwrap();
+    assert!(created["secret"].as_str().is_some_and(|s| !s.is_empty()));
+    let id = created["id"].as_i64().unwrap();
+
+    for uri in ["/tsig-keys".to_string(), format!("/tsig-keys/{id}")] {
+        let response = app
+            .clone()
+            .oneshot(Request::builder().uri(&uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: Value = serde_json::from_slice(&body).unwrap();
+        let key = json.as_array().map_or(&json, |keys| &keys[0]);
+        assert_eq!(key["name"], "kea");
+        assert!(key.get("secret").is_none(), "{uri} leaked the secret");
+    }
+}
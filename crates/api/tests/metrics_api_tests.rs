@@ -0,0 +1,220 @@
+use axum::{
+    body::Body,
+    http::{header, Request, StatusCode},
+    Router,
+};
+use ferrous_dns_api::{create_metrics_routes, MetricsState};
+use ferrous_dns_application::{
+    ports::{
+        AggregateStatus, CacheMetricsSnapshot, DnsCachePort, IpFamily, ResolvedEndpointHealth,
+        TunnelingEvictionTarget, UpstreamGroupHealth, UpstreamHealthPort, UpstreamStatus,
+    },
+    services::{ClientTransport, DnsMetrics, JobMetrics},
+};
+use ferrous_dns_domain::{
+    config::DatabaseConfig, QueryLog, QuerySource, RecordType, UpstreamStrategy,
+};
+use ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository;
+use http_body_util::BodyExt;
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tower::ServiceExt;
+
+struct FixedCache;
+
+impl DnsCachePort for FixedCache {
+    fn cache_size(&self) -> usize {
+        42
+    }
+    fn cache_metrics_snapshot(&self) -> CacheMetricsSnapshot {
+        CacheMetricsSnapshot {
+            total_entries: 42,
+            hits: 900,
+            misses: 100,
+            insertions: 120,
+            evictions: 3,
+            optimistic_refreshes: 7,
+            stale_hits: 1,
+            lazy_deletions: 2,
+            compactions: 4,
+            batch_evictions: 0,
+            hit_rate: 90.0,
+            transient_upstream_errors: 5,
+            aggressive_nxdomain_hits: 6,
+            aggressive_nodata_hits: 8,
+        }
+    }
+    fn insert_permanent_record(&self, _domain: &str, _rt: RecordType, _addrs: Vec<IpAddr>) {}
+    fn remove_record(&self, _domain: &str, _rt: &RecordType) -> bool {
+        false
+    }
+}
+
+struct FixedUpstreamHealth;
+
+impl UpstreamHealthPort for FixedUpstreamHealth {
+    fn get_all_upstream_status(&self) -> Vec<(String, UpstreamStatus)> {
+        Vec::new()
+    }
+    fn get_grouped_upstream_health(&self) -> Vec<UpstreamGroupHealth> {
+        let endpoint =
+            |address: &str, status, latency_ms, consecutive_failures| ResolvedEndpointHealth {
+                address: address.to_string(),
+                family: IpFamily::Ipv4,
+                status,
+                latency_ms,
+                last_error: None,
+                consecutive_failures,
+            };
+        vec![UpstreamGroupHealth {
+            address: "tls://dns.example:853".to_string(),
+            status: AggregateStatus::Partial,
+            resolved: vec![
+                endpoint("192.0.2.1:853", UpstreamStatus::Healthy, Some(12), 0),
+                endpoint("192.0.2.2:853", UpstreamStatus::Unhealthy, None, 3),
+            ],
+            pool_name: "primary".to_string(),
+            strategy: UpstreamStrategy::Parallel,
+        }]
+    }
+}
+
+struct FixedTunneling;
+
+impl TunnelingEvictionTarget for FixedTunneling {
+    fn evict_stale(&self) {}
+    fn tracked_count(&self) -> usize {
+        10
+    }
+    fn flagged_count(&self) -> usize {
+        2
+    }
+}
+
+async fn create_state(bearer_token: Option<&str>) -> MetricsState {
+    let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+    let query_log = Arc::new(SqliteQueryLogRepository::new(
+        pool.clone(),
+        pool.clone(),
+        pool,
+        &DatabaseConfig::default(),
+    ));
+
+    let dns = Arc::new(DnsMetrics::new());
+    dns.record_received(ClientTransport::Udp, 3);
+    for (status, elapsed_us) in [("NOERROR", 150), ("NOERROR", 3_000), ("RATE_LIMITED", 20)] {
+        dns.record_query(&QueryLog {
+            id: None,
+            domain: Arc::from("example.com"),
+            record_type: RecordType::A,
+            client_ip: "192.168.1.10".parse().unwrap(),
+            client_hostname: None,
+            blocked: false,
+            response_time_us: Some(elapsed_us),
+            cache_hit: false,
+            cache_refresh: false,
+            dnssec_status: None,
+            upstream_server: None,
+            upstream_pool: None,
+            response_status: Some(status),
+            timestamp: None,
+            query_source: QuerySource::Client,
+            group_id: Some(1),
+            block_source: None,
+            blocklist_source_ids: Vec::new(),
+        });
+    }
+
+    let jobs = Arc::new(JobMetrics::new());
+    jobs.record("wal_checkpoint", Duration::from_millis(250));
+
+    MetricsState {
+        dns,
+        jobs,
+        cache: Arc::new(FixedCache),
+        upstream_health: Arc::new(FixedUpstreamHealth),
+        query_log,
+        tunneling: Some(Arc::new(FixedTunneling)),
+        dga: None,
+        nxdomain_hijack: None,
+        response_ip_filter: None,
+        dnstap: None,
+        bearer_token: bearer_token.map(Arc::from),
+    }
+}
+
+async fn scrape(app: Router, authorization: Option<&str>) -> (StatusCode, String) {
+    let mut request = Request::builder().uri("/metrics");
+    if let Some(value) = authorization {
+        request = request.header(header::AUTHORIZATION, value);
+    }
+    let response = app
+        .oneshot(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[tokio::test]
+async fn test_metrics_exposes_query_counters_and_histogram() {
+    let app = create_metrics_routes(create_state(None).await);
+
+    let (status, body) = scrape(app, None).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.contains("# TYPE ferrous_dns_queries_total counter\n"));
+    assert!(body.contains("ferrous_dns_queries_total{result=\"noerror\"} 2\n"));
+    assert!(body.contains("ferrous_dns_queries_by_type_total{type=\"A\"} 3\n"));
+    assert!(body.contains("ferrous_dns_queries_received_total{protocol=\"udp\"} 3\n"));
+    assert!(body.contains("ferrous_dns_query_duration_seconds_bucket{le=\"0.0001\"} 1\n"));
+    assert!(body.contains("ferrous_dns_query_duration_seconds_bucket{le=\"0.005\"} 3\n"));
+    assert!(body.contains("ferrous_dns_query_duration_seconds_bucket{le=\"+Inf\"} 3\n"));
+    assert!(body.contains("ferrous_dns_query_duration_seconds_count 3\n"));
+    assert!(body.contains("ferrous_dns_rate_limited_total{action=\"refused\"} 1\n"));
+    assert!(body.contains("ferrous_dns_query_log_dropped_total 0\n"));
+}
+
+#[tokio::test]
+async fn test_metrics_exposes_cache_upstream_detector_and_job_state() {
+    let app = create_metrics_routes(create_state(None).await);
+
+    let (_, body) = scrape(app, None).await;
+
+    assert!(body.contains("ferrous_dns_cache_entries 42\n"));
+    assert!(body.contains("ferrous_dns_cache_hits_total 900\n"));
+    assert!(body.contains("ferrous_dns_cache_aggressive_nsec_hits_total{kind=\"nodata\"} 8\n"));
+    assert!(body.contains(
+        "ferrous_dns_upstream_up{pool=\"primary\",upstream=\"tls://dns.example:853\",endpoint=\"192.0.2.1:853\"} 1\n"
+    ));
+    assert!(body.contains(
+        "ferrous_dns_upstream_latency_seconds{pool=\"primary\",upstream=\"tls://dns.example:853\",endpoint=\"192.0.2.1:853\"} 0.012\n"
+    ));
+    assert!(body.contains(
+        "ferrous_dns_upstream_consecutive_failures{pool=\"primary\",upstream=\"tls://dns.example:853\",endpoint=\"192.0.2.2:853\"} 3\n"
+    ));
+    assert!(body.contains("ferrous_dns_tunneling_flagged_domains 2\n"));
+    assert!(!body.contains("ferrous_dns_dga_flagged_domains"));
+    assert!(body.contains("ferrous_dns_job_runs_total{job=\"wal_checkpoint\"} 1\n"));
+    assert!(body.contains("ferrous_dns_job_last_duration_seconds{job=\"wal_checkpoint\"} 0.25\n"));
+}
+
+#[tokio::test]
+async fn test_metrics_requires_configured_bearer_token() {
+    let state = create_state(Some("scrape-secret")).await;
+
+    let (missing, _) = scrape(create_metrics_routes(state.clone()), None).await;
+    let (wrong, _) = scrape(
+        create_metrics_routes(state.clone()),
+        Some("Bearer not-the-secret"),
+    )
+    .await;
+    let (valid, body) = scrape(create_metrics_routes(state), Some("Bearer scrape-secret")).await;
+
+    assert_eq!(missing, StatusCode::UNAUTHORIZED);
+    assert_eq!(wrong, StatusCode::UNAUTHORIZED);
+    assert_eq!(valid, StatusCode::OK);
+    assert!(body.contains("ferrous_dns_queries_total"));
+}
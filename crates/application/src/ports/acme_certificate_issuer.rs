@@ -0,0 +1,16 @@
+use ferrous_dns_domain::DomainError;
+
+/// A certificate chain and its private key, PEM encoded.
+#[derive(Debug, Clone)]
+pub struct IssuedCertificate {
+    pub cert_pem: String,
+    pub key_pem: String,
+}
+
+/// Orders certificates from an ACME CA (RFC 8555).
+#[async_trait::async_trait]
+pub trait AcmeCertificateIssuer: Send + Sync {
+    /// Orders a certificate covering `domains`, answering the CA's
+    /// challenges, and returns it with a freshly generated key.
+    async fn issue(&self, domains: &[String]) -> Result<IssuedCertificate, DomainError>;
+}
@@ -0,0 +1,7 @@
+use ferrous_dns_domain::AcmeRegistration;
+
+/// Live challenge TXT records served from the acme-dns zone.
+pub trait AcmeChallengeStore: Send + Sync {
+    /// Atomically replaces the served records with those of `registrations`.
+    fn replace_registrations(&self, registrations: &[AcmeRegistration]);
+}
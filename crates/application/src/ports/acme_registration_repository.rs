@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::{AcmeRegistration, DomainError};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait AcmeRegistrationRepository: Send + Sync {
+    async fn create(
+        &self,
+        username: &str,
+        password_hash: &str,
+        subdomain: &str,
+        allow_from: &[Arc<str>],
+    ) -> Result<AcmeRegistration, DomainError>;
+
+    async fn get_by_id(&self, id: i64) -> Result<Option<AcmeRegistration>, DomainError>;
+
+    async fn get_by_username(
+        &self,
+        username: &str,
+    ) -> Result<Option<AcmeRegistration>, DomainError>;
+
+    async fn get_all(&self) -> Result<Vec<AcmeRegistration>, DomainError>;
+
+    /// Replaces the challenge values of a registration (newest first).
+    async fn set_txt(&self, id: i64, txt: &[Arc<str>]) -> Result<(), DomainError>;
+
+    async fn delete(&self, id: i64) -> Result<(), DomainError>;
+}
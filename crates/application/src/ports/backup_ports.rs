@@ -24,6 +24,7 @@ pub trait BlocklistSourceCreator: Send + Sync {
         group_ids: Vec<i64>,
         comment: Option<String>,
         enabled: bool,
+        observe_only: bool,
     ) -> Result<BlocklistSource, DomainError>;
 }
 
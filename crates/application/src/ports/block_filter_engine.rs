@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use ferrous_dns_domain::{BlockSource, DomainError};
+use ferrous_dns_domain::{BlockSource, BlockingResponse, DomainError, RecordType};
 use std::net::IpAddr;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -8,14 +8,131 @@ pub enum FilterDecision {
     Allow,
 }
 
+/// What kind of entry took part in a filter decision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceRuleKind {
+    ManualBlocklist,
+    /// A plain name from a blocklist source.
+    ListExact,
+    /// A `||domain^` or `*.domain` rule from a blocklist source.
+    ListWildcard,
+    /// A `/text/` rule from a blocklist source.
+    ListPattern,
+    /// A blocklist rule carrying `$` modifiers.
+    ListRule,
+    /// An `@@` exception from a blocklist source.
+    ListException,
+    Allowlist,
+    ManagedDomain,
+    RegexFilter,
+}
+
+impl TraceRuleKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TraceRuleKind::ManualBlocklist => "manual_blocklist",
+            TraceRuleKind::ListExact => "list_exact",
+            TraceRuleKind::ListWildcard => "list_wildcard",
+            TraceRuleKind::ListPattern => "list_pattern",
+            TraceRuleKind::ListRule => "list_rule",
+            TraceRuleKind::ListException => "list_exception",
+            TraceRuleKind::Allowlist => "allowlist",
+            TraceRuleKind::ManagedDomain => "managed_domain",
+            TraceRuleKind::RegexFilter => "regex_filter",
+        }
+    }
+}
+
+/// An entry that matched the domain being explained.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceMatch {
+    pub kind: TraceRuleKind,
+    /// The rule as written, e.g. `||ads.example^` or the regex pattern.
+    pub rule: String,
+    /// Names of the blocklist sources listing the rule that apply to the
+    /// group. Empty for entries that do not come from a list.
+    pub sources: Vec<String>,
+}
+
+/// Every filter entry that applies to a domain for one group, whether or
+/// not it decided the outcome.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FilterTrace {
+    /// Names of the blocklist sources the group is subscribed to.
+    pub group_sources: Vec<String>,
+    pub block_matches: Vec<TraceMatch>,
+    pub allow_matches: Vec<TraceMatch>,
+    /// The blocked name in the CNAME chain that got the domain blocked by
+    /// CNAME cloaking, while that decision is cached.
+    pub cname_parent: Option<String>,
+}
+
 #[async_trait]
 pub trait BlockFilterEnginePort: Send + Sync {
     fn resolve_group(&self, ip: IpAddr) -> i64;
     fn check(&self, domain: &str, group_id: i64) -> FilterDecision;
-    fn store_cname_decision(&self, domain: &str, group_id: i64, ttl_secs: u64);
+
+    /// Like [`check`](Self::check), but also applies blocklist rules scoped
+    /// to a query type or client (`$dnstype`, `$client`).
+    fn check_query(
+        &self,
+        domain: &str,
+        _record_type: RecordType,
+        _client_ip: IpAddr,
+        group_id: i64,
+    ) -> FilterDecision {
+        self.check(domain, group_id)
+    }
+    /// Caches a CNAME cloaking block for `domain`, caused by `blocked_cname`
+    /// in its CNAME chain.
+    fn store_cname_decision(&self, domain: &str, blocked_cname: &str, group_id: i64, ttl_secs: u64);
+
+    /// Lists every entry that matches `domain` for `group_id`, for
+    /// explaining a decision. `client` adds the query type and client so
+    /// `$dnstype` and `$client` rules are considered.
+    fn explain(
+        &self,
+        _domain: &str,
+        _group_id: i64,
+        _client: Option<(RecordType, IpAddr)>,
+    ) -> FilterTrace {
+        FilterTrace::default()
+    }
+
+    /// Database ids of the blocklist sources whose entries block `domain`
+    /// for this query, in the group's subscriptions. Called after a list
+    /// block to attribute it; empty when no list entry matches.
+    fn blocklist_source_ids(
+        &self,
+        _domain: &str,
+        _record_type: RecordType,
+        _client_ip: IpAddr,
+        _group_id: i64,
+    ) -> Vec<i64> {
+        Vec::new()
+    }
+
+    /// Database ids of the observe-only blocklist sources that would block
+    /// `domain` for this query if they were enforced. Called for allowed
+    /// queries so they can be logged as would-block.
+    fn observed_source_ids(
+        &self,
+        _domain: &str,
+        _record_type: RecordType,
+        _client_ip: IpAddr,
+        _group_id: i64,
+    ) -> Vec<i64> {
+        Vec::new()
+    }
     async fn reload(&self) -> Result<(), DomainError>;
     async fn load_client_groups(&self) -> Result<(), DomainError>;
     fn compiled_domain_count(&self) -> usize;
     fn is_blocking_enabled(&self) -> bool;
     fn set_blocking_enabled(&self, enabled: bool);
+
+    /// Blocking response override configured for `group_id`, if any.
+    /// `None` means the global `[blocking] mode` applies.
+    fn blocking_response(&self, _group_id: i64) -> Option<BlockingResponse> {
+        None
+    }
 }
@@ -0,0 +1,73 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use ferrous_dns_domain::DomainError;
+use std::net::IpAddr;
+
+/// The list a simulation evaluates.
+#[derive(Debug, Clone)]
+pub enum CandidateList {
+    /// Downloaded once for the simulation; nothing is stored.
+    Url(String),
+    /// List contents in any format a blocklist source accepts.
+    Text(String),
+}
+
+/// Replays the query log of `from..=to` against a candidate list assigned
+/// to `group_ids`.
+#[derive(Debug, Clone)]
+pub struct BlocklistSimulation {
+    pub list: CandidateList,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    /// Groups the list would be assigned to; empty means every group.
+    pub group_ids: Vec<i64>,
+    /// Maximum number of domains and clients reported per outcome.
+    pub limit: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimulatedDomain {
+    pub domain: String,
+    pub queries: u64,
+    pub clients: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimulatedClient {
+    pub client_ip: IpAddr,
+    pub queries: u64,
+}
+
+/// Queries whose outcome the candidate list would change, most queried
+/// first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SimulationImpact {
+    pub queries: u64,
+    pub total_domains: u64,
+    pub total_clients: u64,
+    pub domains: Vec<SimulatedDomain>,
+    pub clients: Vec<SimulatedClient>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SimulationReport {
+    /// Rules parsed from the candidate list.
+    pub entry_count: usize,
+    /// Lines of the candidate list that could not be parsed.
+    pub parse_errors: usize,
+    /// Client queries logged in the time range for the simulated groups.
+    pub queries_examined: u64,
+    /// Allowed queries the list would block.
+    pub newly_blocked: SimulationImpact,
+    /// Queries blocked by a blocklist that the list's exceptions would allow.
+    pub newly_unblocked: SimulationImpact,
+}
+
+/// Port for dry-running a blocklist against logged traffic.
+#[async_trait]
+pub trait BlocklistSimulator: Send + Sync {
+    async fn simulate(
+        &self,
+        simulation: &BlocklistSimulation,
+    ) -> Result<SimulationReport, DomainError>;
+}
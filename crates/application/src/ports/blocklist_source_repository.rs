@@ -10,12 +10,14 @@ pub trait BlocklistSourceRepository: Send + Sync {
         group_ids: Vec<i64>,
         comment: Option<String>,
         enabled: bool,
+        observe_only: bool,
     ) -> Result<BlocklistSource, DomainError>;
 
     async fn get_by_id(&self, id: i64) -> Result<Option<BlocklistSource>, DomainError>;
 
     async fn get_all(&self) -> Result<Vec<BlocklistSource>, DomainError>;
 
+    #[allow(clippy::too_many_arguments)]
     async fn update(
         &self,
         id: i64,
@@ -24,6 +26,7 @@ pub trait BlocklistSourceRepository: Send + Sync {
         group_ids: Option<Vec<i64>>,
         comment: Option<String>,
         enabled: Option<bool>,
+        observe_only: Option<bool>,
     ) -> Result<BlocklistSource, DomainError>;
 
     async fn delete(&self, id: i64) -> Result<(), DomainError>;
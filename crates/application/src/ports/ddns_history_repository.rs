@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::{DdnsUpdate, DomainError};
+
+/// Port for the log of dyndns2 update attempts.
+#[async_trait]
+pub trait DdnsHistoryRepository: Send + Sync {
+    /// Appends one attempt. `id` and `created_at` are assigned by storage.
+    async fn record(&self, update: &DdnsUpdate) -> Result<(), DomainError>;
+
+    /// Most recent attempts first, optionally for one hostname only.
+    async fn get_recent(
+        &self,
+        hostname: Option<&str>,
+        limit: u32,
+    ) -> Result<Vec<DdnsUpdate>, DomainError>;
+
+    /// Drops all but the `keep` most recent attempts. Returns how many
+    /// were removed.
+    async fn prune(&self, keep: u32) -> Result<u64, DomainError>;
+}
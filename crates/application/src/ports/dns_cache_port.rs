@@ -19,6 +19,10 @@ pub struct CacheMetricsSnapshot {
     /// reset, no healthy servers, invalid response, etc.) and therefore NOT
     /// cached as NXDOMAIN. Helps operators diagnose upstream instability.
     pub transient_upstream_errors: u64,
+    /// Negative answers synthesised from cached NSEC/NSEC3 ranges
+    /// (RFC 8198 aggressive NSEC) without an upstream query.
+    pub aggressive_nxdomain_hits: u64,
+    pub aggressive_nodata_hits: u64,
 }
 
 /// Port for DNS cache operations exposed to the API layer.
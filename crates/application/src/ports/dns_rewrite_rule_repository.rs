@@ -0,0 +1,30 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::{DnsRewriteAnswer, DnsRewriteRule, DomainError};
+
+#[async_trait]
+pub trait DnsRewriteRuleRepository: Send + Sync {
+    async fn create(
+        &self,
+        domain: String,
+        answer: DnsRewriteAnswer,
+        group_id: i64,
+        enabled: bool,
+        comment: Option<String>,
+    ) -> Result<DnsRewriteRule, DomainError>;
+
+    async fn get_by_id(&self, id: i64) -> Result<Option<DnsRewriteRule>, DomainError>;
+
+    async fn get_all(&self) -> Result<Vec<DnsRewriteRule>, DomainError>;
+
+    async fn update(
+        &self,
+        id: i64,
+        domain: Option<String>,
+        answer: Option<DnsRewriteAnswer>,
+        group_id: Option<i64>,
+        enabled: Option<bool>,
+        comment: Option<String>,
+    ) -> Result<DnsRewriteRule, DomainError>;
+
+    async fn delete(&self, id: i64) -> Result<(), DomainError>;
+}
@@ -0,0 +1,35 @@
+use ferrous_dns_domain::{DnsRewriteRcode, DnsRewriteRule, RecordType};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+/// Answer synthesised for a query matched by one or more rewrite rules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DnsRewrite {
+    /// Addresses of the queried family. Empty for a NODATA answer, e.g. an
+    /// `AAAA` query against a name that only has `A` rewrites.
+    Addresses(Vec<IpAddr>),
+    /// Resolve this name instead and answer with its addresses.
+    Cname(Arc<str>),
+    Rcode(DnsRewriteRcode),
+}
+
+/// Live table of DNS rewrite rules consulted on the query hot path right
+/// after the block check.
+pub trait DnsRewriteStore: Send + Sync {
+    /// Atomically replaces the active rule set. Disabled rules are ignored.
+    fn replace_rules(&self, rules: &[DnsRewriteRule]);
+
+    /// Returns the rewrite for `domain` in `group_id`, taken from the rules
+    /// on the longest matching suffix. Rcode rules win over CNAME rules,
+    /// which win over address rules.
+    fn rewrite_for(
+        &self,
+        domain: &str,
+        record_type: RecordType,
+        group_id: i64,
+    ) -> Option<DnsRewrite>;
+
+    /// Allocation-free check used by the cache fast paths to hand rewritten
+    /// names over to the full query path.
+    fn has_rewrite(&self, domain: &str, group_id: i64) -> bool;
+}
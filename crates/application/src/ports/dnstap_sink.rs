@@ -0,0 +1,10 @@
+/// Port for reading the dnstap export counters.
+///
+/// Implemented by the infrastructure layer's `DnstapWriter`, which the DNS
+/// servers feed with the wire messages they exchange with clients.
+pub trait DnstapSink: Send + Sync {
+    /// Returns the number of messages dropped because the queue was full.
+    fn dropped_messages(&self) -> u64;
+    /// Returns the number of messages written to the output.
+    fn written_messages(&self) -> u64;
+}
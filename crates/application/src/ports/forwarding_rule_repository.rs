@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::{DomainError, ForwardingRule, ForwardingTarget};
+
+#[async_trait]
+pub trait ForwardingRuleRepository: Send + Sync {
+    async fn create(
+        &self,
+        domain: String,
+        target: ForwardingTarget,
+        enabled: bool,
+        comment: Option<String>,
+    ) -> Result<ForwardingRule, DomainError>;
+
+    async fn get_by_id(&self, id: i64) -> Result<Option<ForwardingRule>, DomainError>;
+
+    async fn get_all(&self) -> Result<Vec<ForwardingRule>, DomainError>;
+
+    async fn update(
+        &self,
+        id: i64,
+        domain: Option<String>,
+        target: Option<ForwardingTarget>,
+        enabled: Option<bool>,
+        comment: Option<String>,
+    ) -> Result<ForwardingRule, DomainError>;
+
+    async fn delete(&self, id: i64) -> Result<(), DomainError>;
+}
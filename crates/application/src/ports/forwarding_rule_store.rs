@@ -0,0 +1,8 @@
+use ferrous_dns_domain::ForwardingRule;
+
+/// Live table of conditional forwarding rules consulted by the resolver
+/// before the regular upstream pools.
+pub trait ForwardingRuleStore: Send + Sync {
+    /// Atomically replaces the active rule set. Disabled rules are ignored.
+    fn replace_rules(&self, rules: &[ForwardingRule]);
+}
@@ -1,5 +1,6 @@
 use async_trait::async_trait;
-use ferrous_dns_domain::{Client, DomainError, Group};
+use ferrous_dns_domain::{BlockingMode, Client, DomainError, Group};
+use std::net::{Ipv4Addr, Ipv6Addr};
 
 #[async_trait]
 pub trait GroupRepository: Send + Sync {
@@ -15,6 +16,14 @@ pub trait GroupRepository: Send + Sync {
         enabled: Option<bool>,
         comment: Option<String>,
     ) -> Result<Group, DomainError>;
+    async fn update_blocking_mode(
+        &self,
+        id: i64,
+        mode: Option<BlockingMode>,
+        ipv4: Option<Ipv4Addr>,
+        ipv6: Option<Ipv6Addr>,
+    ) -> Result<Group, DomainError>;
+    async fn update_upstream_pools(&self, id: i64, pools: &[String]) -> Result<Group, DomainError>;
     async fn delete(&self, id: i64) -> Result<(), DomainError>;
     async fn get_clients_in_group(&self, group_id: i64) -> Result<Vec<Client>, DomainError>;
     async fn count_clients_in_group(&self, group_id: i64) -> Result<u64, DomainError>;
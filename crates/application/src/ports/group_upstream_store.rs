@@ -0,0 +1,12 @@
+use ferrous_dns_domain::Group;
+
+/// Live table of the upstream pools each group resolves through, consulted
+/// by the DNS handler and the upstream pool manager.
+pub trait GroupUpstreamStore: Send + Sync {
+    /// Atomically replaces the table. Groups without upstream pools are
+    /// left out and use the global pools.
+    fn replace_groups(&self, groups: &[Group]);
+
+    /// Whether `group_id` resolves through its own upstream pools.
+    fn has_pools(&self, group_id: i64) -> bool;
+}
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::{DomainError, LocalZone};
+
+/// Persistent storage of authoritative local zones (master files on disk).
+#[async_trait]
+pub trait LocalZoneRepository: Send + Sync {
+    async fn get_all(&self) -> Result<Vec<LocalZone>, DomainError>;
+
+    async fn get(&self, origin: &str) -> Result<Option<LocalZone>, DomainError>;
+
+    /// Writes the zone back to its file. Fails with `InvalidZoneRecord`
+    /// when a record's data does not parse for its type.
+    async fn save(&self, zone: &LocalZone) -> Result<(), DomainError>;
+}
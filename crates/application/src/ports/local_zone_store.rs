@@ -0,0 +1,9 @@
+use ferrous_dns_domain::LocalZone;
+
+/// Live set of authoritative zones answered by the resolver before any
+/// cache or upstream lookup.
+pub trait LocalZoneStore: Send + Sync {
+    /// Atomically replaces the served zones. Zones that fail to compile
+    /// are skipped.
+    fn replace_zones(&self, zones: &[LocalZone]);
+}
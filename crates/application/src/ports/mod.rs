@@ -1,9 +1,13 @@
+mod acme_certificate_issuer;
+mod acme_challenge_store;
+mod acme_registration_repository;
 mod api_token_repository;
 mod arp_reader;
 mod backup_ports;
 mod block_filter_engine;
 mod blocked_service_repository;
 mod blocklist_repository;
+mod blocklist_simulator;
 mod blocklist_source_repository;
 mod cache_maintenance_port;
 mod client_repository;
@@ -11,12 +15,23 @@ mod client_subnet_repository;
 mod config_file_port;
 mod config_repository;
 mod custom_service_repository;
+mod ddns_history_repository;
 mod dga_flag_store;
 mod dns_cache_port;
 mod dns_resolver;
+mod dns_rewrite_rule_repository;
+mod dns_rewrite_store;
+mod dnstap_sink;
+mod forwarding_rule_repository;
+mod forwarding_rule_store;
 mod group_repository;
+mod group_upstream_store;
 mod hostname_resolver;
+mod local_zone_repository;
+mod local_zone_store;
 mod managed_domain_repository;
+mod negative_trust_anchor_repository;
+mod negative_trust_anchor_store;
 mod nxdomain_hijack_store;
 mod ptr_record_registry;
 mod query_log_repository;
@@ -29,18 +44,32 @@ mod schedule_state_port;
 mod service_catalog_port;
 mod session_repository;
 mod tls_certificate_port;
+mod tls_certificate_reloader;
+mod trust_anchor_repository;
+mod trust_anchor_store;
+mod tsig_key_repository;
+mod tsig_key_store;
 mod tunneling_flag_store;
 mod upstream_health_port;
 mod user_repository;
 mod whitelist_repository;
 mod whitelist_source_repository;
 
+pub use acme_certificate_issuer::{AcmeCertificateIssuer, IssuedCertificate};
+pub use acme_challenge_store::AcmeChallengeStore;
+pub use acme_registration_repository::AcmeRegistrationRepository;
 pub use api_token_repository::ApiTokenRepository;
 pub use arp_reader::{ArpReader, ArpTable};
 pub use backup_ports::{BlocklistSourceCreator, GroupCreator, LocalRecordCreator};
-pub use block_filter_engine::{BlockFilterEnginePort, FilterDecision};
+pub use block_filter_engine::{
+    BlockFilterEnginePort, FilterDecision, FilterTrace, TraceMatch, TraceRuleKind,
+};
 pub use blocked_service_repository::BlockedServiceRepository;
 pub use blocklist_repository::BlocklistRepository;
+pub use blocklist_simulator::{
+    BlocklistSimulation, BlocklistSimulator, CandidateList, SimulatedClient, SimulatedDomain,
+    SimulationImpact, SimulationReport,
+};
 pub use blocklist_source_repository::BlocklistSourceRepository;
 pub use cache_maintenance_port::{
     CacheCompactionOutcome, CacheMaintenancePort, CacheRefreshOutcome,
@@ -50,12 +79,23 @@ pub use client_subnet_repository::ClientSubnetRepository;
 pub use config_file_port::ConfigFilePersistence;
 pub use config_repository::ConfigRepository;
 pub use custom_service_repository::CustomServiceRepository;
+pub use ddns_history_repository::DdnsHistoryRepository;
 pub use dga_flag_store::{DgaEvictionTarget, DgaFlagStore};
 pub use dns_cache_port::{CacheMetricsSnapshot, DnsCachePort};
 pub use dns_resolver::{DnsResolution, DnsResolver, EMPTY_CNAME_CHAIN};
+pub use dns_rewrite_rule_repository::DnsRewriteRuleRepository;
+pub use dns_rewrite_store::{DnsRewrite, DnsRewriteStore};
+pub use dnstap_sink::DnstapSink;
+pub use forwarding_rule_repository::ForwardingRuleRepository;
+pub use forwarding_rule_store::ForwardingRuleStore;
 pub use group_repository::GroupRepository;
+pub use group_upstream_store::GroupUpstreamStore;
 pub use hostname_resolver::HostnameResolver;
+pub use local_zone_repository::LocalZoneRepository;
+pub use local_zone_store::LocalZoneStore;
 pub use managed_domain_repository::ManagedDomainRepository;
+pub use negative_trust_anchor_repository::NegativeTrustAnchorRepository;
+pub use negative_trust_anchor_store::NegativeTrustAnchorStore;
 pub use nxdomain_hijack_store::{NxdomainHijackIpStore, NxdomainHijackProbeTarget};
 pub use ptr_record_registry::PtrRecordRegistry;
 pub use query_log_repository::{
@@ -70,6 +110,11 @@ pub use schedule_state_port::ScheduleStatePort;
 pub use service_catalog_port::ServiceCatalogPort;
 pub use session_repository::SessionRepository;
 pub use tls_certificate_port::{TlsCertificateInfo, TlsCertificatePort};
+pub use tls_certificate_reloader::TlsCertificateReloader;
+pub use trust_anchor_repository::TrustAnchorRepository;
+pub use trust_anchor_store::{ProbedKey, TrustAnchorProbe, TrustAnchorStorePort};
+pub use tsig_key_repository::TsigKeyRepository;
+pub use tsig_key_store::TsigKeyStore;
 pub use tunneling_flag_store::{TunnelingEvictionTarget, TunnelingFlagStore};
 pub use upstream_health_port::{
     AggregateStatus, IpFamily, ResolvedEndpointHealth, UpstreamGroupHealth, UpstreamHealthPort,
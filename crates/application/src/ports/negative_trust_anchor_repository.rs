@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::{DomainError, NegativeTrustAnchor};
+
+#[async_trait]
+pub trait NegativeTrustAnchorRepository: Send + Sync {
+    async fn create(
+        &self,
+        domain: String,
+        expires_at: Option<String>,
+        comment: Option<String>,
+    ) -> Result<NegativeTrustAnchor, DomainError>;
+
+    async fn get_by_id(&self, id: i64) -> Result<Option<NegativeTrustAnchor>, DomainError>;
+
+    async fn get_all(&self) -> Result<Vec<NegativeTrustAnchor>, DomainError>;
+
+    async fn delete(&self, id: i64) -> Result<(), DomainError>;
+
+    /// Deletes anchors whose expiry is at or before `now`, returning how
+    /// many were removed.
+    async fn delete_expired(&self, now: &str) -> Result<u64, DomainError>;
+}
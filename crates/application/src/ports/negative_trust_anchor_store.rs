@@ -0,0 +1,7 @@
+use ferrous_dns_domain::NegativeTrustAnchor;
+
+/// Live set of negative trust anchors consulted by the DNSSEC validator.
+pub trait NegativeTrustAnchorStore: Send + Sync {
+    /// Atomically replaces the active anchors.
+    fn replace_anchors(&self, anchors: &[NegativeTrustAnchor]);
+}
@@ -12,4 +12,7 @@ pub trait PtrRecordRegistry: Send + Sync {
 
     /// Removes the PTR mapping for `ip`, if present.
     fn unregister(&self, ip: IpAddr);
+
+    /// The hostname currently registered for `ip`.
+    fn lookup(&self, ip: IpAddr) -> Option<Arc<str>>;
 }
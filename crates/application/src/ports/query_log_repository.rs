@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use ferrous_dns_domain::{
     query_log::{QueryLog, QueryLogFilter, QueryStats},
-    DomainError,
+    BlocklistSourceHits, DomainError,
 };
 
 /// Result of a paginated query log fetch.
@@ -43,6 +43,11 @@ pub trait QueryLogRepository: Send + Sync {
         Ok(())
     }
 
+    /// Entries discarded because the write channel was full.
+    fn dropped_entries(&self) -> u64 {
+        0
+    }
+
     async fn get_recent(&self, limit: u32, period_hours: f32)
         -> Result<Vec<QueryLog>, DomainError>;
 
@@ -83,6 +88,9 @@ pub trait QueryLogRepository: Send + Sync {
         limit: u32,
         period_hours: f32,
     ) -> Result<Vec<(String, Option<String>, u64)>, DomainError>;
+    /// Blocked queries per blocklist source over the last 24 hours, 7 days
+    /// and 30 days, including sources without any hits.
+    async fn get_blocklist_source_hits(&self) -> Result<Vec<BlocklistSourceHits>, DomainError>;
     async fn delete_older_than(&self, days: u32) -> Result<u64, DomainError>;
 }
 
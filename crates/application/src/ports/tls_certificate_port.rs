@@ -7,6 +7,12 @@ pub struct TlsCertificateInfo {
     pub cert_subject: Option<String>,
     pub cert_not_after: Option<String>,
     pub cert_valid: bool,
+    /// Expiry as a Unix timestamp, for renewal decisions.
+    pub cert_not_after_unix: Option<i64>,
+    /// DNS names from the subject alternative name extension.
+    pub cert_dns_names: Vec<String>,
+    /// SHA-256 fingerprint of the leaf certificate, colon-separated hex.
+    pub cert_fingerprint_sha256: Option<String>,
 }
 
 /// Port for TLS certificate management operations (status, upload, generation).
@@ -15,7 +21,8 @@ pub trait TlsCertificatePort: Send + Sync {
     /// Reads and parses certificate files to determine their status.
     async fn get_status(&self, cert_path: &str, key_path: &str) -> TlsCertificateInfo;
 
-    /// Validates PEM data and writes certificate + key to the configured paths.
+    /// Validates PEM data, checks that the key belongs to the certificate and
+    /// writes certificate + key to the configured paths.
     async fn save_certificates(
         &self,
         cert_data: &[u8],
@@ -0,0 +1,19 @@
+use ferrous_dns_domain::DomainError;
+
+/// Swaps certificates into the running TLS listeners.
+pub trait TlsCertificateReloader: Send + Sync {
+    /// Re-reads `cert_path` and `key_path` into every listener serving
+    /// them. New handshakes use the new certificate; established
+    /// connections are unaffected. Returns false when no listener serves
+    /// these files.
+    fn reload(&self, cert_path: &str, key_path: &str) -> Result<bool, DomainError>;
+
+    /// Reloads every pair whose files changed since they were last read.
+    /// A pair that fails to load keeps its previous certificate. Returns
+    /// the number of pairs swapped in.
+    fn reload_changed(&self) -> usize;
+
+    /// SHA-256 fingerprint of the leaf certificate currently served for
+    /// `cert_path` and `key_path`, if any.
+    fn active_fingerprint(&self, cert_path: &str, key_path: &str) -> Option<String>;
+}
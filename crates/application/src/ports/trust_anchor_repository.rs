@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::{DomainError, TrustAnchor};
+
+#[async_trait]
+pub trait TrustAnchorRepository: Send + Sync {
+    async fn get_all(&self) -> Result<Vec<TrustAnchor>, DomainError>;
+
+    /// Inserts an anchor without an id; otherwise updates its state and
+    /// timestamps.
+    async fn save(&self, anchor: &TrustAnchor) -> Result<TrustAnchor, DomainError>;
+
+    async fn delete(&self, id: i64) -> Result<(), DomainError>;
+}
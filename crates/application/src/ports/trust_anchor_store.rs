@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::{DomainError, TrustAnchor, TrustAnchorData};
+
+/// Live set of trust anchors the DNSSEC validator starts its chains from.
+pub trait TrustAnchorStorePort: Send + Sync {
+    /// Atomically replaces the anchors. Only trusted ones (`Valid` and
+    /// `Missing`) are used for validation.
+    fn replace_anchors(&self, anchors: &[TrustAnchor]);
+}
+
+/// A key from a zone's DNSKEY RRset, as seen by an RFC 5011 probe.
+#[derive(Debug, Clone)]
+pub struct ProbedKey {
+    /// Always `TrustAnchorData::Dnskey`.
+    pub data: TrustAnchorData,
+    /// An RRSIG made with this key over the DNSKEY RRset verifies.
+    pub signs_rrset: bool,
+}
+
+/// Fetches DNSKEY RRsets for RFC 5011 trust anchor maintenance.
+#[async_trait]
+pub trait TrustAnchorProbe: Send + Sync {
+    async fn fetch_keys(&self, zone: &str) -> Result<Vec<ProbedKey>, DomainError>;
+
+    /// True when the DS anchor `ds` is a digest of the DNSKEY `key`.
+    fn matches_ds(&self, zone: &str, key: &TrustAnchorData, ds: &TrustAnchorData) -> bool;
+}
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use ferrous_dns_domain::{DomainError, TsigKey};
+
+#[async_trait]
+pub trait TsigKeyRepository: Send + Sync {
+    async fn create(
+        &self,
+        name: String,
+        algorithm: String,
+        secret: String,
+    ) -> Result<TsigKey, DomainError>;
+
+    async fn get_by_id(&self, id: i64) -> Result<Option<TsigKey>, DomainError>;
+
+    async fn get_all(&self) -> Result<Vec<TsigKey>, DomainError>;
+
+    async fn delete(&self, id: i64) -> Result<(), DomainError>;
+}
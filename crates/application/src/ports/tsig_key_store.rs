@@ -0,0 +1,7 @@
+use ferrous_dns_domain::TsigKey;
+
+/// Live set of TSIG keys that authenticate dynamic UPDATE messages.
+pub trait TsigKeyStore: Send + Sync {
+    /// Atomically replaces the active keys.
+    fn replace_keys(&self, keys: &[TsigKey]);
+}
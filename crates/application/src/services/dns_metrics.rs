@@ -0,0 +1,163 @@
+use ferrous_dns_domain::{QueryLog, QuerySource, RecordType};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Response statuses counted under their own `result` label. Anything else
+/// is counted as `other`.
+pub const QUERY_RESULTS: [&str; 14] = [
+    "NOERROR",
+    "LOCAL_DNS",
+    "REWRITTEN",
+    "NXDOMAIN",
+    "SERVFAIL",
+    "TIMEOUT",
+    "BLOCKED",
+    "SAFE_SEARCH",
+    "RATE_LIMITED",
+    "RATE_LIMITED_TC",
+    "TUNNELING_BLOCKED",
+    "DGA_BLOCKED",
+    "NXDOMAIN_HIJACK",
+    "RESPONSE_IP_BLOCKED",
+];
+
+/// Upper bounds of the query latency histogram buckets, in microseconds.
+pub const LATENCY_BUCKETS_US: [u64; 14] = [
+    100, 250, 500, 1_000, 2_500, 5_000, 10_000, 25_000, 50_000, 100_000, 250_000, 500_000,
+    1_000_000, 2_500_000,
+];
+
+/// Transport a client query arrived on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClientTransport {
+    Udp,
+    Tcp,
+    Tls,
+    Https,
+}
+
+impl ClientTransport {
+    pub const ALL: [ClientTransport; 4] = [
+        ClientTransport::Udp,
+        ClientTransport::Tcp,
+        ClientTransport::Tls,
+        ClientTransport::Https,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ClientTransport::Udp => "udp",
+            ClientTransport::Tcp => "tcp",
+            ClientTransport::Tls => "tls",
+            ClientTransport::Https => "https",
+        }
+    }
+}
+
+/// Lock-free counters for client queries, updated on the query path and
+/// read by the metrics endpoint.
+#[repr(align(64))]
+pub struct DnsMetrics {
+    results: [AtomicU64; QUERY_RESULTS.len() + 1],
+    record_types: [AtomicU64; RecordType::ALL.len()],
+    transports: [AtomicU64; ClientTransport::ALL.len()],
+    /// Per-bucket (not cumulative) counts; the last slot is `+Inf`.
+    latency_buckets: [AtomicU64; LATENCY_BUCKETS_US.len() + 1],
+    latency_sum_us: AtomicU64,
+}
+
+/// Point-in-time copy of [`DnsMetrics`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DnsMetricsSnapshot {
+    /// `(status, count)` per entry of [`QUERY_RESULTS`], then `("OTHER", n)`.
+    pub results: Vec<(&'static str, u64)>,
+    /// Record types seen at least once.
+    pub record_types: Vec<(RecordType, u64)>,
+    pub transports: Vec<(ClientTransport, u64)>,
+    /// Cumulative `(upper bound in µs, count)` pairs, `+Inf` excluded.
+    pub latency_buckets: Vec<(u64, u64)>,
+    pub latency_count: u64,
+    pub latency_sum_us: u64,
+}
+
+impl Default for DnsMetrics {
+    fn default() -> Self {
+        Self {
+            results: std::array::from_fn(|_| AtomicU64::new(0)),
+            record_types: std::array::from_fn(|_| AtomicU64::new(0)),
+            transports: std::array::from_fn(|_| AtomicU64::new(0)),
+            latency_buckets: std::array::from_fn(|_| AtomicU64::new(0)),
+            latency_sum_us: AtomicU64::new(0),
+        }
+    }
+}
+
+impl DnsMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Counts a logged query. Queries the server issues itself (cache
+    /// refreshes, health probes) are ignored.
+    pub fn record_query(&self, query_log: &QueryLog) {
+        if query_log.query_source != QuerySource::Client {
+            return;
+        }
+
+        let result = query_log
+            .response_status
+            .and_then(|status| QUERY_RESULTS.iter().position(|r| *r == status))
+            .unwrap_or(QUERY_RESULTS.len());
+        self.results[result].fetch_add(1, Ordering::Relaxed);
+        self.record_types[query_log.record_type as usize].fetch_add(1, Ordering::Relaxed);
+
+        if let Some(elapsed_us) = query_log.response_time_us {
+            let bucket = LATENCY_BUCKETS_US.partition_point(|&le| le < elapsed_us);
+            self.latency_buckets[bucket].fetch_add(1, Ordering::Relaxed);
+            self.latency_sum_us.fetch_add(elapsed_us, Ordering::Relaxed);
+        }
+    }
+
+    /// Counts `messages` client queries received over `transport`.
+    pub fn record_received(&self, transport: ClientTransport, messages: u64) {
+        self.transports[transport as usize].fetch_add(messages, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self) -> DnsMetricsSnapshot {
+        let results = QUERY_RESULTS
+            .iter()
+            .copied()
+            .chain(std::iter::once("OTHER"))
+            .zip(&self.results)
+            .map(|(status, count)| (status, count.load(Ordering::Relaxed)))
+            .collect();
+
+        let record_types = RecordType::ALL
+            .iter()
+            .map(|&rt| (rt, self.record_types[rt as usize].load(Ordering::Relaxed)))
+            .filter(|&(_, count)| count > 0)
+            .collect();
+
+        let transports = ClientTransport::ALL
+            .iter()
+            .map(|&t| (t, self.transports[t as usize].load(Ordering::Relaxed)))
+            .collect();
+
+        let mut cumulative = 0;
+        let mut latency_buckets = Vec::with_capacity(LATENCY_BUCKETS_US.len());
+        for (i, count) in self.latency_buckets.iter().enumerate() {
+            cumulative += count.load(Ordering::Relaxed);
+            if let Some(&le) = LATENCY_BUCKETS_US.get(i) {
+                latency_buckets.push((le, cumulative));
+            }
+        }
+
+        DnsMetricsSnapshot {
+            results,
+            record_types,
+            transports,
+            latency_buckets,
+            latency_count: cumulative,
+            latency_sum_us: self.latency_sum_us.load(Ordering::Relaxed),
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Run statistics for one background job.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct JobStats {
+    pub runs: u64,
+    pub total_duration: Duration,
+    pub last_duration: Duration,
+    /// Unix time the last run finished.
+    pub last_run_secs: u64,
+}
+
+/// Collects run counts and durations of the background jobs. Each job
+/// records one run per tick; scrapes copy the map.
+#[derive(Default)]
+pub struct JobMetrics {
+    jobs: Mutex<BTreeMap<&'static str, JobStats>>,
+}
+
+impl JobMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts timing a run of `job`. The run is recorded when the returned
+    /// guard is dropped.
+    pub fn start(self: &Arc<Self>, job: &'static str) -> JobRun {
+        JobRun {
+            metrics: Arc::clone(self),
+            job,
+            started: Instant::now(),
+        }
+    }
+
+    pub fn record(&self, job: &'static str, duration: Duration) {
+        let finished = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let mut jobs = self.jobs.lock().unwrap_or_else(|e| e.into_inner());
+        let stats = jobs.entry(job).or_default();
+        stats.runs += 1;
+        stats.total_duration += duration;
+        stats.last_duration = duration;
+        stats.last_run_secs = finished;
+    }
+
+    /// Jobs that have completed at least one run, by name.
+    pub fn snapshot(&self) -> Vec<(&'static str, JobStats)> {
+        let jobs = self.jobs.lock().unwrap_or_else(|e| e.into_inner());
+        jobs.iter()
+            .map(|(name, stats)| (*name, stats.clone()))
+            .collect()
+    }
+}
+
+/// Times a job run; see [`JobMetrics::start`].
+pub struct JobRun {
+    metrics: Arc<JobMetrics>,
+    job: &'static str,
+    started: Instant,
+}
+
+impl Drop for JobRun {
+    fn drop(&mut self) {
+        self.metrics.record(self.job, self.started.elapsed());
+    }
+}
@@ -1,3 +1,11 @@
+mod dns_metrics;
+mod job_metrics;
+mod query_stream;
 mod subnet_matcher_service;
 
+pub use dns_metrics::{
+    ClientTransport, DnsMetrics, DnsMetricsSnapshot, LATENCY_BUCKETS_US, QUERY_RESULTS,
+};
+pub use job_metrics::{JobMetrics, JobRun, JobStats};
+pub use query_stream::{QueryStream, QueryStreamFilter};
 pub use subnet_matcher_service::SubnetMatcherService;
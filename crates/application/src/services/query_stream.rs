@@ -0,0 +1,75 @@
+use ferrous_dns_domain::QueryLog;
+use std::net::IpAddr;
+use std::sync::Arc;
+use tokio::sync::broadcast;
+
+const DEFAULT_CAPACITY: usize = 1_024;
+
+/// Fans logged queries out to live subscribers.
+///
+/// Publishing never waits: with no subscribers it returns immediately, and a
+/// subscriber that falls more than `capacity` entries behind skips ahead
+/// instead of holding the sender back.
+pub struct QueryStream {
+    sender: broadcast::Sender<Arc<QueryLog>>,
+}
+
+impl QueryStream {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity.max(1));
+        Self { sender }
+    }
+
+    pub fn publish(&self, query_log: &QueryLog) {
+        if self.sender.receiver_count() == 0 {
+            return;
+        }
+        let mut entry = query_log.clone();
+        if entry.timestamp.is_none() {
+            entry.timestamp = Some(chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string());
+        }
+        let _ = self.sender.send(Arc::new(entry));
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<Arc<QueryLog>> {
+        self.sender.subscribe()
+    }
+
+    pub fn subscriber_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+}
+
+impl Default for QueryStream {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+/// Server-side filter of a live query subscription. Unset fields match
+/// every entry.
+#[derive(Debug, Clone, Default)]
+pub struct QueryStreamFilter {
+    pub client: Option<IpAddr>,
+    /// Lowercase substring the domain must contain.
+    pub domain: Option<String>,
+    pub blocked_only: bool,
+}
+
+impl QueryStreamFilter {
+    pub fn matches(&self, query_log: &QueryLog) -> bool {
+        if self.blocked_only && !query_log.blocked {
+            return false;
+        }
+        if self.client.is_some_and(|ip| ip != query_log.client_ip) {
+            return false;
+        }
+        match &self.domain {
+            Some(needle) => query_log
+                .domain
+                .to_ascii_lowercase()
+                .contains(needle.as_str()),
+            None => true,
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use ferrous_dns_domain::DomainError;
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use super::reload_challenges;
+use crate::ports::{AcmeChallengeStore, AcmeRegistrationRepository};
+
+pub struct DeleteAcmeRegistrationUseCase {
+    repo: Arc<dyn AcmeRegistrationRepository>,
+    store: Arc<dyn AcmeChallengeStore>,
+}
+
+impl DeleteAcmeRegistrationUseCase {
+    pub fn new(
+        repo: Arc<dyn AcmeRegistrationRepository>,
+        store: Arc<dyn AcmeChallengeStore>,
+    ) -> Self {
+        Self { repo, store }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(&self, id: i64) -> Result<(), DomainError> {
+        self.repo
+            .get_by_id(id)
+            .await?
+            .ok_or(DomainError::AcmeRegistrationNotFound(id))?;
+
+        self.repo.delete(id).await?;
+
+        info!(registration_id = ?id, "ACME registration deleted successfully");
+
+        reload_challenges(self.repo.as_ref(), self.store.as_ref()).await;
+
+        Ok(())
+    }
+}
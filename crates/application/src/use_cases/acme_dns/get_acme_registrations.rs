@@ -0,0 +1,25 @@
+use ferrous_dns_domain::{AcmeRegistration, DomainError};
+use std::sync::Arc;
+use tracing::instrument;
+
+use crate::ports::AcmeRegistrationRepository;
+
+pub struct GetAcmeRegistrationsUseCase {
+    repo: Arc<dyn AcmeRegistrationRepository>,
+}
+
+impl GetAcmeRegistrationsUseCase {
+    pub fn new(repo: Arc<dyn AcmeRegistrationRepository>) -> Self {
+        Self { repo }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_all(&self) -> Result<Vec<AcmeRegistration>, DomainError> {
+        self.repo.get_all().await
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_by_id(&self, id: i64) -> Result<Option<AcmeRegistration>, DomainError> {
+        self.repo.get_by_id(id).await
+    }
+}
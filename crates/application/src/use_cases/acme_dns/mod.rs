@@ -0,0 +1,64 @@
+mod delete_acme_registration;
+mod get_acme_registrations;
+mod register_acme_account;
+mod update_acme_challenge;
+
+pub use delete_acme_registration::DeleteAcmeRegistrationUseCase;
+pub use get_acme_registrations::GetAcmeRegistrationsUseCase;
+pub use register_acme_account::{RegisterAcmeAccountUseCase, RegisteredAcmeAccount};
+pub use update_acme_challenge::UpdateAcmeChallengeUseCase;
+
+use crate::ports::{AcmeChallengeStore, AcmeRegistrationRepository};
+use ferrous_dns_domain::DomainError;
+use std::fmt::Write;
+use tracing::error;
+
+/// Pushes the persisted registrations into the served challenge zone.
+async fn reload_challenges(repo: &dyn AcmeRegistrationRepository, store: &dyn AcmeChallengeStore) {
+    match repo.get_all().await {
+        Ok(registrations) => store.replace_registrations(&registrations),
+        Err(e) => error!(error = %e, "Failed to reload ACME challenge records"),
+    }
+}
+
+fn random_bytes<const N: usize>() -> Result<[u8; N], DomainError> {
+    use ring::rand::SecureRandom;
+    let mut buf = [0u8; N];
+    ring::rand::SystemRandom::new()
+        .fill(&mut buf)
+        .map_err(|_| DomainError::IoError("CSPRNG fill failed".to_string()))?;
+    Ok(buf)
+}
+
+/// A random version 4 UUID, as acme-dns uses for usernames and subdomains.
+fn generate_uuid() -> Result<String, DomainError> {
+    let mut bytes = random_bytes::<16>()?;
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let mut uuid = String::with_capacity(36);
+    for (i, byte) in bytes.iter().enumerate() {
+        if matches!(i, 4 | 6 | 8 | 10) {
+            uuid.push('-');
+        }
+        let _ = write!(uuid, "{byte:02x}");
+    }
+    Ok(uuid)
+}
+
+/// A random 40-character password.
+fn generate_password() -> Result<String, DomainError> {
+    use base64::Engine;
+    Ok(base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(random_bytes::<30>()?))
+}
+
+/// Computes SHA-256 hash of a password, returning hex string.
+fn hash_password(password: &str) -> String {
+    use sha2::{Digest, Sha256};
+    let mut hasher = Sha256::new();
+    hasher.update(password.as_bytes());
+    let mut hex = String::with_capacity(64);
+    for byte in hasher.finalize().as_slice() {
+        let _ = write!(hex, "{byte:02x}");
+    }
+    hex
+}
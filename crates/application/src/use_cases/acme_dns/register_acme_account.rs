@@ -0,0 +1,84 @@
+use ferrous_dns_domain::{AcmeRegistration, DomainError};
+use ipnetwork::IpNetwork;
+use std::net::IpAddr;
+use std::sync::Arc;
+use tracing::{info, instrument, warn};
+
+use super::{generate_password, generate_uuid, hash_password, reload_challenges};
+use crate::ports::{AcmeChallengeStore, AcmeRegistrationRepository};
+
+/// A new registration together with the one-time plaintext password.
+#[derive(Debug, Clone)]
+pub struct RegisteredAcmeAccount {
+    pub registration: AcmeRegistration,
+    pub password: String,
+    /// `<subdomain>.<zone>`: the CNAME target for `_acme-challenge` names.
+    pub fulldomain: String,
+}
+
+/// Handles acme-dns `/register`: creates credentials and a random
+/// subdomain of the challenge zone.
+pub struct RegisterAcmeAccountUseCase {
+    repo: Arc<dyn AcmeRegistrationRepository>,
+    store: Arc<dyn AcmeChallengeStore>,
+    zone: Arc<str>,
+    allowed_from: Vec<IpNetwork>,
+}
+
+impl RegisterAcmeAccountUseCase {
+    /// `allowed_from` limits who may register; empty allows any source.
+    pub fn new(
+        repo: Arc<dyn AcmeRegistrationRepository>,
+        store: Arc<dyn AcmeChallengeStore>,
+        zone: &str,
+        allowed_from: Vec<IpNetwork>,
+    ) -> Self {
+        Self {
+            repo,
+            store,
+            zone: Arc::from(zone),
+            allowed_from,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        allow_from: &[String],
+        client_ip: Option<IpAddr>,
+    ) -> Result<RegisteredAcmeAccount, DomainError> {
+        if !self.allowed_from.is_empty()
+            && !client_ip.is_some_and(|ip| self.allowed_from.iter().any(|n| n.contains(ip)))
+        {
+            warn!(client = ?client_ip, "ACME registration refused for source address");
+            return Err(DomainError::InvalidCredentials);
+        }
+
+        let allow_from = AcmeRegistration::parse_allow_from(allow_from)
+            .map_err(DomainError::InvalidAcmeAllowFrom)?;
+
+        let username = generate_uuid()?;
+        let password = generate_password()?;
+        let subdomain = generate_uuid()?;
+
+        let registration = self
+            .repo
+            .create(
+                &username,
+                &hash_password(&password),
+                &subdomain,
+                &allow_from,
+            )
+            .await?;
+
+        info!(subdomain = %subdomain, "ACME registration created");
+
+        reload_challenges(self.repo.as_ref(), self.store.as_ref()).await;
+
+        Ok(RegisteredAcmeAccount {
+            fulldomain: format!("{}.{}", registration.subdomain, self.zone),
+            registration,
+            password,
+        })
+    }
+}
@@ -0,0 +1,81 @@
+use ferrous_dns_domain::{AcmeRegistration, DomainError};
+use std::net::IpAddr;
+use std::sync::Arc;
+use subtle::ConstantTimeEq;
+use tracing::{info, instrument, warn};
+
+use super::{hash_password, reload_challenges};
+use crate::ports::{AcmeChallengeStore, AcmeRegistrationRepository};
+
+/// Handles acme-dns `/update`: publishes a challenge TXT value for the
+/// caller's registration.
+pub struct UpdateAcmeChallengeUseCase {
+    repo: Arc<dyn AcmeRegistrationRepository>,
+    store: Arc<dyn AcmeChallengeStore>,
+}
+
+impl UpdateAcmeChallengeUseCase {
+    pub fn new(
+        repo: Arc<dyn AcmeRegistrationRepository>,
+        store: Arc<dyn AcmeChallengeStore>,
+    ) -> Self {
+        Self { repo, store }
+    }
+
+    /// Returns the stored value. Wrong credentials, another registration's
+    /// subdomain and a source outside `allowfrom` all fail with
+    /// `InvalidCredentials`, so a caller cannot tell them apart.
+    #[instrument(skip(self, password, txt))]
+    pub async fn execute(
+        &self,
+        username: &str,
+        password: &str,
+        subdomain: &str,
+        txt: &str,
+        client_ip: Option<IpAddr>,
+    ) -> Result<Arc<str>, DomainError> {
+        let registration = self
+            .repo
+            .get_by_username(username)
+            .await?
+            .filter(|r| {
+                bool::from(
+                    hash_password(password)
+                        .as_bytes()
+                        .ct_eq(r.password_hash.as_bytes()),
+                )
+            })
+            .ok_or(DomainError::InvalidCredentials)?;
+
+        if !is_uuid(subdomain) {
+            return Err(DomainError::InvalidAcmeSubdomain);
+        }
+        if !registration.subdomain.eq_ignore_ascii_case(subdomain) {
+            warn!(username = %username, "ACME update for another registration's subdomain");
+            return Err(DomainError::InvalidCredentials);
+        }
+        if !registration.allows(client_ip) {
+            warn!(username = %username, client = ?client_ip, "ACME update from disallowed source");
+            return Err(DomainError::InvalidCredentials);
+        }
+        AcmeRegistration::validate_txt(txt).map_err(|_| DomainError::InvalidAcmeTxt)?;
+
+        let id = registration.id.ok_or(DomainError::InvalidCredentials)?;
+        let values = registration.rotated_txt(txt);
+        self.repo.set_txt(id, &values).await?;
+
+        info!(subdomain = %registration.subdomain, "ACME challenge TXT updated");
+
+        reload_challenges(self.repo.as_ref(), self.store.as_ref()).await;
+
+        Ok(Arc::clone(&values[0]))
+    }
+}
+
+fn is_uuid(s: &str) -> bool {
+    s.len() == 36
+        && s.bytes().enumerate().all(|(i, b)| match i {
+            8 | 13 | 18 | 23 => b == b'-',
+            _ => b.is_ascii_hexdigit(),
+        })
+}
@@ -59,6 +59,7 @@ impl ExportConfigUseCase {
                 group_ids: s.group_ids,
                 comment: s.comment.map(|c| c.to_string()),
                 enabled: s.enabled,
+                observe_only: s.observe_only,
             })
             .collect();
 
@@ -222,6 +222,7 @@ impl ImportConfigUseCase {
                     source.group_ids.clone(),
                     source.comment.clone(),
                     source.enabled,
+                    source.observe_only,
                 )
                 .await
             {
@@ -103,6 +103,8 @@ pub struct BlocklistSourceSnapshot {
     pub group_ids: Vec<i64>,
     pub comment: Option<String>,
     pub enabled: bool,
+    #[serde(default)]
+    pub observe_only: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -0,0 +1,100 @@
+use crate::ports::{
+    BlockFilterEnginePort, FilterDecision, FilterTrace, GroupRepository, SafeSearchEnginePort,
+    ScheduleStatePort,
+};
+use ferrous_dns_domain::{DomainError, GroupOverride, RecordType};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+/// Who a decision is explained for: a client, whose group is resolved the
+/// same way as for its queries, or a group directly.
+#[derive(Debug, Clone, Copy)]
+pub enum ExplainSubject {
+    Client(IpAddr),
+    Group(i64),
+}
+
+/// The decision for a domain together with everything that fed into it.
+#[derive(Debug, Clone)]
+pub struct FilterExplanation {
+    pub domain: String,
+    pub group_id: i64,
+    pub group_name: Option<String>,
+    pub client_ip: Option<IpAddr>,
+    pub record_type: RecordType,
+    pub decision: FilterDecision,
+    pub blocking_enabled: bool,
+    /// Schedule override active for the group, if any.
+    pub schedule: Option<GroupOverride>,
+    /// Safe Search target the query would be rewritten to.
+    pub safe_search_cname: Option<&'static str>,
+    pub trace: FilterTrace,
+}
+
+/// Answers "why was this blocked?" for a domain and a client or group.
+pub struct ExplainFilterDecisionUseCase {
+    engine: Arc<dyn BlockFilterEnginePort>,
+    schedule_state: Arc<dyn ScheduleStatePort>,
+    safe_search: Arc<dyn SafeSearchEnginePort>,
+    group_repo: Arc<dyn GroupRepository>,
+}
+
+impl ExplainFilterDecisionUseCase {
+    pub fn new(
+        engine: Arc<dyn BlockFilterEnginePort>,
+        schedule_state: Arc<dyn ScheduleStatePort>,
+        safe_search: Arc<dyn SafeSearchEnginePort>,
+        group_repo: Arc<dyn GroupRepository>,
+    ) -> Self {
+        Self {
+            engine,
+            schedule_state,
+            safe_search,
+            group_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        domain: &str,
+        subject: ExplainSubject,
+        record_type: RecordType,
+    ) -> Result<FilterExplanation, DomainError> {
+        let domain = domain.trim().trim_end_matches('.').to_ascii_lowercase();
+        if domain.is_empty() {
+            return Err(DomainError::InvalidDomainName(
+                "Domain cannot be empty".to_string(),
+            ));
+        }
+
+        let (group_id, client_ip) = match subject {
+            ExplainSubject::Client(ip) => (self.engine.resolve_group(ip), Some(ip)),
+            ExplainSubject::Group(group_id) => (group_id, None),
+        };
+        let group = self.group_repo.get_by_id(group_id).await?;
+        if group.is_none() && matches!(subject, ExplainSubject::Group(_)) {
+            return Err(DomainError::GroupNotFound(group_id));
+        }
+
+        let decision = match client_ip {
+            Some(ip) => self.engine.check_query(&domain, record_type, ip, group_id),
+            None => self.engine.check(&domain, group_id),
+        };
+        let trace = self
+            .engine
+            .explain(&domain, group_id, client_ip.map(|ip| (record_type, ip)));
+
+        Ok(FilterExplanation {
+            group_name: group.map(|g| g.name.to_string()),
+            client_ip,
+            record_type,
+            decision,
+            blocking_enabled: self.engine.is_blocking_enabled(),
+            schedule: self.schedule_state.get(group_id),
+            safe_search_cname: self.safe_search.cname_for(&domain, group_id),
+            trace,
+            group_id,
+            domain,
+        })
+    }
+}
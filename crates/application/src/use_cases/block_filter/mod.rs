@@ -1,3 +1,5 @@
+pub mod explain_decision;
 pub mod get_stats;
 
+pub use explain_decision::{ExplainFilterDecisionUseCase, ExplainSubject, FilterExplanation};
 pub use get_stats::GetBlockFilterStatsUseCase;
@@ -26,6 +26,7 @@ impl CreateBlocklistSourceUseCase {
         group_ids: Vec<i64>,
         comment: Option<String>,
         enabled: bool,
+        observe_only: bool,
     ) -> Result<BlocklistSource, DomainError> {
         BlocklistSource::validate_name(&name).map_err(DomainError::InvalidBlocklistSource)?;
 
@@ -44,13 +45,21 @@ impl CreateBlocklistSourceUseCase {
 
         let source = self
             .repo
-            .create(name.clone(), url, group_ids.clone(), comment, enabled)
+            .create(
+                name.clone(),
+                url,
+                group_ids.clone(),
+                comment,
+                enabled,
+                observe_only,
+            )
             .await?;
 
         info!(
             source_id = ?source.id,
             name = %name,
             group_ids = ?group_ids,
+            observe_only,
             "Blocklist source created successfully"
         );
 
@@ -67,7 +76,9 @@ impl BlocklistSourceCreator for CreateBlocklistSourceUseCase {
         group_ids: Vec<i64>,
         comment: Option<String>,
         enabled: bool,
+        observe_only: bool,
     ) -> Result<BlocklistSource, DomainError> {
-        self.execute(name, url, group_ids, comment, enabled).await
+        self.execute(name, url, group_ids, comment, enabled, observe_only)
+            .await
     }
 }
@@ -0,0 +1,25 @@
+use crate::ports::QueryLogRepository;
+use ferrous_dns_domain::{BlocklistSourceHits, DomainError};
+use std::sync::Arc;
+
+pub struct GetBlocklistSourceHitsUseCase {
+    query_log_repo: Arc<dyn QueryLogRepository>,
+}
+
+impl GetBlocklistSourceHitsUseCase {
+    pub fn new(query_log_repo: Arc<dyn QueryLogRepository>) -> Self {
+        Self { query_log_repo }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<BlocklistSourceHits>, DomainError> {
+        self.query_log_repo.get_blocklist_source_hits().await
+    }
+
+    /// Sources that blocked nothing in the last 30 days, candidates for
+    /// removal.
+    pub async fn zero_hit_sources(&self) -> Result<Vec<BlocklistSourceHits>, DomainError> {
+        let mut sources = self.execute().await?;
+        sources.retain(|s| s.hits_30d == 0);
+        Ok(sources)
+    }
+}
@@ -1,9 +1,13 @@
 mod create_blocklist_source;
 mod delete_blocklist_source;
+mod get_blocklist_source_hits;
 mod get_blocklist_sources;
+mod simulate_blocklist;
 mod update_blocklist_source;
 
 pub use create_blocklist_source::CreateBlocklistSourceUseCase;
 pub use delete_blocklist_source::DeleteBlocklistSourceUseCase;
+pub use get_blocklist_source_hits::GetBlocklistSourceHitsUseCase;
 pub use get_blocklist_sources::GetBlocklistSourcesUseCase;
+pub use simulate_blocklist::SimulateBlocklistUseCase;
 pub use update_blocklist_source::UpdateBlocklistSourceUseCase;
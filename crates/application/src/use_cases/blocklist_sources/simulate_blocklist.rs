@@ -0,0 +1,71 @@
+use ferrous_dns_domain::{BlocklistSource, DomainError};
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use crate::ports::{
+    BlocklistSimulation, BlocklistSimulator, CandidateList, GroupRepository, SimulationReport,
+};
+
+/// Upper bound on the domains and clients listed per outcome.
+const MAX_SIMULATION_LIMIT: usize = 1000;
+
+pub struct SimulateBlocklistUseCase {
+    simulator: Arc<dyn BlocklistSimulator>,
+    group_repo: Arc<dyn GroupRepository>,
+}
+
+impl SimulateBlocklistUseCase {
+    pub fn new(
+        simulator: Arc<dyn BlocklistSimulator>,
+        group_repo: Arc<dyn GroupRepository>,
+    ) -> Self {
+        Self {
+            simulator,
+            group_repo,
+        }
+    }
+
+    #[instrument(skip(self, simulation), fields(groups = ?simulation.group_ids))]
+    pub async fn execute(
+        &self,
+        mut simulation: BlocklistSimulation,
+    ) -> Result<SimulationReport, DomainError> {
+        match &simulation.list {
+            CandidateList::Url(url) => {
+                BlocklistSource::validate_url(&Some(Arc::from(url.as_str())))
+                    .map_err(DomainError::InvalidInput)?;
+            }
+            CandidateList::Text(text) if text.trim().is_empty() => {
+                return Err(DomainError::InvalidInput(
+                    "List text cannot be empty".to_string(),
+                ));
+            }
+            CandidateList::Text(_) => {}
+        }
+
+        if simulation.from >= simulation.to {
+            return Err(DomainError::InvalidInput(
+                "Time range start must be before its end".to_string(),
+            ));
+        }
+
+        for &gid in &simulation.group_ids {
+            self.group_repo
+                .get_by_id(gid)
+                .await?
+                .ok_or(DomainError::GroupNotFound(gid))?;
+        }
+
+        simulation.limit = simulation.limit.clamp(1, MAX_SIMULATION_LIMIT);
+        let report = self.simulator.simulate(&simulation).await?;
+
+        info!(
+            examined = report.queries_examined,
+            newly_blocked = report.newly_blocked.queries,
+            newly_unblocked = report.newly_unblocked.queries,
+            "Blocklist simulation finished"
+        );
+
+        Ok(report)
+    }
+}
@@ -0,0 +1,250 @@
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use ferrous_dns_domain::{
+    Config, DdnsResult, DdnsUpdate, ForwardingRule, LocalDnsRecord, RecordType,
+};
+use tracing::{info, warn};
+
+use super::HISTORY_LIMIT;
+use crate::ports::DdnsHistoryRepository;
+use crate::use_cases::local_records::{in_zone, is_reverse_zone, owner_matches};
+use crate::use_cases::{CreateLocalRecordUseCase, ValidateApiTokenUseCase};
+
+/// Most hostnames one request may update.
+const MAX_HOSTNAMES: usize = 20;
+
+/// TTL of records created by an update. Dynamic addresses change without
+/// notice, so resolvers should not hold on to them for long.
+const DDNS_TTL: u32 = 60;
+
+/// Shortest interval between two history rows for rejected requests, so
+/// that unauthenticated clients cannot keep writing to (and pruning) the
+/// history table. Rejections in between are only counted.
+const REJECTED_ROW_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Applies dyndns2 updates (`/nic/update`) to local records.
+///
+/// Each hostname has to fall inside one of the forward zones that accept
+/// dynamic updates. Its A or AAAA records are replaced by the requested
+/// addresses in one [`CreateLocalRecordUseCase::execute_batch`], so the
+/// existing records are matched and changed under the same config write
+/// lock every other local-record writer takes, and the TOML file, the
+/// cache and the PTR registry follow. Every authenticated attempt is
+/// written to the update history; rejected requests are written at most
+/// once per [`REJECTED_ROW_INTERVAL`].
+pub struct ApplyDdnsUpdateUseCase {
+    create: Arc<CreateLocalRecordUseCase>,
+    validate_token: Arc<ValidateApiTokenUseCase>,
+    history: Arc<dyn DdnsHistoryRepository>,
+    zones: Vec<Arc<str>>,
+    rejected: Mutex<RejectedRequests>,
+}
+
+/// Rejected requests since the last one written to the history.
+#[derive(Default)]
+struct RejectedRequests {
+    last_row: Option<Instant>,
+    suppressed: u64,
+}
+
+impl ApplyDdnsUpdateUseCase {
+    pub fn new(
+        create: Arc<CreateLocalRecordUseCase>,
+        validate_token: Arc<ValidateApiTokenUseCase>,
+        history: Arc<dyn DdnsHistoryRepository>,
+        zones: &[String],
+    ) -> Self {
+        Self {
+            create,
+            validate_token,
+            history,
+            zones: zones
+                .iter()
+                .map(|zone| ForwardingRule::normalize_domain(zone))
+                .filter(|zone| !is_reverse_zone(zone))
+                .map(Arc::from)
+                .collect(),
+            rejected: Mutex::new(RejectedRequests::default()),
+        }
+    }
+
+    /// Updates each of the comma-separated `hostnames` to `ips` (at most
+    /// one address per family) and returns one outcome per hostname, in
+    /// request order. A request rejected as a whole (too many hostnames or
+    /// bad credentials) yields a single outcome and at most a single
+    /// history row.
+    pub async fn execute(
+        &self,
+        token: Option<&str>,
+        hostnames: &str,
+        ips: &[IpAddr],
+        client_ip: Option<IpAddr>,
+    ) -> Vec<DdnsUpdate> {
+        let mut names = hostnames
+            .split(',')
+            .map(|name| name.trim().trim_end_matches('.'))
+            .filter(|name| !name.is_empty());
+        let first = names.next().unwrap_or_default().to_ascii_lowercase();
+        let too_many = names.clone().nth(MAX_HOSTNAMES - 1).is_some();
+
+        let token_id = match token {
+            Some(token) if !too_many => self.validate_token.execute(token).await.ok(),
+            _ => None,
+        };
+        let outcome = |hostname: String, result: DdnsResult| DdnsUpdate {
+            id: None,
+            hostname: Arc::from(hostname),
+            ip: (!ips.is_empty()).then(|| {
+                Arc::from(
+                    ips.iter()
+                        .map(IpAddr::to_string)
+                        .collect::<Vec<_>>()
+                        .join(","),
+                )
+            }),
+            result,
+            token_id,
+            client_ip: client_ip.map(|ip| Arc::from(ip.to_string())),
+            created_at: None,
+        };
+
+        let outcomes = if too_many {
+            vec![outcome(first, DdnsResult::NumHost)]
+        } else if token_id.is_none() {
+            vec![outcome(first, DdnsResult::BadAuth)]
+        } else {
+            let mut outcomes = Vec::with_capacity(MAX_HOSTNAMES);
+            for name in std::iter::once(first).chain(names.map(str::to_ascii_lowercase)) {
+                let result = self.apply(&name, ips).await;
+                outcomes.push(outcome(name, result));
+            }
+            outcomes
+        };
+
+        if token_id.is_none() && !self.admit_rejected_row() {
+            return outcomes;
+        }
+        for outcome in &outcomes {
+            if let Err(e) = self.history.record(outcome).await {
+                warn!(error = %e, hostname = %outcome.hostname, "Failed to record DDNS update");
+            }
+        }
+        if let Err(e) = self.history.prune(HISTORY_LIMIT).await {
+            warn!(error = %e, "Failed to prune DDNS history");
+        }
+
+        outcomes
+    }
+
+    /// Whether a rejected request may be written to the history now.
+    fn admit_rejected_row(&self) -> bool {
+        let mut rejected = self.rejected.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        if rejected
+            .last_row
+            .is_some_and(|last| now.duration_since(last) < REJECTED_ROW_INTERVAL)
+        {
+            rejected.suppressed += 1;
+            return false;
+        }
+        if rejected.suppressed > 0 {
+            warn!(
+                suppressed = rejected.suppressed,
+                "Rejected DDNS requests left out of the history"
+            );
+        }
+        *rejected = RejectedRequests {
+            last_row: Some(now),
+            suppressed: 0,
+        };
+        true
+    }
+
+    async fn apply(&self, name: &str, ips: &[IpAddr]) -> DdnsResult {
+        if !name.contains('.') || ForwardingRule::validate_domain(name).is_err() {
+            return DdnsResult::NotFqdn;
+        }
+        let Some(zone) = self
+            .zones
+            .iter()
+            .filter(|zone| name != zone.as_ref() && in_zone(name, zone))
+            .max_by_key(|zone| zone.len())
+        else {
+            return DdnsResult::NoHost;
+        };
+        if ips.is_empty() {
+            return DdnsResult::DnsErr;
+        }
+
+        let changed = self
+            .create
+            .execute_batch(|config| {
+                ips.iter().fold(false, |changed, ip| {
+                    set_address(config, name, zone, *ip) | changed
+                })
+            })
+            .await;
+
+        match changed {
+            Ok(true) => {
+                info!(hostname = %name, ips = ?ips, "DDNS update applied");
+                DdnsResult::Good
+            }
+            Ok(false) => DdnsResult::NoChg,
+            Err(e) => {
+                warn!(hostname = %name, error = %e, "DDNS update failed");
+                DdnsResult::DnsErr
+            }
+        }
+    }
+}
+
+/// Makes `ip` the only address of its family at `name`. Returns whether
+/// anything changed.
+fn set_address(config: &mut Config, name: &str, zone: &str, ip: IpAddr) -> bool {
+    let record_type = match ip {
+        IpAddr::V4(_) => RecordType::A,
+        IpAddr::V6(_) => RecordType::AAAA,
+    };
+    let dns = &mut config.dns;
+    let matches = |record: &LocalDnsRecord| {
+        owner_matches(record, &dns.local_domain, name)
+            && record.address().is_some_and(|(rt, _)| rt == record_type)
+    };
+    let existing: Vec<usize> = dns
+        .local_records
+        .iter()
+        .enumerate()
+        .filter(|(_, record)| matches(record))
+        .map(|(index, _)| index)
+        .collect();
+
+    let hostname = name
+        .strip_suffix(zone)
+        .and_then(|host| host.strip_suffix('.'))
+        .unwrap_or(name)
+        .to_string();
+
+    if let [index] = existing[..] {
+        let record = &mut dns.local_records[index];
+        if record.address().is_some_and(|(_, current)| current == ip) {
+            return false;
+        }
+        record.hostname = hostname;
+        record.domain = Some(zone.to_string());
+        record.ip = ip.to_string();
+        return true;
+    }
+
+    dns.local_records.retain(|record| !matches(record));
+    dns.local_records.push(LocalDnsRecord {
+        hostname,
+        domain: Some(zone.to_string()),
+        ip: ip.to_string(),
+        record_type: record_type.as_str().to_string(),
+        ttl: Some(DDNS_TTL),
+    });
+    true
+}
@@ -0,0 +1,27 @@
+use ferrous_dns_domain::{DdnsUpdate, DomainError};
+use std::sync::Arc;
+use tracing::instrument;
+
+use crate::ports::DdnsHistoryRepository;
+
+pub struct GetDdnsHistoryUseCase {
+    repo: Arc<dyn DdnsHistoryRepository>,
+}
+
+impl GetDdnsHistoryUseCase {
+    pub fn new(repo: Arc<dyn DdnsHistoryRepository>) -> Self {
+        Self { repo }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        hostname: Option<&str>,
+        limit: u32,
+    ) -> Result<Vec<DdnsUpdate>, DomainError> {
+        let hostname = hostname.map(|h| h.trim().trim_end_matches('.').to_ascii_lowercase());
+        self.repo
+            .get_recent(hostname.as_deref(), limit.min(super::HISTORY_LIMIT))
+            .await
+    }
+}
@@ -0,0 +1,8 @@
+mod apply_ddns_update;
+mod get_ddns_history;
+
+pub use apply_ddns_update::ApplyDdnsUpdateUseCase;
+pub use get_ddns_history::GetDdnsHistoryUseCase;
+
+/// Number of update attempts kept in the history.
+const HISTORY_LIMIT: u32 = 10_000;
@@ -5,17 +5,20 @@ use super::nxdomain_hijack_guard::NxdomainHijackGuard;
 use super::rate_limiter::{DnsRateLimiter, RateLimitDecision};
 use super::rebinding_guard::RebindingGuard;
 use super::response_ip_filter_guard::ResponseIpFilterGuard;
+use super::split_horizon::{DnsView, SplitHorizon};
 use super::tsc_timer;
 use super::tunneling_guard::{TunnelingAnalysisEvent, TunnelingGuard, TunnelingVerdict};
 use crate::ports::{
-    BlockFilterEnginePort, ClientRepository, DgaFlagStore, DnsResolution, DnsResolver,
-    FilterDecision, NxdomainHijackIpStore, QueryLogRepository, ResponseIpFilterStore,
-    SafeSearchEnginePort, TunnelingFlagStore,
+    BlockFilterEnginePort, ClientRepository, DgaFlagStore, DnsResolution, DnsResolver, DnsRewrite,
+    DnsRewriteStore, FilterDecision, GroupUpstreamStore, NxdomainHijackIpStore, QueryLogRepository,
+    ResponseIpFilterStore, SafeSearchEnginePort, TunnelingFlagStore,
 };
+use crate::services::{DnsMetrics, QueryStream};
 use ferrous_dns_domain::{
-    BlockSource, DgaDetectionAction, DgaDetectionConfig, DnsQuery, DnsRequest, DomainError,
-    NxdomainHijackAction, NxdomainHijackConfig, QueryLog, QuerySource, RecordType,
-    ResponseIpFilterAction, ResponseIpFilterConfig, TunnelingAction, TunnelingDetectionConfig,
+    BlockSource, BlockingConfig, BlockingResponse, DgaDetectionAction, DgaDetectionConfig,
+    DnsConfig, DnsQuery, DnsRequest, DnsRewriteRcode, DomainError, NxdomainHijackAction,
+    NxdomainHijackConfig, QueryLog, QueryScope, QuerySource, RecordType, ResponseIpFilterAction,
+    ResponseIpFilterConfig, TunnelingAction, TunnelingDetectionConfig,
 };
 use lru::LruCache;
 use std::cell::RefCell;
@@ -35,6 +38,7 @@ pub struct HandleDnsQueryUseCase {
     resolver: Arc<dyn DnsResolver>,
     block_filter: Arc<dyn BlockFilterEnginePort>,
     safe_search: Option<Arc<dyn SafeSearchEnginePort>>,
+    dns_rewrites: Option<Arc<dyn DnsRewriteStore>>,
     query_log: Arc<dyn QueryLogRepository>,
     client_repo: Option<Arc<dyn ClientRepository>>,
     client_tracking_interval: Duration,
@@ -49,6 +53,12 @@ pub struct HandleDnsQueryUseCase {
     dga_event_tx: Option<tokio::sync::mpsc::Sender<DgaAnalysisEvent>>,
     dga_flag_store: Option<Arc<dyn DgaFlagStore>>,
     cookie_guard: DnsCookieGuard,
+    blocking_response: BlockingResponse,
+    blocked_ttl: u32,
+    metrics: Arc<DnsMetrics>,
+    query_stream: Option<Arc<QueryStream>>,
+    group_upstreams: Option<Arc<dyn GroupUpstreamStore>>,
+    split_horizon: SplitHorizon,
 }
 
 impl HandleDnsQueryUseCase {
@@ -61,6 +71,7 @@ impl HandleDnsQueryUseCase {
             resolver,
             block_filter,
             safe_search: None,
+            dns_rewrites: None,
             query_log,
             client_repo: None,
             client_tracking_interval: Duration::from_secs(60),
@@ -75,6 +86,12 @@ impl HandleDnsQueryUseCase {
             dga_event_tx: None,
             dga_flag_store: None,
             cookie_guard: DnsCookieGuard::disabled(),
+            blocking_response: BlockingResponse::default(),
+            blocked_ttl: BlockingConfig::default().blocked_ttl,
+            metrics: Arc::new(DnsMetrics::new()),
+            query_stream: None,
+            group_upstreams: None,
+            split_horizon: SplitHorizon::disabled(),
         }
     }
 
@@ -83,6 +100,12 @@ impl HandleDnsQueryUseCase {
         self
     }
 
+    /// Enables user-defined rewrite rules, evaluated after the block check.
+    pub fn with_dns_rewrites(mut self, dns_rewrites: Arc<dyn DnsRewriteStore>) -> Self {
+        self.dns_rewrites = Some(dns_rewrites);
+        self
+    }
+
     pub fn with_client_tracking(
         mut self,
         client_repo: Arc<dyn ClientRepository>,
@@ -180,6 +203,61 @@ impl HandleDnsQueryUseCase {
         self
     }
 
+    /// Shares the query counters with the metrics endpoint.
+    pub fn with_metrics(mut self, metrics: Arc<DnsMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Publishes every logged query to live subscribers.
+    pub fn with_query_stream(mut self, query_stream: Arc<QueryStream>) -> Self {
+        self.query_stream = Some(query_stream);
+        self
+    }
+
+    /// Resolves groups with their own upstream pools through those pools,
+    /// in a cache partition of their own.
+    pub fn with_group_upstreams(mut self, group_upstreams: Arc<dyn GroupUpstreamStore>) -> Self {
+        self.group_upstreams = Some(group_upstreams);
+        self
+    }
+
+    /// Enables the split-horizon views of `dns`. A matching view answers
+    /// its own names before the cache lookup and scopes everything else to
+    /// the view's cache partition and upstreams. Rebinding protection for
+    /// the view's clients also honors the view's allowlist.
+    pub fn with_split_horizon(mut self, dns: &DnsConfig) -> Self {
+        self.split_horizon = SplitHorizon::from_config(dns);
+        self
+    }
+
+    /// Sets the global response shape for blocked queries. Groups with their
+    /// own blocking mode override it.
+    pub fn with_blocking_mode(mut self, config: &BlockingConfig) -> Self {
+        self.blocking_response = config.response();
+        self.blocked_ttl = config.blocked_ttl;
+        self
+    }
+
+    /// Resolves how a blocked query from `client_ip` should be answered:
+    /// the client's group override if set, otherwise the global mode.
+    pub fn blocking_response(&self, client_ip: IpAddr) -> BlockingResponse {
+        let group_id = self.block_filter.resolve_group(client_ip);
+        self.block_filter
+            .blocking_response(group_id)
+            .unwrap_or(self.blocking_response)
+    }
+
+    /// TTL applied to synthesized answers for blocked queries.
+    pub fn blocked_ttl(&self) -> u32 {
+        self.blocked_ttl
+    }
+
+    /// Counters updated for every logged client query.
+    pub fn metrics(&self) -> &DnsMetrics {
+        &self.metrics
+    }
+
     /// Exposes the cookie guard so the server handler can generate server
     /// cookies for inclusion in responses.
     pub fn cookie_guard(&self) -> &DnsCookieGuard {
@@ -276,9 +354,41 @@ impl HandleDnsQueryUseCase {
     }
 
     fn log(&self, query_log: &QueryLog) {
-        if let Err(e) = self.query_log.log_query_sync(query_log) {
+        self.metrics.record_query(query_log);
+        let observed = self.observed_source_ids(query_log);
+        let with_observed;
+        let entry = if observed.is_empty() {
+            query_log
+        } else {
+            with_observed = QueryLog {
+                blocklist_source_ids: observed,
+                ..query_log.clone()
+            };
+            &with_observed
+        };
+        if let Err(e) = self.query_log.log_query_sync(entry) {
             tracing::warn!(error = %e, domain = %query_log.domain, "Failed to log query");
         }
+        if let Some(query_stream) = &self.query_stream {
+            query_stream.publish(entry);
+        }
+    }
+
+    /// Observe-only sources that would have blocked an allowed client query.
+    fn observed_source_ids(&self, query_log: &QueryLog) -> Vec<i64> {
+        match query_log.group_id {
+            Some(group_id)
+                if !query_log.blocked && query_log.query_source == QuerySource::Client =>
+            {
+                self.block_filter.observed_source_ids(
+                    &query_log.domain,
+                    query_log.record_type,
+                    query_log.client_ip,
+                    group_id,
+                )
+            }
+            _ => Vec::new(),
+        }
     }
 
     fn base_query_log(request: &DnsRequest, response_time_us: u64, group_id: i64) -> QueryLog {
@@ -300,6 +410,7 @@ impl HandleDnsQueryUseCase {
             query_source: QuerySource::Client,
             group_id: Some(group_id),
             block_source: None,
+            blocklist_source_ids: Vec::new(),
         }
     }
 
@@ -331,7 +442,98 @@ impl HandleDnsQueryUseCase {
         }
     }
 
-    fn blocked_cname(&self, cname_chain: &[Arc<str>], group_id: i64) -> Option<BlockSource> {
+    fn has_rewrite(&self, domain: &str, group_id: i64) -> bool {
+        self.dns_rewrites
+            .as_deref()
+            .is_some_and(|rw| rw.has_rewrite(domain, group_id))
+    }
+
+    /// Group to route upstream queries and cache lookups for: `Some` only
+    /// when the group resolves through its own upstream pools.
+    fn upstream_group(&self, group_id: i64) -> Option<i64> {
+        self.group_upstreams
+            .as_deref()
+            .is_some_and(|store| store.has_pools(group_id))
+            .then_some(group_id)
+    }
+
+    /// Scope of a query from a client in `view` and `group_id`.
+    fn query_scope(&self, view: Option<&DnsView>, group_id: i64) -> QueryScope {
+        QueryScope {
+            view: view.map(DnsView::id),
+            group: self.upstream_group(group_id),
+        }
+    }
+
+    /// Cache lookup for the fast paths, honoring the scope's cache partition.
+    fn try_cache_scoped(
+        &self,
+        domain: &str,
+        record_type: RecordType,
+        scope: QueryScope,
+    ) -> Option<DnsResolution> {
+        if scope.is_shared() {
+            self.resolver.try_cache_str(domain, record_type)
+        } else {
+            self.resolver
+                .try_cache(&DnsQuery::new(Arc::from(domain), record_type).with_scope(scope))
+        }
+    }
+
+    fn rebinding_guard<'a>(&'a self, view: Option<&'a DnsView>) -> &'a RebindingGuard {
+        view.map_or(&self.rebinding_guard, DnsView::rebinding_guard)
+    }
+
+    /// Answers a query matched by a rewrite rule. The query log marks it with
+    /// [`BlockSource::DnsRewrite`] without counting it as blocked.
+    async fn answer_rewrite(
+        &self,
+        request: &DnsRequest,
+        rewrite: DnsRewrite,
+        tsc_start: u64,
+        group_id: i64,
+        scope: QueryScope,
+    ) -> Result<DnsResolution, DomainError> {
+        let rewritten_log = |elapsed_us: u64| QueryLog {
+            response_status: Some("REWRITTEN"),
+            block_source: Some(BlockSource::DnsRewrite),
+            ..Self::base_query_log(request, elapsed_us, group_id)
+        };
+
+        let resolution = match rewrite {
+            DnsRewrite::Addresses(addresses) => {
+                let mut resolution = DnsResolution::new(addresses, false);
+                resolution.min_ttl = Some(self.blocked_ttl);
+                resolution
+            }
+            DnsRewrite::Cname(target) => {
+                let target_query = DnsQuery::new(target, request.record_type).with_scope(scope);
+                let resolution = self.resolver.resolve(&target_query).await?;
+                self.log(&QueryLog {
+                    cache_hit: resolution.cache_hit,
+                    upstream_server: resolution.upstream_server.clone(),
+                    upstream_pool: resolution.upstream_pool.clone(),
+                    ..rewritten_log(tsc_timer::elapsed_us_since(tsc_start))
+                });
+                return Ok(resolution);
+            }
+            DnsRewrite::Rcode(DnsRewriteRcode::NoError) => DnsResolution::new(Vec::new(), false),
+            DnsRewrite::Rcode(DnsRewriteRcode::NxDomain) => {
+                self.log(&rewritten_log(tsc_timer::elapsed_us_since(tsc_start)));
+                return Err(DomainError::NxDomain);
+            }
+            DnsRewrite::Rcode(DnsRewriteRcode::Refused) => {
+                self.log(&rewritten_log(tsc_timer::elapsed_us_since(tsc_start)));
+                return Err(DomainError::FilteredQuery("DNS rewrite rule".to_string()));
+            }
+        };
+
+        self.log(&rewritten_log(tsc_timer::elapsed_us_since(tsc_start)));
+        Ok(resolution)
+    }
+
+    /// The first name in `cname_chain` that is blocked for `group_id`.
+    fn blocked_cname<'a>(&self, cname_chain: &'a [Arc<str>], group_id: i64) -> Option<&'a str> {
         cname_chain
             .iter()
             .find(|domain| {
@@ -340,7 +542,7 @@ impl HandleDnsQueryUseCase {
                     FilterDecision::Block(_)
                 )
             })
-            .map(|_| BlockSource::CnameCloaking)
+            .map(|domain| &**domain)
     }
 
     /// Checks the cache for a non-IP record type (NS, CNAME, SOA, PTR, MX, TXT,
@@ -355,7 +557,14 @@ impl HandleDnsQueryUseCase {
         let tsc_start = tsc_timer::now();
         let group_id = self.block_filter.resolve_group(client_ip);
 
-        if let FilterDecision::Block(_) = self.block_filter.check(domain, group_id) {
+        if let FilterDecision::Block(_) =
+            self.block_filter
+                .check_query(domain, record_type, client_ip, group_id)
+        {
+            return None;
+        }
+
+        if self.has_rewrite(domain, group_id) {
             return None;
         }
 
@@ -375,7 +584,13 @@ impl HandleDnsQueryUseCase {
             }
         }
 
-        let resolution = self.resolver.try_cache_str(domain, record_type)?;
+        let view = self.split_horizon.match_view(client_ip, group_id);
+        if view.is_some_and(|view| view.defines(domain)) {
+            return None;
+        }
+
+        let resolution =
+            self.try_cache_scoped(domain, record_type, self.query_scope(view, group_id))?;
         let wire = resolution.upstream_wire_data?;
         let ttl = resolution.min_ttl.unwrap_or(0);
 
@@ -399,6 +614,7 @@ impl HandleDnsQueryUseCase {
             query_source: QuerySource::Client,
             group_id: Some(group_id),
             block_source: None,
+            blocklist_source_ids: Vec::new(),
         });
 
         Some((wire, ttl))
@@ -413,7 +629,14 @@ impl HandleDnsQueryUseCase {
         let tsc_start = tsc_timer::now();
         let group_id = self.block_filter.resolve_group(client_ip);
 
-        if let FilterDecision::Block(_) = self.block_filter.check(domain, group_id) {
+        if let FilterDecision::Block(_) =
+            self.block_filter
+                .check_query(domain, record_type, client_ip, group_id)
+        {
+            return None;
+        }
+
+        if self.has_rewrite(domain, group_id) {
             return None;
         }
 
@@ -433,7 +656,13 @@ impl HandleDnsQueryUseCase {
             }
         }
 
-        let resolution = self.resolver.try_cache_str(domain, record_type)?;
+        let view = self.split_horizon.match_view(client_ip, group_id);
+        if view.is_some_and(|view| view.defines(domain)) {
+            return None;
+        }
+
+        let resolution =
+            self.try_cache_scoped(domain, record_type, self.query_scope(view, group_id))?;
         if resolution.addresses.is_empty() {
             return None;
         }
@@ -464,6 +693,7 @@ impl HandleDnsQueryUseCase {
             query_source: QuerySource::Client,
             group_id: Some(group_id),
             block_source: None,
+            blocklist_source_ids: Vec::new(),
         });
 
         Some((resolution.addresses, resolution.min_ttl.unwrap_or(60)))
@@ -476,6 +706,8 @@ impl HandleDnsQueryUseCase {
         self.maybe_track_client(request.client_ip);
 
         let group_id = self.block_filter.resolve_group(request.client_ip);
+        let view = self.split_horizon.match_view(request.client_ip, group_id);
+        let scope = self.query_scope(view, group_id);
 
         match self.rate_limiter.check(request.client_ip, false) {
             RateLimitDecision::Allow => {}
@@ -572,26 +804,53 @@ impl HandleDnsQueryUseCase {
             }
         }
 
-        let dns_query = DnsQuery::new(Arc::clone(&request.domain), request.record_type);
-
-        if let FilterDecision::Block(block_source) =
-            self.block_filter.check(&request.domain, group_id)
-        {
+        let dns_query =
+            DnsQuery::new(Arc::clone(&request.domain), request.record_type).with_scope(scope);
+
+        if let FilterDecision::Block(block_source) = self.block_filter.check_query(
+            &request.domain,
+            request.record_type,
+            request.client_ip,
+            group_id,
+        ) {
+            let blocklist_source_ids = match block_source {
+                BlockSource::Blocklist | BlockSource::CnameCloaking => {
+                    self.block_filter.blocklist_source_ids(
+                        &request.domain,
+                        request.record_type,
+                        request.client_ip,
+                        group_id,
+                    )
+                }
+                _ => Vec::new(),
+            };
             self.log(&QueryLog {
                 blocked: true,
                 response_status: Some("BLOCKED"),
                 block_source: Some(block_source),
+                blocklist_source_ids,
                 ..Self::base_query_log(request, elapsed_us(), group_id)
             });
             return Err(DomainError::Blocked);
         }
 
+        if let Some(rewrite) = self
+            .dns_rewrites
+            .as_deref()
+            .and_then(|rw| rw.rewrite_for(&request.domain, request.record_type, group_id))
+        {
+            return self
+                .answer_rewrite(request, rewrite, tsc_start, group_id, scope)
+                .await;
+        }
+
         if let Some(cname_target) = self
             .safe_search
             .as_deref()
             .and_then(|ss| ss.cname_for(&request.domain, group_id))
         {
-            let safe_query = DnsQuery::new(Arc::from(cname_target), request.record_type);
+            let safe_query =
+                DnsQuery::new(Arc::from(cname_target), request.record_type).with_scope(scope);
             let resolution = self.resolver.resolve(&safe_query).await?;
             self.log(&QueryLog {
                 cache_hit: resolution.cache_hit,
@@ -603,6 +862,17 @@ impl HandleDnsQueryUseCase {
             return Ok(resolution);
         }
 
+        if let Some(view) = view {
+            if let Some(resolution) = view.local_answer(&request.domain, request.record_type) {
+                tracing::debug!(domain = %request.domain, view = view.name(), "Answered from view");
+                self.log(&QueryLog {
+                    response_status: Some("LOCAL_DNS"),
+                    ..Self::base_query_log(request, elapsed_us(), group_id)
+                });
+                return Ok(resolution);
+            }
+        }
+
         if let Some(cached) = self.resolver.try_cache(&dns_query) {
             if cached.has_response_data() {
                 if self.nxdomain_hijack_guard.is_hijacked_response(&cached)
@@ -629,20 +899,31 @@ impl HandleDnsQueryUseCase {
 
         match self.resolver.resolve(&dns_query).await {
             Ok(resolution) => {
-                if let Some(block_source) = self.blocked_cname(&resolution.cname_chain, group_id) {
+                if let Some(blocked_cname) = self.blocked_cname(&resolution.cname_chain, group_id) {
                     let ttl = resolution.min_ttl.map(|t| t as u64).unwrap_or(60).max(5);
-                    self.block_filter
-                        .store_cname_decision(&request.domain, group_id, ttl);
+                    self.block_filter.store_cname_decision(
+                        &request.domain,
+                        blocked_cname,
+                        group_id,
+                        ttl,
+                    );
+                    let blocklist_source_ids = self.block_filter.blocklist_source_ids(
+                        blocked_cname,
+                        request.record_type,
+                        request.client_ip,
+                        group_id,
+                    );
                     self.log(&QueryLog {
                         blocked: true,
                         response_status: Some("BLOCKED"),
-                        block_source: Some(block_source),
+                        block_source: Some(BlockSource::CnameCloaking),
+                        blocklist_source_ids,
                         ..Self::base_query_log(request, elapsed_us(), group_id)
                     });
                     return Err(DomainError::Blocked);
                 }
                 if self
-                    .rebinding_guard
+                    .rebinding_guard(view)
                     .is_rebinding_attempt(&request.domain, &resolution)
                 {
                     self.log(&QueryLog {
@@ -6,6 +6,7 @@ mod nxdomain_hijack_guard;
 pub mod rate_limiter;
 mod rebinding_guard;
 mod response_ip_filter_guard;
+mod split_horizon;
 pub mod tsc_timer;
 mod tunneling_guard;
 pub use cookie_guard::DnsCookieGuard;
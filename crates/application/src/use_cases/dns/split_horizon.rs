@@ -0,0 +1,161 @@
+use super::rebinding_guard::RebindingGuard;
+use crate::ports::DnsResolution;
+use ferrous_dns_domain::{DnsConfig, DnsViewConfig, RecordType};
+use ipnetwork::IpNetwork;
+use rustc_hash::FxHashMap;
+use std::borrow::Cow;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+/// Addresses a view defines for one name.
+struct ViewRecords {
+    ipv4: Vec<IpAddr>,
+    ipv6: Vec<IpAddr>,
+    ttl: u32,
+}
+
+/// A compiled `[[dns.views]]` entry.
+pub(super) struct DnsView {
+    id: u32,
+    name: Arc<str>,
+    subnets: Vec<IpNetwork>,
+    groups: Vec<i64>,
+    records: FxHashMap<Box<str>, ViewRecords>,
+    rebinding_guard: RebindingGuard,
+}
+
+impl DnsView {
+    fn compile(id: u32, view: &DnsViewConfig, dns: &DnsConfig) -> Self {
+        let mut records: FxHashMap<Box<str>, ViewRecords> = FxHashMap::default();
+        for record in &view.local_records {
+            let Some((record_type, ip)) = record.address() else {
+                tracing::warn!(
+                    view = %view.name,
+                    hostname = %record.hostname,
+                    "Invalid local record in view, skipping"
+                );
+                continue;
+            };
+            let fqdn = record.fqdn(&dns.local_domain).to_ascii_lowercase();
+            let ttl = record.ttl_or_default();
+            let entry = records
+                .entry(fqdn.into_boxed_str())
+                .or_insert_with(|| ViewRecords {
+                    ipv4: Vec::new(),
+                    ipv6: Vec::new(),
+                    ttl,
+                });
+            entry.ttl = entry.ttl.min(ttl);
+            match record_type {
+                RecordType::AAAA => entry.ipv6.push(ip),
+                _ => entry.ipv4.push(ip),
+            }
+        }
+
+        let allowlist: Vec<String> = dns
+            .rebinding_allowlist
+            .iter()
+            .chain(&view.rebinding_allowlist)
+            .cloned()
+            .collect();
+
+        Self {
+            id,
+            name: Arc::from(view.name.as_str()),
+            subnets: view
+                .subnets
+                .iter()
+                .filter_map(|subnet| subnet.parse().ok())
+                .collect(),
+            groups: view.groups.clone(),
+            records,
+            rebinding_guard: RebindingGuard::new(
+                dns.rebinding_protection_enabled,
+                dns.local_domain.as_deref(),
+                &allowlist,
+            ),
+        }
+    }
+
+    pub(super) fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub(super) fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn matches(&self, client_ip: IpAddr, group_id: i64) -> bool {
+        self.groups.contains(&group_id) || self.subnets.iter().any(|net| net.contains(client_ip))
+    }
+
+    fn records(&self, domain: &str) -> Option<&ViewRecords> {
+        if self.records.is_empty() {
+            return None;
+        }
+        let domain = if domain.bytes().any(|b| b.is_ascii_uppercase()) {
+            Cow::Owned(domain.to_ascii_lowercase())
+        } else {
+            Cow::Borrowed(domain)
+        };
+        self.records.get(domain.trim_end_matches('.'))
+    }
+
+    /// Whether the view answers `domain` from its own records.
+    pub(super) fn defines(&self, domain: &str) -> bool {
+        self.records(domain).is_some()
+    }
+
+    /// The view's answer for `domain`, or `None` when the view does not
+    /// define it. Other record types of a defined name answer NODATA, so
+    /// the outside answer never leaks into the view.
+    pub(super) fn local_answer(
+        &self,
+        domain: &str,
+        record_type: RecordType,
+    ) -> Option<DnsResolution> {
+        let records = self.records(domain)?;
+        let addresses = match record_type {
+            RecordType::A => records.ipv4.clone(),
+            RecordType::AAAA => records.ipv6.clone(),
+            _ => Vec::new(),
+        };
+        let mut resolution = DnsResolution::new(addresses, false);
+        resolution.local_dns = true;
+        resolution.min_ttl = Some(records.ttl);
+        Some(resolution)
+    }
+
+    pub(super) fn rebinding_guard(&self) -> &RebindingGuard {
+        &self.rebinding_guard
+    }
+}
+
+/// Split-horizon views, matched by client subnet or group in file order.
+pub(super) struct SplitHorizon {
+    views: Vec<DnsView>,
+}
+
+impl SplitHorizon {
+    pub(super) fn disabled() -> Self {
+        Self { views: Vec::new() }
+    }
+
+    pub(super) fn from_config(dns: &DnsConfig) -> Self {
+        Self {
+            views: dns
+                .views
+                .iter()
+                .enumerate()
+                .map(|(id, view)| DnsView::compile(id as u32, view, dns))
+                .collect(),
+        }
+    }
+
+    /// The first view matching the client, if any.
+    pub(super) fn match_view(&self, client_ip: IpAddr, group_id: i64) -> Option<&DnsView> {
+        self.views
+            .iter()
+            .find(|view| view.matches(client_ip, group_id))
+    }
+}
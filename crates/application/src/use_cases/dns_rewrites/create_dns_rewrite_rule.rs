@@ -0,0 +1,65 @@
+use ferrous_dns_domain::{DnsRewriteAnswer, DnsRewriteRule, DomainError};
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use super::reload_rules;
+use crate::ports::{DnsRewriteRuleRepository, DnsRewriteStore, GroupRepository};
+
+pub struct CreateDnsRewriteRuleUseCase {
+    repo: Arc<dyn DnsRewriteRuleRepository>,
+    group_repo: Arc<dyn GroupRepository>,
+    store: Arc<dyn DnsRewriteStore>,
+}
+
+impl CreateDnsRewriteRuleUseCase {
+    pub fn new(
+        repo: Arc<dyn DnsRewriteRuleRepository>,
+        group_repo: Arc<dyn GroupRepository>,
+        store: Arc<dyn DnsRewriteStore>,
+    ) -> Self {
+        Self {
+            repo,
+            group_repo,
+            store,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        domain: String,
+        answer: DnsRewriteAnswer,
+        group_id: i64,
+        enabled: bool,
+        comment: Option<String>,
+    ) -> Result<DnsRewriteRule, DomainError> {
+        let domain = DnsRewriteRule::normalize_domain(&domain);
+        DnsRewriteRule::validate_domain(&domain).map_err(DomainError::InvalidDnsRewriteRule)?;
+        DnsRewriteRule::validate_answer(&domain, &answer)
+            .map_err(DomainError::InvalidDnsRewriteRule)?;
+        DnsRewriteRule::validate_comment(&comment.as_deref().map(Arc::from))
+            .map_err(DomainError::InvalidDnsRewriteRule)?;
+
+        self.group_repo
+            .get_by_id(group_id)
+            .await?
+            .ok_or(DomainError::GroupNotFound(group_id))?;
+
+        let rule = self
+            .repo
+            .create(domain.clone(), answer, group_id, enabled, comment)
+            .await?;
+
+        info!(
+            rule_id = ?rule.id,
+            domain = %domain,
+            rewrite = %rule.answer,
+            group_id = group_id,
+            "DNS rewrite rule created successfully"
+        );
+
+        reload_rules(self.repo.as_ref(), self.store.as_ref()).await;
+
+        Ok(rule)
+    }
+}
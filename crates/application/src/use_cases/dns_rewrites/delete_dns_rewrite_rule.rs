@@ -0,0 +1,33 @@
+use ferrous_dns_domain::DomainError;
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use super::reload_rules;
+use crate::ports::{DnsRewriteRuleRepository, DnsRewriteStore};
+
+pub struct DeleteDnsRewriteRuleUseCase {
+    repo: Arc<dyn DnsRewriteRuleRepository>,
+    store: Arc<dyn DnsRewriteStore>,
+}
+
+impl DeleteDnsRewriteRuleUseCase {
+    pub fn new(repo: Arc<dyn DnsRewriteRuleRepository>, store: Arc<dyn DnsRewriteStore>) -> Self {
+        Self { repo, store }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(&self, id: i64) -> Result<(), DomainError> {
+        self.repo
+            .get_by_id(id)
+            .await?
+            .ok_or(DomainError::DnsRewriteRuleNotFound(id))?;
+
+        self.repo.delete(id).await?;
+
+        info!(rule_id = ?id, "DNS rewrite rule deleted successfully");
+
+        reload_rules(self.repo.as_ref(), self.store.as_ref()).await;
+
+        Ok(())
+    }
+}
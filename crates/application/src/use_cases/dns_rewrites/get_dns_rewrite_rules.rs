@@ -0,0 +1,25 @@
+use ferrous_dns_domain::{DnsRewriteRule, DomainError};
+use std::sync::Arc;
+use tracing::instrument;
+
+use crate::ports::DnsRewriteRuleRepository;
+
+pub struct GetDnsRewriteRulesUseCase {
+    repo: Arc<dyn DnsRewriteRuleRepository>,
+}
+
+impl GetDnsRewriteRulesUseCase {
+    pub fn new(repo: Arc<dyn DnsRewriteRuleRepository>) -> Self {
+        Self { repo }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_all(&self) -> Result<Vec<DnsRewriteRule>, DomainError> {
+        self.repo.get_all().await
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_by_id(&self, id: i64) -> Result<Option<DnsRewriteRule>, DomainError> {
+        self.repo.get_by_id(id).await
+    }
+}
@@ -0,0 +1,20 @@
+mod create_dns_rewrite_rule;
+mod delete_dns_rewrite_rule;
+mod get_dns_rewrite_rules;
+mod update_dns_rewrite_rule;
+
+pub use create_dns_rewrite_rule::CreateDnsRewriteRuleUseCase;
+pub use delete_dns_rewrite_rule::DeleteDnsRewriteRuleUseCase;
+pub use get_dns_rewrite_rules::GetDnsRewriteRulesUseCase;
+pub use update_dns_rewrite_rule::UpdateDnsRewriteRuleUseCase;
+
+use crate::ports::{DnsRewriteRuleRepository, DnsRewriteStore};
+use tracing::error;
+
+/// Pushes the persisted rule set into the live rewrite table.
+async fn reload_rules(repo: &dyn DnsRewriteRuleRepository, store: &dyn DnsRewriteStore) {
+    match repo.get_all().await {
+        Ok(rules) => store.replace_rules(&rules),
+        Err(e) => error!(error = %e, "Failed to reload DNS rewrite rules"),
+    }
+}
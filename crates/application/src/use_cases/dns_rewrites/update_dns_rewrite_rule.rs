@@ -0,0 +1,83 @@
+use ferrous_dns_domain::{DnsRewriteAnswer, DnsRewriteRule, DomainError};
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use super::reload_rules;
+use crate::ports::{DnsRewriteRuleRepository, DnsRewriteStore, GroupRepository};
+
+pub struct UpdateDnsRewriteRuleUseCase {
+    repo: Arc<dyn DnsRewriteRuleRepository>,
+    group_repo: Arc<dyn GroupRepository>,
+    store: Arc<dyn DnsRewriteStore>,
+}
+
+impl UpdateDnsRewriteRuleUseCase {
+    pub fn new(
+        repo: Arc<dyn DnsRewriteRuleRepository>,
+        group_repo: Arc<dyn GroupRepository>,
+        store: Arc<dyn DnsRewriteStore>,
+    ) -> Self {
+        Self {
+            repo,
+            group_repo,
+            store,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        id: i64,
+        domain: Option<String>,
+        answer: Option<DnsRewriteAnswer>,
+        group_id: Option<i64>,
+        enabled: Option<bool>,
+        comment: Option<String>,
+    ) -> Result<DnsRewriteRule, DomainError> {
+        let current = self
+            .repo
+            .get_by_id(id)
+            .await?
+            .ok_or(DomainError::DnsRewriteRuleNotFound(id))?;
+
+        let domain = domain.map(|d| DnsRewriteRule::normalize_domain(&d));
+        if let Some(ref d) = domain {
+            DnsRewriteRule::validate_domain(d).map_err(DomainError::InvalidDnsRewriteRule)?;
+        }
+
+        DnsRewriteRule::validate_answer(
+            domain.as_deref().unwrap_or(&current.domain),
+            answer.as_ref().unwrap_or(&current.answer),
+        )
+        .map_err(DomainError::InvalidDnsRewriteRule)?;
+
+        if let Some(ref c) = comment {
+            DnsRewriteRule::validate_comment(&Some(Arc::from(c.as_str())))
+                .map_err(DomainError::InvalidDnsRewriteRule)?;
+        }
+
+        if let Some(gid) = group_id {
+            self.group_repo
+                .get_by_id(gid)
+                .await?
+                .ok_or(DomainError::GroupNotFound(gid))?;
+        }
+
+        let updated = self
+            .repo
+            .update(id, domain, answer, group_id, enabled, comment)
+            .await?;
+
+        info!(
+            rule_id = ?id,
+            domain = %updated.domain,
+            rewrite = %updated.answer,
+            enabled = %updated.enabled,
+            "DNS rewrite rule updated successfully"
+        );
+
+        reload_rules(self.repo.as_ref(), self.store.as_ref()).await;
+
+        Ok(updated)
+    }
+}
@@ -0,0 +1,51 @@
+use ferrous_dns_domain::{DomainError, ForwardingRule, ForwardingTarget};
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use super::reload_rules;
+use crate::ports::{ForwardingRuleRepository, ForwardingRuleStore};
+
+pub struct CreateForwardingRuleUseCase {
+    repo: Arc<dyn ForwardingRuleRepository>,
+    store: Arc<dyn ForwardingRuleStore>,
+}
+
+impl CreateForwardingRuleUseCase {
+    pub fn new(
+        repo: Arc<dyn ForwardingRuleRepository>,
+        store: Arc<dyn ForwardingRuleStore>,
+    ) -> Self {
+        Self { repo, store }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        domain: String,
+        target: ForwardingTarget,
+        enabled: bool,
+        comment: Option<String>,
+    ) -> Result<ForwardingRule, DomainError> {
+        let domain = ForwardingRule::normalize_domain(&domain);
+        ForwardingRule::validate_domain(&domain).map_err(DomainError::InvalidForwardingRule)?;
+        ForwardingRule::validate_target(&target).map_err(DomainError::InvalidForwardingRule)?;
+        ForwardingRule::validate_comment(&comment.as_deref().map(Arc::from))
+            .map_err(DomainError::InvalidForwardingRule)?;
+
+        let rule = self
+            .repo
+            .create(domain.clone(), target, enabled, comment)
+            .await?;
+
+        info!(
+            rule_id = ?rule.id,
+            domain = %domain,
+            target = %rule.target.value(),
+            "Forwarding rule created successfully"
+        );
+
+        reload_rules(self.repo.as_ref(), self.store.as_ref()).await;
+
+        Ok(rule)
+    }
+}
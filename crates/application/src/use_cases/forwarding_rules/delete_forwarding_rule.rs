@@ -0,0 +1,36 @@
+use ferrous_dns_domain::DomainError;
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use super::reload_rules;
+use crate::ports::{ForwardingRuleRepository, ForwardingRuleStore};
+
+pub struct DeleteForwardingRuleUseCase {
+    repo: Arc<dyn ForwardingRuleRepository>,
+    store: Arc<dyn ForwardingRuleStore>,
+}
+
+impl DeleteForwardingRuleUseCase {
+    pub fn new(
+        repo: Arc<dyn ForwardingRuleRepository>,
+        store: Arc<dyn ForwardingRuleStore>,
+    ) -> Self {
+        Self { repo, store }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(&self, id: i64) -> Result<(), DomainError> {
+        self.repo
+            .get_by_id(id)
+            .await?
+            .ok_or(DomainError::ForwardingRuleNotFound(id))?;
+
+        self.repo.delete(id).await?;
+
+        info!(rule_id = ?id, "Forwarding rule deleted successfully");
+
+        reload_rules(self.repo.as_ref(), self.store.as_ref()).await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use ferrous_dns_domain::{DomainError, ForwardingRule};
+use std::sync::Arc;
+use tracing::instrument;
+
+use crate::ports::ForwardingRuleRepository;
+
+pub struct GetForwardingRulesUseCase {
+    repo: Arc<dyn ForwardingRuleRepository>,
+}
+
+impl GetForwardingRulesUseCase {
+    pub fn new(repo: Arc<dyn ForwardingRuleRepository>) -> Self {
+        Self { repo }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_all(&self) -> Result<Vec<ForwardingRule>, DomainError> {
+        self.repo.get_all().await
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_by_id(&self, id: i64) -> Result<Option<ForwardingRule>, DomainError> {
+        self.repo.get_by_id(id).await
+    }
+}
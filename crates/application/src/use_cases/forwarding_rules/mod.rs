@@ -0,0 +1,20 @@
+mod create_forwarding_rule;
+mod delete_forwarding_rule;
+mod get_forwarding_rules;
+mod update_forwarding_rule;
+
+pub use create_forwarding_rule::CreateForwardingRuleUseCase;
+pub use delete_forwarding_rule::DeleteForwardingRuleUseCase;
+pub use get_forwarding_rules::GetForwardingRulesUseCase;
+pub use update_forwarding_rule::UpdateForwardingRuleUseCase;
+
+use crate::ports::{ForwardingRuleRepository, ForwardingRuleStore};
+use tracing::error;
+
+/// Pushes the persisted rule set into the live resolver table.
+async fn reload_rules(repo: &dyn ForwardingRuleRepository, store: &dyn ForwardingRuleStore) {
+    match repo.get_all().await {
+        Ok(rules) => store.replace_rules(&rules),
+        Err(e) => error!(error = %e, "Failed to reload forwarding rules"),
+    }
+}
@@ -0,0 +1,66 @@
+use ferrous_dns_domain::{DomainError, ForwardingRule, ForwardingTarget};
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use super::reload_rules;
+use crate::ports::{ForwardingRuleRepository, ForwardingRuleStore};
+
+pub struct UpdateForwardingRuleUseCase {
+    repo: Arc<dyn ForwardingRuleRepository>,
+    store: Arc<dyn ForwardingRuleStore>,
+}
+
+impl UpdateForwardingRuleUseCase {
+    pub fn new(
+        repo: Arc<dyn ForwardingRuleRepository>,
+        store: Arc<dyn ForwardingRuleStore>,
+    ) -> Self {
+        Self { repo, store }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        id: i64,
+        domain: Option<String>,
+        target: Option<ForwardingTarget>,
+        enabled: Option<bool>,
+        comment: Option<String>,
+    ) -> Result<ForwardingRule, DomainError> {
+        self.repo
+            .get_by_id(id)
+            .await?
+            .ok_or(DomainError::ForwardingRuleNotFound(id))?;
+
+        let domain = domain.map(|d| ForwardingRule::normalize_domain(&d));
+        if let Some(ref d) = domain {
+            ForwardingRule::validate_domain(d).map_err(DomainError::InvalidForwardingRule)?;
+        }
+
+        if let Some(ref t) = target {
+            ForwardingRule::validate_target(t).map_err(DomainError::InvalidForwardingRule)?;
+        }
+
+        if let Some(ref c) = comment {
+            ForwardingRule::validate_comment(&Some(Arc::from(c.as_str())))
+                .map_err(DomainError::InvalidForwardingRule)?;
+        }
+
+        let updated = self
+            .repo
+            .update(id, domain, target, enabled, comment)
+            .await?;
+
+        info!(
+            rule_id = ?id,
+            domain = %updated.domain,
+            target = %updated.target.value(),
+            enabled = %updated.enabled,
+            "Forwarding rule updated successfully"
+        );
+
+        reload_rules(self.repo.as_ref(), self.store.as_ref()).await;
+
+        Ok(updated)
+    }
+}
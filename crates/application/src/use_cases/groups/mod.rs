@@ -3,9 +3,13 @@ mod create_group;
 mod delete_group;
 mod get_groups;
 mod update_group;
+mod update_group_blocking_mode;
+mod update_group_upstream_pools;
 
 pub use assign_client_group::AssignClientGroupUseCase;
 pub use create_group::CreateGroupUseCase;
 pub use delete_group::DeleteGroupUseCase;
 pub use get_groups::GetGroupsUseCase;
 pub use update_group::UpdateGroupUseCase;
+pub use update_group_blocking_mode::UpdateGroupBlockingModeUseCase;
+pub use update_group_upstream_pools::UpdateGroupUpstreamPoolsUseCase;
@@ -0,0 +1,68 @@
+use ferrous_dns_domain::{BlockingMode, DomainError, Group};
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::sync::Arc;
+use tracing::{error, info, instrument};
+
+use crate::ports::{BlockFilterEnginePort, GroupRepository};
+
+pub struct UpdateGroupBlockingModeUseCase {
+    group_repo: Arc<dyn GroupRepository>,
+    block_filter_engine: Arc<dyn BlockFilterEnginePort>,
+}
+
+impl UpdateGroupBlockingModeUseCase {
+    pub fn new(
+        group_repo: Arc<dyn GroupRepository>,
+        block_filter_engine: Arc<dyn BlockFilterEnginePort>,
+    ) -> Self {
+        Self {
+            group_repo,
+            block_filter_engine,
+        }
+    }
+
+    /// Sets the blocking response override for a group. `mode = None` clears
+    /// the override so the group follows the global `[blocking] mode` again.
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        id: i64,
+        mode: Option<BlockingMode>,
+        ipv4: Option<Ipv4Addr>,
+        ipv6: Option<Ipv6Addr>,
+    ) -> Result<Group, DomainError> {
+        self.group_repo
+            .get_by_id(id)
+            .await?
+            .ok_or(DomainError::GroupNotFound(id))?;
+
+        let (ipv4, ipv6) = match mode {
+            Some(BlockingMode::CustomIp) => {
+                if ipv4.is_none() && ipv6.is_none() {
+                    return Err(DomainError::InvalidInput(
+                        "custom_ip blocking mode requires an IPv4 or IPv6 address".to_string(),
+                    ));
+                }
+                (ipv4, ipv6)
+            }
+            _ => (None, None),
+        };
+
+        let updated_group = self
+            .group_repo
+            .update_blocking_mode(id, mode, ipv4, ipv6)
+            .await?;
+
+        if let Err(e) = self.block_filter_engine.load_client_groups().await {
+            error!(error = %e, "Failed to reload group blocking modes after update");
+        }
+
+        info!(
+            group_id = id,
+            mode = mode.map_or("default", |m| m.as_str()),
+            "Group blocking mode updated"
+        );
+
+        Ok(updated_group)
+    }
+}
@@ -0,0 +1,62 @@
+use ferrous_dns_domain::{Config, DomainError, Group};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::{error, info, instrument};
+
+use crate::ports::{GroupRepository, GroupUpstreamStore};
+
+pub struct UpdateGroupUpstreamPoolsUseCase {
+    group_repo: Arc<dyn GroupRepository>,
+    store: Arc<dyn GroupUpstreamStore>,
+    config: Arc<RwLock<Config>>,
+}
+
+impl UpdateGroupUpstreamPoolsUseCase {
+    pub fn new(
+        group_repo: Arc<dyn GroupRepository>,
+        store: Arc<dyn GroupUpstreamStore>,
+        config: Arc<RwLock<Config>>,
+    ) -> Self {
+        Self {
+            group_repo,
+            store,
+            config,
+        }
+    }
+
+    /// Sets the named `[[dns.pools]]` a group resolves through, tried in the
+    /// given order. An empty list puts the group back on the global pools.
+    #[instrument(skip(self))]
+    pub async fn execute(&self, id: i64, pools: Vec<String>) -> Result<Group, DomainError> {
+        self.group_repo
+            .get_by_id(id)
+            .await?
+            .ok_or(DomainError::GroupNotFound(id))?;
+
+        let pools: Vec<String> = pools.into_iter().map(|p| p.trim().to_string()).collect();
+        Group::validate_upstream_pools(&pools).map_err(DomainError::InvalidInput)?;
+
+        {
+            let config = self.config.read().await;
+            if let Some(unknown) = pools
+                .iter()
+                .find(|name| !config.dns.pools.iter().any(|p| &p.name == *name))
+            {
+                return Err(DomainError::InvalidInput(format!(
+                    "Unknown upstream pool '{unknown}'"
+                )));
+            }
+        }
+
+        let updated_group = self.group_repo.update_upstream_pools(id, &pools).await?;
+
+        match self.group_repo.get_all().await {
+            Ok(groups) => self.store.replace_groups(&groups),
+            Err(e) => error!(error = %e, "Failed to reload group upstream pools after update"),
+        }
+
+        info!(group_id = id, pools = ?pools, "Group upstream pools updated");
+
+        Ok(updated_group)
+    }
+}
@@ -1,9 +1,12 @@
+use std::collections::HashSet;
+use std::net::IpAddr;
 use std::sync::Arc;
 
 use async_trait::async_trait;
 use ferrous_dns_domain::{Config, DomainError, LocalDnsRecord, RecordType};
 use tokio::sync::RwLock;
 
+use super::owner_matches;
 use crate::ports::{ConfigRepository, DnsCachePort, LocalRecordCreator, PtrRecordRegistry};
 
 pub struct CreateLocalRecordUseCase {
@@ -96,6 +99,101 @@ impl CreateLocalRecordUseCase {
 
         Ok((new_record, new_index))
     }
+
+    /// Lets `edit` add, change and remove local records (and the stored
+    /// dynamic-update records) under one config write lock, then saves the
+    /// result once. Checks made inside `edit` therefore see exactly the
+    /// records the batch is applied to.
+    ///
+    /// Nothing is saved when `edit` leaves both lists unchanged, and a failed
+    /// save restores them, so a batch is applied completely or not at all.
+    /// After a successful save the PTR registry and cache are brought in line
+    /// with every record the batch added or removed.
+    pub async fn execute_batch<T>(
+        &self,
+        edit: impl FnOnce(&mut Config) -> T,
+    ) -> Result<T, DomainError> {
+        let mut config = self.config.write().await;
+        let previous = config.dns.local_records.clone();
+        let previous_stored = config.dns.dynamic_update_records.clone();
+
+        let result = edit(&mut config);
+        if config.dns.local_records == previous
+            && config.dns.dynamic_update_records == previous_stored
+        {
+            return Ok(result);
+        }
+
+        if let Err(e) = self.config_repo.save_local_records(&config).await {
+            config.dns.local_records = previous;
+            config.dns.dynamic_update_records = previous_stored;
+            return Err(DomainError::IoError(format!(
+                "Failed to save configuration: {}",
+                e
+            )));
+        }
+
+        self.sync_changed(&config, &previous);
+        Ok(result)
+    }
+
+    /// Re-registers the PTR and cache entries of every record found in only
+    /// one of `previous` and the current local records.
+    fn sync_changed(&self, config: &Config, previous: &[LocalDnsRecord]) {
+        let local_domain = &config.dns.local_domain;
+        let current = &config.dns.local_records;
+        let before: HashSet<&LocalDnsRecord> = previous.iter().collect();
+        let after: HashSet<&LocalDnsRecord> = current.iter().collect();
+        let changed: Vec<(String, RecordType, IpAddr)> = before
+            .symmetric_difference(&after)
+            .filter_map(|record| {
+                let (record_type, ip) = record.address()?;
+                let fqdn = record
+                    .fqdn(local_domain)
+                    .trim_end_matches('.')
+                    .to_ascii_lowercase();
+                Some((fqdn, record_type, ip))
+            })
+            .collect();
+
+        if let Some(ref registry) = self.ptr_registry {
+            let addresses: HashSet<IpAddr> = changed.iter().map(|(_, _, ip)| *ip).collect();
+            for ip in addresses {
+                let owner = current
+                    .iter()
+                    .find(|record| record.address().is_some_and(|(_, addr)| addr == ip));
+                match owner {
+                    Some(record) => registry.register(
+                        ip,
+                        Arc::from(record.fqdn(local_domain)),
+                        record.ttl_or_default(),
+                    ),
+                    None => registry.unregister(ip),
+                }
+            }
+        }
+
+        if let Some(ref cache) = self.dns_cache {
+            let rrsets: HashSet<(&str, RecordType)> = changed
+                .iter()
+                .map(|(fqdn, record_type, _)| (fqdn.as_str(), *record_type))
+                .collect();
+            for (fqdn, record_type) in rrsets {
+                let addresses: Vec<IpAddr> = current
+                    .iter()
+                    .filter(|record| owner_matches(record, local_domain, fqdn))
+                    .filter_map(|record| record.address())
+                    .filter(|(rt, _)| *rt == record_type)
+                    .map(|(_, ip)| ip)
+                    .collect();
+                if addresses.is_empty() {
+                    cache.remove_record(fqdn, &record_type);
+                } else {
+                    cache.insert_permanent_record(fqdn, record_type, addresses);
+                }
+            }
+        }
+    }
 }
 
 #[async_trait]
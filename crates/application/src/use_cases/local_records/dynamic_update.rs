@@ -0,0 +1,359 @@
+use std::sync::Arc;
+
+use ferrous_dns_domain::{
+    Config, DynamicUpdate, DynamicUpdateRecord, LocalDnsRecord, PrivateIpFilter, RecordType,
+    UpdateData, UpdateOperation, UpdatePrerequisite, UpdateRcode,
+};
+use tokio::sync::RwLock;
+use tracing::{info, warn};
+
+use super::CreateLocalRecordUseCase;
+use crate::ports::PtrRecordRegistry;
+
+/// Applies RFC 2136 UPDATE messages to local records.
+///
+/// Updates are all-or-nothing (RFC 2136 §3.4.2). Forward-zone updates run
+/// as one [`CreateLocalRecordUseCase::execute_batch`]: prerequisites are
+/// checked and every operation applied under the same config write lock,
+/// the records are saved once, and the PTR registry and cache follow. A
+/// failed save leaves the records as they were. Reverse zones change the
+/// PTR registry directly, also under the config write lock so that they
+/// are serialized with every other local-record writer.
+pub struct ApplyDynamicUpdateUseCase {
+    config: Arc<RwLock<Config>>,
+    create: Arc<CreateLocalRecordUseCase>,
+    ptr_registry: Option<Arc<dyn PtrRecordRegistry>>,
+    zones: Vec<Arc<str>>,
+}
+
+impl ApplyDynamicUpdateUseCase {
+    pub fn new(
+        config: Arc<RwLock<Config>>,
+        create: Arc<CreateLocalRecordUseCase>,
+        zones: &[String],
+    ) -> Self {
+        Self {
+            config,
+            create,
+            ptr_registry: None,
+            zones: zones
+                .iter()
+                .map(|zone| Arc::from(normalize_name(zone)))
+                .collect(),
+        }
+    }
+
+    /// Attaches the live PTR registry that reverse-zone updates write to.
+    pub fn with_ptr_registry(mut self, registry: Option<Arc<dyn PtrRecordRegistry>>) -> Self {
+        self.ptr_registry = registry;
+        self
+    }
+
+    /// Whether any zone accepts updates.
+    pub fn is_enabled(&self) -> bool {
+        !self.zones.is_empty()
+    }
+
+    pub async fn execute(&self, update: &DynamicUpdate) -> Result<(), UpdateRcode> {
+        if !self.zones.contains(&update.zone) {
+            return Err(UpdateRcode::NotAuth);
+        }
+        let zone = update.zone.as_ref();
+        let reverse = is_reverse_zone(zone);
+
+        if update
+            .prerequisites
+            .iter()
+            .any(|prerequisite| !in_zone(prerequisite.name(), zone))
+        {
+            return Err(UpdateRcode::NotZone);
+        }
+        for operation in &update.operations {
+            if !in_zone(operation.name(), zone) {
+                return Err(UpdateRcode::NotZone);
+            }
+            let supported = match operation {
+                UpdateOperation::Add {
+                    name,
+                    data: UpdateData::Address(_) | UpdateData::Rdata { .. },
+                    ..
+                } => !reverse && name.as_ref() != zone,
+                UpdateOperation::Add {
+                    name,
+                    data: UpdateData::Ptr(_),
+                    ..
+                } => reverse && PrivateIpFilter::extract_ip_from_ptr(name).is_some(),
+                _ => true,
+            };
+            if !supported {
+                return Err(UpdateRcode::Refused);
+            }
+        }
+        if reverse && self.ptr_registry.is_none() {
+            return Err(UpdateRcode::Refused);
+        }
+
+        if reverse {
+            let _serialized = self.config.write().await;
+            for prerequisite in &update.prerequisites {
+                check_prerequisite(prerequisite, &self.reverse_data(prerequisite.name()))?;
+            }
+            for operation in &update.operations {
+                self.apply_reverse(operation);
+            }
+        } else {
+            self.create
+                .execute_batch(|config| apply_forward(config, zone, update))
+                .await
+                .map_err(|e| {
+                    warn!(zone = %zone, error = %e, "Dynamic update failed to save local records");
+                    UpdateRcode::ServFail
+                })??;
+        }
+
+        info!(
+            zone = %zone,
+            prerequisites = update.prerequisites.len(),
+            operations = update.operations.len(),
+            "Dynamic update applied"
+        );
+        Ok(())
+    }
+
+    /// The PTR target registered for the address `name` points at. Reverse
+    /// updates write to the registry, so that is where they are checked.
+    fn reverse_data(&self, name: &str) -> Vec<UpdateData> {
+        PrivateIpFilter::extract_ip_from_ptr(name)
+            .zip(self.ptr_registry.as_ref())
+            .and_then(|(ip, registry)| registry.lookup(ip))
+            .map(|target| UpdateData::Ptr(Arc::from(normalize_name(&target))))
+            .into_iter()
+            .collect()
+    }
+
+    fn apply_reverse(&self, operation: &UpdateOperation) {
+        let Some(ref registry) = self.ptr_registry else {
+            return;
+        };
+        let Some(ip) = PrivateIpFilter::extract_ip_from_ptr(operation.name()) else {
+            return;
+        };
+        match operation {
+            UpdateOperation::Add {
+                data: UpdateData::Ptr(target),
+                ttl,
+                ..
+            } => registry.register(ip, target.clone(), *ttl),
+            UpdateOperation::Add { .. } => {}
+            UpdateOperation::DeleteRrset { record_type, .. } => {
+                if *record_type == RecordType::PTR {
+                    registry.unregister(ip);
+                }
+            }
+            UpdateOperation::DeleteName { .. } => registry.unregister(ip),
+            UpdateOperation::DeleteRecord {
+                data: UpdateData::Ptr(target),
+                ..
+            } => {
+                let registered = registry.lookup(ip);
+                if registered.is_some_and(|current| normalize_name(&current) == target.as_ref()) {
+                    registry.unregister(ip);
+                }
+            }
+            UpdateOperation::DeleteRecord { .. } => {}
+        }
+    }
+}
+
+/// Checks the prerequisites of a forward-zone `update` against `config` and
+/// applies its operations to the local and stored records in order.
+fn apply_forward(
+    config: &mut Config,
+    zone: &str,
+    update: &DynamicUpdate,
+) -> Result<(), UpdateRcode> {
+    for prerequisite in &update.prerequisites {
+        let existing: Vec<UpdateData> = forward_data(config, prerequisite.name()).collect();
+        check_prerequisite(prerequisite, &existing)?;
+    }
+
+    let dns = &mut config.dns;
+    for operation in &update.operations {
+        apply_to_records(&mut dns.local_records, &dns.local_domain, zone, operation);
+        apply_to_stored(&mut dns.dynamic_update_records, operation);
+    }
+    Ok(())
+}
+
+/// Applies one forward-zone operation to `records`.
+fn apply_to_records(
+    records: &mut Vec<LocalDnsRecord>,
+    local_domain: &Option<String>,
+    zone: &str,
+    operation: &UpdateOperation,
+) {
+    let name = operation.name();
+    match operation {
+        UpdateOperation::Add {
+            data: UpdateData::Address(ip),
+            ttl,
+            ..
+        } => {
+            let exists = records.iter().any(|record| {
+                owner_matches(record, local_domain, name)
+                    && record.address().is_some_and(|(_, addr)| addr == *ip)
+            });
+            if exists {
+                return;
+            }
+            let hostname = name
+                .strip_suffix(zone)
+                .and_then(|host| host.strip_suffix('.'))
+                .unwrap_or(name);
+            records.push(LocalDnsRecord {
+                hostname: hostname.to_string(),
+                domain: Some(zone.to_string()),
+                ip: ip.to_string(),
+                record_type: UpdateData::Address(*ip).record_type().as_str().to_string(),
+                ttl: Some(*ttl),
+            });
+        }
+        UpdateOperation::Add { .. } => {}
+        _ => records.retain(|record| {
+            let deleted = owner_matches(record, local_domain, name)
+                && record
+                    .address()
+                    .is_some_and(|(_, ip)| deletes(operation, &UpdateData::Address(ip)));
+            !deleted
+        }),
+    }
+}
+
+/// Applies one forward-zone operation to the stored `TXT`/`DHCID` records.
+fn apply_to_stored(stored: &mut Vec<DynamicUpdateRecord>, operation: &UpdateOperation) {
+    let name = operation.name();
+    match operation {
+        UpdateOperation::Add {
+            data: data @ UpdateData::Rdata { record_type, rdata },
+            ttl,
+            ..
+        } => {
+            let exists = stored
+                .iter()
+                .any(|record| record.name == name && record.update_data().as_ref() == Some(data));
+            if !exists {
+                stored.push(DynamicUpdateRecord::new(name, *record_type, rdata, *ttl));
+            }
+        }
+        UpdateOperation::Add { .. } => {}
+        _ => stored.retain(|record| {
+            let deleted = record.name == name
+                && record
+                    .update_data()
+                    .is_some_and(|data| deletes(operation, &data));
+            !deleted
+        }),
+    }
+}
+
+/// Whether the delete `operation` covers a record holding `held`.
+fn deletes(operation: &UpdateOperation, held: &UpdateData) -> bool {
+    match operation {
+        UpdateOperation::DeleteRrset { record_type, .. } => *record_type == held.record_type(),
+        UpdateOperation::DeleteName { .. } => true,
+        UpdateOperation::DeleteRecord { data, .. } => data == held,
+        UpdateOperation::Add { .. } => false,
+    }
+}
+
+/// Checks `prerequisite` against the records `existing` at its name.
+fn check_prerequisite(
+    prerequisite: &UpdatePrerequisite,
+    existing: &[UpdateData],
+) -> Result<(), UpdateRcode> {
+    let of_type = |record_type: RecordType| {
+        existing
+            .iter()
+            .filter(move |data| data.record_type() == record_type)
+    };
+
+    match prerequisite {
+        UpdatePrerequisite::RrsetExists { record_type, .. } => {
+            if of_type(*record_type).next().is_none() {
+                return Err(UpdateRcode::NxRrset);
+            }
+        }
+        UpdatePrerequisite::RrsetEquals {
+            record_type, data, ..
+        } => {
+            let rrset: Vec<&UpdateData> = of_type(*record_type).collect();
+            let equal = !rrset.is_empty()
+                && rrset.iter().all(|held| data.contains(*held))
+                && data.iter().all(|wanted| rrset.contains(&wanted));
+            if !equal {
+                return Err(UpdateRcode::NxRrset);
+            }
+        }
+        UpdatePrerequisite::RrsetAbsent { record_type, .. } => {
+            if of_type(*record_type).next().is_some() {
+                return Err(UpdateRcode::YxRrset);
+            }
+        }
+        UpdatePrerequisite::NameInUse { .. } => {
+            if existing.is_empty() {
+                return Err(UpdateRcode::NxDomain);
+            }
+        }
+        UpdatePrerequisite::NameNotInUse { .. } => {
+            if !existing.is_empty() {
+                return Err(UpdateRcode::YxDomain);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Addresses of the local records named `name`, and the `TXT`/`DHCID`
+/// records stored for it.
+fn forward_data<'a>(config: &'a Config, name: &'a str) -> impl Iterator<Item = UpdateData> + 'a {
+    let addresses = config
+        .dns
+        .local_records
+        .iter()
+        .filter(move |record| owner_matches(record, &config.dns.local_domain, name))
+        .filter_map(|record| record.address())
+        .map(|(_, ip)| UpdateData::Address(ip));
+    let stored = config
+        .dns
+        .dynamic_update_records
+        .iter()
+        .filter(move |record| record.name == name)
+        .filter_map(DynamicUpdateRecord::update_data);
+    addresses.chain(stored)
+}
+
+pub(crate) fn owner_matches(
+    record: &LocalDnsRecord,
+    local_domain: &Option<String>,
+    name: &str,
+) -> bool {
+    record
+        .fqdn(local_domain)
+        .trim_end_matches('.')
+        .eq_ignore_ascii_case(name)
+}
+
+fn normalize_name(name: &str) -> String {
+    name.trim().trim_end_matches('.').to_ascii_lowercase()
+}
+
+pub(crate) fn in_zone(name: &str, zone: &str) -> bool {
+    name == zone
+        || name
+            .strip_suffix(zone)
+            .is_some_and(|prefix| prefix.ends_with('.'))
+}
+
+pub(crate) fn is_reverse_zone(zone: &str) -> bool {
+    in_zone(zone, "in-addr.arpa") || in_zone(zone, "ip6.arpa")
+}
@@ -1,7 +1,11 @@
 pub mod create;
 pub mod delete;
+pub mod dynamic_update;
 pub mod update;
 
 pub use create::CreateLocalRecordUseCase;
 pub use delete::DeleteLocalRecordUseCase;
+pub use dynamic_update::ApplyDynamicUpdateUseCase;
 pub use update::UpdateLocalRecordUseCase;
+
+pub(crate) use dynamic_update::{in_zone, is_reverse_zone, owner_matches};
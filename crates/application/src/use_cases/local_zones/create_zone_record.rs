@@ -0,0 +1,64 @@
+use ferrous_dns_domain::{DomainError, ZoneRecord};
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use super::{load_zone, reload_zones};
+use crate::ports::{LocalZoneRepository, LocalZoneStore};
+
+pub struct CreateZoneRecordUseCase {
+    repo: Arc<dyn LocalZoneRepository>,
+    store: Arc<dyn LocalZoneStore>,
+}
+
+impl CreateZoneRecordUseCase {
+    pub fn new(repo: Arc<dyn LocalZoneRepository>, store: Arc<dyn LocalZoneStore>) -> Self {
+        Self { repo, store }
+    }
+
+    /// Appends a record to the zone, bumps the SOA serial and rewrites the
+    /// zone file. Returns the record and its index in the zone.
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        origin: &str,
+        name: &str,
+        ttl: Option<u32>,
+        record_type: &str,
+        data: &str,
+    ) -> Result<(ZoneRecord, usize), DomainError> {
+        let mut zone = load_zone(self.repo.as_ref(), origin).await?;
+
+        let name = zone
+            .qualify_name(name)
+            .map_err(DomainError::InvalidZoneRecord)?;
+        let record = ZoneRecord::new(
+            &name,
+            ttl.unwrap_or_else(|| zone.default_ttl()),
+            record_type,
+            data,
+        );
+        zone.validate_record(&record, None)
+            .map_err(DomainError::InvalidZoneRecord)?;
+
+        zone.records.push(record.clone());
+        zone.bump_serial();
+        zone.sort_records();
+        let index = zone
+            .records
+            .iter()
+            .position(|r| *r == record)
+            .unwrap_or_default();
+        self.repo.save(&zone).await?;
+
+        info!(
+            zone = %zone.origin,
+            name = %record.name,
+            record_type = %record.record_type,
+            "Zone record created"
+        );
+
+        reload_zones(self.repo.as_ref(), self.store.as_ref()).await;
+
+        Ok((record, index))
+    }
+}
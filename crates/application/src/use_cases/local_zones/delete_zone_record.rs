@@ -0,0 +1,42 @@
+use ferrous_dns_domain::{DomainError, ZoneRecord};
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use super::{load_zone, reload_zones};
+use crate::ports::{LocalZoneRepository, LocalZoneStore};
+
+pub struct DeleteZoneRecordUseCase {
+    repo: Arc<dyn LocalZoneRepository>,
+    store: Arc<dyn LocalZoneStore>,
+}
+
+impl DeleteZoneRecordUseCase {
+    pub fn new(repo: Arc<dyn LocalZoneRepository>, store: Arc<dyn LocalZoneStore>) -> Self {
+        Self { repo, store }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(&self, origin: &str, index: usize) -> Result<ZoneRecord, DomainError> {
+        let mut zone = load_zone(self.repo.as_ref(), origin).await?;
+        if index >= zone.records.len() {
+            return Err(DomainError::ZoneRecordNotFound(index));
+        }
+        zone.validate_removal(index)
+            .map_err(DomainError::InvalidZoneRecord)?;
+
+        let removed = zone.records.remove(index);
+        zone.bump_serial();
+        self.repo.save(&zone).await?;
+
+        info!(
+            zone = %zone.origin,
+            name = %removed.name,
+            record_type = %removed.record_type,
+            "Zone record deleted"
+        );
+
+        reload_zones(self.repo.as_ref(), self.store.as_ref()).await;
+
+        Ok(removed)
+    }
+}
@@ -0,0 +1,26 @@
+use ferrous_dns_domain::{DomainError, LocalZone};
+use std::sync::Arc;
+use tracing::instrument;
+
+use super::load_zone;
+use crate::ports::LocalZoneRepository;
+
+pub struct GetLocalZonesUseCase {
+    repo: Arc<dyn LocalZoneRepository>,
+}
+
+impl GetLocalZonesUseCase {
+    pub fn new(repo: Arc<dyn LocalZoneRepository>) -> Self {
+        Self { repo }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_all(&self) -> Result<Vec<LocalZone>, DomainError> {
+        self.repo.get_all().await
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get(&self, origin: &str) -> Result<LocalZone, DomainError> {
+        load_zone(self.repo.as_ref(), origin).await
+    }
+}
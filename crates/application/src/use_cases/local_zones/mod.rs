@@ -0,0 +1,28 @@
+mod create_zone_record;
+mod delete_zone_record;
+mod get_local_zones;
+mod update_zone_record;
+
+pub use create_zone_record::CreateZoneRecordUseCase;
+pub use delete_zone_record::DeleteZoneRecordUseCase;
+pub use get_local_zones::GetLocalZonesUseCase;
+pub use update_zone_record::UpdateZoneRecordUseCase;
+
+use crate::ports::{LocalZoneRepository, LocalZoneStore};
+use ferrous_dns_domain::{DomainError, LocalZone};
+use tracing::error;
+
+async fn load_zone(repo: &dyn LocalZoneRepository, origin: &str) -> Result<LocalZone, DomainError> {
+    let origin = LocalZone::normalize_name(origin);
+    repo.get(&origin)
+        .await?
+        .ok_or(DomainError::LocalZoneNotFound(origin))
+}
+
+/// Pushes the persisted zones into the live authority.
+async fn reload_zones(repo: &dyn LocalZoneRepository, store: &dyn LocalZoneStore) {
+    match repo.get_all().await {
+        Ok(zones) => store.replace_zones(&zones),
+        Err(e) => error!(error = %e, "Failed to reload local zones"),
+    }
+}
@@ -0,0 +1,69 @@
+use ferrous_dns_domain::{DomainError, ZoneRecord};
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use super::{load_zone, reload_zones};
+use crate::ports::{LocalZoneRepository, LocalZoneStore};
+
+pub struct UpdateZoneRecordUseCase {
+    repo: Arc<dyn LocalZoneRepository>,
+    store: Arc<dyn LocalZoneStore>,
+}
+
+impl UpdateZoneRecordUseCase {
+    pub fn new(repo: Arc<dyn LocalZoneRepository>, store: Arc<dyn LocalZoneStore>) -> Self {
+        Self { repo, store }
+    }
+
+    /// Replaces the record at `index`. Editing the SOA itself keeps the
+    /// submitted serial; any other edit bumps it. Returns the record with
+    /// its new index, which moves if the name or type changed.
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        origin: &str,
+        index: usize,
+        name: &str,
+        ttl: Option<u32>,
+        record_type: &str,
+        data: &str,
+    ) -> Result<(ZoneRecord, usize), DomainError> {
+        let mut zone = load_zone(self.repo.as_ref(), origin).await?;
+        let existing_ttl = zone
+            .records
+            .get(index)
+            .map(|r| r.ttl)
+            .ok_or(DomainError::ZoneRecordNotFound(index))?;
+
+        let name = zone
+            .qualify_name(name)
+            .map_err(DomainError::InvalidZoneRecord)?;
+        let record = ZoneRecord::new(&name, ttl.unwrap_or(existing_ttl), record_type, data);
+        zone.validate_record(&record, Some(index))
+            .map_err(DomainError::InvalidZoneRecord)?;
+
+        zone.records[index] = record.clone();
+        if !record.is_type("SOA") {
+            zone.bump_serial();
+        }
+        zone.sort_records();
+        let position = zone
+            .records
+            .iter()
+            .position(|r| *r == record)
+            .unwrap_or(index);
+        self.repo.save(&zone).await?;
+
+        info!(
+            zone = %zone.origin,
+            index,
+            name = %record.name,
+            record_type = %record.record_type,
+            "Zone record updated"
+        );
+
+        reload_zones(self.repo.as_ref(), self.store.as_ref()).await;
+
+        Ok((record, position))
+    }
+}
@@ -1,3 +1,4 @@
+pub mod acme_dns;
 pub mod api_tokens;
 pub mod auth;
 pub mod backup;
@@ -10,18 +11,30 @@ pub mod client_subnets;
 pub mod clients;
 pub mod config;
 pub mod custom_services;
+pub mod ddns;
 pub mod dns;
+pub mod dns_rewrites;
+pub mod forwarding_rules;
 pub mod groups;
 pub mod local_records;
+pub mod local_zones;
 pub mod managed_domains;
+pub mod negative_trust_anchors;
 pub mod queries;
 pub mod regex_filters;
 pub mod safe_search;
 pub mod schedule;
+pub mod tls;
+pub mod trust_anchors;
+pub mod tsig_keys;
 pub mod users;
 pub mod whitelist;
 pub mod whitelist_sources;
 
+pub use acme_dns::{
+    DeleteAcmeRegistrationUseCase, GetAcmeRegistrationsUseCase, RegisterAcmeAccountUseCase,
+    RegisteredAcmeAccount, UpdateAcmeChallengeUseCase,
+};
 pub use api_tokens::{
     CreateApiTokenUseCase, CreatedApiToken, DeleteApiTokenUseCase, GetApiTokensUseCase,
     UpdateApiTokenUseCase, ValidateApiTokenUseCase,
@@ -31,14 +44,16 @@ pub use auth::{
     LoginUseCase, LogoutUseCase, SetupPasswordUseCase, ValidateSessionUseCase,
 };
 pub use backup::{BackupSnapshot, ExportConfigUseCase, ImportConfigUseCase, ImportSummary};
-pub use block_filter::GetBlockFilterStatsUseCase;
+pub use block_filter::{
+    ExplainFilterDecisionUseCase, ExplainSubject, FilterExplanation, GetBlockFilterStatsUseCase,
+};
 pub use blocked_services::{
     BlockServiceUseCase, GetBlockedServicesUseCase, GetServiceCatalogUseCase, UnblockServiceUseCase,
 };
 pub use blocklist::GetBlocklistUseCase;
 pub use blocklist_sources::{
-    CreateBlocklistSourceUseCase, DeleteBlocklistSourceUseCase, GetBlocklistSourcesUseCase,
-    UpdateBlocklistSourceUseCase,
+    CreateBlocklistSourceUseCase, DeleteBlocklistSourceUseCase, GetBlocklistSourceHitsUseCase,
+    GetBlocklistSourcesUseCase, SimulateBlocklistUseCase, UpdateBlocklistSourceUseCase,
 };
 pub use cache::GetCacheStatsUseCase;
 pub use client_subnets::{
@@ -53,18 +68,35 @@ pub use custom_services::{
     CreateCustomServiceUseCase, DeleteCustomServiceUseCase, GetCustomServicesUseCase,
     UpdateCustomServiceUseCase,
 };
+pub use ddns::{ApplyDdnsUpdateUseCase, GetDdnsHistoryUseCase};
 pub use dns::HandleDnsQueryUseCase;
+pub use dns_rewrites::{
+    CreateDnsRewriteRuleUseCase, DeleteDnsRewriteRuleUseCase, GetDnsRewriteRulesUseCase,
+    UpdateDnsRewriteRuleUseCase,
+};
+pub use forwarding_rules::{
+    CreateForwardingRuleUseCase, DeleteForwardingRuleUseCase, GetForwardingRulesUseCase,
+    UpdateForwardingRuleUseCase,
+};
 pub use groups::{
     AssignClientGroupUseCase, CreateGroupUseCase, DeleteGroupUseCase, GetGroupsUseCase,
-    UpdateGroupUseCase,
+    UpdateGroupBlockingModeUseCase, UpdateGroupUpstreamPoolsUseCase, UpdateGroupUseCase,
 };
 pub use local_records::{
-    CreateLocalRecordUseCase, DeleteLocalRecordUseCase, UpdateLocalRecordUseCase,
+    ApplyDynamicUpdateUseCase, CreateLocalRecordUseCase, DeleteLocalRecordUseCase,
+    UpdateLocalRecordUseCase,
+};
+pub use local_zones::{
+    CreateZoneRecordUseCase, DeleteZoneRecordUseCase, GetLocalZonesUseCase, UpdateZoneRecordUseCase,
 };
 pub use managed_domains::{
     CreateManagedDomainUseCase, DeleteManagedDomainUseCase, GetManagedDomainsUseCase,
     UpdateManagedDomainUseCase,
 };
+pub use negative_trust_anchors::{
+    CleanupExpiredNegativeTrustAnchorsUseCase, CreateNegativeTrustAnchorUseCase,
+    DeleteNegativeTrustAnchorUseCase, GetNegativeTrustAnchorsUseCase,
+};
 pub use queries::{
     CleanupOldQueryLogsUseCase, GetQueryRateUseCase, GetQueryStatsUseCase, GetRecentQueriesUseCase,
     GetTimelineUseCase, GetTopAllowedDomainsUseCase, GetTopBlockedDomainsUseCase,
@@ -81,6 +113,11 @@ pub use schedule::{
     AssignScheduleProfileUseCase, CreateScheduleProfileUseCase, DeleteScheduleProfileUseCase,
     GetScheduleProfilesUseCase, ManageTimeSlotsUseCase, UpdateScheduleProfileUseCase,
 };
+pub use tls::RenewAcmeCertificateUseCase;
+pub use trust_anchors::{
+    GetTrustAnchorsUseCase, LoadTrustAnchorsUseCase, RefreshTrustAnchorsUseCase,
+};
+pub use tsig_keys::{CreateTsigKeyUseCase, DeleteTsigKeyUseCase, GetTsigKeysUseCase};
 pub use users::{CreateUserUseCase, DeleteUserUseCase, GetUsersUseCase};
 pub use whitelist::GetWhitelistUseCase;
 pub use whitelist_sources::{
@@ -0,0 +1,37 @@
+use ferrous_dns_domain::{DomainError, NTA_TIMESTAMP_FORMAT};
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use super::reload_anchors;
+use crate::ports::{NegativeTrustAnchorRepository, NegativeTrustAnchorStore};
+
+/// Deletes negative trust anchors whose expiry has passed.
+pub struct CleanupExpiredNegativeTrustAnchorsUseCase {
+    repo: Arc<dyn NegativeTrustAnchorRepository>,
+    store: Arc<dyn NegativeTrustAnchorStore>,
+}
+
+impl CleanupExpiredNegativeTrustAnchorsUseCase {
+    pub fn new(
+        repo: Arc<dyn NegativeTrustAnchorRepository>,
+        store: Arc<dyn NegativeTrustAnchorStore>,
+    ) -> Self {
+        Self { repo, store }
+    }
+
+    /// Returns the number of anchors removed.
+    pub async fn execute(&self) -> Result<u64, DomainError> {
+        let now = chrono::Utc::now().format(NTA_TIMESTAMP_FORMAT).to_string();
+        self.execute_at(&now).await
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute_at(&self, now: &str) -> Result<u64, DomainError> {
+        let deleted = self.repo.delete_expired(now).await?;
+        if deleted > 0 {
+            info!(deleted, "Expired negative trust anchors removed");
+            reload_anchors(self.repo.as_ref(), self.store.as_ref()).await;
+        }
+        Ok(deleted)
+    }
+}
@@ -0,0 +1,72 @@
+use ferrous_dns_domain::{DomainError, NegativeTrustAnchor, NTA_TIMESTAMP_FORMAT};
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use super::reload_anchors;
+use crate::ports::{NegativeTrustAnchorRepository, NegativeTrustAnchorStore};
+
+pub struct CreateNegativeTrustAnchorUseCase {
+    repo: Arc<dyn NegativeTrustAnchorRepository>,
+    store: Arc<dyn NegativeTrustAnchorStore>,
+}
+
+impl CreateNegativeTrustAnchorUseCase {
+    pub fn new(
+        repo: Arc<dyn NegativeTrustAnchorRepository>,
+        store: Arc<dyn NegativeTrustAnchorStore>,
+    ) -> Self {
+        Self { repo, store }
+    }
+
+    /// Adds an anchor for `domain`, expiring `duration_secs` from now when
+    /// given.
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        domain: String,
+        duration_secs: Option<u64>,
+        comment: Option<String>,
+    ) -> Result<NegativeTrustAnchor, DomainError> {
+        let domain = NegativeTrustAnchor::normalize_domain(&domain);
+        NegativeTrustAnchor::validate_domain(&domain)
+            .map_err(DomainError::InvalidNegativeTrustAnchor)?;
+        NegativeTrustAnchor::validate_comment(&comment.as_deref().map(Arc::from))
+            .map_err(DomainError::InvalidNegativeTrustAnchor)?;
+
+        let expires_at = match duration_secs {
+            Some(0) => {
+                return Err(DomainError::InvalidNegativeTrustAnchor(
+                    "Duration must be greater than zero".to_string(),
+                ))
+            }
+            Some(secs) => {
+                let secs = i64::try_from(secs).map_err(|_| {
+                    DomainError::InvalidNegativeTrustAnchor("Duration is too large".to_string())
+                })?;
+                let expiry = chrono::Utc::now()
+                    .checked_add_signed(chrono::Duration::seconds(secs))
+                    .ok_or_else(|| {
+                        DomainError::InvalidNegativeTrustAnchor("Duration is too large".to_string())
+                    })?;
+                Some(expiry.format(NTA_TIMESTAMP_FORMAT).to_string())
+            }
+            None => None,
+        };
+
+        let anchor = self
+            .repo
+            .create(domain.clone(), expires_at, comment)
+            .await?;
+
+        info!(
+            anchor_id = ?anchor.id,
+            domain = %domain,
+            expires_at = ?anchor.expires_at,
+            "Negative trust anchor created successfully"
+        );
+
+        reload_anchors(self.repo.as_ref(), self.store.as_ref()).await;
+
+        Ok(anchor)
+    }
+}
@@ -0,0 +1,36 @@
+use ferrous_dns_domain::DomainError;
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use super::reload_anchors;
+use crate::ports::{NegativeTrustAnchorRepository, NegativeTrustAnchorStore};
+
+pub struct DeleteNegativeTrustAnchorUseCase {
+    repo: Arc<dyn NegativeTrustAnchorRepository>,
+    store: Arc<dyn NegativeTrustAnchorStore>,
+}
+
+impl DeleteNegativeTrustAnchorUseCase {
+    pub fn new(
+        repo: Arc<dyn NegativeTrustAnchorRepository>,
+        store: Arc<dyn NegativeTrustAnchorStore>,
+    ) -> Self {
+        Self { repo, store }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(&self, id: i64) -> Result<(), DomainError> {
+        self.repo
+            .get_by_id(id)
+            .await?
+            .ok_or(DomainError::NegativeTrustAnchorNotFound(id))?;
+
+        self.repo.delete(id).await?;
+
+        info!(anchor_id = ?id, "Negative trust anchor deleted successfully");
+
+        reload_anchors(self.repo.as_ref(), self.store.as_ref()).await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use ferrous_dns_domain::{DomainError, NegativeTrustAnchor};
+use std::sync::Arc;
+use tracing::instrument;
+
+use crate::ports::NegativeTrustAnchorRepository;
+
+pub struct GetNegativeTrustAnchorsUseCase {
+    repo: Arc<dyn NegativeTrustAnchorRepository>,
+}
+
+impl GetNegativeTrustAnchorsUseCase {
+    pub fn new(repo: Arc<dyn NegativeTrustAnchorRepository>) -> Self {
+        Self { repo }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_all(&self) -> Result<Vec<NegativeTrustAnchor>, DomainError> {
+        self.repo.get_all().await
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_by_id(&self, id: i64) -> Result<Option<NegativeTrustAnchor>, DomainError> {
+        self.repo.get_by_id(id).await
+    }
+}
@@ -0,0 +1,23 @@
+mod cleanup_expired_negative_trust_anchors;
+mod create_negative_trust_anchor;
+mod delete_negative_trust_anchor;
+mod get_negative_trust_anchors;
+
+pub use cleanup_expired_negative_trust_anchors::CleanupExpiredNegativeTrustAnchorsUseCase;
+pub use create_negative_trust_anchor::CreateNegativeTrustAnchorUseCase;
+pub use delete_negative_trust_anchor::DeleteNegativeTrustAnchorUseCase;
+pub use get_negative_trust_anchors::GetNegativeTrustAnchorsUseCase;
+
+use crate::ports::{NegativeTrustAnchorRepository, NegativeTrustAnchorStore};
+use tracing::error;
+
+/// Pushes the persisted anchors into the live validator set.
+async fn reload_anchors(
+    repo: &dyn NegativeTrustAnchorRepository,
+    store: &dyn NegativeTrustAnchorStore,
+) {
+    match repo.get_all().await {
+        Ok(anchors) => store.replace_anchors(&anchors),
+        Err(e) => error!(error = %e, "Failed to reload negative trust anchors"),
+    }
+}
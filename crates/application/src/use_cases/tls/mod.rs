@@ -0,0 +1,3 @@
+mod renew_acme_certificate;
+
+pub use renew_acme_certificate::RenewAcmeCertificateUseCase;
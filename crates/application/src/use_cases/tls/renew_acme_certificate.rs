@@ -0,0 +1,106 @@
+use ferrous_dns_domain::DomainError;
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use crate::ports::{
+    AcmeCertificateIssuer, TlsCertificateInfo, TlsCertificatePort, TlsCertificateReloader,
+};
+
+/// Obtains a certificate from the ACME CA when the current one is missing,
+/// expiring or no longer covers the configured names, writes it to every
+/// listener's certificate paths and swaps it into the running listeners.
+pub struct RenewAcmeCertificateUseCase {
+    issuer: Arc<dyn AcmeCertificateIssuer>,
+    certificates: Arc<dyn TlsCertificatePort>,
+    reloader: Arc<dyn TlsCertificateReloader>,
+    domains: Vec<String>,
+    paths: Vec<(String, String)>,
+    renew_before_secs: i64,
+}
+
+impl RenewAcmeCertificateUseCase {
+    /// `paths` are the certificate/key file pairs to write. The first pair
+    /// decides whether a renewal is due.
+    pub fn new(
+        issuer: Arc<dyn AcmeCertificateIssuer>,
+        certificates: Arc<dyn TlsCertificatePort>,
+        reloader: Arc<dyn TlsCertificateReloader>,
+        domains: Vec<String>,
+        paths: Vec<(String, String)>,
+    ) -> Self {
+        Self {
+            issuer,
+            certificates,
+            reloader,
+            domains,
+            paths,
+            renew_before_secs: 30 * 86_400,
+        }
+    }
+
+    pub fn with_renew_before_days(mut self, days: u32) -> Self {
+        self.renew_before_secs = i64::from(days) * 86_400;
+        self
+    }
+
+    /// Returns true when a new certificate was installed.
+    pub async fn execute(&self) -> Result<bool, DomainError> {
+        self.execute_at(chrono::Utc::now().timestamp()).await
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute_at(&self, now: i64) -> Result<bool, DomainError> {
+        let Some((cert_path, key_path)) = self.paths.first() else {
+            return Ok(false);
+        };
+
+        let status = self.certificates.get_status(cert_path, key_path).await;
+        let Some(reason) = self.renewal_reason(&status, now) else {
+            return Ok(false);
+        };
+        info!(reason, domains = ?self.domains, "Requesting ACME certificate");
+
+        let issued = self.issuer.issue(&self.domains).await?;
+
+        for (cert_path, key_path) in &self.paths {
+            self.certificates
+                .save_certificates(
+                    issued.cert_pem.as_bytes(),
+                    issued.key_pem.as_bytes(),
+                    cert_path,
+                    key_path,
+                )
+                .await?;
+            self.reloader.reload(cert_path, key_path)?;
+        }
+
+        info!(
+            domains = ?self.domains,
+            files = self.paths.len(),
+            "ACME certificate installed"
+        );
+        Ok(true)
+    }
+
+    fn renewal_reason(&self, status: &TlsCertificateInfo, now: i64) -> Option<&'static str> {
+        if !status.cert_exists || !status.key_exists {
+            return Some("no certificate");
+        }
+        let Some(not_after) = status.cert_not_after_unix else {
+            return Some("unreadable certificate");
+        };
+        if !status.cert_valid || not_after - now <= self.renew_before_secs {
+            return Some("certificate expiring");
+        }
+        let covered = self.domains.iter().all(|domain| {
+            status
+                .cert_dns_names
+                .iter()
+                .any(|name| name.eq_ignore_ascii_case(domain))
+        });
+        if !covered {
+            return Some("configured names changed");
+        }
+        None
+    }
+}
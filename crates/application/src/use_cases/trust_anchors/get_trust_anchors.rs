@@ -0,0 +1,20 @@
+use ferrous_dns_domain::{DomainError, TrustAnchor};
+use std::sync::Arc;
+use tracing::instrument;
+
+use crate::ports::TrustAnchorRepository;
+
+pub struct GetTrustAnchorsUseCase {
+    repo: Arc<dyn TrustAnchorRepository>,
+}
+
+impl GetTrustAnchorsUseCase {
+    pub fn new(repo: Arc<dyn TrustAnchorRepository>) -> Self {
+        Self { repo }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_all(&self) -> Result<Vec<TrustAnchor>, DomainError> {
+        self.repo.get_all().await
+    }
+}
@@ -0,0 +1,54 @@
+use ferrous_dns_domain::{DomainError, TrustAnchor, TrustAnchorSource};
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use super::reload_anchors;
+use crate::ports::{TrustAnchorRepository, TrustAnchorStorePort};
+
+/// Merges the configured anchors into the persisted set at startup.
+///
+/// Anchors already known keep their RFC 5011 state, so a revoked key
+/// listed in an old `root.key` is not trusted again. Anchors removed from
+/// the configuration are dropped, together with the keys learned for
+/// zones that are no longer anchored.
+pub struct LoadTrustAnchorsUseCase {
+    repo: Arc<dyn TrustAnchorRepository>,
+    store: Arc<dyn TrustAnchorStorePort>,
+}
+
+impl LoadTrustAnchorsUseCase {
+    pub fn new(repo: Arc<dyn TrustAnchorRepository>, store: Arc<dyn TrustAnchorStorePort>) -> Self {
+        Self { repo, store }
+    }
+
+    #[instrument(skip(self, configured))]
+    pub async fn execute(&self, configured: &[TrustAnchor]) -> Result<(), DomainError> {
+        let stored = self.repo.get_all().await?;
+
+        for anchor in &stored {
+            let zone_configured = configured.iter().any(|c| c.zone == anchor.zone);
+            let dropped = match anchor.source {
+                TrustAnchorSource::Rfc5011 => !zone_configured,
+                _ => !configured.iter().any(|c| same_anchor(c, anchor)),
+            };
+            if let (true, Some(id)) = (dropped, anchor.id) {
+                self.repo.delete(id).await?;
+                info!(zone = %anchor.zone, key_tag = anchor.key_tag(), "Trust anchor no longer configured, removed");
+            }
+        }
+
+        for anchor in configured {
+            if !stored.iter().any(|s| same_anchor(s, anchor)) {
+                self.repo.save(anchor).await?;
+                info!(zone = %anchor.zone, key_tag = anchor.key_tag(), source = anchor.source.as_str(), "Trust anchor added");
+            }
+        }
+
+        reload_anchors(self.repo.as_ref(), self.store.as_ref()).await;
+        Ok(())
+    }
+}
+
+fn same_anchor(a: &TrustAnchor, b: &TrustAnchor) -> bool {
+    a.zone == b.zone && a.data.same_key(&b.data)
+}
@@ -0,0 +1,18 @@
+mod get_trust_anchors;
+mod load_trust_anchors;
+mod refresh_trust_anchors;
+
+pub use get_trust_anchors::GetTrustAnchorsUseCase;
+pub use load_trust_anchors::LoadTrustAnchorsUseCase;
+pub use refresh_trust_anchors::RefreshTrustAnchorsUseCase;
+
+use crate::ports::{TrustAnchorRepository, TrustAnchorStorePort};
+use tracing::error;
+
+/// Pushes the persisted anchors into the live validator.
+async fn reload_anchors(repo: &dyn TrustAnchorRepository, store: &dyn TrustAnchorStorePort) {
+    match repo.get_all().await {
+        Ok(anchors) => store.replace_anchors(&anchors),
+        Err(e) => error!(error = %e, "Failed to reload trust anchors"),
+    }
+}
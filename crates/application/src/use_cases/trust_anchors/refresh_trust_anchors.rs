@@ -0,0 +1,257 @@
+use ferrous_dns_domain::{
+    DomainError, TrustAnchor, TrustAnchorData, TrustAnchorSource, TrustAnchorState,
+};
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use tracing::{info, instrument, warn};
+
+use super::reload_anchors;
+use crate::ports::{ProbedKey, TrustAnchorProbe, TrustAnchorRepository, TrustAnchorStorePort};
+
+const DEFAULT_HOLD_DOWN_SECS: i64 = 30 * 86_400;
+
+/// Probes the DNSKEY RRsets of managed zones and advances each key through
+/// the RFC 5011 state machine.
+pub struct RefreshTrustAnchorsUseCase {
+    repo: Arc<dyn TrustAnchorRepository>,
+    store: Arc<dyn TrustAnchorStorePort>,
+    probe: Arc<dyn TrustAnchorProbe>,
+    hold_down_secs: i64,
+}
+
+impl RefreshTrustAnchorsUseCase {
+    pub fn new(
+        repo: Arc<dyn TrustAnchorRepository>,
+        store: Arc<dyn TrustAnchorStorePort>,
+        probe: Arc<dyn TrustAnchorProbe>,
+    ) -> Self {
+        Self {
+            repo,
+            store,
+            probe,
+            hold_down_secs: DEFAULT_HOLD_DOWN_SECS,
+        }
+    }
+
+    pub fn with_hold_down_days(mut self, days: u32) -> Self {
+        self.hold_down_secs = i64::from(days) * 86_400;
+        self
+    }
+
+    /// Returns the number of anchors whose state changed.
+    pub async fn execute(&self) -> Result<usize, DomainError> {
+        self.execute_at(chrono::Utc::now().timestamp()).await
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute_at(&self, now: i64) -> Result<usize, DomainError> {
+        let anchors = self.repo.get_all().await?;
+        let zones: BTreeSet<Arc<str>> = anchors
+            .iter()
+            .filter(|a| a.managed && a.state != TrustAnchorState::Removed)
+            .map(|a| Arc::clone(&a.zone))
+            .collect();
+
+        let mut changed = 0;
+        for zone in zones {
+            let keys = match self.probe.fetch_keys(&zone).await {
+                Ok(keys) => keys,
+                Err(e) => {
+                    warn!(zone = %zone, error = %e, "Trust anchor probe failed");
+                    continue;
+                }
+            };
+
+            let zone_anchors: Vec<TrustAnchor> =
+                anchors.iter().filter(|a| a.zone == zone).cloned().collect();
+
+            if !self.is_authenticated(&zone, &keys, &zone_anchors) {
+                warn!(zone = %zone, "DNSKEY RRset is not signed by a trusted key; skipping");
+                continue;
+            }
+
+            changed += self.update_zone(&zone, &keys, zone_anchors, now).await?;
+        }
+
+        if changed > 0 {
+            reload_anchors(self.repo.as_ref(), self.store.as_ref()).await;
+        }
+        Ok(changed)
+    }
+
+    /// RFC 5011 §2.2: only a DNSKEY RRset signed by a trusted key may
+    /// change anchor state. A trusted key that has just revoked itself
+    /// still authenticates the RRset carrying its revocation.
+    fn is_authenticated(&self, zone: &str, keys: &[ProbedKey], anchors: &[TrustAnchor]) -> bool {
+        keys.iter().filter(|k| k.signs_rrset).any(|k| {
+            anchors
+                .iter()
+                .any(|a| a.is_trusted() && self.matches(zone, &k.data, a))
+        })
+    }
+
+    fn matches(&self, zone: &str, key: &TrustAnchorData, anchor: &TrustAnchor) -> bool {
+        match &anchor.data {
+            TrustAnchorData::Dnskey { .. } => anchor.data.same_key(key),
+            ds @ TrustAnchorData::Ds { .. } => {
+                self.probe.matches_ds(zone, &key.without_revoke(), ds)
+            }
+        }
+    }
+
+    async fn update_zone(
+        &self,
+        zone: &str,
+        keys: &[ProbedKey],
+        mut anchors: Vec<TrustAnchor>,
+        now: i64,
+    ) -> Result<usize, DomainError> {
+        let hold_down_until = now + self.hold_down_secs;
+        let mut seen = vec![false; anchors.len()];
+        let mut changed = 0;
+
+        for key in keys.iter().filter(|k| k.data.is_sep()) {
+            let index = anchors.iter().position(|a| {
+                matches!(a.data, TrustAnchorData::Dnskey { .. }) && a.data.same_key(&key.data)
+            });
+
+            if let Some(i) = index {
+                seen[i] = true;
+                let anchor = &mut anchors[i];
+                if !anchor.managed {
+                    continue;
+                }
+                let before = anchor.state;
+
+                if key.data.is_revoked() {
+                    // §2.1: a revocation only counts when the key signed it.
+                    if key.signs_rrset
+                        && !matches!(
+                            before,
+                            TrustAnchorState::Revoked | TrustAnchorState::Removed
+                        )
+                    {
+                        anchor.state = TrustAnchorState::Revoked;
+                        anchor.hold_down_until = Some(hold_down_until);
+                    }
+                } else {
+                    match before {
+                        TrustAnchorState::AddPending
+                            if anchor.hold_down_until.is_none_or(|t| now >= t) =>
+                        {
+                            anchor.state = TrustAnchorState::Valid;
+                            anchor.hold_down_until = None;
+                        }
+                        TrustAnchorState::Missing => anchor.state = TrustAnchorState::Valid,
+                        _ => {}
+                    }
+                }
+
+                anchor.last_seen = Some(now);
+                if anchor.state != before {
+                    changed += 1;
+                    info!(
+                        zone,
+                        key_tag = anchor.key_tag(),
+                        from = before.as_str(),
+                        to = anchor.state.as_str(),
+                        "Trust anchor state changed"
+                    );
+                }
+                self.repo.save(anchor).await?;
+                continue;
+            }
+
+            if key.data.is_revoked() {
+                continue;
+            }
+
+            // A key named by a trusted DS anchor is trusted straight away and
+            // takes over from the digest, which cannot follow rollovers itself.
+            let ds_index = anchors.iter().position(|a| {
+                a.managed
+                    && a.is_trusted()
+                    && matches!(a.data, TrustAnchorData::Ds { .. })
+                    && self.probe.matches_ds(zone, &key.data, &a.data)
+            });
+
+            let mut added = match ds_index {
+                Some(i) => {
+                    let ds = &mut anchors[i];
+                    ds.state = TrustAnchorState::Removed;
+                    ds.last_seen = Some(now);
+                    self.repo.save(ds).await?;
+                    TrustAnchor::new(
+                        zone,
+                        key.data.clone(),
+                        TrustAnchorSource::Rfc5011,
+                        true,
+                        now,
+                    )
+                }
+                None => {
+                    let mut pending = TrustAnchor::new(
+                        zone,
+                        key.data.clone(),
+                        TrustAnchorSource::Rfc5011,
+                        true,
+                        now,
+                    );
+                    pending.state = TrustAnchorState::AddPending;
+                    pending.hold_down_until = Some(hold_down_until);
+                    pending
+                }
+            };
+            added.last_seen = Some(now);
+            let added = self.repo.save(&added).await?;
+            info!(
+                zone,
+                key_tag = added.key_tag(),
+                state = added.state.as_str(),
+                "New trust anchor key seen"
+            );
+            anchors.push(added);
+            seen.push(true);
+            changed += 1;
+        }
+
+        for (anchor, seen) in anchors.iter_mut().zip(seen) {
+            if !anchor.managed || matches!(anchor.data, TrustAnchorData::Ds { .. }) {
+                continue;
+            }
+            let before = anchor.state;
+            match before {
+                // §4: a key that vanishes during the add hold-down starts over.
+                TrustAnchorState::AddPending if !seen => {
+                    if let Some(id) = anchor.id {
+                        self.repo.delete(id).await?;
+                    }
+                    info!(
+                        zone,
+                        key_tag = anchor.key_tag(),
+                        "Pending trust anchor key withdrawn"
+                    );
+                    changed += 1;
+                    continue;
+                }
+                TrustAnchorState::Valid if !seen => anchor.state = TrustAnchorState::Missing,
+                TrustAnchorState::Revoked if anchor.hold_down_until.is_none_or(|t| now >= t) => {
+                    anchor.state = TrustAnchorState::Removed;
+                    anchor.hold_down_until = None;
+                }
+                _ => continue,
+            }
+            changed += 1;
+            info!(
+                zone,
+                key_tag = anchor.key_tag(),
+                from = before.as_str(),
+                to = anchor.state.as_str(),
+                "Trust anchor state changed"
+            );
+            self.repo.save(anchor).await?;
+        }
+
+        Ok(changed)
+    }
+}
@@ -0,0 +1,79 @@
+use base64::Engine;
+use ferrous_dns_domain::{DomainError, TsigKey};
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use super::reload_keys;
+use crate::ports::{TsigKeyRepository, TsigKeyStore};
+
+/// Shortest secret accepted, in bytes. RFC 8945 §6 asks for keys at least
+/// as long as the HMAC output; 16 bytes keeps imported legacy keys usable.
+const MIN_SECRET_LEN: usize = 16;
+
+/// Length of generated secrets, in bytes (the HMAC-SHA256 output size).
+const GENERATED_SECRET_LEN: usize = 32;
+
+const DEFAULT_ALGORITHM: &str = "hmac-sha256";
+
+pub struct CreateTsigKeyUseCase {
+    repo: Arc<dyn TsigKeyRepository>,
+    store: Arc<dyn TsigKeyStore>,
+}
+
+impl CreateTsigKeyUseCase {
+    pub fn new(repo: Arc<dyn TsigKeyRepository>, store: Arc<dyn TsigKeyStore>) -> Self {
+        Self { repo, store }
+    }
+
+    /// Adds a key named `name`. Without `secret`, a random one is generated;
+    /// a given secret must be base64, as exported by `tsig-keygen`.
+    #[instrument(skip(self, secret))]
+    pub async fn execute(
+        &self,
+        name: String,
+        algorithm: Option<String>,
+        secret: Option<String>,
+    ) -> Result<TsigKey, DomainError> {
+        let name = TsigKey::normalize_name(&name);
+        TsigKey::validate_name(&name).map_err(DomainError::InvalidTsigKey)?;
+
+        let algorithm =
+            TsigKey::normalize_algorithm(algorithm.as_deref().unwrap_or(DEFAULT_ALGORITHM));
+        TsigKey::validate_algorithm(&algorithm).map_err(DomainError::InvalidTsigKey)?;
+
+        let secret = match secret {
+            Some(secret) => {
+                let secret = secret.trim().to_string();
+                let decoded = base64::engine::general_purpose::STANDARD
+                    .decode(&secret)
+                    .map_err(|_| {
+                        DomainError::InvalidTsigKey("Secret must be base64-encoded".to_string())
+                    })?;
+                if decoded.len() < MIN_SECRET_LEN {
+                    return Err(DomainError::InvalidTsigKey(format!(
+                        "Secret must be at least {MIN_SECRET_LEN} bytes"
+                    )));
+                }
+                secret
+            }
+            None => generate_secret()?,
+        };
+
+        let key = self.repo.create(name.clone(), algorithm, secret).await?;
+
+        info!(key_id = ?key.id, name = %name, "TSIG key created successfully");
+
+        reload_keys(self.repo.as_ref(), self.store.as_ref()).await;
+
+        Ok(key)
+    }
+}
+
+fn generate_secret() -> Result<String, DomainError> {
+    use ring::rand::SecureRandom;
+    let mut buf = [0u8; GENERATED_SECRET_LEN];
+    ring::rand::SystemRandom::new()
+        .fill(&mut buf)
+        .map_err(|_| DomainError::IoError("CSPRNG fill failed".to_string()))?;
+    Ok(base64::engine::general_purpose::STANDARD.encode(buf))
+}
@@ -0,0 +1,33 @@
+use ferrous_dns_domain::DomainError;
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use super::reload_keys;
+use crate::ports::{TsigKeyRepository, TsigKeyStore};
+
+pub struct DeleteTsigKeyUseCase {
+    repo: Arc<dyn TsigKeyRepository>,
+    store: Arc<dyn TsigKeyStore>,
+}
+
+impl DeleteTsigKeyUseCase {
+    pub fn new(repo: Arc<dyn TsigKeyRepository>, store: Arc<dyn TsigKeyStore>) -> Self {
+        Self { repo, store }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(&self, id: i64) -> Result<(), DomainError> {
+        self.repo
+            .get_by_id(id)
+            .await?
+            .ok_or(DomainError::TsigKeyNotFound(id))?;
+
+        self.repo.delete(id).await?;
+
+        info!(key_id = ?id, "TSIG key deleted successfully");
+
+        reload_keys(self.repo.as_ref(), self.store.as_ref()).await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use ferrous_dns_domain::{DomainError, TsigKey};
+use std::sync::Arc;
+use tracing::instrument;
+
+use crate::ports::TsigKeyRepository;
+
+pub struct GetTsigKeysUseCase {
+    repo: Arc<dyn TsigKeyRepository>,
+}
+
+impl GetTsigKeysUseCase {
+    pub fn new(repo: Arc<dyn TsigKeyRepository>) -> Self {
+        Self { repo }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_all(&self) -> Result<Vec<TsigKey>, DomainError> {
+        self.repo.get_all().await
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_by_id(&self, id: i64) -> Result<Option<TsigKey>, DomainError> {
+        self.repo.get_by_id(id).await
+    }
+}
@@ -0,0 +1,18 @@
+mod create_tsig_key;
+mod delete_tsig_key;
+mod get_tsig_keys;
+
+pub use create_tsig_key::CreateTsigKeyUseCase;
+pub use delete_tsig_key::DeleteTsigKeyUseCase;
+pub use get_tsig_keys::GetTsigKeysUseCase;
+
+use crate::ports::{TsigKeyRepository, TsigKeyStore};
+use tracing::error;
+
+/// Pushes the persisted keys into the live UPDATE authenticator.
+async fn reload_keys(repo: &dyn TsigKeyRepository, store: &dyn TsigKeyStore) {
+    match repo.get_all().await {
+        Ok(keys) => store.replace_keys(&keys),
+        Err(e) => error!(error = %e, "Failed to reload TSIG keys"),
+    }
+}
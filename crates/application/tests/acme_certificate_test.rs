@@ -0,0 +1,129 @@
+use ferrous_dns_application::use_cases::RenewAcmeCertificateUseCase;
+use std::sync::Arc;
+
+mod helpers;
+use helpers::{MockAcmeCertificateIssuer, MockTlsCertificateReloader, MockTlsCertificates};
+
+const DAY: i64 = 86_400;
+const NOW: i64 = 1_800_000_000;
+
+struct Fixture {
+    issuer: Arc<MockAcmeCertificateIssuer>,
+    certificates: Arc<MockTlsCertificates>,
+    reloader: Arc<MockTlsCertificateReloader>,
+}
+
+impl Fixture {
+    fn new() -> Self {
+        Self {
+            issuer: Arc::new(MockAcmeCertificateIssuer::new()),
+            certificates: Arc::new(MockTlsCertificates::new()),
+            reloader: Arc::new(MockTlsCertificateReloader::new()),
+        }
+    }
+
+    fn renew(&self, domains: &[&str]) -> RenewAcmeCertificateUseCase {
+        RenewAcmeCertificateUseCase::new(
+            self.issuer.clone(),
+            self.certificates.clone(),
+            self.reloader.clone(),
+            domains.iter().map(|d| d.to_string()).collect(),
+            vec![
+                ("/data/web.pem".to_string(), "/data/web.key".to_string()),
+                ("/data/dot.pem".to_string(), "/data/dot.key".to_string()),
+            ],
+        )
+        .with_renew_before_days(30)
+    }
+}
+
+#[tokio::test]
+async fn issues_when_no_certificate_exists() {
+    let fx = Fixture::new();
+
+    let renewed = fx
+        .renew(&["dns.example.com"])
+        .execute_at(NOW)
+        .await
+        .unwrap();
+
+    assert!(renewed);
+    assert_eq!(
+        fx.issuer.orders(),
+        vec![vec!["dns.example.com".to_string()]]
+    );
+    let saved = fx.certificates.saved();
+    assert_eq!(saved.len(), 2);
+    assert_eq!(saved[0].0, "/data/web.pem");
+    assert_eq!(saved[1].0, "/data/dot.pem");
+    assert_eq!(saved[0].2, MockAcmeCertificateIssuer::CERT_PEM);
+    assert_eq!(
+        fx.reloader.reloads(),
+        vec!["/data/web.pem", "/data/dot.pem"]
+    );
+}
+
+#[tokio::test]
+async fn keeps_a_fresh_certificate() {
+    let fx = Fixture::new();
+    fx.certificates
+        .set_certificate(NOW + 60 * DAY, &["dns.example.com"]);
+
+    let renewed = fx
+        .renew(&["DNS.example.com"])
+        .execute_at(NOW)
+        .await
+        .unwrap();
+
+    assert!(!renewed);
+    assert!(fx.issuer.orders().is_empty());
+    assert!(fx.reloader.reloads().is_empty());
+}
+
+#[tokio::test]
+async fn renews_inside_the_renewal_window() {
+    let fx = Fixture::new();
+    fx.certificates
+        .set_certificate(NOW + 29 * DAY, &["dns.example.com"]);
+
+    assert!(fx
+        .renew(&["dns.example.com"])
+        .execute_at(NOW)
+        .await
+        .unwrap());
+    assert_eq!(fx.issuer.orders().len(), 1);
+}
+
+#[tokio::test]
+async fn renews_when_a_configured_name_is_missing() {
+    let fx = Fixture::new();
+    fx.certificates
+        .set_certificate(NOW + 60 * DAY, &["dns.example.com"]);
+
+    assert!(fx
+        .renew(&["dns.example.com", "*.example.com"])
+        .execute_at(NOW)
+        .await
+        .unwrap());
+    assert_eq!(
+        fx.issuer.orders(),
+        vec![vec![
+            "dns.example.com".to_string(),
+            "*.example.com".to_string()
+        ]]
+    );
+}
+
+#[tokio::test]
+async fn failed_order_leaves_files_and_listeners_alone() {
+    let fx = Fixture::new();
+    fx.certificates
+        .set_certificate(NOW + DAY, &["dns.example.com"]);
+    fx.issuer.fail_orders();
+
+    let result = fx.renew(&["dns.example.com"]).execute_at(NOW).await;
+
+    assert!(result.is_err());
+    assert!(fx.certificates.saved().is_empty());
+    assert!(fx.reloader.reloads().is_empty());
+}
@@ -0,0 +1,330 @@
+use ferrous_dns_application::use_cases::acme_dns::{
+    DeleteAcmeRegistrationUseCase, GetAcmeRegistrationsUseCase, RegisterAcmeAccountUseCase,
+    UpdateAcmeChallengeUseCase,
+};
+use ferrous_dns_application::use_cases::RegisteredAcmeAccount;
+use ferrous_dns_domain::DomainError;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+mod helpers;
+use helpers::{MockAcmeChallengeStore, MockAcmeRegistrationRepository};
+
+const ZONE: &str = "acme.example.com";
+const TXT_A: &str = "LHDhK3oGRvkiefQnx7OOczTY5Tic_xZ6HcMOc_gmtoM";
+const TXT_B: &str = "0123456789abcdefghijklmnopqrstuvwxyzABCDEFG";
+const TXT_C: &str = "zyxwvutsrqponmlkjihgfedcba-_ZYXWVUTSRQPONML";
+
+struct Fixture {
+    repo: Arc<MockAcmeRegistrationRepository>,
+    store: Arc<MockAcmeChallengeStore>,
+}
+
+impl Fixture {
+    fn new() -> Self {
+        Self {
+            repo: Arc::new(MockAcmeRegistrationRepository::new()),
+            store: Arc::new(MockAcmeChallengeStore::new()),
+        }
+    }
+
+    fn register(&self, allowed_from: &[&str]) -> RegisterAcmeAccountUseCase {
+        RegisterAcmeAccountUseCase::new(
+            self.repo.clone(),
+            self.store.clone(),
+            ZONE,
+            allowed_from.iter().map(|n| n.parse().unwrap()).collect(),
+        )
+    }
+
+    fn update(&self) -> UpdateAcmeChallengeUseCase {
+        UpdateAcmeChallengeUseCase::new(self.repo.clone(), self.store.clone())
+    }
+
+    async fn account(&self, allow_from: &[&str]) -> RegisteredAcmeAccount {
+        let allow_from: Vec<String> = allow_from.iter().map(|s| s.to_string()).collect();
+        self.register(&[]).execute(&allow_from, None).await.unwrap()
+    }
+}
+
+fn ip(s: &str) -> Option<IpAddr> {
+    Some(s.parse().unwrap())
+}
+
+// ── RegisterAcmeAccountUseCase ─────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_register_issues_uuid_credentials_under_zone() {
+    let fx = Fixture::new();
+
+    let account = fx.account(&[]).await;
+    let registration = &account.registration;
+
+    assert_eq!(registration.username.len(), 36);
+    assert_eq!(registration.subdomain.len(), 36);
+    assert_ne!(registration.username, registration.subdomain);
+    assert_eq!(
+        account.fulldomain,
+        format!("{}.{}", registration.subdomain, ZONE)
+    );
+    assert_eq!(account.password.len(), 40);
+    assert_ne!(registration.password_hash.as_ref(), account.password);
+    assert_eq!(
+        fx.store.subdomains(),
+        vec![registration.subdomain.to_string()]
+    );
+}
+
+#[tokio::test]
+async fn test_register_normalizes_allowfrom_entries() {
+    let fx = Fixture::new();
+
+    let account = fx
+        .account(&["192.0.2.10", " 10.0.0.0/8 ", "2001:db8::/32"])
+        .await;
+
+    let allow_from: Vec<&str> = account
+        .registration
+        .allow_from
+        .iter()
+        .map(|c| c.as_ref())
+        .collect();
+    assert_eq!(
+        allow_from,
+        vec!["192.0.2.10/32", "10.0.0.0/8", "2001:db8::/32"]
+    );
+}
+
+#[tokio::test]
+async fn test_register_rejects_invalid_allowfrom() {
+    let fx = Fixture::new();
+
+    let result = fx
+        .register(&[])
+        .execute(&["not-a-network".to_string()], None)
+        .await;
+
+    assert!(matches!(result, Err(DomainError::InvalidAcmeAllowFrom(_))));
+    assert_eq!(fx.repo.count().await, 0);
+}
+
+#[tokio::test]
+async fn test_register_enforces_register_allowed_from() {
+    let fx = Fixture::new();
+    let use_case = fx.register(&["10.0.0.0/8"]);
+
+    let refused = use_case.execute(&[], ip("192.0.2.1")).await;
+    assert!(matches!(refused, Err(DomainError::InvalidCredentials)));
+
+    let unknown = use_case.execute(&[], None).await;
+    assert!(matches!(unknown, Err(DomainError::InvalidCredentials)));
+
+    assert!(use_case.execute(&[], ip("10.1.2.3")).await.is_ok());
+    assert_eq!(fx.repo.count().await, 1);
+}
+
+// ── UpdateAcmeChallengeUseCase ─────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_update_publishes_txt_and_reloads_store() {
+    let fx = Fixture::new();
+    let account = fx.account(&[]).await;
+    let reg = &account.registration;
+
+    let txt = fx
+        .update()
+        .execute(
+            &reg.username,
+            &account.password,
+            &reg.subdomain,
+            TXT_A,
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(txt.as_ref(), TXT_A);
+    assert_eq!(fx.store.txt(&reg.subdomain), vec![TXT_A.to_string()]);
+}
+
+#[tokio::test]
+async fn test_update_keeps_two_newest_values() {
+    let fx = Fixture::new();
+    let account = fx.account(&[]).await;
+    let reg = &account.registration;
+    let update = fx.update();
+
+    for txt in [TXT_A, TXT_B, TXT_C] {
+        update
+            .execute(&reg.username, &account.password, &reg.subdomain, txt, None)
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(
+        fx.store.txt(&reg.subdomain),
+        vec![TXT_C.to_string(), TXT_B.to_string()]
+    );
+}
+
+#[tokio::test]
+async fn test_update_rejects_wrong_password() {
+    let fx = Fixture::new();
+    let account = fx.account(&[]).await;
+    let reg = &account.registration;
+
+    let result = fx
+        .update()
+        .execute(&reg.username, "wrong-password", &reg.subdomain, TXT_A, None)
+        .await;
+
+    assert!(matches!(result, Err(DomainError::InvalidCredentials)));
+    assert!(fx.store.txt(&reg.subdomain).is_empty());
+}
+
+#[tokio::test]
+async fn test_update_rejects_another_registrations_subdomain() {
+    let fx = Fixture::new();
+    let first = fx.account(&[]).await;
+    let second = fx.account(&[]).await;
+
+    let result = fx
+        .update()
+        .execute(
+            &first.registration.username,
+            &first.password,
+            &second.registration.subdomain,
+            TXT_A,
+            None,
+        )
+        .await;
+
+    assert!(matches!(result, Err(DomainError::InvalidCredentials)));
+    assert!(fx.store.txt(&second.registration.subdomain).is_empty());
+}
+
+#[tokio::test]
+async fn test_update_rejects_malformed_subdomain_and_txt() {
+    let fx = Fixture::new();
+    let account = fx.account(&[]).await;
+    let reg = &account.registration;
+    let update = fx.update();
+
+    let bad_subdomain = update
+        .execute(&reg.username, &account.password, "not-a-uuid", TXT_A, None)
+        .await;
+    assert!(matches!(
+        bad_subdomain,
+        Err(DomainError::InvalidAcmeSubdomain)
+    ));
+
+    let short_txt = update
+        .execute(
+            &reg.username,
+            &account.password,
+            &reg.subdomain,
+            "abc",
+            None,
+        )
+        .await;
+    assert!(matches!(short_txt, Err(DomainError::InvalidAcmeTxt)));
+
+    let bad_chars = update
+        .execute(
+            &reg.username,
+            &account.password,
+            &reg.subdomain,
+            "LHDhK3oGRvkiefQnx7OOczTY5Tic+xZ6HcMOc/gmtoM",
+            None,
+        )
+        .await;
+    assert!(matches!(bad_chars, Err(DomainError::InvalidAcmeTxt)));
+}
+
+#[tokio::test]
+async fn test_update_enforces_registration_allowfrom() {
+    let fx = Fixture::new();
+    let account = fx.account(&["192.168.1.0/24"]).await;
+    let reg = &account.registration;
+    let update = fx.update();
+
+    let outside = update
+        .execute(
+            &reg.username,
+            &account.password,
+            &reg.subdomain,
+            TXT_A,
+            ip("192.168.2.1"),
+        )
+        .await;
+    assert!(matches!(outside, Err(DomainError::InvalidCredentials)));
+
+    let unknown = update
+        .execute(
+            &reg.username,
+            &account.password,
+            &reg.subdomain,
+            TXT_A,
+            None,
+        )
+        .await;
+    assert!(matches!(unknown, Err(DomainError::InvalidCredentials)));
+
+    let inside = update
+        .execute(
+            &reg.username,
+            &account.password,
+            &reg.subdomain,
+            TXT_A,
+            ip("192.168.1.20"),
+        )
+        .await;
+    assert!(inside.is_ok());
+}
+
+// ── GetAcmeRegistrationsUseCase / DeleteAcmeRegistrationUseCase ─────────────────
+
+#[tokio::test]
+async fn test_get_lists_registrations() {
+    let fx = Fixture::new();
+    let account = fx.account(&[]).await;
+
+    let use_case = GetAcmeRegistrationsUseCase::new(fx.repo.clone());
+    let all = use_case.get_all().await.unwrap();
+    assert_eq!(all.len(), 1);
+
+    let id = account.registration.id.unwrap();
+    let found = use_case.get_by_id(id).await.unwrap().unwrap();
+    assert_eq!(found.subdomain, account.registration.subdomain);
+    assert!(use_case.get_by_id(999).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_delete_removes_registration_from_store() {
+    let fx = Fixture::new();
+    let account = fx.account(&[]).await;
+    let id = account.registration.id.unwrap();
+
+    DeleteAcmeRegistrationUseCase::new(fx.repo.clone(), fx.store.clone())
+        .execute(id)
+        .await
+        .unwrap();
+
+    assert_eq!(fx.repo.count().await, 0);
+    assert!(fx.store.subdomains().is_empty());
+}
+
+#[tokio::test]
+async fn test_delete_unknown_registration_returns_not_found() {
+    let fx = Fixture::new();
+
+    let result = DeleteAcmeRegistrationUseCase::new(fx.repo.clone(), fx.store.clone())
+        .execute(42)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(DomainError::AcmeRegistrationNotFound(42))
+    ));
+    assert_eq!(fx.store.reload_count(), 0);
+}
@@ -0,0 +1,164 @@
+mod helpers;
+
+use ferrous_dns_application::ports::GroupRepository;
+use ferrous_dns_application::use_cases::{HandleDnsQueryUseCase, UpdateGroupBlockingModeUseCase};
+use ferrous_dns_domain::{BlockingConfig, BlockingMode, BlockingResponse, DomainError};
+use helpers::{
+    MockBlockFilterEngine, MockDnsResolver, MockGroupRepository, MockQueryLogRepository,
+};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::sync::Arc;
+
+const CLIENT_IP: IpAddr = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 100));
+
+fn make_use_case(filter: Arc<MockBlockFilterEngine>) -> HandleDnsQueryUseCase {
+    HandleDnsQueryUseCase::new(
+        Arc::new(MockDnsResolver::new()),
+        filter,
+        Arc::new(MockQueryLogRepository::new()),
+    )
+}
+
+// ── HandleDnsQueryUseCase::blocking_response ───────────────────────────────
+
+#[test]
+fn test_default_blocking_response_is_refused() {
+    let use_case = make_use_case(Arc::new(MockBlockFilterEngine::new()));
+
+    let response = use_case.blocking_response(CLIENT_IP);
+
+    assert_eq!(response.mode, BlockingMode::Refused);
+    assert_eq!(use_case.blocked_ttl(), 10);
+}
+
+#[test]
+fn test_global_blocking_mode_applies_without_group_override() {
+    let config = BlockingConfig {
+        mode: BlockingMode::CustomIp,
+        custom_ipv4: Some(Ipv4Addr::new(10, 0, 0, 53)),
+        blocked_ttl: 120,
+        ..Default::default()
+    };
+    let use_case =
+        make_use_case(Arc::new(MockBlockFilterEngine::new())).with_blocking_mode(&config);
+
+    let response = use_case.blocking_response(CLIENT_IP);
+
+    assert_eq!(response.mode, BlockingMode::CustomIp);
+    assert_eq!(response.answer_ipv4(), Some(Ipv4Addr::new(10, 0, 0, 53)));
+    assert_eq!(response.answer_ipv6(), None);
+    assert_eq!(use_case.blocked_ttl(), 120);
+}
+
+#[test]
+fn test_group_override_takes_precedence_over_global_mode() {
+    let filter = Arc::new(MockBlockFilterEngine::new());
+    filter.set_group_blocking_response(Some(BlockingResponse {
+        mode: BlockingMode::Nxdomain,
+        ipv4: None,
+        ipv6: None,
+    }));
+    let config = BlockingConfig {
+        mode: BlockingMode::NullIp,
+        ..Default::default()
+    };
+    let use_case = make_use_case(filter).with_blocking_mode(&config);
+
+    assert_eq!(
+        use_case.blocking_response(CLIENT_IP).mode,
+        BlockingMode::Nxdomain
+    );
+}
+
+// ── UpdateGroupBlockingModeUseCase ─────────────────────────────────────────
+
+#[tokio::test]
+async fn test_set_group_blocking_mode_persists_and_reloads_engine() {
+    let repo = Arc::new(MockGroupRepository::new());
+    let filter = Arc::new(MockBlockFilterEngine::new());
+    let use_case = UpdateGroupBlockingModeUseCase::new(repo.clone(), filter.clone());
+
+    let group = use_case
+        .execute(
+            1,
+            Some(BlockingMode::CustomIp),
+            Some(Ipv4Addr::new(192, 168, 1, 1)),
+            Some(Ipv6Addr::LOCALHOST),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(group.blocking_mode, Some(BlockingMode::CustomIp));
+    assert_eq!(group.blocking_ipv4, Some(Ipv4Addr::new(192, 168, 1, 1)));
+    assert_eq!(group.blocking_ipv6, Some(Ipv6Addr::LOCALHOST));
+    assert_eq!(filter.load_client_groups_count(), 1);
+
+    let stored = repo.get_by_id(1).await.unwrap().unwrap();
+    assert_eq!(stored.blocking_mode, Some(BlockingMode::CustomIp));
+}
+
+#[tokio::test]
+async fn test_custom_ip_mode_without_addresses_is_rejected() {
+    let filter = Arc::new(MockBlockFilterEngine::new());
+    let use_case =
+        UpdateGroupBlockingModeUseCase::new(Arc::new(MockGroupRepository::new()), filter.clone());
+
+    let result = use_case
+        .execute(1, Some(BlockingMode::CustomIp), None, None)
+        .await;
+
+    assert!(matches!(result, Err(DomainError::InvalidInput(_))));
+    assert_eq!(filter.load_client_groups_count(), 0);
+}
+
+#[tokio::test]
+async fn test_non_custom_mode_discards_addresses() {
+    let use_case = UpdateGroupBlockingModeUseCase::new(
+        Arc::new(MockGroupRepository::new()),
+        Arc::new(MockBlockFilterEngine::new()),
+    );
+
+    let group = use_case
+        .execute(
+            1,
+            Some(BlockingMode::Nodata),
+            Some(Ipv4Addr::new(10, 0, 0, 1)),
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(group.blocking_mode, Some(BlockingMode::Nodata));
+    assert_eq!(group.blocking_ipv4, None);
+}
+
+#[tokio::test]
+async fn test_clearing_override_restores_inheritance() {
+    let use_case = UpdateGroupBlockingModeUseCase::new(
+        Arc::new(MockGroupRepository::new()),
+        Arc::new(MockBlockFilterEngine::new()),
+    );
+
+    use_case
+        .execute(1, Some(BlockingMode::NullIp), None, None)
+        .await
+        .unwrap();
+    let group = use_case.execute(1, None, None, None).await.unwrap();
+
+    assert_eq!(group.blocking_mode, None);
+    assert!(group.blocking_response().is_none());
+}
+
+#[tokio::test]
+async fn test_set_blocking_mode_unknown_group_returns_not_found() {
+    let use_case = UpdateGroupBlockingModeUseCase::new(
+        Arc::new(MockGroupRepository::new()),
+        Arc::new(MockBlockFilterEngine::new()),
+    );
+
+    let result = use_case
+        .execute(999, Some(BlockingMode::Nxdomain), None, None)
+        .await;
+
+    assert!(matches!(result, Err(DomainError::GroupNotFound(999))));
+}
@@ -1,13 +1,16 @@
-use ferrous_dns_application::ports::{BlocklistSourceRepository, GroupRepository};
+use chrono::{Duration, Utc};
+use ferrous_dns_application::ports::{
+    BlocklistSimulation, BlocklistSourceRepository, CandidateList, GroupRepository,
+};
 use ferrous_dns_application::use_cases::blocklist_sources::{
     CreateBlocklistSourceUseCase, DeleteBlocklistSourceUseCase, GetBlocklistSourcesUseCase,
-    UpdateBlocklistSourceUseCase,
+    SimulateBlocklistUseCase, UpdateBlocklistSourceUseCase,
 };
 use ferrous_dns_domain::DomainError;
 use std::sync::Arc;
 
 mod helpers;
-use helpers::{MockBlocklistSourceRepository, MockGroupRepository};
+use helpers::{MockBlocklistSimulator, MockBlocklistSourceRepository, MockGroupRepository};
 
 #[tokio::test]
 async fn test_get_all_empty() {
@@ -29,6 +32,7 @@ async fn test_get_all_with_sources() {
         vec![1],
         None,
         true,
+        false,
     )
     .await
     .unwrap();
@@ -38,6 +42,7 @@ async fn test_get_all_with_sources() {
         vec![1],
         Some("Manual list".to_string()),
         false,
+        false,
     )
     .await
     .unwrap();
@@ -55,7 +60,7 @@ async fn test_get_all_with_sources() {
 async fn test_get_by_id_found() {
     let repo = Arc::new(MockBlocklistSourceRepository::new());
     let created = repo
-        .create("Test List".to_string(), None, vec![1], None, true)
+        .create("Test List".to_string(), None, vec![1], None, true, false)
         .await
         .unwrap();
     let id = created.id.unwrap();
@@ -94,6 +99,7 @@ async fn test_create_success() {
             vec![1],
             Some("Main ad block list".to_string()),
             true,
+            false,
         )
         .await;
 
@@ -114,7 +120,14 @@ async fn test_create_with_multiple_groups() {
     let use_case = CreateBlocklistSourceUseCase::new(repo.clone(), group_repo);
 
     let result = use_case
-        .execute("Multi-Group List".to_string(), None, vec![1, 2], None, true)
+        .execute(
+            "Multi-Group List".to_string(),
+            None,
+            vec![1, 2],
+            None,
+            true,
+            false,
+        )
         .await;
 
     assert!(result.is_ok());
@@ -129,7 +142,7 @@ async fn test_create_without_url_succeeds() {
     let use_case = CreateBlocklistSourceUseCase::new(repo, group_repo);
 
     let result = use_case
-        .execute("Manual List".to_string(), None, vec![1], None, true)
+        .execute("Manual List".to_string(), None, vec![1], None, true, false)
         .await;
 
     assert!(result.is_ok());
@@ -144,7 +157,7 @@ async fn test_create_invalid_name_empty() {
     let use_case = CreateBlocklistSourceUseCase::new(repo, group_repo);
 
     let result = use_case
-        .execute("".to_string(), None, vec![1], None, true)
+        .execute("".to_string(), None, vec![1], None, true, false)
         .await;
 
     assert!(result.is_err());
@@ -167,6 +180,7 @@ async fn test_create_invalid_url_scheme() {
             vec![1],
             None,
             true,
+            false,
         )
         .await;
 
@@ -184,7 +198,7 @@ async fn test_create_group_not_found() {
     let use_case = CreateBlocklistSourceUseCase::new(repo, group_repo);
 
     let result = use_case
-        .execute("Test List".to_string(), None, vec![999], None, true)
+        .execute("Test List".to_string(), None, vec![999], None, true, false)
         .await;
 
     assert!(result.is_err());
@@ -202,7 +216,14 @@ async fn test_create_one_invalid_group_in_multi_group_fails() {
 
     // group 1 exists (default), group 999 does not
     let result = use_case
-        .execute("Multi List".to_string(), None, vec![1, 999], None, true)
+        .execute(
+            "Multi List".to_string(),
+            None,
+            vec![1, 999],
+            None,
+            true,
+            false,
+        )
         .await;
 
     assert!(result.is_err());
@@ -219,12 +240,12 @@ async fn test_create_duplicate_name() {
     let use_case = CreateBlocklistSourceUseCase::new(repo, group_repo);
 
     use_case
-        .execute("Duplicate".to_string(), None, vec![1], None, true)
+        .execute("Duplicate".to_string(), None, vec![1], None, true, false)
         .await
         .unwrap();
 
     let result = use_case
-        .execute("Duplicate".to_string(), None, vec![1], None, true)
+        .execute("Duplicate".to_string(), None, vec![1], None, true, false)
         .await;
 
     assert!(result.is_err());
@@ -242,19 +263,49 @@ async fn test_update_toggle_enabled() {
     let update_uc = UpdateBlocklistSourceUseCase::new(repo, group_repo);
 
     let source = create_uc
-        .execute("Toggle List".to_string(), None, vec![1], None, true)
+        .execute("Toggle List".to_string(), None, vec![1], None, true, false)
         .await
         .unwrap();
     let id = source.id.unwrap();
 
     let result = update_uc
-        .execute(id, None, None, None, None, Some(false))
+        .execute(id, None, None, None, None, Some(false), None)
         .await;
 
     assert!(result.is_ok());
     assert!(!result.unwrap().enabled);
 }
 
+#[tokio::test]
+async fn test_update_toggle_observe_only() {
+    let repo = Arc::new(MockBlocklistSourceRepository::new());
+    let group_repo = Arc::new(MockGroupRepository::new());
+    let create_uc = CreateBlocklistSourceUseCase::new(repo.clone(), group_repo.clone());
+    let update_uc = UpdateBlocklistSourceUseCase::new(repo, group_repo);
+
+    let source = create_uc
+        .execute("Trial List".to_string(), None, vec![1], None, true, true)
+        .await
+        .unwrap();
+    assert!(source.observe_only);
+
+    let updated = update_uc
+        .execute(
+            source.id.unwrap(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(false),
+        )
+        .await
+        .unwrap();
+
+    assert!(!updated.observe_only);
+    assert!(updated.enabled);
+}
+
 #[tokio::test]
 async fn test_update_change_groups() {
     let repo = Arc::new(MockBlocklistSourceRepository::new());
@@ -265,13 +316,20 @@ async fn test_update_change_groups() {
     let update_uc = UpdateBlocklistSourceUseCase::new(repo, group_repo);
 
     let source = create_uc
-        .execute("Group Change List".to_string(), None, vec![1], None, true)
+        .execute(
+            "Group Change List".to_string(),
+            None,
+            vec![1],
+            None,
+            true,
+            false,
+        )
         .await
         .unwrap();
     let id = source.id.unwrap();
 
     let result = update_uc
-        .execute(id, None, None, Some(vec![2]), None, None)
+        .execute(id, None, None, Some(vec![2]), None, None, None)
         .await;
 
     assert!(result.is_ok());
@@ -288,13 +346,13 @@ async fn test_update_assign_multiple_groups() {
     let update_uc = UpdateBlocklistSourceUseCase::new(repo, group_repo);
 
     let source = create_uc
-        .execute("Shared List".to_string(), None, vec![1], None, true)
+        .execute("Shared List".to_string(), None, vec![1], None, true, false)
         .await
         .unwrap();
     let id = source.id.unwrap();
 
     let result = update_uc
-        .execute(id, None, None, Some(vec![1, 2]), None, None)
+        .execute(id, None, None, Some(vec![1, 2]), None, None, None)
         .await;
 
     assert!(result.is_ok());
@@ -311,7 +369,7 @@ async fn test_update_source_not_found() {
     let use_case = UpdateBlocklistSourceUseCase::new(repo, group_repo);
 
     let result = use_case
-        .execute(999, None, None, None, None, Some(false))
+        .execute(999, None, None, None, None, Some(false), None)
         .await;
 
     assert!(result.is_err());
@@ -329,12 +387,20 @@ async fn test_update_invalid_group() {
     let update_uc = UpdateBlocklistSourceUseCase::new(repo, group_repo);
 
     let source = create_uc
-        .execute("List".to_string(), None, vec![1], None, true)
+        .execute("List".to_string(), None, vec![1], None, true, false)
         .await
         .unwrap();
 
     let result = update_uc
-        .execute(source.id.unwrap(), None, None, Some(vec![999]), None, None)
+        .execute(
+            source.id.unwrap(),
+            None,
+            None,
+            Some(vec![999]),
+            None,
+            None,
+            None,
+        )
         .await;
 
     assert!(result.is_err());
@@ -358,12 +424,13 @@ async fn test_update_clear_url() {
             vec![1],
             None,
             true,
+            false,
         )
         .await
         .unwrap();
 
     let result = update_uc
-        .execute(source.id.unwrap(), None, Some(None), None, None, None)
+        .execute(source.id.unwrap(), None, Some(None), None, None, None, None)
         .await;
 
     assert!(result.is_ok());
@@ -378,7 +445,7 @@ async fn test_delete_success() {
     let delete_uc = DeleteBlocklistSourceUseCase::new(repo.clone());
 
     let source = create_uc
-        .execute("To Delete".to_string(), None, vec![1], None, true)
+        .execute("To Delete".to_string(), None, vec![1], None, true, false)
         .await
         .unwrap();
     let id = source.id.unwrap();
@@ -403,3 +470,85 @@ async fn test_delete_not_found() {
         other => panic!("Expected BlocklistSourceNotFound, got {:?}", other),
     }
 }
+
+fn simulation(list: CandidateList) -> BlocklistSimulation {
+    let to = Utc::now();
+    BlocklistSimulation {
+        list,
+        from: to - Duration::hours(24),
+        to,
+        group_ids: Vec::new(),
+        limit: 100,
+    }
+}
+
+#[tokio::test]
+async fn test_simulate_clamps_limit() {
+    let simulator = Arc::new(MockBlocklistSimulator::new());
+    let use_case =
+        SimulateBlocklistUseCase::new(simulator.clone(), Arc::new(MockGroupRepository::new()));
+    let mut request = simulation(CandidateList::Text("ads.example".to_string()));
+    request.limit = 1_000_000;
+
+    use_case.execute(request).await.unwrap();
+
+    assert_eq!(simulator.received()[0].limit, 1000);
+}
+
+#[tokio::test]
+async fn test_simulate_rejects_empty_text() {
+    let simulator = Arc::new(MockBlocklistSimulator::new());
+    let use_case =
+        SimulateBlocklistUseCase::new(simulator.clone(), Arc::new(MockGroupRepository::new()));
+
+    let result = use_case
+        .execute(simulation(CandidateList::Text("  \n".to_string())))
+        .await;
+
+    assert!(matches!(result, Err(DomainError::InvalidInput(_))));
+    assert!(simulator.received().is_empty());
+}
+
+#[tokio::test]
+async fn test_simulate_rejects_invalid_url() {
+    let use_case = SimulateBlocklistUseCase::new(
+        Arc::new(MockBlocklistSimulator::new()),
+        Arc::new(MockGroupRepository::new()),
+    );
+
+    let result = use_case
+        .execute(simulation(CandidateList::Url(
+            "ftp://lists.example/ads.txt".to_string(),
+        )))
+        .await;
+
+    assert!(matches!(result, Err(DomainError::InvalidInput(_))));
+}
+
+#[tokio::test]
+async fn test_simulate_rejects_inverted_time_range() {
+    let use_case = SimulateBlocklistUseCase::new(
+        Arc::new(MockBlocklistSimulator::new()),
+        Arc::new(MockGroupRepository::new()),
+    );
+    let mut request = simulation(CandidateList::Text("ads.example".to_string()));
+    std::mem::swap(&mut request.from, &mut request.to);
+
+    let result = use_case.execute(request).await;
+
+    assert!(matches!(result, Err(DomainError::InvalidInput(_))));
+}
+
+#[tokio::test]
+async fn test_simulate_unknown_group() {
+    let use_case = SimulateBlocklistUseCase::new(
+        Arc::new(MockBlocklistSimulator::new()),
+        Arc::new(MockGroupRepository::new()),
+    );
+    let mut request = simulation(CandidateList::Text("ads.example".to_string()));
+    request.group_ids = vec![999];
+
+    let result = use_case.execute(request).await;
+
+    assert!(matches!(result, Err(DomainError::GroupNotFound(999))));
+}
@@ -0,0 +1,504 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{ApiTokenRepository, ConfigRepository, PtrRecordRegistry};
+use ferrous_dns_application::use_cases::{
+    ApplyDdnsUpdateUseCase, CreateApiTokenUseCase, CreateLocalRecordUseCase, GetDdnsHistoryUseCase,
+    ValidateApiTokenUseCase,
+};
+use ferrous_dns_domain::{ApiToken, Config, DdnsResult, DomainError, LocalDnsRecord};
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+use tokio::sync::RwLock;
+
+mod helpers;
+use helpers::MockDdnsHistoryRepository;
+
+const ZONE: &str = "home.example";
+const TOKEN: &str = "ddns-router-token";
+
+// ── Mock ConfigRepository ────────────────────────────────────────────────────
+
+struct MockConfigRepository;
+
+#[async_trait]
+impl ConfigRepository for MockConfigRepository {
+    async fn save_local_records(&self, _config: &Config) -> Result<(), DomainError> {
+        Ok(())
+    }
+}
+
+// ── Mock PtrRecordRegistry ───────────────────────────────────────────────────
+
+#[derive(Default)]
+struct MockPtrRegistry {
+    registered: Mutex<Vec<(IpAddr, String)>>,
+    unregistered: Mutex<Vec<IpAddr>>,
+}
+
+impl PtrRecordRegistry for MockPtrRegistry {
+    fn register(&self, ip: IpAddr, fqdn: Arc<str>, _ttl: u32) {
+        self.registered.lock().unwrap().push((ip, fqdn.to_string()));
+    }
+
+    fn unregister(&self, ip: IpAddr) {
+        self.unregistered.lock().unwrap().push(ip);
+    }
+
+    fn lookup(&self, _ip: IpAddr) -> Option<Arc<str>> {
+        None
+    }
+}
+
+// ── Mock ApiTokenRepository ──────────────────────────────────────────────────
+
+#[derive(Default)]
+struct MockApiTokenRepo {
+    hashes: Mutex<Vec<(i64, String)>>,
+}
+
+#[async_trait]
+impl ApiTokenRepository for MockApiTokenRepo {
+    async fn create(
+        &self,
+        name: &str,
+        key_prefix: &str,
+        key_hash: &str,
+        _key_raw: &str,
+    ) -> Result<ApiToken, DomainError> {
+        let mut hashes = self.hashes.lock().unwrap();
+        let id = hashes.len() as i64 + 1;
+        hashes.push((id, key_hash.to_string()));
+        Ok(ApiToken {
+            id: Some(id),
+            name: Arc::from(name),
+            key_prefix: Arc::from(key_prefix),
+            key_hash: Arc::from(key_hash),
+            key_raw: None,
+            created_at: None,
+            last_used_at: None,
+        })
+    }
+
+    async fn get_all(&self) -> Result<Vec<ApiToken>, DomainError> {
+        Ok(vec![])
+    }
+
+    async fn get_by_id(&self, _id: i64) -> Result<Option<ApiToken>, DomainError> {
+        Ok(None)
+    }
+
+    async fn get_by_name(&self, _name: &str) -> Result<Option<ApiToken>, DomainError> {
+        Ok(None)
+    }
+
+    async fn update(
+        &self,
+        id: i64,
+        _name: &str,
+        _key_prefix: Option<&str>,
+        _key_hash: Option<&str>,
+        _key_raw: Option<&str>,
+    ) -> Result<ApiToken, DomainError> {
+        Err(DomainError::ApiTokenNotFound(id))
+    }
+
+    async fn delete(&self, _id: i64) -> Result<(), DomainError> {
+        Ok(())
+    }
+
+    async fn update_last_used(&self, _id: i64) -> Result<(), DomainError> {
+        Ok(())
+    }
+
+    async fn get_all_hashes(&self) -> Result<Vec<(i64, String)>, DomainError> {
+        Ok(self.hashes.lock().unwrap().clone())
+    }
+
+    async fn get_id_by_hash(&self, key_hash: &str) -> Result<Option<i64>, DomainError> {
+        Ok(self
+            .hashes
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|(_, hash)| hash == key_hash)
+            .map(|(id, _)| *id))
+    }
+}
+
+// ── Helpers ──────────────────────────────────────────────────────────────────
+
+struct Fixture {
+    config: Arc<RwLock<Config>>,
+    registry: Arc<MockPtrRegistry>,
+    history: Arc<MockDdnsHistoryRepository>,
+    use_case: ApplyDdnsUpdateUseCase,
+}
+
+async fn fixture(records: &[(&str, &str, &str, Option<u32>)]) -> Fixture {
+    let mut config = Config::default();
+    for (hostname, ip, record_type, ttl) in records {
+        config.dns.local_records.push(LocalDnsRecord {
+            hostname: hostname.to_string(),
+            domain: Some(ZONE.to_string()),
+            ip: ip.to_string(),
+            record_type: record_type.to_string(),
+            ttl: *ttl,
+        });
+    }
+    let config = Arc::new(RwLock::new(config));
+    let registry = Arc::new(MockPtrRegistry::default());
+    let ptr = Some(registry.clone() as Arc<dyn PtrRecordRegistry>);
+
+    let tokens = Arc::new(MockApiTokenRepo::default());
+    CreateApiTokenUseCase::new(tokens.clone())
+        .execute("router", Some(TOKEN))
+        .await
+        .unwrap();
+
+    let history = Arc::new(MockDdnsHistoryRepository::new());
+    let zones = [format!("{ZONE}."), "168.192.in-addr.arpa".to_string()];
+    let use_case = ApplyDdnsUpdateUseCase::new(
+        Arc::new(
+            CreateLocalRecordUseCase::new(config.clone(), Arc::new(MockConfigRepository))
+                .with_ptr_registry(ptr),
+        ),
+        Arc::new(ValidateApiTokenUseCase::new(tokens)),
+        history.clone(),
+        &zones,
+    );
+
+    Fixture {
+        config,
+        registry,
+        history,
+        use_case,
+    }
+}
+
+fn ip(s: &str) -> IpAddr {
+    s.parse().unwrap()
+}
+
+async fn records(config: &Arc<RwLock<Config>>) -> Vec<(String, String, String)> {
+    config
+        .read()
+        .await
+        .dns
+        .local_records
+        .iter()
+        .map(|r| (r.hostname.clone(), r.ip.clone(), r.record_type.clone()))
+        .collect()
+}
+
+// ── Authentication ───────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_missing_token_is_badauth() {
+    let f = fixture(&[]).await;
+
+    let outcomes = f
+        .use_case
+        .execute(None, "nas.home.example", &[ip("192.168.1.50")], None)
+        .await;
+
+    assert_eq!(outcomes.len(), 1);
+    assert_eq!(outcomes[0].result, DdnsResult::BadAuth);
+    assert_eq!(outcomes[0].response_line(), "badauth");
+    assert!(records(&f.config).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_unknown_token_is_badauth_and_recorded() {
+    let f = fixture(&[]).await;
+
+    let outcomes = f
+        .use_case
+        .execute(
+            Some("not-the-token"),
+            "nas.home.example",
+            &[ip("192.168.1.50")],
+            Some(ip("198.51.100.7")),
+        )
+        .await;
+
+    assert_eq!(outcomes[0].result, DdnsResult::BadAuth);
+    let history = f.history.all().await;
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].result, DdnsResult::BadAuth);
+    assert_eq!(history[0].token_id, None);
+    assert_eq!(history[0].client_ip.as_deref(), Some("198.51.100.7"));
+}
+
+#[tokio::test]
+async fn test_badauth_records_one_row_per_request() {
+    let f = fixture(&[]).await;
+
+    let outcomes = f
+        .use_case
+        .execute(
+            None,
+            "nas.home.example,cam.home.example,tv.home.example",
+            &[ip("192.168.1.50")],
+            None,
+        )
+        .await;
+
+    assert_eq!(outcomes.len(), 1);
+    assert_eq!(outcomes[0].result, DdnsResult::BadAuth);
+    assert_eq!(&*outcomes[0].hostname, "nas.home.example");
+    assert_eq!(f.history.all().await.len(), 1);
+}
+
+#[tokio::test]
+async fn test_repeated_rejections_are_not_all_recorded() {
+    let f = fixture(&[]).await;
+
+    for _ in 0..5 {
+        let outcomes = f
+            .use_case
+            .execute(Some("not-the-token"), "nas.home.example", &[], None)
+            .await;
+        assert_eq!(outcomes[0].result, DdnsResult::BadAuth);
+    }
+    f.use_case
+        .execute(Some(TOKEN), "nas.home.example", &[ip("192.168.1.50")], None)
+        .await;
+
+    let history = f.history.all().await;
+    assert_eq!(history.len(), 2);
+    assert_eq!(
+        history
+            .iter()
+            .filter(|u| u.result == DdnsResult::BadAuth)
+            .count(),
+        1
+    );
+}
+
+// ── Address changes ──────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_new_hostname_creates_record_and_ptr() {
+    let f = fixture(&[]).await;
+
+    let outcomes = f
+        .use_case
+        .execute(Some(TOKEN), "nas.home.example", &[ip("192.168.1.50")], None)
+        .await;
+
+    assert_eq!(outcomes[0].result, DdnsResult::Good);
+    assert_eq!(outcomes[0].response_line(), "good 192.168.1.50");
+    assert_eq!(outcomes[0].token_id, Some(1));
+    assert_eq!(
+        records(&f.config).await,
+        vec![("nas".into(), "192.168.1.50".into(), "A".into())]
+    );
+    assert_eq!(f.config.read().await.dns.local_records[0].ttl, Some(60));
+    assert_eq!(
+        *f.registry.registered.lock().unwrap(),
+        vec![(ip("192.168.1.50"), "nas.home.example".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn test_same_address_is_nochg() {
+    let f = fixture(&[("nas", "192.168.1.50", "A", None)]).await;
+
+    let outcomes = f
+        .use_case
+        .execute(Some(TOKEN), "nas.home.example", &[ip("192.168.1.50")], None)
+        .await;
+
+    assert_eq!(outcomes[0].result, DdnsResult::NoChg);
+    assert_eq!(outcomes[0].response_line(), "nochg 192.168.1.50");
+    assert!(f.registry.registered.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_changed_address_updates_record_and_keeps_ttl() {
+    let f = fixture(&[("nas", "192.168.1.50", "A", Some(600))]).await;
+
+    let outcomes = f
+        .use_case
+        .execute(Some(TOKEN), "nas.home.example", &[ip("192.168.1.51")], None)
+        .await;
+
+    assert_eq!(outcomes[0].result, DdnsResult::Good);
+    assert_eq!(
+        records(&f.config).await,
+        vec![("nas".into(), "192.168.1.51".into(), "A".into())]
+    );
+    assert_eq!(f.config.read().await.dns.local_records[0].ttl, Some(600));
+    assert!(f
+        .registry
+        .unregistered
+        .lock()
+        .unwrap()
+        .contains(&ip("192.168.1.50")));
+}
+
+#[tokio::test]
+async fn test_multiple_records_collapse_to_one() {
+    let f = fixture(&[
+        ("nas", "192.168.1.50", "A", None),
+        ("nas", "192.168.1.52", "A", None),
+    ])
+    .await;
+
+    f.use_case
+        .execute(Some(TOKEN), "nas.home.example", &[ip("192.168.1.51")], None)
+        .await;
+
+    assert_eq!(
+        records(&f.config).await,
+        vec![("nas".into(), "192.168.1.51".into(), "A".into())]
+    );
+}
+
+#[tokio::test]
+async fn test_ipv6_update_leaves_ipv4_alone() {
+    let f = fixture(&[("nas", "192.168.1.50", "A", None)]).await;
+
+    let outcomes = f
+        .use_case
+        .execute(Some(TOKEN), "nas.home.example", &[ip("2001:db8::50")], None)
+        .await;
+
+    assert_eq!(outcomes[0].result, DdnsResult::Good);
+    assert_eq!(
+        records(&f.config).await,
+        vec![
+            ("nas".into(), "192.168.1.50".into(), "A".into()),
+            ("nas".into(), "2001:db8::50".into(), "AAAA".into()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_dual_stack_update_reports_both_addresses() {
+    let f = fixture(&[]).await;
+
+    let outcomes = f
+        .use_case
+        .execute(
+            Some(TOKEN),
+            "nas.home.example",
+            &[ip("192.168.1.50"), ip("2001:db8::50")],
+            None,
+        )
+        .await;
+
+    assert_eq!(
+        outcomes[0].response_line(),
+        "good 192.168.1.50,2001:db8::50"
+    );
+    assert_eq!(records(&f.config).await.len(), 2);
+}
+
+// ── Rejected hostnames ───────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_hostname_checks() {
+    let f = fixture(&[]).await;
+
+    let outcomes = f
+        .use_case
+        .execute(
+            Some(TOKEN),
+            "nas, nas.elsewhere.example, home.example, 50.1.168.192.in-addr.arpa, NAS.Home.Example.",
+            &[ip("192.168.1.50")],
+            None,
+        )
+        .await;
+
+    let results: Vec<_> = outcomes.iter().map(|o| o.result).collect();
+    assert_eq!(
+        results,
+        vec![
+            DdnsResult::NotFqdn,
+            DdnsResult::NoHost,
+            DdnsResult::NoHost,
+            DdnsResult::NoHost,
+            DdnsResult::Good,
+        ]
+    );
+    assert_eq!(outcomes[4].hostname.as_ref(), "nas.home.example");
+}
+
+#[tokio::test]
+async fn test_too_many_hostnames_is_numhost() {
+    let f = fixture(&[]).await;
+    let hostnames = (0..21)
+        .map(|i| format!("host{i}.home.example"))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let outcomes = f
+        .use_case
+        .execute(Some(TOKEN), &hostnames, &[ip("192.168.1.50")], None)
+        .await;
+
+    assert_eq!(outcomes.len(), 1);
+    assert_eq!(outcomes[0].result, DdnsResult::NumHost);
+    assert_eq!(outcomes[0].response_line(), "numhost");
+    assert_eq!(f.history.all().await.len(), 1);
+    assert!(records(&f.config).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_too_many_hostnames_without_token_is_one_numhost_row() {
+    let f = fixture(&[]).await;
+    let hostnames = vec!["a.home.example"; 500].join(",");
+
+    let outcomes = f
+        .use_case
+        .execute(None, &hostnames, &[ip("192.168.1.50")], None)
+        .await;
+
+    assert_eq!(outcomes.len(), 1);
+    assert_eq!(outcomes[0].result, DdnsResult::NumHost);
+    assert_eq!(f.history.all().await.len(), 1);
+}
+
+#[tokio::test]
+async fn test_no_address_is_dnserr() {
+    let f = fixture(&[]).await;
+
+    let outcomes = f
+        .use_case
+        .execute(Some(TOKEN), "nas.home.example", &[], None)
+        .await;
+
+    assert_eq!(outcomes[0].result, DdnsResult::DnsErr);
+}
+
+// ── History ──────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_history_lists_newest_first_and_filters_by_hostname() {
+    let f = fixture(&[]).await;
+    f.use_case
+        .execute(Some(TOKEN), "nas.home.example", &[ip("192.168.1.50")], None)
+        .await;
+    f.use_case
+        .execute(Some(TOKEN), "tv.home.example", &[ip("192.168.1.60")], None)
+        .await;
+    f.use_case
+        .execute(Some(TOKEN), "nas.home.example", &[ip("192.168.1.50")], None)
+        .await;
+    let history = GetDdnsHistoryUseCase::new(f.history.clone());
+
+    let all = history.execute(None, 100).await.unwrap();
+    assert_eq!(all.len(), 3);
+    assert_eq!(all[0].result, DdnsResult::NoChg);
+    assert_eq!(all[2].result, DdnsResult::Good);
+
+    let nas = history
+        .execute(Some("NAS.home.example."), 100)
+        .await
+        .unwrap();
+    assert_eq!(nas.len(), 2);
+
+    let limited = history.execute(None, 1).await.unwrap();
+    assert_eq!(limited.len(), 1);
+    assert_eq!(limited[0].hostname.as_ref(), "nas.home.example");
+}
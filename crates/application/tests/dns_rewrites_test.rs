@@ -0,0 +1,213 @@
+use ferrous_dns_application::use_cases::dns_rewrites::{
+    CreateDnsRewriteRuleUseCase, DeleteDnsRewriteRuleUseCase, GetDnsRewriteRulesUseCase,
+    UpdateDnsRewriteRuleUseCase,
+};
+use ferrous_dns_domain::{DnsRewriteAnswer, DomainError};
+use std::sync::Arc;
+
+mod helpers;
+use helpers::{MockDnsRewriteRuleRepository, MockDnsRewriteStore, MockGroupRepository};
+
+fn answer(rewrite: &str) -> DnsRewriteAnswer {
+    rewrite.parse().unwrap()
+}
+
+fn create_use_case(
+    repo: &Arc<MockDnsRewriteRuleRepository>,
+    store: &Arc<MockDnsRewriteStore>,
+) -> CreateDnsRewriteRuleUseCase {
+    CreateDnsRewriteRuleUseCase::new(
+        repo.clone(),
+        Arc::new(MockGroupRepository::new()),
+        store.clone(),
+    )
+}
+
+// ── CreateDnsRewriteRuleUseCase ───────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_create_normalizes_domain_and_reloads_store() {
+    let repo = Arc::new(MockDnsRewriteRuleRepository::new());
+    let store = Arc::new(MockDnsRewriteStore::new());
+
+    let rule = create_use_case(&repo, &store)
+        .execute(
+            "NAS.Example.org.".to_string(),
+            answer("NOERROR;A;10.0.0.5"),
+            1,
+            true,
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(rule.domain.as_ref(), "nas.example.org");
+    assert_eq!(store.reload_count(), 1);
+    assert_eq!(store.active_domains(), vec!["nas.example.org".to_string()]);
+}
+
+#[tokio::test]
+async fn test_create_rejects_invalid_domain() {
+    let repo = Arc::new(MockDnsRewriteRuleRepository::new());
+    let store = Arc::new(MockDnsRewriteStore::new());
+
+    let result = create_use_case(&repo, &store)
+        .execute("bad domain".to_string(), answer("10.0.0.5"), 1, true, None)
+        .await;
+
+    assert!(matches!(result, Err(DomainError::InvalidDnsRewriteRule(_))));
+    assert_eq!(repo.count().await, 0);
+    assert_eq!(store.reload_count(), 0);
+}
+
+#[tokio::test]
+async fn test_create_rejects_self_referencing_cname() {
+    let repo = Arc::new(MockDnsRewriteRuleRepository::new());
+    let store = Arc::new(MockDnsRewriteStore::new());
+
+    let result = create_use_case(&repo, &store)
+        .execute(
+            "example.org".to_string(),
+            answer("www.example.org"),
+            1,
+            true,
+            None,
+        )
+        .await;
+
+    assert!(matches!(result, Err(DomainError::InvalidDnsRewriteRule(_))));
+}
+
+#[tokio::test]
+async fn test_create_rejects_unknown_group() {
+    let repo = Arc::new(MockDnsRewriteRuleRepository::new());
+    let store = Arc::new(MockDnsRewriteStore::new());
+
+    let result = create_use_case(&repo, &store)
+        .execute(
+            "example.org".to_string(),
+            answer("10.0.0.5"),
+            42,
+            true,
+            None,
+        )
+        .await;
+
+    assert!(matches!(result, Err(DomainError::GroupNotFound(42))));
+    assert_eq!(repo.count().await, 0);
+}
+
+#[tokio::test]
+async fn test_create_allows_several_addresses_for_one_domain() {
+    let repo = Arc::new(MockDnsRewriteRuleRepository::new());
+    let store = Arc::new(MockDnsRewriteStore::new());
+    let use_case = create_use_case(&repo, &store);
+
+    for ip in ["10.0.0.5", "10.0.0.6"] {
+        use_case
+            .execute("example.org".to_string(), answer(ip), 1, true, None)
+            .await
+            .unwrap();
+    }
+    let duplicate = use_case
+        .execute("example.org".to_string(), answer("10.0.0.5"), 1, true, None)
+        .await;
+
+    assert_eq!(repo.count().await, 2);
+    assert!(matches!(
+        duplicate,
+        Err(DomainError::InvalidDnsRewriteRule(_))
+    ));
+}
+
+// ── UpdateDnsRewriteRuleUseCase ───────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_update_changes_answer_and_reloads_store() {
+    let repo = Arc::new(MockDnsRewriteRuleRepository::new());
+    let store = Arc::new(MockDnsRewriteStore::new());
+    let rule = create_use_case(&repo, &store)
+        .execute("example.org".to_string(), answer("10.0.0.5"), 1, true, None)
+        .await
+        .unwrap();
+
+    let updated = UpdateDnsRewriteRuleUseCase::new(
+        repo.clone(),
+        Arc::new(MockGroupRepository::new()),
+        store.clone(),
+    )
+    .execute(
+        rule.id.unwrap(),
+        None,
+        Some(answer("NXDOMAIN")),
+        None,
+        Some(false),
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(updated.answer.to_string(), "NXDOMAIN;;");
+    assert!(!updated.enabled);
+    assert_eq!(store.reload_count(), 2);
+    assert!(store.active_domains().is_empty());
+}
+
+#[tokio::test]
+async fn test_update_missing_rule_returns_not_found() {
+    let repo = Arc::new(MockDnsRewriteRuleRepository::new());
+    let store = Arc::new(MockDnsRewriteStore::new());
+
+    let result = UpdateDnsRewriteRuleUseCase::new(
+        repo.clone(),
+        Arc::new(MockGroupRepository::new()),
+        store.clone(),
+    )
+    .execute(99, None, None, None, Some(false), None)
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(DomainError::DnsRewriteRuleNotFound(99))
+    ));
+}
+
+// ── Get / Delete ──────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_delete_removes_rule_and_reloads_store() {
+    let repo = Arc::new(MockDnsRewriteRuleRepository::new());
+    let store = Arc::new(MockDnsRewriteStore::new());
+    let rule = create_use_case(&repo, &store)
+        .execute("example.org".to_string(), answer("10.0.0.5"), 1, true, None)
+        .await
+        .unwrap();
+
+    DeleteDnsRewriteRuleUseCase::new(repo.clone(), store.clone())
+        .execute(rule.id.unwrap())
+        .await
+        .unwrap();
+
+    let remaining = GetDnsRewriteRulesUseCase::new(repo.clone())
+        .get_all()
+        .await
+        .unwrap();
+    assert!(remaining.is_empty());
+    assert_eq!(store.reload_count(), 2);
+}
+
+#[tokio::test]
+async fn test_delete_missing_rule_returns_not_found() {
+    let repo = Arc::new(MockDnsRewriteRuleRepository::new());
+    let store = Arc::new(MockDnsRewriteStore::new());
+
+    let result = DeleteDnsRewriteRuleUseCase::new(repo, store.clone())
+        .execute(7)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(DomainError::DnsRewriteRuleNotFound(7))
+    ));
+    assert_eq!(store.reload_count(), 0);
+}
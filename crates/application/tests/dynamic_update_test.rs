@@ -0,0 +1,719 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{ConfigRepository, PtrRecordRegistry};
+use ferrous_dns_application::use_cases::{ApplyDynamicUpdateUseCase, CreateLocalRecordUseCase};
+use ferrous_dns_domain::{
+    Config, DomainError, DynamicUpdate, LocalDnsRecord, RecordType, UpdateData, UpdateOperation,
+    UpdatePrerequisite, UpdateRcode,
+};
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::RwLock;
+
+const ZONE: &str = "lan.example";
+const REVERSE_ZONE: &str = "10.in-addr.arpa";
+
+// ── Mock ConfigRepository ────────────────────────────────────────────────────
+
+#[derive(Default)]
+struct MockConfigRepository {
+    saves: AtomicUsize,
+    fail: bool,
+}
+
+#[async_trait]
+impl ConfigRepository for MockConfigRepository {
+    async fn save_local_records(&self, _config: &Config) -> Result<(), DomainError> {
+        if self.fail {
+            return Err(DomainError::IoError("disk full".to_string()));
+        }
+        self.saves.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+// ── Mock PtrRecordRegistry ───────────────────────────────────────────────────
+
+#[derive(Default)]
+struct MockPtrRegistry {
+    live: Mutex<HashMap<IpAddr, Arc<str>>>,
+    registered: Mutex<Vec<(IpAddr, String, u32)>>,
+    unregistered: Mutex<Vec<IpAddr>>,
+}
+
+impl PtrRecordRegistry for MockPtrRegistry {
+    fn register(&self, ip: IpAddr, fqdn: Arc<str>, ttl: u32) {
+        self.live.lock().unwrap().insert(ip, fqdn.clone());
+        self.registered
+            .lock()
+            .unwrap()
+            .push((ip, fqdn.to_string(), ttl));
+    }
+
+    fn unregister(&self, ip: IpAddr) {
+        self.live.lock().unwrap().remove(&ip);
+        self.unregistered.lock().unwrap().push(ip);
+    }
+
+    fn lookup(&self, ip: IpAddr) -> Option<Arc<str>> {
+        self.live.lock().unwrap().get(&ip).cloned()
+    }
+}
+
+// ── Helpers ──────────────────────────────────────────────────────────────────
+
+struct Fixture {
+    config: Arc<RwLock<Config>>,
+    repo: Arc<MockConfigRepository>,
+    registry: Arc<MockPtrRegistry>,
+    use_case: ApplyDynamicUpdateUseCase,
+}
+
+fn fixture(records: &[(&str, &str, &str)]) -> Fixture {
+    fixture_with_repo(records, MockConfigRepository::default())
+}
+
+fn fixture_with_repo(records: &[(&str, &str, &str)], repo: MockConfigRepository) -> Fixture {
+    let mut config = Config::default();
+    for (hostname, ip, record_type) in records {
+        config.dns.local_records.push(LocalDnsRecord {
+            hostname: hostname.to_string(),
+            domain: Some(ZONE.to_string()),
+            ip: ip.to_string(),
+            record_type: record_type.to_string(),
+            ttl: Some(300),
+        });
+    }
+    let config = Arc::new(RwLock::new(config));
+    let registry = Arc::new(MockPtrRegistry::default());
+    let ptr = Some(registry.clone() as Arc<dyn PtrRecordRegistry>);
+    let repo = Arc::new(repo);
+    let zones = [ZONE.to_string(), format!("{REVERSE_ZONE}.")];
+    let create = Arc::new(
+        CreateLocalRecordUseCase::new(config.clone(), repo.clone()).with_ptr_registry(ptr.clone()),
+    );
+    let use_case =
+        ApplyDynamicUpdateUseCase::new(config.clone(), create, &zones).with_ptr_registry(ptr);
+    Fixture {
+        config,
+        repo,
+        registry,
+        use_case,
+    }
+}
+
+fn update(
+    zone: &str,
+    prerequisites: Vec<UpdatePrerequisite>,
+    operations: Vec<UpdateOperation>,
+) -> DynamicUpdate {
+    DynamicUpdate {
+        zone: Arc::from(zone),
+        prerequisites,
+        operations,
+    }
+}
+
+fn addr(ip: &str) -> UpdateData {
+    UpdateData::Address(ip.parse().unwrap())
+}
+
+fn add(name: &str, ip: &str) -> UpdateOperation {
+    UpdateOperation::Add {
+        name: Arc::from(name),
+        data: addr(ip),
+        ttl: 600,
+    }
+}
+
+async fn records(config: &Arc<RwLock<Config>>) -> Vec<(String, String)> {
+    config
+        .read()
+        .await
+        .dns
+        .local_records
+        .iter()
+        .map(|r| (r.hostname.clone(), r.ip.clone()))
+        .collect()
+}
+
+// ── Zone checks ──────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_unconfigured_zone_is_notauth() {
+    let f = fixture(&[]);
+
+    let result = f
+        .use_case
+        .execute(&update("other.example", vec![], vec![]))
+        .await;
+
+    assert_eq!(result, Err(UpdateRcode::NotAuth));
+}
+
+#[tokio::test]
+async fn test_name_outside_zone_is_notzone() {
+    let f = fixture(&[]);
+
+    let result = f
+        .use_case
+        .execute(&update(
+            ZONE,
+            vec![],
+            vec![add("host.other.example", "10.0.0.5")],
+        ))
+        .await;
+
+    assert_eq!(result, Err(UpdateRcode::NotZone));
+    assert!(records(&f.config).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_add_at_zone_apex_is_refused() {
+    let f = fixture(&[]);
+
+    let result = f
+        .use_case
+        .execute(&update(ZONE, vec![], vec![add(ZONE, "10.0.0.5")]))
+        .await;
+
+    assert_eq!(result, Err(UpdateRcode::Refused));
+}
+
+#[test]
+fn test_is_enabled_reflects_zones() {
+    assert!(fixture(&[]).use_case.is_enabled());
+}
+
+// ── Forward zone operations ──────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_add_creates_local_record_and_ptr() {
+    let f = fixture(&[]);
+
+    f.use_case
+        .execute(&update(
+            ZONE,
+            vec![],
+            vec![add("laptop.lan.example", "10.0.0.5")],
+        ))
+        .await
+        .unwrap();
+
+    let config = f.config.read().await;
+    let record = &config.dns.local_records[0];
+    assert_eq!(record.hostname, "laptop");
+    assert_eq!(record.domain.as_deref(), Some(ZONE));
+    assert_eq!(record.ip, "10.0.0.5");
+    assert_eq!(record.record_type, "A");
+    assert_eq!(record.ttl, Some(600));
+    let registered = f.registry.registered.lock().unwrap();
+    assert_eq!(registered[0].1, "laptop.lan.example");
+}
+
+#[tokio::test]
+async fn test_add_ipv6_creates_aaaa_record() {
+    let f = fixture(&[]);
+
+    f.use_case
+        .execute(&update(
+            ZONE,
+            vec![],
+            vec![add("laptop.lan.example", "fd00::5")],
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        f.config.read().await.dns.local_records[0].record_type,
+        "AAAA"
+    );
+}
+
+#[tokio::test]
+async fn test_duplicate_add_is_ignored() {
+    let f = fixture(&[("laptop", "10.0.0.5", "A")]);
+
+    f.use_case
+        .execute(&update(
+            ZONE,
+            vec![],
+            vec![add("laptop.lan.example", "10.0.0.5")],
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(records(&f.config).await.len(), 1);
+}
+
+#[tokio::test]
+async fn test_delete_rrset_removes_only_that_type() {
+    let f = fixture(&[
+        ("laptop", "10.0.0.5", "A"),
+        ("laptop", "10.0.0.6", "A"),
+        ("laptop", "fd00::5", "AAAA"),
+        ("printer", "10.0.0.9", "A"),
+    ]);
+
+    f.use_case
+        .execute(&update(
+            ZONE,
+            vec![],
+            vec![UpdateOperation::DeleteRrset {
+                name: Arc::from("laptop.lan.example"),
+                record_type: RecordType::A,
+            }],
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        records(&f.config).await,
+        vec![
+            ("laptop".to_string(), "fd00::5".to_string()),
+            ("printer".to_string(), "10.0.0.9".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_delete_name_removes_all_records_at_name() {
+    let f = fixture(&[
+        ("laptop", "10.0.0.5", "A"),
+        ("laptop", "fd00::5", "AAAA"),
+        ("printer", "10.0.0.9", "A"),
+    ]);
+
+    f.use_case
+        .execute(&update(
+            ZONE,
+            vec![],
+            vec![UpdateOperation::DeleteName {
+                name: Arc::from("laptop.lan.example"),
+            }],
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        records(&f.config).await,
+        vec![("printer".to_string(), "10.0.0.9".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn test_delete_record_removes_matching_address() {
+    let f = fixture(&[("laptop", "10.0.0.5", "A"), ("laptop", "10.0.0.6", "A")]);
+
+    f.use_case
+        .execute(&update(
+            ZONE,
+            vec![],
+            vec![UpdateOperation::DeleteRecord {
+                name: Arc::from("laptop.lan.example"),
+                data: addr("10.0.0.5"),
+            }],
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        records(&f.config).await,
+        vec![("laptop".to_string(), "10.0.0.6".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn test_replace_address_deletes_then_adds() {
+    let f = fixture(&[("laptop", "10.0.0.5", "A")]);
+
+    f.use_case
+        .execute(&update(
+            ZONE,
+            vec![],
+            vec![
+                UpdateOperation::DeleteRrset {
+                    name: Arc::from("laptop.lan.example"),
+                    record_type: RecordType::A,
+                },
+                add("laptop.lan.example", "10.0.0.7"),
+            ],
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        records(&f.config).await,
+        vec![("laptop".to_string(), "10.0.0.7".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn test_update_is_saved_once() {
+    let f = fixture(&[("laptop", "10.0.0.5", "A")]);
+
+    f.use_case
+        .execute(&update(
+            ZONE,
+            vec![],
+            vec![
+                UpdateOperation::DeleteName {
+                    name: Arc::from("laptop.lan.example"),
+                },
+                add("laptop.lan.example", "10.0.0.7"),
+                add("laptop.lan.example", "fd00::7"),
+                add("printer.lan.example", "10.0.0.9"),
+            ],
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(f.repo.saves.load(Ordering::SeqCst), 1);
+    assert_eq!(records(&f.config).await.len(), 3);
+}
+
+#[tokio::test]
+async fn test_failed_save_applies_nothing() {
+    let f = fixture_with_repo(
+        &[("laptop", "10.0.0.5", "A")],
+        MockConfigRepository {
+            fail: true,
+            ..MockConfigRepository::default()
+        },
+    );
+
+    let result = f
+        .use_case
+        .execute(&update(
+            ZONE,
+            vec![],
+            vec![
+                UpdateOperation::DeleteRrset {
+                    name: Arc::from("laptop.lan.example"),
+                    record_type: RecordType::A,
+                },
+                add("laptop.lan.example", "10.0.0.7"),
+            ],
+        ))
+        .await;
+
+    assert_eq!(result, Err(UpdateRcode::ServFail));
+    assert_eq!(
+        records(&f.config).await,
+        vec![("laptop".to_string(), "10.0.0.5".to_string())]
+    );
+    assert!(f.registry.registered.lock().unwrap().is_empty());
+    assert!(f.registry.unregistered.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_invalid_operation_applies_nothing() {
+    let f = fixture(&[("laptop", "10.0.0.5", "A")]);
+
+    let result = f
+        .use_case
+        .execute(&update(
+            ZONE,
+            vec![],
+            vec![
+                UpdateOperation::DeleteName {
+                    name: Arc::from("laptop.lan.example"),
+                },
+                add(ZONE, "10.0.0.7"),
+            ],
+        ))
+        .await;
+
+    assert_eq!(result, Err(UpdateRcode::Refused));
+    assert_eq!(records(&f.config).await.len(), 1);
+    assert_eq!(f.repo.saves.load(Ordering::SeqCst), 0);
+}
+
+// ── Prerequisites ────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_name_not_in_use_fails_when_name_exists() {
+    let f = fixture(&[("laptop", "10.0.0.5", "A")]);
+
+    let result = f
+        .use_case
+        .execute(&update(
+            ZONE,
+            vec![UpdatePrerequisite::NameNotInUse {
+                name: Arc::from("laptop.lan.example"),
+            }],
+            vec![add("laptop.lan.example", "10.0.0.6")],
+        ))
+        .await;
+
+    assert_eq!(result, Err(UpdateRcode::YxDomain));
+    assert_eq!(records(&f.config).await.len(), 1);
+}
+
+#[tokio::test]
+async fn test_name_in_use_fails_when_name_is_free() {
+    let f = fixture(&[]);
+
+    let result = f
+        .use_case
+        .execute(&update(
+            ZONE,
+            vec![UpdatePrerequisite::NameInUse {
+                name: Arc::from("laptop.lan.example"),
+            }],
+            vec![],
+        ))
+        .await;
+
+    assert_eq!(result, Err(UpdateRcode::NxDomain));
+}
+
+#[tokio::test]
+async fn test_rrset_exists_and_absent() {
+    let f = fixture(&[("laptop", "10.0.0.5", "A")]);
+    let name: Arc<str> = Arc::from("laptop.lan.example");
+
+    let exists_aaaa = f
+        .use_case
+        .execute(&update(
+            ZONE,
+            vec![UpdatePrerequisite::RrsetExists {
+                name: name.clone(),
+                record_type: RecordType::AAAA,
+            }],
+            vec![],
+        ))
+        .await;
+    let absent_a = f
+        .use_case
+        .execute(&update(
+            ZONE,
+            vec![UpdatePrerequisite::RrsetAbsent {
+                name: name.clone(),
+                record_type: RecordType::A,
+            }],
+            vec![],
+        ))
+        .await;
+
+    assert_eq!(exists_aaaa, Err(UpdateRcode::NxRrset));
+    assert_eq!(absent_a, Err(UpdateRcode::YxRrset));
+}
+
+#[tokio::test]
+async fn test_rrset_equals_requires_exact_match() {
+    let f = fixture(&[("laptop", "10.0.0.5", "A"), ("laptop", "10.0.0.6", "A")]);
+    let equals = |ips: &[&str]| UpdatePrerequisite::RrsetEquals {
+        name: Arc::from("laptop.lan.example"),
+        record_type: RecordType::A,
+        data: ips.iter().map(|ip| addr(ip)).collect(),
+    };
+
+    let partial = f
+        .use_case
+        .execute(&update(ZONE, vec![equals(&["10.0.0.5"])], vec![]))
+        .await;
+    let exact = f
+        .use_case
+        .execute(&update(
+            ZONE,
+            vec![equals(&["10.0.0.6", "10.0.0.5"])],
+            vec![],
+        ))
+        .await;
+
+    assert_eq!(partial, Err(UpdateRcode::NxRrset));
+    assert_eq!(exact, Ok(()));
+}
+
+#[tokio::test]
+async fn test_txt_records_are_kept_for_prerequisites() {
+    let f = fixture(&[]);
+    let name = "_owner.app.lan.example";
+    let txt = |value: &str| UpdateData::Rdata {
+        record_type: RecordType::TXT,
+        rdata: Arc::from(value.as_bytes()),
+    };
+
+    f.use_case
+        .execute(&update(
+            ZONE,
+            vec![UpdatePrerequisite::NameNotInUse {
+                name: Arc::from(name),
+            }],
+            vec![UpdateOperation::Add {
+                name: Arc::from(name),
+                data: txt("heritage=external-dns"),
+                ttl: 300,
+            }],
+        ))
+        .await
+        .unwrap();
+    let stored = f.config.read().await.dns.dynamic_update_records.clone();
+    assert_eq!(stored.len(), 1);
+    assert_eq!(stored[0].update_data(), Some(txt("heritage=external-dns")));
+    assert!(records(&f.config).await.is_empty());
+
+    let removed = f
+        .use_case
+        .execute(&update(
+            ZONE,
+            vec![UpdatePrerequisite::RrsetEquals {
+                name: Arc::from(name),
+                record_type: RecordType::TXT,
+                data: vec![txt("heritage=external-dns")],
+            }],
+            vec![UpdateOperation::DeleteRecord {
+                name: Arc::from(name),
+                data: txt("heritage=external-dns"),
+            }],
+        ))
+        .await;
+
+    assert_eq!(removed, Ok(()));
+    assert!(f.config.read().await.dns.dynamic_update_records.is_empty());
+}
+
+// ── Reverse zone ─────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_reverse_zone_registers_and_unregisters_ptr() {
+    let f = fixture(&[]);
+    let name: Arc<str> = Arc::from("5.0.0.10.in-addr.arpa");
+
+    f.use_case
+        .execute(&update(
+            REVERSE_ZONE,
+            vec![],
+            vec![UpdateOperation::Add {
+                name: name.clone(),
+                data: UpdateData::Ptr(Arc::from("laptop.lan.example")),
+                ttl: 600,
+            }],
+        ))
+        .await
+        .unwrap();
+    f.use_case
+        .execute(&update(
+            REVERSE_ZONE,
+            vec![],
+            vec![UpdateOperation::DeleteName { name }],
+        ))
+        .await
+        .unwrap();
+
+    let ip: IpAddr = "10.0.0.5".parse().unwrap();
+    assert_eq!(
+        *f.registry.registered.lock().unwrap(),
+        vec![(ip, "laptop.lan.example".to_string(), 600)]
+    );
+    assert_eq!(*f.registry.unregistered.lock().unwrap(), vec![ip]);
+    assert!(records(&f.config).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_reverse_prerequisites_read_the_ptr_registry() {
+    let f = fixture(&[]);
+    let name: Arc<str> = Arc::from("5.0.0.10.in-addr.arpa");
+    let ptr = |target: &str| UpdateOperation::Add {
+        name: name.clone(),
+        data: UpdateData::Ptr(Arc::from(target)),
+        ttl: 600,
+    };
+
+    f.use_case
+        .execute(&update(
+            REVERSE_ZONE,
+            vec![UpdatePrerequisite::NameNotInUse { name: name.clone() }],
+            vec![ptr("laptop.lan.example")],
+        ))
+        .await
+        .unwrap();
+    let second = f
+        .use_case
+        .execute(&update(
+            REVERSE_ZONE,
+            vec![UpdatePrerequisite::NameNotInUse { name: name.clone() }],
+            vec![ptr("other.lan.example")],
+        ))
+        .await;
+    let equals = f
+        .use_case
+        .execute(&update(
+            REVERSE_ZONE,
+            vec![UpdatePrerequisite::RrsetEquals {
+                name: name.clone(),
+                record_type: RecordType::PTR,
+                data: vec![UpdateData::Ptr(Arc::from("laptop.lan.example"))],
+            }],
+            vec![],
+        ))
+        .await;
+
+    assert_eq!(second, Err(UpdateRcode::YxDomain));
+    assert_eq!(equals, Ok(()));
+    assert_eq!(f.registry.registered.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_reverse_delete_record_requires_matching_target() {
+    let f = fixture(&[]);
+    let name: Arc<str> = Arc::from("5.0.0.10.in-addr.arpa");
+    let delete = |target: &str| UpdateOperation::DeleteRecord {
+        name: name.clone(),
+        data: UpdateData::Ptr(Arc::from(target)),
+    };
+    let ip: IpAddr = "10.0.0.5".parse().unwrap();
+
+    f.use_case
+        .execute(&update(
+            REVERSE_ZONE,
+            vec![],
+            vec![UpdateOperation::Add {
+                name: name.clone(),
+                data: UpdateData::Ptr(Arc::from("laptop.lan.example")),
+                ttl: 600,
+            }],
+        ))
+        .await
+        .unwrap();
+    f.use_case
+        .execute(&update(
+            REVERSE_ZONE,
+            vec![],
+            vec![delete("other.lan.example")],
+        ))
+        .await
+        .unwrap();
+
+    assert!(f.registry.unregistered.lock().unwrap().is_empty());
+    assert!(f.registry.live.lock().unwrap().contains_key(&ip));
+
+    f.use_case
+        .execute(&update(
+            REVERSE_ZONE,
+            vec![],
+            vec![delete("laptop.lan.example")],
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(*f.registry.unregistered.lock().unwrap(), vec![ip]);
+}
+
+#[tokio::test]
+async fn test_address_in_reverse_zone_is_refused() {
+    let f = fixture(&[]);
+
+    let result = f
+        .use_case
+        .execute(&update(
+            REVERSE_ZONE,
+            vec![],
+            vec![add("5.0.0.10.in-addr.arpa", "10.0.0.5")],
+        ))
+        .await;
+
+    assert_eq!(result, Err(UpdateRcode::Refused));
+}
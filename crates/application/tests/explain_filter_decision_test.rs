@@ -0,0 +1,208 @@
+mod helpers;
+
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{
+    BlockFilterEnginePort, FilterDecision, SafeSearchEnginePort, ScheduleStatePort, TraceRuleKind,
+};
+use ferrous_dns_application::use_cases::{ExplainFilterDecisionUseCase, ExplainSubject};
+use ferrous_dns_domain::{BlockSource, DomainError, GroupOverride, RecordType};
+use helpers::{MockBlockFilterEngine, MockGroupRepository};
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr};
+use std::sync::{Arc, Mutex};
+
+const CLIENT_IP: IpAddr = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 100));
+
+#[derive(Default)]
+struct FakeScheduleState {
+    states: Mutex<HashMap<i64, GroupOverride>>,
+}
+
+impl ScheduleStatePort for FakeScheduleState {
+    fn get(&self, group_id: i64) -> Option<GroupOverride> {
+        self.states.lock().unwrap().get(&group_id).copied()
+    }
+
+    fn set(&self, group_id: i64, state: GroupOverride) {
+        self.states.lock().unwrap().insert(group_id, state);
+    }
+
+    fn clear(&self, group_id: i64) {
+        self.states.lock().unwrap().remove(&group_id);
+    }
+
+    fn is_empty(&self) -> bool {
+        self.states.lock().unwrap().is_empty()
+    }
+
+    fn sweep_expired(&self) {}
+}
+
+struct FakeSafeSearch;
+
+#[async_trait]
+impl SafeSearchEnginePort for FakeSafeSearch {
+    fn cname_for(&self, domain: &str, _group_id: i64) -> Option<&'static str> {
+        (domain == "www.google.com").then_some("forcesafesearch.google.com")
+    }
+
+    async fn reload(&self) -> Result<(), DomainError> {
+        Ok(())
+    }
+}
+
+struct Fixture {
+    engine: Arc<MockBlockFilterEngine>,
+    schedule: Arc<FakeScheduleState>,
+    use_case: ExplainFilterDecisionUseCase,
+}
+
+fn fixture() -> Fixture {
+    let engine = Arc::new(MockBlockFilterEngine::new());
+    let schedule = Arc::new(FakeScheduleState::default());
+    let use_case = ExplainFilterDecisionUseCase::new(
+        engine.clone(),
+        schedule.clone(),
+        Arc::new(FakeSafeSearch),
+        Arc::new(MockGroupRepository::new()),
+    );
+    Fixture {
+        engine,
+        schedule,
+        use_case,
+    }
+}
+
+#[tokio::test]
+async fn test_explains_blocked_domain_with_matching_list() {
+    let f = fixture();
+    f.engine.block_domain("ads.example.com");
+
+    let explanation = f
+        .use_case
+        .execute("ads.example.com", ExplainSubject::Group(1), RecordType::A)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        explanation.decision,
+        FilterDecision::Block(BlockSource::Blocklist)
+    );
+    assert_eq!(explanation.group_name.as_deref(), Some("Protected"));
+    assert!(explanation.blocking_enabled);
+    assert_eq!(explanation.trace.block_matches.len(), 1);
+    let matched = &explanation.trace.block_matches[0];
+    assert_eq!(matched.kind, TraceRuleKind::ListExact);
+    assert_eq!(matched.sources, vec!["mock-list".to_string()]);
+}
+
+#[tokio::test]
+async fn test_client_subject_resolves_group_and_keeps_client() {
+    let f = fixture();
+
+    let explanation = f
+        .use_case
+        .execute(
+            "example.com",
+            ExplainSubject::Client(CLIENT_IP),
+            RecordType::AAAA,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(explanation.group_id, 1);
+    assert_eq!(explanation.client_ip, Some(CLIENT_IP));
+    assert_eq!(explanation.record_type, RecordType::AAAA);
+    assert_eq!(explanation.decision, FilterDecision::Allow);
+}
+
+#[tokio::test]
+async fn test_domain_is_normalised() {
+    let f = fixture();
+    f.engine.block_domain("ads.example.com");
+
+    let explanation = f
+        .use_case
+        .execute(
+            " Ads.Example.COM. ",
+            ExplainSubject::Group(1),
+            RecordType::A,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(explanation.domain, "ads.example.com");
+    assert!(matches!(explanation.decision, FilterDecision::Block(_)));
+}
+
+#[tokio::test]
+async fn test_empty_domain_is_rejected() {
+    let f = fixture();
+
+    let result = f
+        .use_case
+        .execute("  ", ExplainSubject::Group(1), RecordType::A)
+        .await;
+
+    assert!(matches!(result, Err(DomainError::InvalidDomainName(_))));
+}
+
+#[tokio::test]
+async fn test_unknown_group_is_not_found() {
+    let f = fixture();
+
+    let result = f
+        .use_case
+        .execute("example.com", ExplainSubject::Group(42), RecordType::A)
+        .await;
+
+    assert!(matches!(result, Err(DomainError::GroupNotFound(42))));
+}
+
+#[tokio::test]
+async fn test_reports_schedule_and_safe_search() {
+    let f = fixture();
+    f.schedule
+        .set(1, GroupOverride::TimedBlockUntil(1_900_000_000));
+
+    let explanation = f
+        .use_case
+        .execute("www.google.com", ExplainSubject::Group(1), RecordType::A)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        explanation.schedule,
+        Some(GroupOverride::TimedBlockUntil(1_900_000_000))
+    );
+    assert_eq!(
+        explanation.safe_search_cname,
+        Some("forcesafesearch.google.com")
+    );
+}
+
+#[tokio::test]
+async fn test_reports_cname_cloaking_parent() {
+    let f = fixture();
+    f.engine
+        .store_cname_decision("metrics.shop.example", "tracker.adnet.example", 1, 60);
+
+    let explanation = f
+        .use_case
+        .execute(
+            "metrics.shop.example",
+            ExplainSubject::Group(1),
+            RecordType::A,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        explanation.decision,
+        FilterDecision::Block(BlockSource::CnameCloaking)
+    );
+    assert_eq!(
+        explanation.trace.cname_parent.as_deref(),
+        Some("tracker.adnet.example")
+    );
+}
@@ -0,0 +1,210 @@
+use ferrous_dns_application::use_cases::forwarding_rules::{
+    CreateForwardingRuleUseCase, DeleteForwardingRuleUseCase, GetForwardingRulesUseCase,
+    UpdateForwardingRuleUseCase,
+};
+use ferrous_dns_domain::{DomainError, ForwardingTarget};
+use std::sync::Arc;
+
+mod helpers;
+use helpers::{MockForwardingRuleRepository, MockForwardingRuleStore};
+
+fn server(endpoint: &str) -> ForwardingTarget {
+    ForwardingTarget::Server(Arc::from(endpoint))
+}
+
+fn create_use_case(
+    repo: &Arc<MockForwardingRuleRepository>,
+    store: &Arc<MockForwardingRuleStore>,
+) -> CreateForwardingRuleUseCase {
+    CreateForwardingRuleUseCase::new(repo.clone(), store.clone())
+}
+
+// ── CreateForwardingRuleUseCase ───────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_create_normalizes_domain_and_reloads_store() {
+    let repo = Arc::new(MockForwardingRuleRepository::new());
+    let store = Arc::new(MockForwardingRuleStore::new());
+
+    let rule = create_use_case(&repo, &store)
+        .execute(
+            "Corp.Example.".to_string(),
+            server("tcp://10.1.1.53:53"),
+            true,
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(rule.domain.as_ref(), "corp.example");
+    assert_eq!(store.reload_count(), 1);
+    assert_eq!(store.active_domains(), vec!["corp.example".to_string()]);
+}
+
+#[tokio::test]
+async fn test_create_rejects_invalid_domain() {
+    let repo = Arc::new(MockForwardingRuleRepository::new());
+    let store = Arc::new(MockForwardingRuleStore::new());
+
+    let result = create_use_case(&repo, &store)
+        .execute("bad domain".to_string(), server("10.1.1.53:53"), true, None)
+        .await;
+
+    assert!(matches!(result, Err(DomainError::InvalidForwardingRule(_))));
+    assert_eq!(repo.count().await, 0);
+    assert_eq!(store.reload_count(), 0);
+}
+
+#[tokio::test]
+async fn test_create_rejects_invalid_endpoint() {
+    let repo = Arc::new(MockForwardingRuleRepository::new());
+    let store = Arc::new(MockForwardingRuleStore::new());
+
+    let result = create_use_case(&repo, &store)
+        .execute("consul".to_string(), server("not-an-endpoint"), true, None)
+        .await;
+
+    assert!(matches!(result, Err(DomainError::InvalidForwardingRule(_))));
+}
+
+#[tokio::test]
+async fn test_create_duplicate_domain_fails() {
+    let repo = Arc::new(MockForwardingRuleRepository::new());
+    let store = Arc::new(MockForwardingRuleStore::new());
+    let use_case = create_use_case(&repo, &store);
+
+    use_case
+        .execute("consul".to_string(), server("127.0.0.1:8600"), true, None)
+        .await
+        .unwrap();
+    let result = use_case
+        .execute("CONSUL".to_string(), server("127.0.0.1:8600"), true, None)
+        .await;
+
+    assert!(matches!(result, Err(DomainError::InvalidForwardingRule(_))));
+}
+
+// ── GetForwardingRulesUseCase ─────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_get_all_and_by_id() {
+    let repo = Arc::new(MockForwardingRuleRepository::new());
+    let store = Arc::new(MockForwardingRuleStore::new());
+    let created = create_use_case(&repo, &store)
+        .execute(
+            "consul".to_string(),
+            ForwardingTarget::Pool(Arc::from("internal")),
+            true,
+            None,
+        )
+        .await
+        .unwrap();
+
+    let use_case = GetForwardingRulesUseCase::new(repo);
+
+    assert_eq!(use_case.get_all().await.unwrap().len(), 1);
+    let fetched = use_case
+        .get_by_id(created.id.unwrap())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        fetched.target,
+        ForwardingTarget::Pool(Arc::from("internal"))
+    );
+    assert!(use_case.get_by_id(999).await.unwrap().is_none());
+}
+
+// ── UpdateForwardingRuleUseCase ───────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_disable_rule_removes_it_from_store() {
+    let repo = Arc::new(MockForwardingRuleRepository::new());
+    let store = Arc::new(MockForwardingRuleStore::new());
+    let created = create_use_case(&repo, &store)
+        .execute("consul".to_string(), server("127.0.0.1:8600"), true, None)
+        .await
+        .unwrap();
+
+    let updated = UpdateForwardingRuleUseCase::new(repo.clone(), store.clone())
+        .execute(created.id.unwrap(), None, None, Some(false), None)
+        .await
+        .unwrap();
+
+    assert!(!updated.enabled);
+    assert_eq!(store.reload_count(), 2);
+    assert!(store.active_domains().is_empty());
+}
+
+#[tokio::test]
+async fn test_update_not_found() {
+    let use_case = UpdateForwardingRuleUseCase::new(
+        Arc::new(MockForwardingRuleRepository::new()),
+        Arc::new(MockForwardingRuleStore::new()),
+    );
+
+    let result = use_case.execute(42, None, None, Some(false), None).await;
+
+    assert!(matches!(
+        result,
+        Err(DomainError::ForwardingRuleNotFound(42))
+    ));
+}
+
+#[tokio::test]
+async fn test_update_rejects_invalid_target() {
+    let repo = Arc::new(MockForwardingRuleRepository::new());
+    let store = Arc::new(MockForwardingRuleStore::new());
+    let created = create_use_case(&repo, &store)
+        .execute("consul".to_string(), server("127.0.0.1:8600"), true, None)
+        .await
+        .unwrap();
+
+    let result = UpdateForwardingRuleUseCase::new(repo, store.clone())
+        .execute(
+            created.id.unwrap(),
+            None,
+            Some(ForwardingTarget::Pool(Arc::from(""))),
+            None,
+            None,
+        )
+        .await;
+
+    assert!(matches!(result, Err(DomainError::InvalidForwardingRule(_))));
+    assert_eq!(store.reload_count(), 1);
+}
+
+// ── DeleteForwardingRuleUseCase ───────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_delete_rule_reloads_store() {
+    let repo = Arc::new(MockForwardingRuleRepository::new());
+    let store = Arc::new(MockForwardingRuleStore::new());
+    let created = create_use_case(&repo, &store)
+        .execute("consul".to_string(), server("127.0.0.1:8600"), true, None)
+        .await
+        .unwrap();
+
+    DeleteForwardingRuleUseCase::new(repo.clone(), store.clone())
+        .execute(created.id.unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(repo.count().await, 0);
+    assert!(store.active_domains().is_empty());
+}
+
+#[tokio::test]
+async fn test_delete_not_found() {
+    let use_case = DeleteForwardingRuleUseCase::new(
+        Arc::new(MockForwardingRuleRepository::new()),
+        Arc::new(MockForwardingRuleStore::new()),
+    );
+
+    let result = use_case.execute(7).await;
+
+    assert!(matches!(
+        result,
+        Err(DomainError::ForwardingRuleNotFound(7))
+    ));
+}
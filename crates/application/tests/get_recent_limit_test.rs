@@ -3,7 +3,9 @@ use ferrous_dns_application::ports::{
     CacheStats, PagedQueryResult, QueryLogRepository, TimeGranularity, TimelineBucket,
 };
 use ferrous_dns_application::use_cases::{GetRecentQueriesUseCase, PagedQueryInput};
-use ferrous_dns_domain::{query_log::QueryLog, DomainError, QueryLogFilter, QueryStats};
+use ferrous_dns_domain::{
+    query_log::QueryLog, BlocklistSourceHits, DomainError, QueryLogFilter, QueryStats,
+};
 use std::sync::{Arc, Mutex};
 
 struct CaptureLimitRepository {
@@ -82,6 +84,10 @@ impl QueryLogRepository for CaptureLimitRepository {
         unimplemented!()
     }
 
+    async fn get_blocklist_source_hits(&self) -> Result<Vec<BlocklistSourceHits>, DomainError> {
+        unimplemented!()
+    }
+
     async fn delete_older_than(&self, _: u32) -> Result<u64, DomainError> {
         unimplemented!()
     }
@@ -0,0 +1,186 @@
+mod helpers;
+
+use ferrous_dns_application::ports::{DnsResolution, GroupRepository, GroupUpstreamStore};
+use ferrous_dns_application::use_cases::{HandleDnsQueryUseCase, UpdateGroupUpstreamPoolsUseCase};
+use ferrous_dns_domain::{
+    Config, DnsRequest, DomainError, RecordType, UpstreamPool, UpstreamStrategy,
+};
+use helpers::{
+    MockBlockFilterEngine, MockDnsResolver, MockGroupRepository, MockGroupUpstreamStore,
+    MockQueryLogRepository,
+};
+use std::net::{IpAddr, Ipv4Addr};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+const CLIENT_IP: IpAddr = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 100));
+
+fn pool(name: &str) -> UpstreamPool {
+    UpstreamPool {
+        name: name.to_string(),
+        strategy: UpstreamStrategy::Failover,
+        priority: 1,
+        servers: vec!["udp://9.9.9.9:53".to_string()],
+        weight: None,
+    }
+}
+
+fn config_with_pools(names: &[&str]) -> Arc<RwLock<Config>> {
+    let mut config = Config::default();
+    config.dns.pools = names.iter().map(|n| pool(n)).collect();
+    Arc::new(RwLock::new(config))
+}
+
+fn make_update_use_case(
+    repo: Arc<MockGroupRepository>,
+    store: Arc<MockGroupUpstreamStore>,
+) -> UpdateGroupUpstreamPoolsUseCase {
+    UpdateGroupUpstreamPoolsUseCase::new(repo, store, config_with_pools(&["filtered", "quad9"]))
+}
+
+// ── UpdateGroupUpstreamPoolsUseCase ────────────────────────────────────────
+
+#[tokio::test]
+async fn test_set_upstream_pools_persists_and_reloads_store() {
+    let repo = Arc::new(MockGroupRepository::new());
+    let store = Arc::new(MockGroupUpstreamStore::new());
+    let use_case = make_update_use_case(repo.clone(), store.clone());
+
+    let group = use_case
+        .execute(1, vec!["filtered".to_string(), " quad9 ".to_string()])
+        .await
+        .unwrap();
+
+    let names: Vec<&str> = group.upstream_pools.iter().map(|p| p.as_ref()).collect();
+    assert_eq!(names, vec!["filtered", "quad9"]);
+    assert_eq!(store.reload_count(), 1);
+    assert_eq!(
+        store.pools(1),
+        Some(vec!["filtered".to_string(), "quad9".to_string()])
+    );
+
+    let stored = repo.get_by_id(1).await.unwrap().unwrap();
+    assert_eq!(stored.upstream_pools.len(), 2);
+}
+
+#[tokio::test]
+async fn test_empty_list_returns_group_to_global_pools() {
+    let store = Arc::new(MockGroupUpstreamStore::new());
+    let use_case = make_update_use_case(Arc::new(MockGroupRepository::new()), store.clone());
+
+    use_case
+        .execute(1, vec!["filtered".to_string()])
+        .await
+        .unwrap();
+    let group = use_case.execute(1, Vec::new()).await.unwrap();
+
+    assert!(group.upstream_pools.is_empty());
+    assert!(!store.has_pools(1));
+}
+
+#[tokio::test]
+async fn test_unknown_pool_is_rejected() {
+    let store = Arc::new(MockGroupUpstreamStore::new());
+    let use_case = make_update_use_case(Arc::new(MockGroupRepository::new()), store.clone());
+
+    let result = use_case.execute(1, vec!["missing".to_string()]).await;
+
+    assert!(matches!(result, Err(DomainError::InvalidInput(msg)) if msg.contains("missing")));
+    assert_eq!(store.reload_count(), 0);
+}
+
+#[tokio::test]
+async fn test_duplicate_pool_is_rejected() {
+    let use_case = make_update_use_case(
+        Arc::new(MockGroupRepository::new()),
+        Arc::new(MockGroupUpstreamStore::new()),
+    );
+
+    let result = use_case
+        .execute(1, vec!["filtered".to_string(), "filtered".to_string()])
+        .await;
+
+    assert!(matches!(result, Err(DomainError::InvalidInput(_))));
+}
+
+#[tokio::test]
+async fn test_set_upstream_pools_unknown_group_returns_not_found() {
+    let use_case = make_update_use_case(
+        Arc::new(MockGroupRepository::new()),
+        Arc::new(MockGroupUpstreamStore::new()),
+    );
+
+    let result = use_case.execute(999, vec!["filtered".to_string()]).await;
+
+    assert!(matches!(result, Err(DomainError::GroupNotFound(999))));
+}
+
+// ── HandleDnsQueryUseCase group routing ────────────────────────────────────
+
+async fn make_handler(
+    store: Arc<MockGroupUpstreamStore>,
+) -> (HandleDnsQueryUseCase, Arc<MockDnsResolver>) {
+    let resolver = Arc::new(MockDnsResolver::new());
+    resolver
+        .set_response(
+            "example.com",
+            DnsResolution::new(vec!["93.184.216.34".parse().unwrap()], false),
+        )
+        .await;
+    let handler = HandleDnsQueryUseCase::new(
+        resolver.clone(),
+        Arc::new(MockBlockFilterEngine::new()),
+        Arc::new(MockQueryLogRepository::new()),
+    )
+    .with_group_upstreams(store);
+    (handler, resolver)
+}
+
+#[tokio::test]
+async fn test_query_from_routed_group_carries_group_id() {
+    let repo = MockGroupRepository::new();
+    repo.update_upstream_pools(1, &["filtered".to_string()])
+        .await
+        .unwrap();
+    let store = Arc::new(MockGroupUpstreamStore::new());
+    store.replace_groups(&repo.get_all().await.unwrap());
+    let (handler, resolver) = make_handler(store).await;
+
+    handler
+        .execute(&DnsRequest::new("example.com", RecordType::A, CLIENT_IP))
+        .await
+        .unwrap();
+
+    let groups = resolver.query_groups();
+    assert!(!groups.is_empty());
+    assert!(groups.iter().all(|g| *g == Some(1)), "groups={groups:?}");
+}
+
+#[tokio::test]
+async fn test_query_from_unrouted_group_uses_shared_partition() {
+    let (handler, resolver) = make_handler(Arc::new(MockGroupUpstreamStore::new())).await;
+
+    handler
+        .execute(&DnsRequest::new("example.com", RecordType::A, CLIENT_IP))
+        .await
+        .unwrap();
+
+    let groups = resolver.query_groups();
+    assert!(!groups.is_empty());
+    assert!(groups.iter().all(Option::is_none), "groups={groups:?}");
+}
+
+#[tokio::test]
+async fn test_fast_path_cache_lookup_is_partitioned_for_routed_group() {
+    let repo = MockGroupRepository::new();
+    repo.update_upstream_pools(1, &["filtered".to_string()])
+        .await
+        .unwrap();
+    let store = Arc::new(MockGroupUpstreamStore::new());
+    store.replace_groups(&repo.get_all().await.unwrap());
+    let (handler, resolver) = make_handler(store).await;
+
+    let _ = handler.try_cache_direct("example.com", RecordType::A, CLIENT_IP);
+
+    assert_eq!(resolver.query_groups(), vec![Some(1)]);
+}
@@ -1,10 +1,14 @@
 mod helpers;
 
-use ferrous_dns_application::{ports::DnsResolution, use_cases::HandleDnsQueryUseCase};
-use ferrous_dns_domain::{BlockSource, DnsRequest, DomainError, RecordType};
+use ferrous_dns_application::{
+    ports::{DnsResolution, DnsRewrite},
+    services::{DnsMetrics, QueryStream},
+    use_cases::HandleDnsQueryUseCase,
+};
+use ferrous_dns_domain::{BlockSource, DnsRequest, DnsRewriteRcode, DomainError, RecordType};
 use helpers::{
     DnsResolutionBuilder, MockBlockFilterEngine, MockClientRepository, MockDnsResolver,
-    MockQueryLogRepository,
+    MockDnsRewriteStore, MockQueryLogRepository, MOCK_LIST_ID,
 };
 use std::{net::IpAddr, sync::Arc};
 
@@ -99,6 +103,202 @@ async fn test_execute_blocked_domain_returns_error_and_logs_blocked() {
     assert_eq!(logs.len(), 1);
     assert!(logs[0].blocked);
     assert_eq!(logs[0].response_status, Some("BLOCKED"));
+    assert_eq!(logs[0].blocklist_source_ids, vec![MOCK_LIST_ID]);
+}
+
+#[tokio::test]
+async fn test_execute_observed_domain_is_resolved_and_logged_as_would_block() {
+    let resolver = Arc::new(MockDnsResolver::new());
+    let filter = Arc::new(MockBlockFilterEngine::new());
+    let log = Arc::new(MockQueryLogRepository::new());
+
+    resolver
+        .set_response("tracker.example.com", upstream_resolution("1.2.3.4"))
+        .await;
+    filter.observe_domain("tracker.example.com");
+
+    let use_case = make_use_case(resolver, filter, log.clone());
+    let request = DnsRequest::new("tracker.example.com", RecordType::A, CLIENT_IP);
+
+    let result = use_case.execute(&request).await;
+
+    assert!(result.is_ok());
+    let logs = log.get_sync_logs();
+    assert_eq!(logs.len(), 1);
+    assert!(!logs[0].blocked);
+    assert!(logs[0].would_block());
+    assert_eq!(logs[0].blocklist_source_ids, vec![MOCK_LIST_ID]);
+}
+
+#[tokio::test]
+async fn test_execute_applies_record_type_scoped_block() {
+    let resolver = Arc::new(MockDnsResolver::new());
+    let filter = Arc::new(MockBlockFilterEngine::new());
+    let log = Arc::new(MockQueryLogRepository::new());
+
+    filter.block_domain_for_type("metrics.example.com", RecordType::AAAA);
+    resolver
+        .set_response("metrics.example.com", upstream_resolution("1.2.3.4"))
+        .await;
+
+    let use_case = make_use_case(resolver, filter, log.clone());
+
+    let aaaa = DnsRequest::new("metrics.example.com", RecordType::AAAA, CLIENT_IP);
+    assert!(matches!(
+        use_case.execute(&aaaa).await,
+        Err(DomainError::Blocked)
+    ));
+
+    let a = DnsRequest::new("metrics.example.com", RecordType::A, CLIENT_IP);
+    assert!(use_case.execute(&a).await.is_ok());
+
+    let logs = log.get_sync_logs();
+    assert_eq!(logs.len(), 2);
+    assert!(logs[0].blocked);
+    assert!(!logs[1].blocked);
+}
+
+// ── execute: DNS rewrites ──────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_execute_rewrite_answers_with_fixed_address() {
+    let resolver = Arc::new(MockDnsResolver::new());
+    let filter = Arc::new(MockBlockFilterEngine::new());
+    let log = Arc::new(MockQueryLogRepository::new());
+    let rewrites = Arc::new(MockDnsRewriteStore::new());
+
+    rewrites.set_rewrite(
+        "nas.example.org",
+        1,
+        DnsRewrite::Addresses(vec!["10.0.0.5".parse().unwrap()]),
+    );
+    resolver
+        .set_response("nas.example.org", upstream_resolution("93.184.216.34"))
+        .await;
+
+    let use_case = make_use_case(resolver, filter, log.clone()).with_dns_rewrites(rewrites);
+    let request = DnsRequest::new("nas.example.org", RecordType::A, CLIENT_IP);
+
+    let resolution = use_case.execute(&request).await.unwrap();
+
+    assert_eq!(
+        resolution.addresses.as_slice(),
+        &["10.0.0.5".parse::<IpAddr>().unwrap()]
+    );
+    let logs = log.get_sync_logs();
+    assert_eq!(logs.len(), 1);
+    assert!(!logs[0].blocked);
+    assert_eq!(logs[0].response_status, Some("REWRITTEN"));
+    assert_eq!(logs[0].block_source, Some(BlockSource::DnsRewrite));
+}
+
+#[tokio::test]
+async fn test_execute_rewrite_cname_resolves_target() {
+    let resolver = Arc::new(MockDnsResolver::new());
+    let filter = Arc::new(MockBlockFilterEngine::new());
+    let log = Arc::new(MockQueryLogRepository::new());
+    let rewrites = Arc::new(MockDnsRewriteStore::new());
+
+    rewrites.set_rewrite(
+        "www.example.org",
+        1,
+        DnsRewrite::Cname(Arc::from("lan.example.net")),
+    );
+    resolver
+        .set_response("lan.example.net", upstream_resolution("192.168.1.10"))
+        .await;
+
+    let use_case = make_use_case(resolver, filter, log.clone()).with_dns_rewrites(rewrites);
+    let request = DnsRequest::new("www.example.org", RecordType::A, CLIENT_IP);
+
+    let resolution = use_case.execute(&request).await.unwrap();
+
+    assert_eq!(
+        resolution.addresses.as_slice(),
+        &["192.168.1.10".parse::<IpAddr>().unwrap()]
+    );
+    assert_eq!(
+        log.get_sync_logs()[0].block_source,
+        Some(BlockSource::DnsRewrite)
+    );
+}
+
+#[tokio::test]
+async fn test_execute_rewrite_rcode_maps_to_error() {
+    let resolver = Arc::new(MockDnsResolver::new());
+    let filter = Arc::new(MockBlockFilterEngine::new());
+    let log = Arc::new(MockQueryLogRepository::new());
+    let rewrites = Arc::new(MockDnsRewriteStore::new());
+
+    rewrites.set_rewrite(
+        "gone.example.org",
+        1,
+        DnsRewrite::Rcode(DnsRewriteRcode::NxDomain),
+    );
+    rewrites.set_rewrite(
+        "refused.example.org",
+        1,
+        DnsRewrite::Rcode(DnsRewriteRcode::Refused),
+    );
+
+    let use_case = make_use_case(resolver, filter, log.clone()).with_dns_rewrites(rewrites);
+
+    let gone = DnsRequest::new("gone.example.org", RecordType::A, CLIENT_IP);
+    assert!(matches!(
+        use_case.execute(&gone).await,
+        Err(DomainError::NxDomain)
+    ));
+    let refused = DnsRequest::new("refused.example.org", RecordType::A, CLIENT_IP);
+    assert!(matches!(
+        use_case.execute(&refused).await,
+        Err(DomainError::FilteredQuery(_))
+    ));
+    assert_eq!(log.get_sync_logs().len(), 2);
+}
+
+#[tokio::test]
+async fn test_execute_block_wins_over_rewrite() {
+    let resolver = Arc::new(MockDnsResolver::new());
+    let filter = Arc::new(MockBlockFilterEngine::new());
+    let log = Arc::new(MockQueryLogRepository::new());
+    let rewrites = Arc::new(MockDnsRewriteStore::new());
+
+    filter.block_domain("ads.example.org");
+    rewrites.set_rewrite(
+        "ads.example.org",
+        1,
+        DnsRewrite::Addresses(vec!["10.0.0.5".parse().unwrap()]),
+    );
+
+    let use_case = make_use_case(resolver, filter, log.clone()).with_dns_rewrites(rewrites);
+    let request = DnsRequest::new("ads.example.org", RecordType::A, CLIENT_IP);
+
+    assert!(matches!(
+        use_case.execute(&request).await,
+        Err(DomainError::Blocked)
+    ));
+}
+
+#[tokio::test]
+async fn test_try_cache_direct_skips_rewritten_domain() {
+    let resolver = Arc::new(MockDnsResolver::new());
+    let filter = Arc::new(MockBlockFilterEngine::new());
+    let log = Arc::new(MockQueryLogRepository::new());
+    let rewrites = Arc::new(MockDnsRewriteStore::new());
+
+    rewrites.set_rewrite(
+        "nas.example.org",
+        1,
+        DnsRewrite::Addresses(vec!["10.0.0.5".parse().unwrap()]),
+    );
+    resolver.set_cached_response("nas.example.org", cached_resolution("93.184.216.34"));
+
+    let use_case = make_use_case(resolver, filter, log.clone()).with_dns_rewrites(rewrites);
+
+    assert!(use_case
+        .try_cache_direct("nas.example.org", RecordType::A, CLIENT_IP)
+        .is_none());
+    assert!(log.get_sync_logs().is_empty());
 }
 
 // ── execute: error paths ───────────────────────────────────────────────────
@@ -368,6 +568,7 @@ async fn test_cname_cloaking_blocked_upstream_path() {
     assert!(logs[0].blocked);
     assert_eq!(logs[0].response_status, Some("BLOCKED"));
     assert_eq!(logs[0].block_source, Some(BlockSource::CnameCloaking));
+    assert_eq!(logs[0].blocklist_source_ids, vec![MOCK_LIST_ID]);
 }
 
 #[tokio::test]
@@ -627,3 +828,74 @@ async fn test_execute_log_records_response_time() {
     let logs = log.get_sync_logs();
     assert!(logs[0].response_time_us.is_some());
 }
+
+// ── metrics ────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_logged_queries_are_counted_in_shared_metrics() {
+    let resolver = Arc::new(MockDnsResolver::new());
+    let filter = Arc::new(MockBlockFilterEngine::new());
+    let log = Arc::new(MockQueryLogRepository::new());
+    let metrics = Arc::new(DnsMetrics::new());
+
+    resolver.set_cached_response("google.com", cached_resolution("8.8.8.8"));
+    filter.block_domain("ads.example.com");
+
+    let use_case = make_use_case(resolver, filter, log).with_metrics(Arc::clone(&metrics));
+    use_case.try_cache_direct("google.com", RecordType::A, CLIENT_IP);
+    let _ = use_case
+        .execute(&DnsRequest::new(
+            "ads.example.com",
+            RecordType::AAAA,
+            CLIENT_IP,
+        ))
+        .await;
+
+    let snapshot = metrics.snapshot();
+    let count = |status: &str| {
+        snapshot
+            .results
+            .iter()
+            .find(|(s, _)| *s == status)
+            .unwrap()
+            .1
+    };
+    assert_eq!(count("NOERROR"), 1);
+    assert_eq!(count("BLOCKED"), 1);
+    assert_eq!(
+        snapshot.record_types,
+        vec![(RecordType::A, 1), (RecordType::AAAA, 1)]
+    );
+    assert_eq!(snapshot.latency_count, 2);
+}
+
+// ── query stream ───────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_logged_queries_are_published_to_query_stream() {
+    let resolver = Arc::new(MockDnsResolver::new());
+    let filter = Arc::new(MockBlockFilterEngine::new());
+    let log = Arc::new(MockQueryLogRepository::new());
+    let stream = Arc::new(QueryStream::default());
+    let mut receiver = stream.subscribe();
+
+    resolver.set_cached_response("google.com", cached_resolution("8.8.8.8"));
+    filter.block_domain("ads.example.com");
+
+    let use_case = make_use_case(resolver, filter, log).with_query_stream(stream.clone());
+    use_case.try_cache_direct("google.com", RecordType::A, CLIENT_IP);
+    let _ = use_case
+        .execute(&DnsRequest::new(
+            "ads.example.com",
+            RecordType::A,
+            CLIENT_IP,
+        ))
+        .await;
+
+    let first = receiver.try_recv().unwrap();
+    assert_eq!(first.domain.as_ref(), "google.com");
+    assert!(!first.blocked);
+    let second = receiver.try_recv().unwrap();
+    assert_eq!(second.domain.as_ref(), "ads.example.com");
+    assert!(second.blocked);
+}
@@ -60,6 +60,10 @@ impl PtrRecordRegistry for MockPtrRegistry {
     fn unregister(&self, ip: IpAddr) {
         self.unregistered.lock().unwrap().push(ip);
     }
+
+    fn lookup(&self, _ip: IpAddr) -> Option<Arc<str>> {
+        None
+    }
 }
 
 // ── Helpers ──────────────────────────────────────────────────────────────────
@@ -187,3 +191,42 @@ async fn test_create_local_record_does_not_register_on_save_failure() {
         "register must NOT be called on rollback path"
     );
 }
+
+#[tokio::test]
+async fn test_create_batch_moves_ptr_of_replaced_address() {
+    let registry = MockPtrRegistry::new_arc();
+    let config = config_with_record("10.0.10.1");
+    let use_case = CreateLocalRecordUseCase::new(config.clone(), MockConfigRepository::ok())
+        .with_ptr_registry(Some(registry.clone() as Arc<dyn PtrRecordRegistry>));
+
+    let result = use_case
+        .execute_batch(|config| {
+            config.dns.local_records[0].ip = "10.0.10.2".to_string();
+        })
+        .await;
+
+    assert!(result.is_ok());
+    assert_eq!(config.read().await.dns.local_records[0].ip, "10.0.10.2");
+    let unregistered = registry.unregistered.lock().unwrap();
+    let registered = registry.registered.lock().unwrap();
+    assert_eq!(*unregistered, ["10.0.10.1".parse::<IpAddr>().unwrap()]);
+    assert_eq!(registered.len(), 1);
+    assert_eq!(registered[0].0, "10.0.10.2".parse::<IpAddr>().unwrap());
+    assert_eq!(registered[0].1, "host.local");
+}
+
+#[tokio::test]
+async fn test_create_batch_restores_records_on_save_failure() {
+    let registry = MockPtrRegistry::new_arc();
+    let config = config_with_record("10.0.10.1");
+    let use_case = CreateLocalRecordUseCase::new(config.clone(), MockConfigRepository::failing())
+        .with_ptr_registry(Some(registry.clone() as Arc<dyn PtrRecordRegistry>));
+
+    let result = use_case
+        .execute_batch(|config| config.dns.local_records.clear())
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(config.read().await.dns.local_records.len(), 1);
+    assert!(registry.unregistered.lock().unwrap().is_empty());
+}
@@ -0,0 +1,183 @@
+use ferrous_dns_application::use_cases::local_zones::{
+    CreateZoneRecordUseCase, DeleteZoneRecordUseCase, GetLocalZonesUseCase, UpdateZoneRecordUseCase,
+};
+use ferrous_dns_domain::{DomainError, LocalZone, ZoneRecord};
+use std::sync::Arc;
+
+mod helpers;
+use helpers::{MockLocalZoneRepository, MockLocalZoneStore};
+
+fn home_zone() -> LocalZone {
+    LocalZone::new(
+        "home.lan",
+        "home.lan.zone",
+        vec![
+            ZoneRecord::new(
+                "home.lan",
+                3600,
+                "SOA",
+                "ns1.home.lan. admin.home.lan. 100 7200 3600 1209600 300",
+            ),
+            ZoneRecord::new("home.lan", 3600, "NS", "ns1.home.lan."),
+            ZoneRecord::new("ns1.home.lan", 3600, "A", "192.168.1.1"),
+        ],
+    )
+}
+
+fn setup() -> (Arc<MockLocalZoneRepository>, Arc<MockLocalZoneStore>) {
+    (
+        Arc::new(MockLocalZoneRepository::with_zones(vec![home_zone()])),
+        Arc::new(MockLocalZoneStore::new()),
+    )
+}
+
+// ── GetLocalZonesUseCase ──────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_get_normalizes_origin() {
+    let (repo, _) = setup();
+    let zone = GetLocalZonesUseCase::new(repo)
+        .get("Home.LAN.")
+        .await
+        .unwrap();
+    assert_eq!(zone.records.len(), 3);
+}
+
+#[tokio::test]
+async fn test_get_unknown_zone() {
+    let (repo, _) = setup();
+    let result = GetLocalZonesUseCase::new(repo).get("corp.lan").await;
+    assert!(matches!(result, Err(DomainError::LocalZoneNotFound(_))));
+}
+
+// ── CreateZoneRecordUseCase ───────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_create_qualifies_name_bumps_serial_and_reloads() {
+    let (repo, store) = setup();
+
+    let (record, index) = CreateZoneRecordUseCase::new(repo.clone(), store.clone())
+        .execute("home.lan", "nas", None, "a", "192.168.1.10")
+        .await
+        .unwrap();
+
+    assert_eq!(record.name.as_ref(), "nas.home.lan");
+    assert_eq!(record.record_type.as_ref(), "A");
+    assert_eq!(record.ttl, 3600);
+    assert_eq!(index, 2);
+
+    let zone = repo.zone("home.lan").await.unwrap();
+    assert_eq!(zone.serial(), Some(101));
+    assert_eq!(zone.records[index], record);
+    assert_eq!(store.reload_count(), 1);
+    assert_eq!(store.record_count("home.lan"), 4);
+}
+
+#[tokio::test]
+async fn test_create_rejects_record_outside_zone() {
+    let (repo, store) = setup();
+
+    let result = CreateZoneRecordUseCase::new(repo.clone(), store.clone())
+        .execute("home.lan", "nas.example.com.", Some(60), "A", "192.0.2.1")
+        .await;
+
+    assert!(matches!(result, Err(DomainError::InvalidZoneRecord(_))));
+    assert_eq!(repo.save_count(), 0);
+    assert_eq!(store.reload_count(), 0);
+}
+
+#[tokio::test]
+async fn test_create_rejects_data_beside_cname() {
+    let (repo, store) = setup();
+    let create = CreateZoneRecordUseCase::new(repo.clone(), store.clone());
+    create
+        .execute("home.lan", "www", Some(60), "CNAME", "ns1.home.lan.")
+        .await
+        .unwrap();
+
+    let result = create
+        .execute("home.lan", "www", Some(60), "TXT", "\"hello\"")
+        .await;
+    assert!(matches!(result, Err(DomainError::InvalidZoneRecord(_))));
+}
+
+// ── UpdateZoneRecordUseCase ───────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_update_keeps_ttl_and_reports_new_index() {
+    let (repo, store) = setup();
+
+    let (record, index) = UpdateZoneRecordUseCase::new(repo.clone(), store.clone())
+        .execute("home.lan", 2, "a.ns1", None, "A", "192.168.1.2")
+        .await
+        .unwrap();
+
+    assert_eq!(record.name.as_ref(), "a.ns1.home.lan");
+    assert_eq!(record.ttl, 3600);
+    assert_eq!(index, 2);
+
+    let zone = repo.zone("home.lan").await.unwrap();
+    assert_eq!(zone.serial(), Some(101));
+    assert_eq!(store.reload_count(), 1);
+}
+
+#[tokio::test]
+async fn test_update_soa_keeps_submitted_serial() {
+    let (repo, store) = setup();
+
+    UpdateZoneRecordUseCase::new(repo.clone(), store)
+        .execute(
+            "home.lan",
+            0,
+            "@",
+            Some(3600),
+            "SOA",
+            "ns1.home.lan. admin.home.lan. 2026101701 7200 3600 1209600 300",
+        )
+        .await
+        .unwrap();
+
+    let zone = repo.zone("home.lan").await.unwrap();
+    assert_eq!(zone.serial(), Some(2026101701));
+}
+
+#[tokio::test]
+async fn test_update_unknown_index() {
+    let (repo, store) = setup();
+
+    let result = UpdateZoneRecordUseCase::new(repo, store)
+        .execute("home.lan", 9, "nas", None, "A", "192.168.1.10")
+        .await;
+
+    assert!(matches!(result, Err(DomainError::ZoneRecordNotFound(9))));
+}
+
+// ── DeleteZoneRecordUseCase ───────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_delete_removes_record_and_bumps_serial() {
+    let (repo, store) = setup();
+
+    let removed = DeleteZoneRecordUseCase::new(repo.clone(), store.clone())
+        .execute("home.lan", 2)
+        .await
+        .unwrap();
+
+    assert_eq!(removed.name.as_ref(), "ns1.home.lan");
+    let zone = repo.zone("home.lan").await.unwrap();
+    assert_eq!(zone.records.len(), 2);
+    assert_eq!(zone.serial(), Some(101));
+    assert_eq!(store.reload_count(), 1);
+}
+
+#[tokio::test]
+async fn test_delete_refuses_soa() {
+    let (repo, store) = setup();
+
+    let result = DeleteZoneRecordUseCase::new(repo.clone(), store.clone())
+        .execute("home.lan", 0)
+        .await;
+
+    assert!(matches!(result, Err(DomainError::InvalidZoneRecord(_))));
+    assert_eq!(repo.save_count(), 0);
+}
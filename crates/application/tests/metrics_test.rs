@@ -0,0 +1,152 @@
+use ferrous_dns_application::services::{ClientTransport, DnsMetrics, JobMetrics};
+use ferrous_dns_domain::{QueryLog, QuerySource, RecordType};
+use std::net::{IpAddr, Ipv4Addr};
+use std::sync::Arc;
+use std::time::Duration;
+
+fn query(status: &'static str, record_type: RecordType, elapsed_us: u64) -> QueryLog {
+    QueryLog {
+        id: None,
+        domain: Arc::from("example.com"),
+        record_type,
+        client_ip: IpAddr::V4(Ipv4Addr::new(192, 168, 1, 10)),
+        client_hostname: None,
+        blocked: false,
+        response_time_us: Some(elapsed_us),
+        cache_hit: false,
+        cache_refresh: false,
+        dnssec_status: None,
+        upstream_server: None,
+        upstream_pool: None,
+        response_status: Some(status),
+        timestamp: None,
+        query_source: QuerySource::Client,
+        group_id: Some(1),
+        block_source: None,
+        blocklist_source_ids: Vec::new(),
+    }
+}
+
+fn result_count(metrics: &DnsMetrics, status: &str) -> u64 {
+    metrics
+        .snapshot()
+        .results
+        .into_iter()
+        .find(|(s, _)| *s == status)
+        .map(|(_, count)| count)
+        .unwrap()
+}
+
+// ── DnsMetrics ─────────────────────────────────────────────────────────────
+
+#[test]
+fn test_counts_queries_by_result_and_record_type() {
+    let metrics = DnsMetrics::new();
+
+    metrics.record_query(&query("NOERROR", RecordType::A, 50));
+    metrics.record_query(&query("NOERROR", RecordType::A, 50));
+    metrics.record_query(&query("RATE_LIMITED", RecordType::MX, 50));
+
+    assert_eq!(result_count(&metrics, "NOERROR"), 2);
+    assert_eq!(result_count(&metrics, "RATE_LIMITED"), 1);
+    assert_eq!(
+        metrics.snapshot().record_types,
+        vec![(RecordType::A, 2), (RecordType::MX, 1)]
+    );
+}
+
+#[test]
+fn test_unknown_status_is_counted_as_other() {
+    let metrics = DnsMetrics::new();
+
+    metrics.record_query(&query("REFUSED", RecordType::A, 50));
+
+    assert_eq!(result_count(&metrics, "OTHER"), 1);
+}
+
+#[test]
+fn test_internal_queries_are_not_counted() {
+    let metrics = DnsMetrics::new();
+
+    metrics.record_query(&QueryLog {
+        query_source: QuerySource::Internal,
+        ..query("NOERROR", RecordType::A, 50)
+    });
+
+    assert_eq!(result_count(&metrics, "NOERROR"), 0);
+    assert_eq!(metrics.snapshot().latency_count, 0);
+}
+
+#[test]
+fn test_latency_histogram_is_cumulative() {
+    let metrics = DnsMetrics::new();
+
+    metrics.record_query(&query("NOERROR", RecordType::A, 100));
+    metrics.record_query(&query("NOERROR", RecordType::A, 800));
+    metrics.record_query(&query("NOERROR", RecordType::A, 10_000_000));
+
+    let snapshot = metrics.snapshot();
+    let bucket = |le: u64| {
+        snapshot
+            .latency_buckets
+            .iter()
+            .find(|(b, _)| *b == le)
+            .unwrap()
+            .1
+    };
+    assert_eq!(bucket(100), 1);
+    assert_eq!(bucket(500), 1);
+    assert_eq!(bucket(1_000), 2);
+    assert_eq!(bucket(2_500_000), 2);
+    assert_eq!(snapshot.latency_count, 3);
+    assert_eq!(snapshot.latency_sum_us, 10_000_900);
+}
+
+#[test]
+fn test_counts_received_queries_by_transport() {
+    let metrics = DnsMetrics::new();
+
+    metrics.record_received(ClientTransport::Udp, 32);
+    metrics.record_received(ClientTransport::Https, 1);
+
+    assert_eq!(
+        metrics.snapshot().transports,
+        vec![
+            (ClientTransport::Udp, 32),
+            (ClientTransport::Tcp, 0),
+            (ClientTransport::Tls, 0),
+            (ClientTransport::Https, 1),
+        ]
+    );
+}
+
+// ── JobMetrics ─────────────────────────────────────────────────────────────
+
+#[test]
+fn test_job_metrics_accumulate_runs() {
+    let metrics = JobMetrics::new();
+
+    metrics.record("wal_checkpoint", Duration::from_millis(30));
+    metrics.record("wal_checkpoint", Duration::from_millis(10));
+
+    let snapshot = metrics.snapshot();
+    assert_eq!(snapshot.len(), 1);
+    let (name, stats) = &snapshot[0];
+    assert_eq!(*name, "wal_checkpoint");
+    assert_eq!(stats.runs, 2);
+    assert_eq!(stats.total_duration, Duration::from_millis(40));
+    assert_eq!(stats.last_duration, Duration::from_millis(10));
+    assert!(stats.last_run_secs > 0);
+}
+
+#[test]
+fn test_job_run_guard_records_on_drop() {
+    let metrics = Arc::new(JobMetrics::new());
+
+    {
+        let _run = metrics.start("session_cleanup");
+        assert!(metrics.snapshot().is_empty());
+    }
+
+    assert_eq!(metrics.snapshot()[0].1.runs, 1);
+}
@@ -0,0 +1,215 @@
+use ferrous_dns_application::ports::NegativeTrustAnchorRepository;
+use ferrous_dns_application::use_cases::negative_trust_anchors::{
+    CleanupExpiredNegativeTrustAnchorsUseCase, CreateNegativeTrustAnchorUseCase,
+    DeleteNegativeTrustAnchorUseCase, GetNegativeTrustAnchorsUseCase,
+};
+use ferrous_dns_domain::DomainError;
+use std::sync::Arc;
+
+mod helpers;
+use helpers::{MockNegativeTrustAnchorRepository, MockNegativeTrustAnchorStore};
+
+fn create_use_case(
+    repo: &Arc<MockNegativeTrustAnchorRepository>,
+    store: &Arc<MockNegativeTrustAnchorStore>,
+) -> CreateNegativeTrustAnchorUseCase {
+    CreateNegativeTrustAnchorUseCase::new(repo.clone(), store.clone())
+}
+
+// ── CreateNegativeTrustAnchorUseCase ──────────────────────────────────────────
+
+#[tokio::test]
+async fn test_create_normalizes_domain_and_reloads_store() {
+    let repo = Arc::new(MockNegativeTrustAnchorRepository::new());
+    let store = Arc::new(MockNegativeTrustAnchorStore::new());
+
+    let anchor = create_use_case(&repo, &store)
+        .execute("Broken.Example.".to_string(), None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(anchor.domain.as_ref(), "broken.example");
+    assert!(anchor.expires_at.is_none());
+    assert_eq!(store.reload_count(), 1);
+    assert_eq!(store.domains(), vec!["broken.example".to_string()]);
+}
+
+#[tokio::test]
+async fn test_create_with_duration_sets_future_expiry() {
+    let repo = Arc::new(MockNegativeTrustAnchorRepository::new());
+    let store = Arc::new(MockNegativeTrustAnchorStore::new());
+    let before = chrono::Utc::now()
+        .format(ferrous_dns_domain::NTA_TIMESTAMP_FORMAT)
+        .to_string();
+
+    let anchor = create_use_case(&repo, &store)
+        .execute("broken.example".to_string(), Some(3600), None)
+        .await
+        .unwrap();
+
+    let expires_at = anchor.expires_at.clone().expect("expiry should be set");
+    assert!(expires_at > before);
+    assert!(!anchor.is_expired_at(&before));
+}
+
+#[tokio::test]
+async fn test_create_rejects_zero_duration() {
+    let repo = Arc::new(MockNegativeTrustAnchorRepository::new());
+    let store = Arc::new(MockNegativeTrustAnchorStore::new());
+
+    let result = create_use_case(&repo, &store)
+        .execute("broken.example".to_string(), Some(0), None)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(DomainError::InvalidNegativeTrustAnchor(_))
+    ));
+    assert_eq!(repo.count().await, 0);
+    assert_eq!(store.reload_count(), 0);
+}
+
+#[tokio::test]
+async fn test_create_rejects_root_and_invalid_names() {
+    let repo = Arc::new(MockNegativeTrustAnchorRepository::new());
+    let store = Arc::new(MockNegativeTrustAnchorStore::new());
+    let use_case = create_use_case(&repo, &store);
+
+    for domain in [".", "", "bad domain.example"] {
+        let result = use_case.execute(domain.to_string(), None, None).await;
+        assert!(
+            matches!(result, Err(DomainError::InvalidNegativeTrustAnchor(_))),
+            "{domain:?} should be rejected"
+        );
+    }
+    assert_eq!(repo.count().await, 0);
+}
+
+#[tokio::test]
+async fn test_create_rejects_duplicate_domain() {
+    let repo = Arc::new(MockNegativeTrustAnchorRepository::new());
+    let store = Arc::new(MockNegativeTrustAnchorStore::new());
+    let use_case = create_use_case(&repo, &store);
+
+    use_case
+        .execute("broken.example".to_string(), None, None)
+        .await
+        .unwrap();
+    let result = use_case
+        .execute("BROKEN.example".to_string(), None, None)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(DomainError::InvalidNegativeTrustAnchor(_))
+    ));
+    assert_eq!(repo.count().await, 1);
+}
+
+// ── GetNegativeTrustAnchorsUseCase ────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_get_returns_created_anchors() {
+    let repo = Arc::new(MockNegativeTrustAnchorRepository::new());
+    let store = Arc::new(MockNegativeTrustAnchorStore::new());
+    let created = create_use_case(&repo, &store)
+        .execute("broken.example".to_string(), None, Some("ticket 42".into()))
+        .await
+        .unwrap();
+
+    let get = GetNegativeTrustAnchorsUseCase::new(repo.clone());
+    assert_eq!(get.get_all().await.unwrap().len(), 1);
+
+    let fetched = get.get_by_id(created.id.unwrap()).await.unwrap().unwrap();
+    assert_eq!(fetched.comment.as_deref(), Some("ticket 42"));
+    assert!(get.get_by_id(999).await.unwrap().is_none());
+}
+
+// ── DeleteNegativeTrustAnchorUseCase ──────────────────────────────────────────
+
+#[tokio::test]
+async fn test_delete_removes_anchor_and_reloads_store() {
+    let repo = Arc::new(MockNegativeTrustAnchorRepository::new());
+    let store = Arc::new(MockNegativeTrustAnchorStore::new());
+    let created = create_use_case(&repo, &store)
+        .execute("broken.example".to_string(), None, None)
+        .await
+        .unwrap();
+
+    DeleteNegativeTrustAnchorUseCase::new(repo.clone(), store.clone())
+        .execute(created.id.unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(repo.count().await, 0);
+    assert_eq!(store.reload_count(), 2);
+    assert!(store.domains().is_empty());
+}
+
+#[tokio::test]
+async fn test_delete_missing_anchor_returns_not_found() {
+    let repo = Arc::new(MockNegativeTrustAnchorRepository::new());
+    let store = Arc::new(MockNegativeTrustAnchorStore::new());
+
+    let result = DeleteNegativeTrustAnchorUseCase::new(repo, store.clone())
+        .execute(7)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(DomainError::NegativeTrustAnchorNotFound(7))
+    ));
+    assert_eq!(store.reload_count(), 0);
+}
+
+// ── CleanupExpiredNegativeTrustAnchorsUseCase ─────────────────────────────────
+
+#[tokio::test]
+async fn test_cleanup_removes_only_expired_anchors() {
+    let repo = Arc::new(MockNegativeTrustAnchorRepository::new());
+    let store = Arc::new(MockNegativeTrustAnchorStore::new());
+    repo.create(
+        "old.example".to_string(),
+        Some("2026-01-01 00:00:00".to_string()),
+        None,
+    )
+    .await
+    .unwrap();
+    repo.create(
+        "later.example".to_string(),
+        Some("2026-06-01 00:00:00".to_string()),
+        None,
+    )
+    .await
+    .unwrap();
+    repo.create("forever.example".to_string(), None, None)
+        .await
+        .unwrap();
+
+    let cleanup = CleanupExpiredNegativeTrustAnchorsUseCase::new(repo.clone(), store.clone());
+    let deleted = cleanup.execute_at("2026-03-01 00:00:00").await.unwrap();
+
+    assert_eq!(deleted, 1);
+    assert_eq!(repo.count().await, 2);
+    assert_eq!(store.reload_count(), 1);
+    let mut domains = store.domains();
+    domains.sort();
+    assert_eq!(domains, vec!["forever.example", "later.example"]);
+}
+
+#[tokio::test]
+async fn test_cleanup_without_expired_anchors_skips_reload() {
+    let repo = Arc::new(MockNegativeTrustAnchorRepository::new());
+    let store = Arc::new(MockNegativeTrustAnchorStore::new());
+    repo.create("forever.example".to_string(), None, None)
+        .await
+        .unwrap();
+
+    let deleted = CleanupExpiredNegativeTrustAnchorsUseCase::new(repo, store.clone())
+        .execute_at("2026-03-01 00:00:00")
+        .await
+        .unwrap();
+
+    assert_eq!(deleted, 0);
+    assert_eq!(store.reload_count(), 0);
+}
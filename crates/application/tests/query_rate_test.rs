@@ -47,6 +47,7 @@ async fn test_get_query_rate_with_data() {
             query_source: QuerySource::Client,
             group_id: None,
             block_source: None,
+            blocklist_source_ids: Vec::new(),
         };
         let _ = repository_mock.log_query(&query).await;
     }
@@ -86,6 +87,7 @@ async fn test_get_query_rate_formatted_with_k() {
             query_source: QuerySource::Client,
             group_id: None,
             block_source: None,
+            blocklist_source_ids: Vec::new(),
         };
         let _ = repository_mock.log_query(&query).await;
     }
@@ -125,6 +127,7 @@ async fn test_get_query_rate_different_units() {
             query_source: QuerySource::Client,
             group_id: None,
             block_source: None,
+            blocklist_source_ids: Vec::new(),
         };
         let _ = repository_mock.log_query(&query).await;
     }
@@ -38,6 +38,7 @@ fn make_log_with_upstream(
         query_source: QuerySource::Client,
         group_id: None,
         block_source,
+        blocklist_source_ids: Vec::new(),
     }
 }
 
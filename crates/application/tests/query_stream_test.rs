@@ -0,0 +1,94 @@
+use ferrous_dns_application::services::{QueryStream, QueryStreamFilter};
+use ferrous_dns_domain::{QueryLog, QuerySource, RecordType};
+use std::net::{IpAddr, Ipv4Addr};
+use std::sync::Arc;
+use tokio::sync::broadcast::error::{RecvError, TryRecvError};
+
+fn entry(domain: &str, client: u8, blocked: bool) -> QueryLog {
+    QueryLog {
+        id: None,
+        domain: Arc::from(domain),
+        record_type: RecordType::A,
+        client_ip: IpAddr::V4(Ipv4Addr::new(192, 168, 1, client)),
+        client_hostname: None,
+        blocked,
+        response_time_us: Some(250),
+        cache_hit: false,
+        cache_refresh: false,
+        dnssec_status: None,
+        upstream_server: None,
+        upstream_pool: None,
+        response_status: Some(if blocked { "BLOCKED" } else { "NOERROR" }),
+        timestamp: None,
+        query_source: QuerySource::Client,
+        group_id: Some(1),
+        block_source: None,
+        blocklist_source_ids: Vec::new(),
+    }
+}
+
+// ── publish / subscribe ─────────────────────────────────────────────────────
+
+#[test]
+fn test_publish_without_subscribers_is_a_no_op() {
+    let stream = QueryStream::default();
+    stream.publish(&entry("example.com", 10, false));
+    assert_eq!(stream.subscriber_count(), 0);
+
+    let mut receiver = stream.subscribe();
+    assert!(matches!(receiver.try_recv(), Err(TryRecvError::Empty)));
+}
+
+#[test]
+fn test_subscribers_receive_published_entries_with_timestamp() {
+    let stream = QueryStream::default();
+    let mut first = stream.subscribe();
+    let mut second = stream.subscribe();
+    assert_eq!(stream.subscriber_count(), 2);
+
+    stream.publish(&entry("example.com", 10, false));
+
+    let received = first.try_recv().unwrap();
+    assert_eq!(received.domain.as_ref(), "example.com");
+    assert!(received.timestamp.is_some());
+    assert_eq!(second.try_recv().unwrap().domain.as_ref(), "example.com");
+}
+
+#[tokio::test]
+async fn test_slow_subscriber_lags_instead_of_blocking_publisher() {
+    let stream = QueryStream::new(2);
+    let mut receiver = stream.subscribe();
+
+    for i in 0..5 {
+        stream.publish(&entry(&format!("host{i}.example.com"), 10, false));
+    }
+
+    assert!(matches!(receiver.recv().await, Err(RecvError::Lagged(3))));
+    assert_eq!(
+        receiver.recv().await.unwrap().domain.as_ref(),
+        "host3.example.com"
+    );
+}
+
+// ── filter ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_default_filter_matches_everything() {
+    let filter = QueryStreamFilter::default();
+    assert!(filter.matches(&entry("example.com", 10, false)));
+    assert!(filter.matches(&entry("ads.example.com", 11, true)));
+}
+
+#[test]
+fn test_filter_by_client_domain_and_blocked() {
+    let filter = QueryStreamFilter {
+        client: Some(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 10))),
+        domain: Some("ads".to_string()),
+        blocked_only: true,
+    };
+
+    assert!(filter.matches(&entry("Ads.Example.com", 10, true)));
+    assert!(!filter.matches(&entry("ads.example.com", 11, true)));
+    assert!(!filter.matches(&entry("www.example.com", 10, true)));
+    assert!(!filter.matches(&entry("ads.example.com", 10, false)));
+}
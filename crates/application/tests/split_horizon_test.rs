@@ -0,0 +1,247 @@
+mod helpers;
+
+use ferrous_dns_application::ports::DnsResolution;
+use ferrous_dns_application::use_cases::HandleDnsQueryUseCase;
+use ferrous_dns_domain::{
+    DnsConfig, DnsRequest, DnsViewConfig, DomainError, LocalDnsRecord, QueryScope, RecordType,
+};
+use helpers::{MockBlockFilterEngine, MockDnsResolver, MockQueryLogRepository};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+const OFFICE_CLIENT: &str = "10.1.2.3";
+const OUTSIDE_CLIENT: &str = "203.0.113.7";
+const PUBLIC_IP: &str = "198.51.100.5";
+
+fn record(hostname: &str, ip: &str, record_type: &str) -> LocalDnsRecord {
+    LocalDnsRecord {
+        hostname: hostname.to_string(),
+        domain: Some("example.com".to_string()),
+        ip: ip.to_string(),
+        record_type: record_type.to_string(),
+        ttl: Some(120),
+    }
+}
+
+fn view(name: &str, subnets: &[&str], groups: &[i64]) -> DnsViewConfig {
+    DnsViewConfig {
+        name: name.to_string(),
+        subnets: subnets.iter().map(|s| s.to_string()).collect(),
+        groups: groups.to_vec(),
+        local_records: Vec::new(),
+        local_zones: Vec::new(),
+        rebinding_allowlist: Vec::new(),
+        upstream_pools: Vec::new(),
+    }
+}
+
+fn office_view() -> DnsViewConfig {
+    DnsViewConfig {
+        local_records: vec![record("git", "10.0.0.5", "A")],
+        ..view("office", &["10.0.0.0/8"], &[])
+    }
+}
+
+fn dns_config(views: Vec<DnsViewConfig>) -> DnsConfig {
+    DnsConfig {
+        views,
+        ..DnsConfig::default()
+    }
+}
+
+async fn make_handler(
+    dns: &DnsConfig,
+) -> (
+    HandleDnsQueryUseCase,
+    Arc<MockDnsResolver>,
+    Arc<MockQueryLogRepository>,
+) {
+    let resolver = Arc::new(MockDnsResolver::new());
+    resolver
+        .set_response(
+            "git.example.com",
+            DnsResolution::new(vec![PUBLIC_IP.parse().unwrap()], false),
+        )
+        .await;
+    let query_log = Arc::new(MockQueryLogRepository::new());
+    let handler = HandleDnsQueryUseCase::new(
+        resolver.clone(),
+        Arc::new(MockBlockFilterEngine::new()),
+        query_log.clone(),
+    )
+    .with_rebinding_protection(
+        dns.rebinding_protection_enabled,
+        dns.local_domain.as_deref(),
+        &dns.rebinding_allowlist,
+    )
+    .with_split_horizon(dns);
+    (handler, resolver, query_log)
+}
+
+fn request(domain: &str, record_type: RecordType, client: &str) -> DnsRequest {
+    DnsRequest::new(domain, record_type, client.parse().unwrap())
+}
+
+#[tokio::test]
+async fn test_view_client_gets_view_record_without_upstream() {
+    let (handler, resolver, query_log) = make_handler(&dns_config(vec![office_view()])).await;
+
+    let resolution = handler
+        .execute(&request("Git.Example.com", RecordType::A, OFFICE_CLIENT))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        *resolution.addresses,
+        vec!["10.0.0.5".parse::<IpAddr>().unwrap()]
+    );
+    assert!(resolution.local_dns);
+    assert_eq!(resolution.min_ttl, Some(120));
+    assert!(resolver.query_scopes().is_empty());
+    let logs = query_log.get_sync_logs();
+    assert_eq!(logs.last().unwrap().response_status, Some("LOCAL_DNS"));
+}
+
+#[tokio::test]
+async fn test_outside_client_gets_public_answer() {
+    let (handler, resolver, _) = make_handler(&dns_config(vec![office_view()])).await;
+
+    let resolution = handler
+        .execute(&request("git.example.com", RecordType::A, OUTSIDE_CLIENT))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        *resolution.addresses,
+        vec![PUBLIC_IP.parse::<IpAddr>().unwrap()]
+    );
+    assert!(resolver.query_scopes().iter().all(QueryScope::is_shared));
+}
+
+#[tokio::test]
+async fn test_other_record_type_of_view_name_answers_nodata() {
+    let (handler, resolver, _) = make_handler(&dns_config(vec![office_view()])).await;
+
+    let resolution = handler
+        .execute(&request("git.example.com", RecordType::AAAA, OFFICE_CLIENT))
+        .await
+        .unwrap();
+
+    assert!(resolution.addresses.is_empty());
+    assert!(resolution.local_dns);
+    assert!(resolver.query_scopes().is_empty());
+}
+
+#[tokio::test]
+async fn test_other_names_resolve_in_view_scope() {
+    let (handler, resolver, _) = make_handler(&dns_config(vec![office_view()])).await;
+    resolver
+        .set_response(
+            "example.org",
+            DnsResolution::new(vec![PUBLIC_IP.parse().unwrap()], false),
+        )
+        .await;
+
+    handler
+        .execute(&request("example.org", RecordType::A, OFFICE_CLIENT))
+        .await
+        .unwrap();
+
+    let scopes = resolver.query_scopes();
+    assert!(!scopes.is_empty());
+    assert!(scopes.iter().all(|s| s.view == Some(0)), "{scopes:?}");
+}
+
+#[tokio::test]
+async fn test_view_matched_by_group() {
+    // MockBlockFilterEngine resolves every client to group 1.
+    let dns = dns_config(vec![
+        view("lan", &["192.168.0.0/16"], &[]),
+        DnsViewConfig {
+            local_records: vec![record("git", "10.0.0.9", "A")],
+            ..view("vpn", &[], &[1])
+        },
+    ]);
+    let (handler, _, _) = make_handler(&dns).await;
+
+    let resolution = handler
+        .execute(&request("git.example.com", RecordType::A, OUTSIDE_CLIENT))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        *resolution.addresses,
+        vec!["10.0.0.9".parse::<IpAddr>().unwrap()]
+    );
+}
+
+#[tokio::test]
+async fn test_first_matching_view_wins() {
+    let dns = dns_config(vec![
+        office_view(),
+        DnsViewConfig {
+            local_records: vec![record("git", "10.0.0.9", "A")],
+            ..view("everyone", &["0.0.0.0/0"], &[])
+        },
+    ]);
+    let (handler, _, _) = make_handler(&dns).await;
+
+    let resolution = handler
+        .execute(&request("git.example.com", RecordType::A, OFFICE_CLIENT))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        *resolution.addresses,
+        vec!["10.0.0.5".parse::<IpAddr>().unwrap()]
+    );
+}
+
+#[tokio::test]
+async fn test_fast_path_defers_view_names_and_scopes_cache_lookups() {
+    let (handler, resolver, _) = make_handler(&dns_config(vec![office_view()])).await;
+    resolver.set_cached_response(
+        "git.example.com",
+        DnsResolution::new(vec![PUBLIC_IP.parse().unwrap()], true),
+    );
+
+    let client: IpAddr = OFFICE_CLIENT.parse().unwrap();
+    assert!(handler
+        .try_cache_direct("git.example.com", RecordType::A, client)
+        .is_none());
+    assert!(resolver.query_scopes().is_empty());
+
+    let _ = handler.try_cache_direct("example.org", RecordType::A, client);
+    assert_eq!(
+        resolver.query_scopes(),
+        vec![QueryScope {
+            view: Some(0),
+            group: None,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn test_view_rebinding_allowlist_applies_only_inside_view() {
+    let dns = dns_config(vec![DnsViewConfig {
+        rebinding_allowlist: vec!["nas.example.com".to_string()],
+        ..view("office", &["10.0.0.0/8"], &[])
+    }]);
+    let (handler, resolver, _) = make_handler(&dns).await;
+    resolver
+        .set_response(
+            "nas.example.com",
+            DnsResolution::new(vec!["192.168.1.20".parse().unwrap()], false),
+        )
+        .await;
+
+    let inside = handler
+        .execute(&request("nas.example.com", RecordType::A, OFFICE_CLIENT))
+        .await;
+    assert!(inside.is_ok());
+
+    let outside = handler
+        .execute(&request("nas.example.com", RecordType::A, OUTSIDE_CLIENT))
+        .await;
+    assert!(matches!(outside, Err(DomainError::Blocked)));
+}
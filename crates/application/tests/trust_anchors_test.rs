@@ -0,0 +1,306 @@
+use ferrous_dns_application::ports::ProbedKey;
+use ferrous_dns_application::use_cases::trust_anchors::{
+    GetTrustAnchorsUseCase, LoadTrustAnchorsUseCase, RefreshTrustAnchorsUseCase,
+};
+use ferrous_dns_domain::{
+    TrustAnchor, TrustAnchorData, TrustAnchorSource, TrustAnchorState, DNSKEY_FLAG_REVOKE,
+};
+use std::sync::Arc;
+
+mod helpers;
+use helpers::{MockTrustAnchorProbe, MockTrustAnchorRepository, MockTrustAnchorStore};
+
+const DAY: i64 = 86_400;
+
+fn ksk(id: u8) -> TrustAnchorData {
+    TrustAnchorData::Dnskey {
+        flags: 257,
+        algorithm: 8,
+        public_key: Arc::from(&[id; 8][..]),
+    }
+}
+
+fn revoked(id: u8) -> TrustAnchorData {
+    TrustAnchorData::Dnskey {
+        flags: 257 | DNSKEY_FLAG_REVOKE,
+        algorithm: 8,
+        public_key: Arc::from(&[id; 8][..]),
+    }
+}
+
+fn zsk(id: u8) -> TrustAnchorData {
+    TrustAnchorData::Dnskey {
+        flags: 256,
+        algorithm: 8,
+        public_key: Arc::from(&[id; 8][..]),
+    }
+}
+
+fn ds_of(id: u8) -> TrustAnchorData {
+    TrustAnchorData::Ds {
+        key_tag: 1,
+        algorithm: 8,
+        digest_type: 2,
+        digest: Arc::from(&[id; 8][..]),
+    }
+}
+
+fn probed(data: TrustAnchorData, signs_rrset: bool) -> ProbedKey {
+    ProbedKey { data, signs_rrset }
+}
+
+fn root_anchor(data: TrustAnchorData) -> TrustAnchor {
+    TrustAnchor::new(".", data, TrustAnchorSource::Builtin, true, 0)
+}
+
+struct Fixture {
+    repo: Arc<MockTrustAnchorRepository>,
+    store: Arc<MockTrustAnchorStore>,
+    probe: Arc<MockTrustAnchorProbe>,
+}
+
+impl Fixture {
+    async fn new(anchors: Vec<TrustAnchor>) -> Self {
+        Self {
+            repo: Arc::new(MockTrustAnchorRepository::with_anchors(anchors).await),
+            store: Arc::new(MockTrustAnchorStore::new()),
+            probe: Arc::new(MockTrustAnchorProbe::new()),
+        }
+    }
+
+    fn refresh(&self) -> RefreshTrustAnchorsUseCase {
+        RefreshTrustAnchorsUseCase::new(self.repo.clone(), self.store.clone(), self.probe.clone())
+            .with_hold_down_days(30)
+    }
+
+    async fn state_of(&self, data: &TrustAnchorData) -> Option<TrustAnchorState> {
+        self.repo
+            .anchors()
+            .await
+            .into_iter()
+            .find(|a| a.data.same_key(data))
+            .map(|a| a.state)
+    }
+}
+
+// ── LoadTrustAnchorsUseCase ───────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_load_inserts_configured_anchors_and_reloads() {
+    let f = Fixture::new(vec![]).await;
+    LoadTrustAnchorsUseCase::new(f.repo.clone(), f.store.clone())
+        .execute(&[root_anchor(ksk(1))])
+        .await
+        .unwrap();
+
+    assert_eq!(f.repo.anchors().await.len(), 1);
+    assert_eq!(f.store.trusted_count(), 1);
+    assert_eq!(f.store.reload_count(), 1);
+}
+
+#[tokio::test]
+async fn test_load_keeps_persisted_state() {
+    let mut revoked_anchor = root_anchor(ksk(1));
+    revoked_anchor.state = TrustAnchorState::Revoked;
+    let f = Fixture::new(vec![revoked_anchor]).await;
+
+    LoadTrustAnchorsUseCase::new(f.repo.clone(), f.store.clone())
+        .execute(&[root_anchor(ksk(1))])
+        .await
+        .unwrap();
+
+    assert_eq!(f.repo.anchors().await.len(), 1);
+    assert_eq!(f.state_of(&ksk(1)).await, Some(TrustAnchorState::Revoked));
+    assert_eq!(f.store.trusted_count(), 0);
+}
+
+#[tokio::test]
+async fn test_load_drops_static_anchors_no_longer_configured() {
+    let configured = TrustAnchor::new(
+        "corp.internal",
+        ds_of(9),
+        TrustAnchorSource::Config,
+        false,
+        0,
+    );
+    let f = Fixture::new(vec![configured, root_anchor(ksk(1))]).await;
+
+    LoadTrustAnchorsUseCase::new(f.repo.clone(), f.store.clone())
+        .execute(&[root_anchor(ksk(1))])
+        .await
+        .unwrap();
+
+    let anchors = f.repo.anchors().await;
+    assert_eq!(anchors.len(), 1);
+    assert_eq!(anchors[0].zone.as_ref(), ".");
+}
+
+#[tokio::test]
+async fn test_load_drops_builtin_root_replaced_by_file() {
+    let mut learned = root_anchor(ksk(2));
+    learned.source = TrustAnchorSource::Rfc5011;
+    let mut orphan = TrustAnchor::new("corp.internal", ksk(3), TrustAnchorSource::Rfc5011, true, 0);
+    orphan.state = TrustAnchorState::AddPending;
+    let f = Fixture::new(vec![root_anchor(ksk(1)), learned, orphan]).await;
+
+    let file_root = TrustAnchor::new(".", ksk(4), TrustAnchorSource::File, true, 0);
+    LoadTrustAnchorsUseCase::new(f.repo.clone(), f.store.clone())
+        .execute(&[file_root])
+        .await
+        .unwrap();
+
+    assert_eq!(f.state_of(&ksk(1)).await, None);
+    assert_eq!(f.state_of(&ksk(2)).await, Some(TrustAnchorState::Valid));
+    assert_eq!(f.state_of(&ksk(3)).await, None);
+    assert_eq!(f.state_of(&ksk(4)).await, Some(TrustAnchorState::Valid));
+}
+
+#[tokio::test]
+async fn test_get_all_lists_anchors() {
+    let f = Fixture::new(vec![root_anchor(ksk(1))]).await;
+    let anchors = GetTrustAnchorsUseCase::new(f.repo.clone())
+        .get_all()
+        .await
+        .unwrap();
+    assert_eq!(anchors.len(), 1);
+}
+
+// ── RefreshTrustAnchorsUseCase ────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_new_key_waits_out_add_hold_down() {
+    let f = Fixture::new(vec![root_anchor(ksk(1))]).await;
+    f.probe.set_keys(
+        ".",
+        vec![
+            probed(ksk(1), true),
+            probed(ksk(2), false),
+            probed(zsk(3), false),
+        ],
+    );
+
+    assert_eq!(f.refresh().execute_at(1_000).await.unwrap(), 1);
+    assert_eq!(
+        f.state_of(&ksk(2)).await,
+        Some(TrustAnchorState::AddPending)
+    );
+    assert_eq!(f.state_of(&zsk(3)).await, None);
+    assert_eq!(f.store.trusted_count(), 1);
+
+    f.refresh().execute_at(1_000 + 29 * DAY).await.unwrap();
+    assert_eq!(
+        f.state_of(&ksk(2)).await,
+        Some(TrustAnchorState::AddPending)
+    );
+
+    f.refresh().execute_at(1_000 + 30 * DAY).await.unwrap();
+    assert_eq!(f.state_of(&ksk(2)).await, Some(TrustAnchorState::Valid));
+    assert_eq!(f.store.trusted_count(), 2);
+}
+
+#[tokio::test]
+async fn test_pending_key_withdrawn_during_hold_down_is_forgotten() {
+    let f = Fixture::new(vec![root_anchor(ksk(1))]).await;
+    f.probe
+        .set_keys(".", vec![probed(ksk(1), true), probed(ksk(2), false)]);
+    f.refresh().execute_at(0).await.unwrap();
+
+    f.probe.set_keys(".", vec![probed(ksk(1), true)]);
+    f.refresh().execute_at(DAY).await.unwrap();
+
+    assert_eq!(f.state_of(&ksk(2)).await, None);
+}
+
+#[tokio::test]
+async fn test_unauthenticated_rrset_changes_nothing() {
+    let f = Fixture::new(vec![root_anchor(ksk(1))]).await;
+    f.probe
+        .set_keys(".", vec![probed(ksk(1), false), probed(ksk(2), true)]);
+
+    assert_eq!(f.refresh().execute_at(0).await.unwrap(), 0);
+    assert_eq!(f.repo.anchors().await.len(), 1);
+    assert_eq!(f.store.reload_count(), 0);
+}
+
+#[tokio::test]
+async fn test_self_signed_revocation_revokes_then_removes() {
+    let mut standby = root_anchor(ksk(2));
+    standby.source = TrustAnchorSource::Rfc5011;
+    let f = Fixture::new(vec![root_anchor(ksk(1)), standby]).await;
+    f.probe
+        .set_keys(".", vec![probed(revoked(1), true), probed(ksk(2), true)]);
+
+    f.refresh().execute_at(0).await.unwrap();
+    assert_eq!(f.state_of(&ksk(1)).await, Some(TrustAnchorState::Revoked));
+    assert_eq!(f.store.trusted_count(), 1);
+
+    f.probe.set_keys(".", vec![probed(ksk(2), true)]);
+    f.refresh().execute_at(30 * DAY).await.unwrap();
+    assert_eq!(f.state_of(&ksk(1)).await, Some(TrustAnchorState::Removed));
+}
+
+#[tokio::test]
+async fn test_revocation_without_self_signature_is_ignored() {
+    let f = Fixture::new(vec![root_anchor(ksk(1)), root_anchor(ksk(2))]).await;
+    f.probe
+        .set_keys(".", vec![probed(revoked(1), false), probed(ksk(2), true)]);
+
+    f.refresh().execute_at(0).await.unwrap();
+    assert_eq!(f.state_of(&ksk(1)).await, Some(TrustAnchorState::Valid));
+}
+
+#[tokio::test]
+async fn test_absent_valid_key_goes_missing_and_returns() {
+    let f = Fixture::new(vec![root_anchor(ksk(1)), root_anchor(ksk(2))]).await;
+    f.probe.set_keys(".", vec![probed(ksk(1), true)]);
+
+    f.refresh().execute_at(0).await.unwrap();
+    assert_eq!(f.state_of(&ksk(2)).await, Some(TrustAnchorState::Missing));
+    assert_eq!(f.store.trusted_count(), 2);
+
+    f.probe
+        .set_keys(".", vec![probed(ksk(1), true), probed(ksk(2), false)]);
+    f.refresh().execute_at(DAY).await.unwrap();
+    assert_eq!(f.state_of(&ksk(2)).await, Some(TrustAnchorState::Valid));
+}
+
+#[tokio::test]
+async fn test_ds_anchor_is_replaced_by_its_dnskey() {
+    let ds = TrustAnchor::new("corp.internal", ds_of(5), TrustAnchorSource::File, true, 0);
+    let f = Fixture::new(vec![ds]).await;
+    f.probe
+        .set_keys("corp.internal.", vec![probed(ksk(5), true)]);
+
+    f.refresh().execute_at(0).await.unwrap();
+
+    assert_eq!(f.state_of(&ds_of(5)).await, Some(TrustAnchorState::Removed));
+    let key = f
+        .repo
+        .anchors()
+        .await
+        .into_iter()
+        .find(|a| a.data.same_key(&ksk(5)))
+        .unwrap();
+    assert_eq!(key.state, TrustAnchorState::Valid);
+    assert_eq!(key.source, TrustAnchorSource::Rfc5011);
+    assert!(key.managed);
+}
+
+#[tokio::test]
+async fn test_static_anchors_are_not_tracked() {
+    let static_anchor =
+        TrustAnchor::new("corp.internal", ksk(7), TrustAnchorSource::Config, false, 0);
+    let f = Fixture::new(vec![static_anchor]).await;
+    f.probe
+        .set_keys("corp.internal.", vec![probed(ksk(8), true)]);
+
+    assert_eq!(f.refresh().execute_at(0).await.unwrap(), 0);
+    assert_eq!(f.state_of(&ksk(7)).await, Some(TrustAnchorState::Valid));
+}
+
+#[tokio::test]
+async fn test_probe_failure_skips_zone() {
+    let f = Fixture::new(vec![root_anchor(ksk(1))]).await;
+    assert_eq!(f.refresh().execute_at(0).await.unwrap(), 0);
+    assert_eq!(f.state_of(&ksk(1)).await, Some(TrustAnchorState::Valid));
+}
@@ -0,0 +1,181 @@
+use base64::Engine;
+use ferrous_dns_application::use_cases::tsig_keys::{
+    CreateTsigKeyUseCase, DeleteTsigKeyUseCase, GetTsigKeysUseCase,
+};
+use ferrous_dns_domain::DomainError;
+use std::sync::Arc;
+
+mod helpers;
+use helpers::{MockTsigKeyRepository, MockTsigKeyStore};
+
+const SECRET: &str = "c2VjcmV0LXNlY3JldC1zZWNyZXQtc2VjcmV0";
+
+fn create_use_case(
+    repo: &Arc<MockTsigKeyRepository>,
+    store: &Arc<MockTsigKeyStore>,
+) -> CreateTsigKeyUseCase {
+    CreateTsigKeyUseCase::new(repo.clone(), store.clone())
+}
+
+// ── CreateTsigKeyUseCase ───────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_create_normalizes_name_and_reloads_store() {
+    let repo = Arc::new(MockTsigKeyRepository::new());
+    let store = Arc::new(MockTsigKeyStore::new());
+
+    let key = create_use_case(&repo, &store)
+        .execute(
+            "Kea-DHCP.Example.".to_string(),
+            Some("HMAC-SHA512".to_string()),
+            Some(SECRET.to_string()),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(key.name.as_ref(), "kea-dhcp.example");
+    assert_eq!(key.algorithm.as_ref(), "hmac-sha512");
+    assert_eq!(key.secret.as_ref(), SECRET);
+    assert_eq!(store.names(), vec!["kea-dhcp.example".to_string()]);
+    assert_eq!(store.reload_count(), 1);
+}
+
+#[tokio::test]
+async fn test_create_defaults_to_sha256_and_generates_secret() {
+    let repo = Arc::new(MockTsigKeyRepository::new());
+    let store = Arc::new(MockTsigKeyStore::new());
+
+    let key = create_use_case(&repo, &store)
+        .execute("external-dns".to_string(), None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(key.algorithm.as_ref(), "hmac-sha256");
+    let decoded = base64::engine::general_purpose::STANDARD
+        .decode(key.secret.as_ref())
+        .unwrap();
+    assert_eq!(decoded.len(), 32);
+}
+
+#[tokio::test]
+async fn test_create_rejects_unsupported_algorithm() {
+    let repo = Arc::new(MockTsigKeyRepository::new());
+    let store = Arc::new(MockTsigKeyStore::new());
+
+    let result = create_use_case(&repo, &store)
+        .execute("kea".to_string(), Some("hmac-md5".to_string()), None)
+        .await;
+
+    assert!(matches!(result, Err(DomainError::InvalidTsigKey(_))));
+    assert_eq!(repo.count().await, 0);
+    assert_eq!(store.reload_count(), 0);
+}
+
+#[tokio::test]
+async fn test_create_rejects_non_base64_secret() {
+    let repo = Arc::new(MockTsigKeyRepository::new());
+    let store = Arc::new(MockTsigKeyStore::new());
+
+    let result = create_use_case(&repo, &store)
+        .execute("kea".to_string(), None, Some("not base64!".to_string()))
+        .await;
+
+    assert!(matches!(result, Err(DomainError::InvalidTsigKey(_))));
+}
+
+#[tokio::test]
+async fn test_create_rejects_short_secret() {
+    let repo = Arc::new(MockTsigKeyRepository::new());
+    let store = Arc::new(MockTsigKeyStore::new());
+
+    let result = create_use_case(&repo, &store)
+        .execute("kea".to_string(), None, Some("c2hvcnQ=".to_string()))
+        .await;
+
+    assert!(matches!(result, Err(DomainError::InvalidTsigKey(_))));
+}
+
+#[tokio::test]
+async fn test_create_rejects_invalid_name() {
+    let repo = Arc::new(MockTsigKeyRepository::new());
+    let store = Arc::new(MockTsigKeyStore::new());
+
+    let result = create_use_case(&repo, &store)
+        .execute("bad name!".to_string(), None, None)
+        .await;
+
+    assert!(matches!(result, Err(DomainError::InvalidTsigKey(_))));
+}
+
+#[tokio::test]
+async fn test_create_duplicate_name_fails() {
+    let repo = Arc::new(MockTsigKeyRepository::new());
+    let store = Arc::new(MockTsigKeyStore::new());
+    let use_case = create_use_case(&repo, &store);
+
+    use_case
+        .execute("kea".to_string(), None, None)
+        .await
+        .unwrap();
+    let result = use_case.execute("KEA".to_string(), None, None).await;
+
+    assert!(matches!(result, Err(DomainError::InvalidTsigKey(_))));
+    assert_eq!(repo.count().await, 1);
+}
+
+// ── DeleteTsigKeyUseCase ───────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_delete_removes_key_and_reloads_store() {
+    let repo = Arc::new(MockTsigKeyRepository::new());
+    let store = Arc::new(MockTsigKeyStore::new());
+    let key = create_use_case(&repo, &store)
+        .execute("kea".to_string(), None, None)
+        .await
+        .unwrap();
+
+    DeleteTsigKeyUseCase::new(repo.clone(), store.clone())
+        .execute(key.id.unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(repo.count().await, 0);
+    assert!(store.names().is_empty());
+    assert_eq!(store.reload_count(), 2);
+}
+
+#[tokio::test]
+async fn test_delete_unknown_key_returns_not_found() {
+    let repo = Arc::new(MockTsigKeyRepository::new());
+    let store = Arc::new(MockTsigKeyStore::new());
+
+    let result = DeleteTsigKeyUseCase::new(repo, store.clone())
+        .execute(42)
+        .await;
+
+    assert!(matches!(result, Err(DomainError::TsigKeyNotFound(42))));
+    assert_eq!(store.reload_count(), 0);
+}
+
+// ── GetTsigKeysUseCase ─────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_get_returns_all_and_by_id() {
+    let repo = Arc::new(MockTsigKeyRepository::new());
+    let store = Arc::new(MockTsigKeyStore::new());
+    let use_case = create_use_case(&repo, &store);
+    let first = use_case
+        .execute("kea".to_string(), None, None)
+        .await
+        .unwrap();
+    use_case
+        .execute("external-dns".to_string(), None, None)
+        .await
+        .unwrap();
+
+    let get = GetTsigKeysUseCase::new(repo);
+    assert_eq!(get.get_all().await.unwrap().len(), 2);
+    let found = get.get_by_id(first.id.unwrap()).await.unwrap().unwrap();
+    assert_eq!(found.name.as_ref(), "kea");
+    assert!(get.get_by_id(99).await.unwrap().is_none());
+}
@@ -1,9 +1,10 @@
 use ferrous_dns_application::ports::CacheMaintenancePort;
 use ferrous_dns_domain::Config;
 use ferrous_dns_jobs::{
-    BlocklistSyncJob, CacheMaintenanceJob, ClientSyncJob, DgaEvictionJob, JobRunner,
-    NxdomainHijackEvictionJob, QueryLogRetentionJob, ResponseIpFilterEvictionJob, RetentionJob,
-    ScheduleEvaluatorJob, SessionCleanupJob, TunnelingEvictionJob, WalCheckpointJob,
+    AcmeRenewalJob, BlocklistSyncJob, CacheMaintenanceJob, ClientSyncJob, DgaEvictionJob,
+    JobRunner, NegativeTrustAnchorCleanupJob, NxdomainHijackEvictionJob, QueryLogRetentionJob,
+    ResponseIpFilterEvictionJob, RetentionJob, ScheduleEvaluatorJob, SessionCleanupJob,
+    TlsCertificateWatchJob, TrustAnchorRefreshJob, TunnelingEvictionJob, WalCheckpointJob,
 };
 use sqlx::SqlitePool;
 use std::sync::Arc;
@@ -21,6 +22,7 @@ pub fn build_job_runner(
     nxdomain_hijack_eviction: Option<NxdomainHijackEvictionJob>,
     response_ip_filter_eviction: Option<ResponseIpFilterEvictionJob>,
     dga_eviction: Option<DgaEvictionJob>,
+    acme_renewal: Option<AcmeRenewalJob>,
 ) -> JobRunner {
     let mut runner = JobRunner::new()
         .with_client_sync(ClientSyncJob::new(
@@ -50,6 +52,19 @@ pub fn build_job_runner(
         );
     }
 
+    if config.dns.dnssec_enabled && config.dns.dnssec.auto_update {
+        runner = runner.with_trust_anchor_refresh(
+            TrustAnchorRefreshJob::new(use_cases.refresh_trust_anchors.clone())
+                .with_interval(config.dns.dnssec.refresh_interval),
+        );
+    }
+
+    if config.dns.dnssec_enabled {
+        runner = runner.with_negative_trust_anchor_cleanup(NegativeTrustAnchorCleanupJob::new(
+            use_cases.cleanup_negative_trust_anchors.clone(),
+        ));
+    }
+
     if let Some(eviction) = tunneling_eviction {
         runner = runner.with_tunneling_eviction(eviction);
     }
@@ -66,5 +81,18 @@ pub fn build_job_runner(
         runner = runner.with_dga_eviction(eviction);
     }
 
+    if let Some(job) = acme_renewal {
+        runner = runner.with_acme_renewal(job);
+    }
+
+    let tls_listeners = config.server.web_tls.enabled
+        || config.server.encrypted_dns.dot_enabled
+        || config.server.encrypted_dns.doh_enabled;
+    if tls_listeners {
+        runner = runner.with_tls_certificate_watch(TlsCertificateWatchJob::new(
+            repos.tls_certificates.clone(),
+        ));
+    }
+
     runner
 }
@@ -2,8 +2,10 @@ pub mod config;
 pub mod database;
 pub mod jobs;
 pub mod logging;
+pub mod trust_anchors;
 
 pub use config::load_config;
 pub use database::init_database;
 pub use jobs::build_job_runner;
 pub use logging::init_logging;
+pub use trust_anchors::load_trust_anchors;
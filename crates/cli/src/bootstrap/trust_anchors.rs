@@ -0,0 +1,42 @@
+use ferrous_dns_application::use_cases::LoadTrustAnchorsUseCase;
+use ferrous_dns_domain::config::DnssecConfig;
+use ferrous_dns_domain::TrustAnchorSource;
+use ferrous_dns_infrastructure::dns::dnssec::{
+    load_trust_anchor_file, parse_trust_anchors, TrustAnchorStore,
+};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::info;
+
+/// Merges the configured trust anchors into the database and loads them
+/// into the validators. The built-in root KSK is used unless the
+/// configuration anchors the root itself.
+pub async fn load_trust_anchors(
+    config: &DnssecConfig,
+    use_case: &LoadTrustAnchorsUseCase,
+) -> anyhow::Result<()> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+
+    let mut anchors = parse_trust_anchors(
+        &config.trust_anchors.join("\n"),
+        TrustAnchorSource::Config,
+        false,
+        now,
+    )
+    .map_err(|e| anyhow::anyhow!("dns.dnssec.trust_anchors: {}", e))?;
+
+    if let Some(path) = &config.trust_anchor_file {
+        anchors.extend(load_trust_anchor_file(Path::new(path), now).map_err(anyhow::Error::msg)?);
+    }
+
+    if !anchors.iter().any(|a| a.zone.as_ref() == ".") {
+        anchors.push(TrustAnchorStore::builtin_root_anchor(now));
+    }
+
+    info!(configured = anchors.len(), "Loading DNSSEC trust anchors");
+    use_case.execute(&anchors).await?;
+    Ok(())
+}
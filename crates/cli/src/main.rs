@@ -5,7 +5,8 @@ static GLOBAL: MiMalloc = MiMalloc;
 
 use anyhow::Context;
 use clap::Parser;
-use ferrous_dns_domain::CliOverrides;
+use ferrous_dns_application::services::JobMetrics;
+use ferrous_dns_domain::{AcmeChallengeType, CliOverrides};
 use ferrous_dns_infrastructure::dns::server::DnsServerHandler;
 use std::net::SocketAddr;
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -77,19 +78,27 @@ async fn async_main() -> anyhow::Result<()> {
         read_pool,
         &config.database,
         config.blocking.enabled,
+        &config.dns.local_zones,
+        &config.dns.views,
+        &config.dns.acme_dns,
     )
     .await?;
     let mut dns_services = wiring::DnsServices::new(&config, &repos).await?;
     let use_cases = wiring::UseCases::new(
         &repos,
         dns_services.pool_manager.clone(),
+        dns_services.dnssec_pool_manager.clone(),
+        &config.dns.dnssec,
         config.dns.local_dns_server.clone(),
     );
+    bootstrap::load_trust_anchors(&config.dns.dnssec, &use_cases.load_trust_anchors).await?;
 
     let tunneling_eviction_job = dns_services.tunneling_eviction_job.take();
     let nxdomain_hijack_job = dns_services.nxdomain_hijack_eviction_job.take();
     let response_ip_filter_job = dns_services.response_ip_filter_eviction_job.take();
     let dga_eviction_job = dns_services.dga_eviction_job.take();
+    let acme_renewal_job = wiring::build_acme_renewal_job(&config, &repos)?;
+    let job_metrics = Arc::new(JobMetrics::new());
     let runner = bootstrap::build_job_runner(
         &use_cases,
         &repos,
@@ -100,7 +109,9 @@ async fn async_main() -> anyhow::Result<()> {
         nxdomain_hijack_job,
         response_ip_filter_job,
         dga_eviction_job,
-    );
+        acme_renewal_job,
+    )
+    .with_metrics(Arc::clone(&job_metrics));
 
     runner.start().await;
 
@@ -121,6 +132,16 @@ async fn async_main() -> anyhow::Result<()> {
             dns_services.health_checker.clone(),
         ));
 
+    let metrics_state = config.server.metrics.enabled.then(|| {
+        wiring::build_metrics_state(
+            &repos,
+            &dns_services,
+            upstream_health.clone(),
+            job_metrics,
+            &config.server.metrics,
+        )
+    });
+
     let pihole_state = wiring::build_pihole_state(
         &use_cases,
         repos.block_filter_engine.clone(),
@@ -129,6 +150,14 @@ async fn async_main() -> anyhow::Result<()> {
         effective_config_path.clone(),
     );
 
+    let dynamic_update = wiring::build_dynamic_update_handler(
+        &repos,
+        &dns_services,
+        config_arc.clone(),
+        &config.dns.dynamic_update_zones,
+        effective_config_path.as_deref(),
+    );
+
     let app_state = wiring::build_app_state(
         use_cases,
         &repos,
@@ -142,7 +171,10 @@ async fn async_main() -> anyhow::Result<()> {
     let handler_use_case = dns_services.handler_use_case;
     let tcp_conn_limiter = dns_services.tcp_conn_limiter;
     let dot_conn_limiter = dns_services.dot_conn_limiter;
-    let dns_handler = DnsServerHandler::new(handler_use_case.clone());
+    let dnstap = dns_services.dnstap;
+    let dns_handler = DnsServerHandler::new(handler_use_case.clone())
+        .with_dynamic_update(dynamic_update)
+        .with_dnstap(dnstap.clone());
     let core_ids_for_dns = core_affinity::get_core_ids().unwrap_or_default();
     let num_dns_workers = core_ids_for_dns.len().max(1);
 
@@ -165,9 +197,11 @@ async fn async_main() -> anyhow::Result<()> {
     let tls_config =
         if config.server.encrypted_dns.dot_enabled || config.server.encrypted_dns.doh_enabled {
             server::load_server_tls_config(
+                &repos.tls_certificates,
                 &config.server.encrypted_dns.tls_cert_path,
                 &config.server.encrypted_dns.tls_key_path,
                 "DoT/DoH",
+                config.server.acme.enabled,
             )?
         } else {
             None
@@ -179,7 +213,9 @@ async fn async_main() -> anyhow::Result<()> {
                 "{}:{}",
                 config.server.bind_address, config.server.encrypted_dns.dot_port
             );
-            let dot_handler = Arc::new(DnsServerHandler::new(handler_use_case.clone()));
+            let dot_handler = Arc::new(
+                DnsServerHandler::new(handler_use_case.clone()).with_dnstap(dnstap.clone()),
+            );
             tokio::spawn(async move {
                 if let Err(e) = server::start_dot_server(
                     dot_addr,
@@ -203,8 +239,9 @@ async fn async_main() -> anyhow::Result<()> {
                 let doh_addr: SocketAddr = format!("{}:{}", config.server.bind_address, doh_port)
                     .parse()
                     .context("Invalid DoH bind address")?;
-                let dedicated_doh_handler =
-                    Arc::new(DnsServerHandler::new(handler_use_case.clone()));
+                let dedicated_doh_handler = Arc::new(
+                    DnsServerHandler::new(handler_use_case.clone()).with_dnstap(dnstap.clone()),
+                );
                 tokio::spawn(async move {
                     if let Err(e) = server::start_doh_server(doh_addr, dedicated_doh_handler).await
                     {
@@ -214,22 +251,45 @@ async fn async_main() -> anyhow::Result<()> {
             }
             None
         } else {
-            tls_config.map(|_| Arc::new(DnsServerHandler::new(handler_use_case)))
+            tls_config
+                .map(|_| Arc::new(DnsServerHandler::new(handler_use_case).with_dnstap(dnstap)))
         }
     } else {
         None
     };
 
+    let metrics_state = match (metrics_state, config.server.metrics.port) {
+        (Some(state), Some(metrics_port)) => {
+            let metrics_addr: SocketAddr =
+                format!("{}:{}", config.server.bind_address, metrics_port)
+                    .parse()
+                    .context("Invalid metrics bind address")?;
+            tokio::spawn(async move {
+                if let Err(e) = server::start_metrics_server(metrics_addr, state).await {
+                    error!(error = %e, "Metrics server error");
+                }
+            });
+            None
+        }
+        (state, _) => state,
+    };
+
     let web_tls_config = if config.server.web_tls.enabled {
         server::load_server_tls_config(
+            &repos.tls_certificates,
             &config.server.web_tls.tls_cert_path,
             &config.server.web_tls.tls_key_path,
             "Web HTTPS",
+            config.server.acme.enabled,
         )?
     } else {
         None
     };
 
+    let acme_http = (config.server.acme.enabled
+        && config.server.acme.challenge == AcmeChallengeType::Http01)
+        .then(|| repos.acme_http_challenges.clone());
+
     let web_addr: SocketAddr = format!("{}:{}", config.server.bind_address, config.server.web_port)
         .parse()
         .expect("Invalid address");
@@ -241,7 +301,9 @@ async fn async_main() -> anyhow::Result<()> {
         &config.server.cors_allowed_origins,
         config.server.pihole_compat,
         doh_handler,
+        metrics_state,
         web_tls_config,
+        acme_http,
     )
     .await?;
 
@@ -1,4 +1,6 @@
 use super::connection_limiter::{ConnectionGuard, ConnectionLimiter};
+use ferrous_dns_application::services::ClientTransport;
+use ferrous_dns_infrastructure::dns::dnstap::SocketProtocol;
 use ferrous_dns_infrastructure::dns::proxy_protocol::{
     read_proxy_v2_client_ip, ProxyProtocolError,
 };
@@ -133,6 +135,8 @@ async fn handle_dot_connection(
     } else {
         peer_addr.ip()
     };
+    // Behind a PROXY Protocol balancer the peer port is the balancer's.
+    let client_port = (!proxy_protocol_enabled).then_some(peer_addr.port());
 
     let mut tls_stream = match acceptor.accept(stream).await {
         Ok(s) => s,
@@ -157,7 +161,17 @@ async fn handle_dot_connection(
             break;
         }
 
+        handler.record_received(ClientTransport::Tls, 1);
+        let query_time = handler.dnstap_query_time();
         if let Some(resp) = handler.handle_raw_udp_fallback(&dns_buf, client_ip).await {
+            handler.tap_client_exchange(
+                SocketProtocol::Dot,
+                client_ip,
+                client_port,
+                &dns_buf,
+                &resp,
+                query_time,
+            );
             let resp_len = (resp.len() as u16).to_be_bytes();
             if tls_stream.write_all(&resp_len).await.is_err() {
                 break;
@@ -1,55 +1,55 @@
 use anyhow::Context;
-use rustls::pki_types::CertificateDer;
-use std::fs::File;
-use std::io::BufReader;
+use ferrous_dns_infrastructure::tls::CertificateResolvers;
 use std::path::Path;
 use std::sync::Arc;
-use tracing::warn;
+use tracing::{info, warn};
 
-/// Loads a `rustls::ServerConfig` from PEM certificate and key files.
+/// Builds a `rustls::ServerConfig` serving the PEM certificate and key files
+/// through a hot-swappable resolver shared with every listener using the
+/// same files.
 ///
 /// Returns `Ok(None)` if either file is absent — the caller should skip the
-/// encrypted listener and continue with plain transport. Returns `Err` only when a
-/// file exists but cannot be parsed or contains an invalid key.
+/// encrypted listener and continue with plain transport. With
+/// `await_acme`, a missing pair instead starts the listener without a
+/// certificate; handshakes succeed once the ACME client has installed one.
+/// Returns `Err` only when both files exist but cannot be parsed or do not
+/// belong together.
 pub fn load_server_tls_config(
+    resolvers: &CertificateResolvers,
     cert_path: &str,
     key_path: &str,
     listener_name: &str,
+    await_acme: bool,
 ) -> anyhow::Result<Option<Arc<rustls::ServerConfig>>> {
-    if !Path::new(cert_path).exists() {
-        warn!(
-            path = cert_path,
-            listener = listener_name,
-            "TLS cert file not found — {listener_name} disabled"
-        );
-        return Ok(None);
-    }
-    if !Path::new(key_path).exists() {
+    let missing = [cert_path, key_path]
+        .into_iter()
+        .find(|path| !Path::new(path).exists());
+
+    if let Some(path) = missing {
+        if await_acme {
+            info!(
+                path,
+                listener = listener_name,
+                "TLS file not found — {listener_name} waits for the ACME certificate"
+            );
+            return Ok(Some(
+                resolvers.resolver(cert_path, key_path).server_config(),
+            ));
+        }
         warn!(
-            path = key_path,
+            path,
             listener = listener_name,
-            "TLS key file not found — {listener_name} disabled"
+            "TLS file not found — {listener_name} disabled"
         );
         return Ok(None);
     }
 
-    let cert_file =
-        File::open(cert_path).with_context(|| format!("Failed to open TLS cert: {cert_path}"))?;
-    let key_file =
-        File::open(key_path).with_context(|| format!("Failed to open TLS key: {key_path}"))?;
-
-    let certs: Vec<CertificateDer> = rustls_pemfile::certs(&mut BufReader::new(cert_file))
-        .collect::<Result<_, _>>()
-        .with_context(|| format!("Failed to parse TLS cert: {cert_path}"))?;
-
-    let key = rustls_pemfile::private_key(&mut BufReader::new(key_file))
-        .with_context(|| format!("Failed to read TLS key: {key_path}"))?
-        .ok_or_else(|| anyhow::anyhow!("No private key found in {key_path}"))?;
-
-    let config = rustls::ServerConfig::builder()
-        .with_no_client_auth()
-        .with_single_cert(certs, key)
-        .context("Failed to build rustls ServerConfig")?;
+    let resolver = resolvers.resolver(cert_path, key_path);
+    if !resolver.is_loaded() {
+        resolver
+            .reload()
+            .with_context(|| format!("Failed to load TLS certificate for {listener_name}"))?;
+    }
 
-    Ok(Some(Arc::new(config)))
+    Ok(Some(resolver.server_config()))
 }
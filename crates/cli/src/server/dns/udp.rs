@@ -1,3 +1,5 @@
+use ferrous_dns_application::services::ClientTransport;
+use ferrous_dns_infrastructure::dns::dnstap::SocketProtocol;
 use ferrous_dns_infrastructure::dns::fast_path::{self, FastPathKind};
 use ferrous_dns_infrastructure::dns::server::DnsServerHandler;
 use ferrous_dns_infrastructure::dns::wire_response;
@@ -104,6 +106,9 @@ async fn run_udp_worker_batch(
                 }
             };
 
+            handler.record_received(ClientTransport::Udp, n as u64);
+            let query_time = handler.dnstap_query_time();
+
             // Process each received packet in the batch.
             pending.clear();
             pending_wire.clear();
@@ -128,6 +133,14 @@ async fn run_udp_worker_batch(
                                         ttl,
                                     )
                                 {
+                                    handler.tap_client_exchange(
+                                        SocketProtocol::Udp,
+                                        client_ip,
+                                        Some(msg.src.port()),
+                                        msg.data,
+                                        &wire[..wire_len],
+                                        query_time,
+                                    );
                                     // Fast path: inline wire buf — zero extra heap allocation.
                                     pending.push(pktinfo::PendingResponse {
                                         wire,
@@ -148,6 +161,14 @@ async fn run_udp_worker_batch(
                                 if let Some(patched) =
                                     wire_response::patch_wire_id(&wire_bytes, fast_query.id)
                                 {
+                                    handler.tap_client_exchange(
+                                        SocketProtocol::Udp,
+                                        client_ip,
+                                        Some(msg.src.port()),
+                                        msg.data,
+                                        &patched,
+                                        query_time,
+                                    );
                                     pending_wire.push(pktinfo::PendingWireResponse {
                                         data: patched,
                                         to: msg.src,
@@ -190,6 +211,14 @@ async fn run_udp_worker_batch(
                 let s = socket.clone();
                 tokio::spawn(async move {
                     if let Some(resp) = h.handle_raw_udp_fallback(&buf, cip).await {
+                        h.tap_client_exchange(
+                            SocketProtocol::Udp,
+                            cip,
+                            Some(from.port()),
+                            &buf,
+                            &resp,
+                            query_time,
+                        );
                         let _ = pktinfo::try_send_with_src_ip(s.get_ref(), &resp, from, dst_ip);
                     }
                 });
@@ -217,6 +246,8 @@ async fn run_udp_worker_single(
         loop {
             match pktinfo::try_recv_with_pktinfo(socket.get_ref(), &mut recv_buf) {
                 Ok((n, from, dst_ip)) => {
+                    handler.record_received(ClientTransport::Udp, 1);
+                    let query_time = handler.dnstap_query_time();
                     let query_buf = &recv_buf[..n];
                     let client_ip = from.ip();
 
@@ -236,6 +267,14 @@ async fn run_udp_worker_single(
                                             ttl,
                                         )
                                     {
+                                        handler.tap_client_exchange(
+                                            SocketProtocol::Udp,
+                                            client_ip,
+                                            Some(from.port()),
+                                            query_buf,
+                                            &wire[..wire_len],
+                                            query_time,
+                                        );
                                         let _ = pktinfo::try_send_with_src_ip(
                                             socket.get_ref(),
                                             &wire[..wire_len],
@@ -255,6 +294,14 @@ async fn run_udp_worker_single(
                                     if let Some(patched) =
                                         wire_response::patch_wire_id(&wire_bytes, fast_query.id)
                                     {
+                                        handler.tap_client_exchange(
+                                            SocketProtocol::Udp,
+                                            client_ip,
+                                            Some(from.port()),
+                                            query_buf,
+                                            &patched,
+                                            query_time,
+                                        );
                                         let _ = pktinfo::try_send_with_src_ip(
                                             socket.get_ref(),
                                             &patched,
@@ -276,6 +323,14 @@ async fn run_udp_worker_single(
                             .handle_raw_udp_fallback(&owned_buf, client_ip)
                             .await
                         {
+                            handler_clone.tap_client_exchange(
+                                SocketProtocol::Udp,
+                                client_ip,
+                                Some(from.port()),
+                                &owned_buf,
+                                &response,
+                                query_time,
+                            );
                             let _ = pktinfo::try_send_with_src_ip(
                                 socket_clone.get_ref(),
                                 &response,
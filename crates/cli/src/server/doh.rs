@@ -3,6 +3,8 @@ use axum::http::{header, HeaderMap, Method, StatusCode};
 use axum::response::{IntoResponse, Response};
 use axum::Extension;
 use base64::Engine;
+use ferrous_dns_application::services::ClientTransport;
+use ferrous_dns_infrastructure::dns::dnstap::SocketProtocol;
 use ferrous_dns_infrastructure::dns::server::DnsServerHandler;
 use hickory_proto::op::{Edns, Message, MessageType, OpCode, Query as DnsQuery};
 use hickory_proto::rr::{DNSClass, Name, RData, RecordType as HickoryRecordType};
@@ -61,7 +63,20 @@ pub async fn dns_query_handler(
         }
     };
 
-    match handler.handle_raw_udp_fallback(&wire, client_ip).await {
+    handler.record_received(ClientTransport::Https, 1);
+    let query_time = handler.dnstap_query_time();
+    let response = handler.handle_raw_udp_fallback(&wire, client_ip).await;
+    if let Some(response_bytes) = &response {
+        handler.tap_client_exchange(
+            SocketProtocol::Doh,
+            client_ip,
+            None,
+            &wire,
+            response_bytes,
+            query_time,
+        );
+    }
+    match response {
         Some(response_bytes) if json_response => match wire_to_dns_json(&response_bytes) {
             Ok(body) => (
                 StatusCode::OK,
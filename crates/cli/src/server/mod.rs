@@ -7,6 +7,7 @@ pub use dns::dot::start_dot_server;
 pub use dns::start_dns_server;
 pub use dns::tls_config::load_server_tls_config;
 pub use web::start_doh_server;
+pub use web::start_metrics_server;
 pub use web::start_web_server;
 
 /// Abstraction for a DNS protocol server that can be started independently.
@@ -1,13 +1,17 @@
 use axum::{
-    extract::State,
-    http::{header, HeaderValue, Method},
-    response::{Html, IntoResponse},
+    extract::{Path, State},
+    http::{header, HeaderValue, Method, StatusCode},
+    response::{Html, IntoResponse, Response},
     routing::get,
     Router,
 };
-use ferrous_dns_api::{create_api_routes, AppState};
+use ferrous_dns_api::{
+    create_acme_dns_routes, create_api_routes, create_ddns_routes, create_metrics_routes, AppState,
+    MetricsState,
+};
 use ferrous_dns_api_pihole::{create_pihole_routes, PiholeAppState};
 use ferrous_dns_infrastructure::dns::server::DnsServerHandler;
+use ferrous_dns_infrastructure::tls::AcmeHttpChallenges;
 use std::net::SocketAddr;
 use std::sync::Arc;
 use tower_http::compression::CompressionLayer;
@@ -42,6 +46,28 @@ pub async fn start_doh_server(
     Ok(())
 }
 
+pub async fn start_metrics_server(
+    bind_addr: SocketAddr,
+    state: MetricsState,
+) -> anyhow::Result<()> {
+    info!(
+        bind_address = %bind_addr,
+        endpoint = format!("http://{}/metrics", bind_addr),
+        "Starting metrics server"
+    );
+
+    let app = create_metrics_routes(state);
+
+    let listener = tokio::net::TcpListener::bind(&bind_addr).await?;
+
+    info!("Metrics server ready on {}", bind_addr);
+
+    axum::serve(listener, app).await?;
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
 pub async fn start_web_server(
     bind_addr: SocketAddr,
     ferrous_state: AppState,
@@ -49,7 +75,9 @@ pub async fn start_web_server(
     cors_allowed_origins: &[String],
     pihole_compat: bool,
     doh_handler: Option<Arc<DnsServerHandler>>,
+    metrics_state: Option<MetricsState>,
     tls_config: Option<Arc<rustls::ServerConfig>>,
+    acme_http: Option<Arc<AcmeHttpChallenges>>,
 ) -> anyhow::Result<()> {
     let scheme = if tls_config.is_some() {
         "https"
@@ -74,21 +102,36 @@ pub async fn start_web_server(
         );
     }
 
+    let acme_dns_enabled = ferrous_state.config.read().await.dns.acme_dns.enabled;
+    if acme_dns_enabled {
+        info!(
+            register_url = format!("{}://{}/register", scheme, bind_addr),
+            "acme-dns API enabled"
+        );
+    }
+
     let app = create_app(
         ferrous_state,
         pihole_state,
         cors_allowed_origins,
         pihole_compat,
         doh_handler,
+        metrics_state,
+        acme_dns_enabled,
+        acme_http.clone(),
     );
 
     if let Some(tls_cfg) = tls_config {
         info!("Web server started successfully (HTTPS)");
-        web_tls::start_https_web_server(bind_addr, app, tls_cfg).await?;
+        web_tls::start_https_web_server(bind_addr, app, tls_cfg, acme_http).await?;
     } else {
         let listener = tokio::net::TcpListener::bind(&bind_addr).await?;
         info!("Web server started successfully");
-        axum::serve(listener, app).await?;
+        axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .await?;
     }
 
     Ok(())
@@ -112,13 +155,19 @@ fn build_strict_cors(allowed_origins: &[String]) -> CorsLayer {
         .allow_headers([header::CONTENT_TYPE, header::AUTHORIZATION])
 }
 
+#[allow(clippy::too_many_arguments)]
 fn create_app(
     ferrous_state: AppState,
     pihole_state: PiholeAppState,
     cors_allowed_origins: &[String],
     pihole_compat: bool,
     doh_handler: Option<Arc<DnsServerHandler>>,
+    metrics_state: Option<MetricsState>,
+    acme_dns_enabled: bool,
+    acme_http: Option<Arc<AcmeHttpChallenges>>,
 ) -> Router {
+    let ddns_routes = create_ddns_routes(ferrous_state.clone());
+    let acme_dns_routes = acme_dns_enabled.then(|| create_acme_dns_routes(ferrous_state.clone()));
     let router = if pihole_compat {
         Router::new()
             .nest("/api", create_pihole_routes(pihole_state))
@@ -128,6 +177,7 @@ fn create_app(
     };
 
     let mut app = router
+        .merge(ddns_routes)
         .route(
             "/ferrous-config.js",
             get(ferrous_config_js_handler).with_state(pihole_compat),
@@ -185,9 +235,41 @@ fn create_app(
             .layer(axum::Extension(handler));
     }
 
+    if let Some(state) = metrics_state {
+        app = app.merge(create_metrics_routes(state));
+    }
+
+    if let Some(routes) = acme_dns_routes {
+        app = app.merge(routes);
+    }
+
+    if let Some(challenges) = acme_http {
+        app = app
+            .route(
+                "/.well-known/acme-challenge/{token}",
+                get(acme_http_challenge_handler),
+            )
+            .layer(axum::Extension(challenges));
+    }
+
     app
 }
 
+/// Serves the key authorization for a pending ACME HTTP-01 challenge.
+async fn acme_http_challenge_handler(
+    axum::Extension(challenges): axum::Extension<Arc<AcmeHttpChallenges>>,
+    Path(token): Path<String>,
+) -> Response {
+    match challenges.get(&token) {
+        Some(key_authorization) => (
+            [(header::CONTENT_TYPE, "text/plain")],
+            key_authorization.to_string(),
+        )
+            .into_response(),
+        None => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
 /// Returns a small JS snippet that sets `window.FERROUS_API_BASE` at runtime.
 ///
 /// The HTMLs are compiled into the binary via `include_str!` and cannot be
@@ -1,4 +1,6 @@
+use axum::extract::ConnectInfo;
 use axum::Router;
+use ferrous_dns_infrastructure::tls::AcmeHttpChallenges;
 use hyper::body::Incoming;
 use hyper_util::rt::{TokioExecutor, TokioIo};
 use hyper_util::server::conn::auto::Builder;
@@ -13,13 +15,15 @@ use tracing::{debug, error, warn};
 ///
 /// On each accepted TCP connection the first byte is peeked:
 /// - `0x16` (TLS ClientHello) → proceed with TLS handshake and serve normally.
-/// - Anything else (plain HTTP) → respond with 301 redirect to `https://`.
+/// - Anything else (plain HTTP) → respond with 301 redirect to `https://`,
+///   except for pending ACME HTTP-01 challenges, which are answered directly.
 ///
 /// This allows the same port to handle both protocols transparently.
 pub(super) async fn start_https_web_server(
     bind_addr: SocketAddr,
     app: Router,
     tls_config: Arc<rustls::ServerConfig>,
+    acme_http: Option<Arc<AcmeHttpChallenges>>,
 ) -> anyhow::Result<()> {
     let listener = TcpListener::bind(bind_addr).await?;
     let acceptor = TlsAcceptor::from(tls_config);
@@ -36,6 +40,7 @@ pub(super) async fn start_https_web_server(
         let acceptor = acceptor.clone();
         let tower_service = app.clone();
         let port = bind_addr.port();
+        let acme_http = acme_http.clone();
 
         tokio::spawn(async move {
             let mut peek_buf = [0u8; 1];
@@ -49,7 +54,7 @@ pub(super) async fn start_https_web_server(
             }
 
             if peek_buf[0] != 0x16 {
-                send_https_redirect(stream, peer_addr, port).await;
+                send_https_redirect(stream, peer_addr, port, acme_http.as_deref()).await;
                 return;
             }
 
@@ -67,7 +72,8 @@ pub(super) async fn start_https_web_server(
                 let mut svc = tower_service.clone();
                 async move {
                     use tower::Service;
-                    let (parts, body) = req.into_parts();
+                    let (mut parts, body) = req.into_parts();
+                    parts.extensions.insert(ConnectInfo(peer_addr));
                     let req = hyper::Request::from_parts(parts, axum::body::Body::new(body));
                     svc.call(req).await
                 }
@@ -84,8 +90,14 @@ pub(super) async fn start_https_web_server(
 }
 
 /// Reads the incoming HTTP request line to extract the path, then sends a
-/// 301 redirect to `https://<Host>:<port><path>`.
-async fn send_https_redirect(mut stream: TcpStream, peer_addr: SocketAddr, port: u16) {
+/// 301 redirect to `https://<Host>:<port><path>`. Pending ACME HTTP-01
+/// tokens are answered in plain HTTP instead, as the CA requires.
+async fn send_https_redirect(
+    mut stream: TcpStream,
+    peer_addr: SocketAddr,
+    port: u16,
+    acme_http: Option<&AcmeHttpChallenges>,
+) {
     let mut buf = [0u8; 1024];
     let n = match tokio::time::timeout(
         std::time::Duration::from_secs(5),
@@ -102,6 +114,22 @@ async fn send_https_redirect(mut stream: TcpStream, peer_addr: SocketAddr, port:
     let host = extract_host(&request).unwrap_or_else(|| peer_addr.ip().to_string());
     let path = extract_path(&request).unwrap_or("/");
 
+    let key_authorization = path
+        .strip_prefix(AcmeHttpChallenges::PATH_PREFIX)
+        .and_then(|token| acme_http?.get(token));
+    if let Some(key_authorization) = key_authorization {
+        let response = format!(
+            "HTTP/1.1 200 OK\r\n\
+             Content-Type: text/plain\r\n\
+             Content-Length: {}\r\n\
+             Connection: close\r\n\r\n{key_authorization}",
+            key_authorization.len()
+        );
+        let _ = stream.write_all(response.as_bytes()).await;
+        debug!(client = %peer_addr, path, "Answered ACME HTTP-01 challenge");
+        return;
+    }
+
     let location = if port == 443 {
         format!("https://{host}{path}")
     } else {
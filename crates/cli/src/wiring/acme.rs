@@ -0,0 +1,51 @@
+use anyhow::Context;
+use ferrous_dns_application::use_cases::RenewAcmeCertificateUseCase;
+use ferrous_dns_domain::Config;
+use ferrous_dns_infrastructure::tls::{AcmeClient, TlsCertificateService};
+use ferrous_dns_jobs::AcmeRenewalJob;
+use std::sync::Arc;
+use tracing::info;
+
+use super::Repositories;
+
+/// Builds the certificate renewal job when `[server.acme]` is enabled.
+/// Certificates are written to every enabled listener's cert/key paths.
+pub fn build_acme_renewal_job(
+    config: &Config,
+    repos: &Repositories,
+) -> anyhow::Result<Option<AcmeRenewalJob>> {
+    let acme = &config.server.acme;
+    if !acme.enabled {
+        return Ok(None);
+    }
+
+    let client = AcmeClient::new(
+        acme,
+        repos.acme_http_challenges.clone(),
+        repos.acme_challenges.clone(),
+    )
+    .context("Failed to set up the ACME client")?;
+
+    let domains = acme.normalized_domains();
+    let paths = config.server.tls_certificate_paths();
+    info!(
+        directory = %acme.directory_url,
+        challenge = ?acme.challenge,
+        domains = ?domains,
+        files = paths.len(),
+        "ACME certificate management enabled"
+    );
+
+    let use_case = RenewAcmeCertificateUseCase::new(
+        Arc::new(client),
+        Arc::new(TlsCertificateService),
+        repos.tls_certificates.clone(),
+        domains,
+        paths,
+    )
+    .with_renew_before_days(acme.renew_before_days);
+
+    Ok(Some(
+        AcmeRenewalJob::new(Arc::new(use_case)).with_interval(acme.check_interval_secs),
+    ))
+}
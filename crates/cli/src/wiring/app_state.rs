@@ -6,11 +6,14 @@ use ferrous_dns_application::ports::{
     BlocklistSourceCreator, ConfigFilePersistence, GroupCreator, LocalRecordCreator, UserProvider,
 };
 use ferrous_dns_application::use_cases::{
-    ChangePasswordUseCase, CreateApiTokenUseCase, CreateLocalRecordUseCase, CreateUserUseCase,
-    DeleteApiTokenUseCase, DeleteLocalRecordUseCase, DeleteUserUseCase, ExportConfigUseCase,
-    GetActiveSessionsUseCase, GetApiTokensUseCase, GetAuthStatusUseCase, GetUsersUseCase,
-    ImportConfigUseCase, LoginUseCase, LogoutUseCase, SetupPasswordUseCase, UpdateApiTokenUseCase,
-    UpdateLocalRecordUseCase, ValidateApiTokenUseCase, ValidateSessionUseCase,
+    ApplyDdnsUpdateUseCase, ChangePasswordUseCase, CreateApiTokenUseCase, CreateLocalRecordUseCase,
+    CreateUserUseCase, DeleteAcmeRegistrationUseCase, DeleteApiTokenUseCase,
+    DeleteLocalRecordUseCase, DeleteUserUseCase, ExportConfigUseCase, GetAcmeRegistrationsUseCase,
+    GetActiveSessionsUseCase, GetApiTokensUseCase, GetAuthStatusUseCase, GetDdnsHistoryUseCase,
+    GetUsersUseCase, ImportConfigUseCase, LoginUseCase, LogoutUseCase, RegisterAcmeAccountUseCase,
+    SetupPasswordUseCase, UpdateAcmeChallengeUseCase, UpdateApiTokenUseCase,
+    UpdateGroupUpstreamPoolsUseCase, UpdateLocalRecordUseCase, ValidateApiTokenUseCase,
+    ValidateSessionUseCase,
 };
 use ferrous_dns_domain::Config;
 use ferrous_dns_infrastructure::auth::{
@@ -126,6 +129,43 @@ pub async fn build_app_state(
         }
     };
 
+    let dns_cache =
+        dns_services.cache.clone() as Arc<dyn ferrous_dns_application::ports::DnsCachePort>;
+    let create_local_record = Arc::new(
+        CreateLocalRecordUseCase::new(config.clone(), config_repo.clone())
+            .with_ptr_registry(dns_services.ptr_registry.clone())
+            .with_dns_cache(Some(dns_cache.clone())),
+    );
+    let update_local_record = Arc::new(
+        UpdateLocalRecordUseCase::new(config.clone(), config_repo.clone())
+            .with_ptr_registry(dns_services.ptr_registry.clone())
+            .with_dns_cache(Some(dns_cache.clone())),
+    );
+    let delete_local_record = Arc::new(
+        DeleteLocalRecordUseCase::new(config.clone(), config_repo)
+            .with_ptr_registry(dns_services.ptr_registry.clone())
+            .with_dns_cache(Some(dns_cache.clone())),
+    );
+    let apply_ddns_update = Arc::new(ApplyDdnsUpdateUseCase::new(
+        create_local_record.clone(),
+        auth.validate_api_token.clone(),
+        repos.ddns_history.clone(),
+        &config.read().await.dns.dynamic_update_zones,
+    ));
+
+    let register_acme_account = {
+        let cfg = config.read().await;
+        Arc::new(RegisterAcmeAccountUseCase::new(
+            repos.acme_registration.clone(),
+            repos.acme_challenges.clone(),
+            &cfg.dns.acme_dns.normalized_zone(),
+            cfg.dns
+                .acme_dns
+                .parse_register_allowed_from()
+                .unwrap_or_default(),
+        ))
+    };
+
     AppState {
         query: QueryUseCases {
             get_stats: use_cases.get_stats,
@@ -135,37 +175,61 @@ pub async fn build_app_state(
             get_cache_stats: use_cases.get_cache_stats,
             get_top_blocked_domains: use_cases.get_top_blocked_domains,
             get_top_clients: use_cases.get_top_clients,
+            query_stream: dns_services.query_stream.clone(),
         },
         dns: DnsUseCases {
-            cache: dns_services.cache.clone()
-                as Arc<dyn ferrous_dns_application::ports::DnsCachePort>,
-            create_local_record: Arc::new(
-                CreateLocalRecordUseCase::new(config.clone(), config_repo.clone())
-                    .with_ptr_registry(dns_services.ptr_registry.clone())
-                    .with_dns_cache(Some(dns_services.cache.clone()
-                        as Arc<dyn ferrous_dns_application::ports::DnsCachePort>)),
-            ),
-            update_local_record: Arc::new(
-                UpdateLocalRecordUseCase::new(config.clone(), config_repo.clone())
-                    .with_ptr_registry(dns_services.ptr_registry.clone())
-                    .with_dns_cache(Some(dns_services.cache.clone()
-                        as Arc<dyn ferrous_dns_application::ports::DnsCachePort>)),
-            ),
-            delete_local_record: Arc::new(
-                DeleteLocalRecordUseCase::new(config.clone(), config_repo)
-                    .with_ptr_registry(dns_services.ptr_registry.clone())
-                    .with_dns_cache(Some(dns_services.cache.clone()
-                        as Arc<dyn ferrous_dns_application::ports::DnsCachePort>)),
-            ),
+            cache: dns_cache,
+            create_local_record,
+            update_local_record,
+            delete_local_record,
+            apply_ddns_update,
+            get_ddns_history: Arc::new(GetDdnsHistoryUseCase::new(repos.ddns_history.clone())),
             upstream_health: Arc::new(UpstreamHealthAdapter::new(
                 dns_services.pool_manager.clone(),
                 dns_services.health_checker.clone(),
             )),
+            get_forwarding_rules: use_cases.get_forwarding_rules,
+            create_forwarding_rule: use_cases.create_forwarding_rule,
+            update_forwarding_rule: use_cases.update_forwarding_rule,
+            delete_forwarding_rule: use_cases.delete_forwarding_rule,
+            get_dns_rewrite_rules: use_cases.get_dns_rewrite_rules,
+            create_dns_rewrite_rule: use_cases.create_dns_rewrite_rule,
+            update_dns_rewrite_rule: use_cases.update_dns_rewrite_rule,
+            delete_dns_rewrite_rule: use_cases.delete_dns_rewrite_rule,
+            get_local_zones: use_cases.get_local_zones,
+            create_zone_record: use_cases.create_zone_record,
+            update_zone_record: use_cases.update_zone_record,
+            delete_zone_record: use_cases.delete_zone_record,
+            get_trust_anchors: use_cases.get_trust_anchors,
+            get_negative_trust_anchors: use_cases.get_negative_trust_anchors,
+            create_negative_trust_anchor: use_cases.create_negative_trust_anchor,
+            delete_negative_trust_anchor: use_cases.delete_negative_trust_anchor,
+            get_tsig_keys: use_cases.get_tsig_keys,
+            create_tsig_key: use_cases.create_tsig_key,
+            delete_tsig_key: use_cases.delete_tsig_key,
+            get_acme_registrations: Arc::new(GetAcmeRegistrationsUseCase::new(
+                repos.acme_registration.clone(),
+            )),
+            register_acme_account,
+            update_acme_challenge: Arc::new(UpdateAcmeChallengeUseCase::new(
+                repos.acme_registration.clone(),
+                repos.acme_challenges.clone(),
+            )),
+            delete_acme_registration: Arc::new(DeleteAcmeRegistrationUseCase::new(
+                repos.acme_registration.clone(),
+                repos.acme_challenges.clone(),
+            )),
         },
         groups: GroupUseCases {
             get_groups: use_cases.get_groups,
             create_group: use_cases.create_group,
             update_group: use_cases.update_group,
+            update_group_blocking_mode: use_cases.update_group_blocking_mode,
+            update_group_upstream_pools: Arc::new(UpdateGroupUpstreamPoolsUseCase::new(
+                repos.group.clone(),
+                repos.group_upstreams.clone(),
+                config.clone(),
+            )),
             delete_group: use_cases.delete_group,
             assign_client_group: use_cases.assign_client_group,
         },
@@ -185,6 +249,8 @@ pub async fn build_app_state(
             create_blocklist_source: use_cases.create_blocklist_source,
             update_blocklist_source: use_cases.update_blocklist_source,
             delete_blocklist_source: use_cases.delete_blocklist_source,
+            get_blocklist_source_hits: use_cases.get_blocklist_source_hits,
+            simulate_blocklist: use_cases.simulate_blocklist,
             get_whitelist: use_cases.get_whitelist,
             get_whitelist_sources: use_cases.get_whitelist_sources,
             create_whitelist_source: use_cases.create_whitelist_source,
@@ -199,6 +265,7 @@ pub async fn build_app_state(
             update_regex_filter: use_cases.update_regex_filter,
             delete_regex_filter: use_cases.delete_regex_filter,
             get_block_filter_stats: use_cases.get_block_filter_stats,
+            explain_filter_decision: use_cases.explain_filter_decision,
         },
         services: ServiceUseCases {
             get_service_catalog: use_cases.get_service_catalog,
@@ -230,5 +297,6 @@ pub async fn build_app_state(
         config_file_persistence: config_persistence,
         config_path,
         tls_cert: Arc::new(TlsCertificateService),
+        tls_reloader: repos.tls_certificates.clone(),
     }
 }
@@ -8,15 +8,16 @@ use ferrous_dns_application::ports::{
     NxdomainHijackProbeTarget, PtrRecordRegistry, ResponseIpFilterEvictionTarget,
     ResponseIpFilterStore, TunnelingEvictionTarget, TunnelingFlagStore,
 };
+use ferrous_dns_application::services::{DnsMetrics, QueryStream};
 use ferrous_dns_application::use_cases::dns::rate_limiter::DnsRateLimiter;
 use ferrous_dns_application::use_cases::dns::tsc_timer;
 use ferrous_dns_application::use_cases::dns::DnsCookieGuard;
 use ferrous_dns_application::use_cases::HandleDnsQueryUseCase;
-use ferrous_dns_domain::Config;
+use ferrous_dns_domain::{BlockingMode, Config};
 use ferrous_dns_infrastructure::dns::{
     cache::DnsCache, cache_maintenance::DnsCacheMaintenance, events::QueryEventEmitter,
-    resolver::LocalPtrResolver, DgaDetector, HealthChecker, HickoryDnsResolver,
-    NxdomainHijackDetector, PoolManager, ResponseIpFilterDetector, TunnelingDetector,
+    resolver::LocalPtrResolver, DgaDetector, DnstapWriter, HealthChecker, HickoryDnsResolver,
+    NxdomainHijackDetector, PoolManager, Recursor, ResponseIpFilterDetector, TunnelingDetector,
 };
 use ferrous_dns_jobs::{
     DgaEvictionJob, NxdomainHijackEvictionJob, ResponseIpFilterEvictionJob, TunnelingEvictionJob,
@@ -30,6 +31,7 @@ pub struct DnsServices {
     pub cache: Arc<DnsCache>,
     pub handler_use_case: Arc<HandleDnsQueryUseCase>,
     pub pool_manager: Arc<PoolManager>,
+    pub dnssec_pool_manager: Arc<PoolManager>,
     pub health_checker: Option<Arc<HealthChecker>>,
     pub cache_maintenance: Option<Arc<dyn CacheMaintenancePort>>,
     pub ptr_registry: Option<Arc<dyn PtrRecordRegistry>>,
@@ -39,6 +41,13 @@ pub struct DnsServices {
     pub nxdomain_hijack_eviction_job: Option<NxdomainHijackEvictionJob>,
     pub response_ip_filter_eviction_job: Option<ResponseIpFilterEvictionJob>,
     pub dga_eviction_job: Option<DgaEvictionJob>,
+    pub dns_metrics: Arc<DnsMetrics>,
+    pub tunneling_target: Option<Arc<dyn TunnelingEvictionTarget>>,
+    pub nxdomain_hijack_target: Option<Arc<dyn NxdomainHijackProbeTarget>>,
+    pub response_ip_filter_target: Option<Arc<dyn ResponseIpFilterEvictionTarget>>,
+    pub dga_target: Option<Arc<dyn DgaEvictionTarget>>,
+    pub dnstap: Option<Arc<DnstapWriter>>,
+    pub query_stream: Arc<QueryStream>,
 }
 
 impl DnsServices {
@@ -46,10 +55,25 @@ impl DnsServices {
         info!("Initializing DNS services with load balancing");
         tsc_timer::init();
 
-        let emitter = pool::setup_event_logger(repos);
+        let dnstap = if config.dns.dnstap.enabled {
+            Some(DnstapWriter::spawn(&config.dns.dnstap)?)
+        } else {
+            None
+        };
+        let mut emitter = pool::setup_event_logger(repos);
+        if let Some(ref dnstap) = dnstap {
+            emitter = emitter.with_dnstap(Arc::clone(dnstap));
+        }
         let health_checker = pool::setup_health_checker(config);
-        let pool_manager =
-            pool::setup_pool_manager(config, health_checker.clone(), emitter.clone()).await?;
+        let recursor = pool::setup_recursor(config);
+        let pool_manager = pool::setup_pool_manager(
+            config,
+            health_checker.clone(),
+            emitter.clone(),
+            &recursor,
+            &repos.group_upstreams,
+        )
+        .await?;
 
         pool::start_health_checker_task(health_checker.clone(), &pool_manager, config);
         let stored_health_checker = health_checker.clone();
@@ -63,12 +87,13 @@ impl DnsServices {
                 health_checker,
                 QueryEventEmitter::new_disabled(),
             )
-            .await?,
+            .await?
+            .with_recursor(Arc::clone(&recursor)),
         );
 
         let mut dns_resolver = resolver::build_resolver(
             pool_manager,
-            pool_manager_for_dnssec,
+            pool_manager_for_dnssec.clone(),
             config,
             repos,
             timeout_ms,
@@ -78,6 +103,7 @@ impl DnsServices {
         if config.dns.cache_enabled {
             dns_resolver = dns_resolver
                 .with_inflight_shards(config.dns.cache_inflight_shards)
+                .with_aggressive_nsec(config.dns.cache_aggressive_nsec)
                 .with_cache(dns_cache.clone(), config.dns.cache_ttl);
         }
 
@@ -85,6 +111,7 @@ impl DnsServices {
             config,
             &dns_cache,
             stored_health_checker.clone(),
+            &recursor,
             timeout_ms,
             repos,
         )
@@ -184,12 +211,15 @@ impl DnsServices {
                 (None, None)
             };
 
+        let dns_metrics = Arc::new(DnsMetrics::new());
+        let query_stream = Arc::new(QueryStream::default());
         let mut handler = HandleDnsQueryUseCase::new(
             resolver.clone(),
             repos.block_filter_engine.clone(),
             repos.query_log.clone(),
         )
         .with_safe_search(repos.safe_search_engine.clone())
+        .with_dns_rewrites(repos.dns_rewrites.clone())
         .with_client_tracking(
             repos.client.clone(),
             config.database.client_tracking_interval,
@@ -199,7 +229,11 @@ impl DnsServices {
             config.dns.local_domain.as_deref(),
             &config.dns.rebinding_allowlist,
         )
-        .with_rate_limiter(rate_limiter);
+        .with_rate_limiter(rate_limiter)
+        .with_metrics(Arc::clone(&dns_metrics))
+        .with_query_stream(Arc::clone(&query_stream))
+        .with_group_upstreams(repos.group_upstreams.clone())
+        .with_split_horizon(&config.dns);
 
         if let Some((ref detector, ref tx)) = tunneling_detector {
             handler = handler
@@ -303,8 +337,21 @@ impl DnsServices {
             );
         }
 
+        handler = handler.with_blocking_mode(&config.blocking);
+        if config.blocking.mode != BlockingMode::Refused {
+            info!(mode = %config.blocking.mode, "Custom blocking response mode enabled");
+        }
+
         let handler_use_case = Arc::new(handler);
 
+        let tunneling_target =
+            tunneling_detector.map(|(detector, _)| detector as Arc<dyn TunnelingEvictionTarget>);
+        let nxdomain_hijack_target =
+            nxdomain_hijack_detector.map(|detector| detector as Arc<dyn NxdomainHijackProbeTarget>);
+        let response_ip_filter_target = response_ip_filter_detector
+            .map(|detector| detector as Arc<dyn ResponseIpFilterEvictionTarget>);
+        let dga_target = dga_detector.map(|(detector, _)| detector as Arc<dyn DgaEvictionTarget>);
+
         let tcp_conn_limiter =
             ConnectionLimiter::new(config.dns.rate_limit.tcp_max_connections_per_ip);
         let dot_conn_limiter =
@@ -316,6 +363,7 @@ impl DnsServices {
             cache: dns_cache,
             handler_use_case,
             pool_manager: pool_manager_clone,
+            dnssec_pool_manager: pool_manager_for_dnssec,
             health_checker: stored_health_checker,
             cache_maintenance,
             ptr_registry,
@@ -325,6 +373,13 @@ impl DnsServices {
             nxdomain_hijack_eviction_job,
             response_ip_filter_eviction_job,
             dga_eviction_job,
+            dns_metrics,
+            tunneling_target,
+            nxdomain_hijack_target,
+            response_ip_filter_target,
+            dga_target,
+            dnstap,
+            query_stream,
         })
     }
 
@@ -332,6 +387,7 @@ impl DnsServices {
         config: &Config,
         cache: &Arc<DnsCache>,
         health_checker: Option<Arc<HealthChecker>>,
+        recursor: &Arc<Recursor>,
         timeout_ms: u64,
         repos: &Repositories,
     ) -> anyhow::Result<Option<Arc<dyn CacheMaintenancePort>>> {
@@ -348,7 +404,10 @@ impl DnsServices {
                 health_checker,
                 QueryEventEmitter::new_disabled(),
             )
-            .await?,
+            .await?
+            .with_recursor(Arc::clone(recursor))
+            .with_group_upstreams(repos.group_upstreams.clone())
+            .with_view_upstreams(&config.dns.views),
         );
 
         let resolver_for_maintenance: Arc<dyn ferrous_dns_application::ports::DnsResolver> =
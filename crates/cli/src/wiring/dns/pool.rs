@@ -1,6 +1,7 @@
 use ferrous_dns_domain::Config;
 use ferrous_dns_infrastructure::dns::{
-    events::QueryEventEmitter, query_logger::QueryEventLogger, HealthChecker, PoolManager,
+    events::QueryEventEmitter, query_logger::QueryEventLogger, GroupUpstreamTable, HealthChecker,
+    PoolManager, Recursor,
 };
 use std::sync::Arc;
 use tracing::info;
@@ -33,13 +34,30 @@ pub(super) fn setup_health_checker(config: &Config) -> Option<Arc<HealthChecker>
     Some(checker)
 }
 
+pub(super) fn setup_recursor(config: &Config) -> Arc<Recursor> {
+    let recursor = &config.dns.recursor;
+    info!(
+        qname_minimization = recursor.qname_minimization,
+        case_randomization = recursor.case_randomization,
+        ns_cache_size = recursor.ns_cache_size,
+        "Recursor configured"
+    );
+    Arc::new(Recursor::new(recursor.clone()))
+}
+
 pub(super) async fn setup_pool_manager(
     config: &Config,
     health_checker: Option<Arc<HealthChecker>>,
     emitter: QueryEventEmitter,
+    recursor: &Arc<Recursor>,
+    group_upstreams: &Arc<GroupUpstreamTable>,
 ) -> anyhow::Result<Arc<PoolManager>> {
     Ok(Arc::new(
-        PoolManager::new(config.dns.pools.clone(), health_checker, emitter).await?,
+        PoolManager::new(config.dns.pools.clone(), health_checker, emitter)
+            .await?
+            .with_recursor(Arc::clone(recursor))
+            .with_group_upstreams(Arc::clone(group_upstreams))
+            .with_view_upstreams(&config.dns.views),
     ))
 }
 
@@ -1,4 +1,5 @@
-use ferrous_dns_domain::Config;
+use ferrous_dns_domain::{AcmeChallengeType, Config};
+use ferrous_dns_infrastructure::dns::dnssec::TrustAnchorStore;
 use ferrous_dns_infrastructure::dns::{HickoryDnsResolver, PoolManager};
 use std::sync::Arc;
 use tracing::info;
@@ -24,10 +25,22 @@ pub(super) fn build_resolver(
         config.dns.local_domain.clone(),
         config.dns.local_dns_server.is_some(),
     )
-    .with_local_dns_server(config.dns.local_dns_server.clone());
+    .with_local_dns_server(config.dns.local_dns_server.clone())
+    .with_forwarding_rules(repos.forwarding_rules.clone())
+    .with_local_authority(repos.local_authority.clone())
+    .with_view_authorities(repos.view_authorities.clone());
+
+    let acme_dns01 =
+        config.server.acme.enabled && config.server.acme.challenge == AcmeChallengeType::Dns01;
+    if config.dns.acme_dns.enabled || acme_dns01 {
+        resolver = resolver.with_acme_challenges(repos.acme_challenges.clone());
+    }
 
     if config.dns.dnssec_enabled {
-        resolver = resolver.with_dnssec_pool_manager(pool_manager_for_dnssec);
+        resolver = resolver
+            .with_dnssec_pool_manager(pool_manager_for_dnssec)
+            .with_trust_anchors(TrustAnchorStore::clone(&repos.trust_anchors))
+            .with_negative_trust_anchors(repos.negative_trust_anchors.clone());
     }
 
     info!(
@@ -37,6 +50,11 @@ pub(super) fn build_resolver(
         block_non_fqdn = config.dns.block_non_fqdn,
         local_domain = ?config.dns.local_domain,
         local_dns_server = ?config.dns.local_dns_server,
+        forwarding_rules = repos.forwarding_rules.len(),
+        local_zones = repos.local_authority.len(),
+        views = config.dns.views.len(),
+        acme_dns_zone = repos.acme_challenges.zone(),
+        negative_trust_anchors = repos.negative_trust_anchors.len(),
         "DNS resolver created with all features"
     );
 
@@ -0,0 +1,50 @@
+use ferrous_dns_application::ports::{ConfigRepository, DnsCachePort};
+use ferrous_dns_application::use_cases::{ApplyDynamicUpdateUseCase, CreateLocalRecordUseCase};
+use ferrous_dns_domain::Config;
+use ferrous_dns_infrastructure::dns::DynamicUpdateHandler;
+use ferrous_dns_infrastructure::repositories::TomlConfigRepository;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::info;
+
+use super::{DnsServices, Repositories};
+
+/// Builds the RFC 2136 UPDATE handler for the UDP/TCP listeners, or `None`
+/// when no zone accepts dynamic updates.
+pub fn build_dynamic_update_handler(
+    repos: &Repositories,
+    dns_services: &DnsServices,
+    config: Arc<RwLock<Config>>,
+    zones: &[String],
+    config_path: Option<&str>,
+) -> Option<Arc<DynamicUpdateHandler>> {
+    if zones.is_empty() {
+        return None;
+    }
+
+    let effective_path = config_path
+        .map(String::from)
+        .or_else(Config::get_config_path)
+        .unwrap_or_else(|| "ferrous-dns.toml".to_string());
+    let config_repo: Arc<dyn ConfigRepository> =
+        Arc::new(TomlConfigRepository::new(effective_path));
+    let create_local_record = Arc::new(
+        CreateLocalRecordUseCase::new(config.clone(), config_repo)
+            .with_ptr_registry(dns_services.ptr_registry.clone())
+            .with_dns_cache(Some(dns_services.cache.clone() as Arc<dyn DnsCachePort>)),
+    );
+    let use_case = Arc::new(
+        ApplyDynamicUpdateUseCase::new(config, create_local_record, zones)
+            .with_ptr_registry(dns_services.ptr_registry.clone()),
+    );
+
+    info!(
+        zones = ?zones,
+        tsig_keys = repos.tsig_keys.len(),
+        "Dynamic updates enabled"
+    );
+    Some(Arc::new(DynamicUpdateHandler::new(
+        repos.tsig_keys.clone(),
+        use_case,
+    )))
+}
@@ -0,0 +1,34 @@
+use ferrous_dns_api::MetricsState;
+use ferrous_dns_application::ports::{DnstapSink, UpstreamHealthPort};
+use ferrous_dns_application::services::JobMetrics;
+use ferrous_dns_domain::config::MetricsConfig;
+use std::sync::Arc;
+
+use super::{DnsServices, Repositories};
+
+/// Constructs [`MetricsState`] from the counters already owned by the DNS
+/// services, the query log writer and the job runner.
+pub fn build_metrics_state(
+    repos: &Repositories,
+    dns_services: &DnsServices,
+    upstream_health: Arc<dyn UpstreamHealthPort>,
+    jobs: Arc<JobMetrics>,
+    config: &MetricsConfig,
+) -> MetricsState {
+    MetricsState {
+        dns: dns_services.dns_metrics.clone(),
+        jobs,
+        cache: dns_services.cache.clone(),
+        upstream_health,
+        query_log: repos.query_log.clone(),
+        tunneling: dns_services.tunneling_target.clone(),
+        dga: dns_services.dga_target.clone(),
+        nxdomain_hijack: dns_services.nxdomain_hijack_target.clone(),
+        response_ip_filter: dns_services.response_ip_filter_target.clone(),
+        dnstap: dns_services
+            .dnstap
+            .clone()
+            .map(|writer| writer as Arc<dyn DnstapSink>),
+        bearer_token: config.bearer_token.as_deref().map(Arc::from),
+    }
+}
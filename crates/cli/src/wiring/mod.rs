@@ -1,11 +1,17 @@
+pub mod acme;
 pub mod app_state;
 pub mod dns;
+pub mod dynamic_update;
+pub mod metrics_state;
 pub mod pihole_state;
 pub mod repositories;
 pub mod use_cases;
 
+pub use acme::build_acme_renewal_job;
 pub use app_state::build_app_state;
 pub use dns::DnsServices;
+pub use dynamic_update::build_dynamic_update_handler;
+pub use metrics_state::build_metrics_state;
 pub use pihole_state::build_pihole_state;
 pub use repositories::Repositories;
 pub use use_cases::UseCases;
@@ -35,6 +35,7 @@ pub fn build_pihole_state(
         },
         blocking: PiholeBlockingState {
             block_filter_engine,
+            explain_filter_decision: use_cases.explain_filter_decision.clone(),
             get_managed_domains: use_cases.get_managed_domains.clone(),
             create_managed_domain: use_cases.create_managed_domain.clone(),
             update_managed_domain: use_cases.update_managed_domain.clone(),
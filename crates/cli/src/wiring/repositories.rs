@@ -1,12 +1,22 @@
-use ferrous_dns_application::ports::{ApiTokenRepository, SessionRepository, UserRepository};
 use ferrous_dns_application::ports::{
-    BlockFilterEnginePort, CustomServiceRepository, SafeSearchConfigRepository,
+    AcmeChallengeStore, AcmeRegistrationRepository, BlockFilterEnginePort, CustomServiceRepository,
+    DnsRewriteRuleRepository, DnsRewriteStore, ForwardingRuleRepository, ForwardingRuleStore,
+    GroupRepository, GroupUpstreamStore, LocalZoneRepository, LocalZoneStore,
+    NegativeTrustAnchorRepository, NegativeTrustAnchorStore, SafeSearchConfigRepository,
     SafeSearchEnginePort, ScheduleProfileRepository, ScheduleStatePort, ServiceCatalogPort,
+    TsigKeyRepository, TsigKeyStore,
 };
+use ferrous_dns_application::ports::{ApiTokenRepository, SessionRepository, UserRepository};
 use ferrous_dns_application::use_cases::custom_services::custom_to_definition;
-use ferrous_dns_domain::config::DatabaseConfig;
-use ferrous_dns_infrastructure::dns::{BlockFilterEngine, SafeSearchEnforcer};
+use ferrous_dns_domain::config::{AcmeDnsConfig, DatabaseConfig, DnsViewConfig, LocalZoneConfig};
+use ferrous_dns_infrastructure::dns::dnssec::{NegativeTrustAnchorTable, TrustAnchorStore};
+use ferrous_dns_infrastructure::dns::resolver::ForwardingRuleTable;
+use ferrous_dns_infrastructure::dns::{
+    AcmeChallengeTable, BlockFilterEngine, DnsRewriteTable, GroupUpstreamTable, LocalAuthority,
+    SafeSearchEnforcer, SqliteBlocklistSimulator, TsigKeyTable, ViewAuthorities,
+};
 use ferrous_dns_infrastructure::repositories::{
+    acme_registration_repository::SqliteAcmeRegistrationRepository,
     api_token_repository::SqliteApiTokenRepository,
     blocked_service_repository::SqliteBlockedServiceRepository,
     blocklist_repository::SqliteBlocklistRepository,
@@ -14,18 +24,25 @@ use ferrous_dns_infrastructure::repositories::{
     client_repository::SqliteClientRepository,
     client_subnet_repository::SqliteClientSubnetRepository,
     custom_service_repository::SqliteCustomServiceRepository,
-    group_repository::SqliteGroupRepository,
+    ddns_history_repository::SqliteDdnsHistoryRepository,
+    dns_rewrite_rule_repository::SqliteDnsRewriteRuleRepository,
+    forwarding_rule_repository::SqliteForwardingRuleRepository,
+    group_repository::SqliteGroupRepository, local_zone_repository::FileLocalZoneRepository,
     managed_domain_repository::SqliteManagedDomainRepository,
+    negative_trust_anchor_repository::SqliteNegativeTrustAnchorRepository,
     query_log_repository::SqliteQueryLogRepository,
     regex_filter_repository::SqliteRegexFilterRepository,
     schedule_profile_repository::SqliteScheduleProfileRepository,
     session_repository::SqliteSessionRepository,
     sqlite_safe_search_config_repository::SqliteSafeSearchConfigRepository,
-    user_repository::SqliteUserRepository, whitelist_repository::SqliteWhitelistRepository,
+    trust_anchor_repository::SqliteTrustAnchorRepository,
+    tsig_key_repository::SqliteTsigKeyRepository, user_repository::SqliteUserRepository,
+    whitelist_repository::SqliteWhitelistRepository,
     whitelist_source_repository::SqliteWhitelistSourceRepository,
 };
 use ferrous_dns_infrastructure::schedule::ScheduleStateStore;
 use ferrous_dns_infrastructure::service_catalog::{CompositeServiceCatalog, ServiceCatalog};
+use ferrous_dns_infrastructure::tls::{AcmeHttpChallenges, CertificateResolvers};
 use sqlx::{Row, SqlitePool};
 use std::sync::Arc;
 use tracing::{info, warn};
@@ -38,13 +55,33 @@ pub struct Repositories {
     pub whitelist_source: Arc<SqliteWhitelistSourceRepository>,
     pub client: Arc<SqliteClientRepository>,
     pub group: Arc<SqliteGroupRepository>,
+    pub group_upstreams: Arc<GroupUpstreamTable>,
     pub client_subnet: Arc<SqliteClientSubnetRepository>,
     pub managed_domain: Arc<SqliteManagedDomainRepository>,
+    pub forwarding_rule: Arc<SqliteForwardingRuleRepository>,
+    pub forwarding_rules: Arc<ForwardingRuleTable>,
+    pub dns_rewrite_rule: Arc<SqliteDnsRewriteRuleRepository>,
+    pub dns_rewrites: Arc<DnsRewriteTable>,
+    pub local_zone: Arc<FileLocalZoneRepository>,
+    pub local_authority: Arc<LocalAuthority>,
+    pub view_authorities: Arc<ViewAuthorities>,
+    pub trust_anchor: Arc<SqliteTrustAnchorRepository>,
+    pub trust_anchors: Arc<TrustAnchorStore>,
+    pub negative_trust_anchor: Arc<SqliteNegativeTrustAnchorRepository>,
+    pub negative_trust_anchors: Arc<NegativeTrustAnchorTable>,
+    pub tsig_key: Arc<SqliteTsigKeyRepository>,
+    pub tsig_keys: Arc<TsigKeyTable>,
+    pub ddns_history: Arc<SqliteDdnsHistoryRepository>,
+    pub acme_registration: Arc<SqliteAcmeRegistrationRepository>,
+    pub acme_challenges: Arc<AcmeChallengeTable>,
+    pub acme_http_challenges: Arc<AcmeHttpChallenges>,
+    pub tls_certificates: Arc<CertificateResolvers>,
     pub regex_filter: Arc<SqliteRegexFilterRepository>,
     pub blocked_service: Arc<SqliteBlockedServiceRepository>,
     pub custom_service: Arc<SqliteCustomServiceRepository>,
     pub service_catalog: Arc<dyn ServiceCatalogPort>,
     pub block_filter_engine: Arc<dyn BlockFilterEnginePort>,
+    pub blocklist_simulator: Arc<SqliteBlocklistSimulator>,
     pub safe_search_config: Arc<SqliteSafeSearchConfigRepository>,
     pub safe_search_engine: Arc<dyn SafeSearchEnginePort>,
     pub schedule_profile: Arc<dyn ScheduleProfileRepository>,
@@ -55,12 +92,16 @@ pub struct Repositories {
 }
 
 impl Repositories {
+    #[allow(clippy::too_many_arguments)]
     pub async fn new(
         write_pool: SqlitePool,
         query_log_pool: SqlitePool,
         read_pool: SqlitePool,
         db_config: &DatabaseConfig,
         blocking_enabled: bool,
+        local_zones: &[LocalZoneConfig],
+        views: &[DnsViewConfig],
+        acme_dns: &AcmeDnsConfig,
     ) -> Result<Self, ferrous_dns_domain::DomainError> {
         let blocklist = SqliteBlocklistRepository::load(write_pool.clone()).await?;
         let whitelist = SqliteWhitelistRepository::load(write_pool.clone()).await?;
@@ -80,13 +121,18 @@ impl Repositories {
 
         let schedule_state: Arc<dyn ScheduleStatePort> = Arc::new(ScheduleStateStore::new());
 
-        let block_filter_engine: Arc<dyn BlockFilterEnginePort> = BlockFilterEngine::new(
+        let engine = BlockFilterEngine::new(
             write_pool.clone(),
             default_group_id,
             schedule_state.clone(),
             blocking_enabled,
         )
         .await?;
+        let blocklist_simulator = Arc::new(SqliteBlocklistSimulator::new(
+            read_pool.clone(),
+            engine.live_index(),
+        )?);
+        let block_filter_engine: Arc<dyn BlockFilterEnginePort> = engine;
 
         let composite = CompositeServiceCatalog::new(ServiceCatalog::load());
         let service_catalog: Arc<dyn ServiceCatalogPort> = Arc::new(composite);
@@ -102,6 +148,82 @@ impl Repositories {
             }
         }
 
+        let forwarding_rule = Arc::new(SqliteForwardingRuleRepository::new(write_pool.clone()));
+        let forwarding_rules = Arc::new(ForwardingRuleTable::new());
+        match forwarding_rule.get_all().await {
+            Ok(rules) => forwarding_rules.replace_rules(&rules),
+            Err(e) => warn!(error = %e, "Failed to load forwarding rules"),
+        }
+
+        let group = Arc::new(SqliteGroupRepository::new(write_pool.clone()));
+        let group_upstreams = Arc::new(GroupUpstreamTable::new());
+        match group.get_all().await {
+            Ok(groups) => group_upstreams.replace_groups(&groups),
+            Err(e) => warn!(error = %e, "Failed to load group upstream pools"),
+        }
+
+        let dns_rewrite_rule = Arc::new(SqliteDnsRewriteRuleRepository::new(write_pool.clone()));
+        let dns_rewrites = Arc::new(DnsRewriteTable::new());
+        match dns_rewrite_rule.get_all().await {
+            Ok(rules) => dns_rewrites.replace_rules(&rules),
+            Err(e) => warn!(error = %e, "Failed to load DNS rewrite rules"),
+        }
+
+        let negative_trust_anchor =
+            Arc::new(SqliteNegativeTrustAnchorRepository::new(write_pool.clone()));
+        let negative_trust_anchors = Arc::new(NegativeTrustAnchorTable::new());
+        match negative_trust_anchor.get_all().await {
+            Ok(anchors) => negative_trust_anchors.replace_anchors(&anchors),
+            Err(e) => warn!(error = %e, "Failed to load negative trust anchors"),
+        }
+
+        let tsig_key = Arc::new(SqliteTsigKeyRepository::new(write_pool.clone()));
+        let tsig_keys = Arc::new(TsigKeyTable::new());
+        match tsig_key.get_all().await {
+            Ok(keys) => tsig_keys.replace_keys(&keys),
+            Err(e) => warn!(error = %e, "Failed to load TSIG keys"),
+        }
+
+        let acme_registration = Arc::new(SqliteAcmeRegistrationRepository::new(write_pool.clone()));
+        let acme_challenges = Arc::new(if acme_dns.enabled {
+            AcmeChallengeTable::new(
+                &acme_dns.normalized_zone(),
+                &acme_dns.normalized_nameserver(),
+                acme_dns.ttl,
+            )
+        } else {
+            AcmeChallengeTable::disabled()
+        });
+        if acme_dns.enabled {
+            match acme_registration.get_all().await {
+                Ok(registrations) => acme_challenges.replace_registrations(&registrations),
+                Err(e) => warn!(error = %e, "Failed to load ACME registrations"),
+            }
+        }
+
+        let local_zone = Arc::new(FileLocalZoneRepository::new(local_zones.to_vec()));
+        let local_authority = Arc::new(LocalAuthority::new());
+        match local_zone.get_all().await {
+            Ok(zones) => local_authority.replace_zones(&zones),
+            Err(e) => warn!(error = %e, "Failed to load local zones"),
+        }
+
+        let mut view_authorities = ViewAuthorities::default();
+        for (id, view) in views.iter().enumerate() {
+            if view.local_zones.is_empty() {
+                continue;
+            }
+            let authority = Arc::new(LocalAuthority::new());
+            match FileLocalZoneRepository::new(view.local_zones.clone())
+                .get_all()
+                .await
+            {
+                Ok(zones) => authority.replace_zones(&zones),
+                Err(e) => warn!(view = %view.name, error = %e, "Failed to load view zones"),
+            }
+            view_authorities.insert(id as u32, authority);
+        }
+
         let safe_search_config =
             Arc::new(SqliteSafeSearchConfigRepository::new(write_pool.clone()));
         let safe_search_engine: Arc<dyn SafeSearchEnginePort> = {
@@ -121,14 +243,34 @@ impl Repositories {
             whitelist: Arc::new(whitelist),
             whitelist_source: Arc::new(SqliteWhitelistSourceRepository::new(write_pool.clone())),
             client: Arc::new(SqliteClientRepository::new(write_pool.clone(), db_config)),
-            group: Arc::new(SqliteGroupRepository::new(write_pool.clone())),
+            group,
+            group_upstreams,
             client_subnet: Arc::new(SqliteClientSubnetRepository::new(write_pool.clone())),
             managed_domain: Arc::new(SqliteManagedDomainRepository::new(write_pool.clone())),
+            forwarding_rule,
+            forwarding_rules,
+            dns_rewrite_rule,
+            dns_rewrites,
+            local_zone,
+            local_authority,
+            view_authorities: Arc::new(view_authorities),
+            trust_anchor: Arc::new(SqliteTrustAnchorRepository::new(write_pool.clone())),
+            trust_anchors: Arc::new(TrustAnchorStore::empty()),
+            negative_trust_anchor,
+            negative_trust_anchors,
+            tsig_key,
+            tsig_keys,
+            ddns_history: Arc::new(SqliteDdnsHistoryRepository::new(write_pool.clone())),
+            acme_registration,
+            acme_challenges,
+            acme_http_challenges: Arc::new(AcmeHttpChallenges::new()),
+            tls_certificates: Arc::new(CertificateResolvers::new()),
             regex_filter: Arc::new(SqliteRegexFilterRepository::new(write_pool.clone())),
             blocked_service: Arc::new(SqliteBlockedServiceRepository::new(write_pool.clone())),
             custom_service,
             service_catalog,
             block_filter_engine,
+            blocklist_simulator,
             safe_search_config: safe_search_config.clone(),
             safe_search_engine,
             schedule_profile: Arc::new(SqliteScheduleProfileRepository::new(write_pool.clone())),
@@ -2,25 +2,36 @@ use super::Repositories;
 use ferrous_dns_application::services::SubnetMatcherService;
 use ferrous_dns_application::use_cases::{
     AssignClientGroupUseCase, AssignScheduleProfileUseCase, BlockServiceUseCase,
-    CleanupOldClientsUseCase, CleanupOldQueryLogsUseCase, CreateBlocklistSourceUseCase,
-    CreateClientSubnetUseCase, CreateCustomServiceUseCase, CreateGroupUseCase,
-    CreateManagedDomainUseCase, CreateManualClientUseCase, CreateRegexFilterUseCase,
-    CreateScheduleProfileUseCase, CreateWhitelistSourceUseCase, DeleteBlocklistSourceUseCase,
-    DeleteClientSubnetUseCase, DeleteClientUseCase, DeleteCustomServiceUseCase, DeleteGroupUseCase,
-    DeleteManagedDomainUseCase, DeleteRegexFilterUseCase, DeleteSafeSearchConfigsUseCase,
-    DeleteScheduleProfileUseCase, DeleteWhitelistSourceUseCase, GetBlockFilterStatsUseCase,
-    GetBlockedServicesUseCase, GetBlocklistSourcesUseCase, GetBlocklistUseCase,
+    CleanupExpiredNegativeTrustAnchorsUseCase, CleanupOldClientsUseCase,
+    CleanupOldQueryLogsUseCase, CreateBlocklistSourceUseCase, CreateClientSubnetUseCase,
+    CreateCustomServiceUseCase, CreateDnsRewriteRuleUseCase, CreateForwardingRuleUseCase,
+    CreateGroupUseCase, CreateManagedDomainUseCase, CreateManualClientUseCase,
+    CreateNegativeTrustAnchorUseCase, CreateRegexFilterUseCase, CreateScheduleProfileUseCase,
+    CreateTsigKeyUseCase, CreateWhitelistSourceUseCase, CreateZoneRecordUseCase,
+    DeleteBlocklistSourceUseCase, DeleteClientSubnetUseCase, DeleteClientUseCase,
+    DeleteCustomServiceUseCase, DeleteDnsRewriteRuleUseCase, DeleteForwardingRuleUseCase,
+    DeleteGroupUseCase, DeleteManagedDomainUseCase, DeleteNegativeTrustAnchorUseCase,
+    DeleteRegexFilterUseCase, DeleteSafeSearchConfigsUseCase, DeleteScheduleProfileUseCase,
+    DeleteTsigKeyUseCase, DeleteWhitelistSourceUseCase, DeleteZoneRecordUseCase,
+    ExplainFilterDecisionUseCase, GetBlockFilterStatsUseCase, GetBlockedServicesUseCase,
+    GetBlocklistSourceHitsUseCase, GetBlocklistSourcesUseCase, GetBlocklistUseCase,
     GetCacheStatsUseCase, GetClientSubnetsUseCase, GetClientsUseCase, GetCustomServicesUseCase,
-    GetGroupsUseCase, GetManagedDomainsUseCase, GetQueryRateUseCase, GetQueryStatsUseCase,
-    GetRecentQueriesUseCase, GetRegexFiltersUseCase, GetSafeSearchConfigsUseCase,
-    GetScheduleProfilesUseCase, GetServiceCatalogUseCase, GetTimelineUseCase,
-    GetTopAllowedDomainsUseCase, GetTopBlockedDomainsUseCase, GetTopClientsUseCase,
-    GetWhitelistSourcesUseCase, GetWhitelistUseCase, ManageTimeSlotsUseCase, SyncArpCacheUseCase,
+    GetDnsRewriteRulesUseCase, GetForwardingRulesUseCase, GetGroupsUseCase, GetLocalZonesUseCase,
+    GetManagedDomainsUseCase, GetNegativeTrustAnchorsUseCase, GetQueryRateUseCase,
+    GetQueryStatsUseCase, GetRecentQueriesUseCase, GetRegexFiltersUseCase,
+    GetSafeSearchConfigsUseCase, GetScheduleProfilesUseCase, GetServiceCatalogUseCase,
+    GetTimelineUseCase, GetTopAllowedDomainsUseCase, GetTopBlockedDomainsUseCase,
+    GetTopClientsUseCase, GetTrustAnchorsUseCase, GetTsigKeysUseCase, GetWhitelistSourcesUseCase,
+    GetWhitelistUseCase, LoadTrustAnchorsUseCase, ManageTimeSlotsUseCase,
+    RefreshTrustAnchorsUseCase, SimulateBlocklistUseCase, SyncArpCacheUseCase,
     SyncHostnamesUseCase, ToggleSafeSearchUseCase, UnblockServiceUseCase,
     UpdateBlocklistSourceUseCase, UpdateClientUseCase, UpdateCustomServiceUseCase,
+    UpdateDnsRewriteRuleUseCase, UpdateForwardingRuleUseCase, UpdateGroupBlockingModeUseCase,
     UpdateGroupUseCase, UpdateManagedDomainUseCase, UpdateRegexFilterUseCase,
-    UpdateScheduleProfileUseCase, UpdateWhitelistSourceUseCase,
+    UpdateScheduleProfileUseCase, UpdateWhitelistSourceUseCase, UpdateZoneRecordUseCase,
 };
+use ferrous_dns_domain::config::DnssecConfig;
+use ferrous_dns_infrastructure::dns::dnssec::DnskeyProbe;
 use ferrous_dns_infrastructure::dns::PoolManager;
 use ferrous_dns_infrastructure::system::{LinuxArpReader, PtrHostnameResolver};
 use std::sync::Arc;
@@ -32,6 +43,7 @@ pub struct UseCases {
     pub get_query_rate: Arc<GetQueryRateUseCase>,
     pub get_blocklist: Arc<GetBlocklistUseCase>,
     pub get_block_filter_stats: Arc<GetBlockFilterStatsUseCase>,
+    pub explain_filter_decision: Arc<ExplainFilterDecisionUseCase>,
     pub get_cache_stats: Arc<GetCacheStatsUseCase>,
     pub get_top_blocked_domains: Arc<GetTopBlockedDomainsUseCase>,
     pub get_top_allowed_domains: Arc<GetTopAllowedDomainsUseCase>,
@@ -44,6 +56,7 @@ pub struct UseCases {
     pub get_groups: Arc<GetGroupsUseCase>,
     pub create_group: Arc<CreateGroupUseCase>,
     pub update_group: Arc<UpdateGroupUseCase>,
+    pub update_group_blocking_mode: Arc<UpdateGroupBlockingModeUseCase>,
     pub delete_group: Arc<DeleteGroupUseCase>,
     pub assign_client_group: Arc<AssignClientGroupUseCase>,
     pub get_client_subnets: Arc<GetClientSubnetsUseCase>,
@@ -56,6 +69,8 @@ pub struct UseCases {
     pub create_blocklist_source: Arc<CreateBlocklistSourceUseCase>,
     pub update_blocklist_source: Arc<UpdateBlocklistSourceUseCase>,
     pub delete_blocklist_source: Arc<DeleteBlocklistSourceUseCase>,
+    pub get_blocklist_source_hits: Arc<GetBlocklistSourceHitsUseCase>,
+    pub simulate_blocklist: Arc<SimulateBlocklistUseCase>,
     pub get_whitelist: Arc<GetWhitelistUseCase>,
     pub get_whitelist_sources: Arc<GetWhitelistSourcesUseCase>,
     pub create_whitelist_source: Arc<CreateWhitelistSourceUseCase>,
@@ -65,6 +80,28 @@ pub struct UseCases {
     pub create_managed_domain: Arc<CreateManagedDomainUseCase>,
     pub update_managed_domain: Arc<UpdateManagedDomainUseCase>,
     pub delete_managed_domain: Arc<DeleteManagedDomainUseCase>,
+    pub get_forwarding_rules: Arc<GetForwardingRulesUseCase>,
+    pub create_forwarding_rule: Arc<CreateForwardingRuleUseCase>,
+    pub update_forwarding_rule: Arc<UpdateForwardingRuleUseCase>,
+    pub delete_forwarding_rule: Arc<DeleteForwardingRuleUseCase>,
+    pub get_dns_rewrite_rules: Arc<GetDnsRewriteRulesUseCase>,
+    pub create_dns_rewrite_rule: Arc<CreateDnsRewriteRuleUseCase>,
+    pub update_dns_rewrite_rule: Arc<UpdateDnsRewriteRuleUseCase>,
+    pub delete_dns_rewrite_rule: Arc<DeleteDnsRewriteRuleUseCase>,
+    pub get_local_zones: Arc<GetLocalZonesUseCase>,
+    pub create_zone_record: Arc<CreateZoneRecordUseCase>,
+    pub update_zone_record: Arc<UpdateZoneRecordUseCase>,
+    pub delete_zone_record: Arc<DeleteZoneRecordUseCase>,
+    pub get_trust_anchors: Arc<GetTrustAnchorsUseCase>,
+    pub get_negative_trust_anchors: Arc<GetNegativeTrustAnchorsUseCase>,
+    pub create_negative_trust_anchor: Arc<CreateNegativeTrustAnchorUseCase>,
+    pub delete_negative_trust_anchor: Arc<DeleteNegativeTrustAnchorUseCase>,
+    pub cleanup_negative_trust_anchors: Arc<CleanupExpiredNegativeTrustAnchorsUseCase>,
+    pub get_tsig_keys: Arc<GetTsigKeysUseCase>,
+    pub create_tsig_key: Arc<CreateTsigKeyUseCase>,
+    pub delete_tsig_key: Arc<DeleteTsigKeyUseCase>,
+    pub load_trust_anchors: Arc<LoadTrustAnchorsUseCase>,
+    pub refresh_trust_anchors: Arc<RefreshTrustAnchorsUseCase>,
     pub get_regex_filters: Arc<GetRegexFiltersUseCase>,
     pub create_regex_filter: Arc<CreateRegexFilterUseCase>,
     pub update_regex_filter: Arc<UpdateRegexFilterUseCase>,
@@ -93,6 +130,8 @@ impl UseCases {
     pub fn new(
         repos: &Repositories,
         pool_manager: Arc<PoolManager>,
+        dnssec_pool_manager: Arc<PoolManager>,
+        dnssec: &DnssecConfig,
         local_dns_server: Option<String>,
     ) -> Self {
         let arp_reader = Arc::new(LinuxArpReader::new());
@@ -114,6 +153,12 @@ impl UseCases {
             get_block_filter_stats: Arc::new(GetBlockFilterStatsUseCase::new(
                 repos.block_filter_engine.clone(),
             )),
+            explain_filter_decision: Arc::new(ExplainFilterDecisionUseCase::new(
+                repos.block_filter_engine.clone(),
+                repos.schedule_state.clone(),
+                repos.safe_search_engine.clone(),
+                repos.group.clone(),
+            )),
             get_cache_stats: Arc::new(GetCacheStatsUseCase::new(repos.query_log.clone())),
             get_top_blocked_domains: Arc::new(GetTopBlockedDomainsUseCase::new(
                 repos.query_log.clone(),
@@ -133,6 +178,10 @@ impl UseCases {
             get_groups: Arc::new(GetGroupsUseCase::new(repos.group.clone())),
             create_group: Arc::new(CreateGroupUseCase::new(repos.group.clone())),
             update_group: Arc::new(UpdateGroupUseCase::new(repos.group.clone())),
+            update_group_blocking_mode: Arc::new(UpdateGroupBlockingModeUseCase::new(
+                repos.group.clone(),
+                repos.block_filter_engine.clone(),
+            )),
             delete_group: Arc::new(DeleteGroupUseCase::new(repos.group.clone())),
             assign_client_group: Arc::new(AssignClientGroupUseCase::new(
                 repos.client.clone(),
@@ -176,6 +225,13 @@ impl UseCases {
             delete_blocklist_source: Arc::new(DeleteBlocklistSourceUseCase::new(
                 repos.blocklist_source.clone(),
             )),
+            get_blocklist_source_hits: Arc::new(GetBlocklistSourceHitsUseCase::new(
+                repos.query_log.clone(),
+            )),
+            simulate_blocklist: Arc::new(SimulateBlocklistUseCase::new(
+                repos.blocklist_simulator.clone(),
+                repos.group.clone(),
+            )),
             get_whitelist: Arc::new(GetWhitelistUseCase::new(repos.whitelist.clone())),
             get_whitelist_sources: Arc::new(GetWhitelistSourcesUseCase::new(
                 repos.whitelist_source.clone(),
@@ -208,6 +264,90 @@ impl UseCases {
                 repos.managed_domain.clone(),
                 repos.block_filter_engine.clone(),
             )),
+            get_forwarding_rules: Arc::new(GetForwardingRulesUseCase::new(
+                repos.forwarding_rule.clone(),
+            )),
+            create_forwarding_rule: Arc::new(CreateForwardingRuleUseCase::new(
+                repos.forwarding_rule.clone(),
+                repos.forwarding_rules.clone(),
+            )),
+            update_forwarding_rule: Arc::new(UpdateForwardingRuleUseCase::new(
+                repos.forwarding_rule.clone(),
+                repos.forwarding_rules.clone(),
+            )),
+            delete_forwarding_rule: Arc::new(DeleteForwardingRuleUseCase::new(
+                repos.forwarding_rule.clone(),
+                repos.forwarding_rules.clone(),
+            )),
+            get_dns_rewrite_rules: Arc::new(GetDnsRewriteRulesUseCase::new(
+                repos.dns_rewrite_rule.clone(),
+            )),
+            create_dns_rewrite_rule: Arc::new(CreateDnsRewriteRuleUseCase::new(
+                repos.dns_rewrite_rule.clone(),
+                repos.group.clone(),
+                repos.dns_rewrites.clone(),
+            )),
+            update_dns_rewrite_rule: Arc::new(UpdateDnsRewriteRuleUseCase::new(
+                repos.dns_rewrite_rule.clone(),
+                repos.group.clone(),
+                repos.dns_rewrites.clone(),
+            )),
+            delete_dns_rewrite_rule: Arc::new(DeleteDnsRewriteRuleUseCase::new(
+                repos.dns_rewrite_rule.clone(),
+                repos.dns_rewrites.clone(),
+            )),
+            get_local_zones: Arc::new(GetLocalZonesUseCase::new(repos.local_zone.clone())),
+            create_zone_record: Arc::new(CreateZoneRecordUseCase::new(
+                repos.local_zone.clone(),
+                repos.local_authority.clone(),
+            )),
+            update_zone_record: Arc::new(UpdateZoneRecordUseCase::new(
+                repos.local_zone.clone(),
+                repos.local_authority.clone(),
+            )),
+            delete_zone_record: Arc::new(DeleteZoneRecordUseCase::new(
+                repos.local_zone.clone(),
+                repos.local_authority.clone(),
+            )),
+            get_trust_anchors: Arc::new(GetTrustAnchorsUseCase::new(repos.trust_anchor.clone())),
+            load_trust_anchors: Arc::new(LoadTrustAnchorsUseCase::new(
+                repos.trust_anchor.clone(),
+                repos.trust_anchors.clone(),
+            )),
+            refresh_trust_anchors: Arc::new(
+                RefreshTrustAnchorsUseCase::new(
+                    repos.trust_anchor.clone(),
+                    repos.trust_anchors.clone(),
+                    Arc::new(DnskeyProbe::new(dnssec_pool_manager, 5000)),
+                )
+                .with_hold_down_days(dnssec.hold_down_days),
+            ),
+            get_negative_trust_anchors: Arc::new(GetNegativeTrustAnchorsUseCase::new(
+                repos.negative_trust_anchor.clone(),
+            )),
+            create_negative_trust_anchor: Arc::new(CreateNegativeTrustAnchorUseCase::new(
+                repos.negative_trust_anchor.clone(),
+                repos.negative_trust_anchors.clone(),
+            )),
+            delete_negative_trust_anchor: Arc::new(DeleteNegativeTrustAnchorUseCase::new(
+                repos.negative_trust_anchor.clone(),
+                repos.negative_trust_anchors.clone(),
+            )),
+            cleanup_negative_trust_anchors: Arc::new(
+                CleanupExpiredNegativeTrustAnchorsUseCase::new(
+                    repos.negative_trust_anchor.clone(),
+                    repos.negative_trust_anchors.clone(),
+                ),
+            ),
+            get_tsig_keys: Arc::new(GetTsigKeysUseCase::new(repos.tsig_key.clone())),
+            create_tsig_key: Arc::new(CreateTsigKeyUseCase::new(
+                repos.tsig_key.clone(),
+                repos.tsig_keys.clone(),
+            )),
+            delete_tsig_key: Arc::new(DeleteTsigKeyUseCase::new(
+                repos.tsig_key.clone(),
+                repos.tsig_keys.clone(),
+            )),
             get_regex_filters: Arc::new(GetRegexFiltersUseCase::new(repos.regex_filter.clone())),
             create_regex_filter: Arc::new(CreateRegexFilterUseCase::new(
                 repos.regex_filter.clone(),
@@ -0,0 +1,203 @@
+use super::ConfigError;
+use crate::entities::forwarding_rule::ForwardingRule;
+use serde::{Deserialize, Serialize};
+
+/// How the CA checks control of the certificate's names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum AcmeChallengeType {
+    /// `http://<name>/.well-known/acme-challenge/<token>`, answered on the
+    /// web port. The CA connects to port 80.
+    #[default]
+    #[serde(rename = "http-01")]
+    Http01,
+    /// A TXT record at `_acme-challenge.<name>`, answered by this server.
+    /// Required for wildcard names.
+    #[serde(rename = "dns-01")]
+    Dns01,
+}
+
+/// Built-in ACME client that obtains and renews the certificate used by
+/// DoT, DoH and the web UI.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AcmeConfig {
+    /// Master switch — disabled by default.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// ACME directory of the CA.
+    #[serde(default = "default_directory_url")]
+    pub directory_url: String,
+
+    /// Contact address registered with the account. Optional.
+    #[serde(default)]
+    pub email: Option<String>,
+
+    /// Names on the certificate. The first one becomes the subject.
+    #[serde(default)]
+    pub domains: Vec<String>,
+
+    #[serde(default)]
+    pub challenge: AcmeChallengeType,
+
+    /// Agrees to the CA's terms of service. Must be set to order a
+    /// certificate.
+    #[serde(default)]
+    pub accept_terms: bool,
+
+    /// PEM file holding the account key. Created on first use.
+    #[serde(default = "default_account_key_path")]
+    pub account_key_path: String,
+
+    /// Extra PEM root trusted for the directory connection, for private
+    /// CAs such as step-ca or Pebble.
+    #[serde(default)]
+    pub ca_cert_path: Option<String>,
+
+    /// Renew once the certificate expires within this many days.
+    #[serde(default = "default_renew_before_days")]
+    pub renew_before_days: u32,
+
+    /// How often the certificate is checked, in seconds. The first check
+    /// runs at startup.
+    #[serde(default = "default_check_interval_secs")]
+    pub check_interval_secs: u64,
+}
+
+impl AcmeConfig {
+    /// The configured names, lowercase and without a trailing dot.
+    pub fn normalized_domains(&self) -> Vec<String> {
+        self.domains
+            .iter()
+            .map(|d| ForwardingRule::normalize_domain(d))
+            .collect()
+    }
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.domains.is_empty() {
+            return Err(ConfigError::Validation(
+                "server.acme.domains must list at least one name".to_string(),
+            ));
+        }
+        for domain in self.normalized_domains() {
+            let base = match domain.strip_prefix("*.") {
+                Some(base) if self.challenge == AcmeChallengeType::Dns01 => base,
+                Some(_) => {
+                    return Err(ConfigError::Validation(format!(
+                        "Wildcard name '{}' requires server.acme.challenge = \"dns-01\"",
+                        domain
+                    )))
+                }
+                None => &domain,
+            };
+            ForwardingRule::validate_domain(base).map_err(|e| {
+                ConfigError::Validation(format!("Invalid server.acme domain '{}': {}", domain, e))
+            })?;
+        }
+        if !self.directory_url.starts_with("https://") {
+            return Err(ConfigError::Validation(format!(
+                "server.acme.directory_url must be an https URL, got '{}'",
+                self.directory_url
+            )));
+        }
+        if !self.accept_terms {
+            return Err(ConfigError::Validation(
+                "server.acme.accept_terms must be true to order certificates".to_string(),
+            ));
+        }
+        if self.renew_before_days == 0 {
+            return Err(ConfigError::Validation(
+                "server.acme.renew_before_days must be at least 1".to_string(),
+            ));
+        }
+        if self.check_interval_secs == 0 {
+            return Err(ConfigError::Validation(
+                "server.acme.check_interval_secs must be at least 1".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Default for AcmeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            directory_url: default_directory_url(),
+            email: None,
+            domains: Vec::new(),
+            challenge: AcmeChallengeType::default(),
+            accept_terms: false,
+            account_key_path: default_account_key_path(),
+            ca_cert_path: None,
+            renew_before_days: default_renew_before_days(),
+            check_interval_secs: default_check_interval_secs(),
+        }
+    }
+}
+
+fn default_directory_url() -> String {
+    "https://acme-v02.api.letsencrypt.org/directory".to_string()
+}
+
+fn default_account_key_path() -> String {
+    "/data/acme_account.pem".to_string()
+}
+
+fn default_renew_before_days() -> u32 {
+    30
+}
+
+fn default_check_interval_secs() -> u64 {
+    43_200
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_domains(domains: &[&str]) -> AcmeConfig {
+        AcmeConfig {
+            enabled: true,
+            domains: domains.iter().map(|d| d.to_string()).collect(),
+            accept_terms: true,
+            ..AcmeConfig::default()
+        }
+    }
+
+    #[test]
+    fn deserializes_challenge_names() {
+        let config: AcmeConfig = toml::from_str("challenge = \"dns-01\"").unwrap();
+        assert_eq!(config.challenge, AcmeChallengeType::Dns01);
+        assert_eq!(config.renew_before_days, 30);
+
+        let config: AcmeConfig = toml::from_str("").unwrap();
+        assert_eq!(config.challenge, AcmeChallengeType::Http01);
+        assert!(!config.enabled);
+    }
+
+    #[test]
+    fn wildcard_requires_dns01() {
+        let mut config = with_domains(&["*.Example.com.", "example.com"]);
+        assert!(config.validate().is_err());
+
+        config.challenge = AcmeChallengeType::Dns01;
+        assert!(config.validate().is_ok());
+        assert_eq!(
+            config.normalized_domains(),
+            vec!["*.example.com", "example.com"]
+        );
+    }
+
+    #[test]
+    fn rejects_missing_domains_terms_and_plain_http_directory() {
+        assert!(with_domains(&[]).validate().is_err());
+
+        let mut config = with_domains(&["dns.example.com"]);
+        config.accept_terms = false;
+        assert!(config.validate().is_err());
+
+        let mut config = with_domains(&["dns.example.com"]);
+        config.directory_url = "http://localhost:14000/dir".to_string();
+        assert!(config.validate().is_err());
+    }
+}
@@ -0,0 +1,143 @@
+use super::ConfigError;
+use crate::entities::forwarding_rule::ForwardingRule;
+use ipnetwork::IpNetwork;
+use serde::{Deserialize, Serialize};
+
+/// acme-dns compatible responder for ACME DNS-01 challenges.
+///
+/// Hosts point `_acme-challenge.<name>` CNAMEs at a registration under
+/// `zone`, and their ACME client publishes the challenge TXT value through
+/// the `/register` and `/update` endpoints.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AcmeDnsConfig {
+    /// Master switch — disabled by default.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Zone served authoritatively for the challenge records, e.g.
+    /// `acme.example.com`. Public CAs need it delegated to this server.
+    #[serde(default)]
+    pub zone: String,
+
+    /// Name server published in the zone's SOA and NS records. Defaults to
+    /// `ns.<zone>`.
+    #[serde(default)]
+    pub nameserver: Option<String>,
+
+    /// TTL of the challenge TXT records, in seconds.
+    #[serde(default = "default_ttl")]
+    pub ttl: u32,
+
+    /// Networks allowed to call `/register`. Empty allows any source.
+    #[serde(default)]
+    pub register_allowed_from: Vec<String>,
+
+    /// Take the client address from `X-Forwarded-For` / `X-Real-IP` when
+    /// checking allowed sources. Only enable behind a trusted proxy.
+    #[serde(default)]
+    pub trust_proxy_headers: bool,
+}
+
+impl AcmeDnsConfig {
+    /// The configured zone, lowercase and without a trailing dot.
+    pub fn normalized_zone(&self) -> String {
+        ForwardingRule::normalize_domain(&self.zone)
+    }
+
+    /// The SOA/NS name server, lowercase and without a trailing dot.
+    pub fn normalized_nameserver(&self) -> String {
+        match &self.nameserver {
+            Some(ns) => ForwardingRule::normalize_domain(ns),
+            None => format!("ns.{}", self.normalized_zone()),
+        }
+    }
+
+    pub fn parse_register_allowed_from(&self) -> Result<Vec<IpNetwork>, ConfigError> {
+        self.register_allowed_from
+            .iter()
+            .map(|cidr| {
+                cidr.trim().parse::<IpNetwork>().map_err(|e| {
+                    ConfigError::Validation(format!(
+                        "Invalid acme_dns.register_allowed_from entry '{}': {}",
+                        cidr, e
+                    ))
+                })
+            })
+            .collect()
+    }
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let zone = self.normalized_zone();
+        ForwardingRule::validate_domain(&zone).map_err(|e| {
+            ConfigError::Validation(format!("Invalid acme_dns.zone '{}': {}", self.zone, e))
+        })?;
+        ForwardingRule::validate_domain(&self.normalized_nameserver())
+            .map_err(|e| ConfigError::Validation(format!("Invalid acme_dns.nameserver: {}", e)))?;
+        if self.ttl == 0 {
+            return Err(ConfigError::Validation(
+                "acme_dns.ttl must be at least 1".to_string(),
+            ));
+        }
+        self.parse_register_allowed_from()?;
+        Ok(())
+    }
+}
+
+impl Default for AcmeDnsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            zone: String::new(),
+            nameserver: None,
+            ttl: default_ttl(),
+            register_allowed_from: Vec::new(),
+            trust_proxy_headers: false,
+        }
+    }
+}
+
+fn default_ttl() -> u32 {
+    10
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_zone(zone: &str) -> AcmeDnsConfig {
+        AcmeDnsConfig {
+            enabled: true,
+            zone: zone.to_string(),
+            ..AcmeDnsConfig::default()
+        }
+    }
+
+    #[test]
+    fn deserializes_empty_toml_with_defaults() {
+        let config: AcmeDnsConfig = toml::from_str("").unwrap();
+        assert!(!config.enabled);
+        assert_eq!(config.ttl, 10);
+        assert!(config.register_allowed_from.is_empty());
+        assert!(!config.trust_proxy_headers);
+    }
+
+    #[test]
+    fn normalizes_zone_and_derives_nameserver() {
+        let config = with_zone("ACME.Example.com.");
+        assert_eq!(config.normalized_zone(), "acme.example.com");
+        assert_eq!(config.normalized_nameserver(), "ns.acme.example.com");
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn rejects_missing_zone_and_bad_networks() {
+        assert!(with_zone("").validate().is_err());
+
+        let mut config = with_zone("acme.example.com");
+        config.register_allowed_from = vec!["10.0.0.0/8".into(), "not-a-cidr".into()];
+        assert!(config.validate().is_err());
+
+        config.register_allowed_from = vec!["10.0.0.0/8".into(), "2001:db8::/32".into()];
+        assert_eq!(config.parse_register_allowed_from().unwrap().len(), 2);
+    }
+}
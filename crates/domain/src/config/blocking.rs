@@ -1,4 +1,7 @@
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct BlockingConfig {
@@ -9,6 +12,22 @@ pub struct BlockingConfig {
 
     #[serde(default)]
     pub whitelist: Vec<String>,
+
+    /// How blocked queries are answered. Groups may override this.
+    #[serde(default)]
+    pub mode: BlockingMode,
+
+    /// IPv4 address returned for blocked A queries in `custom_ip` mode.
+    #[serde(default)]
+    pub custom_ipv4: Option<Ipv4Addr>,
+
+    /// IPv6 address returned for blocked AAAA queries in `custom_ip` mode.
+    #[serde(default)]
+    pub custom_ipv6: Option<Ipv6Addr>,
+
+    /// TTL in seconds of synthesized answers for blocked queries.
+    #[serde(default = "default_blocked_ttl")]
+    pub blocked_ttl: u32,
 }
 
 impl Default for BlockingConfig {
@@ -17,6 +36,207 @@ impl Default for BlockingConfig {
             enabled: true,
             custom_blocked: vec![],
             whitelist: vec![],
+            mode: BlockingMode::default(),
+            custom_ipv4: None,
+            custom_ipv6: None,
+            blocked_ttl: default_blocked_ttl(),
+        }
+    }
+}
+
+impl BlockingConfig {
+    /// Global response used for groups without an override.
+    pub fn response(&self) -> BlockingResponse {
+        BlockingResponse {
+            mode: self.mode,
+            ipv4: self.custom_ipv4,
+            ipv6: self.custom_ipv6,
+        }
+    }
+}
+
+fn default_blocked_ttl() -> u32 {
+    10
+}
+
+/// Shape of the DNS answer sent for a blocked query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BlockingMode {
+    /// REFUSED with no answer.
+    #[default]
+    Refused,
+    /// NXDOMAIN — the name does not exist.
+    Nxdomain,
+    /// NOERROR with an empty answer section.
+    Nodata,
+    /// `0.0.0.0` for A and `::` for AAAA; NODATA for other types.
+    NullIp,
+    /// Configured addresses for A/AAAA; NODATA for other types or when
+    /// no address of the queried family is configured.
+    CustomIp,
+}
+
+impl BlockingMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Refused => "refused",
+            Self::Nxdomain => "nxdomain",
+            Self::Nodata => "nodata",
+            Self::NullIp => "null_ip",
+            Self::CustomIp => "custom_ip",
+        }
+    }
+}
+
+impl fmt::Display for BlockingMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for BlockingMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "refused" => Ok(Self::Refused),
+            "nxdomain" => Ok(Self::Nxdomain),
+            "nodata" => Ok(Self::Nodata),
+            "null_ip" => Ok(Self::NullIp),
+            "custom_ip" => Ok(Self::CustomIp),
+            other => Err(format!(
+                "Invalid blocking mode '{other}': expected refused, nxdomain, nodata, null_ip or custom_ip"
+            )),
+        }
+    }
+}
+
+/// Resolved blocking response for a group: the mode plus the addresses
+/// used by `custom_ip`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BlockingResponse {
+    pub mode: BlockingMode,
+    pub ipv4: Option<Ipv4Addr>,
+    pub ipv6: Option<Ipv6Addr>,
+}
+
+impl BlockingResponse {
+    /// Address to answer an A query with, or `None` for an empty answer.
+    pub fn answer_ipv4(&self) -> Option<Ipv4Addr> {
+        match self.mode {
+            BlockingMode::NullIp => Some(Ipv4Addr::UNSPECIFIED),
+            BlockingMode::CustomIp => self.ipv4,
+            _ => None,
+        }
+    }
+
+    /// Address to answer an AAAA query with, or `None` for an empty answer.
+    pub fn answer_ipv6(&self) -> Option<Ipv6Addr> {
+        match self.mode {
+            BlockingMode::NullIp => Some(Ipv6Addr::UNSPECIFIED),
+            BlockingMode::CustomIp => self.ipv6,
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_mode_is_refused() {
+        let config = BlockingConfig::default();
+        assert_eq!(config.mode, BlockingMode::Refused);
+        assert_eq!(config.blocked_ttl, 10);
+        assert!(config.custom_ipv4.is_none());
+        assert!(config.custom_ipv6.is_none());
+    }
+
+    #[test]
+    fn deserializes_existing_config_without_mode() {
+        let toml = r#"
+            enabled = true
+            custom_blocked = ["ads.example.com"]
+        "#;
+        let config: BlockingConfig = toml::from_str(toml).unwrap();
+        assert_eq!(config.mode, BlockingMode::Refused);
+        assert_eq!(config.custom_blocked, vec!["ads.example.com"]);
+    }
+
+    #[test]
+    fn deserializes_custom_ip_mode() {
+        let toml = r#"
+            enabled = true
+            mode = "custom_ip"
+            custom_ipv4 = "192.168.1.10"
+            custom_ipv6 = "fd00::10"
+            blocked_ttl = 300
+        "#;
+        let config: BlockingConfig = toml::from_str(toml).unwrap();
+        assert_eq!(config.mode, BlockingMode::CustomIp);
+        assert_eq!(config.custom_ipv4, Some(Ipv4Addr::new(192, 168, 1, 10)));
+        assert_eq!(config.custom_ipv6, Some("fd00::10".parse().unwrap()));
+        assert_eq!(config.blocked_ttl, 300);
+    }
+
+    #[test]
+    fn rejects_unknown_mode() {
+        let toml = r#"
+            enabled = true
+            mode = "sinkhole"
+        "#;
+        assert!(toml::from_str::<BlockingConfig>(toml).is_err());
+    }
+
+    #[test]
+    fn mode_string_roundtrip() {
+        for mode in [
+            BlockingMode::Refused,
+            BlockingMode::Nxdomain,
+            BlockingMode::Nodata,
+            BlockingMode::NullIp,
+            BlockingMode::CustomIp,
+        ] {
+            assert_eq!(mode.as_str().parse::<BlockingMode>().unwrap(), mode);
+        }
+    }
+
+    #[test]
+    fn null_ip_answers_unspecified_addresses() {
+        let response = BlockingResponse {
+            mode: BlockingMode::NullIp,
+            ..Default::default()
+        };
+        assert_eq!(response.answer_ipv4(), Some(Ipv4Addr::UNSPECIFIED));
+        assert_eq!(response.answer_ipv6(), Some(Ipv6Addr::UNSPECIFIED));
+    }
+
+    #[test]
+    fn custom_ip_without_family_answers_nothing() {
+        let response = BlockingResponse {
+            mode: BlockingMode::CustomIp,
+            ipv4: Some(Ipv4Addr::new(10, 0, 0, 1)),
+            ipv6: None,
+        };
+        assert_eq!(response.answer_ipv4(), Some(Ipv4Addr::new(10, 0, 0, 1)));
+        assert_eq!(response.answer_ipv6(), None);
+    }
+
+    #[test]
+    fn non_ip_modes_answer_nothing() {
+        for mode in [
+            BlockingMode::Refused,
+            BlockingMode::Nxdomain,
+            BlockingMode::Nodata,
+        ] {
+            let response = BlockingResponse {
+                mode,
+                ipv4: Some(Ipv4Addr::new(10, 0, 0, 1)),
+                ipv6: None,
+            };
+            assert_eq!(response.answer_ipv4(), None);
         }
     }
 }
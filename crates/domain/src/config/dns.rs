@@ -1,15 +1,21 @@
 use serde::{Deserialize, Serialize};
 
+use super::acme_dns::AcmeDnsConfig;
 use super::dga_detection::DgaDetectionConfig;
 use super::dns_cookies::DnsCookiesConfig;
+use super::dnssec::DnssecConfig;
+use super::dnstap::DnstapConfig;
 use super::health::HealthCheckConfig;
-use super::local_records::LocalDnsRecord;
+use super::local_records::{DynamicUpdateRecord, LocalDnsRecord};
+use super::local_zones::LocalZoneConfig;
 use super::nxdomain_hijack::NxdomainHijackConfig;
 use super::rate_limit::RateLimitConfig;
+use super::recursor::RecursorConfig;
 use super::response_ip_filter::ResponseIpFilterConfig;
 use super::tunneling::TunnelingDetectionConfig;
 use super::upstream::UpstreamPool;
 use super::upstream::UpstreamStrategy;
+use super::views::DnsViewConfig;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct DnsConfig {
@@ -28,6 +34,10 @@ pub struct DnsConfig {
     #[serde(default = "default_false")]
     pub dnssec_enabled: bool,
 
+    /// Trust anchors and their rollover tracking (`[dns.dnssec]`).
+    #[serde(default)]
+    pub dnssec: DnssecConfig,
+
     #[serde(default)]
     pub default_strategy: UpstreamStrategy,
 
@@ -37,6 +47,10 @@ pub struct DnsConfig {
     #[serde(default)]
     pub health_check: HealthCheckConfig,
 
+    /// Iterative resolution settings for pools using the `Recursive` strategy.
+    #[serde(default)]
+    pub recursor: RecursorConfig,
+
     #[serde(default = "default_cache_max_entries")]
     pub cache_max_entries: usize,
     #[serde(default = "default_cache_eviction_strategy")]
@@ -78,6 +92,11 @@ pub struct DnsConfig {
     #[serde(default = "default_cache_max_ttl")]
     pub cache_max_ttl: u32,
 
+    /// Answer names inside cached, DNSSEC-validated NSEC/NSEC3 ranges with
+    /// a synthesised NXDOMAIN/NODATA (RFC 8198). Needs `dnssec_enabled`.
+    #[serde(default = "default_false")]
+    pub cache_aggressive_nsec: bool,
+
     #[serde(default = "default_true")]
     pub block_private_ptr: bool,
 
@@ -93,6 +112,33 @@ pub struct DnsConfig {
     #[serde(default)]
     pub local_records: Vec<LocalDnsRecord>,
 
+    /// Zones that accept TSIG-signed RFC 2136 UPDATE messages on the UDP and
+    /// TCP listeners. Forward zones change local records; reverse zones
+    /// (`in-addr.arpa`, `ip6.arpa`) change PTR mappings. Empty disables
+    /// dynamic updates.
+    #[serde(default)]
+    pub dynamic_update_zones: Vec<String>,
+
+    /// `TXT` and `DHCID` records written by dynamic updates to forward
+    /// zones. Managed by the update path; not served.
+    #[serde(default)]
+    pub dynamic_update_records: Vec<DynamicUpdateRecord>,
+
+    /// Take the dyndns2 client address (used when a request names no
+    /// address) from `X-Forwarded-For` / `X-Real-IP`. Only enable behind a
+    /// trusted proxy.
+    #[serde(default)]
+    pub ddns_trust_proxy_headers: bool,
+
+    /// Zones answered authoritatively from master files (`[[dns.local_zones]]`).
+    #[serde(default)]
+    pub local_zones: Vec<LocalZoneConfig>,
+
+    /// Split-horizon views (`[[dns.views]]`), matched by client subnet or
+    /// group in file order.
+    #[serde(default)]
+    pub views: Vec<DnsViewConfig>,
+
     /// Whether DNS rebinding protection is enabled. When `true`, responses that
     /// resolve a public domain to a private/RFC1918 IP are blocked.
     /// Defaults to `true` — opt-out rather than opt-in for security-sensitive features.
@@ -128,6 +174,14 @@ pub struct DnsConfig {
     /// DNS Cookies anti-spoofing configuration (RFC 7873).
     #[serde(default)]
     pub dns_cookies: DnsCookiesConfig,
+
+    /// dnstap export of client and forwarder messages.
+    #[serde(default)]
+    pub dnstap: DnstapConfig,
+
+    /// acme-dns compatible DNS-01 challenge responder.
+    #[serde(default)]
+    pub acme_dns: AcmeDnsConfig,
 }
 
 impl Default for DnsConfig {
@@ -138,9 +192,11 @@ impl Default for DnsConfig {
             cache_enabled: true,
             cache_ttl: default_cache_ttl(),
             dnssec_enabled: false,
+            dnssec: DnssecConfig::default(),
             default_strategy: UpstreamStrategy::Parallel,
             pools: vec![],
             health_check: HealthCheckConfig::default(),
+            recursor: RecursorConfig::default(),
             cache_max_entries: default_cache_max_entries(),
             cache_eviction_strategy: default_cache_eviction_strategy(),
             cache_optimistic_refresh: default_cache_optimistic_refresh(),
@@ -158,11 +214,17 @@ impl Default for DnsConfig {
             cache_eviction_sample_size: default_cache_eviction_sample_size(),
             cache_min_ttl: default_cache_min_ttl(),
             cache_max_ttl: default_cache_max_ttl(),
+            cache_aggressive_nsec: false,
             block_private_ptr: true,
             block_non_fqdn: false,
             local_domain: None,
             local_dns_server: None,
             local_records: vec![],
+            dynamic_update_zones: vec![],
+            dynamic_update_records: vec![],
+            ddns_trust_proxy_headers: false,
+            local_zones: vec![],
+            views: vec![],
             rebinding_protection_enabled: true,
             rebinding_allowlist: vec![],
             rate_limit: RateLimitConfig::default(),
@@ -171,6 +233,8 @@ impl Default for DnsConfig {
             response_ip_filter: ResponseIpFilterConfig::default(),
             dga_detection: DgaDetectionConfig::default(),
             dns_cookies: DnsCookiesConfig::default(),
+            dnstap: DnstapConfig::default(),
+            acme_dns: AcmeDnsConfig::default(),
         }
     }
 }
@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+
+/// Trust anchor settings for DNSSEC validation (`[dns.dnssec]`).
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DnssecConfig {
+    /// Extra trust anchors, one DNSKEY or DS record per entry in zone file
+    /// presentation format, e.g. `"corp.internal. DS 4711 13 2 3A1F…"`.
+    /// These are static: rollover tracking never changes them.
+    #[serde(default)]
+    pub trust_anchors: Vec<String>,
+
+    /// File of DNSKEY/DS records in the same format, such as the
+    /// `root.key` written by `unbound-anchor`. Its zones are tracked with
+    /// RFC 5011 and replace the built-in root anchor when they include `.`.
+    #[serde(default)]
+    pub trust_anchor_file: Option<String>,
+
+    /// Follow KSK rollovers of the root and `trust_anchor_file` zones
+    /// automatically (RFC 5011).
+    #[serde(default = "default_true")]
+    pub auto_update: bool,
+
+    /// Seconds between DNSKEY probes of the tracked zones.
+    #[serde(default = "default_refresh_interval")]
+    pub refresh_interval: u64,
+
+    /// Days a new key must stay published before it is trusted, and a
+    /// revoked key is remembered before it is dropped.
+    #[serde(default = "default_hold_down_days")]
+    pub hold_down_days: u32,
+}
+
+impl Default for DnssecConfig {
+    fn default() -> Self {
+        Self {
+            trust_anchors: Vec::new(),
+            trust_anchor_file: None,
+            auto_update: default_true(),
+            refresh_interval: default_refresh_interval(),
+            hold_down_days: default_hold_down_days(),
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_refresh_interval() -> u64 {
+    43_200
+}
+
+fn default_hold_down_days() -> u32 {
+    30
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_empty_toml_with_defaults() {
+        let config: DnssecConfig = toml::from_str("").unwrap();
+        assert!(config.trust_anchors.is_empty());
+        assert!(config.trust_anchor_file.is_none());
+        assert!(config.auto_update);
+        assert_eq!(config.refresh_interval, 43_200);
+        assert_eq!(config.hold_down_days, 30);
+    }
+
+    #[test]
+    fn deserializes_anchor_list() {
+        let config: DnssecConfig = toml::from_str(
+            r#"
+            trust_anchors = ["corp.internal. DS 4711 13 2 3A1F"]
+            trust_anchor_file = "/var/lib/ferrous-dns/root.key"
+            auto_update = false
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.trust_anchors.len(), 1);
+        assert_eq!(
+            config.trust_anchor_file.as_deref(),
+            Some("/var/lib/ferrous-dns/root.key")
+        );
+        assert!(!config.auto_update);
+    }
+}
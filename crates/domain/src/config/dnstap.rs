@@ -0,0 +1,133 @@
+use super::ConfigError;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// dnstap (Frame Streams + protobuf) export of client and forwarder traffic.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DnstapConfig {
+    /// Master switch — disabled by default.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Where frames are written: `unix:/path/to.sock`, `tcp:host:port` or
+    /// `file:/path/to/file`.
+    #[serde(default = "default_output")]
+    pub output: String,
+
+    /// Sent as the dnstap `identity` field. Omitted when unset.
+    #[serde(default)]
+    pub identity: Option<String>,
+
+    /// Emit CLIENT_QUERY / CLIENT_RESPONSE messages.
+    #[serde(default = "default_true")]
+    pub client_messages: bool,
+
+    /// Emit FORWARDER_QUERY / FORWARDER_RESPONSE messages.
+    #[serde(default = "default_true")]
+    pub forwarder_messages: bool,
+
+    /// Encoded frames buffered while the writer is busy or reconnecting.
+    /// Frames beyond this are dropped and counted.
+    #[serde(default = "default_queue_capacity")]
+    pub queue_capacity: usize,
+}
+
+/// Parsed form of [`DnstapConfig::output`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DnstapOutput {
+    Unix(PathBuf),
+    Tcp(String),
+    File(PathBuf),
+}
+
+impl DnstapConfig {
+    pub fn parse_output(&self) -> Result<DnstapOutput, ConfigError> {
+        let invalid = || {
+            ConfigError::Validation(format!(
+                "Invalid dnstap output '{}': expected unix:<path>, tcp:<host:port> or file:<path>",
+                self.output
+            ))
+        };
+        let (scheme, target) = self.output.split_once(':').ok_or_else(invalid)?;
+        if target.is_empty() {
+            return Err(invalid());
+        }
+        match scheme {
+            "unix" => Ok(DnstapOutput::Unix(PathBuf::from(target))),
+            "tcp" => Ok(DnstapOutput::Tcp(target.to_string())),
+            "file" => Ok(DnstapOutput::File(PathBuf::from(target))),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl Default for DnstapConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            output: default_output(),
+            identity: None,
+            client_messages: true,
+            forwarder_messages: true,
+            queue_capacity: default_queue_capacity(),
+        }
+    }
+}
+
+fn default_output() -> String {
+    "unix:/var/run/dnstap.sock".to_string()
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_queue_capacity() -> usize {
+    8_192
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_output(output: &str) -> DnstapConfig {
+        DnstapConfig {
+            output: output.to_string(),
+            ..DnstapConfig::default()
+        }
+    }
+
+    #[test]
+    fn deserializes_empty_toml_with_defaults() {
+        let config: DnstapConfig = toml::from_str("").unwrap();
+        assert!(!config.enabled);
+        assert!(config.client_messages);
+        assert!(config.forwarder_messages);
+        assert_eq!(config.queue_capacity, 8_192);
+        assert_eq!(
+            config.parse_output().unwrap(),
+            DnstapOutput::Unix(PathBuf::from("/var/run/dnstap.sock"))
+        );
+    }
+
+    #[test]
+    fn parses_each_output_kind() {
+        assert_eq!(
+            with_output("tcp:10.0.0.5:6000").parse_output().unwrap(),
+            DnstapOutput::Tcp("10.0.0.5:6000".to_string())
+        );
+        assert_eq!(
+            with_output("file:/var/log/dnstap.fstrm")
+                .parse_output()
+                .unwrap(),
+            DnstapOutput::File(PathBuf::from("/var/log/dnstap.fstrm"))
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_or_empty_outputs() {
+        assert!(with_output("udp:10.0.0.5:6000").parse_output().is_err());
+        assert!(with_output("file:").parse_output().is_err());
+        assert!(with_output("/var/run/dnstap.sock").parse_output().is_err());
+    }
+}
@@ -1,6 +1,11 @@
+use crate::dns_record::RecordType;
+use crate::value_objects::dynamic_update::UpdateData;
 use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+use std::net::IpAddr;
+use std::sync::Arc;
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub struct LocalDnsRecord {
     pub hostname: String,
 
@@ -29,4 +34,72 @@ impl LocalDnsRecord {
     pub fn ttl_or_default(&self) -> u32 {
         self.ttl.unwrap_or(300)
     }
+
+    /// The record type and address, or `None` unless this is an A record
+    /// with an IPv4 address or an AAAA record with an IPv6 address.
+    pub fn address(&self) -> Option<(RecordType, IpAddr)> {
+        let ip: IpAddr = self.ip.parse().ok()?;
+        match (self.record_type.to_ascii_uppercase().as_str(), ip) {
+            ("A", IpAddr::V4(_)) => Some((RecordType::A, ip)),
+            ("AAAA", IpAddr::V6(_)) => Some((RecordType::AAAA, ip)),
+            _ => None,
+        }
+    }
+}
+
+/// A `TXT` or `DHCID` record written by an RFC 2136 update. These are not
+/// served; they are kept so that later updates from the same client can
+/// check prerequisites against them.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DynamicUpdateRecord {
+    /// Owner name, lowercase, without the trailing dot.
+    pub name: String,
+
+    pub record_type: String,
+
+    /// RDATA in the RFC 3597 generic form, `\# <length> <hex>`.
+    pub data: String,
+
+    #[serde(default)]
+    pub ttl: Option<u32>,
+}
+
+impl DynamicUpdateRecord {
+    pub fn new(name: &str, record_type: RecordType, rdata: &[u8], ttl: u32) -> Self {
+        let mut data = format!("\\# {} ", rdata.len());
+        for byte in rdata {
+            let _ = write!(data, "{byte:02x}");
+        }
+        Self {
+            name: name.to_string(),
+            record_type: record_type.as_str().to_string(),
+            data: data.trim_end().to_string(),
+            ttl: Some(ttl),
+        }
+    }
+
+    /// The record as update data, or `None` if it is not a well-formed
+    /// `TXT` or `DHCID` record.
+    pub fn update_data(&self) -> Option<UpdateData> {
+        let record_type: RecordType = self.record_type.parse().ok()?;
+        if !matches!(record_type, RecordType::TXT | RecordType::DHCID) {
+            return None;
+        }
+        let mut parts = self.data.split_whitespace();
+        if parts.next()? != "\\#" {
+            return None;
+        }
+        let len: usize = parts.next()?.parse().ok()?;
+        let hex: String = parts.collect();
+        if hex.len() != len * 2 {
+            return None;
+        }
+        let rdata = (0..len)
+            .map(|i| u8::from_str_radix(hex.get(i * 2..i * 2 + 2)?, 16).ok())
+            .collect::<Option<Vec<u8>>>()?;
+        Some(UpdateData::Rdata {
+            record_type,
+            rdata: Arc::from(rdata),
+        })
+    }
 }
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// An authoritative zone served from an RFC 1035 master file.
+///
+/// `origin` is the zone apex and the initial `$ORIGIN` of the file;
+/// relative `$INCLUDE` paths resolve against the file's directory.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LocalZoneConfig {
+    pub origin: String,
+
+    pub file: String,
+}
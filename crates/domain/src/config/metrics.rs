@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+/// Configuration for the Prometheus text-format `/metrics` endpoint.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct MetricsConfig {
+    /// Whether `/metrics` is served.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Serves `/metrics` on its own plain-HTTP listener on this port instead
+    /// of the web server.
+    #[serde(default)]
+    pub port: Option<u16>,
+
+    /// When set, scrapes must send `Authorization: Bearer <token>`.
+    #[serde(default)]
+    pub bearer_token: Option<String>,
+}
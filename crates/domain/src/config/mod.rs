@@ -1,39 +1,55 @@
+pub mod acme;
+pub mod acme_dns;
 pub mod auth;
 pub mod blocking;
 pub mod database;
 pub mod dga_detection;
 pub mod dns;
 pub mod dns_cookies;
+pub mod dnssec;
+pub mod dnstap;
 pub mod encrypted_dns;
 pub mod errors;
 pub mod health;
 pub mod local_records;
+pub mod local_zones;
 pub mod logging;
+pub mod metrics;
 pub mod nxdomain_hijack;
 pub mod rate_limit;
+pub mod recursor;
 pub mod response_ip_filter;
 pub mod root;
 pub mod server;
 pub mod tunneling;
 pub mod upstream;
+pub mod views;
 pub mod web_tls;
 
+pub use acme::{AcmeChallengeType, AcmeConfig};
+pub use acme_dns::AcmeDnsConfig;
 pub use auth::{AdminConfig, AuthConfig};
-pub use blocking::BlockingConfig;
+pub use blocking::{BlockingConfig, BlockingMode, BlockingResponse};
 pub use database::DatabaseConfig;
 pub use dga_detection::{DgaDetectionAction, DgaDetectionConfig};
 pub use dns::DnsConfig;
 pub use dns_cookies::DnsCookiesConfig;
+pub use dnssec::DnssecConfig;
+pub use dnstap::{DnstapConfig, DnstapOutput};
 pub use encrypted_dns::EncryptedDnsConfig;
 pub use errors::ConfigError;
 pub use health::HealthCheckConfig;
-pub use local_records::LocalDnsRecord;
+pub use local_records::{DynamicUpdateRecord, LocalDnsRecord};
+pub use local_zones::LocalZoneConfig;
 pub use logging::LoggingConfig;
+pub use metrics::MetricsConfig;
 pub use nxdomain_hijack::{NxdomainHijackAction, NxdomainHijackConfig};
 pub use rate_limit::RateLimitConfig;
+pub use recursor::RecursorConfig;
 pub use response_ip_filter::{ResponseIpFilterAction, ResponseIpFilterConfig};
 pub use root::{CliOverrides, Config};
 pub use server::ServerConfig;
 pub use tunneling::{TunnelingAction, TunnelingDetectionConfig};
 pub use upstream::{UpstreamPool, UpstreamStrategy};
+pub use views::DnsViewConfig;
 pub use web_tls::WebTlsConfig;
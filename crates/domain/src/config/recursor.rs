@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+
+/// Iterative resolver settings used by pools with `strategy = "Recursive"`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RecursorConfig {
+    /// Reveal only one more label to each zone while walking delegations
+    /// (QNAME minimisation, RFC 9156).
+    #[serde(default = "default_true")]
+    pub qname_minimization: bool,
+
+    /// Randomise the letter case of outgoing query names and drop answers
+    /// that do not echo it back exactly ("DNS 0x20").
+    #[serde(default = "default_true")]
+    pub case_randomization: bool,
+
+    /// Maximum number of zone cuts kept in the NS/glue cache.
+    #[serde(default = "default_ns_cache_size")]
+    pub ns_cache_size: usize,
+
+    /// Upper bound on outgoing queries spent on a single client query,
+    /// including lookups of out-of-bailiwick nameserver addresses.
+    #[serde(default = "default_max_queries")]
+    pub max_queries: u32,
+}
+
+impl Default for RecursorConfig {
+    fn default() -> Self {
+        Self {
+            qname_minimization: default_true(),
+            case_randomization: default_true(),
+            ns_cache_size: default_ns_cache_size(),
+            max_queries: default_max_queries(),
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_ns_cache_size() -> usize {
+    10_000
+}
+
+fn default_max_queries() -> u32 {
+    64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_empty_toml_with_defaults() {
+        let config: RecursorConfig = toml::from_str("").unwrap();
+        assert!(config.qname_minimization);
+        assert!(config.case_randomization);
+        assert_eq!(config.ns_cache_size, 10_000);
+        assert_eq!(config.max_queries, 64);
+    }
+
+    #[test]
+    fn deserializes_partial_toml_preserves_defaults() {
+        let config: RecursorConfig = toml::from_str("case_randomization = false").unwrap();
+        assert!(config.qname_minimization);
+        assert!(!config.case_randomization);
+    }
+}
@@ -7,7 +7,8 @@ use super::dns::DnsConfig;
 use super::errors::ConfigError;
 use super::logging::LoggingConfig;
 use super::server::ServerConfig;
-use super::upstream::UpstreamPool;
+use super::upstream::{UpstreamPool, UpstreamStrategy};
+use crate::entities::forwarding_rule::ForwardingRule;
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct Config {
@@ -90,7 +91,7 @@ impl Config {
         }
 
         for pool in &self.dns.pools {
-            if pool.servers.is_empty() {
+            if pool.servers.is_empty() && pool.strategy != UpstreamStrategy::Recursive {
                 return Err(ConfigError::Validation(format!(
                     "Pool '{}' has no servers",
                     pool.name
@@ -98,6 +99,35 @@ impl Config {
             }
         }
 
+        for (i, view) in self.dns.views.iter().enumerate() {
+            view.validate(&self.dns.pools)?;
+            if self.dns.views[..i].iter().any(|v| v.name == view.name) {
+                return Err(ConfigError::Validation(format!(
+                    "Duplicate view name '{}'",
+                    view.name
+                )));
+            }
+        }
+
+        for zone in &self.dns.dynamic_update_zones {
+            let normalized = ForwardingRule::normalize_domain(zone);
+            ForwardingRule::validate_domain(&normalized).map_err(|e| {
+                ConfigError::Validation(format!("Invalid dynamic update zone '{}': {}", zone, e))
+            })?;
+        }
+
+        if self.dns.dnstap.enabled {
+            self.dns.dnstap.parse_output()?;
+        }
+
+        if self.dns.acme_dns.enabled {
+            self.dns.acme_dns.validate()?;
+        }
+
+        if self.server.acme.enabled {
+            self.server.acme.validate()?;
+        }
+
         Ok(())
     }
 
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 
+use super::acme::AcmeConfig;
 use super::encrypted_dns::EncryptedDnsConfig;
+use super::metrics::MetricsConfig;
 use super::web_tls::WebTlsConfig;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -29,6 +31,13 @@ pub struct ServerConfig {
 
     #[serde(default)]
     pub web_tls: WebTlsConfig,
+
+    #[serde(default)]
+    pub metrics: MetricsConfig,
+
+    /// Built-in ACME certificate provisioning for the TLS listeners.
+    #[serde(default)]
+    pub acme: AcmeConfig,
 }
 
 fn default_cors_origins() -> Vec<String> {
@@ -46,6 +55,38 @@ impl Default for ServerConfig {
             proxy_protocol_enabled: false,
             pihole_compat: false,
             web_tls: WebTlsConfig::default(),
+            metrics: MetricsConfig::default(),
+            acme: AcmeConfig::default(),
+        }
+    }
+}
+
+impl ServerConfig {
+    /// Certificate/key file pairs of the enabled TLS listeners, without
+    /// duplicates. Falls back to the web UI paths when none is enabled.
+    pub fn tls_certificate_paths(&self) -> Vec<(String, String)> {
+        let mut paths = Vec::new();
+        if self.web_tls.enabled {
+            paths.push((
+                self.web_tls.tls_cert_path.clone(),
+                self.web_tls.tls_key_path.clone(),
+            ));
+        }
+        if self.encrypted_dns.dot_enabled || self.encrypted_dns.doh_enabled {
+            let pair = (
+                self.encrypted_dns.tls_cert_path.clone(),
+                self.encrypted_dns.tls_key_path.clone(),
+            );
+            if !paths.contains(&pair) {
+                paths.push(pair);
+            }
+        }
+        if paths.is_empty() {
+            paths.push((
+                self.web_tls.tls_cert_path.clone(),
+                self.web_tls.tls_key_path.clone(),
+            ));
         }
+        paths
     }
 }
@@ -9,6 +9,9 @@ pub struct UpstreamPool {
     #[serde(default = "default_priority")]
     pub priority: u8,
 
+    /// Upstream endpoints. For `Recursive` pools these override the built-in
+    /// root hints and may be left empty.
+    #[serde(default)]
     pub servers: Vec<String>,
 
     #[serde(default)]
@@ -23,6 +26,9 @@ pub enum UpstreamStrategy {
     Failover,
 
     Balanced,
+
+    /// Iterative resolution from the root servers instead of forwarding.
+    Recursive,
 }
 
 impl UpstreamStrategy {
@@ -31,6 +37,7 @@ impl UpstreamStrategy {
             Self::Parallel => "parallel",
             Self::Failover => "failover",
             Self::Balanced => "balanced",
+            Self::Recursive => "recursive",
         }
     }
 }
@@ -41,6 +48,7 @@ impl std::fmt::Display for UpstreamStrategy {
             Self::Parallel => f.write_str("Parallel"),
             Self::Failover => f.write_str("Failover"),
             Self::Balanced => f.write_str("Balanced"),
+            Self::Recursive => f.write_str("Recursive"),
         }
     }
 }
@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+use super::errors::ConfigError;
+use super::local_records::LocalDnsRecord;
+use super::local_zones::LocalZoneConfig;
+use super::upstream::UpstreamPool;
+
+/// A split-horizon view (`[[dns.views]]`): clients in `subnets` or in one of
+/// `groups` see the view's own records, zones and upstreams instead of the
+/// global ones. Views are evaluated in file order and the first match wins.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DnsViewConfig {
+    pub name: String,
+
+    /// Client networks in CIDR notation.
+    #[serde(default)]
+    pub subnets: Vec<String>,
+
+    /// Client group ids.
+    #[serde(default)]
+    pub groups: Vec<i64>,
+
+    /// Names answered only inside this view. A name defined here hides
+    /// every other record type for it from the view's clients.
+    #[serde(default)]
+    pub local_records: Vec<LocalDnsRecord>,
+
+    /// Zones answered authoritatively inside this view, ahead of the
+    /// global `[[dns.local_zones]]`.
+    #[serde(default)]
+    pub local_zones: Vec<LocalZoneConfig>,
+
+    /// Added to the global `rebinding_allowlist` for this view's clients.
+    #[serde(default)]
+    pub rebinding_allowlist: Vec<String>,
+
+    /// Pools tried in order for this view's clients instead of the global
+    /// pools or the group's own pools. Empty keeps the usual selection.
+    #[serde(default)]
+    pub upstream_pools: Vec<String>,
+}
+
+impl DnsViewConfig {
+    pub fn validate(&self, pools: &[UpstreamPool]) -> Result<(), ConfigError> {
+        let invalid =
+            |reason: String| ConfigError::Validation(format!("View '{}': {}", self.name, reason));
+
+        if self.name.trim().is_empty() {
+            return Err(ConfigError::Validation(
+                "View name cannot be empty".to_string(),
+            ));
+        }
+        if self.subnets.is_empty() && self.groups.is_empty() {
+            return Err(invalid("needs at least one subnet or group".to_string()));
+        }
+        for subnet in &self.subnets {
+            if subnet.parse::<ipnetwork::IpNetwork>().is_err() {
+                return Err(invalid(format!("invalid subnet '{}'", subnet)));
+            }
+        }
+        for record in &self.local_records {
+            if record.address().is_none() {
+                return Err(invalid(format!(
+                    "invalid local record '{}' ({} {})",
+                    record.hostname, record.record_type, record.ip
+                )));
+            }
+        }
+        for name in &self.upstream_pools {
+            if !pools.iter().any(|p| p.name == *name) {
+                return Err(invalid(format!("unknown upstream pool '{}'", name)));
+            }
+        }
+        Ok(())
+    }
+}
@@ -24,6 +24,7 @@ pub enum RecordType {
     TLSA,
     SSHFP,
     DNAME,
+    DHCID,
 
     RRSIG,
     NSEC,
@@ -50,6 +51,8 @@ pub enum RecordType {
 macro_rules! impl_record_type_conversions {
     ( $( ($variant:ident, $str:literal, $code:literal) ),* $(,)? ) => {
         impl RecordType {
+            pub const ALL: &'static [RecordType] = &[ $( RecordType::$variant, )* ];
+
             pub fn as_str(&self) -> &'static str {
                 match self {
                     $( RecordType::$variant => $str, )*
@@ -105,6 +108,7 @@ impl_record_type_conversions! {
     (RRSIG,      "RRSIG",      46),
     (NSEC,       "NSEC",       47),
     (DNSKEY,     "DNSKEY",     48),
+    (DHCID,      "DHCID",      49),
     (NSEC3,      "NSEC3",      50),
     (NSEC3PARAM, "NSEC3PARAM", 51),
     (TLSA,       "TLSA",       52),
@@ -135,6 +139,7 @@ impl RecordType {
             | RecordType::SVCB
             | RecordType::HTTPS
             | RecordType::DNAME
+            | RecordType::DHCID
             | RecordType::ANAME => RecordCategory::Advanced,
 
             RecordType::DS
@@ -186,7 +191,7 @@ impl RecordType {
         use RecordType::*;
         match category {
             RecordCategory::Basic => vec![A, AAAA, CNAME, MX, TXT, PTR],
-            RecordCategory::Advanced => vec![SRV, SOA, NS, NAPTR, SVCB, HTTPS, DNAME, ANAME],
+            RecordCategory::Advanced => vec![SRV, SOA, NS, NAPTR, SVCB, HTTPS, DNAME, DHCID, ANAME],
             RecordCategory::Dnssec => {
                 vec![DS, DNSKEY, RRSIG, NSEC, NSEC3, NSEC3PARAM, CDS, CDNSKEY]
             }
@@ -0,0 +1,86 @@
+use ipnetwork::IpNetwork;
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+/// Length of a DNS-01 key authorization digest: base64url SHA-256, no padding.
+pub const ACME_TXT_LEN: usize = 43;
+
+/// TXT values kept per registration. Two, like acme-dns, so a certificate
+/// covering both `example.com` and `*.example.com` can validate at once.
+pub const ACME_TXT_KEEP: usize = 2;
+
+/// An acme-dns registration: credentials allowed to publish DNS-01
+/// challenge values at `<subdomain>.<challenge zone>`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AcmeRegistration {
+    pub id: Option<i64>,
+    pub username: Arc<str>,
+    /// SHA-256 of the password, hex encoded. The password itself is only
+    /// returned once, by `/register`.
+    #[serde(skip_serializing)]
+    pub password_hash: Arc<str>,
+    pub subdomain: Arc<str>,
+    /// Networks allowed to call `/update`. Empty allows any source.
+    pub allow_from: Vec<Arc<str>>,
+    /// Current challenge values, newest first.
+    pub txt: Vec<Arc<str>>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+impl AcmeRegistration {
+    /// Whether `ip` may update this registration.
+    pub fn allows(&self, ip: Option<IpAddr>) -> bool {
+        if self.allow_from.is_empty() {
+            return true;
+        }
+        let Some(ip) = ip else {
+            return false;
+        };
+        self.allow_from
+            .iter()
+            .filter_map(|cidr| cidr.parse::<IpNetwork>().ok())
+            .any(|network| network.contains(ip))
+    }
+
+    /// Parses `allowfrom` entries. Bare addresses are taken as single hosts.
+    pub fn parse_allow_from(entries: &[String]) -> Result<Vec<Arc<str>>, String> {
+        entries
+            .iter()
+            .map(|entry| {
+                let entry = entry.trim();
+                let network = match entry.parse::<IpAddr>() {
+                    Ok(ip) => IpNetwork::from(ip),
+                    Err(_) => entry
+                        .parse::<IpNetwork>()
+                        .map_err(|_| format!("invalid network '{}'", entry))?,
+                };
+                Ok(Arc::from(network.to_string()))
+            })
+            .collect()
+    }
+
+    /// A challenge value is the 43-character base64url digest an ACME
+    /// client is told to publish.
+    pub fn validate_txt(txt: &str) -> Result<(), String> {
+        if txt.len() != ACME_TXT_LEN {
+            return Err(format!("TXT value must be {} characters", ACME_TXT_LEN));
+        }
+        if !txt
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+        {
+            return Err("TXT value must be base64url".to_string());
+        }
+        Ok(())
+    }
+
+    /// `txt` with `value` pushed to the front, keeping [`ACME_TXT_KEEP`].
+    pub fn rotated_txt(&self, value: &str) -> Vec<Arc<str>> {
+        std::iter::once(Arc::from(value))
+            .chain(self.txt.iter().filter(|t| t.as_ref() != value).cloned())
+            .take(ACME_TXT_KEEP)
+            .collect()
+    }
+}
@@ -29,6 +29,9 @@ pub enum BlockSource {
     ResponseIpFilter,
     /// Blocked by DGA (Domain Generation Algorithm) detection.
     DgaDetection,
+    /// Answered by a user-defined DNS rewrite rule instead of being resolved.
+    /// Logged with `blocked = false`.
+    DnsRewrite,
 }
 
 impl BlockSource {
@@ -45,6 +48,7 @@ impl BlockSource {
             BlockSource::NxdomainHijack => "nxdomain_hijack",
             BlockSource::ResponseIpFilter => "response_ip_filter",
             BlockSource::DgaDetection => "dga_detection",
+            BlockSource::DnsRewrite => "dns_rewrite",
         }
     }
 
@@ -61,6 +65,7 @@ impl BlockSource {
             8 => Some(BlockSource::NxdomainHijack),
             9 => Some(BlockSource::ResponseIpFilter),
             10 => Some(BlockSource::DgaDetection),
+            11 => Some(BlockSource::DnsRewrite),
             _ => None,
         }
     }
@@ -78,6 +83,7 @@ impl BlockSource {
             BlockSource::NxdomainHijack => 8,
             BlockSource::ResponseIpFilter => 9,
             BlockSource::DgaDetection => 10,
+            BlockSource::DnsRewrite => 11,
         }
     }
 }
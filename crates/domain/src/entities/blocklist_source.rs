@@ -1,3 +1,4 @@
+use super::list_source_status::ListSourceStatus;
 use crate::value_objects::validators;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -10,8 +11,12 @@ pub struct BlocklistSource {
     pub group_ids: Vec<i64>,
     pub comment: Option<Arc<str>>,
     pub enabled: bool,
+    /// Matches are logged as would-block instead of being blocked.
+    pub observe_only: bool,
     pub created_at: Option<String>,
     pub updated_at: Option<String>,
+    /// Last download of `url`; `None` until the list has been fetched.
+    pub status: Option<ListSourceStatus>,
 }
 
 impl BlocklistSource {
@@ -30,8 +35,10 @@ impl BlocklistSource {
             group_ids,
             comment,
             enabled,
+            observe_only: false,
             created_at: None,
             updated_at: None,
+            status: None,
         }
     }
 
@@ -47,3 +54,20 @@ impl BlocklistSource {
         validators::validate_comment(comment)
     }
 }
+
+/// Blocked queries attributed to a blocklist source over the last 24 hours,
+/// 7 days and 30 days.
+///
+/// A query blocked by entries in several sources counts for each of them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlocklistSourceHits {
+    pub source_id: i64,
+    pub name: Arc<str>,
+    pub enabled: bool,
+    pub hits_24h: u64,
+    pub hits_7d: u64,
+    pub hits_30d: u64,
+    pub unique_domains_24h: u64,
+    pub unique_domains_7d: u64,
+    pub unique_domains_30d: u64,
+}
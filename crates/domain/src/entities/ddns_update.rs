@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Return code of a dyndns2 update for one hostname, as sent back to the
+/// client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DdnsResult {
+    /// The records now point at the requested address.
+    Good,
+    /// The records already pointed at the requested address.
+    NoChg,
+    /// Missing or unknown API token.
+    BadAuth,
+    /// The hostname is not a fully qualified domain name.
+    NotFqdn,
+    /// The hostname is outside every zone that accepts updates.
+    NoHost,
+    /// Too many hostnames in one request.
+    NumHost,
+    /// The records could not be written.
+    DnsErr,
+}
+
+impl DdnsResult {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Good => "good",
+            Self::NoChg => "nochg",
+            Self::BadAuth => "badauth",
+            Self::NotFqdn => "notfqdn",
+            Self::NoHost => "nohost",
+            Self::NumHost => "numhost",
+            Self::DnsErr => "dnserr",
+        }
+    }
+}
+
+impl std::str::FromStr for DdnsResult {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "good" => Ok(Self::Good),
+            "nochg" => Ok(Self::NoChg),
+            "badauth" => Ok(Self::BadAuth),
+            "notfqdn" => Ok(Self::NotFqdn),
+            "nohost" => Ok(Self::NoHost),
+            "numhost" => Ok(Self::NumHost),
+            "dnserr" => Ok(Self::DnsErr),
+            _ => Err(()),
+        }
+    }
+}
+
+/// One dyndns2 update attempt for one hostname, as kept in the history.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DdnsUpdate {
+    pub id: Option<i64>,
+    pub hostname: Arc<str>,
+    /// Requested addresses, comma-separated, when the request got that far.
+    pub ip: Option<Arc<str>>,
+    pub result: DdnsResult,
+    /// API token that authenticated the request.
+    pub token_id: Option<i64>,
+    pub client_ip: Option<Arc<str>>,
+    pub created_at: Option<String>,
+}
+
+impl DdnsUpdate {
+    /// The response line for this hostname (`good 203.0.113.7`, `badauth`).
+    pub fn response_line(&self) -> String {
+        match (&self.result, &self.ip) {
+            (DdnsResult::Good | DdnsResult::NoChg, Some(ip)) => {
+                format!("{} {}", self.result.as_str(), ip)
+            }
+            _ => self.result.as_str().to_string(),
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::net::IpAddr;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Response code returned by an rcode-only rewrite.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DnsRewriteRcode {
+    /// Empty `NOERROR` answer (NODATA).
+    NoError,
+    NxDomain,
+    Refused,
+}
+
+impl DnsRewriteRcode {
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            DnsRewriteRcode::NoError => "NOERROR",
+            DnsRewriteRcode::NxDomain => "NXDOMAIN",
+            DnsRewriteRcode::Refused => "REFUSED",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_uppercase().as_str() {
+            "NOERROR" => Some(DnsRewriteRcode::NoError),
+            "NXDOMAIN" => Some(DnsRewriteRcode::NxDomain),
+            "REFUSED" => Some(DnsRewriteRcode::Refused),
+            _ => None,
+        }
+    }
+}
+
+/// Fixed answer served by a rewrite rule, written in AdGuard's
+/// `$dnsrewrite` value syntax.
+///
+/// Accepted forms are the full `RCODE;TYPE;VALUE` triple
+/// (`NOERROR;A;10.0.0.5`, `NOERROR;CNAME;lan.example`, `NXDOMAIN;;`) and the
+/// shorthands `10.0.0.5`, `::1`, `lan.example` and `REFUSED`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DnsRewriteAnswer {
+    /// `A` or `AAAA` record, depending on the address family.
+    Address(IpAddr),
+    /// Answers with the addresses of another name.
+    Cname(Arc<str>),
+    /// Answers with a bare response code and no records.
+    Rcode(DnsRewriteRcode),
+}
+
+impl DnsRewriteAnswer {
+    pub fn type_str(&self) -> &'static str {
+        match self {
+            DnsRewriteAnswer::Address(IpAddr::V4(_)) => "A",
+            DnsRewriteAnswer::Address(IpAddr::V6(_)) => "AAAA",
+            DnsRewriteAnswer::Cname(_) => "CNAME",
+            DnsRewriteAnswer::Rcode(_) => "",
+        }
+    }
+
+    fn parse_cname(target: &str) -> Result<Self, String> {
+        let target = DnsRewriteRule::normalize_domain(target);
+        DnsRewriteRule::validate_domain(&target)
+            .map_err(|e| format!("Invalid CNAME target: {e}"))?;
+        Ok(DnsRewriteAnswer::Cname(Arc::from(target)))
+    }
+
+    fn parse_triple(rcode: &str, rtype: &str, value: &str) -> Result<Self, String> {
+        let rcode = DnsRewriteRcode::parse(rcode.trim())
+            .ok_or_else(|| format!("Unsupported response code '{}'", rcode.trim()))?;
+        let rtype = rtype.trim().to_ascii_uppercase();
+        let value = value.trim();
+
+        if rcode != DnsRewriteRcode::NoError || (rtype.is_empty() && value.is_empty()) {
+            if !rtype.is_empty() || !value.is_empty() {
+                return Err(format!("{} rewrites cannot carry a record", rcode.to_str()));
+            }
+            return Ok(DnsRewriteAnswer::Rcode(rcode));
+        }
+
+        match rtype.as_str() {
+            "A" => match value.parse::<IpAddr>() {
+                Ok(ip @ IpAddr::V4(_)) => Ok(DnsRewriteAnswer::Address(ip)),
+                _ => Err(format!("'{value}' is not an IPv4 address")),
+            },
+            "AAAA" => match value.parse::<IpAddr>() {
+                Ok(ip @ IpAddr::V6(_)) => Ok(DnsRewriteAnswer::Address(ip)),
+                _ => Err(format!("'{value}' is not an IPv6 address")),
+            },
+            "CNAME" => Self::parse_cname(value),
+            other => Err(format!(
+                "Unsupported record type '{other}' (only A, AAAA and CNAME are supported)"
+            )),
+        }
+    }
+}
+
+impl FromStr for DnsRewriteAnswer {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err("Rewrite cannot be empty".to_string());
+        }
+
+        let parts: Vec<&str> = s.split(';').collect();
+        match parts.as_slice() {
+            [rcode, rtype, value] => Self::parse_triple(rcode, rtype, value),
+            [single] => {
+                if let Ok(ip) = single.parse::<IpAddr>() {
+                    return Ok(DnsRewriteAnswer::Address(ip));
+                }
+                if let Some(rcode) = DnsRewriteRcode::parse(single) {
+                    return Ok(DnsRewriteAnswer::Rcode(rcode));
+                }
+                Self::parse_cname(single)
+            }
+            _ => Err(format!(
+                "Invalid rewrite '{s}' (expected RCODE;TYPE;VALUE, an IP address or a hostname)"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for DnsRewriteAnswer {
+    /// Writes the canonical `RCODE;TYPE;VALUE` form.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DnsRewriteAnswer::Address(ip) => write!(f, "NOERROR;{};{ip}", self.type_str()),
+            DnsRewriteAnswer::Cname(target) => write!(f, "NOERROR;CNAME;{target}"),
+            DnsRewriteAnswer::Rcode(rcode) => write!(f, "{};;", rcode.to_str()),
+        }
+    }
+}
+
+impl Serialize for DnsRewriteAnswer {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DnsRewriteAnswer {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Answers queries for `domain` and its subdomains with a fixed record
+/// instead of resolving them, for clients in `group_id`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DnsRewriteRule {
+    pub id: Option<i64>,
+    pub domain: Arc<str>,
+    pub answer: DnsRewriteAnswer,
+    pub group_id: i64,
+    pub enabled: bool,
+    pub comment: Option<Arc<str>>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+impl DnsRewriteRule {
+    pub fn new(
+        id: Option<i64>,
+        domain: Arc<str>,
+        answer: DnsRewriteAnswer,
+        group_id: i64,
+        enabled: bool,
+        comment: Option<Arc<str>>,
+    ) -> Self {
+        Self {
+            id,
+            domain,
+            answer,
+            group_id,
+            enabled,
+            comment,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    /// Lowercases and strips the trailing root dot so rules compare
+    /// against normalized query names.
+    pub fn normalize_domain(domain: &str) -> String {
+        domain.trim().trim_end_matches('.').to_ascii_lowercase()
+    }
+
+    pub fn validate_domain(domain: &str) -> Result<(), String> {
+        if domain.is_empty() {
+            return Err("Domain cannot be empty".to_string());
+        }
+        if domain.len() > 253 {
+            return Err("Domain cannot exceed 253 characters".to_string());
+        }
+        if domain.starts_with('.') || domain.contains("..") {
+            return Err("Domain contains an empty label".to_string());
+        }
+        let valid = domain
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.' || c == '_');
+        if !valid {
+            return Err(
+                "Domain contains invalid characters (only alphanumeric, hyphens, dots and underscores are allowed)".to_string(),
+            );
+        }
+        Ok(())
+    }
+
+    /// Rejects a CNAME pointing back at the rewritten name or one of its
+    /// subdomains, which would loop through the same rule.
+    pub fn validate_answer(domain: &str, answer: &DnsRewriteAnswer) -> Result<(), String> {
+        if let DnsRewriteAnswer::Cname(target) = answer {
+            let target = target.as_ref();
+            if target == domain || target.ends_with(&format!(".{domain}")) {
+                return Err(format!(
+                    "CNAME target '{target}' is covered by the rule itself"
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn validate_comment(comment: &Option<Arc<str>>) -> Result<(), String> {
+        if let Some(c) = comment {
+            if c.len() > 500 {
+                return Err("Comment cannot exceed 500 characters".to_string());
+            }
+        }
+        Ok(())
+    }
+}
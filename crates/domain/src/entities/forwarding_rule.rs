@@ -0,0 +1,130 @@
+use crate::value_objects::dns_protocol::DnsProtocol;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Where queries matching a forwarding rule are sent.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "lowercase")]
+pub enum ForwardingTarget {
+    /// A configured `[[dns.pools]]` entry, referenced by name.
+    Pool(Arc<str>),
+    /// A single upstream endpoint such as `tcp://10.1.1.53:53`.
+    Server(Arc<str>),
+}
+
+impl ForwardingTarget {
+    pub fn type_str(&self) -> &'static str {
+        match self {
+            ForwardingTarget::Pool(_) => "pool",
+            ForwardingTarget::Server(_) => "server",
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        match self {
+            ForwardingTarget::Pool(v) | ForwardingTarget::Server(v) => v,
+        }
+    }
+
+    pub fn from_parts(target_type: &str, value: &str) -> Option<Self> {
+        match target_type {
+            "pool" => Some(ForwardingTarget::Pool(Arc::from(value))),
+            "server" => Some(ForwardingTarget::Server(Arc::from(value))),
+            _ => None,
+        }
+    }
+}
+
+/// Routes every query for `domain` and its subdomains to `target`
+/// instead of the regular upstream pools.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForwardingRule {
+    pub id: Option<i64>,
+    pub domain: Arc<str>,
+    pub target: ForwardingTarget,
+    pub enabled: bool,
+    pub comment: Option<Arc<str>>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+impl ForwardingRule {
+    pub fn new(
+        id: Option<i64>,
+        domain: Arc<str>,
+        target: ForwardingTarget,
+        enabled: bool,
+        comment: Option<Arc<str>>,
+    ) -> Self {
+        Self {
+            id,
+            domain,
+            target,
+            enabled,
+            comment,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    /// Lowercases and strips the trailing root dot so rules compare
+    /// against normalized query names.
+    pub fn normalize_domain(domain: &str) -> String {
+        domain.trim().trim_end_matches('.').to_ascii_lowercase()
+    }
+
+    pub fn validate_domain(domain: &str) -> Result<(), String> {
+        if domain.is_empty() {
+            return Err("Domain cannot be empty".to_string());
+        }
+        if domain.len() > 253 {
+            return Err("Domain cannot exceed 253 characters".to_string());
+        }
+        if domain.starts_with('.') || domain.contains("..") {
+            return Err("Domain contains an empty label".to_string());
+        }
+        let valid = domain
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.' || c == '_');
+        if !valid {
+            return Err(
+                "Domain contains invalid characters (only alphanumeric, hyphens, dots and underscores are allowed)".to_string(),
+            );
+        }
+        Ok(())
+    }
+
+    pub fn validate_target(target: &ForwardingTarget) -> Result<(), String> {
+        match target {
+            ForwardingTarget::Pool(name) => {
+                if name.is_empty() {
+                    return Err("Pool name cannot be empty".to_string());
+                }
+            }
+            ForwardingTarget::Server(endpoint) => {
+                let protocol = endpoint
+                    .parse::<DnsProtocol>()
+                    .map_err(|e| format!("Invalid upstream endpoint '{endpoint}': {e}"))?;
+                // Only DoH/H3 resolve their hostname at query time; other
+                // transports need an IP, or a pool that expands the name.
+                let resolves_at_runtime =
+                    matches!(protocol, DnsProtocol::Https { .. } | DnsProtocol::H3 { .. });
+                if protocol.needs_resolution() && !resolves_at_runtime {
+                    return Err(format!(
+                        "Upstream endpoint '{endpoint}' must use an IP address; reference a pool to use a hostname"
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn validate_comment(comment: &Option<Arc<str>>) -> Result<(), String> {
+        if let Some(c) = comment {
+            if c.len() > 500 {
+                return Err("Comment cannot exceed 500 characters".to_string());
+            }
+        }
+        Ok(())
+    }
+}
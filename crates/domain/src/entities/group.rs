@@ -1,4 +1,6 @@
+use crate::config::blocking::{BlockingMode, BlockingResponse};
 use serde::{Deserialize, Serialize};
+use std::net::{Ipv4Addr, Ipv6Addr};
 use std::sync::Arc;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -8,6 +10,17 @@ pub struct Group {
     pub enabled: bool,
     pub comment: Option<Arc<str>>,
     pub is_default: bool,
+    /// Overrides the global `[blocking] mode`; `None` inherits it.
+    #[serde(default)]
+    pub blocking_mode: Option<BlockingMode>,
+    #[serde(default)]
+    pub blocking_ipv4: Option<Ipv4Addr>,
+    #[serde(default)]
+    pub blocking_ipv6: Option<Ipv6Addr>,
+    /// Named `[[dns.pools]]` this group resolves through, tried in order.
+    /// Empty means the global pools.
+    #[serde(default)]
+    pub upstream_pools: Vec<Arc<str>>,
     pub created_at: Option<String>,
     pub updated_at: Option<String>,
 }
@@ -26,6 +39,10 @@ impl Group {
             enabled,
             comment,
             is_default,
+            blocking_mode: None,
+            blocking_ipv4: None,
+            blocking_ipv6: None,
+            upstream_pools: Vec::new(),
             created_at: None,
             updated_at: None,
         }
@@ -39,6 +56,15 @@ impl Group {
         !self.is_default
     }
 
+    /// Group-specific blocking response, if this group overrides the global mode.
+    pub fn blocking_response(&self) -> Option<BlockingResponse> {
+        self.blocking_mode.map(|mode| BlockingResponse {
+            mode,
+            ipv4: self.blocking_ipv4,
+            ipv6: self.blocking_ipv6,
+        })
+    }
+
     pub fn validate_name(name: &str) -> Result<(), String> {
         if name.is_empty() {
             return Err("Group name cannot be empty".to_string());
@@ -62,6 +88,18 @@ impl Group {
         Ok(())
     }
 
+    pub fn validate_upstream_pools(pools: &[String]) -> Result<(), String> {
+        for (i, pool) in pools.iter().enumerate() {
+            if pool.trim().is_empty() {
+                return Err("Upstream pool name cannot be empty".to_string());
+            }
+            if pools[..i].contains(pool) {
+                return Err(format!("Upstream pool '{pool}' is listed more than once"));
+            }
+        }
+        Ok(())
+    }
+
     pub fn validate_comment(comment: &Option<Arc<str>>) -> Result<(), String> {
         if let Some(c) = comment {
             if c.len() > 500 {
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Outcome of the most recent download of a blocklist or allowlist URL.
+///
+/// Status is tracked per URL, so sources sharing a URL report the same
+/// fetch. `entry_count` and `parse_errors` describe the copy currently in
+/// use, which is the last good download when the latest attempt failed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ListSourceStatus {
+    pub last_attempt_at: String,
+    pub last_success_at: Option<String>,
+    /// HTTP status of the last attempt; `None` when no response arrived.
+    pub last_http_status: Option<u16>,
+    pub entry_count: u64,
+    /// Lines that are neither comments nor rules the filter understands.
+    pub parse_errors: u64,
+    pub last_error: Option<Arc<str>>,
+}
@@ -0,0 +1,225 @@
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// RFC 2181 §8: TTLs are unsigned values with the top bit clear.
+const MAX_TTL: u32 = i32::MAX as u32;
+
+/// One resource record of a local zone, in master-file presentation format.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ZoneRecord {
+    /// Fully-qualified owner name, lowercase, without the trailing root dot.
+    pub name: Arc<str>,
+    pub ttl: u32,
+    pub record_type: Arc<str>,
+    /// RDATA as written in a zone file, e.g. `10 mail.home.lan.` for MX.
+    pub data: Arc<str>,
+}
+
+impl ZoneRecord {
+    pub fn new(name: &str, ttl: u32, record_type: &str, data: &str) -> Self {
+        Self {
+            name: Arc::from(LocalZone::normalize_name(name)),
+            ttl,
+            record_type: Arc::from(record_type.trim().to_ascii_uppercase()),
+            data: Arc::from(data.trim()),
+        }
+    }
+
+    pub fn is_type(&self, record_type: &str) -> bool {
+        self.record_type.eq_ignore_ascii_case(record_type)
+    }
+}
+
+/// An authoritative zone loaded from a master file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocalZone {
+    /// Zone apex, lowercase, without the trailing root dot.
+    pub origin: Arc<str>,
+    pub file: Arc<str>,
+    pub records: Vec<ZoneRecord>,
+}
+
+impl LocalZone {
+    pub fn new(origin: &str, file: &str, records: Vec<ZoneRecord>) -> Self {
+        Self {
+            origin: Arc::from(Self::normalize_name(origin)),
+            file: Arc::from(file),
+            records,
+        }
+    }
+
+    /// Lowercases and strips the trailing root dot.
+    pub fn normalize_name(name: &str) -> String {
+        name.trim().trim_end_matches('.').to_ascii_lowercase()
+    }
+
+    /// Returns true when `name` is the apex or lies below it.
+    pub fn contains(&self, name: &str) -> bool {
+        let origin = self.origin.as_ref();
+        let Some(split) = name.len().checked_sub(origin.len()) else {
+            return false;
+        };
+        name.as_bytes()[split..].eq_ignore_ascii_case(origin.as_bytes())
+            && (split == 0 || name.as_bytes()[split - 1] == b'.')
+    }
+
+    /// Resolves an owner name the way a zone file does: `@` or an empty
+    /// name is the apex, a trailing dot marks an absolute name, anything
+    /// else is relative to the origin.
+    pub fn qualify_name(&self, name: &str) -> Result<String, String> {
+        let name = name.trim();
+        if name.chars().any(char::is_whitespace) {
+            return Err("Record name cannot contain whitespace".to_string());
+        }
+
+        let qualified = if name.is_empty() || name == "@" {
+            self.origin.to_string()
+        } else if name.ends_with('.') {
+            Self::normalize_name(name)
+        } else {
+            Self::normalize_name(&format!("{}.{}", name, self.origin))
+        };
+
+        if qualified.len() > 253 {
+            return Err("Record name cannot exceed 253 characters".to_string());
+        }
+        if qualified
+            .split('.')
+            .any(|label| label.is_empty() || label.len() > 63)
+        {
+            return Err(format!("Invalid record name '{}'", name));
+        }
+        if !self.contains(&qualified) {
+            return Err(format!(
+                "Record name '{}' is outside zone '{}'",
+                qualified, self.origin
+            ));
+        }
+        Ok(qualified)
+    }
+
+    /// Puts records in the order they are listed and written: SOA first,
+    /// then by owner name from the apex down, then by type. Record indexes
+    /// used by the API refer to this order.
+    pub fn sort_records(&mut self) {
+        self.records.sort_by(|a, b| {
+            b.is_type("SOA")
+                .cmp(&a.is_type("SOA"))
+                .then_with(|| a.name.split('.').rev().cmp(b.name.split('.').rev()))
+                .then_with(|| a.record_type.cmp(&b.record_type))
+                .then_with(|| a.data.cmp(&b.data))
+        });
+    }
+
+    pub fn soa(&self) -> Option<&ZoneRecord> {
+        self.records.iter().find(|r| r.is_type("SOA"))
+    }
+
+    /// TTL for records added without one: the SOA's own TTL, as BIND does
+    /// for records that follow it without `$TTL`.
+    pub fn default_ttl(&self) -> u32 {
+        self.soa().map(|soa| soa.ttl).unwrap_or(3600)
+    }
+
+    /// SOA serial (third RDATA field), if the zone has a well-formed SOA.
+    pub fn serial(&self) -> Option<u32> {
+        self.soa()?.data.split_whitespace().nth(2)?.parse().ok()
+    }
+
+    /// Increments the SOA serial using RFC 1982 wrap-around arithmetic so
+    /// secondaries and caches notice the change.
+    pub fn bump_serial(&mut self) {
+        let Some(soa) = self.records.iter_mut().find(|r| r.is_type("SOA")) else {
+            return;
+        };
+        let mut fields: Vec<String> = soa.data.split_whitespace().map(str::to_string).collect();
+        let Some(serial) = fields.get(2).and_then(|s| s.parse::<u32>().ok()) else {
+            return;
+        };
+        fields[2] = serial.wrapping_add(1).to_string();
+        soa.data = Arc::from(fields.join(" "));
+    }
+
+    /// Checks `record` against the zone before it is added, or before it
+    /// replaces the record at index `replacing`.
+    pub fn validate_record(
+        &self,
+        record: &ZoneRecord,
+        replacing: Option<usize>,
+    ) -> Result<(), String> {
+        if !self.contains(&record.name) {
+            return Err(format!(
+                "Record name '{}' is outside zone '{}'",
+                record.name, self.origin
+            ));
+        }
+        if record.ttl > MAX_TTL {
+            return Err(format!("TTL cannot exceed {}", MAX_TTL));
+        }
+        if record.record_type.is_empty()
+            || !record
+                .record_type
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric())
+        {
+            return Err(format!("Invalid record type '{}'", record.record_type));
+        }
+        if record.data.is_empty() {
+            return Err("Record data cannot be empty".to_string());
+        }
+        if record.data.contains(['\n', '\r']) {
+            return Err("Record data must be a single line".to_string());
+        }
+
+        if let Some(index) = replacing {
+            if self.records.get(index).is_some_and(|r| r.is_type("SOA")) && !record.is_type("SOA") {
+                return Err("The zone SOA record cannot be replaced by another type".to_string());
+            }
+        }
+
+        let others = || {
+            self.records
+                .iter()
+                .enumerate()
+                .filter(move |(i, _)| Some(*i) != replacing)
+                .map(|(_, r)| r)
+        };
+
+        if record.is_type("SOA") {
+            if record.name.as_ref() != self.origin.as_ref() {
+                return Err("SOA record must be at the zone apex".to_string());
+            }
+            if others().any(|r| r.is_type("SOA")) {
+                return Err("Zone already has an SOA record".to_string());
+            }
+        }
+
+        let same_name = || others().filter(|r| r.name == record.name);
+        if record.is_type("CNAME") {
+            if same_name().next().is_some() {
+                return Err(format!(
+                    "CNAME at '{}' cannot coexist with other records",
+                    record.name
+                ));
+            }
+        } else if same_name().any(|r| r.is_type("CNAME")) {
+            return Err(format!(
+                "'{}' already has a CNAME record; no other data is allowed",
+                record.name
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the record at `index` may be deleted. Every zone keeps
+    /// its SOA record.
+    pub fn validate_removal(&self, index: usize) -> Result<(), String> {
+        match self.records.get(index) {
+            Some(record) if record.is_type("SOA") => {
+                Err("The zone SOA record cannot be deleted".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
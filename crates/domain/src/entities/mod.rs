@@ -1,3 +1,4 @@
+pub mod acme_registration;
 pub mod api_token;
 pub mod auth_session;
 pub mod block_source;
@@ -7,13 +8,21 @@ pub mod blocklist_source;
 pub mod client;
 pub mod client_subnet;
 pub mod custom_service;
+pub mod ddns_update;
+pub mod dns_rewrite_rule;
+pub mod forwarding_rule;
 pub mod group;
+pub mod list_source_status;
+pub mod local_zone;
 pub mod managed_domain;
+pub mod negative_trust_anchor;
 pub mod query_log;
 pub mod regex_filter;
 pub mod safe_search;
 pub mod schedule;
 pub mod service_catalog;
+pub mod trust_anchor;
+pub mod tsig_key;
 pub mod user;
 pub mod whitelist;
 pub mod whitelist_source;
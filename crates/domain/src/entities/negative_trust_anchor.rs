@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use super::forwarding_rule::ForwardingRule;
+
+/// Timestamp format shared with the rest of the database
+/// (`YYYY-MM-DD HH:MM:SS`, UTC).
+pub const NTA_TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// A negative trust anchor (RFC 7646): DNSSEC validation is skipped for
+/// `domain` and its subdomains, and their answers are treated as Insecure.
+///
+/// Meant as a stopgap while a zone operator fixes a broken signature, so
+/// an anchor usually carries an expiry after which it stops applying.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NegativeTrustAnchor {
+    pub id: Option<i64>,
+    pub domain: Arc<str>,
+    /// UTC expiry; `None` keeps the anchor until it is deleted.
+    pub expires_at: Option<String>,
+    pub comment: Option<Arc<str>>,
+    pub created_at: Option<String>,
+}
+
+impl NegativeTrustAnchor {
+    pub fn new(
+        id: Option<i64>,
+        domain: Arc<str>,
+        expires_at: Option<String>,
+        comment: Option<Arc<str>>,
+    ) -> Self {
+        Self {
+            id,
+            domain,
+            expires_at,
+            comment,
+            created_at: None,
+        }
+    }
+
+    /// Whether the anchor has expired at `now`, given in
+    /// [`NTA_TIMESTAMP_FORMAT`]. The format sorts chronologically.
+    pub fn is_expired_at(&self, now: &str) -> bool {
+        self.expires_at.as_deref().is_some_and(|t| t <= now)
+    }
+
+    pub fn normalize_domain(domain: &str) -> String {
+        ForwardingRule::normalize_domain(domain)
+    }
+
+    /// Expects a normalized name. The root normalizes to an empty name and
+    /// is rejected: switching validation off entirely is what
+    /// `dnssec_enabled = false` is for.
+    pub fn validate_domain(domain: &str) -> Result<(), String> {
+        ForwardingRule::validate_domain(domain)
+    }
+
+    pub fn validate_comment(comment: &Option<Arc<str>>) -> Result<(), String> {
+        ForwardingRule::validate_comment(comment)
+    }
+}
@@ -32,6 +32,8 @@ pub enum QueryCategory {
     Upstream,
     RateLimited,
     Malware,
+    /// Allowed queries matched by an observe-only blocklist source.
+    WouldBlock,
 }
 
 impl FromStr for QueryCategory {
@@ -45,6 +47,7 @@ impl FromStr for QueryCategory {
             "upstream" => Ok(Self::Upstream),
             "rate-limited" => Ok(Self::RateLimited),
             "malware" => Ok(Self::Malware),
+            "would-block" => Ok(Self::WouldBlock),
             other => Err(format!("invalid query category: '{other}'")),
         }
     }
@@ -127,6 +130,17 @@ pub struct QueryLog {
 
     pub group_id: Option<i64>,
     pub block_source: Option<BlockSource>,
+    /// Blocklist sources whose entries blocked the query; empty unless
+    /// `block_source` is a list decision. On an allowed query, the
+    /// observe-only sources that would have blocked it.
+    pub blocklist_source_ids: Vec<i64>,
+}
+
+impl QueryLog {
+    /// Allowed, but matched by an observe-only blocklist source.
+    pub fn would_block(&self) -> bool {
+        !self.blocked && !self.blocklist_source_ids.is_empty()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -0,0 +1,261 @@
+use std::sync::Arc;
+
+/// DNSKEY flag marking a key signing key (Secure Entry Point, RFC 4034 §2.1.1).
+pub const DNSKEY_FLAG_SEP: u16 = 0x0001;
+
+/// DNSKEY flag set on a key that its owner has revoked (RFC 5011 §2.1).
+pub const DNSKEY_FLAG_REVOKE: u16 = 0x0080;
+
+/// Lifecycle of a trust anchor key as tracked by RFC 5011 §4.
+///
+/// `Start` is not stored: a key that leaves `AddPending` before its
+/// hold-down expires is simply forgotten.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrustAnchorState {
+    /// Newly published key waiting out the add hold-down.
+    AddPending,
+    /// Trusted and present in the zone's DNSKEY RRset.
+    Valid,
+    /// Trusted but absent from the last validated DNSKEY RRset.
+    Missing,
+    /// Revoked by its owner; never trusted again.
+    Revoked,
+    /// Revoked key whose remove hold-down has passed, or a DS anchor
+    /// replaced by the DNSKEY it points to. Kept so it is not re-added.
+    Removed,
+}
+
+impl TrustAnchorState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::AddPending => "add_pending",
+            Self::Valid => "valid",
+            Self::Missing => "missing",
+            Self::Revoked => "revoked",
+            Self::Removed => "removed",
+        }
+    }
+}
+
+impl std::str::FromStr for TrustAnchorState {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "add_pending" => Ok(Self::AddPending),
+            "valid" => Ok(Self::Valid),
+            "missing" => Ok(Self::Missing),
+            "revoked" => Ok(Self::Revoked),
+            "removed" => Ok(Self::Removed),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Where a trust anchor came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrustAnchorSource {
+    /// Compiled-in root KSK.
+    Builtin,
+    /// `dns.dnssec.trust_anchors` in the configuration file.
+    Config,
+    /// `dns.dnssec.trust_anchor_file`.
+    File,
+    /// Learned from a validated DNSKEY RRset (RFC 5011).
+    Rfc5011,
+}
+
+impl TrustAnchorSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Builtin => "builtin",
+            Self::Config => "config",
+            Self::File => "file",
+            Self::Rfc5011 => "rfc5011",
+        }
+    }
+}
+
+impl std::str::FromStr for TrustAnchorSource {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "builtin" => Ok(Self::Builtin),
+            "config" => Ok(Self::Config),
+            "file" => Ok(Self::File),
+            "rfc5011" => Ok(Self::Rfc5011),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Key material of a trust anchor: either the DNSKEY itself or a DS
+/// digest of it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrustAnchorData {
+    Dnskey {
+        flags: u16,
+        algorithm: u8,
+        public_key: Arc<[u8]>,
+    },
+    Ds {
+        key_tag: u16,
+        algorithm: u8,
+        digest_type: u8,
+        digest: Arc<[u8]>,
+    },
+}
+
+impl TrustAnchorData {
+    pub fn type_str(&self) -> &'static str {
+        match self {
+            Self::Dnskey { .. } => "DNSKEY",
+            Self::Ds { .. } => "DS",
+        }
+    }
+
+    pub fn algorithm(&self) -> u8 {
+        match self {
+            Self::Dnskey { algorithm, .. } | Self::Ds { algorithm, .. } => *algorithm,
+        }
+    }
+
+    /// Key tag as defined in RFC 4034 Appendix B.
+    pub fn key_tag(&self) -> u16 {
+        match self {
+            Self::Ds { key_tag, .. } => *key_tag,
+            Self::Dnskey {
+                flags,
+                algorithm,
+                public_key,
+            } => {
+                let mut rdata = Vec::with_capacity(4 + public_key.len());
+                rdata.extend_from_slice(&flags.to_be_bytes());
+                rdata.push(3);
+                rdata.push(*algorithm);
+                rdata.extend_from_slice(public_key);
+
+                let mut acc: u32 = 0;
+                for (i, byte) in rdata.iter().enumerate() {
+                    acc += if i % 2 == 0 {
+                        u32::from(*byte) << 8
+                    } else {
+                        u32::from(*byte)
+                    };
+                }
+                acc += acc >> 16;
+                (acc & 0xFFFF) as u16
+            }
+        }
+    }
+
+    pub fn is_sep(&self) -> bool {
+        matches!(self, Self::Dnskey { flags, .. } if flags & DNSKEY_FLAG_SEP != 0)
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        matches!(self, Self::Dnskey { flags, .. } if flags & DNSKEY_FLAG_REVOKE != 0)
+    }
+
+    /// The same key with the REVOKE flag cleared, as it was published
+    /// before revocation.
+    pub fn without_revoke(&self) -> Self {
+        match self {
+            Self::Dnskey {
+                flags,
+                algorithm,
+                public_key,
+            } => Self::Dnskey {
+                flags: flags & !DNSKEY_FLAG_REVOKE,
+                algorithm: *algorithm,
+                public_key: Arc::clone(public_key),
+            },
+            ds => ds.clone(),
+        }
+    }
+
+    /// True when both describe the same key, ignoring the REVOKE flag.
+    pub fn same_key(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Self::Dnskey {
+                    algorithm: a1,
+                    public_key: k1,
+                    ..
+                },
+                Self::Dnskey {
+                    algorithm: a2,
+                    public_key: k2,
+                    ..
+                },
+            ) => a1 == a2 && k1 == k2,
+            (a @ Self::Ds { .. }, b @ Self::Ds { .. }) => a == b,
+            _ => false,
+        }
+    }
+}
+
+/// A DNSSEC trust anchor and its RFC 5011 tracking state.
+///
+/// Times are Unix seconds.
+#[derive(Debug, Clone)]
+pub struct TrustAnchor {
+    pub id: Option<i64>,
+    /// Absolute zone name with a trailing dot; `.` for the root.
+    pub zone: Arc<str>,
+    pub data: TrustAnchorData,
+    pub state: TrustAnchorState,
+    pub source: TrustAnchorSource,
+    /// Follows key rollovers of its zone (RFC 5011).
+    pub managed: bool,
+    pub first_seen: i64,
+    pub last_seen: Option<i64>,
+    /// When an `AddPending` key becomes trusted or a `Revoked` key is removed.
+    pub hold_down_until: Option<i64>,
+}
+
+impl TrustAnchor {
+    /// A trusted anchor, as loaded from the configuration.
+    pub fn new(
+        zone: &str,
+        data: TrustAnchorData,
+        source: TrustAnchorSource,
+        managed: bool,
+        now: i64,
+    ) -> Self {
+        Self {
+            id: None,
+            zone: Arc::from(Self::normalize_zone(zone).as_str()),
+            data,
+            state: TrustAnchorState::Valid,
+            source,
+            managed,
+            first_seen: now,
+            last_seen: None,
+            hold_down_until: None,
+        }
+    }
+
+    /// Lowercases and adds the trailing root dot.
+    pub fn normalize_zone(zone: &str) -> String {
+        let zone = zone.trim().trim_end_matches('.').to_ascii_lowercase();
+        if zone.is_empty() {
+            ".".to_string()
+        } else {
+            format!("{}.", zone)
+        }
+    }
+
+    pub fn key_tag(&self) -> u16 {
+        self.data.key_tag()
+    }
+
+    /// Valid and Missing keys both anchor validation (RFC 5011 §4).
+    pub fn is_trusted(&self) -> bool {
+        matches!(
+            self.state,
+            TrustAnchorState::Valid | TrustAnchorState::Missing
+        )
+    }
+}
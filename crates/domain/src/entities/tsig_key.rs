@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use super::forwarding_rule::ForwardingRule;
+
+/// HMAC algorithms accepted for TSIG keys, by their RFC 8945 names.
+pub const TSIG_ALGORITHMS: &[&str] = &["hmac-sha256", "hmac-sha384", "hmac-sha512"];
+
+/// A shared secret (RFC 8945) that authenticates dynamic UPDATE messages.
+///
+/// `name` is the key name clients put in their TSIG record, so it has to
+/// match the name configured on the DHCP server or external-dns exactly.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TsigKey {
+    pub id: Option<i64>,
+    pub name: Arc<str>,
+    pub algorithm: Arc<str>,
+    /// Base64-encoded secret, the form BIND, Kea and external-dns use.
+    pub secret: Arc<str>,
+    pub created_at: Option<String>,
+}
+
+impl TsigKey {
+    pub fn normalize_name(name: &str) -> String {
+        ForwardingRule::normalize_domain(name)
+    }
+
+    /// Expects a normalized name.
+    pub fn validate_name(name: &str) -> Result<(), String> {
+        ForwardingRule::validate_domain(name)
+    }
+
+    pub fn normalize_algorithm(algorithm: &str) -> String {
+        algorithm.trim().trim_end_matches('.').to_ascii_lowercase()
+    }
+
+    /// Expects a normalized algorithm.
+    pub fn validate_algorithm(algorithm: &str) -> Result<(), String> {
+        if TSIG_ALGORITHMS.contains(&algorithm) {
+            Ok(())
+        } else {
+            Err(format!(
+                "Unsupported algorithm '{}' (expected one of: {})",
+                algorithm,
+                TSIG_ALGORITHMS.join(", ")
+            ))
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use super::list_source_status::ListSourceStatus;
 use crate::value_objects::validators;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -12,6 +13,8 @@ pub struct WhitelistSource {
     pub enabled: bool,
     pub created_at: Option<String>,
     pub updated_at: Option<String>,
+    /// Last download of `url`; `None` until the list has been fetched.
+    pub status: Option<ListSourceStatus>,
 }
 
 impl WhitelistSource {
@@ -32,6 +35,7 @@ impl WhitelistSource {
             enabled,
             created_at: None,
             updated_at: None,
+            status: None,
         }
     }
 
@@ -110,6 +110,57 @@ pub enum DomainError {
     #[error("Invalid managed domain: {0}")]
     InvalidManagedDomain(String),
 
+    #[error("Forwarding rule not found: {0}")]
+    ForwardingRuleNotFound(i64),
+
+    #[error("Invalid forwarding rule: {0}")]
+    InvalidForwardingRule(String),
+
+    #[error("DNS rewrite rule not found: {0}")]
+    DnsRewriteRuleNotFound(i64),
+
+    #[error("Invalid DNS rewrite rule: {0}")]
+    InvalidDnsRewriteRule(String),
+
+    #[error("Local zone not found: {0}")]
+    LocalZoneNotFound(String),
+
+    #[error("Zone record not found: {0}")]
+    ZoneRecordNotFound(usize),
+
+    #[error("Invalid zone record: {0}")]
+    InvalidZoneRecord(String),
+
+    #[error("Trust anchor not found: {0}")]
+    TrustAnchorNotFound(i64),
+
+    #[error("Negative trust anchor not found: {0}")]
+    NegativeTrustAnchorNotFound(i64),
+
+    #[error("Invalid negative trust anchor: {0}")]
+    InvalidNegativeTrustAnchor(String),
+
+    #[error("TSIG key not found: {0}")]
+    TsigKeyNotFound(i64),
+
+    #[error("Invalid TSIG key: {0}")]
+    InvalidTsigKey(String),
+
+    #[error("ACME registration not found: {0}")]
+    AcmeRegistrationNotFound(i64),
+
+    #[error("Invalid ACME allowfrom entry: {0}")]
+    InvalidAcmeAllowFrom(String),
+
+    #[error("Invalid ACME subdomain")]
+    InvalidAcmeSubdomain,
+
+    #[error("Invalid ACME challenge TXT value")]
+    InvalidAcmeTxt,
+
+    #[error("ACME certificate order failed: {0}")]
+    AcmeOrderFailed(String),
+
     #[error("Regex filter not found: {0}")]
     RegexFilterNotFound(i64),
 
@@ -11,24 +11,33 @@ pub use entities::schedule;
 pub use entities::whitelist;
 
 pub use config::{
-    AdminConfig, AuthConfig, CliOverrides, Config, ConfigError, DgaDetectionAction,
-    DgaDetectionConfig, DnsConfig, DnsCookiesConfig, EncryptedDnsConfig, HealthCheckConfig,
-    LocalDnsRecord, NxdomainHijackAction, NxdomainHijackConfig, RateLimitConfig,
-    ResponseIpFilterAction, ResponseIpFilterConfig, TunnelingAction, TunnelingDetectionConfig,
-    UpstreamPool, UpstreamStrategy,
+    AcmeChallengeType, AcmeConfig, AcmeDnsConfig, AdminConfig, AuthConfig, BlockingConfig,
+    BlockingMode, BlockingResponse, CliOverrides, Config, ConfigError, DgaDetectionAction,
+    DgaDetectionConfig, DnsConfig, DnsCookiesConfig, DnsViewConfig, DnssecConfig, DnstapConfig,
+    DnstapOutput, DynamicUpdateRecord, EncryptedDnsConfig, HealthCheckConfig, LocalDnsRecord,
+    LocalZoneConfig, MetricsConfig, NxdomainHijackAction, NxdomainHijackConfig, RateLimitConfig,
+    RecursorConfig, ResponseIpFilterAction, ResponseIpFilterConfig, TunnelingAction,
+    TunnelingDetectionConfig, UpstreamPool, UpstreamStrategy,
 };
 pub use dns_record::{DnsRecord, RecordCategory, RecordType};
+pub use entities::acme_registration::{AcmeRegistration, ACME_TXT_KEEP, ACME_TXT_LEN};
 pub use entities::api_token::ApiToken;
 pub use entities::auth_session::AuthSession;
 pub use entities::block_source::BlockSource;
 pub use entities::blocked_service::BlockedService;
 pub use entities::blocklist::BlockedDomain;
-pub use entities::blocklist_source::BlocklistSource;
+pub use entities::blocklist_source::{BlocklistSource, BlocklistSourceHits};
 pub use entities::client::{Client, ClientStats};
 pub use entities::client_subnet::{ClientSubnet, SubnetMatcher};
 pub use entities::custom_service::CustomService;
+pub use entities::ddns_update::{DdnsResult, DdnsUpdate};
+pub use entities::dns_rewrite_rule::{DnsRewriteAnswer, DnsRewriteRcode, DnsRewriteRule};
+pub use entities::forwarding_rule::{ForwardingRule, ForwardingTarget};
 pub use entities::group::{Group, GroupStats};
+pub use entities::list_source_status::ListSourceStatus;
+pub use entities::local_zone::{LocalZone, ZoneRecord};
 pub use entities::managed_domain::{DomainAction, ManagedDomain};
+pub use entities::negative_trust_anchor::{NegativeTrustAnchor, NTA_TIMESTAMP_FORMAT};
 pub use entities::query_log::{
     CacheStats, QueryCategory, QueryLog, QueryLogFilter, QuerySource, QueryStats,
 };
@@ -38,11 +47,19 @@ pub use entities::schedule::{
     evaluate_slots, GroupOverride, ScheduleAction, ScheduleProfile, TimeSlot, UnknownScheduleAction,
 };
 pub use entities::service_catalog::ServiceDefinition;
+pub use entities::trust_anchor::{
+    TrustAnchor, TrustAnchorData, TrustAnchorSource, TrustAnchorState, DNSKEY_FLAG_REVOKE,
+    DNSKEY_FLAG_SEP,
+};
+pub use entities::tsig_key::{TsigKey, TSIG_ALGORITHMS};
 pub use entities::user::{User, UserRole, UserSource};
 pub use entities::whitelist::WhitelistedDomain;
 pub use entities::whitelist_source::WhitelistSource;
 pub use errors::domain_error::DomainError;
 pub use value_objects::dns_protocol::{DnsProtocol, UpstreamAddr};
-pub use value_objects::dns_query::DnsQuery;
+pub use value_objects::dns_query::{DnsQuery, QueryScope};
 pub use value_objects::dns_request::{DnsRequest, EdnsCookie};
+pub use value_objects::dynamic_update::{
+    DynamicUpdate, UpdateData, UpdateOperation, UpdatePrerequisite, UpdateRcode,
+};
 pub use value_objects::query_filters::{FqdnFilter, PrivateIpFilter};
@@ -1,10 +1,34 @@
 use crate::dns_record::RecordType;
 use std::sync::Arc;
 
+/// Who a query is answered for. Selects the upstream pools and the cache
+/// partition, so answers produced for one scope are never served to
+/// another. The default scope uses the global pools and the shared cache.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct QueryScope {
+    /// Split-horizon view matched by the client, by position in
+    /// `[[dns.views]]`.
+    pub view: Option<u32>,
+    /// Group whose own upstream pools answer this query.
+    pub group: Option<i64>,
+}
+
+impl QueryScope {
+    pub const SHARED: Self = Self {
+        view: None,
+        group: None,
+    };
+
+    pub fn is_shared(&self) -> bool {
+        *self == Self::SHARED
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct DnsQuery {
     pub domain: Arc<str>,
     pub record_type: RecordType,
+    pub scope: QueryScope,
 }
 
 impl DnsQuery {
@@ -12,6 +36,22 @@ impl DnsQuery {
         Self {
             domain: domain.into(),
             record_type,
+            scope: QueryScope::SHARED,
         }
     }
+
+    pub fn with_group(mut self, group_id: Option<i64>) -> Self {
+        self.scope.group = group_id;
+        self
+    }
+
+    pub fn with_view(mut self, view: Option<u32>) -> Self {
+        self.scope.view = view;
+        self
+    }
+
+    pub fn with_scope(mut self, scope: QueryScope) -> Self {
+        self.scope = scope;
+        self
+    }
 }
@@ -0,0 +1,143 @@
+use crate::dns_record::RecordType;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+/// Record data carried by a dynamic UPDATE: addresses in forward zones,
+/// PTR targets in reverse zones, and the bookkeeping records update
+/// clients keep next to their addresses.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum UpdateData {
+    /// `A` or `AAAA` record, depending on the address family.
+    Address(IpAddr),
+    Ptr(Arc<str>),
+    /// `TXT` or `DHCID` record as raw RDATA, such as the DHCID a DHCP
+    /// server claims a name with (RFC 4701) or an external-dns ownership
+    /// TXT. Stored so later prerequisites can check it; never served.
+    Rdata {
+        record_type: RecordType,
+        rdata: Arc<[u8]>,
+    },
+}
+
+impl UpdateData {
+    pub fn record_type(&self) -> RecordType {
+        match self {
+            UpdateData::Address(IpAddr::V4(_)) => RecordType::A,
+            UpdateData::Address(IpAddr::V6(_)) => RecordType::AAAA,
+            UpdateData::Ptr(_) => RecordType::PTR,
+            UpdateData::Rdata { record_type, .. } => *record_type,
+        }
+    }
+}
+
+/// Prerequisite section entry (RFC 2136 §2.4). Names are normalized:
+/// lowercase, without the trailing dot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UpdatePrerequisite {
+    /// An RRset of this type exists, whatever its records (§2.4.1).
+    RrsetExists {
+        name: Arc<str>,
+        record_type: RecordType,
+    },
+    /// An RRset of this type exists with exactly these records (§2.4.2).
+    /// Record types that cannot be represented as [`UpdateData`] carry no
+    /// data and never match.
+    RrsetEquals {
+        name: Arc<str>,
+        record_type: RecordType,
+        data: Vec<UpdateData>,
+    },
+    /// No RRset of this type exists (§2.4.3).
+    RrsetAbsent {
+        name: Arc<str>,
+        record_type: RecordType,
+    },
+    /// The name owns at least one record (§2.4.4).
+    NameInUse { name: Arc<str> },
+    /// The name owns no records (§2.4.5).
+    NameNotInUse { name: Arc<str> },
+}
+
+impl UpdatePrerequisite {
+    pub fn name(&self) -> &str {
+        match self {
+            UpdatePrerequisite::RrsetExists { name, .. }
+            | UpdatePrerequisite::RrsetEquals { name, .. }
+            | UpdatePrerequisite::RrsetAbsent { name, .. }
+            | UpdatePrerequisite::NameInUse { name }
+            | UpdatePrerequisite::NameNotInUse { name } => name,
+        }
+    }
+}
+
+/// Update section entry (RFC 2136 §2.5).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UpdateOperation {
+    /// Adds a record; duplicates are ignored (§2.5.1).
+    Add {
+        name: Arc<str>,
+        data: UpdateData,
+        ttl: u32,
+    },
+    /// Deletes every record of one type at the name (§2.5.2).
+    DeleteRrset {
+        name: Arc<str>,
+        record_type: RecordType,
+    },
+    /// Deletes every record at the name (§2.5.3).
+    DeleteName { name: Arc<str> },
+    /// Deletes one record (§2.5.4).
+    DeleteRecord { name: Arc<str>, data: UpdateData },
+}
+
+impl UpdateOperation {
+    pub fn name(&self) -> &str {
+        match self {
+            UpdateOperation::Add { name, .. }
+            | UpdateOperation::DeleteRrset { name, .. }
+            | UpdateOperation::DeleteName { name }
+            | UpdateOperation::DeleteRecord { name, .. } => name,
+        }
+    }
+}
+
+/// A decoded RFC 2136 UPDATE message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DynamicUpdate {
+    /// Normalized zone name from the zone section.
+    pub zone: Arc<str>,
+    pub prerequisites: Vec<UpdatePrerequisite>,
+    pub operations: Vec<UpdateOperation>,
+}
+
+/// Response code of a rejected UPDATE (RFC 2136 §2.2).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UpdateRcode {
+    FormErr,
+    ServFail,
+    NxDomain,
+    NotImp,
+    Refused,
+    YxDomain,
+    YxRrset,
+    NxRrset,
+    NotAuth,
+    NotZone,
+}
+
+impl UpdateRcode {
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            UpdateRcode::FormErr => "FORMERR",
+            UpdateRcode::ServFail => "SERVFAIL",
+            UpdateRcode::NxDomain => "NXDOMAIN",
+            UpdateRcode::NotImp => "NOTIMP",
+            UpdateRcode::Refused => "REFUSED",
+            UpdateRcode::YxDomain => "YXDOMAIN",
+            UpdateRcode::YxRrset => "YXRRSET",
+            UpdateRcode::NxRrset => "NXRRSET",
+            UpdateRcode::NotAuth => "NOTAUTH",
+            UpdateRcode::NotZone => "NOTZONE",
+        }
+    }
+}
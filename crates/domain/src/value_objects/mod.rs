@@ -1,5 +1,6 @@
 pub mod dns_protocol;
 pub mod dns_query;
 pub mod dns_request;
+pub mod dynamic_update;
 pub mod query_filters;
 pub mod validators;
@@ -1,4 +1,5 @@
 use ferrous_dns_domain::config::dns::DnsConfig;
+use ferrous_dns_domain::{Config, UpstreamStrategy};
 
 #[test]
 fn test_config_default_values() {
@@ -116,3 +117,213 @@ fn test_local_domain_intercept_suffix_logic() {
 
     assert_eq!("lan", local_domain);
 }
+
+#[test]
+fn test_recursive_pool_without_servers_deserializes_and_validates() {
+    let toml_str = r#"
+        [recursor]
+        qname_minimization = false
+        max_queries = 32
+
+        [[pools]]
+        name = "recursive"
+        strategy = "Recursive"
+        priority = 1
+    "#;
+
+    let dns: DnsConfig = toml::from_str(toml_str).unwrap();
+    let config = Config {
+        dns,
+        ..Default::default()
+    };
+    assert_eq!(config.dns.pools[0].strategy, UpstreamStrategy::Recursive);
+    assert!(config.dns.pools[0].servers.is_empty());
+    assert!(!config.dns.recursor.qname_minimization);
+    assert!(config.dns.recursor.case_randomization);
+    assert_eq!(config.dns.recursor.max_queries, 32);
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn test_forwarding_pool_without_servers_fails_validation() {
+    let toml_str = r#"
+        [[pools]]
+        name = "empty"
+        strategy = "Parallel"
+        priority = 1
+    "#;
+
+    let dns: DnsConfig = toml::from_str(toml_str).unwrap();
+    let config = Config {
+        dns,
+        ..Default::default()
+    };
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_views_deserialize_and_validate() {
+    let toml_str = r#"
+        [[pools]]
+        name = "internal"
+        strategy = "Failover"
+        priority = 1
+        servers = ["udp://10.0.0.1:53"]
+
+        [[views]]
+        name = "office"
+        subnets = ["10.0.0.0/8", "fd00::/8"]
+        groups = [2]
+        rebinding_allowlist = ["git.example.com"]
+        upstream_pools = ["internal"]
+
+        [[views.local_records]]
+        hostname = "git"
+        domain = "example.com"
+        ip = "10.0.0.5"
+        record_type = "A"
+    "#;
+
+    let dns: DnsConfig = toml::from_str(toml_str).unwrap();
+    let config = Config {
+        dns,
+        ..Default::default()
+    };
+    let view = &config.dns.views[0];
+    assert_eq!(view.name, "office");
+    assert_eq!(view.subnets.len(), 2);
+    assert_eq!(view.groups, vec![2]);
+    assert_eq!(view.local_records[0].fqdn(&None), "git.example.com");
+    assert!(view.local_zones.is_empty());
+    assert!(config.validate().is_ok());
+}
+
+fn config_with_view(view_toml: &str) -> Config {
+    let toml_str = format!(
+        r#"
+        [[pools]]
+        name = "internal"
+        strategy = "Failover"
+        priority = 1
+        servers = ["udp://10.0.0.1:53"]
+
+        {view_toml}
+    "#
+    );
+    Config {
+        dns: toml::from_str(&toml_str).unwrap(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_view_without_matcher_fails_validation() {
+    let config = config_with_view(
+        r#"
+        [[views]]
+        name = "empty"
+    "#,
+    );
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_view_with_invalid_subnet_fails_validation() {
+    let config = config_with_view(
+        r#"
+        [[views]]
+        name = "office"
+        subnets = ["10.0.0.0/33"]
+    "#,
+    );
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_view_with_unknown_pool_fails_validation() {
+    let config = config_with_view(
+        r#"
+        [[views]]
+        name = "office"
+        groups = [1]
+        upstream_pools = ["missing"]
+    "#,
+    );
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_view_with_mismatched_record_fails_validation() {
+    let config = config_with_view(
+        r#"
+        [[views]]
+        name = "office"
+        groups = [1]
+
+        [[views.local_records]]
+        hostname = "git.example.com"
+        ip = "10.0.0.5"
+        record_type = "AAAA"
+    "#,
+    );
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_duplicate_view_names_fail_validation() {
+    let config = config_with_view(
+        r#"
+        [[views]]
+        name = "office"
+        groups = [1]
+
+        [[views]]
+        name = "office"
+        groups = [2]
+    "#,
+    );
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_dynamic_update_zones_deserialize_and_validate() {
+    let dns: DnsConfig = toml::from_str(
+        r#"
+        dynamic_update_zones = ["lan.", "1.168.192.in-addr.arpa"]
+
+        [[pools]]
+        name = "default"
+        strategy = "Failover"
+        priority = 1
+        servers = ["udp://10.0.0.1:53"]
+    "#,
+    )
+    .unwrap();
+    assert_eq!(dns.dynamic_update_zones.len(), 2);
+    let config = Config {
+        dns,
+        ..Default::default()
+    };
+    assert!(config.validate().is_ok());
+    assert!(Config::default().dns.dynamic_update_zones.is_empty());
+}
+
+#[test]
+fn test_invalid_dynamic_update_zone_fails_validation() {
+    let config = Config {
+        dns: toml::from_str(
+            r#"
+            dynamic_update_zones = ["bad zone"]
+
+            [[pools]]
+            name = "default"
+            strategy = "Failover"
+            priority = 1
+            servers = ["udp://10.0.0.1:53"]
+        "#,
+        )
+        .unwrap(),
+        ..Default::default()
+    };
+    assert!(config.validate().is_err());
+}
@@ -0,0 +1,88 @@
+use ferrous_dns_domain::{DnsRewriteAnswer, DnsRewriteRcode, DnsRewriteRule};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+fn parse(s: &str) -> Result<DnsRewriteAnswer, String> {
+    s.parse()
+}
+
+// ── DnsRewriteAnswer parsing ──────────────────────────────────────────────────
+
+#[test]
+fn test_parse_full_triples() {
+    assert_eq!(
+        parse("NOERROR;A;10.0.0.5").unwrap(),
+        DnsRewriteAnswer::Address("10.0.0.5".parse::<IpAddr>().unwrap())
+    );
+    assert_eq!(
+        parse("noerror;aaaa;fd00::5").unwrap(),
+        DnsRewriteAnswer::Address("fd00::5".parse::<IpAddr>().unwrap())
+    );
+    assert_eq!(
+        parse("NOERROR;CNAME;Lan.Example.net.").unwrap(),
+        DnsRewriteAnswer::Cname(Arc::from("lan.example.net"))
+    );
+    assert_eq!(
+        parse("NXDOMAIN;;").unwrap(),
+        DnsRewriteAnswer::Rcode(DnsRewriteRcode::NxDomain)
+    );
+    assert_eq!(
+        parse("NOERROR;;").unwrap(),
+        DnsRewriteAnswer::Rcode(DnsRewriteRcode::NoError)
+    );
+}
+
+#[test]
+fn test_parse_shorthands() {
+    assert_eq!(parse("10.0.0.5").unwrap().type_str(), "A");
+    assert_eq!(parse("::1").unwrap().type_str(), "AAAA");
+    assert_eq!(parse("lan.example.net").unwrap().type_str(), "CNAME");
+    assert_eq!(
+        parse("REFUSED").unwrap(),
+        DnsRewriteAnswer::Rcode(DnsRewriteRcode::Refused)
+    );
+}
+
+#[test]
+fn test_parse_rejects_mismatched_or_unsupported_records() {
+    assert!(parse("").is_err());
+    assert!(parse("NOERROR;A;fd00::5").is_err());
+    assert!(parse("NOERROR;AAAA;10.0.0.5").is_err());
+    assert!(parse("NOERROR;TXT;hello").is_err());
+    assert!(parse("NXDOMAIN;A;10.0.0.5").is_err());
+    assert!(parse("SERVFAIL;;").is_err());
+    assert!(parse("a;b").is_err());
+    assert!(parse("not a host").is_err());
+}
+
+#[test]
+fn test_display_round_trips_canonical_form() {
+    for s in [
+        "NOERROR;A;10.0.0.5",
+        "NOERROR;AAAA;fd00::5",
+        "NOERROR;CNAME;lan.example.net",
+        "REFUSED;;",
+    ] {
+        assert_eq!(parse(s).unwrap().to_string(), s);
+    }
+    assert_eq!(parse("10.0.0.5").unwrap().to_string(), "NOERROR;A;10.0.0.5");
+}
+
+// ── DnsRewriteRule validation ─────────────────────────────────────────────────
+
+#[test]
+fn test_validate_answer_rejects_cname_into_own_subtree() {
+    let cname = parse("www.example.org").unwrap();
+    assert!(DnsRewriteRule::validate_answer("example.org", &cname).is_err());
+    assert!(DnsRewriteRule::validate_answer("www.example.org", &cname).is_err());
+    assert!(DnsRewriteRule::validate_answer("example.com", &cname).is_ok());
+    assert!(DnsRewriteRule::validate_answer("ample.org", &parse("example.org").unwrap()).is_ok());
+}
+
+#[test]
+fn test_validate_domain() {
+    assert!(DnsRewriteRule::validate_domain("nas.example.org").is_ok());
+    assert!(DnsRewriteRule::validate_domain("").is_err());
+    assert!(DnsRewriteRule::validate_domain("a..b").is_err());
+    assert!(DnsRewriteRule::validate_domain("bad domain").is_err());
+}
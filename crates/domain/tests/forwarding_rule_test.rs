@@ -0,0 +1,109 @@
+use ferrous_dns_domain::{ForwardingRule, ForwardingTarget};
+use std::sync::Arc;
+
+#[test]
+fn test_forwarding_rule_creation() {
+    let rule = ForwardingRule::new(
+        Some(1),
+        Arc::from("corp.example"),
+        ForwardingTarget::Server(Arc::from("tcp://10.1.1.53:53")),
+        true,
+        Some(Arc::from("Corporate DNS")),
+    );
+
+    assert_eq!(rule.id, Some(1));
+    assert_eq!(rule.domain.as_ref(), "corp.example");
+    assert_eq!(rule.target.type_str(), "server");
+    assert_eq!(rule.target.value(), "tcp://10.1.1.53:53");
+    assert!(rule.enabled);
+    assert!(rule.created_at.is_none());
+}
+
+// ── ForwardingTarget ──────────────────────────────────────────────────────────
+
+#[test]
+fn test_target_from_parts_round_trip() {
+    let pool = ForwardingTarget::from_parts("pool", "internal").unwrap();
+    assert_eq!(pool, ForwardingTarget::Pool(Arc::from("internal")));
+    assert_eq!(pool.type_str(), "pool");
+
+    let server = ForwardingTarget::from_parts("server", "127.0.0.1:8600").unwrap();
+    assert_eq!(server.value(), "127.0.0.1:8600");
+}
+
+#[test]
+fn test_target_from_parts_unknown_type() {
+    assert!(ForwardingTarget::from_parts("resolver", "x").is_none());
+}
+
+// ── normalize_domain / validate_domain ────────────────────────────────────────
+
+#[test]
+fn test_normalize_domain_lowercases_and_strips_root() {
+    assert_eq!(
+        ForwardingRule::normalize_domain(" Corp.Example. "),
+        "corp.example"
+    );
+}
+
+#[test]
+fn test_validate_domain_valid() {
+    assert!(ForwardingRule::validate_domain("corp.example").is_ok());
+    assert!(ForwardingRule::validate_domain("10.in-addr.arpa").is_ok());
+    assert!(ForwardingRule::validate_domain("consul").is_ok());
+}
+
+#[test]
+fn test_validate_domain_rejects_empty_and_bad_labels() {
+    assert!(ForwardingRule::validate_domain("").is_err());
+    assert!(ForwardingRule::validate_domain(".corp").is_err());
+    assert!(ForwardingRule::validate_domain("corp..example").is_err());
+    assert!(ForwardingRule::validate_domain("*.corp.example").is_err());
+}
+
+#[test]
+fn test_validate_domain_too_long() {
+    let long = "a".repeat(254);
+    assert!(ForwardingRule::validate_domain(&long).is_err());
+}
+
+// ── validate_target ───────────────────────────────────────────────────────────
+
+#[test]
+fn test_validate_target_accepts_endpoints() {
+    for endpoint in ["10.1.1.53:53", "tcp://10.1.1.53:53", "udp://127.0.0.1:8600"] {
+        let target = ForwardingTarget::Server(Arc::from(endpoint));
+        assert!(
+            ForwardingRule::validate_target(&target).is_ok(),
+            "{endpoint} should be valid"
+        );
+    }
+}
+
+#[test]
+fn test_validate_target_rejects_bad_endpoint() {
+    let target = ForwardingTarget::Server(Arc::from("ftp://10.1.1.53"));
+    assert!(ForwardingRule::validate_target(&target).is_err());
+}
+
+#[test]
+fn test_validate_target_rejects_unresolved_hostname() {
+    let target = ForwardingTarget::Server(Arc::from("tls://dns.corp.example:853"));
+    assert!(ForwardingRule::validate_target(&target).is_err());
+
+    let doh = ForwardingTarget::Server(Arc::from("https://dns.corp.example/dns-query"));
+    assert!(ForwardingRule::validate_target(&doh).is_ok());
+}
+
+#[test]
+fn test_validate_target_rejects_empty_pool() {
+    let target = ForwardingTarget::Pool(Arc::from(""));
+    assert!(ForwardingRule::validate_target(&target).is_err());
+}
+
+#[test]
+fn test_validate_comment_too_long() {
+    let comment = Some(Arc::from("x".repeat(501).as_str()));
+    assert!(ForwardingRule::validate_comment(&comment).is_err());
+    assert!(ForwardingRule::validate_comment(&None).is_ok());
+}
@@ -62,6 +62,26 @@ fn test_validate_comment_too_long() {
     assert!(Group::validate_comment(&long_comment).is_err());
 }
 
+#[test]
+fn test_validate_upstream_pools_valid() {
+    assert!(Group::validate_upstream_pools(&[]).is_ok());
+    assert!(
+        Group::validate_upstream_pools(&["cleanbrowsing".to_string(), "quad9".to_string()]).is_ok()
+    );
+}
+
+#[test]
+fn test_validate_upstream_pools_rejects_empty_and_duplicates() {
+    assert!(Group::validate_upstream_pools(&[" ".to_string()]).is_err());
+    assert!(Group::validate_upstream_pools(&["quad9".to_string(), "quad9".to_string()]).is_err());
+}
+
+#[test]
+fn test_new_group_uses_global_pools() {
+    let group = Group::new(None, Arc::from("Kids"), true, None, false);
+    assert!(group.upstream_pools.is_empty());
+}
+
 #[test]
 fn test_can_disable_regular_group() {
     let group = Group::new(None, Arc::from("Regular"), true, None, false);
@@ -85,6 +85,7 @@ impl QueryLogBuilder {
             query_source: self.query_source,
             group_id: None,
             block_source: self.block_source,
+            blocklist_source_ids: Vec::new(),
         }
     }
 }
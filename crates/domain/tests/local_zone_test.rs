@@ -0,0 +1,182 @@
+use ferrous_dns_domain::{LocalZone, ZoneRecord};
+
+fn zone() -> LocalZone {
+    LocalZone::new(
+        "Home.Lan.",
+        "/etc/ferrous-dns/zones/home.lan.zone",
+        vec![
+            ZoneRecord::new(
+                "home.lan",
+                3600,
+                "SOA",
+                "ns1.home.lan. admin.home.lan. 2026010101 7200 3600 1209600 300",
+            ),
+            ZoneRecord::new("www.home.lan", 300, "CNAME", "nas.home.lan."),
+            ZoneRecord::new("nas.home.lan", 300, "A", "192.168.1.10"),
+        ],
+    )
+}
+
+// ── names ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_new_normalizes_origin() {
+    assert_eq!(zone().origin.as_ref(), "home.lan");
+}
+
+#[test]
+fn test_record_new_normalizes_fields() {
+    let record = ZoneRecord::new("NAS.Home.Lan.", 60, " aaaa ", " fd00::10 ");
+    assert_eq!(record.name.as_ref(), "nas.home.lan");
+    assert_eq!(record.record_type.as_ref(), "AAAA");
+    assert_eq!(record.data.as_ref(), "fd00::10");
+    assert!(record.is_type("aaaa"));
+}
+
+#[test]
+fn test_contains_respects_label_boundaries() {
+    let zone = zone();
+    assert!(zone.contains("home.lan"));
+    assert!(zone.contains("a.b.home.lan"));
+    assert!(!zone.contains("myhome.lan"));
+    assert!(!zone.contains("lan"));
+}
+
+#[test]
+fn test_qualify_name_apex_relative_and_absolute() {
+    let zone = zone();
+    assert_eq!(zone.qualify_name("@").unwrap(), "home.lan");
+    assert_eq!(zone.qualify_name("").unwrap(), "home.lan");
+    assert_eq!(zone.qualify_name("Printer").unwrap(), "printer.home.lan");
+    assert_eq!(
+        zone.qualify_name("printer.home.lan.").unwrap(),
+        "printer.home.lan"
+    );
+    assert_eq!(zone.qualify_name("*.dev").unwrap(), "*.dev.home.lan");
+}
+
+#[test]
+fn test_qualify_name_rejects_out_of_zone_and_bad_labels() {
+    let zone = zone();
+    assert!(zone.qualify_name("example.com.").is_err());
+    assert!(zone.qualify_name("a..b").is_err());
+    assert!(zone.qualify_name("two words").is_err());
+    assert!(zone.qualify_name(&"x".repeat(64)).is_err());
+}
+
+// ── SOA handling ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_serial_and_default_ttl() {
+    let zone = zone();
+    assert_eq!(zone.serial(), Some(2026010101));
+    assert_eq!(zone.default_ttl(), 3600);
+}
+
+#[test]
+fn test_bump_serial_wraps_around() {
+    let mut zone = zone();
+    zone.bump_serial();
+    assert_eq!(zone.serial(), Some(2026010102));
+
+    zone.records[0] = ZoneRecord::new(
+        "home.lan",
+        3600,
+        "SOA",
+        "ns1.home.lan. admin.home.lan. 4294967295 7200 3600 1209600 300",
+    );
+    zone.bump_serial();
+    assert_eq!(zone.serial(), Some(0));
+}
+
+#[test]
+fn test_sort_records_puts_soa_first_then_by_name() {
+    let mut zone = zone();
+    zone.records.reverse();
+    zone.records
+        .push(ZoneRecord::new("a.nas.home.lan", 300, "A", "192.168.1.11"));
+    zone.sort_records();
+
+    let order: Vec<(&str, &str)> = zone
+        .records
+        .iter()
+        .map(|r| (r.name.as_ref(), r.record_type.as_ref()))
+        .collect();
+    assert_eq!(
+        order,
+        vec![
+            ("home.lan", "SOA"),
+            ("nas.home.lan", "A"),
+            ("a.nas.home.lan", "A"),
+            ("www.home.lan", "CNAME"),
+        ]
+    );
+}
+
+// ── validation ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_validate_accepts_new_record() {
+    let record = ZoneRecord::new("mail.home.lan", 300, "MX", "10 nas.home.lan.");
+    assert!(zone().validate_record(&record, None).is_ok());
+}
+
+#[test]
+fn test_validate_rejects_out_of_zone_and_bad_fields() {
+    let zone = zone();
+    let outside = ZoneRecord::new("nas.example.com", 300, "A", "192.0.2.1");
+    assert!(zone.validate_record(&outside, None).is_err());
+
+    let bad_ttl = ZoneRecord::new("nas.home.lan", u32::MAX, "A", "192.0.2.1");
+    assert!(zone.validate_record(&bad_ttl, None).is_err());
+
+    let bad_type = ZoneRecord::new("nas.home.lan", 300, "A-B", "192.0.2.1");
+    assert!(zone.validate_record(&bad_type, None).is_err());
+
+    let multi_line = ZoneRecord::new("nas.home.lan", 300, "TXT", "\"a\"\nb");
+    assert!(zone.validate_record(&multi_line, None).is_err());
+}
+
+#[test]
+fn test_validate_enforces_cname_exclusivity() {
+    let zone = zone();
+    let beside_cname = ZoneRecord::new("www.home.lan", 300, "A", "192.168.1.20");
+    assert!(zone.validate_record(&beside_cname, None).is_err());
+
+    let cname_beside_data = ZoneRecord::new("nas.home.lan", 300, "CNAME", "www.home.lan.");
+    assert!(zone.validate_record(&cname_beside_data, None).is_err());
+
+    // Replacing the only record at a name may change its type.
+    assert!(zone.validate_record(&beside_cname, Some(1)).is_ok());
+}
+
+#[test]
+fn test_validate_keeps_a_single_apex_soa() {
+    let zone = zone();
+    let second = ZoneRecord::new(
+        "home.lan",
+        3600,
+        "SOA",
+        "ns2.home.lan. admin.home.lan. 1 7200 3600 1209600 300",
+    );
+    assert!(zone.validate_record(&second, None).is_err());
+    assert!(zone.validate_record(&second, Some(0)).is_ok());
+
+    let below_apex = ZoneRecord::new(
+        "sub.home.lan",
+        3600,
+        "SOA",
+        "ns2.home.lan. admin.home.lan. 1 7200 3600 1209600 300",
+    );
+    assert!(zone.validate_record(&below_apex, Some(0)).is_err());
+
+    let not_soa = ZoneRecord::new("home.lan", 300, "A", "192.168.1.1");
+    assert!(zone.validate_record(&not_soa, Some(0)).is_err());
+}
+
+#[test]
+fn test_validate_removal_protects_soa() {
+    let zone = zone();
+    assert!(zone.validate_removal(0).is_err());
+    assert!(zone.validate_removal(2).is_ok());
+}
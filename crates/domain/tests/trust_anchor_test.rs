@@ -0,0 +1,116 @@
+use ferrous_dns_domain::{
+    TrustAnchor, TrustAnchorData, TrustAnchorSource, TrustAnchorState, DNSKEY_FLAG_REVOKE,
+};
+use std::str::FromStr;
+use std::sync::Arc;
+
+fn ksk(flags: u16) -> TrustAnchorData {
+    TrustAnchorData::Dnskey {
+        flags,
+        algorithm: 13,
+        public_key: Arc::from(&[0x11u8, 0x22, 0x33, 0x44, 0x55][..]),
+    }
+}
+
+#[test]
+fn test_new_anchor_is_valid_and_trusted() {
+    let anchor = TrustAnchor::new(
+        "Corp.Internal",
+        ksk(257),
+        TrustAnchorSource::Config,
+        false,
+        100,
+    );
+
+    assert_eq!(anchor.zone.as_ref(), "corp.internal.");
+    assert_eq!(anchor.state, TrustAnchorState::Valid);
+    assert_eq!(anchor.first_seen, 100);
+    assert!(anchor.is_trusted());
+    assert!(anchor.id.is_none());
+}
+
+#[test]
+fn test_normalize_zone_root() {
+    assert_eq!(TrustAnchor::normalize_zone("."), ".");
+    assert_eq!(TrustAnchor::normalize_zone(""), ".");
+    assert_eq!(TrustAnchor::normalize_zone("example.com."), "example.com.");
+}
+
+#[test]
+fn test_only_valid_and_missing_are_trusted() {
+    let mut anchor = TrustAnchor::new(".", ksk(257), TrustAnchorSource::Builtin, true, 0);
+    for (state, trusted) in [
+        (TrustAnchorState::AddPending, false),
+        (TrustAnchorState::Valid, true),
+        (TrustAnchorState::Missing, true),
+        (TrustAnchorState::Revoked, false),
+        (TrustAnchorState::Removed, false),
+    ] {
+        anchor.state = state;
+        assert_eq!(anchor.is_trusted(), trusted, "{:?}", state);
+    }
+}
+
+#[test]
+fn test_state_and_source_round_trip() {
+    for state in [
+        TrustAnchorState::AddPending,
+        TrustAnchorState::Valid,
+        TrustAnchorState::Missing,
+        TrustAnchorState::Revoked,
+        TrustAnchorState::Removed,
+    ] {
+        assert_eq!(TrustAnchorState::from_str(state.as_str()), Ok(state));
+    }
+    for source in [
+        TrustAnchorSource::Builtin,
+        TrustAnchorSource::Config,
+        TrustAnchorSource::File,
+        TrustAnchorSource::Rfc5011,
+    ] {
+        assert_eq!(TrustAnchorSource::from_str(source.as_str()), Ok(source));
+    }
+    assert!(TrustAnchorState::from_str("start").is_err());
+}
+
+// ── TrustAnchorData ───────────────────────────────────────────────────────────
+
+#[test]
+fn test_revoke_flag_shifts_key_tag_by_128() {
+    let key = ksk(257);
+    let revoked = ksk(257 | DNSKEY_FLAG_REVOKE);
+
+    assert!(revoked.is_revoked());
+    assert!(!key.is_revoked());
+    assert_eq!(revoked.key_tag(), key.key_tag().wrapping_add(128));
+    assert_eq!(revoked.without_revoke(), key);
+}
+
+#[test]
+fn test_same_key_ignores_revoke_flag() {
+    assert!(ksk(257).same_key(&ksk(257 | DNSKEY_FLAG_REVOKE)));
+    assert!(!ksk(257).same_key(&TrustAnchorData::Dnskey {
+        flags: 257,
+        algorithm: 13,
+        public_key: Arc::from(&[0x99u8][..]),
+    }));
+}
+
+#[test]
+fn test_sep_flag() {
+    assert!(ksk(257).is_sep());
+    assert!(!ksk(256).is_sep());
+}
+
+#[test]
+fn test_ds_key_tag_is_stored_tag() {
+    let ds = TrustAnchorData::Ds {
+        key_tag: 20326,
+        algorithm: 8,
+        digest_type: 2,
+        digest: Arc::from(&[0xE0u8; 32][..]),
+    };
+    assert_eq!(ds.key_tag(), 20326);
+    assert_eq!(ds.type_str(), "DS");
+    assert!(!ds.is_sep());
+}
@@ -0,0 +1,100 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use ferrous_dns_infrastructure::dns::block_filter::{
+    build_list_index, parse_list_text, BlockIndex, ParsedEntry, RuleDirectory, SourceBits,
+    SourceIndex,
+};
+use std::collections::HashMap;
+use std::time::Duration;
+
+const DOMAINS: usize = 200_000;
+const SUBTREES: usize = 5_000;
+const PROBES: usize = 1024;
+
+/// Group subscribed to every source.
+const ALL: i64 = 1;
+/// Group subscribed to every other source.
+const HALF: i64 = 2;
+
+/// Builds an index of `sources` lists sharing `DOMAINS` exact names and
+/// `SUBTREES` `||domain^` rules, with most names listed by two sources.
+fn build(sources: usize) -> BlockIndex {
+    let mut texts = vec![String::new(); sources];
+    for i in 0..DOMAINS {
+        let line = format!("0.0.0.0 ads{i}.tracker{}.example\n", i % 1000);
+        texts[i % sources].push_str(&line);
+        texts[(i * 7 + 3) % sources].push_str(&line);
+    }
+    for j in 0..SUBTREES {
+        texts[j % sources].push_str(&format!("||cdn{j}.adnet.example^\n"));
+    }
+
+    let entries: HashMap<SourceIndex, Vec<ParsedEntry>> = texts
+        .iter()
+        .enumerate()
+        .map(|(idx, text)| (idx as SourceIndex + 1, parse_list_text(text)))
+        .collect();
+
+    let count = sources as SourceIndex;
+    let mut groups = HashMap::new();
+    groups.insert(ALL, (1..=count).collect::<SourceBits>());
+    groups.insert(HALF, (1..=count).step_by(2).collect::<SourceBits>());
+    build_list_index(&entries, &groups, &RuleDirectory::default())
+}
+
+fn probes(f: impl Fn(usize) -> String) -> Vec<String> {
+    (0..PROBES).map(f).collect()
+}
+
+/// Median `is_blocked` time per lookup, this bench ported to the fixed-width
+/// `u64` source mask of ec6b212 versus the current `SourceBits`. One run each
+/// on the same shared machine, so gaps below ~30% are within noise. The old
+/// mask had no room for more than 63 sources.
+///
+/// | case               | u64, 8 | bits, 8 | u64, 63 | bits, 63 | bits, 256 |
+/// |:-------------------|-------:|--------:|--------:|---------:|----------:|
+/// | exact_hit / all    | 430 ns |  380 ns |  443 ns |   370 ns |    432 ns |
+/// | exact_hit / half   | 312 ns |  320 ns |  477 ns |   415 ns |    457 ns |
+/// | subtree_hit / all  | 344 ns |  367 ns |  466 ns |   386 ns |    414 ns |
+/// | subtree_hit / half | 372 ns |  340 ns |  354 ns |   385 ns |    449 ns |
+/// | miss / all         | 262 ns |  299 ns |  207 ns |   334 ns |    252 ns |
+/// | miss / half        | 245 ns |  251 ns |  311 ns |   352 ns |    259 ns |
+fn bench_lookups(c: &mut Criterion) {
+    let exact_hits = probes(|i| format!("ads{}.tracker{}.example", i * 97, (i * 97) % 1000));
+    let subtree_hits = probes(|i| format!("img.cdn{}.adnet.example", i * 3));
+    let misses = probes(|i| format!("www.site{i}.legit.example"));
+
+    let mut group = c.benchmark_group("block_index");
+    for sources in [8usize, 63, 256] {
+        let index = build(sources);
+        for (name, domains) in [
+            ("exact_hit", &exact_hits),
+            ("subtree_hit", &subtree_hits),
+            ("miss", &misses),
+        ] {
+            for (label, group_id) in [("all", ALL), ("half", HALF)] {
+                group.bench_with_input(
+                    BenchmarkId::new(format!("{name}/{label}"), sources),
+                    domains,
+                    |b, domains| {
+                        let mut i = 0usize;
+                        b.iter(|| {
+                            i = (i + 1) % PROBES;
+                            black_box(index.is_blocked(black_box(&domains[i]), group_id))
+                        })
+                    },
+                );
+            }
+        }
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default()
+        .sample_size(50)
+        .warm_up_time(Duration::from_secs(1))
+        .measurement_time(Duration::from_secs(3));
+    targets = bench_lookups
+}
+criterion_main!(benches);
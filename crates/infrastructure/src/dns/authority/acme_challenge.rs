@@ -0,0 +1,221 @@
+use super::local_authority::AuthorityResponse;
+use arc_swap::ArcSwap;
+use ferrous_dns_application::ports::AcmeChallengeStore;
+use ferrous_dns_domain::AcmeRegistration;
+use hickory_proto::op::ResponseCode;
+use hickory_proto::rr::rdata::{NS, SOA, TXT};
+use hickory_proto::rr::{Name, RData, Record, RecordType as HickoryRecordType};
+use rustc_hash::FxHashMap;
+use std::sync::Arc;
+use tracing::info;
+
+const SOA_REFRESH: i32 = 3600;
+const SOA_RETRY: i32 = 600;
+const SOA_EXPIRE: i32 = 86400;
+
+struct ChallengeRecords {
+    serial: u32,
+    /// Subdomain label to its challenge values, newest first.
+    txt: FxHashMap<Box<str>, Vec<Arc<str>>>,
+}
+
+/// The acme-dns challenge zone, answered authoritatively.
+///
+/// Each registration owns `<subdomain>.<zone>`; its TXT values are swapped
+/// in whole on every `/update`. Built with an empty zone, the table answers
+/// nothing from the zone.
+///
+/// The built-in ACME client also publishes its own DNS-01 records here, at
+/// `_acme-challenge.<name>` outside any zone, for as long as an order is
+/// pending.
+pub struct AcmeChallengeTable {
+    zone: Box<str>,
+    apex: Option<Name>,
+    nameserver: Option<Name>,
+    ttl: u32,
+    records: ArcSwap<ChallengeRecords>,
+    direct: ArcSwap<FxHashMap<Box<str>, Vec<Arc<str>>>>,
+}
+
+impl AcmeChallengeTable {
+    /// `zone` and `nameserver` are lowercase without a trailing dot.
+    pub fn new(zone: &str, nameserver: &str, ttl: u32) -> Self {
+        Self {
+            zone: zone.into(),
+            apex: Self::fqdn(zone),
+            nameserver: Self::fqdn(nameserver),
+            ttl,
+            records: ArcSwap::from_pointee(ChallengeRecords {
+                serial: 1,
+                txt: FxHashMap::default(),
+            }),
+            direct: ArcSwap::from_pointee(FxHashMap::default()),
+        }
+    }
+
+    /// A table that answers nothing, for when acme-dns is disabled.
+    pub fn disabled() -> Self {
+        Self::new("", "", 0)
+    }
+
+    pub fn zone(&self) -> &str {
+        &self.zone
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.load().txt.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.load().txt.is_empty()
+    }
+
+    /// Returns true when `domain` (lowercase, no trailing dot) is the
+    /// challenge zone, a name under it, or a published DNS-01 name.
+    pub fn contains(&self, domain: &str) -> bool {
+        self.in_zone(domain) || self.is_published(domain)
+    }
+
+    fn in_zone(&self, domain: &str) -> bool {
+        self.apex.is_some()
+            && (domain == &*self.zone
+                || domain
+                    .strip_suffix(&*self.zone)
+                    .is_some_and(|prefix| prefix.ends_with('.')))
+    }
+
+    /// Adds a DNS-01 TXT value at `name` (lowercase, no trailing dot).
+    pub fn publish_txt(&self, name: &str, value: &str) {
+        self.direct.rcu(|direct| {
+            let mut direct = FxHashMap::clone(direct);
+            let values = direct.entry(name.into()).or_default();
+            if !values.iter().any(|v| v.as_ref() == value) {
+                values.push(Arc::from(value));
+            }
+            direct
+        });
+    }
+
+    /// Returns true when DNS-01 values are published at `domain`.
+    pub fn is_published(&self, domain: &str) -> bool {
+        self.direct.load().contains_key(domain)
+    }
+
+    /// Removes every DNS-01 TXT value published at `name`.
+    pub fn withdraw_txt(&self, name: &str) {
+        self.direct.rcu(|direct| {
+            let mut direct = FxHashMap::clone(direct);
+            direct.remove(name);
+            direct
+        });
+    }
+
+    /// Answers `domain` from the challenge zone, or `None` when it lies
+    /// outside it.
+    pub fn answer(
+        &self,
+        domain: &str,
+        record_type: HickoryRecordType,
+    ) -> Option<AuthorityResponse> {
+        if let Some(values) = self.direct.load().get(domain) {
+            let mut response = AuthorityResponse::new();
+            if record_type == HickoryRecordType::TXT {
+                let name = Name::from_ascii(format!("{}.", domain)).ok()?;
+                response
+                    .answers
+                    .extend(values.iter().map(|value| self.txt(&name, value)));
+            }
+            return Some(response);
+        }
+        if !self.in_zone(domain) {
+            return None;
+        }
+        let apex = self.apex.as_ref()?;
+        let records = self.records.load();
+        let mut response = AuthorityResponse::new();
+
+        if domain == &*self.zone {
+            match record_type {
+                HickoryRecordType::SOA => response.answers.push(self.soa(apex, records.serial)),
+                HickoryRecordType::NS => response.answers.push(self.ns(apex)),
+                _ => response.authority.push(self.soa(apex, records.serial)),
+            }
+            return Some(response);
+        }
+
+        let label = &domain[..domain.len() - self.zone.len() - 1];
+        let Some(values) = records.txt.get(label) else {
+            response.response_code = ResponseCode::NXDomain;
+            response.authority.push(self.soa(apex, records.serial));
+            return Some(response);
+        };
+
+        if record_type == HickoryRecordType::TXT && !values.is_empty() {
+            let name = Name::from_ascii(format!("{}.", domain)).ok()?;
+            response
+                .answers
+                .extend(values.iter().map(|value| self.txt(&name, value)));
+        } else {
+            response.authority.push(self.soa(apex, records.serial));
+        }
+        Some(response)
+    }
+
+    fn txt(&self, name: &Name, value: &str) -> Record {
+        Record::from_rdata(
+            name.clone(),
+            self.ttl.max(1),
+            RData::TXT(TXT::new(vec![value.to_string()])),
+        )
+    }
+
+    fn soa(&self, apex: &Name, serial: u32) -> Record {
+        let mname = self.nameserver.clone().unwrap_or_else(|| apex.clone());
+        let rname =
+            Self::fqdn(&format!("hostmaster.{}", self.zone)).unwrap_or_else(|| apex.clone());
+        Record::from_rdata(
+            apex.clone(),
+            self.ttl,
+            RData::SOA(SOA::new(
+                mname,
+                rname,
+                serial,
+                SOA_REFRESH,
+                SOA_RETRY,
+                SOA_EXPIRE,
+                self.ttl,
+            )),
+        )
+    }
+
+    fn ns(&self, apex: &Name) -> Record {
+        let nameserver = self.nameserver.clone().unwrap_or_else(|| apex.clone());
+        Record::from_rdata(apex.clone(), self.ttl, RData::NS(NS(nameserver)))
+    }
+
+    fn fqdn(name: &str) -> Option<Name> {
+        if name.is_empty() {
+            return None;
+        }
+        Name::from_ascii(format!("{}.", name.trim_end_matches('.'))).ok()
+    }
+}
+
+impl AcmeChallengeStore for AcmeChallengeTable {
+    fn replace_registrations(&self, registrations: &[AcmeRegistration]) {
+        let txt: FxHashMap<Box<str>, Vec<Arc<str>>> = registrations
+            .iter()
+            .map(|r| {
+                (
+                    r.subdomain.to_ascii_lowercase().into_boxed_str(),
+                    r.txt.clone(),
+                )
+            })
+            .collect();
+
+        info!(registrations = txt.len(), "ACME challenge records loaded");
+        let serial = self.records.load().serial.wrapping_add(1).max(1);
+        self.records
+            .store(Arc::new(ChallengeRecords { serial, txt }));
+    }
+}
@@ -0,0 +1,238 @@
+use super::zone::{compile_zones, AuthoritativeZone, ZoneLookup};
+use arc_swap::ArcSwap;
+use ferrous_dns_application::ports::LocalZoneStore;
+use ferrous_dns_domain::LocalZone;
+use hickory_proto::op::{Message, MessageType, OpCode, Query, ResponseCode};
+use hickory_proto::rr::{LowerName, Name, RData, Record, RecordType as HickoryRecordType};
+use hickory_proto::serialize::binary::{BinEncodable, BinEncoder};
+use rustc_hash::FxHashMap;
+use std::sync::Arc;
+use tracing::info;
+
+/// Longest CNAME chain followed through local zones.
+const MAX_CNAME_HOPS: usize = 8;
+
+/// A response composed from local zones.
+#[derive(Debug, Clone)]
+pub struct AuthorityResponse {
+    pub response_code: ResponseCode,
+    /// False for referrals, where the data belongs to a delegated server.
+    pub authoritative: bool,
+    pub answers: Vec<Record>,
+    pub authority: Vec<Record>,
+    pub additionals: Vec<Record>,
+    /// CNAME target that leaves every local zone, still to be resolved.
+    pub external_target: Option<Name>,
+}
+
+impl AuthorityResponse {
+    pub(crate) fn new() -> Self {
+        Self {
+            response_code: ResponseCode::NoError,
+            authoritative: true,
+            answers: Vec::new(),
+            authority: Vec::new(),
+            additionals: Vec::new(),
+            external_target: None,
+        }
+    }
+
+    /// Lowest TTL across the answer section, or the negative-caching TTL
+    /// of the SOA for NXDOMAIN/NODATA.
+    pub fn min_ttl(&self) -> Option<u32> {
+        self.answers
+            .iter()
+            .chain(self.authority.iter())
+            .map(Record::ttl)
+            .min()
+    }
+
+    pub fn negative_soa_ttl(&self) -> Option<u32> {
+        if !self.answers.is_empty() {
+            return None;
+        }
+        self.authority
+            .iter()
+            .find(|r| r.record_type() == HickoryRecordType::SOA)
+            .map(Record::ttl)
+    }
+
+    pub fn to_wire(&self, name: &Name, record_type: HickoryRecordType) -> Option<Vec<u8>> {
+        let mut message = Message::new(0, MessageType::Response, OpCode::Query);
+        message.set_response_code(self.response_code);
+        message.set_authoritative(self.authoritative);
+        message.set_recursion_desired(true);
+        message.set_recursion_available(true);
+        message.add_query(Query::query(name.clone(), record_type));
+        message.add_answers(self.answers.iter().cloned());
+        message.add_name_servers(self.authority.iter().cloned());
+        message.add_additionals(self.additionals.iter().cloned());
+
+        let mut buf = Vec::with_capacity(256);
+        let mut encoder = BinEncoder::new(&mut buf);
+        message.emit(&mut encoder).ok()?;
+        Some(buf)
+    }
+}
+
+/// Zones of each split-horizon view, keyed by the view's position in
+/// `[[dns.views]]`.
+pub type ViewAuthorities = FxHashMap<u32, Arc<LocalAuthority>>;
+
+/// Hot-swappable set of authoritative local zones.
+///
+/// Zones are keyed by their apex; lookups walk the query name towards the
+/// root so the most specific zone wins, like forwarding rules do.
+pub struct LocalAuthority {
+    zones: ArcSwap<FxHashMap<Box<str>, Arc<AuthoritativeZone>>>,
+}
+
+impl Default for LocalAuthority {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LocalAuthority {
+    pub fn new() -> Self {
+        Self {
+            zones: ArcSwap::from_pointee(FxHashMap::default()),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.zones.load().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.zones.load().is_empty()
+    }
+
+    /// Returns true when `domain` (lowercase, no trailing dot) falls inside
+    /// a local zone.
+    pub fn contains(&self, domain: &str) -> bool {
+        let zones = self.zones.load();
+        !zones.is_empty() && Self::find_zone(&zones, domain).is_some()
+    }
+
+    /// Answers `domain` from the local zones, or `None` when no zone
+    /// covers it.
+    pub fn answer(
+        &self,
+        domain: &str,
+        record_type: HickoryRecordType,
+    ) -> Option<AuthorityResponse> {
+        let zones = self.zones.load();
+        if zones.is_empty() {
+            return None;
+        }
+        let mut zone = Self::find_zone(&zones, domain)?;
+        let mut name = Name::from_ascii(format!("{}.", domain.trim_end_matches('.'))).ok()?;
+
+        let mut response = AuthorityResponse::new();
+        for _ in 0..MAX_CNAME_HOPS {
+            match zone.lookup(&name, record_type) {
+                ZoneLookup::Answer(records) => {
+                    Self::add_additionals(&zones, &records, &mut response.additionals);
+                    response.answers.extend(records);
+                    return Some(response);
+                }
+                ZoneLookup::Cname(record) => {
+                    let RData::CNAME(target) = record.data() else {
+                        return Some(response);
+                    };
+                    let target = target.0.clone();
+                    response.answers.push(*record);
+
+                    let target_str = target.to_ascii().to_ascii_lowercase();
+                    match Self::find_zone(&zones, target_str.trim_end_matches('.')) {
+                        Some(next) => {
+                            zone = next;
+                            name = target;
+                        }
+                        None => {
+                            response.external_target = Some(target);
+                            return Some(response);
+                        }
+                    }
+                }
+                ZoneLookup::NoData => {
+                    response.authority.push(zone.negative_soa());
+                    return Some(response);
+                }
+                ZoneLookup::NxDomain => {
+                    response.response_code = ResponseCode::NXDomain;
+                    response.authority.push(zone.negative_soa());
+                    return Some(response);
+                }
+                ZoneLookup::Referral { ns, glue } => {
+                    if response.answers.is_empty() {
+                        response.authoritative = false;
+                        response.authority = ns;
+                        response.additionals = glue;
+                    }
+                    return Some(response);
+                }
+            }
+        }
+
+        response.response_code = ResponseCode::ServFail;
+        Some(response)
+    }
+
+    /// Adds in-zone addresses for the targets of MX, SRV and NS answers
+    /// (RFC 1034 §3.6 additional section processing).
+    fn add_additionals(
+        zones: &FxHashMap<Box<str>, Arc<AuthoritativeZone>>,
+        answers: &[Record],
+        additionals: &mut Vec<Record>,
+    ) {
+        for record in answers {
+            let target = match record.data() {
+                RData::MX(mx) => mx.exchange(),
+                RData::SRV(srv) => srv.target(),
+                RData::NS(ns) => &ns.0,
+                _ => continue,
+            };
+            let target_str = target.to_ascii().to_ascii_lowercase();
+            if let Some(zone) = Self::find_zone(zones, target_str.trim_end_matches('.')) {
+                additionals.extend(zone.address_records(&LowerName::new(target)));
+            }
+        }
+    }
+
+    fn find_zone(
+        zones: &FxHashMap<Box<str>, Arc<AuthoritativeZone>>,
+        domain: &str,
+    ) -> Option<Arc<AuthoritativeZone>> {
+        let mut name = domain;
+        loop {
+            if let Some(zone) = zones.get(name) {
+                return Some(Arc::clone(zone));
+            }
+            match name.split_once('.') {
+                Some((_, parent)) => name = parent,
+                None => return None,
+            }
+        }
+    }
+}
+
+impl LocalZoneStore for LocalAuthority {
+    fn replace_zones(&self, zones: &[LocalZone]) {
+        let compiled: FxHashMap<Box<str>, Arc<AuthoritativeZone>> = compile_zones(zones)
+            .into_iter()
+            .map(|zone| {
+                let key = zone
+                    .origin()
+                    .to_ascii()
+                    .trim_end_matches('.')
+                    .to_ascii_lowercase();
+                (key.into_boxed_str(), Arc::new(zone))
+            })
+            .collect();
+
+        info!(zones = compiled.len(), "Local authoritative zones loaded");
+        self.zones.store(Arc::new(compiled));
+    }
+}
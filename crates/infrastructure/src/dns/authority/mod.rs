@@ -0,0 +1,9 @@
+pub mod acme_challenge;
+pub mod local_authority;
+pub mod zone;
+pub mod zone_file;
+
+pub use acme_challenge::AcmeChallengeTable;
+pub use local_authority::{AuthorityResponse, LocalAuthority, ViewAuthorities};
+pub use zone::{AuthoritativeZone, ZoneLookup};
+pub use zone_file::{format_zone, parse_zone_file, parse_zone_text};
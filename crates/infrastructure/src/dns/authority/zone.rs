@@ -0,0 +1,227 @@
+use super::zone_file::{compile_record, origin_name};
+use ferrous_dns_domain::LocalZone;
+use hickory_proto::rr::{LowerName, Name, RData, Record, RecordType as HickoryRecordType};
+use rustc_hash::{FxHashMap, FxHashSet};
+use tracing::warn;
+
+/// Outcome of looking a single name up inside one zone (RFC 1034 §4.3.2).
+#[derive(Debug, Clone)]
+pub enum ZoneLookup {
+    /// Records of the requested type, owned by the query name.
+    Answer(Vec<Record>),
+    /// The name is an alias; the caller continues with the target.
+    Cname(Box<Record>),
+    /// The name exists but has no records of the requested type.
+    NoData,
+    NxDomain,
+    /// The name lies at or below a delegation to another nameserver.
+    Referral {
+        ns: Vec<Record>,
+        glue: Vec<Record>,
+    },
+}
+
+/// A local zone compiled for lookups.
+pub struct AuthoritativeZone {
+    origin: Name,
+    soa: Record,
+    nodes: FxHashMap<LowerName, Vec<Record>>,
+    /// Every owner name plus the empty non-terminals between it and the apex.
+    names: FxHashSet<LowerName>,
+    /// Names below the apex that carry NS records.
+    cuts: FxHashSet<LowerName>,
+}
+
+impl AuthoritativeZone {
+    pub fn compile(zone: &LocalZone) -> Result<Self, String> {
+        let origin = origin_name(&zone.origin)?;
+        let origin_lower = LowerName::new(&origin);
+
+        let mut soa = None;
+        let mut nodes: FxHashMap<LowerName, Vec<Record>> = FxHashMap::default();
+        for zone_record in &zone.records {
+            let record = compile_record(&origin, zone_record)?;
+            if record.record_type() == HickoryRecordType::SOA {
+                soa = Some(record.clone());
+            }
+            nodes
+                .entry(LowerName::new(record.name()))
+                .or_default()
+                .push(record);
+        }
+        let soa = soa.ok_or_else(|| format!("zone '{}' has no SOA record", zone.origin))?;
+
+        let mut names = FxHashSet::default();
+        let mut cuts = FxHashSet::default();
+        for (name, records) in &nodes {
+            if *name != origin_lower
+                && records
+                    .iter()
+                    .any(|r| r.record_type() == HickoryRecordType::NS)
+            {
+                cuts.insert(name.clone());
+            }
+            let mut current = name.clone();
+            while origin_lower.zone_of(&current) && names.insert(current.clone()) {
+                if current == origin_lower {
+                    break;
+                }
+                current = current.base_name();
+            }
+        }
+
+        Ok(Self {
+            origin,
+            soa,
+            nodes,
+            names,
+            cuts,
+        })
+    }
+
+    pub fn origin(&self) -> &Name {
+        &self.origin
+    }
+
+    /// The SOA for negative answers, with its TTL capped at the SOA MINIMUM
+    /// as RFC 2308 §3 requires.
+    pub fn negative_soa(&self) -> Record {
+        let mut soa = self.soa.clone();
+        if let RData::SOA(data) = self.soa.data() {
+            soa.set_ttl(self.soa.ttl().min(data.minimum()));
+        }
+        soa
+    }
+
+    pub fn lookup(&self, qname: &Name, qtype: HickoryRecordType) -> ZoneLookup {
+        let lower = LowerName::new(qname);
+
+        if let Some(cut) = self.delegation_for(&lower) {
+            let ns: Vec<Record> = self.records_of(&cut, HickoryRecordType::NS).collect();
+            let glue = ns
+                .iter()
+                .filter_map(|r| match r.data() {
+                    RData::NS(target) => Some(LowerName::new(&target.0)),
+                    _ => None,
+                })
+                .filter(|target| cut.zone_of(target))
+                .flat_map(|target| self.address_records(&target))
+                .collect();
+            return ZoneLookup::Referral { ns, glue };
+        }
+
+        if let Some(records) = self.nodes.get(&lower) {
+            return Self::answer_at(qname, records, qtype);
+        }
+        if self.names.contains(&lower) {
+            return ZoneLookup::NoData;
+        }
+
+        // RFC 4592: a wildcard only applies directly below the closest
+        // encloser, the deepest existing ancestor of the query name.
+        let mut encloser = lower.base_name();
+        while !self.names.contains(&encloser) {
+            if encloser.is_root() {
+                return ZoneLookup::NxDomain;
+            }
+            encloser = encloser.base_name();
+        }
+        let wildcard = Name::from_ascii("*")
+            .and_then(|star| star.append_domain(&Name::from(&encloser)))
+            .map(|name| LowerName::new(&name));
+        match wildcard.ok().and_then(|w| self.nodes.get(&w)) {
+            Some(records) => Self::answer_at(qname, records, qtype),
+            None => ZoneLookup::NxDomain,
+        }
+    }
+
+    /// A/AAAA records at `name`, used for glue and additional-section data.
+    pub fn address_records(&self, name: &LowerName) -> Vec<Record> {
+        self.nodes
+            .get(name)
+            .map(|records| {
+                records
+                    .iter()
+                    .filter(|r| {
+                        matches!(
+                            r.record_type(),
+                            HickoryRecordType::A | HickoryRecordType::AAAA
+                        )
+                    })
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn delegation_for(&self, name: &LowerName) -> Option<LowerName> {
+        if self.cuts.is_empty() {
+            return None;
+        }
+        let origin_labels = self.origin.num_labels();
+        let mut current = name.clone();
+        let mut found = None;
+        while current.num_labels() > origin_labels {
+            if self.cuts.contains(&current) {
+                found = Some(current.clone());
+            }
+            current = current.base_name();
+        }
+        found
+    }
+
+    fn records_of(
+        &self,
+        name: &LowerName,
+        qtype: HickoryRecordType,
+    ) -> impl Iterator<Item = Record> + '_ {
+        self.nodes
+            .get(name)
+            .into_iter()
+            .flatten()
+            .filter(move |r| r.record_type() == qtype)
+            .cloned()
+    }
+
+    fn answer_at(qname: &Name, records: &[Record], qtype: HickoryRecordType) -> ZoneLookup {
+        let owned = |r: &Record| {
+            let mut r = r.clone();
+            r.set_name(qname.clone());
+            r
+        };
+
+        if qtype != HickoryRecordType::CNAME {
+            if let Some(cname) = records
+                .iter()
+                .find(|r| r.record_type() == HickoryRecordType::CNAME)
+            {
+                return ZoneLookup::Cname(Box::new(owned(cname)));
+            }
+        }
+
+        let answers: Vec<Record> = records
+            .iter()
+            .filter(|r| qtype == HickoryRecordType::ANY || r.record_type() == qtype)
+            .map(owned)
+            .collect();
+        if answers.is_empty() {
+            ZoneLookup::NoData
+        } else {
+            ZoneLookup::Answer(answers)
+        }
+    }
+}
+
+/// Compiles every zone, skipping (and logging) those that fail.
+pub fn compile_zones(zones: &[LocalZone]) -> Vec<AuthoritativeZone> {
+    zones
+        .iter()
+        .filter_map(|zone| match AuthoritativeZone::compile(zone) {
+            Ok(compiled) => Some(compiled),
+            Err(e) => {
+                warn!(zone = %zone.origin, error = %e, "Skipping local zone that failed to compile");
+                None
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,153 @@
+use ferrous_dns_domain::{LocalZone, ZoneRecord};
+use hickory_proto::rr::{Name, RData, Record};
+use hickory_proto::serialize::txt::Parser;
+use std::fmt::Write;
+use std::path::Path;
+
+/// Parses an RFC 1035 master file (`$ORIGIN`, `$TTL`, `$INCLUDE`) into a
+/// zone. Relative `$INCLUDE` paths resolve against the file's directory.
+pub fn parse_zone_file(path: &Path, origin: &str) -> Result<LocalZone, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    parse_zone_text(&text, Some(path), origin)
+}
+
+pub fn parse_zone_text(text: &str, path: Option<&Path>, origin: &str) -> Result<LocalZone, String> {
+    let file = path.map(|p| p.display().to_string()).unwrap_or_default();
+    let origin_name = origin_name(origin)?;
+
+    let (_, rrsets) = Parser::new(text, path.map(Path::to_path_buf), Some(origin_name))
+        .parse()
+        .map_err(|e| format!("{}: {}", file, e))?;
+
+    let mut zone = LocalZone::new(origin, &file, Vec::new());
+    for record in rrsets.values().flat_map(|set| set.records_without_rrsigs()) {
+        let zone_record = to_zone_record(record);
+        if !zone.contains(&zone_record.name) {
+            return Err(format!(
+                "{}: record '{}' is outside zone '{}'",
+                file, zone_record.name, zone.origin
+            ));
+        }
+        zone.records.push(zone_record);
+    }
+
+    match zone.soa() {
+        Some(soa) if soa.name == zone.origin => {}
+        _ => {
+            return Err(format!(
+                "{}: zone '{}' has no SOA record at its apex",
+                file, zone.origin
+            ))
+        }
+    }
+
+    zone.sort_records();
+    Ok(zone)
+}
+
+/// Serializes a zone back to master-file format. Every record is written
+/// inline, so `$INCLUDE` directives and comments in the original file are
+/// not preserved.
+pub fn format_zone(zone: &LocalZone) -> String {
+    let mut out = String::with_capacity(64 + zone.records.len() * 48);
+    let _ = writeln!(out, "; Zone {} — written by Ferrous DNS", zone.origin);
+    let _ = writeln!(out, "$ORIGIN {}.", zone.origin);
+    for record in &zone.records {
+        let _ = writeln!(
+            out,
+            "{}\t{}\tIN\t{}\t{}",
+            relative_owner(&record.name, &zone.origin),
+            record.ttl,
+            record.record_type,
+            record.data
+        );
+    }
+    out
+}
+
+/// Parses one zone record into wire form, validating its RDATA for the
+/// record type. Relative names in the data are qualified with `origin`.
+pub fn compile_record(origin: &Name, record: &ZoneRecord) -> Result<Record, String> {
+    let line = format!(
+        "{}. {} IN {} {}\n",
+        record.name, record.ttl, record.record_type, record.data
+    );
+    let (_, rrsets) = Parser::new(line, None, Some(origin.clone()))
+        .parse()
+        .map_err(|e| format!("{} record '{}': {}", record.record_type, record.name, e))?;
+
+    let mut parsed = rrsets
+        .into_values()
+        .flat_map(|set| set.records_without_rrsigs().cloned().collect::<Vec<_>>())
+        .next()
+        .ok_or_else(|| format!("{} record '{}' is empty", record.record_type, record.name))?;
+    // The master-file parser stamps SOA records with their EXPIRE value.
+    parsed.set_ttl(record.ttl);
+    Ok(parsed)
+}
+
+pub fn origin_name(origin: &str) -> Result<Name, String> {
+    let origin = LocalZone::normalize_name(origin);
+    if origin.is_empty() {
+        return Err("Zone origin cannot be empty".to_string());
+    }
+    Name::from_ascii(format!("{}.", origin))
+        .map_err(|e| format!("Invalid zone origin '{}': {}", origin, e))
+}
+
+fn to_zone_record(record: &Record) -> ZoneRecord {
+    let ttl = match record.data() {
+        // See `compile_record`: the parsed TTL of an SOA is its EXPIRE, so
+        // fall back to MINIMUM, the TTL negative answers are cached for.
+        RData::SOA(soa) => soa.minimum(),
+        _ => record.ttl(),
+    };
+    ZoneRecord::new(
+        &record.name().to_ascii(),
+        ttl,
+        &record.record_type().to_string(),
+        &presentation(record.data()),
+    )
+}
+
+/// Renders RDATA the way it is written in a zone file. Hickory's `Display`
+/// is already master-file syntax except for TXT, whose strings it prints
+/// unquoted.
+fn presentation(rdata: &RData) -> String {
+    match rdata {
+        RData::TXT(txt) => {
+            let mut out = String::new();
+            for (i, chunk) in txt.txt_data().iter().enumerate() {
+                if i > 0 {
+                    out.push(' ');
+                }
+                out.push('"');
+                for ch in String::from_utf8_lossy(chunk).chars() {
+                    match ch {
+                        '"' | '\\' => {
+                            out.push('\\');
+                            out.push(ch);
+                        }
+                        c if c.is_ascii_control() => {
+                            let _ = write!(out, "\\{:03}", c as u8);
+                        }
+                        c => out.push(c),
+                    }
+                }
+                out.push('"');
+            }
+            out
+        }
+        other => other.to_string(),
+    }
+}
+
+fn relative_owner(name: &str, origin: &str) -> String {
+    if name == origin {
+        return "@".to_string();
+    }
+    match name.strip_suffix(origin) {
+        Some(prefix) if prefix.ends_with('.') => prefix.trim_end_matches('.').to_string(),
+        _ => format!("{}.", name),
+    }
+}
@@ -1,21 +1,22 @@
+use super::rules::{ListRules, QueryContext};
+use super::source_set::{
+    GroupMask, SourceBits, SourceIndex, SourceSetId, SourceSets, MANUAL_SOURCE,
+};
 use super::suffix_trie::SuffixTrie;
 use crate::dns::cache::bloom::AtomicBloom;
 use aho_corasick::AhoCorasick;
 use compact_str::CompactString;
 use dashmap::{DashMap, DashSet};
 use fancy_regex::Regex;
+use ferrous_dns_application::ports::{FilterTrace, TraceMatch, TraceRuleKind};
 use ferrous_dns_domain::BlockSource;
 use rustc_hash::FxBuildHasher;
 use std::collections::{HashMap, HashSet};
 
-pub type SourceBitSet = u64;
-
-pub const MANUAL_SOURCE_BIT: u64 = 1u64 << 63;
-
 #[derive(Debug, Clone)]
 pub struct SourceMeta {
     pub group_id: i64,
-    pub bit: u8,
+    pub source: SourceIndex,
 }
 
 pub struct AllowlistIndex {
@@ -23,6 +24,10 @@ pub struct AllowlistIndex {
     pub global_wildcard: SuffixTrie,
     pub group_exact: HashMap<i64, DashSet<CompactString, FxBuildHasher>>,
     pub group_wildcard: HashMap<i64, SuffixTrie>,
+    /// `@@` exceptions from blocklist sources, with the set of sources
+    /// listing them. They only lift blocks for groups subscribed to one.
+    pub source_exact: DashMap<CompactString, SourceSetId, FxBuildHasher>,
+    pub source_wildcard: SuffixTrie<SourceSetId>,
 }
 
 impl AllowlistIndex {
@@ -32,6 +37,8 @@ impl AllowlistIndex {
             global_wildcard: SuffixTrie::new(),
             group_exact: HashMap::new(),
             group_wildcard: HashMap::new(),
+            source_exact: DashMap::with_hasher(FxBuildHasher),
+            source_wildcard: SuffixTrie::new(),
         }
     }
 
@@ -55,6 +62,19 @@ impl AllowlistIndex {
         }
         false
     }
+
+    /// Whether a blocklist exception from a source in `mask` covers `domain`.
+    #[inline]
+    pub fn is_excepted(&self, domain: &str, mask: &GroupMask) -> bool {
+        if let Some(set) = self.source_exact.get(domain) {
+            if mask.covers(*set) {
+                return true;
+            }
+        }
+        self.source_wildcard
+            .find(domain, |set| mask.covers(set))
+            .is_some()
+    }
 }
 
 impl Default for AllowlistIndex {
@@ -64,24 +84,44 @@ impl Default for AllowlistIndex {
 }
 
 pub struct BlockIndex {
-    pub group_masks: HashMap<i64, SourceBitSet>,
+    pub group_masks: HashMap<i64, GroupMask>,
+    /// Mask for groups missing from `group_masks`: the manual blocklist only.
+    pub default_mask: GroupMask,
+    /// Source combinations referenced by the rules below.
+    pub source_sets: SourceSets,
     pub total_blocked_domains: usize,
-    pub exact: DashMap<CompactString, SourceBitSet, FxBuildHasher>,
+    pub exact: DashMap<CompactString, SourceSetId, FxBuildHasher>,
     pub bloom: AtomicBloom,
-    pub wildcard: SuffixTrie,
-    pub patterns: Vec<(AhoCorasick, SourceBitSet)>,
+    pub wildcard: SuffixTrie<SourceSetId>,
+    pub patterns: Vec<(AhoCorasick, SourceSetId)>,
     pub allowlists: AllowlistIndex,
     pub managed_denies: HashMap<i64, DashSet<CompactString, FxBuildHasher>>,
     pub managed_deny_wildcards: HashMap<i64, SuffixTrie>,
     pub allow_regex_patterns: HashMap<i64, Vec<Regex>>,
     pub block_regex_patterns: HashMap<i64, Vec<Regex>>,
     pub groups_with_advanced_rules: HashSet<i64>,
+    /// Blocklist rules with `$` modifiers or exceptions the allowlist cannot hold.
+    pub list_rules: ListRules,
+    /// Lowercased group names, matched by `$ctag`.
+    pub group_names: HashMap<i64, CompactString>,
+    /// Names of the blocklist sources, for explaining decisions.
+    pub source_names: HashMap<SourceIndex, CompactString>,
+    /// Database ids of the blocklist sources, for attributing blocks.
+    pub source_ids: HashMap<SourceIndex, i64>,
+    /// Masks over each group's enforced and observe-only sources, for the
+    /// groups subscribed to an observe-only source.
+    pub observe_masks: HashMap<i64, GroupMask>,
+    /// Sources whose matches are logged as would-block, never enforced.
+    pub observe_sources: SourceBits,
 }
 
 impl BlockIndex {
     pub fn empty() -> Self {
+        let source_sets = SourceSets::new();
         Self {
             group_masks: HashMap::new(),
+            default_mask: source_sets.group_mask(&SourceBits::new()),
+            source_sets,
             total_blocked_domains: 0,
             exact: DashMap::with_hasher(FxBuildHasher),
             bloom: AtomicBloom::new(1000, 0.001),
@@ -93,21 +133,96 @@ impl BlockIndex {
             allow_regex_patterns: HashMap::new(),
             block_regex_patterns: HashMap::new(),
             groups_with_advanced_rules: HashSet::new(),
+            list_rules: ListRules::new(),
+            group_names: HashMap::new(),
+            source_names: HashMap::new(),
+            source_ids: HashMap::new(),
+            observe_masks: HashMap::new(),
+            observe_sources: SourceBits::new(),
         }
     }
 
-    /// Returns the bitmask for a group. Groups not in the map get only the
-    /// manual-blocklist bit — they are NOT promoted to the default group.
+    /// Returns the mask for a group. Groups not in the map get only the
+    /// manual blocklist — they are NOT promoted to the default group.
     #[inline]
-    pub fn group_mask(&self, group_id: i64) -> SourceBitSet {
+    pub fn group_mask(&self, group_id: i64) -> &GroupMask {
         self.group_masks
             .get(&group_id)
-            .copied()
-            .unwrap_or(MANUAL_SOURCE_BIT)
+            .unwrap_or(&self.default_mask)
+    }
+
+    /// Resolves per-group subscriptions into masks over `source_sets`. Must
+    /// run once every rule has been added.
+    pub fn set_group_sources(&mut self, group_sources: &HashMap<i64, SourceBits>) {
+        self.group_masks = group_sources
+            .iter()
+            .map(|(&group_id, sources)| (group_id, self.source_sets.group_mask(sources)))
+            .collect();
+        self.default_mask = self.source_sets.group_mask(&SourceBits::new());
+    }
+
+    /// Resolves per-group observe-only subscriptions. Each mask also holds
+    /// the group's enforced sources, so their exceptions still apply. Must
+    /// run after [`set_group_sources`](Self::set_group_sources).
+    pub fn set_observed_sources(&mut self, observed: &HashMap<i64, SourceBits>) {
+        self.observe_sources = SourceBits::new();
+        self.observe_masks = HashMap::with_capacity(observed.len());
+        for (&group_id, sources) in observed {
+            let mut all = self.group_mask(group_id).sources().clone();
+            for source in sources.iter() {
+                all.insert(source);
+                self.observe_sources.insert(source);
+            }
+            self.observe_masks
+                .insert(group_id, self.source_sets.group_mask(&all));
+        }
     }
 
     #[inline]
     pub fn is_blocked(&self, domain: &str, group_id: i64) -> Option<BlockSource> {
+        self.evaluate(domain, group_id, None)
+    }
+
+    /// Like [`is_blocked`](Self::is_blocked), also applying `$dnstype` and
+    /// `$client` rules.
+    #[inline]
+    pub fn is_blocked_for_query(
+        &self,
+        domain: &str,
+        group_id: i64,
+        ctx: &QueryContext,
+    ) -> Option<BlockSource> {
+        self.evaluate(domain, group_id, Some(ctx))
+    }
+
+    /// Whether an exception from a source the group is subscribed to
+    /// allows `domain`, regardless of any block rule.
+    pub fn is_excepted(&self, domain: &str, group_id: i64, ctx: Option<&QueryContext>) -> bool {
+        let mask = self.group_mask(group_id);
+        if self.allowlists.is_excepted(domain, mask) {
+            return true;
+        }
+        if self.list_rules.is_empty() {
+            return false;
+        }
+        let group_name = self.group_names.get(&group_id).map(|n| n.as_str());
+        let verdict = self.list_rules.evaluate(domain, mask, group_name, ctx);
+        verdict.allow || verdict.important_allow
+    }
+
+    /// Whether a `$dnstype` or `$client` rule targets `domain`, so a decision
+    /// made without the query context may be wrong.
+    #[inline]
+    pub fn needs_query_context(&self, domain: &str) -> bool {
+        self.list_rules.needs_context(domain)
+    }
+
+    fn evaluate(
+        &self,
+        domain: &str,
+        group_id: i64,
+        ctx: Option<&QueryContext>,
+    ) -> Option<BlockSource> {
         if self.allowlists.is_allowed(domain, group_id) {
             return None;
         }
@@ -137,28 +252,8 @@ impl BlockIndex {
             }
         }
 
-        if !self.bloom.check(&domain) {
-            if has_advanced {
-                if let Some(regexes) = self.block_regex_patterns.get(&group_id) {
-                    for r in regexes {
-                        if r.is_match(domain).unwrap_or(false) {
-                            return Some(BlockSource::RegexFilter);
-                        }
-                    }
-                }
-            }
-            return None;
-        }
-
-        if let Some(entry) = self.exact.get(domain) {
-            if entry.value() & mask != 0 {
-                return Some(BlockSource::Blocklist);
-            }
-        }
-
-        let wildcard_result = self.check_wildcard_and_patterns(domain, mask);
-        if wildcard_result.is_some() {
-            return wildcard_result;
+        if self.is_list_blocked(domain, group_id, mask, ctx) {
+            return Some(BlockSource::Blocklist);
         }
 
         if has_advanced {
@@ -174,19 +269,307 @@ impl BlockIndex {
         None
     }
 
+    /// Applies the blocklist sources in `mask`. Manual blocklist entries
+    /// always block; otherwise `$important` rules win over exceptions, and
+    /// exceptions win over ordinary block rules.
     #[inline]
-    fn check_wildcard_and_patterns(&self, domain: &str, mask: SourceBitSet) -> Option<BlockSource> {
-        let wildcard_bits = self.wildcard.lookup(domain);
-        if wildcard_bits & mask != 0 {
-            return Some(BlockSource::Blocklist);
+    fn is_list_blocked(
+        &self,
+        domain: &str,
+        group_id: i64,
+        mask: &GroupMask,
+        ctx: Option<&QueryContext>,
+    ) -> bool {
+        let matched = self.matching_sources(domain, mask);
+        if matched.is_some_and(|set| self.source_sets.get(set).contains(MANUAL_SOURCE)) {
+            return true;
+        }
+
+        if self.list_rules.is_empty() {
+            return matched.is_some() && !self.allowlists.is_excepted(domain, mask);
         }
 
-        for (ac, source_mask) in &self.patterns {
-            if source_mask & mask != 0 && ac.is_match(domain) {
-                return Some(BlockSource::Blocklist);
+        let group_name = self.group_names.get(&group_id).map(|n| n.as_str());
+        let verdict = self.list_rules.evaluate(domain, mask, group_name, ctx);
+        if verdict.important_allow {
+            return false;
+        }
+        if verdict.important_block {
+            return true;
+        }
+        (matched.is_some() || verdict.block)
+            && !verdict.allow
+            && !self.allowlists.is_excepted(domain, mask)
+    }
+
+    /// The sources listing the first plain rule for `domain` that applies
+    /// to `mask`.
+    #[inline]
+    fn matching_sources(&self, domain: &str, mask: &GroupMask) -> Option<SourceSetId> {
+        if self.bloom.check(&domain) {
+            if let Some(entry) = self.exact.get(domain) {
+                let set = *entry.value();
+                if mask.covers(set) {
+                    return Some(set);
+                }
             }
         }
 
-        None
+        if let Some(set) = self.wildcard.find(domain, |set| mask.covers(set)) {
+            return Some(set);
+        }
+
+        self.patterns
+            .iter()
+            .find(|(ac, set)| mask.covers(*set) && ac.is_match(domain))
+            .map(|(_, set)| *set)
     }
+
+    /// Every entry matching `domain` for `group_id`. Unlike
+    /// [`is_blocked`](Self::is_blocked) this does not stop at the first
+    /// match, so it is only meant for troubleshooting.
+    pub fn explain(&self, domain: &str, group_id: i64, ctx: Option<&QueryContext>) -> FilterTrace {
+        let mask = self.group_mask(group_id);
+        let names = |set: SourceSetId| -> Vec<String> {
+            self.source_sets
+                .get(set)
+                .iter()
+                .filter(|&source| source != MANUAL_SOURCE && mask.sources().contains(source))
+                .map(|source| self.source_name(source))
+                .collect()
+        };
+        let entry = |kind: TraceRuleKind, rule: String| TraceMatch {
+            kind,
+            rule,
+            sources: Vec::new(),
+        };
+        let wildcard_rule = |parent: &str| format!("*.{parent}");
+
+        let mut trace = FilterTrace {
+            group_sources: mask
+                .sources()
+                .iter()
+                .filter(|&source| source != MANUAL_SOURCE)
+                .map(|source| self.source_name(source))
+                .collect(),
+            ..FilterTrace::default()
+        };
+        let allow = &mut trace.allow_matches;
+
+        let allowlists = &self.allowlists;
+        let group_exact = allowlists.group_exact.get(&group_id);
+        if allowlists.global_exact.contains(domain)
+            || group_exact.is_some_and(|set| set.contains(domain))
+        {
+            allow.push(entry(TraceRuleKind::Allowlist, domain.to_string()));
+        }
+        let group_wildcard = allowlists.group_wildcard.get(&group_id);
+        for trie in std::iter::once(&allowlists.global_wildcard).chain(group_wildcard) {
+            for (parent, _) in trie.matches(domain) {
+                allow.push(entry(TraceRuleKind::Allowlist, wildcard_rule(parent)));
+            }
+        }
+        for r in self
+            .allow_regex_patterns
+            .get(&group_id)
+            .into_iter()
+            .flatten()
+        {
+            if r.is_match(domain).unwrap_or(false) {
+                allow.push(entry(TraceRuleKind::RegexFilter, regex_text(r)));
+            }
+        }
+        if let Some(set) = allowlists.source_exact.get(domain) {
+            if mask.covers(*set) {
+                allow.push(TraceMatch {
+                    kind: TraceRuleKind::ListException,
+                    rule: format!("@@|{domain}^"),
+                    sources: names(*set),
+                });
+            }
+        }
+        for (parent, set) in allowlists.source_wildcard.matches(domain) {
+            if mask.covers(set) {
+                allow.push(TraceMatch {
+                    kind: TraceRuleKind::ListException,
+                    rule: format!("@@{}", wildcard_rule(parent)),
+                    sources: names(set),
+                });
+            }
+        }
+
+        let block = &mut trace.block_matches;
+        if self
+            .managed_denies
+            .get(&group_id)
+            .is_some_and(|set| set.contains(domain))
+        {
+            block.push(entry(TraceRuleKind::ManagedDomain, domain.to_string()));
+        }
+        if let Some(trie) = self.managed_deny_wildcards.get(&group_id) {
+            for (parent, _) in trie.matches(domain) {
+                block.push(entry(TraceRuleKind::ManagedDomain, wildcard_rule(parent)));
+            }
+        }
+        if let Some(set) = self.exact.get(domain) {
+            if self.source_sets.get(*set).contains(MANUAL_SOURCE) {
+                block.push(entry(TraceRuleKind::ManualBlocklist, domain.to_string()));
+            }
+            let sources = names(*set);
+            if !sources.is_empty() {
+                block.push(TraceMatch {
+                    kind: TraceRuleKind::ListExact,
+                    rule: domain.to_string(),
+                    sources,
+                });
+            }
+        }
+        for (parent, set) in self.wildcard.matches(domain) {
+            if mask.covers(set) {
+                block.push(TraceMatch {
+                    kind: TraceRuleKind::ListWildcard,
+                    rule: wildcard_rule(parent),
+                    sources: names(set),
+                });
+            }
+        }
+        for (ac, set) in &self.patterns {
+            if !mask.covers(*set) {
+                continue;
+            }
+            if let Some(m) = ac.find(domain) {
+                block.push(TraceMatch {
+                    kind: TraceRuleKind::ListPattern,
+                    rule: format!("/{}/", &domain[m.start()..m.end()]),
+                    sources: names(*set),
+                });
+            }
+        }
+        let group_name = self.group_names.get(&group_id).map(|n| n.as_str());
+        for rule in self.list_rules.matching(domain, mask, group_name, ctx) {
+            let matched = TraceMatch {
+                kind: if rule.exception {
+                    TraceRuleKind::ListException
+                } else {
+                    TraceRuleKind::ListRule
+                },
+                rule: rule.text,
+                sources: names(rule.sources),
+            };
+            if rule.exception {
+                trace.allow_matches.push(matched);
+            } else {
+                trace.block_matches.push(matched);
+            }
+        }
+        for r in self
+            .block_regex_patterns
+            .get(&group_id)
+            .into_iter()
+            .flatten()
+        {
+            if r.is_match(domain).unwrap_or(false) {
+                trace
+                    .block_matches
+                    .push(entry(TraceRuleKind::RegexFilter, regex_text(r)));
+            }
+        }
+
+        trace
+    }
+
+    /// Database ids of the sources, among those `group_id` is subscribed
+    /// to, with an entry blocking `domain`. Exceptions are not considered:
+    /// this attributes a block already decided by [`evaluate`](Self::evaluate).
+    pub fn blocking_source_ids(
+        &self,
+        domain: &str,
+        group_id: i64,
+        ctx: Option<&QueryContext>,
+    ) -> Vec<i64> {
+        let mask = self.group_mask(group_id);
+        self.matching_source_ids(domain, group_id, mask, ctx, |source| {
+            mask.sources().contains(source)
+        })
+    }
+
+    /// Database ids of the observe-only sources that would block `domain`
+    /// for `group_id` if they were enforced. Empty for groups without
+    /// observe-only sources.
+    pub fn observed_source_ids(
+        &self,
+        domain: &str,
+        group_id: i64,
+        ctx: Option<&QueryContext>,
+    ) -> Vec<i64> {
+        let Some(mask) = self.observe_masks.get(&group_id) else {
+            return Vec::new();
+        };
+        if self.allowlists.is_allowed(domain, group_id)
+            || !self.is_list_blocked(domain, group_id, mask, ctx)
+        {
+            return Vec::new();
+        }
+        self.matching_source_ids(domain, group_id, mask, ctx, |source| {
+            mask.sources().contains(source) && self.observe_sources.contains(source)
+        })
+    }
+
+    fn matching_source_ids(
+        &self,
+        domain: &str,
+        group_id: i64,
+        mask: &GroupMask,
+        ctx: Option<&QueryContext>,
+        keep: impl Fn(SourceIndex) -> bool,
+    ) -> Vec<i64> {
+        let mut sets: Vec<SourceSetId> = Vec::new();
+        if let Some(set) = self.exact.get(domain) {
+            sets.push(*set);
+        }
+        sets.extend(
+            self.wildcard
+                .matches(domain)
+                .into_iter()
+                .map(|(_, set)| set),
+        );
+        sets.extend(
+            self.patterns
+                .iter()
+                .filter(|(ac, _)| ac.is_match(domain))
+                .map(|(_, set)| *set),
+        );
+        let group_name = self.group_names.get(&group_id).map(|n| n.as_str());
+        sets.extend(
+            self.list_rules
+                .blocking_sources(domain, mask, group_name, ctx),
+        );
+
+        let mut sources = SourceBits::new();
+        for set in sets {
+            for source in self.source_sets.get(set).iter() {
+                if source != MANUAL_SOURCE && keep(source) {
+                    sources.insert(source);
+                }
+            }
+        }
+        sources
+            .iter()
+            .filter_map(|source| self.source_ids.get(&source).copied())
+            .collect()
+    }
+
+    fn source_name(&self, source: SourceIndex) -> String {
+        self.source_names
+            .get(&source)
+            .map(|name| name.to_string())
+            .unwrap_or_else(|| format!("source #{source}"))
+    }
+}
+
+/// The pattern of a regex filter as configured, without the `(?i)` flag
+/// added when compiling it.
+fn regex_text(r: &Regex) -> String {
+    let pattern = r.as_str();
+    pattern.strip_prefix("(?i)").unwrap_or(pattern).to_string()
 }
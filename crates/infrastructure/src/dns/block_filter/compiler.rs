@@ -1,6 +1,12 @@
-use super::block_index::{AllowlistIndex, BlockIndex, SourceBitSet, SourceMeta, MANUAL_SOURCE_BIT};
+use super::block_index::{AllowlistIndex, BlockIndex, SourceMeta};
+use super::rules::{FilterRule, ListRules, RuleDirectory, RuleTarget};
+use super::source_fetch::load_list_source;
+use super::source_set::{
+    SourceBits, SourceIndex, SourceSetId, SourceSets, MANUAL_SOURCE_SET, NO_SOURCES,
+};
 use super::suffix_trie::SuffixTrie;
 use crate::dns::cache::bloom::AtomicBloom;
+use crate::repositories::list_source_fetches;
 use aho_corasick::AhoCorasick;
 use compact_str::CompactString;
 use dashmap::{DashMap, DashSet};
@@ -8,10 +14,11 @@ use fancy_regex::Regex;
 use ferrous_dns_domain::DomainError;
 use futures::future::join_all;
 use rayon::prelude::*;
-use rustc_hash::FxBuildHasher;
+use rustc_hash::{FxBuildHasher, FxHashMap};
 use sqlx::{Row, SqlitePool};
-use std::collections::HashMap;
-use std::sync::LazyLock;
+use std::collections::{HashMap, HashSet};
+use std::net::IpAddr;
+use std::sync::{LazyLock, Mutex, PoisonError};
 use tracing::{info, warn};
 
 static BLOCKLIST_BUILD_POOL: LazyLock<rayon::ThreadPool> = LazyLock::new(|| {
@@ -25,11 +32,107 @@ static BLOCKLIST_BUILD_POOL: LazyLock<rayon::ThreadPool> = LazyLock::new(|| {
         .expect("blocklist rayon pool")
 });
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ParsedEntry {
     Exact(String),
     Wildcard(String),
     Pattern(String),
+    /// `||domain^`: the domain and every name below it.
+    Subtree(String),
+    /// An `@@` exception or a rule with `$` modifiers.
+    Rule(Box<FilterRule>),
+}
+
+impl ParsedEntry {
+    /// The entry for `rule`, using a plain variant when it has no modifiers.
+    pub fn from_rule(rule: FilterRule) -> Self {
+        if !rule.is_plain() {
+            return ParsedEntry::Rule(Box::new(rule));
+        }
+        match rule.target {
+            RuleTarget::Exact(d) => ParsedEntry::Exact(d),
+            RuleTarget::Wildcard(d) => ParsedEntry::Wildcard(d),
+            RuleTarget::Pattern(p) => ParsedEntry::Pattern(p),
+            RuleTarget::Subtree(d) => ParsedEntry::Subtree(d),
+        }
+    }
+}
+
+fn is_rule_domain(domain: &str) -> bool {
+    let domain = domain.strip_prefix("*.").unwrap_or(domain);
+    !domain.is_empty()
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && domain
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'-' | b'_'))
+}
+
+/// Parses the part of an adblock rule before `$`. URL rules with a path
+/// or a mid-name wildcard have no DNS meaning and yield `None`.
+fn parse_rule_target(pattern: &str) -> Option<RuleTarget> {
+    if pattern.len() > 2 && pattern.starts_with('/') && pattern.ends_with('/') {
+        return Some(RuleTarget::Pattern(
+            pattern[1..pattern.len() - 1].to_lowercase(),
+        ));
+    }
+
+    let (subtree, rest) = match pattern.strip_prefix("||") {
+        Some(rest) => (true, rest),
+        None => (false, pattern.strip_prefix('|').unwrap_or(pattern)),
+    };
+    let domain = rest
+        .trim_end_matches('|')
+        .trim_end_matches('^')
+        .to_ascii_lowercase();
+    if !is_rule_domain(&domain) {
+        return None;
+    }
+
+    Some(if domain.starts_with("*.") {
+        RuleTarget::Wildcard(domain)
+    } else if subtree {
+        RuleTarget::Subtree(domain)
+    } else {
+        RuleTarget::Exact(domain)
+    })
+}
+
+/// Parses an AdGuard/uBlock rule, with the `@@` prefix already removed
+/// when `exception` is set.
+fn parse_adblock_rule(rule: &str, exception: bool) -> Option<ParsedEntry> {
+    let (pattern, modifiers) = if rule.starts_with('/') {
+        match rule.rfind("/$") {
+            Some(pos) if pos > 0 => (&rule[..=pos], Some(&rule[pos + 2..])),
+            _ => (rule, None),
+        }
+    } else {
+        match rule.split_once('$') {
+            Some((p, m)) => (p, Some(m)),
+            None => (rule, None),
+        }
+    };
+
+    let mut parsed = FilterRule::new(parse_rule_target(pattern.trim())?);
+    parsed.exception = exception;
+    if let Some(modifiers) = modifiers {
+        if !parsed.apply_modifiers(modifiers) {
+            return None;
+        }
+    }
+
+    // A rule for a bare TLD blocks a whole namespace; only allow it when
+    // `$denyallow` carves out the names that must keep resolving.
+    if let Some(domain) = match &parsed.target {
+        RuleTarget::Exact(d) | RuleTarget::Subtree(d) => Some(d),
+        _ => None,
+    } {
+        if !domain.contains('.') && (parsed.exception || parsed.denyallow.is_empty()) {
+            return None;
+        }
+    }
+
+    Some(ParsedEntry::from_rule(parsed))
 }
 
 pub fn parse_list_line(line: &str) -> Option<ParsedEntry> {
@@ -39,28 +142,16 @@ pub fn parse_list_line(line: &str) -> Option<ParsedEntry> {
         return None;
     }
 
-    if line.starts_with("@@") {
-        return None;
+    if let Some(rule) = line.strip_prefix("@@") {
+        return parse_adblock_rule(rule, true);
     }
 
     if line.starts_with('/') && line.ends_with('/') && line.len() > 2 {
         return Some(ParsedEntry::Pattern(line[1..line.len() - 1].to_lowercase()));
     }
 
-    if line.starts_with("||") {
-        let inner = line.strip_prefix("||").unwrap_or("");
-        let domain = match inner.find('^') {
-            Some(pos) => &inner[..pos],
-            None => inner,
-        };
-        let domain = domain.trim().to_ascii_lowercase();
-        if domain.is_empty() || !domain.contains('.') {
-            return None;
-        }
-        if domain.starts_with("*.") {
-            return Some(ParsedEntry::Wildcard(domain));
-        }
-        return Some(ParsedEntry::Exact(domain));
+    if line.starts_with('|') || line.starts_with('/') || line.contains('$') || line.ends_with('^') {
+        return parse_adblock_rule(line, false);
     }
 
     if line.starts_with("*.") {
@@ -89,7 +180,7 @@ pub fn parse_list_line(line: &str) -> Option<ParsedEntry> {
         }
     }
 
-    if parts.len() == 1 && parts[0].contains('.') {
+    if parts.len() == 1 && parts[0].contains('.') && is_rule_domain(parts[0]) {
         return Some(ParsedEntry::Exact(parts[0].to_ascii_lowercase()));
     }
 
@@ -100,28 +191,42 @@ pub fn parse_list_text(text: &str) -> Vec<ParsedEntry> {
     text.lines().filter_map(parse_list_line).collect()
 }
 
-async fn fetch_url(url: &str, client: &reqwest::Client) -> Result<String, String> {
-    let response = client
-        .get(url)
-        .timeout(std::time::Duration::from_secs(30))
-        .send()
-        .await
-        .map_err(|e| format!("fetch error for {}: {}", url, e))?;
+/// Comments, list headers and hosts-file aliases for local addresses,
+/// which carry no rule but are not malformed either.
+fn is_ignorable_line(line: &str) -> bool {
+    let line = line.trim();
+    line.is_empty()
+        || line.starts_with(['#', '!', '['])
+        || line
+            .split_whitespace()
+            .next()
+            .and_then(|addr| addr.split('%').next())
+            .is_some_and(|addr| addr.parse::<IpAddr>().is_ok())
+}
 
-    if !response.status().is_success() {
-        return Err(format!("HTTP {} for {}", response.status().as_u16(), url));
+/// Parses a list and counts the lines that are neither comments nor
+/// rules the filter understands.
+pub fn parse_list_text_counted(text: &str) -> (Vec<ParsedEntry>, usize) {
+    let mut entries = Vec::new();
+    let mut errors = 0;
+    for line in text.lines() {
+        match parse_list_line(line) {
+            Some(entry) => entries.push(entry),
+            None if is_ignorable_line(line) => {}
+            None => errors += 1,
+        }
     }
-
-    response
-        .text()
-        .await
-        .map_err(|e| format!("read error for {}: {}", url, e))
+    (entries, errors)
 }
 
 struct SourceLoad {
     default_group_id: i64,
     sources: Vec<SourceMeta>,
-    url_tasks: Vec<(u8, String)>,
+    /// Assignments of observe-only sources, kept out of `sources`.
+    observed: Vec<SourceMeta>,
+    url_tasks: Vec<(SourceIndex, String)>,
+    source_names: HashMap<SourceIndex, CompactString>,
+    source_ids: HashMap<SourceIndex, i64>,
     all_group_ids: Vec<i64>,
 }
 
@@ -133,26 +238,33 @@ async fn load_sources(pool: &SqlitePool) -> Result<SourceLoad, DomainError> {
         .map(|row| row.get::<i64, _>("id"))
         .unwrap_or(1);
 
-    // Step 1: Load distinct enabled sources for bit assignment (max 63)
-    let source_rows =
-        sqlx::query("SELECT id, url FROM blocklist_sources WHERE enabled = 1 ORDER BY id")
-            .fetch_all(pool)
-            .await
-            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+    // Step 1: Load distinct enabled sources and number them from 1
+    // (0 is the manual blocklist)
+    let source_rows = sqlx::query(
+        "SELECT id, name, url, observe_only FROM blocklist_sources WHERE enabled = 1 ORDER BY id",
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
 
-    if source_rows.len() > 63 {
-        warn!(
-            count = source_rows.len(),
-            "More than 63 blocklist sources; only the first 63 will be used"
-        );
-    }
+    let id_to_index: HashMap<i64, SourceIndex> = source_rows
+        .iter()
+        .enumerate()
+        .map(|(idx, row)| (row.get::<i64, _>("id"), idx as SourceIndex + 1))
+        .collect();
 
-    // Build id→bit map (capped at 63)
-    let id_to_bit: HashMap<i64, u8> = source_rows
+    let source_ids: HashMap<SourceIndex, i64> = id_to_index
+        .iter()
+        .map(|(&id, &source)| (source, id))
+        .collect();
+
+    let source_names: HashMap<SourceIndex, CompactString> = source_rows
         .iter()
-        .take(63)
         .enumerate()
-        .map(|(idx, row)| (row.get::<i64, _>("id"), idx as u8))
+        .map(|(idx, row)| {
+            let name: String = row.get("name");
+            (idx as SourceIndex + 1, CompactString::new(name))
+        })
         .collect();
 
     // Step 2: Load all (source_id, group_id) assignments from pivot
@@ -166,26 +278,31 @@ async fn load_sources(pool: &SqlitePool) -> Result<SourceLoad, DomainError> {
     .await
     .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
 
-    // Expand into flat Vec<SourceMeta> — same bit can appear with multiple group_ids
-    // build_group_masks is unchanged: it iterates (bit, group_id) pairs
-    let sources: Vec<SourceMeta> = assignment_rows
+    let observe_only: SourceBits = source_rows
+        .iter()
+        .enumerate()
+        .filter(|(_, row)| row.get::<bool, _>("observe_only"))
+        .map(|(idx, _)| idx as SourceIndex + 1)
+        .collect();
+
+    // Expand into flat Vec<SourceMeta> — same source can appear with multiple group_ids
+    let (observed, sources): (Vec<SourceMeta>, Vec<SourceMeta>) = assignment_rows
         .iter()
         .filter_map(|row| {
             let source_id: i64 = row.get("source_id");
             let group_id: i64 = row.get("group_id");
-            id_to_bit
+            id_to_index
                 .get(&source_id)
-                .map(|&bit| SourceMeta { group_id, bit })
+                .map(|&source| SourceMeta { group_id, source })
         })
-        .collect();
+        .partition(|meta| observe_only.contains(meta.source));
 
-    let url_tasks: Vec<(u8, String)> = source_rows
+    let url_tasks: Vec<(SourceIndex, String)> = source_rows
         .iter()
-        .take(63)
         .enumerate()
         .filter_map(|(idx, row)| {
             let url: Option<String> = row.get("url");
-            url.map(|u| (idx as u8, u))
+            url.map(|u| (idx as SourceIndex + 1, u))
         })
         .collect();
 
@@ -201,63 +318,56 @@ async fn load_sources(pool: &SqlitePool) -> Result<SourceLoad, DomainError> {
     Ok(SourceLoad {
         default_group_id,
         sources,
+        observed,
         url_tasks,
+        source_names,
+        source_ids,
         all_group_ids,
     })
 }
 
-fn build_group_masks(sources: &[SourceMeta], all_group_ids: &[i64]) -> HashMap<i64, SourceBitSet> {
-    // Pre-populate ALL groups with MANUAL_SOURCE_BIT only (global manual blocklist).
-    // Each group is independent — no inheritance from default.
-    let mut group_masks: HashMap<i64, SourceBitSet> = HashMap::with_capacity(all_group_ids.len());
+fn build_group_sources(sources: &[SourceMeta], all_group_ids: &[i64]) -> HashMap<i64, SourceBits> {
+    // Pre-populate ALL groups with no list sources (the global manual blocklist
+    // is added to every mask). Each group is independent — no inheritance from default.
+    let mut group_sources: HashMap<i64, SourceBits> = HashMap::with_capacity(all_group_ids.len());
     for &gid in all_group_ids {
-        group_masks.insert(gid, MANUAL_SOURCE_BIT);
+        group_sources.insert(gid, SourceBits::new());
     }
 
-    // Add each source's bit ONLY to its assigned group
+    // Add each source ONLY to its assigned group
     for src in sources {
-        let entry = group_masks.entry(src.group_id).or_insert(MANUAL_SOURCE_BIT);
-        *entry |= 1u64 << src.bit;
+        group_sources
+            .entry(src.group_id)
+            .or_default()
+            .insert(src.source);
     }
 
-    group_masks
+    group_sources
 }
 
 async fn fetch_sources_parallel(
-    url_tasks: Vec<(u8, String)>,
+    url_tasks: Vec<(SourceIndex, String)>,
+    pool: &SqlitePool,
     client: &reqwest::Client,
-) -> HashMap<u8, Vec<ParsedEntry>> {
-    struct FetchResult {
-        bit: u8,
-        text: Option<String>,
-    }
-
+) -> HashMap<SourceIndex, Vec<ParsedEntry>> {
     let tasks: Vec<_> = url_tasks
         .into_iter()
-        .map(|(bit, u)| {
+        .map(|(source, u)| {
+            let pool = pool.clone();
             let client = client.clone();
             tokio::spawn(async move {
-                let text = match fetch_url(&u, &client).await {
-                    Ok(t) => {
-                        info!(url = %u, "Fetched blocklist source");
-                        Some(t)
-                    }
-                    Err(e) => {
-                        warn!(url = %u, error = %e, "Failed to fetch blocklist source");
-                        None
-                    }
-                };
-                FetchResult { bit, text }
+                let entries = load_list_source(&pool, &client, &u, "blocklist").await;
+                (source, entries)
             })
         })
         .collect();
 
-    let mut source_entries: HashMap<u8, Vec<ParsedEntry>> = HashMap::new();
+    let mut source_entries: HashMap<SourceIndex, Vec<ParsedEntry>> = HashMap::new();
     for result in join_all(tasks).await {
         match result {
-            Ok(fr) => {
-                if let Some(text) = fr.text {
-                    source_entries.insert(fr.bit, parse_list_text(&text));
+            Ok((source, entries)) => {
+                if let Some(entries) = entries {
+                    source_entries.insert(source, entries);
                 }
             }
             Err(e) => {
@@ -311,75 +421,175 @@ async fn load_manual_domains(pool: &SqlitePool) -> Result<Vec<String>, DomainErr
     Ok(domains)
 }
 
+pub(super) async fn load_rule_directory(pool: &SqlitePool) -> Result<RuleDirectory, DomainError> {
+    let group_rows = sqlx::query("SELECT id, name FROM groups")
+        .fetch_all(pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+    let client_rows =
+        sqlx::query("SELECT ip_address, hostname FROM clients WHERE hostname IS NOT NULL")
+            .fetch_all(pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+    let mut directory = RuleDirectory::default();
+    for row in &group_rows {
+        directory
+            .group_names
+            .insert(row.get("id"), row.get::<String, _>("name"));
+    }
+    for row in &client_rows {
+        let hostname: String = row.get("hostname");
+        if let Ok(ip) = row.get::<String, _>("ip_address").parse::<IpAddr>() {
+            directory
+                .client_addresses
+                .entry(hostname.to_ascii_lowercase())
+                .or_default()
+                .push(ip);
+        }
+    }
+    Ok(directory)
+}
+
+/// Entries switched off by a `$badfilter` rule in any source.
+fn disabled_entries(
+    source_entries: &HashMap<SourceIndex, Vec<ParsedEntry>>,
+) -> HashSet<ParsedEntry> {
+    source_entries
+        .values()
+        .flatten()
+        .filter_map(|entry| match entry {
+            ParsedEntry::Rule(rule) => rule.disabled_rule().map(ParsedEntry::from_rule),
+            _ => None,
+        })
+        .collect()
+}
+
 struct BlockIndexData {
     total_exact: usize,
     bloom: AtomicBloom,
-    exact: DashMap<CompactString, SourceBitSet, FxBuildHasher>,
-    wildcard: SuffixTrie,
-    patterns: Vec<(AhoCorasick, SourceBitSet)>,
+    source_sets: SourceSets,
+    exact: DashMap<CompactString, SourceSetId, FxBuildHasher>,
+    wildcard: SuffixTrie<SourceSetId>,
+    patterns: Vec<(AhoCorasick, SourceSetId)>,
+    list_rules: ListRules,
+    exception_exact: DashMap<CompactString, SourceSetId, FxBuildHasher>,
+    exception_wildcard: SuffixTrie<SourceSetId>,
 }
 
 fn build_exact_and_wildcard(
     manual_domains: &[String],
-    source_entries: &HashMap<u8, Vec<ParsedEntry>>,
+    source_entries: &HashMap<SourceIndex, Vec<ParsedEntry>>,
+    directory: &RuleDirectory,
 ) -> BlockIndexData {
+    let disabled = disabled_entries(source_entries);
+    let is_enabled = |entry: &ParsedEntry| disabled.is_empty() || !disabled.contains(entry);
+
     let exact_count: usize = manual_domains.len()
         + source_entries
             .values()
             .flat_map(|entries| entries.iter())
-            .filter(|e| matches!(e, ParsedEntry::Exact(_)))
+            .filter(|e| matches!(e, ParsedEntry::Exact(_) | ParsedEntry::Subtree(_)))
             .count();
 
     let bloom_capacity = (exact_count + 100).max(1000);
     let bloom = AtomicBloom::new(bloom_capacity, 0.001);
-    let exact: DashMap<CompactString, SourceBitSet, FxBuildHasher> =
+    let exact: DashMap<CompactString, SourceSetId, FxBuildHasher> =
         DashMap::with_capacity_and_hasher(exact_count, FxBuildHasher);
-    let mut wildcard = SuffixTrie::new();
-    let mut patterns_by_source: HashMap<u8, Vec<String>> = HashMap::new();
+    let mut wildcard: SuffixTrie<SourceSetId> = SuffixTrie::new();
+    let mut patterns_by_source: HashMap<SourceIndex, Vec<String>> = HashMap::new();
+    let mut list_rules = ListRules::new();
+    let exception_exact: DashMap<CompactString, SourceSetId, FxBuildHasher> =
+        DashMap::with_hasher(FxBuildHasher);
+    let mut exception_wildcard: SuffixTrie<SourceSetId> = SuffixTrie::new();
 
     for domain in manual_domains {
         bloom.set(domain);
         exact
             .entry(CompactString::new(domain))
-            .and_modify(|bits| *bits |= MANUAL_SOURCE_BIT)
-            .or_insert(MANUAL_SOURCE_BIT);
+            .or_insert(MANUAL_SOURCE_SET);
     }
 
+    // Each domain maps to the interned set of sources listing it. Workers
+    // remember which sets they have already extended with their source, so
+    // the shared interner is only locked for combinations not seen before.
+    let shared_sets = Mutex::new(SourceSets::new());
     BLOCKLIST_BUILD_POOL.install(|| {
-        source_entries.par_iter().for_each(|(bit, entries)| {
-            let source_bit: SourceBitSet = 1u64 << *bit;
+        source_entries.par_iter().for_each(|(&source, entries)| {
+            let mut extended: FxHashMap<SourceSetId, SourceSetId> = FxHashMap::default();
+            let mut extend = |set: SourceSetId| {
+                *extended.entry(set).or_insert_with(|| {
+                    shared_sets
+                        .lock()
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .with_source(set, source)
+                })
+            };
             for entry in entries {
-                if let ParsedEntry::Exact(domain) = entry {
+                if let ParsedEntry::Exact(domain) | ParsedEntry::Subtree(domain) = entry {
+                    if !is_enabled(entry) {
+                        continue;
+                    }
                     bloom.set(domain);
-                    exact
+                    let mut set = exact
                         .entry(CompactString::new(domain))
-                        .and_modify(|bits| *bits |= source_bit)
-                        .or_insert(source_bit);
+                        .or_insert(NO_SOURCES);
+                    *set = extend(*set);
                 }
             }
         });
     });
+    let mut sets = shared_sets
+        .into_inner()
+        .unwrap_or_else(PoisonError::into_inner);
 
-    for (bit, entries) in source_entries {
-        let source_bit: SourceBitSet = 1u64 << *bit;
-        for entry in entries {
+    for (&source, entries) in source_entries {
+        let singleton = sets.singleton(source);
+        for entry in entries.iter().filter(|e| is_enabled(e)) {
             match entry {
                 ParsedEntry::Exact(_) => {}
+                ParsedEntry::Subtree(domain) => {
+                    wildcard.update_wildcard(domain, |set| sets.with_source(set, source));
+                }
                 ParsedEntry::Wildcard(pattern) => {
-                    wildcard.insert_wildcard(pattern, source_bit);
+                    wildcard.update_wildcard(pattern, |set| sets.with_source(set, source));
                 }
                 ParsedEntry::Pattern(pat) => {
                     patterns_by_source
-                        .entry(*bit)
+                        .entry(source)
                         .or_default()
                         .push(pat.clone());
                 }
+                ParsedEntry::Rule(rule) if rule.badfilter => {}
+                ParsedEntry::Rule(rule) if rule.is_plain_exception() => match &rule.target {
+                    RuleTarget::Exact(domain) => {
+                        let mut set = exception_exact
+                            .entry(CompactString::new(domain))
+                            .or_insert(NO_SOURCES);
+                        *set = sets.with_source(*set, source);
+                    }
+                    RuleTarget::Subtree(domain) => {
+                        let mut set = exception_exact
+                            .entry(CompactString::new(domain))
+                            .or_insert(NO_SOURCES);
+                        *set = sets.with_source(*set, source);
+                        exception_wildcard
+                            .update_wildcard(domain, |set| sets.with_source(set, source));
+                    }
+                    RuleTarget::Wildcard(pattern) => {
+                        exception_wildcard
+                            .update_wildcard(pattern, |set| sets.with_source(set, source));
+                    }
+                    RuleTarget::Pattern(_) => list_rules.push(rule, singleton, directory),
+                },
+                ParsedEntry::Rule(rule) => list_rules.push(rule, singleton, directory),
             }
         }
     }
 
-    let mut patterns: Vec<(AhoCorasick, SourceBitSet)> = Vec::new();
-    for (bit, pats) in patterns_by_source {
+    let mut patterns: Vec<(AhoCorasick, SourceSetId)> = Vec::new();
+    for (source, pats) in patterns_by_source {
         if pats.is_empty() {
             continue;
         }
@@ -388,23 +598,68 @@ fn build_exact_and_wildcard(
             .build(&pats)
         {
             Ok(ac) => {
-                patterns.push((ac, 1u64 << bit));
+                patterns.push((ac, sets.singleton(source)));
             }
             Err(e) => {
-                warn!(source_bit = bit, error = %e, "Failed to compile Aho-Corasick patterns");
+                warn!(source, error = %e, "Failed to compile Aho-Corasick patterns");
             }
         }
     }
 
+    if !disabled.is_empty() || !list_rules.is_empty() {
+        info!(
+            badfilter = disabled.len(),
+            modifier_rules = list_rules.len(),
+            "Compiled blocklist rules with modifiers"
+        );
+    }
+
     BlockIndexData {
         total_exact: exact.len(),
         bloom,
+        source_sets: sets,
         exact,
         wildcard,
         patterns,
+        list_rules,
+        exception_exact,
+        exception_wildcard,
     }
 }
 
+/// Builds an index from parsed blocklist sources alone, without the
+/// manual blocklist, allowlists or regex filters kept in the database.
+/// List sources are numbered from 1; `group_sources` holds the sources
+/// each group is subscribed to.
+pub fn build_list_index(
+    source_entries: &HashMap<SourceIndex, Vec<ParsedEntry>>,
+    group_sources: &HashMap<i64, SourceBits>,
+    directory: &RuleDirectory,
+) -> BlockIndex {
+    let data = build_exact_and_wildcard(&[], source_entries, directory);
+    let mut index = BlockIndex::empty();
+    index.source_sets = data.source_sets;
+    index.total_blocked_domains = data.total_exact;
+    index.exact = data.exact;
+    index.bloom = data.bloom;
+    index.wildcard = data.wildcard;
+    index.patterns = data.patterns;
+    index.list_rules = data.list_rules;
+    index.allowlists.source_exact = data.exception_exact;
+    index.allowlists.source_wildcard = data.exception_wildcard;
+    index.group_names = group_names(directory);
+    index.set_group_sources(group_sources);
+    index
+}
+
+fn group_names(directory: &RuleDirectory) -> HashMap<i64, CompactString> {
+    directory
+        .group_names
+        .iter()
+        .map(|(id, name)| (*id, CompactString::new(name.to_ascii_lowercase())))
+        .collect()
+}
+
 struct RegexFilterMaps {
     block_patterns: HashMap<i64, Vec<Regex>>,
     allow_patterns: HashMap<i64, Vec<Regex>>,
@@ -461,24 +716,34 @@ pub async fn compile_block_index(
     let SourceLoad {
         default_group_id,
         sources,
+        observed,
         url_tasks,
+        source_names,
+        source_ids,
         all_group_ids,
     } = load_sources(pool).await?;
 
-    let group_masks = build_group_masks(&sources, &all_group_ids);
-    let source_entries = fetch_sources_parallel(url_tasks, client).await;
+    let group_sources = build_group_sources(&sources, &all_group_ids);
+    let observed_sources = build_group_sources(&observed, &[]);
+    let source_entries = fetch_sources_parallel(url_tasks, pool, client).await;
     let manual_domains = load_manual_domains(pool).await?;
     let managed_domain_entries = load_managed_domains_for_index(pool).await?;
     let regex_filter_maps = load_regex_filters_for_index(pool).await?;
+    let directory = load_rule_directory(pool).await?;
+    let group_names = group_names(&directory);
 
     let BlockIndexData {
         total_exact,
         bloom,
+        source_sets,
         exact,
         wildcard,
         patterns,
+        list_rules,
+        exception_exact,
+        exception_wildcard,
     } = tokio::task::spawn_blocking(move || {
-        build_exact_and_wildcard(&manual_domains, &source_entries)
+        build_exact_and_wildcard(&manual_domains, &source_entries, &directory)
     })
     .await
     .map_err(|e| {
@@ -510,9 +775,17 @@ pub async fn compile_block_index(
         "Block index compiled"
     );
 
-    let allowlists =
+    let mut allowlists =
         build_allowlist_index(pool, client, default_group_id, &managed_domain_entries).await?;
 
+    match list_source_fetches::prune_unreferenced(pool).await {
+        Ok(0) => {}
+        Ok(pruned) => info!(pruned, "Dropped stored copies of removed list sources"),
+        Err(e) => warn!(error = %e, "Failed to prune stored list source copies"),
+    }
+    allowlists.source_exact = exception_exact;
+    allowlists.source_wildcard = exception_wildcard;
+
     let mut groups_with_advanced_rules = std::collections::HashSet::new();
     for gid in managed_denies.keys() {
         groups_with_advanced_rules.insert(*gid);
@@ -527,8 +800,10 @@ pub async fn compile_block_index(
         groups_with_advanced_rules.insert(*gid);
     }
 
-    Ok(BlockIndex {
-        group_masks,
+    let mut index = BlockIndex {
+        group_masks: HashMap::new(),
+        default_mask: source_sets.group_mask(&SourceBits::new()),
+        source_sets,
         total_blocked_domains: total_exact,
         exact,
         bloom,
@@ -540,7 +815,16 @@ pub async fn compile_block_index(
         allow_regex_patterns: regex_filter_maps.allow_patterns,
         block_regex_patterns: regex_filter_maps.block_patterns,
         groups_with_advanced_rules,
-    })
+        list_rules,
+        group_names,
+        source_names,
+        source_ids,
+        observe_masks: HashMap::new(),
+        observe_sources: SourceBits::new(),
+    };
+    index.set_group_sources(&group_sources);
+    index.set_observed_sources(&observed_sources);
+    Ok(index)
 }
 
 async fn build_allowlist_index(
@@ -611,22 +895,15 @@ async fn build_allowlist_index(
         .collect();
 
     // Fetch each unique URL once; we'll re-map to group_ids after
-    let fetched: Vec<(String, Option<String>)> = {
+    let fetched: Vec<(String, Option<Vec<ParsedEntry>>)> = {
         let tasks: Vec<_> = deduped_tasks
             .into_iter()
             .map(|(_, url)| {
+                let pool = pool.clone();
                 let client = client.clone();
-                let url_clone = url.clone();
-                let url_for_task = url.clone();
                 tokio::spawn(async move {
-                    let text = match fetch_url(&url_for_task, &client).await {
-                        Ok(t) => Some(t),
-                        Err(e) => {
-                            tracing::warn!(url = %url_for_task, error = %e, "Failed to fetch whitelist source");
-                            None
-                        }
-                    };
-                    (url_clone, text)
+                    let entries = load_list_source(&pool, &client, &url, "whitelist").await;
+                    (url, entries)
                 })
             })
             .collect();
@@ -637,9 +914,8 @@ async fn build_allowlist_index(
             .collect()
     };
 
-    for (url, text_opt) in fetched {
-        if let Some(text) = text_opt {
-            let entries = parse_list_text(&text);
+    for (url, entries_opt) in fetched {
+        if let Some(entries) = entries_opt {
             let group_ids = url_to_groups.get(&url).cloned().unwrap_or_default();
             for group_id in group_ids {
                 let exact_set = allowlists
@@ -653,10 +929,28 @@ async fn build_allowlist_index(
                         ParsedEntry::Exact(domain) => {
                             exact_set.insert(CompactString::new(domain));
                         }
+                        ParsedEntry::Subtree(domain) => {
+                            exact_set.insert(CompactString::new(domain));
+                            trie.insert_wildcard(domain, 1u64);
+                        }
                         ParsedEntry::Wildcard(pattern) => {
                             trie.insert_wildcard(pattern, 1u64);
                         }
                         ParsedEntry::Pattern(_) => {}
+                        ParsedEntry::Rule(rule) if rule.badfilter => {}
+                        ParsedEntry::Rule(rule) => match &rule.target {
+                            RuleTarget::Exact(domain) => {
+                                exact_set.insert(CompactString::new(domain));
+                            }
+                            RuleTarget::Subtree(domain) => {
+                                exact_set.insert(CompactString::new(domain));
+                                trie.insert_wildcard(domain, 1u64);
+                            }
+                            RuleTarget::Wildcard(pattern) => {
+                                trie.insert_wildcard(pattern, 1u64);
+                            }
+                            RuleTarget::Pattern(_) => {}
+                        },
                     }
                 }
             }
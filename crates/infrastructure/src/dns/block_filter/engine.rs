@@ -4,12 +4,18 @@ use super::decision_cache::{
     decision_key, decision_l0_clear, decision_l0_get_by_key, decision_l0_set_by_key,
     BlockDecisionCache,
 };
+use super::rules::QueryContext;
 use crate::dns::cache::coarse_clock::coarse_now_secs;
 use arc_swap::ArcSwap;
 use async_trait::async_trait;
 use dashmap::DashMap;
-use ferrous_dns_application::ports::{BlockFilterEnginePort, FilterDecision, ScheduleStatePort};
-use ferrous_dns_domain::{BlockSource, ClientSubnet, DomainError, GroupOverride, SubnetMatcher};
+use ferrous_dns_application::ports::{
+    BlockFilterEnginePort, FilterDecision, FilterTrace, ScheduleStatePort,
+};
+use ferrous_dns_domain::{
+    BlockSource, BlockingMode, BlockingResponse, ClientSubnet, DomainError, GroupOverride,
+    RecordType, SubnetMatcher,
+};
 use lru::LruCache;
 use rustc_hash::FxBuildHasher;
 use sqlx::{Row, SqlitePool};
@@ -25,6 +31,16 @@ type GroupL0Cache = LruCache<IpAddr, (i64, u64, u64), FxBuildHasher>;
 
 const GROUP_L0_CAPACITY: usize = 256;
 
+/// Upper bound on remembered CNAME cloaking parents before expired ones
+/// are swept.
+const CNAME_PARENT_CAPACITY: usize = 10_000;
+
+/// Outcome of the global toggle and schedule checks that run before the index.
+enum Precheck {
+    Decided(FilterDecision),
+    Evaluate { skip_decision_cache: bool },
+}
+
 /// Monotonic counter bumped when client-to-group mappings change.
 /// Entries written under an older epoch are treated as stale.
 static GROUP_EPOCH: AtomicU64 = AtomicU64::new(0);
@@ -38,10 +54,15 @@ thread_local! {
 }
 
 pub struct BlockFilterEngine {
-    index: ArcSwap<BlockIndex>,
+    index: Arc<ArcSwap<BlockIndex>>,
     decision_cache: BlockDecisionCache,
+    /// Blocked CNAME behind each cached CNAME cloaking decision, keyed like
+    /// the decision cache: (blocked name, expiry_secs). Only read by `explain`.
+    cname_parents: DashMap<u64, (Arc<str>, u64), FxBuildHasher>,
     client_groups: Arc<DashMap<IpAddr, i64, FxBuildHasher>>,
     subnet_matcher: ArcSwap<Option<SubnetMatcher>>,
+    /// Per-group blocking response overrides; groups absent here use the global mode.
+    group_blocking: Arc<DashMap<i64, BlockingResponse, FxBuildHasher>>,
     /// Shared in-memory store of active schedule overrides per group.
     /// Written by `ScheduleEvaluatorJob` every 60 s; read in `check()` on every query.
     schedule_state: Arc<dyn ScheduleStatePort>,
@@ -66,10 +87,12 @@ impl BlockFilterEngine {
             .map_err(|e| DomainError::BlockFilterCompileError(e.to_string()))?;
 
         let engine = Arc::new(Self {
-            index: ArcSwap::from_pointee(BlockIndex::empty()),
+            index: Arc::new(ArcSwap::from_pointee(BlockIndex::empty())),
             decision_cache: BlockDecisionCache::new(),
+            cname_parents: DashMap::with_hasher(FxBuildHasher),
             client_groups: Arc::new(DashMap::with_hasher(FxBuildHasher)),
             subnet_matcher: ArcSwap::from_pointee(None),
+            group_blocking: Arc::new(DashMap::with_hasher(FxBuildHasher)),
             schedule_state,
             blocking_enabled: AtomicBool::new(blocking_enabled),
             default_group_id,
@@ -86,8 +109,7 @@ impl BlockFilterEngine {
             {
                 Ok(new_index) => {
                     background_engine.index.store(Arc::new(new_index));
-                    background_engine.decision_cache.clear();
-                    decision_l0_clear();
+                    background_engine.clear_decisions();
                     info!("Block filter compilation completed");
                 }
                 Err(e) => {
@@ -102,6 +124,17 @@ impl BlockFilterEngine {
         Ok(engine)
     }
 
+    /// Shared handle to the index answering queries, replaced on reload.
+    pub fn live_index(&self) -> Arc<ArcSwap<BlockIndex>> {
+        Arc::clone(&self.index)
+    }
+
+    fn clear_decisions(&self) {
+        self.decision_cache.clear();
+        self.cname_parents.clear();
+        decision_l0_clear();
+    }
+
     fn resolve_group_uncached(&self, ip: IpAddr) -> i64 {
         if let Some(gid) = self.client_groups.get(&ip) {
             return *gid;
@@ -117,6 +150,42 @@ impl BlockFilterEngine {
         self.default_group_id
     }
 
+    fn precheck(&self, group_id: i64) -> Precheck {
+        if !self.blocking_enabled.load(Ordering::Acquire) {
+            return Precheck::Decided(FilterDecision::Allow);
+        }
+
+        // Schedule override check: O(1) is_empty() guard keeps cost zero when
+        // no schedules are configured. Not cached per-domain — schedule state
+        // changes every minute, not per query.
+        if !self.schedule_state.is_empty() {
+            match self.schedule_state.get(group_id) {
+                Some(GroupOverride::BlockAll) => {
+                    return Precheck::Decided(FilterDecision::Block(BlockSource::Schedule));
+                }
+                Some(GroupOverride::AllowAll) => {
+                    return Precheck::Decided(FilterDecision::Allow);
+                }
+                Some(GroupOverride::TimedBypassUntil(t)) if coarse_now_secs() < t => {
+                    return Precheck::Decided(FilterDecision::Allow);
+                }
+                Some(GroupOverride::TimedBypassUntil(_)) => {
+                    return Precheck::Evaluate {
+                        skip_decision_cache: true,
+                    };
+                }
+                Some(GroupOverride::TimedBlockUntil(t)) if coarse_now_secs() < t => {
+                    return Precheck::Decided(FilterDecision::Block(BlockSource::Schedule));
+                }
+                _ => {} // expired or no override — fall through to normal check
+            }
+        }
+
+        Precheck::Evaluate {
+            skip_decision_cache: false,
+        }
+    }
+
     async fn load_client_groups_inner(&self) -> Result<(), DomainError> {
         let client_rows =
             sqlx::query("SELECT ip_address, group_id FROM clients WHERE group_id IS NOT NULL")
@@ -161,7 +230,37 @@ impl BlockFilterEngine {
         };
         self.subnet_matcher.store(Arc::new(matcher));
 
-        info!(clients = client_rows.len(), "Client groups loaded");
+        let blocking_rows = sqlx::query(
+            "SELECT id, blocking_mode, blocking_ipv4, blocking_ipv6 FROM groups
+             WHERE blocking_mode IS NOT NULL",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        self.group_blocking.clear();
+        for row in &blocking_rows {
+            let group_id: i64 = row.get("id");
+            let mode_str: String = row.get("blocking_mode");
+            let Ok(mode) = mode_str.parse::<BlockingMode>() else {
+                warn!(group_id, mode = %mode_str, "Ignoring invalid group blocking mode");
+                continue;
+            };
+            let ipv4 = row
+                .get::<Option<String>, _>("blocking_ipv4")
+                .and_then(|ip| ip.parse().ok());
+            let ipv6 = row
+                .get::<Option<String>, _>("blocking_ipv6")
+                .and_then(|ip| ip.parse().ok());
+            self.group_blocking
+                .insert(group_id, BlockingResponse { mode, ipv4, ipv6 });
+        }
+
+        info!(
+            clients = client_rows.len(),
+            blocking_overrides = blocking_rows.len(),
+            "Client groups loaded"
+        );
 
         Ok(())
     }
@@ -196,35 +295,12 @@ impl BlockFilterEnginePort for BlockFilterEngine {
 
     #[inline]
     fn check(&self, domain: &str, group_id: i64) -> FilterDecision {
-        if !self.blocking_enabled.load(Ordering::Acquire) {
-            return FilterDecision::Allow;
-        }
-
-        // Schedule override check: O(1) is_empty() guard keeps cost zero when
-        // no schedules are configured. Not cached per-domain — schedule state
-        // changes every minute, not per query.
-        let mut skip_decision_cache = false;
-
-        if !self.schedule_state.is_empty() {
-            match self.schedule_state.get(group_id) {
-                Some(GroupOverride::BlockAll) => {
-                    return FilterDecision::Block(BlockSource::Schedule);
-                }
-                Some(GroupOverride::AllowAll) => {
-                    return FilterDecision::Allow;
-                }
-                Some(GroupOverride::TimedBypassUntil(t)) if coarse_now_secs() < t => {
-                    return FilterDecision::Allow;
-                }
-                Some(GroupOverride::TimedBypassUntil(_)) => {
-                    skip_decision_cache = true;
-                }
-                Some(GroupOverride::TimedBlockUntil(t)) if coarse_now_secs() < t => {
-                    return FilterDecision::Block(BlockSource::Schedule);
-                }
-                _ => {} // expired or no override — fall through to normal check
-            }
-        }
+        let skip_decision_cache = match self.precheck(group_id) {
+            Precheck::Decided(decision) => return decision,
+            Precheck::Evaluate {
+                skip_decision_cache,
+            } => skip_decision_cache,
+        };
 
         let key = decision_key(domain, group_id);
 
@@ -258,12 +334,117 @@ impl BlockFilterEnginePort for BlockFilterEngine {
     }
 
     #[inline]
-    fn store_cname_decision(&self, domain: &str, group_id: i64, ttl_secs: u64) {
+    fn check_query(
+        &self,
+        domain: &str,
+        record_type: RecordType,
+        client_ip: IpAddr,
+        group_id: i64,
+    ) -> FilterDecision {
+        let guard = self.index.load();
+        if !guard.needs_query_context(domain) {
+            return self.check(domain, group_id);
+        }
+
+        // The verdict depends on the query type or client, so it bypasses
+        // the per-domain decision caches.
+        if let Precheck::Decided(decision) = self.precheck(group_id) {
+            return decision;
+        }
+        let ctx = QueryContext {
+            record_type,
+            client_ip,
+        };
+        match guard.is_blocked_for_query(domain, group_id, &ctx) {
+            Some(source) => FilterDecision::Block(source),
+            None => FilterDecision::Allow,
+        }
+    }
+
+    #[inline]
+    fn store_cname_decision(
+        &self,
+        domain: &str,
+        blocked_cname: &str,
+        group_id: i64,
+        ttl_secs: u64,
+    ) {
         let key = decision_key(domain, group_id);
         let source = Some(ferrous_dns_domain::BlockSource::CnameCloaking);
         self.decision_cache
             .set_by_key_with_ttl(key, source, ttl_secs);
         decision_l0_set_by_key(key, source);
+
+        let now = coarse_now_secs();
+        if self.cname_parents.len() >= CNAME_PARENT_CAPACITY {
+            self.cname_parents.retain(|_, (_, expires)| *expires > now);
+        }
+        self.cname_parents
+            .insert(key, (Arc::from(blocked_cname), now + ttl_secs));
+    }
+
+    fn explain(
+        &self,
+        domain: &str,
+        group_id: i64,
+        client: Option<(RecordType, IpAddr)>,
+    ) -> FilterTrace {
+        let ctx = client.map(|(record_type, client_ip)| QueryContext {
+            record_type,
+            client_ip,
+        });
+        let mut trace = self.index.load().explain(domain, group_id, ctx.as_ref());
+        trace.cname_parent = self
+            .cname_parents
+            .get(&decision_key(domain, group_id))
+            .filter(|entry| entry.1 > coarse_now_secs())
+            .map(|entry| entry.0.to_string());
+        trace
+    }
+
+    fn blocklist_source_ids(
+        &self,
+        domain: &str,
+        record_type: RecordType,
+        client_ip: IpAddr,
+        group_id: i64,
+    ) -> Vec<i64> {
+        let ctx = QueryContext {
+            record_type,
+            client_ip,
+        };
+        let index = self.index.load();
+        let ids = index.blocking_source_ids(domain, group_id, Some(&ctx));
+        if !ids.is_empty() {
+            return ids;
+        }
+        // A cached CNAME cloaking block is attributed to the blocked target.
+        match self
+            .cname_parents
+            .get(&decision_key(domain, group_id))
+            .filter(|entry| entry.1 > coarse_now_secs())
+        {
+            Some(entry) => index.blocking_source_ids(&entry.0, group_id, Some(&ctx)),
+            None => ids,
+        }
+    }
+
+    fn observed_source_ids(
+        &self,
+        domain: &str,
+        record_type: RecordType,
+        client_ip: IpAddr,
+        group_id: i64,
+    ) -> Vec<i64> {
+        let index = self.index.load();
+        if index.observe_masks.is_empty() {
+            return Vec::new();
+        }
+        let ctx = QueryContext {
+            record_type,
+            client_ip,
+        };
+        index.observed_source_ids(domain, group_id, Some(&ctx))
     }
 
     async fn reload(&self) -> Result<(), DomainError> {
@@ -277,9 +458,7 @@ impl BlockFilterEnginePort for BlockFilterEngine {
             })?;
 
         self.index.store(Arc::new(new_index));
-
-        self.decision_cache.clear();
-        decision_l0_clear();
+        self.clear_decisions();
 
         info!("Block filter reload completed");
         Ok(())
@@ -289,8 +468,7 @@ impl BlockFilterEnginePort for BlockFilterEngine {
         self.load_client_groups_inner().await?;
         // Client-to-group mappings changed — invalidate all cached lookups.
         GROUP_EPOCH.fetch_add(1, Ordering::Release);
-        self.decision_cache.clear();
-        decision_l0_clear();
+        self.clear_decisions();
         Ok(())
     }
 
@@ -306,4 +484,12 @@ impl BlockFilterEnginePort for BlockFilterEngine {
         self.blocking_enabled.store(enabled, Ordering::Release);
         info!(enabled, "Blocking toggle changed");
     }
+
+    #[inline]
+    fn blocking_response(&self, group_id: i64) -> Option<BlockingResponse> {
+        if self.group_blocking.is_empty() {
+            return None;
+        }
+        self.group_blocking.get(&group_id).map(|r| *r)
+    }
 }
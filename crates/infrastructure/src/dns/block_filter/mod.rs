@@ -2,6 +2,21 @@ mod block_index;
 mod compiler;
 mod decision_cache;
 mod engine;
+mod rules;
+mod simulation;
+mod source_fetch;
+mod source_set;
 mod suffix_trie;
 
+pub use block_index::{AllowlistIndex, BlockIndex};
+pub use compiler::{
+    build_list_index, parse_list_line, parse_list_text, parse_list_text_counted, ParsedEntry,
+};
 pub use engine::BlockFilterEngine;
+pub use rules::{ClientSpec, FilterRule, ModifierList, QueryContext, RuleDirectory, RuleTarget};
+pub use simulation::SqliteBlocklistSimulator;
+pub use source_fetch::load_list_source;
+pub use source_set::{
+    GroupMask, SourceBits, SourceIndex, SourceSetId, SourceSets, MANUAL_SOURCE, MANUAL_SOURCE_SET,
+    NO_SOURCES,
+};
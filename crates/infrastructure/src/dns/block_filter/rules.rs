@@ -0,0 +1,580 @@
+use super::source_set::{GroupMask, SourceSetId};
+use compact_str::CompactString;
+use ferrous_dns_domain::RecordType;
+use ipnetwork::IpNetwork;
+use rustc_hash::FxHashMap;
+use smallvec::SmallVec;
+use std::collections::HashMap;
+use std::fmt;
+use std::net::IpAddr;
+use tracing::debug;
+
+/// The names a list rule applies to, before modifiers are considered.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RuleTarget {
+    /// `|example.com^` or a bare hostname: that name only.
+    Exact(String),
+    /// `||example.com^`: the name and every name below it.
+    Subtree(String),
+    /// `*.example.com`: names below it, not the name itself.
+    Wildcard(String),
+    /// `/text/`: names containing the text.
+    Pattern(String),
+}
+
+impl RuleTarget {
+    /// Domain used to index the rule; `None` for patterns.
+    fn anchor(&self) -> Option<&str> {
+        match self {
+            RuleTarget::Exact(d) | RuleTarget::Subtree(d) => Some(d),
+            RuleTarget::Wildcard(d) => Some(d.strip_prefix("*.").unwrap_or(d)),
+            RuleTarget::Pattern(_) => None,
+        }
+    }
+
+    /// Whether the target covers `domain`, given that `suffix` is the
+    /// anchor it was found under.
+    fn covers(&self, domain: &str, suffix: &str) -> bool {
+        match self {
+            RuleTarget::Exact(_) => domain.len() == suffix.len(),
+            RuleTarget::Subtree(_) => true,
+            RuleTarget::Wildcard(_) => domain.len() > suffix.len(),
+            RuleTarget::Pattern(p) => domain.contains(p.as_str()),
+        }
+    }
+}
+
+impl fmt::Display for RuleTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuleTarget::Exact(d) => write!(f, "|{d}^"),
+            RuleTarget::Subtree(d) => write!(f, "||{d}^"),
+            RuleTarget::Wildcard(d) => f.write_str(d),
+            RuleTarget::Pattern(p) => write!(f, "/{p}/"),
+        }
+    }
+}
+
+/// Values of a `|`-separated modifier such as `$dnstype=A|~AAAA`. A value
+/// matches when it is in `include` (or `include` is empty) and not in
+/// `exclude`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ModifierList<T> {
+    pub include: Vec<T>,
+    pub exclude: Vec<T>,
+}
+
+impl<T> Default for ModifierList<T> {
+    fn default() -> Self {
+        Self {
+            include: Vec::new(),
+            exclude: Vec::new(),
+        }
+    }
+}
+
+impl<T: fmt::Display> ModifierList<T> {
+    fn write(&self, f: &mut fmt::Formatter<'_>, name: &str) -> fmt::Result {
+        write!(f, "{name}=")?;
+        let items = self.include.iter().map(|v| (false, v));
+        let items = items.chain(self.exclude.iter().map(|v| (true, v)));
+        for (i, (negated, value)) in items.enumerate() {
+            let sep = if i == 0 { "" } else { "|" };
+            let not = if negated { "~" } else { "" };
+            write!(f, "{sep}{not}{value}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> ModifierList<T> {
+    fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    fn permits(&self, matches: impl Fn(&T) -> bool) -> bool {
+        (self.include.is_empty() || self.include.iter().any(&matches))
+            && !self.exclude.iter().any(matches)
+    }
+}
+
+/// A `$client` value: an address, a CIDR block or a client name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClientSpec {
+    Network(IpNetwork),
+    Name(String),
+}
+
+/// An adblock-style rule with an `@@` exception marker or `$` modifiers.
+/// Plain block rules are kept as [`ParsedEntry`](super::ParsedEntry)
+/// variants instead.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FilterRule {
+    pub target: RuleTarget,
+    pub exception: bool,
+    pub important: bool,
+    pub badfilter: bool,
+    pub dnstype: Option<ModifierList<RecordType>>,
+    pub client: Option<ModifierList<ClientSpec>>,
+    pub ctag: Option<ModifierList<String>>,
+    pub denyallow: Vec<String>,
+}
+
+impl FilterRule {
+    pub fn new(target: RuleTarget) -> Self {
+        Self {
+            target,
+            exception: false,
+            important: false,
+            badfilter: false,
+            dnstype: None,
+            client: None,
+            ctag: None,
+            denyallow: Vec::new(),
+        }
+    }
+
+    /// A block rule with no modifiers, which needs no rule evaluation.
+    pub fn is_plain(&self) -> bool {
+        !self.exception && !self.has_modifiers()
+    }
+
+    /// An exception with no modifiers, which can live in the allowlist.
+    pub fn is_plain_exception(&self) -> bool {
+        self.exception && !self.has_modifiers()
+    }
+
+    fn has_modifiers(&self) -> bool {
+        self.important
+            || self.badfilter
+            || self.dnstype.is_some()
+            || self.client.is_some()
+            || self.ctag.is_some()
+            || !self.denyallow.is_empty()
+    }
+
+    /// The rule a `$badfilter` rule disables: itself without `$badfilter`.
+    pub fn disabled_rule(&self) -> Option<FilterRule> {
+        self.badfilter.then(|| FilterRule {
+            badfilter: false,
+            ..self.clone()
+        })
+    }
+
+    /// Applies the comma-separated `$` modifier text. Returns `false` when a
+    /// modifier is unknown or malformed; such rules are not meant for DNS
+    /// filtering and are skipped.
+    pub fn apply_modifiers(&mut self, text: &str) -> bool {
+        for modifier in split_unescaped(text, ',') {
+            let modifier = modifier.trim();
+            let (name, value) = match modifier.split_once('=') {
+                Some((n, v)) => (n.trim().to_ascii_lowercase(), Some(v.trim())),
+                None => (modifier.to_ascii_lowercase(), None),
+            };
+            let applied = match (name.as_str(), value) {
+                ("important", None) => {
+                    self.important = true;
+                    true
+                }
+                ("badfilter", None) => {
+                    self.badfilter = true;
+                    true
+                }
+                ("dnstype", Some(v)) => parse_list(v, |s| s.parse::<RecordType>().ok())
+                    .map(|l| self.dnstype = Some(l))
+                    .is_some(),
+                ("client", Some(v)) => parse_list(v, parse_client)
+                    .map(|l| self.client = Some(l))
+                    .is_some(),
+                ("ctag", Some(v)) => parse_list(v, |s| Some(s.to_ascii_lowercase()))
+                    .map(|l| self.ctag = Some(l))
+                    .is_some(),
+                ("denyallow", Some(v)) => parse_list(v, |s| {
+                    Some(s.trim_matches('.').to_ascii_lowercase()).filter(|d| !d.is_empty())
+                })
+                .filter(|l| l.exclude.is_empty())
+                .map(|l| self.denyallow = l.include)
+                .is_some(),
+                _ => false,
+            };
+            if !applied {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+fn split_unescaped(text: &str, sep: char) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(next) = chars.next() {
+                    current.push(next);
+                }
+            }
+            c if c == sep => parts.push(std::mem::take(&mut current)),
+            c => current.push(c),
+        }
+    }
+    parts.push(current);
+    parts
+}
+
+fn parse_list<T>(value: &str, parse: impl Fn(&str) -> Option<T>) -> Option<ModifierList<T>> {
+    let mut list = ModifierList::default();
+    for item in split_unescaped(value, '|') {
+        let item = item.trim();
+        let (negated, item) = match item.strip_prefix('~') {
+            Some(rest) => (true, rest),
+            None => (false, item),
+        };
+        let item = item.trim_matches(|c| c == '\'' || c == '"');
+        if item.is_empty() {
+            return None;
+        }
+        let parsed = parse(item)?;
+        if negated {
+            list.exclude.push(parsed);
+        } else {
+            list.include.push(parsed);
+        }
+    }
+    (!list.is_empty()).then_some(list)
+}
+
+fn parse_client(value: &str) -> Option<ClientSpec> {
+    if let Ok(net) = value.parse::<IpNetwork>() {
+        return Some(ClientSpec::Network(net));
+    }
+    if let Ok(ip) = value.parse::<IpAddr>() {
+        return Some(ClientSpec::Network(IpNetwork::from(ip)));
+    }
+    Some(ClientSpec::Name(value.to_ascii_lowercase()))
+}
+
+/// Names that `$client` and `$ctag` values are resolved against. Client
+/// names map to the addresses last seen for that hostname; tags match
+/// group names.
+#[derive(Debug, Default, Clone)]
+pub struct RuleDirectory {
+    pub group_names: HashMap<i64, String>,
+    pub client_addresses: HashMap<String, Vec<IpAddr>>,
+}
+
+/// The parts of a query that `$dnstype` and `$client` rules look at.
+#[derive(Debug, Clone, Copy)]
+pub struct QueryContext {
+    pub record_type: RecordType,
+    pub client_ip: IpAddr,
+}
+
+/// A list rule that applied to a query, for explaining decisions.
+#[derive(Debug, Clone)]
+pub struct MatchedRule {
+    pub text: String,
+    pub sources: SourceSetId,
+    pub exception: bool,
+}
+
+/// Which kinds of list rule matched a query.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RuleVerdict {
+    pub block: bool,
+    pub allow: bool,
+    pub important_block: bool,
+    pub important_allow: bool,
+}
+
+struct CompiledRule {
+    target: RuleTarget,
+    sources: SourceSetId,
+    exception: bool,
+    important: bool,
+    dnstype: Option<ModifierList<RecordType>>,
+    client: Option<ModifierList<IpNetwork>>,
+    ctag: Option<ModifierList<String>>,
+    denyallow: Vec<CompactString>,
+}
+
+/// Renders the rule in adblock syntax. `$client` names show as the
+/// addresses they resolved to.
+impl fmt::Display for CompiledRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.exception {
+            f.write_str("@@")?;
+        }
+        write!(f, "{}", self.target)?;
+        let mut sep = "$";
+        let mut next = |f: &mut fmt::Formatter<'_>| -> fmt::Result {
+            f.write_str(sep)?;
+            sep = ",";
+            Ok(())
+        };
+        if self.important {
+            next(f)?;
+            f.write_str("important")?;
+        }
+        if let Some(types) = &self.dnstype {
+            next(f)?;
+            types.write(f, "dnstype")?;
+        }
+        if let Some(clients) = &self.client {
+            next(f)?;
+            clients.write(f, "client")?;
+        }
+        if let Some(tags) = &self.ctag {
+            next(f)?;
+            tags.write(f, "ctag")?;
+        }
+        if !self.denyallow.is_empty() {
+            next(f)?;
+            write!(f, "denyallow={}", self.denyallow.join("|"))?;
+        }
+        Ok(())
+    }
+}
+
+impl CompiledRule {
+    fn needs_context(&self) -> bool {
+        self.dnstype.is_some() || self.client.is_some()
+    }
+
+    fn applies(&self, domain: &str, group_name: Option<&str>, ctx: Option<&QueryContext>) -> bool {
+        if self
+            .denyallow
+            .iter()
+            .any(|d| is_same_or_subdomain(domain, d))
+        {
+            return false;
+        }
+        if let Some(tags) = &self.ctag {
+            let permitted = match group_name {
+                Some(name) => tags.permits(|t| t == name),
+                None => tags.include.is_empty(),
+            };
+            if !permitted {
+                return false;
+            }
+        }
+        if !self.needs_context() {
+            return true;
+        }
+        let Some(ctx) = ctx else {
+            return false;
+        };
+        if let Some(types) = &self.dnstype {
+            if !types.permits(|t| *t == ctx.record_type) {
+                return false;
+            }
+        }
+        if let Some(clients) = &self.client {
+            if !clients.permits(|net| net.contains(ctx.client_ip)) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+fn is_same_or_subdomain(domain: &str, parent: &str) -> bool {
+    domain == parent
+        || (domain.len() > parent.len()
+            && domain.ends_with(parent)
+            && domain.as_bytes()[domain.len() - parent.len() - 1] == b'.')
+}
+
+/// List rules that carry modifiers or are exceptions that cannot live in
+/// the allowlist. They are few next to plain rules, so they are indexed
+/// by anchor domain and evaluated one by one.
+#[derive(Default)]
+pub struct ListRules {
+    rules: Vec<CompiledRule>,
+    anchored: FxHashMap<CompactString, SmallVec<[u32; 2]>>,
+    unanchored: Vec<u32>,
+    contextual: bool,
+    exceptions: bool,
+}
+
+impl ListRules {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Whether any rule is an exception.
+    pub fn has_exceptions(&self) -> bool {
+        self.exceptions
+    }
+
+    /// Adds `rule` for the sources in `sources`. `$client` names are
+    /// resolved through `directory`; a rule limited to clients that are all
+    /// unknown can never match and is dropped.
+    pub fn push(&mut self, rule: &FilterRule, sources: SourceSetId, directory: &RuleDirectory) {
+        let client = match &rule.client {
+            Some(spec) => {
+                let resolve = |items: &[ClientSpec]| -> Vec<IpNetwork> {
+                    items
+                        .iter()
+                        .flat_map(|item| match item {
+                            ClientSpec::Network(net) => vec![*net],
+                            ClientSpec::Name(name) => directory
+                                .client_addresses
+                                .get(name)
+                                .map(|ips| ips.iter().map(|ip| IpNetwork::from(*ip)).collect())
+                                .unwrap_or_default(),
+                        })
+                        .collect()
+                };
+                let resolved = ModifierList {
+                    include: resolve(&spec.include),
+                    exclude: resolve(&spec.exclude),
+                };
+                if !spec.include.is_empty() && resolved.include.is_empty() {
+                    debug!(target = ?rule.target, "Skipping $client rule with no known clients");
+                    return;
+                }
+                Some(resolved)
+            }
+            None => None,
+        };
+
+        let compiled = CompiledRule {
+            target: rule.target.clone(),
+            sources,
+            exception: rule.exception,
+            important: rule.important,
+            dnstype: rule.dnstype.clone(),
+            client,
+            ctag: rule.ctag.clone(),
+            denyallow: rule
+                .denyallow
+                .iter()
+                .map(|d| CompactString::new(d))
+                .collect(),
+        };
+
+        let idx = self.rules.len() as u32;
+        self.contextual |= compiled.needs_context();
+        self.exceptions |= compiled.exception;
+        match compiled.target.anchor() {
+            Some(anchor) => self
+                .anchored
+                .entry(CompactString::new(anchor))
+                .or_default()
+                .push(idx),
+            None => self.unanchored.push(idx),
+        }
+        self.rules.push(compiled);
+    }
+
+    fn for_each_candidate(&self, domain: &str, mut f: impl FnMut(&CompiledRule)) {
+        let mut suffix = domain;
+        loop {
+            if let Some(indices) = self.anchored.get(suffix) {
+                for &i in indices {
+                    let rule = &self.rules[i as usize];
+                    if rule.target.covers(domain, suffix) {
+                        f(rule);
+                    }
+                }
+            }
+            match suffix.split_once('.') {
+                Some((_, parent)) => suffix = parent,
+                None => break,
+            }
+        }
+        for &i in &self.unanchored {
+            let rule = &self.rules[i as usize];
+            if rule.target.covers(domain, domain) {
+                f(rule);
+            }
+        }
+    }
+
+    /// Whether a `$dnstype` or `$client` rule targets `domain`, so its
+    /// verdict depends on the query and must not be cached per domain.
+    pub fn needs_context(&self, domain: &str) -> bool {
+        if !self.contextual {
+            return false;
+        }
+        let mut found = false;
+        self.for_each_candidate(domain, |rule| found |= rule.needs_context());
+        found
+    }
+
+    /// Evaluates the rules from sources in `mask` against `domain`. Rules
+    /// that need the query context are skipped when `ctx` is `None`.
+    pub fn evaluate(
+        &self,
+        domain: &str,
+        mask: &GroupMask,
+        group_name: Option<&str>,
+        ctx: Option<&QueryContext>,
+    ) -> RuleVerdict {
+        let mut verdict = RuleVerdict::default();
+        self.for_each_candidate(domain, |rule| {
+            if !mask.covers(rule.sources) || !rule.applies(domain, group_name, ctx) {
+                return;
+            }
+            match (rule.exception, rule.important) {
+                (true, true) => verdict.important_allow = true,
+                (false, true) => verdict.important_block = true,
+                (true, false) => verdict.allow = true,
+                (false, false) => verdict.block = true,
+            }
+        });
+        verdict
+    }
+
+    /// The rules from sources in `mask` that apply to `domain`, in the
+    /// order [`evaluate`](Self::evaluate) visits them.
+    pub fn matching(
+        &self,
+        domain: &str,
+        mask: &GroupMask,
+        group_name: Option<&str>,
+        ctx: Option<&QueryContext>,
+    ) -> Vec<MatchedRule> {
+        let mut matched = Vec::new();
+        self.for_each_candidate(domain, |rule| {
+            if mask.covers(rule.sources) && rule.applies(domain, group_name, ctx) {
+                matched.push(MatchedRule {
+                    text: rule.to_string(),
+                    sources: rule.sources,
+                    exception: rule.exception,
+                });
+            }
+        });
+        matched
+    }
+
+    /// Source sets of the blocking (non-exception) rules matching `domain`
+    /// for a group.
+    pub fn blocking_sources(
+        &self,
+        domain: &str,
+        mask: &GroupMask,
+        group_name: Option<&str>,
+        ctx: Option<&QueryContext>,
+    ) -> Vec<SourceSetId> {
+        let mut sources = Vec::new();
+        self.for_each_candidate(domain, |rule| {
+            if !rule.exception && mask.covers(rule.sources) && rule.applies(domain, group_name, ctx)
+            {
+                sources.push(rule.sources);
+            }
+        });
+        sources
+    }
+}
@@ -0,0 +1,264 @@
+use super::compiler::{build_list_index, load_rule_directory, parse_list_text_counted};
+use super::rules::QueryContext;
+use super::source_set::{SourceBits, SourceIndex};
+use super::BlockIndex;
+use arc_swap::ArcSwap;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use ferrous_dns_application::ports::{
+    BlocklistSimulation, BlocklistSimulator, CandidateList, SimulatedClient, SimulatedDomain,
+    SimulationImpact, SimulationReport,
+};
+use ferrous_dns_domain::{DomainError, RecordType};
+use rustc_hash::FxBuildHasher;
+use sqlx::{Row, SqlitePool};
+use std::collections::{HashMap, HashSet};
+use std::net::IpAddr;
+use std::sync::Arc;
+use tracing::{debug, instrument};
+
+/// Source index of the candidate list in the temporary index.
+const CANDIDATE: SourceIndex = 1;
+
+/// Logged client queries sharing a domain, type, client, group and outcome.
+struct LoggedQueries {
+    domain: String,
+    record_type: RecordType,
+    client_ip: IpAddr,
+    group_id: i64,
+    blocked: bool,
+    list_blocked: bool,
+    count: u64,
+}
+
+/// Dry-runs a candidate blocklist by replaying the stored query log
+/// through an index compiled from that list alone.
+///
+/// A logged query that was allowed and matches the list would be newly
+/// blocked, unless the live allowlists or an exception from the group's
+/// lists cover it. One that was blocked by a blocklist and matches an
+/// exception in the list would be newly unblocked. The live index is only
+/// read.
+pub struct SqliteBlocklistSimulator {
+    pool: SqlitePool,
+    http_client: reqwest::Client,
+    live: Arc<ArcSwap<BlockIndex>>,
+}
+
+impl SqliteBlocklistSimulator {
+    pub fn new(pool: SqlitePool, live: Arc<ArcSwap<BlockIndex>>) -> Result<Self, DomainError> {
+        let http_client = reqwest::Client::builder()
+            .user_agent("ferrous-dns/1.0 (blocklist-sync)")
+            .timeout(std::time::Duration::from_secs(30))
+            .build()
+            .map_err(|e| DomainError::BlockFilterFetchError(e.to_string()))?;
+        Ok(Self {
+            pool,
+            http_client,
+            live,
+        })
+    }
+
+    async fn candidate_text(&self, list: &CandidateList) -> Result<String, DomainError> {
+        let url = match list {
+            CandidateList::Text(text) => return Ok(text.clone()),
+            CandidateList::Url(url) => url,
+        };
+        let response = self
+            .http_client
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| DomainError::BlockFilterFetchError(format!("{url}: {e}")))?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(DomainError::BlockFilterFetchError(format!(
+                "{url}: HTTP {}",
+                status.as_u16()
+            )));
+        }
+        response
+            .text()
+            .await
+            .map_err(|e| DomainError::BlockFilterFetchError(format!("{url}: {e}")))
+    }
+
+    async fn load_queries(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<LoggedQueries>, DomainError> {
+        let default_group_id: i64 =
+            sqlx::query("SELECT id FROM groups WHERE is_default = 1 LIMIT 1")
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| DomainError::DatabaseError(e.to_string()))?
+                .map(|row| row.get::<i64, _>("id"))
+                .unwrap_or(1);
+
+        let rows = sqlx::query(
+            "SELECT domain, record_type, client_ip, group_id, blocked,
+                    block_source = 'blocklist' AS list_blocked, COUNT(*) AS queries
+             FROM query_log
+             WHERE query_source = 'client' AND created_at >= ? AND created_at <= ?
+             GROUP BY domain, record_type, client_ip, group_id, blocked, list_blocked",
+        )
+        .bind(from.format("%Y-%m-%d %H:%M:%S").to_string())
+        .bind(to.format("%Y-%m-%d %H:%M:%S").to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows
+            .iter()
+            .filter_map(|row| {
+                Some(LoggedQueries {
+                    domain: row.get::<String, _>("domain").to_ascii_lowercase(),
+                    record_type: row.get::<String, _>("record_type").parse().ok()?,
+                    client_ip: row.get::<String, _>("client_ip").parse().ok()?,
+                    group_id: row
+                        .get::<Option<i64>, _>("group_id")
+                        .unwrap_or(default_group_id),
+                    blocked: row.get::<i64, _>("blocked") != 0,
+                    list_blocked: row.get::<Option<bool>, _>("list_blocked") == Some(true),
+                    count: row.get::<i64, _>("queries") as u64,
+                })
+            })
+            .collect())
+    }
+}
+
+#[async_trait]
+impl BlocklistSimulator for SqliteBlocklistSimulator {
+    #[instrument(skip(self, simulation), fields(from = %simulation.from, to = %simulation.to))]
+    async fn simulate(
+        &self,
+        simulation: &BlocklistSimulation,
+    ) -> Result<SimulationReport, DomainError> {
+        let text = self.candidate_text(&simulation.list).await?;
+        let (entries, parse_errors) = parse_list_text_counted(&text);
+        drop(text);
+
+        let mut queries = self.load_queries(simulation.from, simulation.to).await?;
+        if !simulation.group_ids.is_empty() {
+            queries.retain(|q| simulation.group_ids.contains(&q.group_id));
+        }
+        debug!(
+            rows = queries.len(),
+            entries = entries.len(),
+            "Replaying query log"
+        );
+
+        let directory = load_rule_directory(&self.pool).await?;
+        let group_sources: HashMap<i64, SourceBits> = queries
+            .iter()
+            .map(|q| (q.group_id, [CANDIDATE].into_iter().collect()))
+            .collect();
+        let entry_count = entries.len();
+        let limit = simulation.limit;
+        let live = self.live.load_full();
+
+        tokio::task::spawn_blocking(move || {
+            let source_entries = HashMap::from([(CANDIDATE, entries)]);
+            let candidate = build_list_index(&source_entries, &group_sources, &directory);
+            let mut report = replay(&candidate, &live, &queries, limit);
+            report.entry_count = entry_count;
+            report.parse_errors = parse_errors;
+            report
+        })
+        .await
+        .map_err(|e| DomainError::BlockFilterCompileError(format!("simulation task panicked: {e}")))
+    }
+}
+
+fn replay(
+    candidate: &BlockIndex,
+    live: &BlockIndex,
+    queries: &[LoggedQueries],
+    limit: usize,
+) -> SimulationReport {
+    let mut examined = 0;
+    let mut newly_blocked = ImpactBuilder::default();
+    let mut newly_unblocked = ImpactBuilder::default();
+
+    for q in queries {
+        examined += q.count;
+        let ctx = QueryContext {
+            record_type: q.record_type,
+            client_ip: q.client_ip,
+        };
+        if !q.blocked {
+            if candidate
+                .is_blocked_for_query(&q.domain, q.group_id, &ctx)
+                .is_some()
+                && !live.allowlists.is_allowed(&q.domain, q.group_id)
+                && !live.is_excepted(&q.domain, q.group_id, Some(&ctx))
+            {
+                newly_blocked.add(q);
+            }
+        } else if q.list_blocked && candidate.is_excepted(&q.domain, q.group_id, Some(&ctx)) {
+            newly_unblocked.add(q);
+        }
+    }
+
+    SimulationReport {
+        queries_examined: examined,
+        newly_blocked: newly_blocked.finish(limit),
+        newly_unblocked: newly_unblocked.finish(limit),
+        ..SimulationReport::default()
+    }
+}
+
+#[derive(Default)]
+struct ImpactBuilder<'a> {
+    queries: u64,
+    domains: HashMap<&'a str, (u64, HashSet<IpAddr, FxBuildHasher>), FxBuildHasher>,
+    clients: HashMap<IpAddr, u64, FxBuildHasher>,
+}
+
+impl<'a> ImpactBuilder<'a> {
+    fn add(&mut self, q: &'a LoggedQueries) {
+        self.queries += q.count;
+        let (count, clients) = self.domains.entry(&q.domain).or_default();
+        *count += q.count;
+        clients.insert(q.client_ip);
+        *self.clients.entry(q.client_ip).or_default() += q.count;
+    }
+
+    fn finish(self, limit: usize) -> SimulationImpact {
+        let total_domains = self.domains.len() as u64;
+        let total_clients = self.clients.len() as u64;
+
+        let mut domains: Vec<SimulatedDomain> = self
+            .domains
+            .into_iter()
+            .map(|(domain, (queries, clients))| SimulatedDomain {
+                domain: domain.to_string(),
+                queries,
+                clients: clients.len() as u64,
+            })
+            .collect();
+        domains.sort_by(|a, b| b.queries.cmp(&a.queries).then(a.domain.cmp(&b.domain)));
+        domains.truncate(limit);
+
+        let mut clients: Vec<SimulatedClient> = self
+            .clients
+            .into_iter()
+            .map(|(client_ip, queries)| SimulatedClient { client_ip, queries })
+            .collect();
+        clients.sort_by(|a, b| {
+            b.queries
+                .cmp(&a.queries)
+                .then(a.client_ip.cmp(&b.client_ip))
+        });
+        clients.truncate(limit);
+
+        SimulationImpact {
+            queries: self.queries,
+            total_domains,
+            total_clients,
+            domains,
+            clients,
+        }
+    }
+}
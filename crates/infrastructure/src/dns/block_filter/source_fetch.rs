@@ -0,0 +1,126 @@
+use super::compiler::{parse_list_text_counted, ParsedEntry};
+use crate::repositories::list_source_fetches::{self, CachedList, FetchRecord};
+use reqwest::header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use reqwest::StatusCode;
+use sqlx::SqlitePool;
+use tracing::{info, warn};
+
+enum Download {
+    Modified(u16, CachedList),
+    NotModified,
+    Failed(Option<u16>, String),
+}
+
+fn header_string(
+    response: &reqwest::Response,
+    name: reqwest::header::HeaderName,
+) -> Option<String> {
+    response
+        .headers()
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string)
+}
+
+/// Requests `url`, revalidating `cached` with `If-None-Match` /
+/// `If-Modified-Since` when there is one.
+async fn download(url: &str, client: &reqwest::Client, cached: Option<&CachedList>) -> Download {
+    let mut request = client.get(url).timeout(std::time::Duration::from_secs(30));
+    if let Some(cached) = cached {
+        if let Some(etag) = &cached.etag {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+        if let Some(last_modified) = &cached.last_modified {
+            request = request.header(IF_MODIFIED_SINCE, last_modified);
+        }
+    }
+
+    let response = match request.send().await {
+        Ok(r) => r,
+        Err(e) => return Download::Failed(None, format!("fetch error: {e}")),
+    };
+
+    let status = response.status();
+    if status == StatusCode::NOT_MODIFIED && cached.is_some() {
+        return Download::NotModified;
+    }
+    if !status.is_success() {
+        return Download::Failed(Some(status.as_u16()), format!("HTTP {}", status.as_u16()));
+    }
+
+    let etag = header_string(&response, ETAG);
+    let last_modified = header_string(&response, LAST_MODIFIED);
+    match response.text().await {
+        Ok(content) => Download::Modified(
+            status.as_u16(),
+            CachedList {
+                etag,
+                last_modified,
+                content,
+            },
+        ),
+        Err(e) => Download::Failed(Some(status.as_u16()), format!("read error: {e}")),
+    }
+}
+
+/// Downloads and parses a list source, keeping a local copy of the last
+/// good download in the database.
+///
+/// Unchanged lists are revalidated with a conditional request instead of
+/// being downloaded again, and a failed download falls back to the stored
+/// copy. The outcome is recorded as the URL's fetch status. Returns `None`
+/// only when the download failed and no earlier copy exists.
+pub async fn load_list_source(
+    pool: &SqlitePool,
+    client: &reqwest::Client,
+    url: &str,
+    kind: &'static str,
+) -> Option<Vec<ParsedEntry>> {
+    let cached = list_source_fetches::load_cached(pool, url)
+        .await
+        .unwrap_or_else(|e| {
+            warn!(url = %url, error = %e, "Failed to load cached list source");
+            None
+        });
+
+    let mut record = FetchRecord::default();
+    match download(url, client, cached.as_ref()).await {
+        Download::Modified(status, list) => {
+            info!(url = %url, kind, "Fetched list source");
+            record.succeeded = true;
+            record.http_status = Some(status);
+            record.fresh = Some(list);
+        }
+        Download::NotModified => {
+            info!(url = %url, kind, "List source not modified; using stored copy");
+            record.succeeded = true;
+            record.http_status = Some(StatusCode::NOT_MODIFIED.as_u16());
+        }
+        Download::Failed(status, error) => {
+            if cached.is_some() {
+                warn!(url = %url, kind, error = %error, "Failed to fetch list source; using last good copy");
+            } else {
+                warn!(url = %url, kind, error = %error, "Failed to fetch list source");
+            }
+            record.http_status = status;
+            record.error = Some(error);
+        }
+    }
+
+    let text = record
+        .fresh
+        .as_ref()
+        .or(cached.as_ref())
+        .map(|list| list.content.as_str());
+    let parsed = text.map(parse_list_text_counted);
+    if let Some((entries, parse_errors)) = &parsed {
+        record.entry_count = entries.len();
+        record.parse_errors = *parse_errors;
+    }
+
+    if let Err(e) = list_source_fetches::record_fetch(pool, url, &record).await {
+        warn!(url = %url, error = %e, "Failed to record list source fetch status");
+    }
+
+    parsed.map(|(entries, _)| entries)
+}
@@ -0,0 +1,174 @@
+use rustc_hash::FxHashMap;
+
+/// Position of a source in a compiled [`BlockIndex`](super::BlockIndex).
+/// Blocklist sources are numbered from 1; 0 is the manual blocklist.
+pub type SourceIndex = u32;
+
+/// The manual blocklist, which every group is subscribed to.
+pub const MANUAL_SOURCE: SourceIndex = 0;
+
+/// Handle to a combination of sources interned in [`SourceSets`].
+pub type SourceSetId = u32;
+
+/// The empty set of sources.
+pub const NO_SOURCES: SourceSetId = 0;
+
+/// The set holding only the manual blocklist.
+pub const MANUAL_SOURCE_SET: SourceSetId = 1;
+
+/// Growable bitset. Bits are only ever added, so there are no trailing
+/// zero words and equal sets compare and hash equal.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct SourceBits {
+    words: Vec<u64>,
+}
+
+impl SourceBits {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, bit: u32) {
+        let word = (bit >> 6) as usize;
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+        self.words[word] |= 1u64 << (bit & 63);
+    }
+
+    #[inline]
+    pub fn contains(&self, bit: u32) -> bool {
+        self.words
+            .get((bit >> 6) as usize)
+            .is_some_and(|w| w & (1u64 << (bit & 63)) != 0)
+    }
+
+    pub fn intersects(&self, other: &SourceBits) -> bool {
+        self.words.iter().zip(&other.words).any(|(a, b)| a & b != 0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = u32> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            (0..64u32)
+                .filter(move |b| word & (1u64 << b) != 0)
+                .map(move |b| (i as u32) * 64 + b)
+        })
+    }
+}
+
+impl FromIterator<u32> for SourceBits {
+    fn from_iter<I: IntoIterator<Item = u32>>(iter: I) -> Self {
+        let mut bits = SourceBits::new();
+        for bit in iter {
+            bits.insert(bit);
+        }
+        bits
+    }
+}
+
+/// Interns the combinations of sources that list the same rule.
+///
+/// Lists overlap heavily, so a few thousand distinct combinations cover
+/// millions of rules whatever the number of sources. Rules store a 32-bit
+/// [`SourceSetId`] and a [`GroupMask`] resolves each id to one bit.
+pub struct SourceSets {
+    sets: Vec<SourceBits>,
+    ids: FxHashMap<SourceBits, SourceSetId>,
+    additions: FxHashMap<(SourceSetId, SourceIndex), SourceSetId>,
+}
+
+impl SourceSets {
+    pub fn new() -> Self {
+        let mut sets = Self {
+            sets: Vec::new(),
+            ids: FxHashMap::default(),
+            additions: FxHashMap::default(),
+        };
+        sets.intern(SourceBits::new());
+        sets.intern(SourceBits::from_iter([MANUAL_SOURCE]));
+        sets
+    }
+
+    pub fn len(&self) -> usize {
+        self.sets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sets.is_empty()
+    }
+
+    pub fn get(&self, id: SourceSetId) -> &SourceBits {
+        &self.sets[id as usize]
+    }
+
+    fn intern(&mut self, bits: SourceBits) -> SourceSetId {
+        if let Some(&id) = self.ids.get(&bits) {
+            return id;
+        }
+        let id = self.sets.len() as SourceSetId;
+        self.sets.push(bits.clone());
+        self.ids.insert(bits, id);
+        id
+    }
+
+    /// The set `set` with `source` added.
+    pub fn with_source(&mut self, set: SourceSetId, source: SourceIndex) -> SourceSetId {
+        if let Some(&id) = self.additions.get(&(set, source)) {
+            return id;
+        }
+        let mut bits = self.sets[set as usize].clone();
+        bits.insert(source);
+        let id = self.intern(bits);
+        self.additions.insert((set, source), id);
+        id
+    }
+
+    pub fn singleton(&mut self, source: SourceIndex) -> SourceSetId {
+        self.with_source(NO_SOURCES, source)
+    }
+
+    /// The mask of a group subscribed to `sources` and the manual blocklist.
+    pub fn group_mask(&self, sources: &SourceBits) -> GroupMask {
+        let mut sources = sources.clone();
+        sources.insert(MANUAL_SOURCE);
+        let sets = self
+            .sets
+            .iter()
+            .enumerate()
+            .filter(|(_, set)| set.intersects(&sources))
+            .map(|(id, _)| id as SourceSetId)
+            .collect();
+        GroupMask { sources, sets }
+    }
+}
+
+impl Default for SourceSets {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The sources a group is subscribed to, resolved against [`SourceSets`]
+/// so that testing a rule is a single bit lookup.
+#[derive(Debug, Clone)]
+pub struct GroupMask {
+    sources: SourceBits,
+    /// Interned sets sharing at least one source with `sources`.
+    sets: SourceBits,
+}
+
+impl GroupMask {
+    /// Whether any source in `set` applies to the group.
+    #[inline]
+    pub fn covers(&self, set: SourceSetId) -> bool {
+        self.sets.contains(set)
+    }
+
+    pub fn sources(&self) -> &SourceBits {
+        &self.sources
+    }
+}
@@ -4,53 +4,52 @@ use smallvec::SmallVec;
 use std::collections::HashMap;
 
 #[derive(Default)]
-struct TrieNode {
-    children: HashMap<CompactString, TrieNode, FxBuildHasher>,
-    wildcard_mask: u64,
-}
-
-impl TrieNode {
-    fn new() -> Self {
-        Self {
-            children: HashMap::with_hasher(FxBuildHasher),
-            wildcard_mask: 0,
-        }
-    }
+struct TrieNode<V> {
+    children: HashMap<CompactString, TrieNode<V>, FxBuildHasher>,
+    value: V,
 }
 
+/// Maps wildcard patterns to a value, looked up by every proper parent of
+/// a name. `V::default()` means no pattern ends at a node.
 #[derive(Default)]
-pub struct SuffixTrie {
-    root: TrieNode,
+pub struct SuffixTrie<V = u64> {
+    root: TrieNode<V>,
 }
 
-impl SuffixTrie {
+impl<V: Copy + Default + PartialEq> SuffixTrie<V> {
     pub fn new() -> Self {
         Self {
-            root: TrieNode::new(),
+            root: TrieNode {
+                children: HashMap::with_hasher(FxBuildHasher),
+                value: V::default(),
+            },
         }
     }
 
-    pub fn insert_wildcard(&mut self, pattern: &str, source_mask: u64) {
+    /// Replaces the value of `pattern` (`*.domain` or `domain`) with
+    /// `update` applied to the current one.
+    pub fn update_wildcard(&mut self, pattern: &str, update: impl FnOnce(V) -> V) {
         let domain = pattern.strip_prefix("*.").unwrap_or(pattern);
         let mut node = &mut self.root;
         for label in domain.split('.').rev() {
             node = node.children.entry(CompactString::new(label)).or_default();
         }
-        node.wildcard_mask |= source_mask;
+        node.value = update(node.value);
     }
 
-    #[inline]
-    pub fn lookup(&self, domain: &str) -> u64 {
+    /// The first value, from the root down, of a pattern covering `domain`
+    /// that `accept` takes.
+    #[inline(always)]
+    pub fn find(&self, domain: &str, mut accept: impl FnMut(V) -> bool) -> Option<V> {
         let labels: SmallVec<[&str; 8]> = domain.split('.').rev().collect();
         let n = labels.len();
         let mut node = &self.root;
-        let mut result: u64 = 0;
 
         for (i, label) in labels.iter().enumerate() {
             match node.children.get(*label) {
                 Some(child) => {
-                    if child.wildcard_mask != 0 && i + 1 < n {
-                        result |= child.wildcard_mask;
+                    if child.value != V::default() && i + 1 < n && accept(child.value) {
+                        return Some(child.value);
                     }
                     node = child;
                 }
@@ -58,6 +57,45 @@ impl SuffixTrie {
             }
         }
 
+        None
+    }
+
+    /// Every pattern covering `domain`, as `(parent, value)` pairs from the
+    /// root down. Used off the hot path to explain decisions.
+    pub fn matches<'a>(&self, domain: &'a str) -> Vec<(&'a str, V)> {
+        let mut found = Vec::new();
+        let mut node = &self.root;
+        let mut end = domain.len();
+        loop {
+            let start = domain[..end].rfind('.').map_or(0, |dot| dot + 1);
+            let Some(child) = node.children.get(&domain[start..end]) else {
+                break;
+            };
+            if start == 0 {
+                break;
+            }
+            if child.value != V::default() {
+                found.push((&domain[start..], child.value));
+            }
+            node = child;
+            end = start - 1;
+        }
+        found
+    }
+}
+
+impl SuffixTrie<u64> {
+    pub fn insert_wildcard(&mut self, pattern: &str, source_mask: u64) {
+        self.update_wildcard(pattern, |mask| mask | source_mask);
+    }
+
+    #[inline]
+    pub fn lookup(&self, domain: &str) -> u64 {
+        let mut result = 0;
+        self.find(domain, |mask| {
+            result |= mask;
+            false
+        });
         result
     }
 }
@@ -0,0 +1,400 @@
+use super::coarse_clock::coarse_now_secs;
+use crate::dns::dnssec::validation::denial::{self, DenialKind};
+use crate::dns::dnssec::ValidationResult;
+use crate::dns::forwarding::RecordTypeMapper;
+use dashmap::DashMap;
+use ferrous_dns_domain::RecordType;
+use hickory_proto::dnssec::rdata::{DNSSECRData, NSEC3};
+use hickory_proto::op::{Message, MessageType, OpCode, Query, ResponseCode};
+use hickory_proto::rr::{LowerName, Name, RData, Record, RecordType as HickoryRecordType};
+use hickory_proto::serialize::binary::{BinEncodable, BinEncoder};
+use rustc_hash::{FxBuildHasher, FxHashMap};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Zones whose denial records are kept at once.
+const MAX_ZONES: usize = 4_096;
+
+/// NSEC/NSEC3 RRsets kept per zone. Random-subdomain floods against one
+/// zone produce a new span per miss until the zone's chain is covered.
+const MAX_RRSETS_PER_ZONE: usize = 1_024;
+
+/// An RRset together with the RRSIGs that cover it.
+struct CachedRrset {
+    records: Vec<Record>,
+    expires_at_secs: u64,
+}
+
+type RrsetKey = (LowerName, HickoryRecordType);
+
+struct ZoneDenials {
+    apex: Name,
+    soa: CachedRrset,
+    rrsets: FxHashMap<RrsetKey, CachedRrset>,
+    /// NSEC owners in canonical order (RFC 4034 §6.1).
+    nsec_owners: BTreeSet<LowerName>,
+    /// NSEC3 owner hashes in order, with the owner each belongs to.
+    nsec3_owners: BTreeMap<Vec<u8>, LowerName>,
+}
+
+impl ZoneDenials {
+    fn new(apex: Name) -> Self {
+        Self {
+            apex,
+            soa: CachedRrset {
+                records: Vec::new(),
+                expires_at_secs: 0,
+            },
+            rrsets: FxHashMap::default(),
+            nsec_owners: BTreeSet::new(),
+            nsec3_owners: BTreeMap::new(),
+        }
+    }
+
+    fn insert(&mut self, key: RrsetKey, rrset: CachedRrset) {
+        match key.1 {
+            HickoryRecordType::NSEC => {
+                self.nsec_owners.insert(key.0.clone());
+            }
+            _ => {
+                if let Some(hash) = denial::nsec3_owner_hash(&key.0) {
+                    self.nsec3_owners.insert(hash, key.0.clone());
+                }
+            }
+        }
+        self.rrsets.insert(key, rrset);
+    }
+
+    fn remove(&mut self, key: &RrsetKey) {
+        if self.rrsets.remove(key).is_none() {
+            return;
+        }
+        match key.1 {
+            HickoryRecordType::NSEC => {
+                self.nsec_owners.remove(&key.0);
+            }
+            _ => {
+                if let Some(hash) = denial::nsec3_owner_hash(&key.0) {
+                    self.nsec3_owners.remove(&hash);
+                }
+            }
+        }
+    }
+
+    fn remove_expired(&mut self, now: u64) {
+        let expired: Vec<RrsetKey> = self
+            .rrsets
+            .iter()
+            .filter(|(_, rrset)| rrset.expires_at_secs <= now)
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in &expired {
+            self.remove(key);
+        }
+    }
+
+    /// The RRsets that can match or cover one of `names`: for each name the
+    /// closest entry at or before it in the ordered chain, or the last
+    /// entry when the name sorts before them all (the wrap-around span).
+    fn proof_candidates(&self, names: &[Name]) -> Vec<RrsetKey> {
+        let mut keys: Vec<RrsetKey> = Vec::new();
+        let mut push = |key: RrsetKey| {
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        };
+
+        if !self.nsec_owners.is_empty() {
+            for name in names {
+                let name = LowerName::new(name);
+                let owner = self
+                    .nsec_owners
+                    .range(..=&name)
+                    .next_back()
+                    .or_else(|| self.nsec_owners.last());
+                if let Some(owner) = owner {
+                    push((owner.clone(), HickoryRecordType::NSEC));
+                }
+            }
+        }
+
+        if let Some(params) = self.nsec3_params() {
+            for hash in names
+                .iter()
+                .filter_map(|name| denial::nsec3_hash(params, name))
+            {
+                let owner = self
+                    .nsec3_owners
+                    .range(..=hash)
+                    .next_back()
+                    .or_else(|| self.nsec3_owners.last_key_value());
+                if let Some((_, owner)) = owner {
+                    push((owner.clone(), HickoryRecordType::NSEC3));
+                }
+            }
+        }
+
+        keys
+    }
+
+    /// Hash parameters of the zone's NSEC3 chain, taken from any cached
+    /// NSEC3 record.
+    fn nsec3_params(&self) -> Option<&NSEC3> {
+        let owner = self.nsec3_owners.values().next()?;
+        self.rrsets
+            .get(&(owner.clone(), HickoryRecordType::NSEC3))?
+            .records
+            .iter()
+            .find_map(|r| match r.data() {
+                RData::DNSSEC(DNSSECRData::NSEC3(nsec3)) => Some(nsec3),
+                _ => None,
+            })
+    }
+}
+
+/// A negative answer built from cached NSEC/NSEC3 ranges.
+#[derive(Debug, Clone)]
+pub struct SynthesizedDenial {
+    pub kind: DenialKind,
+    pub wire: Vec<u8>,
+    pub ttl: u32,
+}
+
+/// Aggressive use of DNSSEC-validated cache (RFC 8198).
+///
+/// Keeps the NSEC/NSEC3 records of validated negative answers per signing
+/// zone and answers any later query whose name falls inside a cached range
+/// with a synthesised NXDOMAIN/NODATA, without going upstream. Only denials
+/// the DNSSEC layer marked `Secure` are stored, so every RRset here has
+/// already had its RRSIG verified against the zone's validated keys.
+pub struct AggressiveNsecCache {
+    zones: DashMap<Box<str>, ZoneDenials, FxBuildHasher>,
+}
+
+impl Default for AggressiveNsecCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AggressiveNsecCache {
+    pub fn new() -> Self {
+        Self {
+            zones: DashMap::with_hasher(FxBuildHasher),
+        }
+    }
+
+    /// Number of zones with cached denial records.
+    pub fn len(&self) -> usize {
+        self.zones.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.zones.is_empty()
+    }
+
+    pub fn clear(&self) {
+        self.zones.clear();
+    }
+
+    /// Stores the NSEC/NSEC3 RRsets from the authority section of a
+    /// negative response that validated as `Secure`.
+    ///
+    /// TTLs are capped at the SOA MINIMUM (RFC 8198 §5.4, RFC 9077) so a
+    /// synthesised answer never outlives a plain negative cache entry.
+    pub fn store(&self, message: &Message) {
+        let authority = message.name_servers();
+        let Some(apex) = denial::denial_signer(authority) else {
+            return;
+        };
+        let Some(soa_ttl) = authority.iter().find_map(|r| match r.data() {
+            RData::SOA(soa) if *r.name() == apex => Some(r.ttl().min(soa.minimum())),
+            _ => None,
+        }) else {
+            return;
+        };
+
+        let now = coarse_now_secs();
+        let mut rrsets: FxHashMap<RrsetKey, CachedRrset> = FxHashMap::default();
+        for record in authority {
+            let rtype = record.record_type();
+            if !matches!(rtype, HickoryRecordType::NSEC | HickoryRecordType::NSEC3)
+                || !apex.zone_of(record.name())
+            {
+                continue;
+            }
+            let ttl = record.ttl().min(soa_ttl);
+            let entry = rrsets
+                .entry((LowerName::new(record.name()), rtype))
+                .or_insert_with(|| CachedRrset {
+                    records: Self::rrsigs_for(authority, record.name(), rtype, &apex),
+                    expires_at_secs: now + u64::from(ttl),
+                });
+            entry.records.push(record.clone());
+            entry.expires_at_secs = entry.expires_at_secs.min(now + u64::from(ttl));
+        }
+        if rrsets.is_empty() {
+            return;
+        }
+
+        let mut soa_records = Self::rrsigs_for(authority, &apex, HickoryRecordType::SOA, &apex);
+        soa_records.extend(
+            authority
+                .iter()
+                .filter(|r| r.record_type() == HickoryRecordType::SOA && *r.name() == apex)
+                .cloned(),
+        );
+        let soa = CachedRrset {
+            records: soa_records,
+            expires_at_secs: now + u64::from(soa_ttl),
+        };
+
+        let key = Self::zone_key(&apex);
+        if !self.zones.contains_key(key.as_ref()) && self.zones.len() >= MAX_ZONES {
+            self.evict_zone(now);
+        }
+        let mut zone = self
+            .zones
+            .entry(key)
+            .or_insert_with(|| ZoneDenials::new(apex.clone()));
+        zone.soa = soa;
+        if zone.rrsets.len() + rrsets.len() > MAX_RRSETS_PER_ZONE {
+            zone.remove_expired(now);
+        }
+        for (key, rrset) in rrsets {
+            if zone.rrsets.len() >= MAX_RRSETS_PER_ZONE && !zone.rrsets.contains_key(&key) {
+                let Some(victim) = zone.rrsets.keys().next().cloned() else {
+                    break;
+                };
+                zone.remove(&victim);
+            }
+            zone.insert(key, rrset);
+        }
+    }
+
+    /// Answers `domain` (lowercase, no trailing dot) from the cached ranges
+    /// of the closest enclosing zone, or `None` when they prove nothing.
+    pub fn synthesize(&self, domain: &str, record_type: RecordType) -> Option<SynthesizedDenial> {
+        if self.zones.is_empty() {
+            return None;
+        }
+        let zone = self.find_zone(domain)?;
+        let now = coarse_now_secs();
+        if zone.soa.expires_at_secs <= now {
+            return None;
+        }
+
+        let qname = Name::from_ascii(format!("{}.", domain)).ok()?;
+        let qtype = RecordTypeMapper::to_hickory(&record_type);
+        let candidates = zone.proof_candidates(&denial::proof_names(&qname, &zone.apex));
+        let data: Vec<Record> = candidates
+            .iter()
+            .filter_map(|key| zone.rrsets.get(key))
+            .filter(|rrset| rrset.expires_at_secs > now)
+            .flat_map(|rrset| rrset.records.iter())
+            .filter(|r| r.record_type() != HickoryRecordType::RRSIG)
+            .cloned()
+            .collect();
+        let proof: Vec<Record> = denial::relevant_records(&qname, &zone.apex, &data)
+            .into_iter()
+            .cloned()
+            .collect();
+
+        let kind = [DenialKind::NxDomain, DenialKind::NoData]
+            .into_iter()
+            .find(|&kind| {
+                denial::verify_denial(&qname, qtype, kind, &zone.apex, &proof)
+                    == ValidationResult::Secure
+            })?;
+
+        let mut authority: Vec<&CachedRrset> = vec![&zone.soa];
+        for record in &proof {
+            let key = (LowerName::new(record.name()), record.record_type());
+            if let Some(rrset) = zone.rrsets.get(&key) {
+                if !authority.iter().any(|seen| std::ptr::eq(*seen, rrset)) {
+                    authority.push(rrset);
+                }
+            }
+        }
+
+        let ttl = authority
+            .iter()
+            .map(|rrset| rrset.expires_at_secs.saturating_sub(now))
+            .min()
+            .unwrap_or(0) as u32;
+
+        let mut message = Message::new(0, MessageType::Response, OpCode::Query);
+        message.set_response_code(match kind {
+            DenialKind::NxDomain => ResponseCode::NXDomain,
+            DenialKind::NoData => ResponseCode::NoError,
+        });
+        message.set_recursion_desired(true);
+        message.set_recursion_available(true);
+        message.add_query(Query::query(qname, qtype));
+        message.add_name_servers(authority.iter().flat_map(|rrset| {
+            rrset.records.iter().map(move |r| {
+                let mut r = r.clone();
+                r.set_ttl(ttl);
+                r
+            })
+        }));
+
+        let mut wire = Vec::with_capacity(512);
+        let mut encoder = BinEncoder::new(&mut wire);
+        message.emit(&mut encoder).ok()?;
+        Some(SynthesizedDenial { kind, wire, ttl })
+    }
+
+    fn find_zone(
+        &self,
+        domain: &str,
+    ) -> Option<dashmap::mapref::one::Ref<'_, Box<str>, ZoneDenials>> {
+        let mut name = domain;
+        loop {
+            if let Some(zone) = self.zones.get(name) {
+                return Some(zone);
+            }
+            match name.split_once('.') {
+                Some((_, parent)) => name = parent,
+                None => return self.zones.get(""),
+            }
+        }
+    }
+
+    fn evict_zone(&self, now: u64) {
+        let victim = self
+            .zones
+            .iter()
+            .find(|z| z.soa.expires_at_secs <= now)
+            .or_else(|| self.zones.iter().next())
+            .map(|z| z.key().clone());
+        if let Some(key) = victim {
+            self.zones.remove(&key);
+        }
+    }
+
+    fn zone_key(apex: &Name) -> Box<str> {
+        apex.to_ascii()
+            .trim_end_matches('.')
+            .to_ascii_lowercase()
+            .into_boxed_str()
+    }
+
+    fn rrsigs_for(
+        authority: &[Record],
+        owner: &Name,
+        rtype: HickoryRecordType,
+        signer: &Name,
+    ) -> Vec<Record> {
+        authority
+            .iter()
+            .filter(|r| r.name() == owner)
+            .filter(|r| match r.data() {
+                RData::DNSSEC(DNSSECRData::RRSIG(rrsig)) => {
+                    rrsig.input().type_covered == rtype && rrsig.input().signer_name == *signer
+                }
+                _ => false,
+            })
+            .cloned()
+            .collect()
+    }
+}
@@ -1,12 +1,15 @@
 use compact_str::CompactString;
 use equivalent::Equivalent;
-use ferrous_dns_domain::RecordType;
+use ferrous_dns_domain::{QueryScope, RecordType};
 use std::hash::{Hash, Hasher};
 
 #[derive(Clone, Debug, Eq)]
 pub struct CacheKey {
     pub domain: CompactString,
     pub record_type: RecordType,
+    /// Scope whose view or own upstream pools produced the entry;
+    /// [`QueryScope::SHARED`] is the partition used by every other client.
+    pub partition: QueryScope,
 }
 
 impl CacheKey {
@@ -19,10 +22,17 @@ impl CacheKey {
     /// materialized into the `CompactString`.
     #[inline]
     pub fn new(domain: &str, record_type: RecordType) -> Self {
+        Self::partitioned(domain, record_type, QueryScope::SHARED)
+    }
+
+    /// Creates a key in the cache partition of `partition`.
+    #[inline]
+    pub fn partitioned(domain: &str, record_type: RecordType, partition: QueryScope) -> Self {
         let domain = normalize_domain_to_compact(domain);
         Self {
             domain,
             record_type,
+            partition,
         }
     }
 }
@@ -63,13 +73,16 @@ impl Hash for CacheKey {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.domain.as_str().hash(state);
         std::mem::discriminant(&self.record_type).hash(state);
+        self.partition.hash(state);
     }
 }
 
 impl PartialEq for CacheKey {
     #[inline]
     fn eq(&self, other: &CacheKey) -> bool {
-        self.record_type == other.record_type && self.domain == other.domain
+        self.record_type == other.record_type
+            && self.partition == other.partition
+            && self.domain == other.domain
     }
 }
 
@@ -77,6 +90,7 @@ impl PartialEq for CacheKey {
 pub struct BorrowedKey<'a> {
     pub domain: &'a str,
     pub record_type: RecordType,
+    pub partition: QueryScope,
 }
 
 impl<'a> BorrowedKey<'a> {
@@ -86,6 +100,12 @@ impl<'a> BorrowedKey<'a> {
     /// domain lowercased.
     #[inline]
     pub fn new(domain: &'a str, record_type: RecordType) -> Self {
+        Self::partitioned(domain, record_type, QueryScope::SHARED)
+    }
+
+    /// Zero-copy key view in the cache partition of `partition`.
+    #[inline]
+    pub fn partitioned(domain: &'a str, record_type: RecordType, partition: QueryScope) -> Self {
         debug_assert!(
             domain.bytes().all(|b| !b.is_ascii_uppercase()),
             "BorrowedKey domain must be ASCII-lowercased by the caller; got `{}`",
@@ -94,6 +114,7 @@ impl<'a> BorrowedKey<'a> {
         Self {
             domain,
             record_type,
+            partition,
         }
     }
 }
@@ -103,13 +124,16 @@ impl<'a> Hash for BorrowedKey<'a> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.domain.hash(state);
         std::mem::discriminant(&self.record_type).hash(state);
+        self.partition.hash(state);
     }
 }
 
 impl<'a> PartialEq for BorrowedKey<'a> {
     #[inline]
     fn eq(&self, other: &BorrowedKey<'a>) -> bool {
-        self.record_type == other.record_type && self.domain == other.domain
+        self.record_type == other.record_type
+            && self.partition == other.partition
+            && self.domain == other.domain
     }
 }
 
@@ -118,20 +142,26 @@ impl<'a> Eq for BorrowedKey<'a> {}
 impl<'a> PartialEq<CacheKey> for BorrowedKey<'a> {
     #[inline]
     fn eq(&self, other: &CacheKey) -> bool {
-        self.record_type == other.record_type && self.domain == other.domain.as_str()
+        self.record_type == other.record_type
+            && self.partition == other.partition
+            && self.domain == other.domain.as_str()
     }
 }
 
 impl<'a> PartialEq<BorrowedKey<'a>> for CacheKey {
     #[inline]
     fn eq(&self, other: &BorrowedKey<'a>) -> bool {
-        self.record_type == other.record_type && self.domain.as_str() == other.domain
+        self.record_type == other.record_type
+            && self.partition == other.partition
+            && self.domain.as_str() == other.domain
     }
 }
 
 impl<'a> Equivalent<CacheKey> for BorrowedKey<'a> {
     #[inline]
     fn equivalent(&self, key: &CacheKey) -> bool {
-        self.record_type == key.record_type && self.domain == key.domain.as_str()
+        self.record_type == key.record_type
+            && self.partition == key.partition
+            && self.domain == key.domain.as_str()
     }
 }
@@ -1,6 +1,6 @@
 use crate::dns::cache::coarse_clock::coarse_now_secs;
 use compact_str::CompactString;
-use ferrous_dns_domain::RecordType;
+use ferrous_dns_domain::{QueryScope, RecordType};
 use lru::LruCache;
 use rustc_hash::FxBuildHasher;
 use std::cell::RefCell;
@@ -35,35 +35,60 @@ thread_local! {
 }
 
 /// Looks up a domain in the thread-local L1 cache, returning addresses and remaining TTL.
+#[inline]
+pub fn l1_get(domain: &str, record_type: &RecordType, partition: QueryScope) -> Option<L1Hit> {
+    with_l1_key(domain, record_type, partition, lookup_l1)
+}
+
+/// Builds the composite key `"Type:domain"` (or `"Type#v<view>#g<group>:domain"`
+/// for a scoped cache partition) and hands it to `f`.
 ///
-/// The composite key `"Type:domain"` is built byte-by-byte with ASCII-lowercasing
-/// applied to the domain portion (RFC 1035 §2.3.3: DNS is case-insensitive).
-/// All lowercasing happens in the stack buffer — zero heap allocation.
+/// The domain portion is ASCII-lowercased byte-by-byte (RFC 1035 §2.3.3: DNS
+/// is case-insensitive). All lowercasing happens in the stack buffer — zero
+/// heap allocation unless the key exceeds the buffer.
 #[inline]
-pub fn l1_get(domain: &str, record_type: &RecordType) -> Option<L1Hit> {
+fn with_l1_key<R>(
+    domain: &str,
+    record_type: &RecordType,
+    partition: QueryScope,
+    f: impl FnOnce(&str) -> R,
+) -> R {
+    use std::io::Write;
+
     let type_str = record_type.as_str();
-    let type_len = type_str.len();
-    let dom_len = domain.len();
-    let total = type_len + 1 + dom_len;
+    let mut buf = [0u8; 288];
+    let mut prefix_len = type_str.len();
+    buf[..prefix_len].copy_from_slice(type_str.as_bytes());
+    if !partition.is_shared() {
+        let mut cursor = &mut buf[prefix_len..];
+        let before = cursor.len();
+        // A u32 and an i64 with their markers always fit after the type.
+        if let Some(view) = partition.view {
+            let _ = write!(cursor, "#v{view}");
+        }
+        if let Some(group) = partition.group {
+            let _ = write!(cursor, "#g{group}");
+        }
+        prefix_len += before - cursor.len();
+    }
+    let total = prefix_len + 1 + domain.len();
 
-    let mut buf = [0u8; 260];
     if total <= buf.len() {
-        buf[..type_len].copy_from_slice(type_str.as_bytes());
-        buf[type_len] = b':';
+        buf[prefix_len] = b':';
         for (i, &b) in domain.as_bytes().iter().enumerate() {
-            buf[type_len + 1 + i] = b.to_ascii_lowercase();
+            buf[prefix_len + 1 + i] = b.to_ascii_lowercase();
         }
-        // SAFETY: composed from valid UTF-8 (type_str + ':' + ASCII-lowercased domain)
-        let key_str = unsafe { std::str::from_utf8_unchecked(&buf[..total]) };
-        lookup_l1(key_str)
+        // SAFETY: composed from valid UTF-8 (prefix + ':' + ASCII-lowercased domain)
+        f(unsafe { std::str::from_utf8_unchecked(&buf[..total]) })
     } else {
         let mut key = CompactString::with_capacity(total);
-        key.push_str(type_str);
+        // SAFETY: the prefix is ASCII written above.
+        key.push_str(unsafe { std::str::from_utf8_unchecked(&buf[..prefix_len]) });
         key.push(':');
         for &b in domain.as_bytes() {
             key.push(b.to_ascii_lowercase() as char);
         }
-        lookup_l1(&key)
+        f(&key)
     }
 }
 
@@ -91,39 +116,17 @@ fn lookup_l1(key_str: &str) -> Option<L1Hit> {
 
 /// Inserts a resolved entry into the thread-local L1 cache with an expiration timestamp.
 ///
-/// The composite key `"Type:domain"` is built byte-by-byte with ASCII-lowercasing
-/// applied to the domain portion so lookups from any case variant hit the same
-/// entry (RFC 1035 §2.3.3). Lowercasing happens in-place in the stack buffer.
+/// Uses the same composite key as [`l1_get`], so lookups from any case variant
+/// hit the same entry and partitions never share entries.
 #[inline]
 pub fn l1_insert(
     domain: &str,
     record_type: &RecordType,
+    partition: QueryScope,
     addresses: Arc<Vec<IpAddr>>,
     expires_secs: u64,
 ) {
-    let type_str = record_type.as_str();
-    let type_len = type_str.len();
-    let dom_len = domain.len();
-    let total = type_len + 1 + dom_len;
-
-    let mut buf = [0u8; 260];
-    let key = if total <= buf.len() {
-        buf[..type_len].copy_from_slice(type_str.as_bytes());
-        buf[type_len] = b':';
-        for (i, &b) in domain.as_bytes().iter().enumerate() {
-            buf[type_len + 1 + i] = b.to_ascii_lowercase();
-        }
-        // SAFETY: composed from valid UTF-8 (type_str + ':' + ASCII-lowercased domain)
-        CompactString::from(unsafe { std::str::from_utf8_unchecked(&buf[..total]) })
-    } else {
-        let mut key = CompactString::with_capacity(total);
-        key.push_str(type_str);
-        key.push(':');
-        for &b in domain.as_bytes() {
-            key.push(b.to_ascii_lowercase() as char);
-        }
-        key
-    };
+    let key = with_l1_key(domain, record_type, partition, |k| CompactString::from(k));
 
     L1_CACHE.with(|state| {
         state.borrow_mut().cache.put(
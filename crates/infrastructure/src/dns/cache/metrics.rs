@@ -22,6 +22,11 @@ pub struct CacheMetrics {
     /// during transient upstream instability and hand clients fake NXDOMAIN
     /// answers for legitimate domains.
     pub transient_upstream_errors: AtomicU64,
+
+    /// NXDOMAIN and NODATA answers synthesised from cached NSEC/NSEC3
+    /// ranges (RFC 8198) instead of being sent upstream.
+    pub aggressive_nxdomain_hits: AtomicU64,
+    pub aggressive_nodata_hits: AtomicU64,
 }
 
 impl CacheMetrics {
@@ -1,3 +1,4 @@
+pub mod aggressive_nsec;
 pub mod bloom;
 pub mod coarse_clock;
 pub mod compaction;
@@ -13,6 +14,7 @@ pub mod record;
 pub mod refresh;
 pub mod storage;
 
+pub use aggressive_nsec::{AggressiveNsecCache, SynthesizedDenial};
 pub use bloom::AtomicBloom;
 pub use data::{CachedAddresses, CachedData, DnssecStatus};
 pub use eviction::EvictionStrategy;
@@ -1,7 +1,7 @@
 use super::coarse_clock::coarse_now_secs;
 use super::key::CacheKey;
 use dashmap::DashMap;
-use ferrous_dns_domain::RecordType;
+use ferrous_dns_domain::{QueryScope, RecordType};
 use rustc_hash::FxBuildHasher;
 use smallvec::SmallVec;
 
@@ -54,13 +54,18 @@ impl NegativeDnsCache {
         }
     }
 
-    pub fn get(&self, domain: &str, record_type: &RecordType) -> Option<u32> {
+    pub fn get(
+        &self,
+        domain: &str,
+        record_type: &RecordType,
+        partition: QueryScope,
+    ) -> Option<u32> {
         debug_assert!(
             domain.bytes().all(|b| !b.is_ascii_uppercase()),
             "NegativeDnsCache::get expects caller to pass ASCII-lowercased domain; got `{}`",
             domain
         );
-        let key = CacheKey::new(domain, *record_type);
+        let key = CacheKey::partitioned(domain, *record_type, partition);
         let now = coarse_now_secs();
 
         match self.cache.get(&key) {
@@ -77,7 +82,7 @@ impl NegativeDnsCache {
         }
     }
 
-    pub fn insert(&self, domain: &str, record_type: RecordType, ttl: u32) {
+    pub fn insert(&self, domain: &str, record_type: RecordType, partition: QueryScope, ttl: u32) {
         debug_assert!(
             domain.bytes().all(|b| !b.is_ascii_uppercase()),
             "NegativeDnsCache::insert expects caller to pass ASCII-lowercased domain; got `{}`",
@@ -104,17 +109,17 @@ impl NegativeDnsCache {
             }
         }
         let expires_at_secs = coarse_now_secs() + ttl as u64;
-        let key = CacheKey::new(domain, record_type);
+        let key = CacheKey::partitioned(domain, record_type, partition);
         self.cache.insert(key, NegativeEntry { expires_at_secs });
     }
 
-    pub fn remove(&self, domain: &str, record_type: &RecordType) {
+    pub fn remove(&self, domain: &str, record_type: &RecordType, partition: QueryScope) {
         debug_assert!(
             domain.bytes().all(|b| !b.is_ascii_uppercase()),
             "NegativeDnsCache::remove expects caller to pass ASCII-lowercased domain; got `{}`",
             domain
         );
-        let key = CacheKey::new(domain, *record_type);
+        let key = CacheKey::partitioned(domain, *record_type, partition);
         self.cache.remove(&key);
     }
 
@@ -1,5 +1,6 @@
 use super::data::{CachedData, DnssecStatus};
-use ferrous_dns_domain::RecordType;
+use crate::dns::dnssec::DenialKind;
+use ferrous_dns_domain::{QueryScope, RecordType};
 
 pub trait DnsCacheAccess: Send + Sync {
     fn get(
@@ -17,10 +18,46 @@ pub trait DnsCacheAccess: Send + Sync {
         dnssec_status: Option<DnssecStatus>,
     );
 
+    /// Looks up `domain` in the cache partition of `partition`. The default
+    /// only serves the shared partition, so implementations without
+    /// partition support never hand one scope's answers to another.
+    fn get_partitioned(
+        &self,
+        domain: &str,
+        record_type: &RecordType,
+        partition: QueryScope,
+    ) -> Option<(CachedData, Option<DnssecStatus>, Option<u32>)> {
+        if partition.is_shared() {
+            self.get(domain, record_type)
+        } else {
+            None
+        }
+    }
+
+    /// Stores an entry in the cache partition of `partition`. The default
+    /// only caches the shared partition.
+    fn insert_partitioned(
+        &self,
+        domain: &str,
+        record_type: RecordType,
+        partition: QueryScope,
+        data: CachedData,
+        ttl: u32,
+        dnssec_status: Option<DnssecStatus>,
+    ) {
+        if partition.is_shared() {
+            self.insert(domain, record_type, data, ttl, dnssec_status);
+        }
+    }
+
     /// Phase 6: records a transient upstream error that was explicitly NOT
     /// cached as a negative response (timeout, connection refused/reset,
     /// no healthy servers, etc.). Default is a no-op so test doubles don't
     /// have to implement metrics.
     #[inline]
     fn record_transient_upstream_error(&self) {}
+
+    /// Records a negative answer synthesised from cached NSEC/NSEC3 ranges.
+    #[inline]
+    fn record_aggressive_nsec_hit(&self, _kind: DenialKind) {}
 }
@@ -6,8 +6,9 @@ use super::l1::{l1_clear, l1_get, l1_insert};
 use super::negative_cache::NegativeDnsCache;
 use super::port::DnsCacheAccess;
 use super::{CacheMetrics, CachedData, CachedRecord, DnssecStatus};
+use crate::dns::dnssec::DenialKind;
 use dashmap::{DashMap, DashSet};
-use ferrous_dns_domain::RecordType;
+use ferrous_dns_domain::{QueryScope, RecordType};
 use rustc_hash::FxBuildHasher;
 use std::borrow::Cow;
 use std::collections::BinaryHeap;
@@ -96,7 +97,7 @@ pub struct DnsCache {
     permanent_keys: Arc<DashSet<CacheKey, FxBuildHasher>>,
     min_ttl: u32,
     max_ttl: u32,
-    stale_refresh_tx: OnceLock<mpsc::Sender<(Arc<str>, RecordType)>>,
+    stale_refresh_tx: OnceLock<mpsc::Sender<CacheKey>>,
 }
 
 impl DnsCache {
@@ -178,12 +179,29 @@ impl DnsCache {
         &self,
         domain: &str,
         record_type: &RecordType,
+    ) -> Option<(CachedData, Option<DnssecStatus>, Option<u32>)> {
+        self.get_partitioned(domain, record_type, QueryScope::SHARED)
+    }
+
+    /// Looks up `domain` in the cache partition of `partition` only; entries
+    /// stored for other partitions are never returned, except permanent
+    /// local records, which every partition sees.
+    pub fn get_partitioned(
+        &self,
+        domain: &str,
+        record_type: &RecordType,
+        partition: QueryScope,
     ) -> Option<(CachedData, Option<DnssecStatus>, Option<u32>)> {
         let domain = normalize_domain(domain);
         let domain = domain.as_ref();
-        let borrowed = BorrowedKey::new(domain, *record_type);
+        if !partition.is_shared() {
+            if let Some(hit) = self.get_permanent(domain, record_type) {
+                return Some(hit);
+            }
+        }
+        let borrowed = BorrowedKey::partitioned(domain, *record_type, partition);
 
-        if let Some((arc_data, remaining_ttl)) = l1_get(domain, record_type) {
+        if let Some((arc_data, remaining_ttl)) = l1_get(domain, record_type, partition) {
             self.metrics.hits.fetch_add(1, AtomicOrdering::Relaxed);
             self.bloom.refresh(&borrowed);
             return Some((
@@ -198,7 +216,7 @@ impl DnsCache {
         let in_bloom = self.bloom.check(&borrowed);
 
         if !in_bloom {
-            if let Some(remaining_ttl) = self.negative.get(domain, record_type) {
+            if let Some(remaining_ttl) = self.negative.get(domain, record_type, partition) {
                 self.metrics.hits.fetch_add(1, AtomicOrdering::Relaxed);
                 return Some((CachedData::NegativeResponse, None, Some(remaining_ttl)));
             }
@@ -206,7 +224,7 @@ impl DnsCache {
             return None;
         }
 
-        let key = CacheKey::new(domain, *record_type);
+        let key = CacheKey::partitioned(domain, *record_type, partition);
 
         if let Some(entry) = self.cache.get(&key) {
             let record = entry.value();
@@ -221,9 +239,7 @@ impl DnsCache {
                 record.record_hit();
                 self.bloom.refresh(&borrowed);
                 if let Some(tx) = self.stale_refresh_tx.get() {
-                    if record.try_set_refreshing()
-                        && tx.try_send((Arc::from(domain), key.record_type)).is_err()
-                    {
+                    if record.try_set_refreshing() && tx.try_send(key.clone()).is_err() {
                         record.clear_refreshing();
                     }
                 }
@@ -245,7 +261,7 @@ impl DnsCache {
                 record.record_hit();
                 self.bloom.refresh(&borrowed);
                 let remaining_ttl = record.expires_at_secs.saturating_sub(now_secs) as u32;
-                self.promote_to_l1(domain, record_type, record, now_secs);
+                self.promote_to_l1(domain, record_type, partition, record, now_secs);
                 return Some((
                     record.data.clone(),
                     Some(record.dnssec_status),
@@ -254,7 +270,7 @@ impl DnsCache {
             }
         }
 
-        if let Some(remaining_ttl) = self.negative.get(domain, record_type) {
+        if let Some(remaining_ttl) = self.negative.get(domain, record_type, partition) {
             self.metrics.hits.fetch_add(1, AtomicOrdering::Relaxed);
             return Some((CachedData::NegativeResponse, None, Some(remaining_ttl)));
         }
@@ -263,6 +279,31 @@ impl DnsCache {
         None
     }
 
+    /// Permanent entries live in the shared partition only.
+    fn get_permanent(
+        &self,
+        domain: &str,
+        record_type: &RecordType,
+    ) -> Option<(CachedData, Option<DnssecStatus>, Option<u32>)> {
+        if self.permanent_keys.is_empty() {
+            return None;
+        }
+        let key = CacheKey::new(domain, *record_type);
+        if !self.permanent_keys.contains(&key) {
+            return None;
+        }
+        let entry = self.cache.get(&key)?;
+        let record = entry.value();
+        self.metrics.hits.fetch_add(1, AtomicOrdering::Relaxed);
+        record.record_hit();
+        let remaining_ttl = record.expires_at_secs.saturating_sub(coarse_now_secs()) as u32;
+        Some((
+            record.data.clone(),
+            Some(record.dnssec_status),
+            Some(remaining_ttl),
+        ))
+    }
+
     pub fn insert(
         &self,
         domain: &str,
@@ -270,6 +311,26 @@ impl DnsCache {
         data: CachedData,
         ttl: u32,
         dnssec_status: Option<DnssecStatus>,
+    ) {
+        self.insert_partitioned(
+            domain,
+            record_type,
+            QueryScope::SHARED,
+            data,
+            ttl,
+            dnssec_status,
+        );
+    }
+
+    /// Stores an entry in the cache partition of `partition`.
+    pub fn insert_partitioned(
+        &self,
+        domain: &str,
+        record_type: RecordType,
+        partition: QueryScope,
+        data: CachedData,
+        ttl: u32,
+        dnssec_status: Option<DnssecStatus>,
     ) {
         let domain = normalize_domain(domain);
         let domain = domain.as_ref();
@@ -282,12 +343,12 @@ impl DnsCache {
             // break the refresh/access-window cycle, while deflating negatives
             // would defeat the 300s floor that keeps NXDOMAINs from escaping
             // to upstream on every repeated miss.
-            self.negative.insert(domain, record_type, ttl);
+            self.negative.insert(domain, record_type, partition, ttl);
             return;
         }
 
         let ttl = self.clamp_ttl(ttl);
-        let key = CacheKey::new(domain, record_type);
+        let key = CacheKey::partitioned(domain, record_type, partition);
 
         if self.cache.len() >= self.max_entries {
             self.eviction_pending.store(true, AtomicOrdering::Relaxed);
@@ -316,12 +377,13 @@ impl DnsCache {
         }
 
         if let Some(addresses) = maybe_l1_addresses {
-            l1_insert(domain, &record_type, addresses, expires_secs);
+            l1_insert(domain, &record_type, partition, addresses, expires_secs);
         }
 
         debug!(
             domain = %domain,
             record_type = %record_type,
+            partition = ?partition,
             ttl,
             "Inserted record into cache"
         );
@@ -354,7 +416,13 @@ impl DnsCache {
         self.cache.insert(key, record);
 
         if let Some(addresses) = maybe_l1_addresses {
-            l1_insert(domain, &record_type, addresses, u64::MAX);
+            l1_insert(
+                domain,
+                &record_type,
+                QueryScope::SHARED,
+                addresses,
+                u64::MAX,
+            );
         }
     }
 
@@ -424,7 +492,7 @@ impl DnsCache {
         self.access_window_secs
     }
 
-    pub fn set_stale_refresh_sender(&self, tx: mpsc::Sender<(Arc<str>, RecordType)>) {
+    pub fn set_stale_refresh_sender(&self, tx: mpsc::Sender<CacheKey>) {
         if self.stale_refresh_tx.set(tx).is_err() {
             tracing::warn!("Stale refresh sender already configured — second sender dropped");
         }
@@ -437,10 +505,29 @@ impl DnsCache {
         new_ttl: Option<u32>,
         new_data: CachedData,
         dnssec_status: Option<DnssecStatus>,
+    ) -> bool {
+        self.refresh_record_partitioned(
+            domain,
+            record_type,
+            QueryScope::SHARED,
+            new_ttl,
+            new_data,
+            dnssec_status,
+        )
+    }
+
+    pub fn refresh_record_partitioned(
+        &self,
+        domain: &str,
+        record_type: &RecordType,
+        partition: QueryScope,
+        new_ttl: Option<u32>,
+        new_data: CachedData,
+        dnssec_status: Option<DnssecStatus>,
     ) -> bool {
         let domain = normalize_domain(domain);
         let domain = domain.as_ref();
-        let key = CacheKey::new(domain, *record_type);
+        let key = CacheKey::partitioned(domain, *record_type, partition);
         let now = coarse_now_secs();
 
         if let Some(mut entry) = self.cache.get_mut(&key) {
@@ -465,7 +552,13 @@ impl DnsCache {
             record.data = new_data;
 
             if let Some(addresses) = maybe_l1_addresses {
-                l1_insert(domain, record_type, addresses, record.expires_at_secs);
+                l1_insert(
+                    domain,
+                    record_type,
+                    partition,
+                    addresses,
+                    record.expires_at_secs,
+                );
             }
             true
         } else {
@@ -479,6 +572,7 @@ impl DnsCache {
         &self,
         domain: &str,
         record_type: &RecordType,
+        partition: QueryScope,
         record: &CachedRecord,
         now_secs: u64,
     ) {
@@ -489,6 +583,7 @@ impl DnsCache {
             l1_insert(
                 domain,
                 record_type,
+                partition,
                 Arc::clone(&entry.addresses),
                 record.expires_at_secs,
             );
@@ -661,6 +756,10 @@ impl ferrous_dns_application::ports::DnsCachePort for DnsCache {
             transient_upstream_errors: metrics
                 .transient_upstream_errors
                 .load(AtomicOrdering::Relaxed),
+            aggressive_nxdomain_hits: metrics
+                .aggressive_nxdomain_hits
+                .load(AtomicOrdering::Relaxed),
+            aggressive_nodata_hits: metrics.aggressive_nodata_hits.load(AtomicOrdering::Relaxed),
         }
     }
 
@@ -701,10 +800,48 @@ impl DnsCacheAccess for DnsCache {
         DnsCache::insert(self, domain, record_type, data, ttl, dnssec_status);
     }
 
+    fn get_partitioned(
+        &self,
+        domain: &str,
+        record_type: &RecordType,
+        partition: QueryScope,
+    ) -> Option<(CachedData, Option<DnssecStatus>, Option<u32>)> {
+        DnsCache::get_partitioned(self, domain, record_type, partition)
+    }
+
+    fn insert_partitioned(
+        &self,
+        domain: &str,
+        record_type: RecordType,
+        partition: QueryScope,
+        data: CachedData,
+        ttl: u32,
+        dnssec_status: Option<DnssecStatus>,
+    ) {
+        DnsCache::insert_partitioned(
+            self,
+            domain,
+            record_type,
+            partition,
+            data,
+            ttl,
+            dnssec_status,
+        );
+    }
+
     #[inline]
     fn record_transient_upstream_error(&self) {
         self.metrics
             .transient_upstream_errors
             .fetch_add(1, AtomicOrdering::Relaxed);
     }
+
+    #[inline]
+    fn record_aggressive_nsec_hit(&self, kind: DenialKind) {
+        let counter = match kind {
+            DenialKind::NxDomain => &self.metrics.aggressive_nxdomain_hits,
+            DenialKind::NoData => &self.metrics.aggressive_nodata_hits,
+        };
+        counter.fetch_add(1, AtomicOrdering::Relaxed);
+    }
 }
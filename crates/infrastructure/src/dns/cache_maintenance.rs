@@ -1,11 +1,11 @@
-use super::cache::{coarse_clock, CachedAddresses, CachedData, DnsCache};
+use super::cache::{coarse_clock, CacheKey, CachedAddresses, CachedData, DnsCache};
 
 use async_trait::async_trait;
 use ferrous_dns_application::ports::{
     CacheCompactionOutcome, CacheMaintenancePort, CacheRefreshOutcome, DnsResolver,
     QueryLogRepository,
 };
-use ferrous_dns_domain::{DnsQuery, DomainError, QueryLog, QuerySource, RecordType};
+use ferrous_dns_domain::{DnsQuery, DomainError, QueryLog, QuerySource};
 use std::net::IpAddr;
 use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
 use std::sync::Arc;
@@ -67,10 +67,11 @@ impl DnsCacheMaintenance {
         cache: &Arc<DnsCache>,
         resolver: &Arc<dyn DnsResolver>,
         query_log: &Option<Arc<dyn QueryLogRepository>>,
-        domain: &str,
-        record_type: &ferrous_dns_domain::RecordType,
+        key: &CacheKey,
     ) -> Result<bool, DomainError> {
         let start = Instant::now();
+        let domain = key.domain.as_str();
+        let record_type = &key.record_type;
 
         debug!(
             domain = %domain,
@@ -78,7 +79,9 @@ impl DnsCacheMaintenance {
             "Refreshing cache entry (will revalidate DNSSEC if enabled)"
         );
 
-        let query = DnsQuery::new(domain, *record_type);
+        // Partitioned entries belong to a group with its own upstream pools
+        // and must be refreshed through those same pools.
+        let query = DnsQuery::new(domain, *record_type).with_scope(key.partition);
 
         match resolver.resolve(&query).await {
             Ok(resolution)
@@ -99,9 +102,10 @@ impl DnsCacheMaintenance {
                     return Ok(false);
                 };
 
-                let refreshed = cache.refresh_record(
+                let refreshed = cache.refresh_record_partitioned(
                     domain,
                     record_type,
+                    key.partition,
                     resolution.min_ttl,
                     new_data,
                     dnssec_status,
@@ -130,6 +134,7 @@ impl DnsCacheMaintenance {
                         query_source: QuerySource::Internal,
                         group_id: None,
                         block_source: None,
+                        blocklist_source_ids: Vec::new(),
                     };
 
                     if let Err(e) = log.log_query(&log_entry).await {
@@ -159,7 +164,7 @@ impl DnsCacheMaintenance {
         cache: Arc<DnsCache>,
         resolver: Arc<dyn DnsResolver>,
         query_log: Option<Arc<dyn QueryLogRepository>>,
-        mut rx: mpsc::Receiver<(Arc<str>, RecordType)>,
+        mut rx: mpsc::Receiver<CacheKey>,
     ) {
         const MAX_CONCURRENT_REFRESHES: usize = 16;
         let semaphore = Arc::new(tokio::sync::Semaphore::new(MAX_CONCURRENT_REFRESHES));
@@ -167,7 +172,7 @@ impl DnsCacheMaintenance {
         tokio::spawn(async move {
             loop {
                 match rx.recv().await {
-                    Some((domain, record_type)) => {
+                    Some(key) => {
                         let permit = match semaphore.clone().acquire_owned().await {
                             Ok(p) => p,
                             Err(_) => break,
@@ -176,32 +181,24 @@ impl DnsCacheMaintenance {
                         let resolver = Arc::clone(&resolver);
                         let query_log = query_log.clone();
                         tokio::spawn(async move {
-                            match Self::refresh_entry(
-                                &cache,
-                                &resolver,
-                                &query_log,
-                                &domain,
-                                &record_type,
-                            )
-                            .await
-                            {
+                            match Self::refresh_entry(&cache, &resolver, &query_log, &key).await {
                                 Ok(true) => {
                                     debug!(
-                                        domain = %domain,
-                                        record_type = %record_type,
+                                        domain = %key.domain,
+                                        record_type = %key.record_type,
                                         "Stale entry refreshed immediately"
                                     );
                                 }
                                 Ok(false) => {
-                                    cache.reset_refreshing(&domain, &record_type);
+                                    cache.reset_refreshing_key(&key);
                                 }
                                 Err(e) => {
                                     debug!(
-                                        domain = %domain,
+                                        domain = %key.domain,
                                         error = %e,
                                         "Stale refresh failed"
                                     );
-                                    cache.reset_refreshing(&domain, &record_type);
+                                    cache.reset_refreshing_key(&key);
                                 }
                             }
                             drop(permit);
@@ -260,16 +257,8 @@ impl CacheMaintenancePort for DnsCacheMaintenance {
         let mut failed = 0;
         let candidate_count = candidates.len();
 
-        for (domain, record_type) in &candidates {
-            match Self::refresh_entry(
-                &self.cache,
-                &self.resolver,
-                &self.query_log,
-                domain,
-                record_type,
-            )
-            .await
-            {
+        for key in &candidates {
+            match Self::refresh_entry(&self.cache, &self.resolver, &self.query_log, key).await {
                 Ok(true) => {
                     refreshed += 1;
                     self.cache
@@ -278,10 +267,10 @@ impl CacheMaintenancePort for DnsCacheMaintenance {
                         .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
                 }
                 Ok(false) => {
-                    self.cache.reset_refreshing(domain, record_type);
+                    self.cache.reset_refreshing_key(key);
                 }
                 Err(_) => {
-                    self.cache.reset_refreshing(domain, record_type);
+                    self.cache.reset_refreshing_key(key);
                     failed += 1;
                 }
             }
@@ -0,0 +1,157 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use ferrous_dns_domain::{TrustAnchor, TrustAnchorData, TrustAnchorSource};
+use std::path::Path;
+use std::sync::Arc;
+
+/// Reads a trust anchor file such as the `root.key` written by
+/// `unbound-anchor`. Its anchors are managed with RFC 5011.
+pub fn load_trust_anchor_file(path: &Path, now: i64) -> Result<Vec<TrustAnchor>, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    parse_trust_anchors(&text, TrustAnchorSource::File, true, now)
+        .map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Parses DNSKEY and DS records in zone file presentation format: one
+/// record per line, or spread over several inside parentheses. TTL and
+/// class may be omitted and `$` directives are ignored.
+pub fn parse_trust_anchors(
+    text: &str,
+    source: TrustAnchorSource,
+    managed: bool,
+    now: i64,
+) -> Result<Vec<TrustAnchor>, String> {
+    let mut anchors = Vec::new();
+    for (line_no, record) in records(text) {
+        let data = parse_record(&record).map_err(|e| format!("line {}: {}", line_no, e))?;
+        if let Some((zone, data)) = data {
+            anchors.push(TrustAnchor::new(&zone, data, source, managed, now));
+        }
+    }
+    Ok(anchors)
+}
+
+/// Joins parenthesised continuation lines and strips comments, yielding
+/// each record with the line it starts on.
+fn records(text: &str) -> Vec<(usize, String)> {
+    let mut out = Vec::new();
+    let mut current = String::new();
+    let mut start = 0;
+    let mut depth = 0usize;
+
+    for (i, line) in text.lines().enumerate() {
+        let line = line.split(';').next().unwrap_or_default();
+        if depth == 0 {
+            start = i + 1;
+        }
+        for c in line.chars() {
+            match c {
+                '(' => {
+                    depth += 1;
+                    current.push(' ');
+                }
+                ')' => {
+                    depth = depth.saturating_sub(1);
+                    current.push(' ');
+                }
+                c => current.push(c),
+            }
+        }
+        current.push(' ');
+        if depth == 0 {
+            if !current.trim().is_empty() {
+                out.push((start, std::mem::take(&mut current)));
+            }
+            current.clear();
+        }
+    }
+    if !current.trim().is_empty() {
+        out.push((start, current));
+    }
+    out
+}
+
+fn parse_record(record: &str) -> Result<Option<(String, TrustAnchorData)>, String> {
+    let mut tokens = record.split_whitespace();
+    let owner = tokens.next().ok_or("empty record")?;
+    if owner.starts_with('$') {
+        return Ok(None);
+    }
+
+    let mut rtype = None;
+    for token in tokens.by_ref() {
+        let upper = token.to_ascii_uppercase();
+        if token.chars().all(|c| c.is_ascii_digit()) || matches!(upper.as_str(), "IN" | "CH" | "HS")
+        {
+            continue;
+        }
+        rtype = Some(upper);
+        break;
+    }
+
+    let rest: Vec<&str> = tokens.collect();
+    let field = |i: usize, name: &str| -> Result<&str, String> {
+        rest.get(i)
+            .copied()
+            .ok_or_else(|| format!("{} not present", name))
+    };
+
+    let data = match rtype.as_deref() {
+        Some("DNSKEY") => {
+            let flags: u16 = number(field(0, "flags")?, "flags")?;
+            let protocol: u8 = number(field(1, "protocol")?, "protocol")?;
+            if protocol != 3 {
+                return Err("DNSKEY protocol field must be 3".to_string());
+            }
+            let algorithm: u8 = number(field(2, "algorithm")?, "algorithm")?;
+            let key: String = rest.iter().skip(3).copied().collect();
+            if key.is_empty() {
+                return Err("public key not present".to_string());
+            }
+            let public_key = STANDARD
+                .decode(key.as_bytes())
+                .map_err(|e| format!("invalid public key: {}", e))?;
+            TrustAnchorData::Dnskey {
+                flags,
+                algorithm,
+                public_key: Arc::from(public_key),
+            }
+        }
+        Some("DS") => {
+            let key_tag: u16 = number(field(0, "key tag")?, "key tag")?;
+            let algorithm: u8 = number(field(1, "algorithm")?, "algorithm")?;
+            let digest_type: u8 = number(field(2, "digest type")?, "digest type")?;
+            let hex: String = rest.iter().skip(3).copied().collect();
+            if hex.is_empty() {
+                return Err("digest not present".to_string());
+            }
+            TrustAnchorData::Ds {
+                key_tag,
+                algorithm,
+                digest_type,
+                digest: Arc::from(decode_hex(&hex)?),
+            }
+        }
+        Some(other) => return Err(format!("{} {} is not a DNSKEY or DS record", owner, other)),
+        None => return Err(format!("{}: record type missing", owner)),
+    };
+
+    Ok(Some((owner.to_string(), data)))
+}
+
+fn number<T: std::str::FromStr>(token: &str, name: &str) -> Result<T, String> {
+    token
+        .parse()
+        .map_err(|_| format!("invalid {}: '{}'", name, token))
+}
+
+fn decode_hex(hex: &str) -> Result<Vec<u8>, String> {
+    if !hex.is_ascii() || !hex.len().is_multiple_of(2) {
+        return Err("digest is not an even number of hex digits".to_string());
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| format!("invalid digest: '{}'", hex))
+        })
+        .collect()
+}
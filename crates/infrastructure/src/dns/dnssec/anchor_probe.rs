@@ -0,0 +1,113 @@
+use super::crypto::SignatureVerifier;
+use super::trust_anchor::AnchorKey;
+use super::types::{DnskeyRecord, RrsigRecord};
+use crate::dns::load_balancer::PoolManager;
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{ProbedKey, TrustAnchorProbe};
+use ferrous_dns_domain::{DomainError, QueryScope, RecordType, TrustAnchorData};
+use hickory_proto::dnssec::rdata::DNSSECRData;
+use hickory_proto::dnssec::PublicKey;
+use hickory_proto::rr::{RData, Record, RecordType as HickoryRecordType};
+use std::sync::Arc;
+use tracing::debug;
+
+/// Fetches a zone's DNSKEY RRset for RFC 5011 trust anchor maintenance
+/// and checks which keys signed it.
+pub struct DnskeyProbe {
+    pool_manager: Arc<PoolManager>,
+    timeout_ms: u64,
+    verifier: SignatureVerifier,
+}
+
+impl DnskeyProbe {
+    pub fn new(pool_manager: Arc<PoolManager>, timeout_ms: u64) -> Self {
+        Self {
+            pool_manager,
+            timeout_ms,
+            verifier: SignatureVerifier,
+        }
+    }
+}
+
+#[async_trait]
+impl TrustAnchorProbe for DnskeyProbe {
+    async fn fetch_keys(&self, zone: &str) -> Result<Vec<ProbedKey>, DomainError> {
+        let name: Arc<str> = Arc::from(zone);
+        let result = self
+            .pool_manager
+            .query(
+                &name,
+                &RecordType::DNSKEY,
+                self.timeout_ms,
+                true,
+                QueryScope::SHARED,
+            )
+            .await?;
+
+        let mut keys = Vec::new();
+        let mut rrset: Vec<Record> = Vec::new();
+        let mut rrsigs = Vec::new();
+
+        for record in &result.response.raw_answers {
+            match record.data() {
+                RData::DNSSEC(DNSSECRData::DNSKEY(dnskey)) => {
+                    let pk = dnskey.public_key();
+                    keys.push(DnskeyRecord {
+                        flags: dnskey.flags(),
+                        protocol: 3,
+                        algorithm: u8::from(<dyn PublicKey>::algorithm(pk)),
+                        public_key: <dyn PublicKey>::public_bytes(pk).to_vec(),
+                    });
+                    rrset.push(record.clone());
+                }
+                RData::DNSSEC(DNSSECRData::RRSIG(rrsig))
+                    if rrsig.input().type_covered == HickoryRecordType::DNSKEY =>
+                {
+                    rrsigs.extend(RrsigRecord::from_hickory(rrsig));
+                }
+                _ => {}
+            }
+        }
+
+        let now_secs = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs() as u32)
+            .unwrap_or(0);
+
+        let probed: Vec<ProbedKey> = keys
+            .into_iter()
+            .map(|key| {
+                let signs_rrset = rrsigs.iter().any(|sig| {
+                    self.verifier
+                        .verify_rrsig(sig, &key, zone, &rrset, now_secs)
+                        .unwrap_or(false)
+                });
+                ProbedKey {
+                    data: TrustAnchorData::Dnskey {
+                        flags: key.flags,
+                        algorithm: key.algorithm,
+                        public_key: Arc::from(key.public_key),
+                    },
+                    signs_rrset,
+                }
+            })
+            .collect();
+
+        debug!(
+            zone = %zone,
+            keys = probed.len(),
+            signing = probed.iter().filter(|k| k.signs_rrset).count(),
+            "DNSKEY RRset probed"
+        );
+        Ok(probed)
+    }
+
+    fn matches_ds(&self, zone: &str, key: &TrustAnchorData, ds: &TrustAnchorData) -> bool {
+        match (AnchorKey::from_data(key), AnchorKey::from_data(ds)) {
+            (AnchorKey::Dnskey(key), AnchorKey::Ds(ds)) => {
+                self.verifier.verify_ds(&ds, &key, zone).unwrap_or(false)
+            }
+            _ => false,
+        }
+    }
+}
@@ -1,7 +1,9 @@
 pub mod entries;
+pub mod negative_anchors;
 pub mod stats;
 pub mod storage;
 
 pub use entries::{DnskeyEntry, DsEntry, ValidationEntry};
+pub use negative_anchors::NegativeTrustAnchorTable;
 pub use stats::{CacheStats, CacheStatsSnapshot};
 pub use storage::DnssecCache;
@@ -0,0 +1,107 @@
+use arc_swap::ArcSwap;
+use chrono::NaiveDateTime;
+use ferrous_dns_application::ports::NegativeTrustAnchorStore;
+use ferrous_dns_domain::{NegativeTrustAnchor, NTA_TIMESTAMP_FORMAT};
+use rustc_hash::FxHashMap;
+use std::borrow::Cow;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::{info, warn};
+
+/// Lock-free, hot-swappable set of negative trust anchors (RFC 7646).
+///
+/// A name is covered when it or one of its parents is listed and the
+/// entry has not expired yet. Expired entries stop applying straight away;
+/// the cleanup job only removes them from the database.
+pub struct NegativeTrustAnchorTable {
+    /// Normalized domain → expiry in Unix seconds.
+    anchors: ArcSwap<FxHashMap<Box<str>, Option<i64>>>,
+}
+
+impl Default for NegativeTrustAnchorTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NegativeTrustAnchorTable {
+    pub fn new() -> Self {
+        Self {
+            anchors: ArcSwap::from_pointee(FxHashMap::default()),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.anchors.load().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.anchors.load().is_empty()
+    }
+
+    pub fn covers(&self, domain: &str) -> bool {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0);
+        self.covers_at(domain, now)
+    }
+
+    pub fn covers_at(&self, domain: &str, now: i64) -> bool {
+        let anchors = self.anchors.load();
+        if anchors.is_empty() {
+            return false;
+        }
+
+        let normalized: Cow<'_, str> = if domain.bytes().any(|b| b.is_ascii_uppercase()) {
+            Cow::Owned(domain.to_ascii_lowercase())
+        } else {
+            Cow::Borrowed(domain)
+        };
+        let mut name = normalized.trim_end_matches('.');
+
+        loop {
+            if let Some(expires_at) = anchors.get(name) {
+                if expires_at.is_none_or(|t| now < t) {
+                    return true;
+                }
+            }
+            match name.split_once('.') {
+                Some((_, parent)) => name = parent,
+                None => return false,
+            }
+        }
+    }
+
+    fn parse_expiry(anchor: &NegativeTrustAnchor) -> Result<Option<i64>, ()> {
+        let Some(expires_at) = anchor.expires_at.as_deref() else {
+            return Ok(None);
+        };
+        NaiveDateTime::parse_from_str(expires_at, NTA_TIMESTAMP_FORMAT)
+            .map(|t| Some(t.and_utc().timestamp()))
+            .map_err(|_| {
+                warn!(
+                    domain = %anchor.domain,
+                    expires_at = %expires_at,
+                    "Skipping negative trust anchor with invalid expiry"
+                );
+            })
+    }
+}
+
+impl NegativeTrustAnchorStore for NegativeTrustAnchorTable {
+    fn replace_anchors(&self, anchors: &[NegativeTrustAnchor]) {
+        let compiled: FxHashMap<Box<str>, Option<i64>> = anchors
+            .iter()
+            .filter_map(|a| {
+                let key = NegativeTrustAnchor::normalize_domain(&a.domain);
+                Self::parse_expiry(a)
+                    .ok()
+                    .map(|expiry| (key.into_boxed_str(), expiry))
+            })
+            .collect();
+
+        info!(anchors = compiled.len(), "Negative trust anchors loaded");
+        self.anchors.store(Arc::new(compiled));
+    }
+}
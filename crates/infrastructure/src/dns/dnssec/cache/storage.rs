@@ -1,6 +1,7 @@
 use super::super::types::{DnskeyRecord, DsRecord};
 use super::super::validation::ValidationResult;
 use super::entries::{DnskeyEntry, DsEntry, ValidationEntry};
+use super::negative_anchors::NegativeTrustAnchorTable;
 use super::stats::{CacheStats, CacheStatsSnapshot};
 use dashmap::DashMap;
 use ferrous_dns_domain::RecordType;
@@ -15,18 +16,33 @@ pub struct DnssecCache {
     ds_records: DashMap<Arc<str>, DsEntry>,
 
     stats: Arc<CacheStats>,
+
+    negative_anchors: Arc<NegativeTrustAnchorTable>,
 }
 
 impl DnssecCache {
     pub fn new() -> Self {
+        Self::with_negative_anchors(Arc::new(NegativeTrustAnchorTable::new()))
+    }
+
+    /// Cache that treats names under `negative_anchors` as Insecure, so
+    /// results cached before an anchor was added are not served.
+    pub fn with_negative_anchors(negative_anchors: Arc<NegativeTrustAnchorTable>) -> Self {
         Self {
             validations: DashMap::new(),
             dnskeys: DashMap::new(),
             ds_records: DashMap::new(),
             stats: Arc::new(CacheStats::default()),
+            negative_anchors,
         }
     }
 
+    /// Whether validation is disabled for `domain` by a negative trust
+    /// anchor (RFC 7646).
+    pub fn is_negative_anchor(&self, domain: &str) -> bool {
+        self.negative_anchors.covers(domain)
+    }
+
     pub fn cache_validation(
         &self,
         domain: &str,
@@ -34,6 +50,10 @@ impl DnssecCache {
         result: ValidationResult,
         ttl_seconds: u32,
     ) {
+        if self.is_negative_anchor(domain) {
+            return;
+        }
+
         let key = (Arc::from(domain), record_type);
         let entry = ValidationEntry::new(result, ttl_seconds);
 
@@ -52,6 +72,10 @@ impl DnssecCache {
         domain: &str,
         record_type: RecordType,
     ) -> Option<ValidationResult> {
+        if self.is_negative_anchor(domain) {
+            return Some(ValidationResult::Insecure);
+        }
+
         let key = (Arc::from(domain), record_type);
 
         if let Some(entry) = self.validations.get(&key) {
@@ -1,3 +1,5 @@
+pub mod anchor_file;
+pub mod anchor_probe;
 pub mod cache;
 pub mod crypto;
 pub mod trust_anchor;
@@ -6,10 +8,12 @@ pub mod validation;
 pub mod validator;
 pub mod validator_pool;
 
-pub use cache::{CacheStatsSnapshot, DnssecCache};
+pub use anchor_file::{load_trust_anchor_file, parse_trust_anchors};
+pub use anchor_probe::DnskeyProbe;
+pub use cache::{CacheStatsSnapshot, DnssecCache, NegativeTrustAnchorTable};
 pub use crypto::SignatureVerifier;
-pub use trust_anchor::{TrustAnchor, TrustAnchorStore};
+pub use trust_anchor::{AnchorKey, TrustAnchor, TrustAnchorStore};
 pub use types::{DnskeyRecord, DsRecord, RrsigRecord};
-pub use validation::{ChainVerifier, ValidationResult};
+pub use validation::{ChainVerifier, DenialKind, ValidationResult, MAX_NSEC3_ITERATIONS};
 pub use validator::{DnssecValidator, ValidatedResponse, ValidatorStats};
 pub use validator_pool::DnssecValidatorPool;
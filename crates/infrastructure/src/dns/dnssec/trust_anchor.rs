@@ -1,11 +1,51 @@
-use super::types::DnskeyRecord;
+use super::types::{DnskeyRecord, DsRecord};
+use arc_swap::ArcSwap;
 use base64::{engine::general_purpose::STANDARD, Engine};
+use ferrous_dns_application::ports::TrustAnchorStorePort;
+use ferrous_dns_domain::{TrustAnchorData, TrustAnchorSource};
+use std::sync::Arc;
+use tracing::info;
+
+/// Key material a chain of trust may start from.
+#[derive(Debug, Clone)]
+pub enum AnchorKey {
+    Dnskey(DnskeyRecord),
+    Ds(DsRecord),
+}
+
+impl AnchorKey {
+    pub fn from_data(data: &TrustAnchorData) -> Self {
+        match data {
+            TrustAnchorData::Dnskey {
+                flags,
+                algorithm,
+                public_key,
+            } => Self::Dnskey(DnskeyRecord {
+                flags: *flags,
+                protocol: 3,
+                algorithm: *algorithm,
+                public_key: public_key.to_vec(),
+            }),
+            TrustAnchorData::Ds {
+                key_tag,
+                algorithm,
+                digest_type,
+                digest,
+            } => Self::Ds(DsRecord {
+                key_tag: *key_tag,
+                algorithm: *algorithm,
+                digest_type: *digest_type,
+                digest: digest.to_vec(),
+            }),
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct TrustAnchor {
     pub domain: String,
 
-    pub dnskey: DnskeyRecord,
+    pub key: AnchorKey,
 
     pub description: String,
 }
@@ -14,39 +54,84 @@ impl TrustAnchor {
     pub fn new(domain: String, dnskey: DnskeyRecord, description: String) -> Self {
         Self {
             domain,
-            dnskey,
+            key: AnchorKey::Dnskey(dnskey),
             description,
         }
     }
 
+    pub fn from_ds(domain: String, ds: DsRecord, description: String) -> Self {
+        Self {
+            domain,
+            key: AnchorKey::Ds(ds),
+            description,
+        }
+    }
+
+    pub fn dnskey(&self) -> Option<&DnskeyRecord> {
+        match &self.key {
+            AnchorKey::Dnskey(dnskey) => Some(dnskey),
+            AnchorKey::Ds(_) => None,
+        }
+    }
+
+    pub fn ds(&self) -> Option<&DsRecord> {
+        match &self.key {
+            AnchorKey::Ds(ds) => Some(ds),
+            AnchorKey::Dnskey(_) => None,
+        }
+    }
+
     pub fn matches(&self, dnskey: &DnskeyRecord) -> bool {
-        if self.dnskey.calculate_key_tag() != dnskey.calculate_key_tag() {
+        let Some(anchor) = self.dnskey() else {
+            return false;
+        };
+
+        if anchor.calculate_key_tag() != dnskey.calculate_key_tag() {
             return false;
         }
 
-        if self.dnskey.algorithm != dnskey.algorithm {
+        if anchor.algorithm != dnskey.algorithm {
             return false;
         }
 
-        self.dnskey.public_key == dnskey.public_key
+        anchor.public_key == dnskey.public_key
+    }
+
+    fn from_entity(anchor: &ferrous_dns_domain::TrustAnchor) -> Self {
+        let description = format!(
+            "{} {} ({})",
+            anchor.source.as_str(),
+            anchor.data.type_str(),
+            anchor.key_tag()
+        );
+        let key = AnchorKey::from_data(&anchor.data);
+        Self {
+            domain: anchor.zone.to_string(),
+            key,
+            description,
+        }
     }
 }
 
+/// Trust anchors shared by every validator. Clones share the same set, so
+/// RFC 5011 updates reach validators that are already running.
 #[derive(Debug, Clone)]
 pub struct TrustAnchorStore {
-    anchors: Vec<TrustAnchor>,
+    anchors: Arc<ArcSwap<Vec<TrustAnchor>>>,
 }
 
 impl TrustAnchorStore {
     pub fn new() -> Self {
-        Self {
-            anchors: Self::default_root_anchors(),
-        }
+        Self::with_anchors(Self::default_root_anchors())
     }
 
     pub fn empty() -> Self {
+        Self::with_anchors(Vec::new())
+    }
+
+    fn with_anchors(anchors: Vec<TrustAnchor>) -> Self {
         Self {
-            anchors: Vec::new(),
+            anchors: Arc::new(ArcSwap::from_pointee(anchors)),
         }
     }
 
@@ -58,6 +143,23 @@ impl TrustAnchorStore {
         )]
     }
 
+    /// The compiled-in root KSK as a managed anchor, seeded into the
+    /// database when no other root anchor is configured.
+    pub fn builtin_root_anchor(now: i64) -> ferrous_dns_domain::TrustAnchor {
+        let key = Self::root_ksk_20326();
+        ferrous_dns_domain::TrustAnchor::new(
+            ".",
+            TrustAnchorData::Dnskey {
+                flags: key.flags,
+                algorithm: key.algorithm,
+                public_key: Arc::from(key.public_key),
+            },
+            TrustAnchorSource::Builtin,
+            true,
+            now,
+        )
+    }
+
     fn root_ksk_20326() -> DnskeyRecord {
         let public_key_b64 = concat!(
             "AwEAAaz/tAm8yTn4Mfeh5eyI96WSVexTBAvkMgJzkKTOiW1vkIbzxeF3",
@@ -81,38 +183,73 @@ impl TrustAnchorStore {
         }
     }
 
-    pub fn add_anchor(&mut self, anchor: TrustAnchor) {
-        self.anchors.push(anchor);
+    pub fn add_anchor(&self, anchor: TrustAnchor) {
+        self.anchors.rcu(|current| {
+            let mut next = Vec::clone(current);
+            next.push(anchor.clone());
+            next
+        });
     }
 
     pub fn is_trusted(&self, dnskey: &DnskeyRecord, domain: &str) -> bool {
-        let normalized_domain = if domain.ends_with('.') {
-            domain.to_string()
-        } else if domain.is_empty() || domain == "." {
-            ".".to_string()
-        } else {
-            format!("{}.", domain)
-        };
+        let normalized_domain = normalize(domain);
 
         self.anchors
+            .load()
             .iter()
             .any(|anchor| anchor.domain == normalized_domain && anchor.matches(dnskey))
     }
 
-    pub fn get_anchor(&self, domain: &str) -> Option<&TrustAnchor> {
-        let normalized_domain = if domain.ends_with('.') {
-            domain.to_string()
-        } else {
-            format!("{}.", domain)
-        };
+    pub fn get_anchor(&self, domain: &str) -> Option<TrustAnchor> {
+        let normalized_domain = normalize(domain);
 
         self.anchors
+            .load()
             .iter()
             .find(|anchor| anchor.domain == normalized_domain)
+            .cloned()
     }
 
-    pub fn get_all_anchors(&self) -> &[TrustAnchor] {
-        &self.anchors
+    pub fn anchors_for(&self, domain: &str) -> Vec<TrustAnchor> {
+        let normalized_domain = normalize(domain);
+
+        self.anchors
+            .load()
+            .iter()
+            .filter(|anchor| anchor.domain == normalized_domain)
+            .cloned()
+            .collect()
+    }
+
+    /// The deepest anchored zone at or above `domain`, where its chain of
+    /// trust starts.
+    pub fn closest_zone(&self, domain: &str) -> Option<String> {
+        let anchors = self.anchors.load();
+        let mut zone = normalize(domain);
+        loop {
+            if anchors.iter().any(|anchor| anchor.domain == zone) {
+                return Some(zone);
+            }
+            if zone == "." {
+                return None;
+            }
+            zone = match zone.split_once('.') {
+                Some((_, "")) | None => ".".to_string(),
+                Some((_, parent)) => parent.to_string(),
+            };
+        }
+    }
+
+    pub fn get_all_anchors(&self) -> Vec<TrustAnchor> {
+        Vec::clone(&self.anchors.load())
+    }
+
+    pub fn len(&self) -> usize {
+        self.anchors.load().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.anchors.load().is_empty()
     }
 }
 
@@ -121,3 +258,20 @@ impl Default for TrustAnchorStore {
         Self::new()
     }
 }
+
+impl TrustAnchorStorePort for TrustAnchorStore {
+    fn replace_anchors(&self, anchors: &[ferrous_dns_domain::TrustAnchor]) {
+        let trusted: Vec<TrustAnchor> = anchors
+            .iter()
+            .filter(|a| a.is_trusted() && !a.data.is_revoked())
+            .map(TrustAnchor::from_entity)
+            .collect();
+
+        info!(anchors = trusted.len(), "DNSSEC trust anchors loaded");
+        self.anchors.store(Arc::new(trusted));
+    }
+}
+
+fn normalize(domain: &str) -> String {
+    ferrous_dns_domain::TrustAnchor::normalize_zone(domain)
+}
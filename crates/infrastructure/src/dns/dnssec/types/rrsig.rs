@@ -1,4 +1,6 @@
+use crate::dns::forwarding::record_type_map::RecordTypeMapper;
 use ferrous_dns_domain::{DomainError, RecordType};
+use hickory_proto::dnssec::rdata::RRSIG;
 use std::fmt;
 
 #[derive(Debug, Clone)]
@@ -15,6 +17,23 @@ pub struct RrsigRecord {
 }
 
 impl RrsigRecord {
+    /// Converts a parsed hickory RRSIG, or `None` when it covers a type
+    /// this crate does not model.
+    pub fn from_hickory(rrsig: &RRSIG) -> Option<Self> {
+        let input = rrsig.input();
+        Some(Self {
+            type_covered: RecordTypeMapper::from_hickory(input.type_covered)?,
+            algorithm: u8::from(input.algorithm),
+            labels: input.num_labels,
+            original_ttl: input.original_ttl,
+            signature_expiration: input.sig_expiration.get(),
+            signature_inception: input.sig_inception.get(),
+            key_tag: input.key_tag,
+            signer_name: input.signer_name.to_string(),
+            signature: rrsig.sig().to_vec(),
+        })
+    }
+
     pub fn parse(data: &[u8], full_data: &[u8]) -> Result<Self, DomainError> {
         if data.len() < 18 {
             return Err(DomainError::InvalidDnsResponse(
@@ -2,9 +2,8 @@ use crate::dns::dnssec::cache::DnssecCache;
 use crate::dns::dnssec::crypto::SignatureVerifier;
 use crate::dns::dnssec::trust_anchor::TrustAnchorStore;
 use crate::dns::dnssec::types::{DnskeyRecord, DsRecord, RrsigRecord};
-use crate::dns::forwarding::record_type_map::RecordTypeMapper;
 use crate::dns::load_balancer::PoolManager;
-use ferrous_dns_domain::{DomainError, RecordType};
+use ferrous_dns_domain::{DomainError, QueryScope, RecordType};
 use hickory_proto::dnssec::rdata::DNSSECRData;
 use hickory_proto::dnssec::PublicKey;
 use hickory_proto::rr::{RData, Record};
@@ -76,27 +75,32 @@ impl ChainVerifier {
             "Starting DNSSEC chain verification"
         );
 
-        if self.trust_store.get_anchor(".").is_none() {
-            warn!("No root trust anchor configured");
-            return Ok(ValidationResult::Indeterminate);
+        if self.dnssec_cache.is_negative_anchor(domain) {
+            debug!(domain = %domain, "Negative trust anchor covers zone, skipping validation");
+            return Ok(ValidationResult::Insecure);
         }
 
+        let Some(anchor_zone) = self.trust_store.closest_zone(domain) else {
+            warn!(domain = %domain, "No trust anchor covers domain");
+            return Ok(ValidationResult::Indeterminate);
+        };
+
         let labels = Self::split_domain(domain);
-        debug!(labels = ?labels, "Domain labels");
+        debug!(labels = ?labels, anchor = %anchor_zone, "Domain labels");
 
-        let root_anchor = match self.trust_store.get_anchor(".") {
-            Some(anchor) => anchor,
-            None => {
-                warn!("Root trust anchor disappeared during validation");
-                return Ok(ValidationResult::Indeterminate);
-            }
-        };
-        self.validated_keys
-            .insert(".".to_string(), Arc::from(vec![root_anchor.dnskey.clone()]));
+        if let Err(e) = self.seed_anchor(&anchor_zone).await {
+            warn!(
+                zone = %anchor_zone,
+                error = %e,
+                "Trust anchor does not match the zone's DNSKEY RRset"
+            );
+            return Ok(ValidationResult::Bogus);
+        }
 
-        let mut current_domain = String::from(".");
+        let anchor_depth = Self::split_domain(&anchor_zone).len();
+        let mut current_domain = anchor_zone;
 
-        for label in &labels {
+        for label in labels.iter().skip(anchor_depth) {
             let child_domain = if current_domain == "." {
                 format!("{}.", label)
             } else {
@@ -163,8 +167,33 @@ impl ChainVerifier {
             return Err(DomainError::InsecureDelegation);
         }
 
-        let dnskey_result = dnskey_result?;
+        self.authenticate_dnskeys(child_domain, &ds_records, dnskey_result?)
+    }
+
+    /// Starts the chain at an anchored zone. DNSKEY anchors are trusted as
+    /// they are; DS anchors authenticate the zone's own DNSKEY RRset.
+    async fn seed_anchor(&mut self, zone: &str) -> Result<(), DomainError> {
+        let anchors = self.trust_store.anchors_for(zone);
+
+        let keys: Vec<DnskeyRecord> = anchors.iter().filter_map(|a| a.dnskey().cloned()).collect();
+        if !keys.is_empty() {
+            self.validated_keys
+                .insert(zone.to_string(), Arc::from(keys));
+            return Ok(());
+        }
+
+        let ds_records: Vec<DsRecord> = anchors.iter().filter_map(|a| a.ds().cloned()).collect();
+        let dnskey_result =
+            Self::fetch_dnskey(&self.dnssec_cache, &self.pool_manager, zone).await?;
+        self.authenticate_dnskeys(zone, &ds_records, dnskey_result)
+    }
 
+    fn authenticate_dnskeys(
+        &mut self,
+        child_domain: &str,
+        ds_records: &[DsRecord],
+        dnskey_result: DnskeyQueryResult,
+    ) -> Result<(), DomainError> {
         if dnskey_result.keys.is_empty() {
             warn!(domain = %child_domain, "No DNSKEY records found");
             return Err(DomainError::InvalidDnsResponse(
@@ -286,7 +315,9 @@ impl ChainVerifier {
         debug!(domain = %domain, "DS cache miss, querying DNS");
 
         let domain_arc: Arc<str> = Arc::from(domain);
-        let result = pool.query(&domain_arc, &RecordType::DS, 5000, true).await;
+        let result = pool
+            .query(&domain_arc, &RecordType::DS, 5000, true, QueryScope::SHARED)
+            .await;
 
         match result {
             Ok(upstream_result) => {
@@ -343,7 +374,13 @@ impl ChainVerifier {
 
         let domain_arc: Arc<str> = Arc::from(domain);
         let result = pool
-            .query(&domain_arc, &RecordType::DNSKEY, 5000, true)
+            .query(
+                &domain_arc,
+                &RecordType::DNSKEY,
+                5000,
+                true,
+                QueryScope::SHARED,
+            )
             .await;
 
         match result {
@@ -369,22 +406,9 @@ impl ChainVerifier {
                             if input.type_covered != hickory_proto::rr::RecordType::DNSKEY {
                                 continue;
                             }
-                            let Some(type_covered) =
-                                RecordTypeMapper::from_hickory(input.type_covered)
-                            else {
-                                continue;
-                            };
-                            rrsigs.push(RrsigRecord {
-                                type_covered,
-                                algorithm: u8::from(input.algorithm),
-                                labels: input.num_labels,
-                                original_ttl: input.original_ttl,
-                                signature_expiration: input.sig_expiration.get(),
-                                signature_inception: input.sig_inception.get(),
-                                key_tag: input.key_tag,
-                                signer_name: input.signer_name.to_string(),
-                                signature: rrsig.sig().to_vec(),
-                            });
+                            if let Some(record) = RrsigRecord::from_hickory(rrsig) {
+                                rrsigs.push(record);
+                            }
                         }
                         _ => {}
                     }
@@ -414,6 +438,14 @@ impl ChainVerifier {
         }
     }
 
+    pub fn is_negative_anchor(&self, domain: &str) -> bool {
+        self.dnssec_cache.is_negative_anchor(domain)
+    }
+
+    pub fn trust_anchors_count(&self) -> usize {
+        self.trust_store.len()
+    }
+
     pub fn get_zone_keys(&self, zone: &str) -> Option<&Arc<[DnskeyRecord]>> {
         self.validated_keys.get(zone)
     }
@@ -0,0 +1,491 @@
+use super::chain::ValidationResult;
+use hickory_proto::dnssec::rdata::{DNSSECRData, NSEC, NSEC3};
+use hickory_proto::rr::{Name, RData, Record, RecordType};
+use std::cmp::Ordering;
+use tracing::debug;
+
+/// NSEC3 iteration counts above this are treated as insecure (RFC 9276 §3.2).
+pub const MAX_NSEC3_ITERATIONS: u16 = 150;
+
+/// DNAME (RFC 6672) has no dedicated variant in hickory's `RecordType`.
+const DNAME: RecordType = RecordType::Unknown(39);
+
+/// The kind of negative answer being proven.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DenialKind {
+    /// The name does not exist (RCODE NXDOMAIN).
+    NxDomain,
+    /// The name exists but has no records of the queried type.
+    NoData,
+}
+
+/// Checks that the NSEC or NSEC3 records in `records` prove the negative
+/// answer for `qname`/`qtype` inside `zone`.
+///
+/// Signatures are not checked here: the caller must have verified every
+/// NSEC/NSEC3 RRset first. Returns `Secure` when the proof holds,
+/// `Insecure` when it rests on an opt-out span or an iteration count above
+/// [`MAX_NSEC3_ITERATIONS`], and `Bogus` otherwise.
+pub fn verify_denial(
+    qname: &Name,
+    qtype: RecordType,
+    kind: DenialKind,
+    zone: &Name,
+    records: &[Record],
+) -> ValidationResult {
+    let nsec3 = Nsec3Set::collect(zone, records);
+    if !nsec3.is_empty() {
+        return nsec3.prove_denial(qname, qtype, kind);
+    }
+    let nsec = collect_nsec(records);
+    if !nsec.is_empty() {
+        return prove_nsec_denial(&nsec, qname, qtype, kind);
+    }
+    debug!(qname = %qname, "Negative answer carries no NSEC or NSEC3 records");
+    ValidationResult::Bogus
+}
+
+/// Checks that a positive answer synthesised from a wildcard is legitimate:
+/// no name closer to `qname` than the wildcard's parent `encloser` exists
+/// (RFC 4035 §5.3.4, RFC 5155 §8.8).
+pub fn verify_wildcard_expansion(
+    qname: &Name,
+    encloser: &Name,
+    zone: &Name,
+    records: &[Record],
+) -> ValidationResult {
+    let nsec3 = Nsec3Set::collect(zone, records);
+    if !nsec3.is_empty() {
+        return nsec3.prove_wildcard_expansion(qname, encloser);
+    }
+    let nsec = collect_nsec(records);
+    if nsec.iter().any(|n| n.covers(qname)) {
+        return ValidationResult::Secure;
+    }
+    debug!(qname = %qname, "Wildcard answer without proof that the name does not exist");
+    ValidationResult::Bogus
+}
+
+/// Narrows `records` to the NSEC/NSEC3 records that can take part in a
+/// denial proof for `qname`: those matching or covering `qname`, one of its
+/// ancestors inside `zone`, or the wildcard directly below such an ancestor.
+pub fn relevant_records<'r>(qname: &Name, zone: &Name, records: &'r [Record]) -> Vec<&'r Record> {
+    let candidates = proof_names(qname, zone);
+    let nsec3 = Nsec3Set::collect(zone, records);
+    let hashes: Vec<Vec<u8>> = candidates.iter().filter_map(|c| nsec3.hash(c)).collect();
+    let mut relevant: Vec<&Record> = nsec3
+        .entries
+        .iter()
+        .filter(|e| hashes.iter().any(|h| e.owner_hash == *h || e.covers(h)))
+        .map(|e| e.record)
+        .collect();
+    relevant.extend(
+        collect_nsec(records)
+            .into_iter()
+            .filter(|n| candidates.iter().any(|c| n.owner == c || n.covers(c)))
+            .map(|n| n.record),
+    );
+    relevant
+}
+
+/// The names a denial proof for `qname` may need a matching or covering
+/// record for: `qname` and its ancestors inside `zone`, each with the
+/// wildcard directly below it.
+pub fn proof_names(qname: &Name, zone: &Name) -> Vec<Name> {
+    let mut names = Vec::new();
+    let mut labels = usize::from(qname.num_labels());
+    while labels >= usize::from(zone.num_labels()) {
+        let ancestor = qname.trim_to(labels);
+        names.extend(wildcard_of(&ancestor));
+        names.push(ancestor);
+        if labels == 0 {
+            break;
+        }
+        labels -= 1;
+    }
+    names
+}
+
+/// The hash an NSEC3 owner name carries in its first label.
+pub fn nsec3_owner_hash(owner: &Name) -> Option<Vec<u8>> {
+    owner.iter().next().and_then(decode_base32hex)
+}
+
+/// Hashes `name` with the algorithm, salt and iterations of `nsec3`.
+pub fn nsec3_hash(nsec3: &NSEC3, name: &Name) -> Option<Vec<u8>> {
+    nsec3
+        .hash_algorithm()
+        .hash(nsec3.salt(), name, nsec3.iterations())
+        .ok()
+        .map(|digest| digest.as_ref().to_vec())
+}
+
+/// The signer of the first NSEC/NSEC3 RRSIG in an authority section: the
+/// zone the denial proof belongs to.
+pub fn denial_signer(authority: &[Record]) -> Option<Name> {
+    authority.iter().find_map(|record| match record.data() {
+        RData::DNSSEC(DNSSECRData::RRSIG(rrsig))
+            if matches!(
+                rrsig.input().type_covered,
+                RecordType::NSEC | RecordType::NSEC3
+            ) =>
+        {
+            Some(rrsig.input().signer_name.clone())
+        }
+        _ => None,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// NSEC (RFC 4035 §5.4, RFC 6840 §4)
+// ---------------------------------------------------------------------------
+
+struct NsecEntry<'a> {
+    record: &'a Record,
+    owner: &'a Name,
+    nsec: &'a NSEC,
+}
+
+impl NsecEntry<'_> {
+    fn has(&self, rtype: RecordType) -> bool {
+        self.nsec.type_bit_maps().any(|t| t == rtype)
+    }
+
+    /// True when `name` sorts strictly between the owner and the next name,
+    /// including the wrap-around span of the last NSEC in the zone.
+    fn covers(&self, name: &Name) -> bool {
+        let next = self.nsec.next_domain_name();
+        match self.owner.cmp(next) {
+            Ordering::Less => self.owner < name && name < next,
+            _ => self.owner < name || name < next,
+        }
+    }
+
+    /// Delegation and DNAME NSECs say nothing about names below them.
+    fn is_cut_above(&self, name: &Name) -> bool {
+        self.owner != name
+            && self.owner.zone_of(name)
+            && (self.has(DNAME) || (self.has(RecordType::NS) && !self.has(RecordType::SOA)))
+    }
+}
+
+fn collect_nsec(records: &[Record]) -> Vec<NsecEntry<'_>> {
+    records
+        .iter()
+        .filter_map(|r| match r.data() {
+            RData::DNSSEC(DNSSECRData::NSEC(nsec)) => Some(NsecEntry {
+                record: r,
+                owner: r.name(),
+                nsec,
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+fn prove_nsec_denial(
+    nsec: &[NsecEntry<'_>],
+    qname: &Name,
+    qtype: RecordType,
+    kind: DenialKind,
+) -> ValidationResult {
+    if let Some(matching) = nsec.iter().find(|n| n.owner == qname) {
+        if kind == DenialKind::NxDomain {
+            debug!(qname = %qname, "NSEC proves the NXDOMAIN name exists");
+            return ValidationResult::Bogus;
+        }
+        return nodata_bitmap_result(qtype, |t| matching.has(t));
+    }
+
+    let Some(covering) = nsec
+        .iter()
+        .find(|n| n.covers(qname) && !n.is_cut_above(qname))
+    else {
+        debug!(qname = %qname, "No NSEC covers the query name");
+        return ValidationResult::Bogus;
+    };
+
+    // An empty non-terminal has no NSEC of its own, but the next name in
+    // the zone lies below it (RFC 4035 §3.1.3.2).
+    if kind == DenialKind::NoData && qname.zone_of(covering.nsec.next_domain_name()) {
+        return ValidationResult::Secure;
+    }
+
+    let encloser = nsec_closest_encloser(qname, covering);
+    let Some(wildcard) = wildcard_of(&encloser) else {
+        return ValidationResult::Bogus;
+    };
+
+    match kind {
+        DenialKind::NxDomain => {
+            if nsec.iter().any(|n| n.covers(&wildcard)) {
+                ValidationResult::Secure
+            } else {
+                debug!(qname = %qname, wildcard = %wildcard, "No NSEC denies the wildcard");
+                ValidationResult::Bogus
+            }
+        }
+        DenialKind::NoData => match nsec.iter().find(|n| *n.owner == wildcard) {
+            Some(matching) => nodata_bitmap_result(qtype, |t| matching.has(t)),
+            None => ValidationResult::Bogus,
+        },
+    }
+}
+
+/// The deepest ancestor of `qname` that the covering NSEC shows to exist:
+/// the longer of its common suffixes with the NSEC owner and next name.
+fn nsec_closest_encloser(qname: &Name, covering: &NsecEntry<'_>) -> Name {
+    let with_owner = common_suffix_labels(qname, covering.owner);
+    let with_next = common_suffix_labels(qname, covering.nsec.next_domain_name());
+    qname.trim_to(with_owner.max(with_next))
+}
+
+// ---------------------------------------------------------------------------
+// NSEC3 (RFC 5155 §8)
+// ---------------------------------------------------------------------------
+
+struct Nsec3Entry<'a> {
+    record: &'a Record,
+    owner_hash: Vec<u8>,
+    nsec3: &'a NSEC3,
+}
+
+impl Nsec3Entry<'_> {
+    fn has(&self, rtype: RecordType) -> bool {
+        self.nsec3.type_bit_maps().any(|t| t == rtype)
+    }
+
+    fn covers(&self, hash: &[u8]) -> bool {
+        let owner = self.owner_hash.as_slice();
+        let next = self.nsec3.next_hashed_owner_name();
+        match owner.cmp(next) {
+            Ordering::Less => owner < hash && hash < next,
+            _ => owner < hash || hash < next,
+        }
+    }
+}
+
+/// The closest-encloser proof of RFC 5155 §8.3.
+struct ClosestEncloser<'s, 'a> {
+    encloser: Name,
+    /// The NSEC3 covering the next closer name.
+    next_closer: &'s Nsec3Entry<'a>,
+}
+
+struct Nsec3Set<'a> {
+    entries: Vec<Nsec3Entry<'a>>,
+    iterations: u16,
+    salt: &'a [u8],
+    zone: Name,
+}
+
+impl<'a> Nsec3Set<'a> {
+    /// Collects the NSEC3 records owned directly below `zone` that share the
+    /// parameters of the first one (RFC 5155 §8.2).
+    fn collect(zone: &Name, records: &'a [Record]) -> Self {
+        let mut set = Self {
+            entries: Vec::new(),
+            iterations: 0,
+            salt: &[],
+            zone: zone.clone(),
+        };
+        for record in records {
+            let RData::DNSSEC(DNSSECRData::NSEC3(nsec3)) = record.data() else {
+                continue;
+            };
+            let owner = record.name();
+            if owner.num_labels() != zone.num_labels() + 1 || !zone.zone_of(owner) {
+                continue;
+            }
+            let Some(owner_hash) = nsec3_owner_hash(owner) else {
+                continue;
+            };
+            if set.entries.is_empty() {
+                set.iterations = nsec3.iterations();
+                set.salt = nsec3.salt();
+            } else if nsec3.iterations() != set.iterations || nsec3.salt() != set.salt {
+                continue;
+            }
+            set.entries.push(Nsec3Entry {
+                record,
+                owner_hash,
+                nsec3,
+            });
+        }
+        set
+    }
+
+    fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn hash(&self, name: &Name) -> Option<Vec<u8>> {
+        nsec3_hash(self.entries.first()?.nsec3, name)
+    }
+
+    fn matching(&self, name: &Name) -> Option<&Nsec3Entry<'a>> {
+        let hash = self.hash(name)?;
+        self.entries.iter().find(|e| e.owner_hash == hash)
+    }
+
+    fn covering(&self, name: &Name) -> Option<&Nsec3Entry<'a>> {
+        let hash = self.hash(name)?;
+        self.entries.iter().find(|e| e.covers(&hash))
+    }
+
+    /// Finds the closest provable encloser of `qname` and the NSEC3 that
+    /// covers the next closer name.
+    fn closest_encloser(&self, qname: &Name) -> Option<ClosestEncloser<'_, 'a>> {
+        let zone_labels = self.zone.num_labels();
+        let mut labels = qname.num_labels();
+        while labels > zone_labels {
+            let encloser = qname.trim_to(usize::from(labels - 1));
+            if let Some(matching) = self.matching(&encloser) {
+                // A delegation or DNAME at the encloser hides everything below.
+                if matching.has(DNAME)
+                    || (matching.has(RecordType::NS) && !matching.has(RecordType::SOA))
+                {
+                    return None;
+                }
+                let next_closer = qname.trim_to(usize::from(labels));
+                return self
+                    .covering(&next_closer)
+                    .map(|next_closer| ClosestEncloser {
+                        encloser,
+                        next_closer,
+                    });
+            }
+            labels -= 1;
+        }
+        None
+    }
+
+    fn prove_denial(&self, qname: &Name, qtype: RecordType, kind: DenialKind) -> ValidationResult {
+        if self.iterations > MAX_NSEC3_ITERATIONS {
+            debug!(
+                iterations = self.iterations,
+                "NSEC3 iteration count above limit, treating as insecure"
+            );
+            return ValidationResult::Insecure;
+        }
+
+        if let Some(matching) = self.matching(qname) {
+            if kind == DenialKind::NxDomain {
+                debug!(qname = %qname, "NSEC3 proves the NXDOMAIN name exists");
+                return ValidationResult::Bogus;
+            }
+            return nodata_bitmap_result(qtype, |t| matching.has(t));
+        }
+
+        let Some(proof) = self.closest_encloser(qname) else {
+            debug!(qname = %qname, "No NSEC3 closest encloser proof");
+            return ValidationResult::Bogus;
+        };
+        let Some(wildcard) = wildcard_of(&proof.encloser) else {
+            return ValidationResult::Bogus;
+        };
+
+        match kind {
+            DenialKind::NxDomain => {
+                if self.covering(&wildcard).is_none() {
+                    debug!(qname = %qname, wildcard = %wildcard, "No NSEC3 denies the wildcard");
+                    return ValidationResult::Bogus;
+                }
+                Self::opt_out_result(proof.next_closer)
+            }
+            DenialKind::NoData => {
+                if let Some(matching) = self.matching(&wildcard) {
+                    return nodata_bitmap_result(qtype, |t| matching.has(t));
+                }
+                // A DS query for an unsigned delegation inside an opt-out
+                // span has no NSEC3 of its own (RFC 5155 §8.6).
+                if qtype == RecordType::DS && proof.next_closer.nsec3.opt_out() {
+                    return ValidationResult::Insecure;
+                }
+                ValidationResult::Bogus
+            }
+        }
+    }
+
+    fn prove_wildcard_expansion(&self, qname: &Name, encloser: &Name) -> ValidationResult {
+        if self.iterations > MAX_NSEC3_ITERATIONS {
+            return ValidationResult::Insecure;
+        }
+        if encloser.num_labels() >= qname.num_labels() {
+            return ValidationResult::Bogus;
+        }
+        let next_closer = qname.trim_to(usize::from(encloser.num_labels()) + 1);
+        match self.covering(&next_closer) {
+            Some(entry) => Self::opt_out_result(entry),
+            None => ValidationResult::Bogus,
+        }
+    }
+
+    /// Names inside an opt-out span may be unsigned delegations, so the
+    /// proof cannot rule them out (RFC 5155 §9.2).
+    fn opt_out_result(entry: &Nsec3Entry<'_>) -> ValidationResult {
+        if entry.nsec3.opt_out() {
+            ValidationResult::Insecure
+        } else {
+            ValidationResult::Secure
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// NODATA holds when neither the queried type nor a CNAME exists at the
+/// name. DS answers come from the parent side of a cut and every other
+/// type from the child side, so the NS/SOA bits must agree with `qtype`.
+fn nodata_bitmap_result(qtype: RecordType, has: impl Fn(RecordType) -> bool) -> ValidationResult {
+    if has(qtype) || (qtype != RecordType::CNAME && has(RecordType::CNAME)) {
+        return ValidationResult::Bogus;
+    }
+    let parent_side = has(RecordType::NS) && !has(RecordType::SOA);
+    if qtype == RecordType::DS {
+        if has(RecordType::SOA) {
+            return ValidationResult::Bogus;
+        }
+    } else if parent_side {
+        return ValidationResult::Bogus;
+    }
+    ValidationResult::Secure
+}
+
+fn wildcard_of(encloser: &Name) -> Option<Name> {
+    encloser.prepend_label("*").ok()
+}
+
+fn common_suffix_labels(a: &Name, b: &Name) -> usize {
+    a.iter()
+        .rev()
+        .zip(b.iter().rev())
+        .take_while(|(x, y)| x.eq_ignore_ascii_case(y))
+        .count()
+}
+
+/// Decodes the base32hex owner label of an NSEC3 record (RFC 4648 §7,
+/// case-insensitive, no padding).
+fn decode_base32hex(label: &[u8]) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(label.len() * 5 / 8);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    for &c in label {
+        let value = match c {
+            b'0'..=b'9' => c - b'0',
+            b'a'..=b'v' => c - b'a' + 10,
+            b'A'..=b'V' => c - b'A' + 10,
+            _ => return None,
+        };
+        buffer = (buffer << 5) | u32::from(value);
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    Some(out)
+}
@@ -1,3 +1,5 @@
 pub mod chain;
+pub mod denial;
 
 pub use chain::{ChainVerifier, ValidationResult};
+pub use denial::{DenialKind, MAX_NSEC3_ITERATIONS};
@@ -2,12 +2,15 @@ use super::cache::DnssecCache;
 use super::crypto::SignatureVerifier;
 use super::trust_anchor::TrustAnchorStore;
 use super::types::RrsigRecord;
+use super::validation::denial::{self, DenialKind};
 use super::validation::{ChainVerifier, ValidationResult};
 use crate::dns::forwarding::record_type_map::RecordTypeMapper;
 use crate::dns::load_balancer::PoolManager;
-use ferrous_dns_domain::{DomainError, RecordType};
+use ferrous_dns_domain::{DomainError, QueryScope, RecordType};
 use hickory_proto::dnssec::rdata::DNSSECRData;
-use hickory_proto::rr::{RData, Record};
+use hickory_proto::op::{Message, ResponseCode};
+use hickory_proto::rr::{Name, RData, Record, RecordType as HickoryRecordType};
+use std::str::FromStr;
 use std::sync::Arc;
 use tracing::{debug, warn};
 
@@ -134,7 +137,13 @@ impl DnssecValidator {
         let domain_arc: Arc<str> = Arc::from(domain);
         let upstream_result = self
             .pool_manager
-            .query(&domain_arc, &record_type, self.timeout_ms, true)
+            .query(
+                &domain_arc,
+                &record_type,
+                self.timeout_ms,
+                true,
+                QueryScope::SHARED,
+            )
             .await?;
 
         debug!(
@@ -144,19 +153,10 @@ impl DnssecValidator {
             "DNS query completed"
         );
 
-        let chain_domain = Self::extract_signer_zone(upstream_result.response.message.answers())
-            .unwrap_or_else(|| domain.to_owned());
-
-        let mut validation_status = self
-            .chain_verifier
-            .verify_chain(&chain_domain, record_type)
+        let validation_status = self
+            .validate_message(domain, record_type, &upstream_result.response.message)
             .await?;
 
-        if validation_status == ValidationResult::Secure {
-            let all_answers: Vec<Record> = upstream_result.response.message.answers().to_vec();
-            validation_status = self.verify_rrset_signatures(domain, &all_answers);
-        }
-
         let elapsed = start.elapsed().as_millis() as u64;
 
         debug!(
@@ -187,7 +187,7 @@ impl DnssecValidator {
         &mut self,
         domain: &str,
         record_type: RecordType,
-        message: &hickory_proto::op::Message,
+        message: &Message,
     ) -> Result<ValidatedResponse, DomainError> {
         debug!(
             domain = %domain,
@@ -197,18 +197,7 @@ impl DnssecValidator {
 
         let start = std::time::Instant::now();
 
-        let chain_domain =
-            Self::extract_signer_zone(message.answers()).unwrap_or_else(|| domain.to_owned());
-
-        let mut validation_status = self
-            .chain_verifier
-            .verify_chain(&chain_domain, record_type)
-            .await?;
-
-        if validation_status == ValidationResult::Secure {
-            let all_answers: Vec<Record> = message.answers().to_vec();
-            validation_status = self.verify_rrset_signatures(domain, &all_answers);
-        }
+        let validation_status = self.validate_message(domain, record_type, message).await?;
 
         let elapsed = start.elapsed().as_millis() as u64;
 
@@ -229,6 +218,195 @@ impl DnssecValidator {
         })
     }
 
+    async fn validate_message(
+        &mut self,
+        domain: &str,
+        record_type: RecordType,
+        message: &Message,
+    ) -> Result<ValidationResult, DomainError> {
+        let hickory_type = RecordTypeMapper::to_hickory(&record_type);
+        let (target, has_data) = Self::answer_target(domain, hickory_type, message.answers());
+
+        // RFC 7646: answers for names under a negative trust anchor are
+        // treated as if the zone were unsigned.
+        if self.chain_verifier.is_negative_anchor(domain)
+            || self.chain_verifier.is_negative_anchor(&target.to_string())
+        {
+            debug!(domain = %domain, "Negative trust anchor applies, answer is insecure");
+            return Ok(ValidationResult::Insecure);
+        }
+
+        if message.response_code() != ResponseCode::NXDomain && has_data {
+            let chain_domain =
+                Self::extract_signer_zone(message.answers()).unwrap_or_else(|| domain.to_owned());
+            let mut status = self
+                .chain_verifier
+                .verify_chain(&chain_domain, record_type)
+                .await?;
+            if status == ValidationResult::Secure {
+                status = self.verify_rrset_signatures(domain, message.answers());
+            }
+            if status == ValidationResult::Secure {
+                status = self.verify_wildcard_answers(message);
+            }
+            return Ok(status);
+        }
+
+        // Negative answer: the proof lives in the zone that signed the
+        // authority section. Falling back to the SOA owner keeps a stripped
+        // signature from turning a signed zone's denial into "insecure".
+        let denial_zone = Self::extract_signer_zone(message.name_servers())
+            .or_else(|| Self::soa_owner(message.name_servers()))
+            .unwrap_or_else(|| target.to_string());
+        let mut status = self
+            .chain_verifier
+            .verify_chain(&denial_zone, record_type)
+            .await?;
+
+        // A CNAME chain that ends in a negative answer is signed by
+        // whichever zones the aliases live in.
+        if status == ValidationResult::Secure && !message.answers().is_empty() {
+            if let Some(answer_zone) = Self::extract_signer_zone(message.answers()) {
+                status = self
+                    .chain_verifier
+                    .verify_chain(&answer_zone, record_type)
+                    .await?;
+            }
+            if status == ValidationResult::Secure {
+                status = self.verify_rrset_signatures(domain, message.answers());
+            }
+        }
+
+        if status == ValidationResult::Secure {
+            status = self.verify_denial(&target.to_string(), record_type, message);
+        }
+        Ok(status)
+    }
+
+    /// Validates the NSEC/NSEC3 proof in the authority section of a
+    /// negative response for `domain`, the last name of any CNAME chain.
+    ///
+    /// The keys of the signing zone must already be validated, either by
+    /// [`verify_chain`](ChainVerifier::verify_chain) or in tests by
+    /// [`insert_zone_keys_for_test`](Self::insert_zone_keys_for_test).
+    pub fn verify_denial(
+        &self,
+        domain: &str,
+        record_type: RecordType,
+        message: &Message,
+    ) -> ValidationResult {
+        let Ok(qname) = Name::from_str(&Self::fqdn(domain)) else {
+            return ValidationResult::Bogus;
+        };
+        let kind = if message.response_code() == ResponseCode::NXDomain {
+            DenialKind::NxDomain
+        } else {
+            DenialKind::NoData
+        };
+        let authority = message.name_servers();
+
+        let Some(zone) = denial::denial_signer(authority) else {
+            debug!(domain = %domain, "Negative answer has no signed NSEC/NSEC3 records");
+            return ValidationResult::Bogus;
+        };
+        if !zone.zone_of(&qname) {
+            warn!(domain = %domain, zone = %zone, "Denial signed by an unrelated zone");
+            return ValidationResult::Bogus;
+        }
+        if !self.authority_signatures_valid(&zone, authority) {
+            warn!(domain = %domain, zone = %zone, "Denial RRSIG verification failed");
+            return ValidationResult::Bogus;
+        }
+
+        let hickory_type = RecordTypeMapper::to_hickory(&record_type);
+        let status = denial::verify_denial(&qname, hickory_type, kind, &zone, authority);
+        debug!(
+            domain = %domain,
+            kind = ?kind,
+            status = %status.as_str(),
+            "Authenticated denial of existence checked"
+        );
+        status
+    }
+
+    /// Answers expanded from a wildcard carry an RRSIG with fewer labels
+    /// than the owner name; they need proof that the name itself does not
+    /// exist.
+    fn verify_wildcard_answers(&self, message: &Message) -> ValidationResult {
+        let expanded = message
+            .answers()
+            .iter()
+            .find_map(|record| match record.data() {
+                RData::DNSSEC(DNSSECRData::RRSIG(rrsig))
+                    if rrsig.input().num_labels < record.name().num_labels() =>
+                {
+                    Some((record.name(), rrsig.input()))
+                }
+                _ => None,
+            });
+        let Some((owner, input)) = expanded else {
+            return ValidationResult::Secure;
+        };
+
+        let zone = &input.signer_name;
+        let authority = message.name_servers();
+        if !self.authority_signatures_valid(zone, authority) {
+            warn!(domain = %owner, "Wildcard proof RRSIG verification failed");
+            return ValidationResult::Bogus;
+        }
+        let encloser = owner.trim_to(usize::from(input.num_labels));
+        denial::verify_wildcard_expansion(owner, &encloser, zone, authority)
+    }
+
+    /// Every NSEC and NSEC3 RRset in `authority` must carry a valid RRSIG
+    /// made with the validated keys of `zone`.
+    fn authority_signatures_valid(&self, zone: &Name, authority: &[Record]) -> bool {
+        let Some(zone_keys) = self.chain_verifier.get_zone_keys(&zone.to_string()) else {
+            debug!(zone = %zone, "No trusted keys for denial zone");
+            return false;
+        };
+        let now_secs = Self::now_secs();
+
+        let mut checked: Vec<(&Name, HickoryRecordType)> = Vec::new();
+        for record in authority {
+            let rtype = record.record_type();
+            if !matches!(rtype, HickoryRecordType::NSEC | HickoryRecordType::NSEC3) {
+                continue;
+            }
+            let owner = record.name();
+            if checked.contains(&(owner, rtype)) {
+                continue;
+            }
+            checked.push((owner, rtype));
+
+            let owner_str = owner.to_string();
+            let verified = authority
+                .iter()
+                .filter(|r| r.name() == owner)
+                .filter_map(|r| match r.data() {
+                    RData::DNSSEC(DNSSECRData::RRSIG(rrsig))
+                        if rrsig.input().type_covered == rtype
+                            && rrsig.input().signer_name == *zone =>
+                    {
+                        RrsigRecord::from_hickory(rrsig)
+                    }
+                    _ => None,
+                })
+                .any(|rrsig| {
+                    zone_keys.iter().any(|key| {
+                        SignatureVerifier
+                            .verify_rrsig(&rrsig, key, &owner_str, authority, now_secs)
+                            .unwrap_or(false)
+                    })
+                });
+            if !verified {
+                debug!(owner = %owner, record_type = %rtype, "Unsigned or forged denial RRset");
+                return false;
+            }
+        }
+        true
+    }
+
     pub async fn validate_simple(
         &mut self,
         domain: &str,
@@ -244,7 +422,13 @@ impl DnssecValidator {
         let domain_arc: Arc<str> = Arc::from(domain);
         let result = self
             .pool_manager
-            .query(&domain_arc, &RecordType::DS, self.timeout_ms, true)
+            .query(
+                &domain_arc,
+                &RecordType::DS,
+                self.timeout_ms,
+                true,
+                QueryScope::SHARED,
+            )
             .await;
 
         match result {
@@ -270,7 +454,7 @@ impl DnssecValidator {
     pub fn stats(&self) -> ValidatorStats {
         ValidatorStats {
             timeout_ms: self.timeout_ms,
-            trust_anchors_count: 1,
+            trust_anchors_count: self.chain_verifier.trust_anchors_count(),
         }
     }
 
@@ -278,7 +462,7 @@ impl DnssecValidator {
         for record in answers {
             if let RData::DNSSEC(DNSSECRData::RRSIG(rrsig)) = record.data() {
                 let input = rrsig.input();
-                if input.type_covered != hickory_proto::rr::RecordType::DNSKEY {
+                if input.type_covered != HickoryRecordType::DNSKEY {
                     return Some(input.signer_name.to_string());
                 }
             }
@@ -286,6 +470,55 @@ impl DnssecValidator {
         None
     }
 
+    fn soa_owner(authority: &[Record]) -> Option<String> {
+        authority
+            .iter()
+            .find(|r| r.record_type() == HickoryRecordType::SOA)
+            .map(|r| r.name().to_string())
+    }
+
+    /// Follows the CNAME chain in `answers` from `domain` and reports its
+    /// final name and whether that name has data of `record_type`.
+    fn answer_target(
+        domain: &str,
+        record_type: HickoryRecordType,
+        answers: &[Record],
+    ) -> (Name, bool) {
+        let mut target = Name::from_str(&Self::fqdn(domain)).unwrap_or_else(|_| Name::root());
+        for _ in 0..=answers.len() {
+            let mut next = None;
+            for record in answers.iter().filter(|r| *r.name() == target) {
+                match record.data() {
+                    RData::CNAME(cname) if record_type != HickoryRecordType::CNAME => {
+                        next = Some(cname.0.clone());
+                    }
+                    _ if record.record_type() == record_type => return (target, true),
+                    _ => {}
+                }
+            }
+            match next {
+                Some(name) => target = name,
+                None => break,
+            }
+        }
+        (target, false)
+    }
+
+    fn fqdn(domain: &str) -> String {
+        if domain.ends_with('.') {
+            domain.to_string()
+        } else {
+            format!("{}.", domain)
+        }
+    }
+
+    fn now_secs() -> u32 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs() as u32)
+            .unwrap_or(0)
+    }
+
     pub fn verify_rrset_signatures(
         &self,
         domain: &str,
@@ -297,25 +530,12 @@ impl DnssecValidator {
         for record in all_answers {
             match record.data() {
                 RData::DNSSEC(DNSSECRData::RRSIG(rrsig)) => {
-                    let input = rrsig.input();
-                    if input.type_covered == hickory_proto::rr::RecordType::DNSKEY {
+                    if rrsig.input().type_covered == HickoryRecordType::DNSKEY {
                         continue;
                     }
-                    let Some(type_covered) = RecordTypeMapper::from_hickory(input.type_covered)
-                    else {
-                        continue;
-                    };
-                    rrsigs.push(RrsigRecord {
-                        type_covered,
-                        algorithm: u8::from(input.algorithm),
-                        labels: input.num_labels,
-                        original_ttl: input.original_ttl,
-                        signature_expiration: input.sig_expiration.get(),
-                        signature_inception: input.sig_inception.get(),
-                        key_tag: input.key_tag,
-                        signer_name: input.signer_name.to_string(),
-                        signature: rrsig.sig().to_vec(),
-                    });
+                    if let Some(rrsig) = RrsigRecord::from_hickory(rrsig) {
+                        rrsigs.push(rrsig);
+                    }
                 }
                 _ => data_records.push(record.clone()),
             }
@@ -332,10 +552,7 @@ impl DnssecValidator {
 
         let crypto_verifier = SignatureVerifier;
 
-        let now_secs = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .map(|d| d.as_secs() as u32)
-            .unwrap_or(0);
+        let now_secs = Self::now_secs();
 
         for rrsig in &rrsigs {
             let zone = &rrsig.signer_name;
@@ -349,13 +566,7 @@ impl DnssecValidator {
                 .iter()
                 .find(|r| r.record_type() == hickory_type)
                 .map(|r| r.name().to_string())
-                .unwrap_or_else(|| {
-                    if domain.ends_with('.') {
-                        domain.to_string()
-                    } else {
-                        format!("{}.", domain)
-                    }
-                });
+                .unwrap_or_else(|| Self::fqdn(domain));
 
             for key in zone_keys.iter() {
                 match crypto_verifier.verify_rrsig(rrsig, key, &owner, &data_records, now_secs) {
@@ -1,4 +1,5 @@
-use super::cache::DnssecCache;
+use super::cache::{DnssecCache, NegativeTrustAnchorTable};
+use super::trust_anchor::TrustAnchorStore;
 use super::validator::{DnssecValidator, ValidatedResponse};
 use crate::dns::load_balancer::PoolManager;
 use ferrous_dns_domain::{DomainError, RecordType};
@@ -14,12 +15,45 @@ pub struct DnssecValidatorPool {
 
 impl DnssecValidatorPool {
     pub fn new(pool_manager: Arc<PoolManager>, timeout_ms: u64, size: usize) -> Self {
-        let cache = Arc::new(DnssecCache::new());
+        Self::with_trust_store(pool_manager, TrustAnchorStore::new(), timeout_ms, size)
+    }
+
+    /// Pool whose validators all read `trust_store`, so anchor updates
+    /// apply to every one of them.
+    pub fn with_trust_store(
+        pool_manager: Arc<PoolManager>,
+        trust_store: TrustAnchorStore,
+        timeout_ms: u64,
+        size: usize,
+    ) -> Self {
+        Self::with_anchors(
+            pool_manager,
+            trust_store,
+            Arc::new(NegativeTrustAnchorTable::new()),
+            timeout_ms,
+            size,
+        )
+    }
+
+    /// Like [`with_trust_store`](Self::with_trust_store), with validation
+    /// skipped for names under `negative_anchors`.
+    pub fn with_anchors(
+        pool_manager: Arc<PoolManager>,
+        trust_store: TrustAnchorStore,
+        negative_anchors: Arc<NegativeTrustAnchorTable>,
+        timeout_ms: u64,
+        size: usize,
+    ) -> Self {
+        let cache = Arc::new(DnssecCache::with_negative_anchors(negative_anchors));
         let validators = (0..size)
             .map(|_| {
                 Mutex::new(
-                    DnssecValidator::with_cache(pool_manager.clone(), cache.clone())
-                        .with_timeout(timeout_ms),
+                    DnssecValidator::with_trust_store_and_cache(
+                        pool_manager.clone(),
+                        trust_store.clone(),
+                        cache.clone(),
+                    )
+                    .with_timeout(timeout_ms),
                 )
             })
             .collect();
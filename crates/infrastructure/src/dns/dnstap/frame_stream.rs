@@ -0,0 +1,101 @@
+//! Frame Streams framing for dnstap. Sockets use the bidirectional
+//! handshake (READY → ACCEPT → START … STOP → FINISH); files get the
+//! unidirectional START … STOP form.
+
+use std::io;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+pub const CONTENT_TYPE: &[u8] = b"protobuf:dnstap.Dnstap";
+
+const CONTROL_ACCEPT: u32 = 0x01;
+const CONTROL_START: u32 = 0x02;
+const CONTROL_STOP: u32 = 0x03;
+const CONTROL_READY: u32 = 0x04;
+const CONTROL_FINISH: u32 = 0x05;
+
+const FIELD_CONTENT_TYPE: u32 = 0x01;
+
+/// Longest control frame we accept from a collector.
+const MAX_CONTROL_FRAME: u32 = 512;
+
+/// Appends a data frame carrying `payload` to `buf`.
+pub fn encode_data_frame(buf: &mut Vec<u8>, payload: &[u8]) {
+    buf.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+    buf.extend_from_slice(payload);
+}
+
+fn encode_control_frame(control_type: u32, with_content_type: bool) -> Vec<u8> {
+    let mut body = control_type.to_be_bytes().to_vec();
+    if with_content_type {
+        body.extend_from_slice(&FIELD_CONTENT_TYPE.to_be_bytes());
+        body.extend_from_slice(&(CONTENT_TYPE.len() as u32).to_be_bytes());
+        body.extend_from_slice(CONTENT_TYPE);
+    }
+    let mut frame = Vec::with_capacity(body.len() + 8);
+    frame.extend_from_slice(&0u32.to_be_bytes());
+    frame.extend_from_slice(&(body.len() as u32).to_be_bytes());
+    frame.extend_from_slice(&body);
+    frame
+}
+
+async fn read_control_frame<R: AsyncRead + Unpin>(reader: &mut R) -> io::Result<u32> {
+    if reader.read_u32().await? != 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "expected a Frame Streams control frame",
+        ));
+    }
+    let len = reader.read_u32().await?;
+    if !(4..=MAX_CONTROL_FRAME).contains(&len) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid Frame Streams control frame length",
+        ));
+    }
+    let mut body = vec![0u8; len as usize];
+    reader.read_exact(&mut body).await?;
+    Ok(u32::from_be_bytes([body[0], body[1], body[2], body[3]]))
+}
+
+/// Runs the bidirectional handshake and writes START.
+pub async fn open_bidirectional<S: AsyncRead + AsyncWrite + Unpin>(
+    stream: &mut S,
+) -> io::Result<()> {
+    stream
+        .write_all(&encode_control_frame(CONTROL_READY, true))
+        .await?;
+    stream.flush().await?;
+    if read_control_frame(stream).await? != CONTROL_ACCEPT {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "collector did not ACCEPT the dnstap content type",
+        ));
+    }
+    open_unidirectional(stream).await
+}
+
+/// Writes START.
+pub async fn open_unidirectional<W: AsyncWrite + Unpin>(writer: &mut W) -> io::Result<()> {
+    writer
+        .write_all(&encode_control_frame(CONTROL_START, true))
+        .await?;
+    writer.flush().await
+}
+
+/// Writes STOP and, on sockets, waits for the collector's FINISH.
+pub async fn close<S: AsyncRead + AsyncWrite + Unpin>(
+    stream: &mut S,
+    bidirectional: bool,
+) -> io::Result<()> {
+    stream
+        .write_all(&encode_control_frame(CONTROL_STOP, false))
+        .await?;
+    stream.flush().await?;
+    if bidirectional && read_control_frame(stream).await? != CONTROL_FINISH {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "collector did not FINISH the stream",
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,6 @@
+pub mod frame_stream;
+pub mod message;
+pub mod writer;
+
+pub use message::{DnstapMessage, MessageType, SocketProtocol};
+pub use writer::DnstapWriter;
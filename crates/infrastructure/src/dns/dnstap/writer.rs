@@ -0,0 +1,292 @@
+use super::frame_stream;
+use super::message::{DnstapMessage, MessageType, SocketProtocol};
+use ferrous_dns_application::ports::DnstapSink;
+use ferrous_dns_domain::{ConfigError, DnsProtocol, DnstapConfig, DnstapOutput};
+use std::io;
+use std::net::IpAddr;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
+use tokio::sync::mpsc;
+use tracing::{info, warn};
+
+const VERSION: &[u8] = concat!("ferrous-dns ", env!("CARGO_PKG_VERSION")).as_bytes();
+
+/// Frames are coalesced into writes of up to this many bytes.
+const WRITE_BATCH_BYTES: usize = 64 * 1024;
+
+const RECONNECT_MIN: Duration = Duration::from_millis(500);
+const RECONNECT_MAX: Duration = Duration::from_secs(30);
+
+#[derive(Default)]
+struct Counters {
+    dropped: AtomicU64,
+    written: AtomicU64,
+}
+
+/// Encodes dnstap messages on the caller's thread and hands them to a
+/// background task over a bounded channel. A full channel drops the message
+/// instead of blocking the query path.
+pub struct DnstapWriter {
+    sender: mpsc::Sender<Vec<u8>>,
+    identity: Option<Box<[u8]>>,
+    client_messages: bool,
+    forwarder_messages: bool,
+    counters: Arc<Counters>,
+}
+
+impl DnstapWriter {
+    /// Starts the output task for `config.output`. The task (re)connects in
+    /// the background, so an unreachable collector does not fail startup.
+    pub fn spawn(config: &DnstapConfig) -> Result<Arc<Self>, ConfigError> {
+        let output = config.parse_output()?;
+        let (sender, receiver) = mpsc::channel(config.queue_capacity.max(1));
+        let counters = Arc::new(Counters::default());
+
+        info!(output = %config.output, "dnstap output enabled");
+        tokio::spawn(run_output(output, receiver, Arc::clone(&counters)));
+
+        Ok(Arc::new(Self {
+            sender,
+            identity: config
+                .identity
+                .as_ref()
+                .map(|identity| identity.as_bytes().into()),
+            client_messages: config.client_messages,
+            forwarder_messages: config.forwarder_messages,
+            counters,
+        }))
+    }
+
+    /// Emits FORWARDER_QUERY for a query about to be sent upstream. Returns
+    /// the query time to pass to [`Self::forwarder_response`], or `None`
+    /// when forwarder messages are disabled.
+    pub fn forwarder_query(&self, protocol: &DnsProtocol, query: &[u8]) -> Option<SystemTime> {
+        if !self.forwarder_messages {
+            return None;
+        }
+        let query_time = SystemTime::now();
+        let mut message = forwarder_message(MessageType::ForwarderQuery, protocol);
+        message.query_time = Some(query_time);
+        message.query_message = Some(query);
+        self.enqueue(&message);
+        Some(query_time)
+    }
+
+    /// Emits FORWARDER_RESPONSE for an upstream answer.
+    pub fn forwarder_response(
+        &self,
+        protocol: &DnsProtocol,
+        query: &[u8],
+        response: &[u8],
+        query_time: SystemTime,
+    ) {
+        let mut message = forwarder_message(MessageType::ForwarderResponse, protocol);
+        message.query_time = Some(query_time);
+        message.query_message = Some(query);
+        message.response_time = Some(SystemTime::now());
+        message.response_message = Some(response);
+        self.enqueue(&message);
+    }
+
+    /// Returns the query time to pass to [`Self::client_exchange`], or
+    /// `None` when client messages are disabled.
+    pub fn client_query_time(&self) -> Option<SystemTime> {
+        self.client_messages.then(SystemTime::now)
+    }
+
+    /// Emits the CLIENT_QUERY / CLIENT_RESPONSE pair for a query received
+    /// from `client_ip` and the response sent back, as wire bytes.
+    pub fn client_exchange(
+        &self,
+        protocol: SocketProtocol,
+        client_ip: IpAddr,
+        client_port: Option<u16>,
+        query: &[u8],
+        response: &[u8],
+        query_time: SystemTime,
+    ) {
+        let mut message = DnstapMessage::new(MessageType::ClientQuery);
+        message.socket_protocol = Some(protocol);
+        message.query_address = Some(client_ip);
+        message.query_port = client_port;
+        message.query_time = Some(query_time);
+        message.query_message = Some(query);
+        self.enqueue(&message);
+
+        message.message_type = MessageType::ClientResponse;
+        message.response_time = Some(SystemTime::now());
+        message.response_message = Some(response);
+        self.enqueue(&message);
+    }
+
+    fn enqueue(&self, message: &DnstapMessage<'_>) {
+        let frame = message.encode(self.identity.as_deref(), VERSION);
+        if self.sender.try_send(frame).is_err()
+            && self.counters.dropped.fetch_add(1, Ordering::Relaxed) == 0
+        {
+            warn!("dnstap queue full, dropping messages");
+        }
+    }
+}
+
+impl DnstapSink for DnstapWriter {
+    fn dropped_messages(&self) -> u64 {
+        self.counters.dropped.load(Ordering::Relaxed)
+    }
+
+    fn written_messages(&self) -> u64 {
+        self.counters.written.load(Ordering::Relaxed)
+    }
+}
+
+fn forwarder_message<'a>(message_type: MessageType, protocol: &DnsProtocol) -> DnstapMessage<'a> {
+    let (socket_protocol, address) = match protocol {
+        DnsProtocol::Udp { .. } => (SocketProtocol::Udp, protocol.socket_addr()),
+        DnsProtocol::Tcp { .. } => (SocketProtocol::Tcp, protocol.socket_addr()),
+        DnsProtocol::Tls { .. } => (SocketProtocol::Dot, protocol.socket_addr()),
+        DnsProtocol::Quic { .. } => (SocketProtocol::Doq, protocol.socket_addr()),
+        DnsProtocol::Https { resolved_addrs, .. } | DnsProtocol::H3 { resolved_addrs, .. } => {
+            (SocketProtocol::Doh, resolved_addrs.first().copied())
+        }
+    };
+    let mut message = DnstapMessage::new(message_type);
+    message.socket_protocol = Some(socket_protocol);
+    message.response_address = address;
+    message
+}
+
+trait OutputStream: AsyncRead + AsyncWrite + Unpin + Send {}
+
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> OutputStream for T {}
+
+/// Opens `output` and completes the Frame Streams handshake. Returns the
+/// stream and whether it is bidirectional.
+async fn open(output: &DnstapOutput) -> io::Result<(Box<dyn OutputStream>, bool)> {
+    match output {
+        #[cfg(unix)]
+        DnstapOutput::Unix(path) => {
+            let mut stream = tokio::net::UnixStream::connect(path).await?;
+            frame_stream::open_bidirectional(&mut stream).await?;
+            Ok((Box::new(stream), true))
+        }
+        #[cfg(not(unix))]
+        DnstapOutput::Unix(_) => Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "Unix sockets are not supported on this platform",
+        )),
+        DnstapOutput::Tcp(addr) => {
+            let mut stream = tokio::net::TcpStream::connect(addr.as_str()).await?;
+            stream.set_nodelay(true)?;
+            frame_stream::open_bidirectional(&mut stream).await?;
+            Ok((Box::new(stream), true))
+        }
+        DnstapOutput::File(path) => {
+            rotate(path).await?;
+            let mut file = tokio::fs::OpenOptions::new()
+                .create(true)
+                .write(true)
+                .truncate(true)
+                .open(path)
+                .await?;
+            frame_stream::open_unidirectional(&mut file).await?;
+            Ok((Box::new(file), false))
+        }
+    }
+}
+
+/// Moves a non-empty file at `path` aside to `<path>.<unix millis>`, so that
+/// every file holds exactly one `START`…`STOP` stream.
+async fn rotate(path: &Path) -> io::Result<()> {
+    match tokio::fs::metadata(path).await {
+        Ok(metadata) if metadata.len() > 0 => {}
+        Ok(_) => return Ok(()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e),
+    }
+    let millis = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    let mut rotated = path.as_os_str().to_owned();
+    rotated.push(format!(".{millis}"));
+    tokio::fs::rename(path, &rotated).await?;
+    info!(path = %path.display(), rotated = ?rotated, "Rotated dnstap output file");
+    Ok(())
+}
+
+async fn run_output(
+    output: DnstapOutput,
+    mut receiver: mpsc::Receiver<Vec<u8>>,
+    counters: Arc<Counters>,
+) {
+    let mut backoff = RECONNECT_MIN;
+    loop {
+        match open(&output).await {
+            Ok((mut stream, bidirectional)) => {
+                backoff = RECONNECT_MIN;
+                match pump(&mut stream, &mut receiver, &counters).await {
+                    Ok(()) => {
+                        let _ = frame_stream::close(&mut stream, bidirectional).await;
+                        return;
+                    }
+                    Err(e) => {
+                        warn!(error = %e, "dnstap output failed, reconnecting");
+                        // Finish the file's stream if it still takes
+                        // writes; the next open starts a new file.
+                        if !bidirectional {
+                            let _ = frame_stream::close(&mut stream, false).await;
+                        }
+                    }
+                }
+            }
+            Err(e) => warn!(error = %e, "Failed to open dnstap output"),
+        }
+
+        if receiver.is_closed() && receiver.is_empty() {
+            return;
+        }
+        // Messages keep queueing while disconnected; once the queue is full
+        // they are dropped at enqueue time.
+        tokio::time::sleep(backoff).await;
+        backoff = (backoff * 2).min(RECONNECT_MAX);
+    }
+}
+
+/// Writes frames until the channel closes. Frames lost to a write error
+/// are counted as dropped.
+async fn pump(
+    stream: &mut Box<dyn OutputStream>,
+    receiver: &mut mpsc::Receiver<Vec<u8>>,
+    counters: &Counters,
+) -> io::Result<()> {
+    let mut batch = Vec::with_capacity(WRITE_BATCH_BYTES);
+    while let Some(frame) = receiver.recv().await {
+        batch.clear();
+        frame_stream::encode_data_frame(&mut batch, &frame);
+        let mut frames = 1;
+        while batch.len() < WRITE_BATCH_BYTES {
+            match receiver.try_recv() {
+                Ok(frame) => {
+                    frame_stream::encode_data_frame(&mut batch, &frame);
+                    frames += 1;
+                }
+                Err(_) => break,
+            }
+        }
+
+        let result = async {
+            stream.write_all(&batch).await?;
+            stream.flush().await
+        }
+        .await;
+        if let Err(e) = result {
+            counters.dropped.fetch_add(frames, Ordering::Relaxed);
+            return Err(e);
+        }
+        counters.written.fetch_add(frames, Ordering::Relaxed);
+    }
+    Ok(())
+}
@@ -0,0 +1,176 @@
+use ferrous_dns_domain::{
+    DynamicUpdate, RecordType, UpdateData, UpdateOperation, UpdatePrerequisite, UpdateRcode,
+};
+use hickory_proto::op::Message;
+use hickory_proto::rr::{DNSClass, Name, RData, Record, RecordType as HickoryRecordType};
+use hickory_proto::serialize::binary::BinEncodable;
+use std::sync::Arc;
+
+/// Decodes the zone, prerequisite and update sections of an UPDATE
+/// message, performing the format checks of RFC 2136 §3.1, §3.2 and the
+/// §3.4.1 prescan. Semantic checks (zone membership, prerequisite
+/// evaluation) are left to the use case.
+pub(super) fn decode_update(message: &Message) -> Result<DynamicUpdate, UpdateRcode> {
+    let [zone] = message.queries() else {
+        return Err(UpdateRcode::FormErr);
+    };
+    if zone.query_type() != HickoryRecordType::SOA {
+        return Err(UpdateRcode::FormErr);
+    }
+    if zone.query_class() != DNSClass::IN {
+        return Err(UpdateRcode::NotAuth);
+    }
+
+    let mut prerequisites: Vec<UpdatePrerequisite> = Vec::new();
+    for record in message.answers() {
+        decode_prerequisite(record, &mut prerequisites)?;
+    }
+
+    let mut operations = Vec::with_capacity(message.name_servers().len());
+    for record in message.name_servers() {
+        if let Some(operation) = decode_operation(record)? {
+            operations.push(operation);
+        }
+    }
+
+    Ok(DynamicUpdate {
+        zone: normalize(zone.name()),
+        prerequisites,
+        operations,
+    })
+}
+
+fn decode_prerequisite(
+    record: &Record,
+    prerequisites: &mut Vec<UpdatePrerequisite>,
+) -> Result<(), UpdateRcode> {
+    if record.ttl() != 0 {
+        return Err(UpdateRcode::FormErr);
+    }
+    let name = normalize(record.name());
+    let hickory_type = record.record_type();
+    let is_any = hickory_type == HickoryRecordType::ANY;
+    let empty = matches!(record.data(), RData::Update0(_));
+
+    let prerequisite = match record.dns_class() {
+        DNSClass::ANY if !empty => return Err(UpdateRcode::FormErr),
+        DNSClass::ANY if is_any => UpdatePrerequisite::NameInUse { name },
+        DNSClass::ANY => match record_type(hickory_type) {
+            Some(record_type) => UpdatePrerequisite::RrsetExists { name, record_type },
+            None => return Err(UpdateRcode::NxRrset),
+        },
+        DNSClass::NONE if !empty => return Err(UpdateRcode::FormErr),
+        DNSClass::NONE if is_any => UpdatePrerequisite::NameNotInUse { name },
+        DNSClass::NONE => match record_type(hickory_type) {
+            Some(record_type) => UpdatePrerequisite::RrsetAbsent { name, record_type },
+            None => return Ok(()),
+        },
+        DNSClass::IN if is_any || empty => return Err(UpdateRcode::FormErr),
+        DNSClass::IN => {
+            let Some(record_type) = record_type(hickory_type) else {
+                return Err(UpdateRcode::NxRrset);
+            };
+            // Value-dependent prerequisites for one name and type form a
+            // single RRset that has to match as a whole (§3.2.3).
+            let existing = prerequisites.iter_mut().find_map(|p| match p {
+                UpdatePrerequisite::RrsetEquals {
+                    name: n,
+                    record_type: t,
+                    data,
+                } if *n == name && *t == record_type => Some(data),
+                _ => None,
+            });
+            let value = update_data(record);
+            match existing {
+                Some(data) => data.extend(value),
+                None => prerequisites.push(UpdatePrerequisite::RrsetEquals {
+                    name,
+                    record_type,
+                    data: value.into_iter().collect(),
+                }),
+            }
+            return Ok(());
+        }
+        _ => return Err(UpdateRcode::FormErr),
+    };
+    prerequisites.push(prerequisite);
+    Ok(())
+}
+
+/// Decodes one update section entry. Deletions of record types local
+/// records never hold are dropped, since they cannot match anything.
+fn decode_operation(record: &Record) -> Result<Option<UpdateOperation>, UpdateRcode> {
+    let name = normalize(record.name());
+    let hickory_type = record.record_type();
+    let empty = matches!(record.data(), RData::Update0(_));
+
+    match record.dns_class() {
+        DNSClass::IN => {
+            if is_meta_type(hickory_type) || empty {
+                return Err(UpdateRcode::FormErr);
+            }
+            let data = update_data(record).ok_or(UpdateRcode::Refused)?;
+            Ok(Some(UpdateOperation::Add {
+                name,
+                data,
+                ttl: record.ttl(),
+            }))
+        }
+        DNSClass::ANY => {
+            if record.ttl() != 0 || !empty {
+                return Err(UpdateRcode::FormErr);
+            }
+            if hickory_type == HickoryRecordType::ANY {
+                return Ok(Some(UpdateOperation::DeleteName { name }));
+            }
+            if is_meta_type(hickory_type) {
+                return Err(UpdateRcode::FormErr);
+            }
+            Ok(record_type(hickory_type)
+                .map(|record_type| UpdateOperation::DeleteRrset { name, record_type }))
+        }
+        DNSClass::NONE => {
+            if record.ttl() != 0 || is_meta_type(hickory_type) {
+                return Err(UpdateRcode::FormErr);
+            }
+            Ok(update_data(record).map(|data| UpdateOperation::DeleteRecord { name, data }))
+        }
+        _ => Err(UpdateRcode::FormErr),
+    }
+}
+
+fn update_data(record: &Record) -> Option<UpdateData> {
+    match record.data() {
+        RData::A(a) => Some(UpdateData::Address(a.0.into())),
+        RData::AAAA(aaaa) => Some(UpdateData::Address(aaaa.0.into())),
+        RData::PTR(ptr) => Some(UpdateData::Ptr(normalize(&ptr.0))),
+        RData::Update0(_) => None,
+        rdata => {
+            let record_type = record_type(record.record_type())
+                .filter(|t| matches!(t, RecordType::TXT | RecordType::DHCID))?;
+            Some(UpdateData::Rdata {
+                record_type,
+                rdata: Arc::from(rdata.to_bytes().ok()?),
+            })
+        }
+    }
+}
+
+fn record_type(hickory_type: HickoryRecordType) -> Option<RecordType> {
+    RecordType::from_u16(u16::from(hickory_type))
+}
+
+fn is_meta_type(hickory_type: HickoryRecordType) -> bool {
+    matches!(
+        hickory_type,
+        HickoryRecordType::ANY
+            | HickoryRecordType::AXFR
+            | HickoryRecordType::IXFR
+            | HickoryRecordType::OPT
+            | HickoryRecordType::TSIG
+    ) || matches!(u16::from(hickory_type), 253 | 254)
+}
+
+fn normalize(name: &Name) -> Arc<str> {
+    Arc::from(name.to_ascii().trim_end_matches('.').to_ascii_lowercase())
+}
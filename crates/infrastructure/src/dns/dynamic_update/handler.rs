@@ -0,0 +1,194 @@
+use super::decode::decode_update;
+use super::tsig_keys::TsigKeyTable;
+use ferrous_dns_application::use_cases::ApplyDynamicUpdateUseCase;
+use ferrous_dns_domain::UpdateRcode;
+use hickory_proto::dnssec::rdata::tsig::{make_tsig_record, message_tbs, TSIG};
+use hickory_proto::dnssec::rdata::DNSSECRData;
+use hickory_proto::dnssec::tsig::TSigner;
+use hickory_proto::op::{Message, MessageSignature, MessageType, OpCode, ResponseCode};
+use hickory_proto::rr::Record;
+use hickory_proto::serialize::binary::{BinEncodable, BinEncoder};
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::{debug, warn};
+
+/// TSIG error codes (RFC 8945 §4.3).
+const TSIG_BADSIG: u16 = 16;
+const TSIG_BADKEY: u16 = 17;
+const TSIG_BADTIME: u16 = 18;
+
+/// Answers RFC 2136 UPDATE messages authenticated with TSIG (RFC 8945).
+///
+/// Unsigned updates are refused. Responses to verified requests are signed
+/// with the request's key, so clients can authenticate the outcome.
+pub struct DynamicUpdateHandler {
+    keys: Arc<TsigKeyTable>,
+    use_case: Arc<ApplyDynamicUpdateUseCase>,
+}
+
+impl DynamicUpdateHandler {
+    pub fn new(keys: Arc<TsigKeyTable>, use_case: Arc<ApplyDynamicUpdateUseCase>) -> Self {
+        Self { keys, use_case }
+    }
+
+    /// Handles the UPDATE `request`, parsed from `raw`, and returns the wire
+    /// response.
+    pub async fn handle(
+        &self,
+        raw: &[u8],
+        request: &Message,
+        client_ip: IpAddr,
+    ) -> Option<Vec<u8>> {
+        let MessageSignature::Tsig(ref tsig_record) = *request.signature() else {
+            warn!(client = %client_ip, "Refusing unsigned dynamic update");
+            return encode(&response(request, ResponseCode::Refused));
+        };
+        let Some(tsig) = tsig_record
+            .data()
+            .as_dnssec()
+            .and_then(DNSSECRData::as_tsig)
+        else {
+            return encode(&response(request, ResponseCode::FormErr));
+        };
+
+        let Some(signer) = self.keys.get(tsig_record.name()) else {
+            warn!(client = %client_ip, key = %tsig_record.name(), "Dynamic update signed with unknown key");
+            return unsigned_tsig_error(request, tsig_record, tsig, TSIG_BADKEY);
+        };
+
+        let now = unix_now();
+        let request_mac = match signer.verify_message_byte(raw, None, true) {
+            Ok((mac, _, window)) if window.contains(&now) => mac,
+            Ok((mac, _, _)) => {
+                warn!(client = %client_ip, key = %tsig_record.name(), "Dynamic update signature out of time window");
+                let mut resp = response(request, ResponseCode::NotAuth);
+                let other = now.to_be_bytes()[2..].to_vec();
+                sign(&signer, &mac, &mut resp, tsig.time(), TSIG_BADTIME, other)?;
+                return encode(&resp);
+            }
+            Err(e) => {
+                warn!(client = %client_ip, key = %tsig_record.name(), error = %e, "Dynamic update signature invalid");
+                return unsigned_tsig_error(request, tsig_record, tsig, TSIG_BADSIG);
+            }
+        };
+
+        let outcome = match decode_update(request) {
+            Ok(update) => {
+                debug!(
+                    client = %client_ip,
+                    zone = %update.zone,
+                    operations = update.operations.len(),
+                    "Dynamic update received"
+                );
+                self.use_case.execute(&update).await
+            }
+            Err(rcode) => Err(rcode),
+        };
+        let code = match outcome {
+            Ok(()) => ResponseCode::NoError,
+            Err(rcode) => {
+                debug!(client = %client_ip, rcode = rcode.to_str(), "Dynamic update rejected");
+                response_code(rcode)
+            }
+        };
+
+        let mut resp = response(request, code);
+        sign(&signer, &request_mac, &mut resp, now, 0, Vec::new())?;
+        encode(&resp)
+    }
+}
+
+fn response(request: &Message, code: ResponseCode) -> Message {
+    let mut resp = Message::new(request.id(), MessageType::Response, OpCode::Update);
+    resp.set_response_code(code);
+    for zone in request.queries() {
+        resp.add_query(zone.clone());
+    }
+    resp
+}
+
+/// NOTAUTH carrying a TSIG error and an empty MAC, as required when the
+/// request could not be authenticated (RFC 8945 §5.3.2).
+fn unsigned_tsig_error(
+    request: &Message,
+    tsig_record: &Record,
+    tsig: &TSIG,
+    error: u16,
+) -> Option<Vec<u8>> {
+    let mut resp = response(request, ResponseCode::NotAuth);
+    let rdata = TSIG::new(
+        tsig.algorithm().clone(),
+        tsig.time(),
+        tsig.fudge(),
+        Vec::new(),
+        request.id(),
+        error,
+        Vec::new(),
+    );
+    resp.set_signature(MessageSignature::Tsig(make_tsig_record(
+        tsig_record.name().clone(),
+        rdata,
+    )));
+    encode(&resp)
+}
+
+/// Signs `resp` as the answer to a request carrying `request_mac`
+/// (RFC 8945 §5.3).
+fn sign(
+    signer: &TSigner,
+    request_mac: &[u8],
+    resp: &mut Message,
+    time: u64,
+    error: u16,
+    other: Vec<u8>,
+) -> Option<()> {
+    let pre_tsig = TSIG::new(
+        signer.algorithm().clone(),
+        time,
+        signer.fudge(),
+        Vec::new(),
+        resp.id(),
+        error,
+        other,
+    );
+    let mut tbs = Vec::with_capacity(512);
+    tbs.extend_from_slice(&(request_mac.len() as u16).to_be_bytes());
+    tbs.extend_from_slice(request_mac);
+    tbs.extend(message_tbs(resp, &pre_tsig, signer.signer_name()).ok()?);
+    let mac = signer.sign(&tbs).ok()?;
+    resp.set_signature(MessageSignature::Tsig(make_tsig_record(
+        signer.signer_name().clone(),
+        pre_tsig.set_mac(mac),
+    )));
+    Some(())
+}
+
+fn response_code(rcode: UpdateRcode) -> ResponseCode {
+    match rcode {
+        UpdateRcode::FormErr => ResponseCode::FormErr,
+        UpdateRcode::ServFail => ResponseCode::ServFail,
+        UpdateRcode::NxDomain => ResponseCode::NXDomain,
+        UpdateRcode::NotImp => ResponseCode::NotImp,
+        UpdateRcode::Refused => ResponseCode::Refused,
+        UpdateRcode::YxDomain => ResponseCode::YXDomain,
+        UpdateRcode::YxRrset => ResponseCode::YXRRSet,
+        UpdateRcode::NxRrset => ResponseCode::NXRRSet,
+        UpdateRcode::NotAuth => ResponseCode::NotAuth,
+        UpdateRcode::NotZone => ResponseCode::NotZone,
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn encode(msg: &Message) -> Option<Vec<u8>> {
+    let mut buf = Vec::with_capacity(512);
+    let mut encoder = BinEncoder::new(&mut buf);
+    msg.emit(&mut encoder).ok()?;
+    Some(buf)
+}
@@ -0,0 +1,6 @@
+mod decode;
+mod handler;
+mod tsig_keys;
+
+pub use handler::DynamicUpdateHandler;
+pub use tsig_keys::TsigKeyTable;
@@ -0,0 +1,79 @@
+use arc_swap::ArcSwap;
+use base64::Engine;
+use ferrous_dns_application::ports::TsigKeyStore;
+use ferrous_dns_domain::TsigKey;
+use hickory_proto::dnssec::rdata::tsig::TsigAlgorithm;
+use hickory_proto::dnssec::tsig::TSigner;
+use hickory_proto::rr::Name;
+use rustc_hash::FxHashMap;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+/// Allowed clock skew between the signer and us, in seconds (RFC 8945
+/// recommends 300).
+const TSIG_FUDGE: u16 = 300;
+
+/// Lock-free, hot-swappable set of TSIG keys, by key name.
+pub struct TsigKeyTable {
+    keys: ArcSwap<FxHashMap<Box<str>, TSigner>>,
+}
+
+impl Default for TsigKeyTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TsigKeyTable {
+    pub fn new() -> Self {
+        Self {
+            keys: ArcSwap::from_pointee(FxHashMap::default()),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.load().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.load().is_empty()
+    }
+
+    /// The signer for the key a TSIG record names, if it is known.
+    pub fn get(&self, key_name: &Name) -> Option<TSigner> {
+        let name = TsigKey::normalize_name(&key_name.to_ascii());
+        self.keys.load().get(name.as_str()).cloned()
+    }
+
+    fn compile(key: &TsigKey) -> Option<TSigner> {
+        let secret = base64::engine::general_purpose::STANDARD
+            .decode(key.secret.as_bytes())
+            .map_err(|_| warn!(name = %key.name, "Skipping TSIG key with invalid secret"))
+            .ok()?;
+        let algorithm = Name::from_ascii(key.algorithm.as_ref())
+            .map(TsigAlgorithm::from_name)
+            .map_err(|_| warn!(name = %key.name, "Skipping TSIG key with invalid algorithm"))
+            .ok()?;
+        let signer_name = Name::from_ascii(key.name.as_ref())
+            .map_err(|_| warn!(name = %key.name, "Skipping TSIG key with invalid name"))
+            .ok()?;
+        TSigner::new(secret, algorithm, signer_name, TSIG_FUDGE)
+            .map_err(|e| warn!(name = %key.name, error = %e, "Skipping unusable TSIG key"))
+            .ok()
+    }
+}
+
+impl TsigKeyStore for TsigKeyTable {
+    fn replace_keys(&self, keys: &[TsigKey]) {
+        let compiled: FxHashMap<Box<str>, TSigner> = keys
+            .iter()
+            .filter_map(|key| {
+                let signer = Self::compile(key)?;
+                Some((TsigKey::normalize_name(&key.name).into_boxed_str(), signer))
+            })
+            .collect();
+
+        info!(keys = compiled.len(), "TSIG keys loaded");
+        self.keys.store(Arc::new(compiled));
+    }
+}
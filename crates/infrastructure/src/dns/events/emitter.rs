@@ -1,4 +1,6 @@
 use super::QueryEvent;
+use crate::dns::dnstap::DnstapWriter;
+use std::sync::Arc;
 use tokio::sync::mpsc;
 use tracing::warn;
 
@@ -7,16 +9,23 @@ const QUERY_EVENT_CHANNEL_CAPACITY: usize = 4096;
 #[derive(Clone)]
 pub struct QueryEventEmitter {
     sender: Option<mpsc::Sender<QueryEvent>>,
+    dnstap: Option<Arc<DnstapWriter>>,
 }
 
 impl QueryEventEmitter {
     pub fn new_disabled() -> Self {
-        Self { sender: None }
+        Self {
+            sender: None,
+            dnstap: None,
+        }
     }
 
     pub fn new_enabled() -> (Self, mpsc::Receiver<QueryEvent>) {
         let (tx, rx) = mpsc::channel(QUERY_EVENT_CHANNEL_CAPACITY);
-        let emitter = Self { sender: Some(tx) };
+        let emitter = Self {
+            sender: Some(tx),
+            dnstap: None,
+        };
         (emitter, rx)
     }
 
@@ -31,6 +40,16 @@ impl QueryEventEmitter {
     pub fn is_enabled(&self) -> bool {
         self.sender.is_some()
     }
+
+    /// Also writes the wire messages of upstream exchanges to `dnstap`.
+    pub fn with_dnstap(mut self, dnstap: Arc<DnstapWriter>) -> Self {
+        self.dnstap = Some(dnstap);
+        self
+    }
+
+    pub fn dnstap(&self) -> Option<&DnstapWriter> {
+        self.dnstap.as_deref()
+    }
 }
 
 impl Default for QueryEventEmitter {
@@ -43,6 +62,7 @@ impl std::fmt::Debug for QueryEventEmitter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("QueryEventEmitter")
             .field("enabled", &self.is_enabled())
+            .field("dnstap", &self.dnstap.is_some())
             .finish()
     }
 }
@@ -1,12 +1,16 @@
 use super::record_type_map::RecordTypeMapper;
 use ferrous_dns_domain::{DomainError, RecordType};
 use hickory_proto::op::{Edns, Message, MessageType, OpCode, Query};
-use hickory_proto::rr::Name;
+use hickory_proto::rr::{Name, RecordType as HickoryRecordType};
 use hickory_proto::serialize::binary::{BinEncodable, BinEncoder};
 use ring::rand::{SecureRandom, SystemRandom};
 use std::str::FromStr;
 use std::sync::LazyLock;
 
+/// Advertised UDP payload for queries to authoritative servers; small enough
+/// to avoid IP fragmentation (DNS Flag Day 2020).
+const ITERATIVE_EDNS_PAYLOAD: u16 = 1232;
+
 pub struct MessageBuilder;
 
 impl MessageBuilder {
@@ -45,6 +49,24 @@ impl MessageBuilder {
         Ok((id, bytes))
     }
 
+    /// Builds a non-recursive (RD=0) query as sent by the recursor to
+    /// authoritative servers. The name is used verbatim so that callers can
+    /// apply 0x20 case randomisation.
+    pub fn build_iterative_query(
+        name: &Name,
+        record_type: HickoryRecordType,
+        dnssec_ok: bool,
+    ) -> Result<Vec<u8>, DomainError> {
+        let mut message = Message::new(Self::secure_random_id(), MessageType::Query, OpCode::Query);
+        message.set_recursion_desired(false);
+        message.add_query(Query::query(name.clone(), record_type));
+        let mut edns = Self::build_edns(dnssec_ok);
+        edns.set_max_payload(ITERATIVE_EDNS_PAYLOAD);
+        message.set_edns(edns);
+
+        Self::serialize_message(&message)
+    }
+
     fn secure_random_id() -> u16 {
         static SECURE_RNG: LazyLock<SystemRandom> = LazyLock::new(SystemRandom::new);
 
@@ -44,6 +44,7 @@ impl RecordTypeMapper {
             RecordType::IPSECKEY => HickoryRecordType::Unknown(45),
             RecordType::OPENPGPKEY => HickoryRecordType::OPENPGPKEY,
 
+            RecordType::DHCID => HickoryRecordType::Unknown(49),
             RecordType::ZONEMD => HickoryRecordType::Unknown(63),
 
             RecordType::ANAME => HickoryRecordType::ANAME,
@@ -87,6 +88,7 @@ impl RecordTypeMapper {
 
             HickoryRecordType::Unknown(11) => Some(RecordType::WKS),
             HickoryRecordType::Unknown(45) => Some(RecordType::IPSECKEY),
+            HickoryRecordType::Unknown(49) => Some(RecordType::DHCID),
             HickoryRecordType::Unknown(63) => Some(RecordType::ZONEMD),
 
             HickoryRecordType::OPENPGPKEY => Some(RecordType::OPENPGPKEY),
@@ -0,0 +1,56 @@
+use arc_swap::ArcSwap;
+use ferrous_dns_application::ports::GroupUpstreamStore;
+use ferrous_dns_domain::Group;
+use rustc_hash::FxHashMap;
+use std::sync::Arc;
+use tracing::info;
+
+/// Lock-free, hot-swappable map from group id to the upstream pools that
+/// group resolves through, in the order they should be tried.
+pub struct GroupUpstreamTable {
+    routes: ArcSwap<FxHashMap<i64, Arc<[Arc<str>]>>>,
+}
+
+impl Default for GroupUpstreamTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GroupUpstreamTable {
+    pub fn new() -> Self {
+        Self {
+            routes: ArcSwap::from_pointee(FxHashMap::default()),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.routes.load().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.routes.load().is_empty()
+    }
+
+    /// Pools for `group_id`, or `None` when the group uses the global pools.
+    pub fn pools(&self, group_id: i64) -> Option<Arc<[Arc<str>]>> {
+        self.routes.load().get(&group_id).cloned()
+    }
+}
+
+impl GroupUpstreamStore for GroupUpstreamTable {
+    fn replace_groups(&self, groups: &[Group]) {
+        let routes: FxHashMap<i64, Arc<[Arc<str>]>> = groups
+            .iter()
+            .filter(|g| !g.upstream_pools.is_empty())
+            .filter_map(|g| g.id.map(|id| (id, Arc::from(g.upstream_pools.as_slice()))))
+            .collect();
+
+        info!(groups = routes.len(), "Group upstream pools loaded");
+        self.routes.store(Arc::new(routes));
+    }
+
+    fn has_pools(&self, group_id: i64) -> bool {
+        self.routes.load().contains_key(&group_id)
+    }
+}
@@ -1,16 +1,20 @@
 pub mod balanced;
 pub mod failover;
+pub mod group_upstreams;
 pub mod health;
 pub mod parallel;
 pub mod pool;
 pub mod query;
+pub mod recursive;
 pub mod strategy;
 pub mod upstream_health_adapter;
 
 pub use balanced::BalancedStrategy;
 pub use failover::FailoverStrategy;
+pub use group_upstreams::GroupUpstreamTable;
 pub use health::{HealthChecker, ServerHealth, ServerStatus};
 pub use parallel::ParallelStrategy;
 pub use pool::{PoolGroupEntry, PoolManager};
+pub use recursive::RecursiveStrategy;
 pub use strategy::{Strategy, UpstreamResult};
 pub use upstream_health_adapter::UpstreamHealthAdapter;
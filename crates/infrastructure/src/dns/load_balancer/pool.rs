@@ -1,14 +1,20 @@
 use super::balanced::BalancedStrategy;
 use super::failover::FailoverStrategy;
+use super::group_upstreams::GroupUpstreamTable;
 use super::health::HealthChecker;
 use super::parallel::ParallelStrategy;
+use super::query::query_server;
+use super::recursive::RecursiveStrategy;
 use super::strategy::{QueryContext, Strategy, UpstreamResult};
 use crate::dns::events::QueryEventEmitter;
 use crate::dns::forwarding::{MessageBuilder, ResponseParser};
+use crate::dns::recursor::{root_hint_endpoints, Recursor};
 use crate::dns::transport::resolver;
 use ferrous_dns_domain::{
-    Config, DnsProtocol, DomainError, RecordType, UpstreamPool, UpstreamStrategy,
+    Config, DnsProtocol, DnsViewConfig, DomainError, QueryScope, RecordType, RecursorConfig,
+    UpstreamPool, UpstreamStrategy,
 };
+use rustc_hash::FxHashMap;
 use smallvec::SmallVec;
 use std::collections::HashMap;
 use std::net::SocketAddr;
@@ -20,6 +26,8 @@ pub struct PoolManager {
     pools: Vec<PoolWithStrategy>,
     health_checker: Option<Arc<HealthChecker>>,
     emitter: QueryEventEmitter,
+    group_upstreams: Option<Arc<GroupUpstreamTable>>,
+    view_upstreams: FxHashMap<u32, Arc<[Arc<str>]>>,
 }
 
 /// Maps one original configured server string to its resolved protocol entries.
@@ -50,12 +58,21 @@ impl PoolManager {
         }
 
         let mut pools_with_strategy = Vec::new();
-        for pool in pools {
+        let mut recursor: Option<Arc<Recursor>> = None;
+        for mut pool in pools {
             let strategy = match pool.strategy {
                 UpstreamStrategy::Parallel => Strategy::Parallel(ParallelStrategy::new()),
                 UpstreamStrategy::Balanced => Strategy::Balanced(BalancedStrategy::new()),
                 UpstreamStrategy::Failover => Strategy::Failover(FailoverStrategy::new()),
+                UpstreamStrategy::Recursive => {
+                    let recursor = recursor
+                        .get_or_insert_with(|| Arc::new(Recursor::new(RecursorConfig::default())));
+                    Strategy::Recursive(RecursiveStrategy::new(Arc::clone(recursor)))
+                }
             };
+            if pool.strategy == UpstreamStrategy::Recursive && pool.servers.is_empty() {
+                pool.servers = root_hint_endpoints();
+            }
 
             let server_entries: Result<Vec<(Arc<str>, DnsProtocol)>, _> = pool
                 .servers
@@ -101,9 +118,60 @@ impl PoolManager {
             pools: pools_with_strategy,
             health_checker,
             emitter,
+            group_upstreams: None,
+            view_upstreams: FxHashMap::default(),
+        })
+    }
+
+    /// Routes queries of groups with their own upstream pools through those
+    /// pools only, instead of the global pool order.
+    pub fn with_group_upstreams(mut self, table: Arc<GroupUpstreamTable>) -> Self {
+        self.group_upstreams = Some(table);
+        self
+    }
+
+    /// Routes queries of split-horizon views with their own upstream pools
+    /// through those pools. A view's pools take precedence over the group's.
+    pub fn with_view_upstreams(mut self, views: &[DnsViewConfig]) -> Self {
+        self.view_upstreams = views
+            .iter()
+            .enumerate()
+            .filter(|(_, view)| !view.upstream_pools.is_empty())
+            .map(|(id, view)| {
+                let pools: Arc<[Arc<str>]> = view
+                    .upstream_pools
+                    .iter()
+                    .map(|name| Arc::from(name.as_str()))
+                    .collect();
+                (id as u32, pools)
+            })
+            .collect();
+        self
+    }
+
+    /// Pools a scoped query is restricted to, or `None` for the global order.
+    fn scoped_pools(&self, scope: QueryScope) -> Option<Arc<[Arc<str>]>> {
+        if let Some(pools) = scope.view.and_then(|id| self.view_upstreams.get(&id)) {
+            return Some(Arc::clone(pools));
+        }
+        scope.group.and_then(|id| {
+            self.group_upstreams
+                .as_ref()
+                .and_then(|table| table.pools(id))
         })
     }
 
+    /// Replaces the recursor behind every `Recursive` pool, so that several
+    /// managers can share one NS cache and the configured recursor settings.
+    pub fn with_recursor(mut self, recursor: Arc<Recursor>) -> Self {
+        for pool in &mut self.pools {
+            if pool.config.strategy == UpstreamStrategy::Recursive {
+                pool.strategy = Strategy::Recursive(RecursiveStrategy::new(Arc::clone(&recursor)));
+            }
+        }
+        self
+    }
+
     async fn expand_hostnames(entries: Vec<(Arc<str>, DnsProtocol)>) -> Vec<ServerGroup> {
         let mut groups = Vec::new();
         for (original, protocol) in entries {
@@ -237,54 +305,57 @@ impl PoolManager {
         .await
     }
 
+    /// Queries the pools in priority order, moving on to the next pool only
+    /// on transport errors. When the view or group in `scope` has its own
+    /// upstream pools, only those are tried, in their configured order; the
+    /// global pools are never used as a fallback so a filtering group cannot
+    /// leak to unfiltered upstreams.
     pub async fn query(
         &self,
         domain: &Arc<str>,
         record_type: &RecordType,
         timeout_ms: u64,
         dnssec_ok: bool,
+        scope: QueryScope,
     ) -> Result<UpstreamResult, DomainError> {
+        let scoped_pools = self.scoped_pools(scope);
+        let pools: SmallVec<[&PoolWithStrategy; 4]> = match &scoped_pools {
+            Some(names) => names
+                .iter()
+                .filter_map(|name| {
+                    let pool = self.pools.iter().find(|p| *p.name_arc == **name);
+                    if pool.is_none() {
+                        warn!(scope = ?scope, pool = %name, "Scope references unknown upstream pool");
+                    }
+                    pool
+                })
+                .collect(),
+            None => self.pools.iter().collect(),
+        };
+
         debug!(
-            total_pools = self.pools.len(),
+            total_pools = pools.len(),
+            scope = ?scope,
             %domain, "Starting load balancer query"
         );
 
         let query_bytes: Arc<[u8]> =
             Arc::from(MessageBuilder::build_query(domain, record_type, dnssec_ok)?);
 
-        for pool in &self.pools {
-            let healthy_refs: SmallVec<[&Arc<DnsProtocol>; 16]> =
-                if let Some(ref checker) = self.health_checker {
-                    pool.server_protocols
-                        .iter()
-                        .filter(|p| checker.is_healthy(p))
-                        .collect()
-                } else {
-                    pool.server_protocols.iter().collect()
-                };
-
-            if healthy_refs.is_empty() {
-                debug!(pool = %pool.config.name, "All unhealthy, skipping");
-                continue;
-            }
-
-            let ctx = QueryContext {
-                servers: &healthy_refs,
-                domain,
-                record_type,
-                timeout_ms,
-                query_bytes: Arc::clone(&query_bytes),
-                emitter: &self.emitter,
-                pool_name: &pool.name_arc,
-                server_displays: &pool.server_displays,
-            };
-
-            match pool.strategy.query_refs(&ctx).await {
-                Ok(result) => {
-                    debug!(pool = %pool.config.name, server = %result.server, "Pool query successful");
-                    return Ok(result);
-                }
-                Err(e) => {
+        for pool in pools {
+            match self
+                .query_single_pool(
+                    pool,
+                    domain,
+                    record_type,
+                    timeout_ms,
+                    dnssec_ok,
+                    &query_bytes,
+                )
+                .await
+            {
+                Some(Ok(result)) => return Ok(result),
+                Some(Err(e)) => {
                     if ResponseParser::is_transport_error(&e) {
                         warn!(pool = %pool.config.name, error = %e, "Transport error, trying next pool");
                         continue;
@@ -293,11 +364,123 @@ impl PoolManager {
                         return Err(e);
                     }
                 }
+                None => continue,
             }
         }
         Err(DomainError::TransportAllServersUnreachable)
     }
 
+    /// Queries only the pool named `pool_name`, without falling through to
+    /// other pools. Used by conditional forwarding rules.
+    pub async fn query_pool(
+        &self,
+        pool_name: &str,
+        domain: &Arc<str>,
+        record_type: &RecordType,
+        timeout_ms: u64,
+        dnssec_ok: bool,
+    ) -> Result<UpstreamResult, DomainError> {
+        let pool = self
+            .pools
+            .iter()
+            .find(|p| p.config.name == pool_name)
+            .ok_or_else(|| {
+                DomainError::InvalidForwardingRule(format!("Unknown upstream pool '{pool_name}'"))
+            })?;
+
+        let query_bytes: Arc<[u8]> =
+            Arc::from(MessageBuilder::build_query(domain, record_type, dnssec_ok)?);
+
+        self.query_single_pool(
+            pool,
+            domain,
+            record_type,
+            timeout_ms,
+            dnssec_ok,
+            &query_bytes,
+        )
+        .await
+        .unwrap_or(Err(DomainError::TransportAllServersUnreachable))
+    }
+
+    /// Queries a single endpoint outside any configured pool, reporting
+    /// `label` as the pool name in query events.
+    pub async fn query_endpoint(
+        &self,
+        protocol: &DnsProtocol,
+        label: &Arc<str>,
+        domain: &Arc<str>,
+        record_type: &RecordType,
+        timeout_ms: u64,
+        dnssec_ok: bool,
+    ) -> Result<UpstreamResult, DomainError> {
+        let query_bytes = MessageBuilder::build_query(domain, record_type, dnssec_ok)?;
+        let attempt = query_server(
+            protocol,
+            &query_bytes,
+            domain,
+            record_type,
+            timeout_ms,
+            &self.emitter,
+            label,
+            &Arc::new(HashMap::new()),
+        )
+        .await?;
+
+        Ok(UpstreamResult {
+            response: attempt.response,
+            server: attempt.server_addr,
+            latency_ms: attempt.latency_ms,
+            pool_name: Arc::clone(label),
+            server_display: attempt.server_display,
+        })
+    }
+
+    /// Runs the pool's strategy against its healthy servers. Returns `None`
+    /// when every server in the pool is marked unhealthy.
+    async fn query_single_pool(
+        &self,
+        pool: &PoolWithStrategy,
+        domain: &Arc<str>,
+        record_type: &RecordType,
+        timeout_ms: u64,
+        dnssec_ok: bool,
+        query_bytes: &Arc<[u8]>,
+    ) -> Option<Result<UpstreamResult, DomainError>> {
+        let healthy_refs: SmallVec<[&Arc<DnsProtocol>; 16]> =
+            if let Some(ref checker) = self.health_checker {
+                pool.server_protocols
+                    .iter()
+                    .filter(|p| checker.is_healthy(p))
+                    .collect()
+            } else {
+                pool.server_protocols.iter().collect()
+            };
+
+        if healthy_refs.is_empty() {
+            debug!(pool = %pool.config.name, "All unhealthy, skipping");
+            return None;
+        }
+
+        let ctx = QueryContext {
+            servers: &healthy_refs,
+            domain,
+            record_type,
+            timeout_ms,
+            query_bytes: Arc::clone(query_bytes),
+            dnssec_ok,
+            emitter: &self.emitter,
+            pool_name: &pool.name_arc,
+            server_displays: &pool.server_displays,
+        };
+
+        let result = pool.strategy.query_refs(&ctx).await;
+        if let Ok(ref r) = result {
+            debug!(pool = %pool.config.name, server = %r.server, "Pool query successful");
+        }
+        Some(result)
+    }
+
     pub fn get_all_servers(&self) -> Vec<std::net::SocketAddr> {
         self.pools
             .iter()
@@ -37,7 +37,13 @@ pub async fn query_server(
 
     let dns_transport = transport::get_or_create_transport(protocol)?;
 
+    let dnstap_query_time = emitter
+        .dnstap()
+        .and_then(|dnstap| dnstap.forwarder_query(protocol, query_bytes));
     let transport_response = dns_transport.send(query_bytes, timeout_duration).await?;
+    if let (Some(dnstap), Some(query_time)) = (emitter.dnstap(), dnstap_query_time) {
+        dnstap.forwarder_response(protocol, query_bytes, &transport_response.bytes, query_time);
+    }
 
     let dns_response = ResponseParser::parse_bytes(transport_response.bytes)?;
 
@@ -64,7 +70,18 @@ pub async fn query_server(
                 .unwrap_or(Duration::from_millis(500));
 
             let tcp_start = Instant::now();
+            let dnstap_query_time = emitter
+                .dnstap()
+                .and_then(|dnstap| dnstap.forwarder_query(&tcp_protocol, query_bytes));
             let tcp_response = tcp_transport.send(query_bytes, remaining).await?;
+            if let (Some(dnstap), Some(query_time)) = (emitter.dnstap(), dnstap_query_time) {
+                dnstap.forwarder_response(
+                    &tcp_protocol,
+                    query_bytes,
+                    &tcp_response.bytes,
+                    query_time,
+                );
+            }
             let tcp_dns_response = ResponseParser::parse_bytes(tcp_response.bytes)?;
 
             let tcp_response_time_us = tcp_start.elapsed().as_micros() as u64;
@@ -0,0 +1,63 @@
+use super::strategy::{QueryContext, UpstreamResult};
+use crate::dns::events::QueryEvent;
+use crate::dns::recursor::Recursor;
+use ferrous_dns_domain::DomainError;
+use smallvec::SmallVec;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Instant;
+use tracing::debug;
+
+/// Resolves iteratively from the pool's servers, which act as root hints,
+/// instead of forwarding to them.
+pub struct RecursiveStrategy {
+    recursor: Arc<Recursor>,
+}
+
+impl RecursiveStrategy {
+    pub fn new(recursor: Arc<Recursor>) -> Self {
+        Self { recursor }
+    }
+
+    pub async fn query_refs(&self, ctx: &QueryContext<'_>) -> Result<UpstreamResult, DomainError> {
+        let roots: SmallVec<[SocketAddr; 32]> =
+            ctx.servers.iter().filter_map(|p| p.socket_addr()).collect();
+        if roots.is_empty() {
+            return Err(DomainError::TransportNoHealthyServers);
+        }
+        debug!(strategy = "recursive", roots = roots.len(), domain = %ctx.domain, "Resolving iteratively");
+
+        let start = Instant::now();
+        let answer = self
+            .recursor
+            .resolve(
+                &roots,
+                ctx.domain,
+                ctx.record_type,
+                ctx.dnssec_ok,
+                ctx.timeout_ms,
+            )
+            .await?;
+
+        let server_display: Arc<str> = Arc::from(answer.server.to_string());
+        if ctx.emitter.is_enabled() {
+            ctx.emitter.emit(QueryEvent {
+                domain: Arc::clone(ctx.domain),
+                record_type: *ctx.record_type,
+                upstream_server: Arc::clone(&server_display),
+                response_time_us: start.elapsed().as_micros() as u64,
+                success: !answer.response.addresses.is_empty()
+                    || !answer.response.cname_chain.is_empty(),
+                pool_name: Some(Arc::clone(ctx.pool_name)),
+            });
+        }
+
+        Ok(UpstreamResult {
+            response: answer.response,
+            server: answer.server,
+            latency_ms: start.elapsed().as_millis() as u64,
+            pool_name: Arc::clone(ctx.pool_name),
+            server_display,
+        })
+    }
+}
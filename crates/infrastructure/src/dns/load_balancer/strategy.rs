@@ -1,6 +1,7 @@
 use super::balanced::BalancedStrategy;
 use super::failover::FailoverStrategy;
 use super::parallel::ParallelStrategy;
+use super::recursive::RecursiveStrategy;
 use crate::dns::events::QueryEventEmitter;
 use crate::dns::forwarding::DnsResponse;
 use ferrous_dns_domain::{DnsProtocol, DomainError, RecordType};
@@ -22,6 +23,7 @@ pub struct QueryContext<'a> {
     pub record_type: &'a RecordType,
     pub timeout_ms: u64,
     pub query_bytes: Arc<[u8]>,
+    pub dnssec_ok: bool,
     pub emitter: &'a QueryEventEmitter,
     pub pool_name: &'a Arc<str>,
     pub server_displays: &'a Arc<std::collections::HashMap<Arc<DnsProtocol>, Arc<str>>>,
@@ -31,6 +33,7 @@ pub enum Strategy {
     Parallel(ParallelStrategy),
     Balanced(BalancedStrategy),
     Failover(FailoverStrategy),
+    Recursive(RecursiveStrategy),
 }
 
 impl Strategy {
@@ -39,6 +42,7 @@ impl Strategy {
             Self::Parallel(s) => s.query_refs(ctx).await,
             Self::Balanced(s) => s.query_refs(ctx).await,
             Self::Failover(s) => s.query_refs(ctx).await,
+            Self::Recursive(s) => s.query_refs(ctx).await,
         }
     }
 }
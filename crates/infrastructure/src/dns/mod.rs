@@ -1,8 +1,11 @@
+pub mod authority;
 pub mod block_filter;
 pub mod cache;
 pub mod cache_maintenance;
 pub mod dga_detection;
 pub mod dnssec;
+pub mod dnstap;
+pub mod dynamic_update;
 pub mod ede;
 pub mod events;
 pub mod fast_path;
@@ -12,31 +15,38 @@ pub mod nxdomain_hijack;
 pub mod prefetch;
 pub mod proxy_protocol;
 pub mod query_logger;
+pub mod recursor;
 pub mod resolver;
 pub mod response_ip_filter;
+pub mod rewrite;
 pub mod safe_search;
 pub mod server;
 pub mod transport;
 pub mod tunneling;
 pub mod wire_response;
 
-pub use block_filter::BlockFilterEngine;
+pub use authority::{AcmeChallengeTable, LocalAuthority, ViewAuthorities};
+pub use block_filter::{BlockFilterEngine, SqliteBlocklistSimulator};
 pub use cache::{
     CacheKey, CacheMetrics, CachedAddresses, CachedData, CachedRecord, DnsCache, DnsCacheAccess,
     DnsCacheConfig, DnssecStatus, EvictionStrategy, NegativeQueryTracker,
 };
 pub use cache_maintenance::DnsCacheMaintenance;
 pub use dga_detection::DgaDetector;
+pub use dnstap::DnstapWriter;
+pub use dynamic_update::{DynamicUpdateHandler, TsigKeyTable};
 pub use events::{QueryEvent, QueryEventEmitter};
 pub use load_balancer::{
-    BalancedStrategy, FailoverStrategy, HealthChecker, ParallelStrategy, PoolManager, ServerHealth,
-    ServerStatus, UpstreamHealthAdapter,
+    BalancedStrategy, FailoverStrategy, GroupUpstreamTable, HealthChecker, ParallelStrategy,
+    PoolManager, RecursiveStrategy, ServerHealth, ServerStatus, UpstreamHealthAdapter,
 };
 pub use nxdomain_hijack::NxdomainHijackDetector;
 pub use prefetch::PrefetchPredictor;
 pub use proxy_protocol::read_proxy_v2_client_ip;
 pub use query_logger::QueryEventLogger;
+pub use recursor::Recursor;
 pub use resolver::HickoryDnsResolver;
 pub use response_ip_filter::ResponseIpFilterDetector;
+pub use rewrite::DnsRewriteTable;
 pub use safe_search::SafeSearchEnforcer;
 pub use tunneling::TunnelingDetector;
@@ -84,6 +84,7 @@ impl QueryEventLogger {
                 query_source: QuerySource::Internal,
                 group_id: None,
                 block_source: None,
+                blocklist_source_ids: Vec::new(),
             };
 
             match repo.log_query(&query_log).await {
@@ -0,0 +1,566 @@
+use super::ns_cache::NsCache;
+use crate::dns::forwarding::{DnsResponse, MessageBuilder, RecordTypeMapper, ResponseParser};
+use crate::dns::transport::{tcp::TcpTransport, udp::UdpTransport, DnsTransport};
+use bytes::Bytes;
+use ferrous_dns_domain::{DomainError, RecordType, RecursorConfig, UpstreamAddr};
+use futures::future::BoxFuture;
+use hickory_proto::op::{Edns, Message, MessageType, OpCode, Query, ResponseCode};
+use hickory_proto::rr::{Name, RData, Record, RecordType as HickoryRecordType};
+use smallvec::SmallVec;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::{debug, trace, warn};
+
+/// Longest CNAME chain followed across zones before giving up.
+const MAX_CNAME_HOPS: usize = 8;
+
+/// Nesting limit for glueless nameserver lookups (a lookup that needs the
+/// address of a nameserver, which itself needs a lookup, ...).
+const MAX_DEPTH: u8 = 6;
+
+/// RFC 9156 §3 `MAX_MINIMISE_COUNT`: after this many minimised queries the
+/// remaining labels are sent in one go.
+const MAX_MINIMISE_STEPS: usize = 10;
+
+/// Glueless nameserver names resolved per referral before giving up.
+const MAX_NS_LOOKUPS: usize = 3;
+
+const SERVER_TIMEOUT: Duration = Duration::from_millis(1500);
+
+/// Result of a recursive lookup, shaped like a forwarded upstream answer.
+pub struct RecursiveAnswer {
+    pub response: DnsResponse,
+    /// Last authoritative server that answered.
+    pub server: SocketAddr,
+}
+
+/// Iterative resolver that walks delegations from the root servers down to
+/// the authoritative zone, with QNAME minimisation (RFC 9156) and 0x20
+/// case randomisation.
+pub struct Recursor {
+    config: RecursorConfig,
+    ns_cache: NsCache,
+    nameserver_port: u16,
+}
+
+/// Per-query state shared by the main lookup and any nameserver address
+/// lookups it triggers.
+struct Lookup<'a> {
+    roots: &'a [SocketAddr],
+    dnssec_ok: bool,
+    queries_left: u32,
+    deadline: Instant,
+    last_server: Option<SocketAddr>,
+}
+
+struct Referral {
+    zone: Name,
+    ns_names: Vec<Name>,
+    ttl: u32,
+}
+
+impl Recursor {
+    pub fn new(config: RecursorConfig) -> Self {
+        let ns_cache = NsCache::new(config.ns_cache_size);
+        Self {
+            config,
+            ns_cache,
+            nameserver_port: 53,
+        }
+    }
+
+    /// Port used to reach nameservers learned from referrals. Only useful
+    /// for pointing the recursor at stand-in servers on loopback.
+    pub fn with_nameserver_port(mut self, port: u16) -> Self {
+        self.nameserver_port = port;
+        self
+    }
+
+    pub fn ns_cache(&self) -> &NsCache {
+        &self.ns_cache
+    }
+
+    pub async fn resolve(
+        &self,
+        roots: &[SocketAddr],
+        domain: &str,
+        record_type: &RecordType,
+        dnssec_ok: bool,
+        timeout_ms: u64,
+    ) -> Result<RecursiveAnswer, DomainError> {
+        if roots.is_empty() {
+            return Err(DomainError::TransportNoHealthyServers);
+        }
+
+        let mut qname = Name::from_ascii(domain)
+            .map_err(|e| {
+                DomainError::InvalidDomainName(format!("Invalid domain '{}': {}", domain, e))
+            })?
+            .to_lowercase();
+        qname.set_fqdn(true);
+        let qtype = RecordTypeMapper::to_hickory(record_type);
+
+        let mut lookup = Lookup {
+            roots,
+            dnssec_ok,
+            queries_left: self.config.max_queries,
+            deadline: Instant::now() + Duration::from_millis(timeout_ms),
+            last_server: None,
+        };
+
+        let message = self.resolve_name(&mut lookup, qname, qtype, 0).await?;
+        let bytes = message.to_vec().map_err(|e| {
+            DomainError::InvalidDnsResponse(format!("Failed to serialize response: {}", e))
+        })?;
+
+        Ok(RecursiveAnswer {
+            response: ResponseParser::parse_bytes(Bytes::from(bytes))?,
+            server: lookup.last_server.unwrap_or(roots[0]),
+        })
+    }
+
+    /// Resolves `qname`, following CNAMEs that leave the answering zone.
+    fn resolve_name<'s, 'a: 's>(
+        &'s self,
+        lookup: &'s mut Lookup<'a>,
+        qname: Name,
+        qtype: HickoryRecordType,
+        depth: u8,
+    ) -> BoxFuture<'s, Result<Message, DomainError>> {
+        Box::pin(async move {
+            let mut answers: Vec<Record> = Vec::new();
+            let mut current = qname.clone();
+
+            for _ in 0..=MAX_CNAME_HOPS {
+                let (mut response, zone) = self
+                    .resolve_iterative(lookup, &current, qtype, depth)
+                    .await?;
+                let rcode = response.response_code();
+                // Records outside the answering zone are not the server's to
+                // give; a CNAME leading out of it is resolved from scratch.
+                let section = Self::in_zone(response.take_answers(), &zone);
+                let next = Self::chase_cname(&section, &current, qtype);
+                answers.extend(section);
+
+                match next {
+                    Some(target) if rcode == ResponseCode::NoError => {
+                        trace!(from = %current, to = %target, "Following CNAME out of zone");
+                        current = target;
+                    }
+                    _ => {
+                        return Ok(Self::assemble(
+                            qname,
+                            qtype,
+                            rcode,
+                            answers,
+                            Self::in_zone(response.take_name_servers(), &zone),
+                            lookup.dnssec_ok,
+                        ));
+                    }
+                }
+            }
+
+            Err(DomainError::InvalidDnsResponse(format!(
+                "CNAME chain for {} exceeds {} hops",
+                qname, MAX_CNAME_HOPS
+            )))
+        })
+    }
+
+    /// Walks delegations towards `qname` and returns the authoritative
+    /// response for it (answer, NODATA or NXDOMAIN) with the zone of the
+    /// server that gave it.
+    async fn resolve_iterative(
+        &self,
+        lookup: &mut Lookup<'_>,
+        qname: &Name,
+        qtype: HickoryRecordType,
+        depth: u8,
+    ) -> Result<(Message, Name), DomainError> {
+        // DS records live on the parent side of a zone cut.
+        let start_from = if qtype == HickoryRecordType::DS && !qname.is_root() {
+            qname.base_name()
+        } else {
+            qname.clone()
+        };
+        let (mut zone, mut servers): (Name, Arc<[SocketAddr]>) =
+            match self.ns_cache.closest(&start_from) {
+                Some(delegation) => (delegation.zone, delegation.servers),
+                None => (Name::root(), Arc::from(lookup.roots)),
+            };
+
+        let qname_labels = qname.num_labels() as usize;
+        let mut known_labels = zone.num_labels() as usize;
+        let mut minimise = self.config.qname_minimization;
+        let mut minimise_steps = 0;
+
+        loop {
+            let minimised =
+                minimise && known_labels + 1 < qname_labels && minimise_steps < MAX_MINIMISE_STEPS;
+            let (send_name, send_type) = if minimised {
+                // RFC 9156 §2.1: QTYPE A draws less attention than NS.
+                (qname.trim_to(known_labels + 1), HickoryRecordType::A)
+            } else {
+                (qname.clone(), qtype)
+            };
+
+            let response = self
+                .query_servers(lookup, &servers, &send_name, send_type)
+                .await?;
+
+            if let Some(referral) = Self::referral(&response, &zone, &send_name) {
+                if qtype == HickoryRecordType::DS && referral.zone == *qname {
+                    return Ok((response, zone));
+                }
+                let addrs = self
+                    .referral_addresses(lookup, &zone, &referral, &response, depth)
+                    .await;
+                if addrs.is_empty() {
+                    return Err(DomainError::InvalidDnsResponse(format!(
+                        "No reachable nameservers for {}",
+                        referral.zone
+                    )));
+                }
+                debug!(zone = %referral.zone, servers = addrs.len(), "Following referral");
+                self.ns_cache
+                    .insert(&referral.zone, addrs.clone(), referral.ttl);
+                known_labels = referral.zone.num_labels() as usize;
+                zone = referral.zone;
+                servers = Arc::from(addrs);
+                continue;
+            }
+
+            if !minimised {
+                return Ok((response, zone));
+            }
+            if response.response_code() == ResponseCode::NXDomain {
+                // Re-ask with the full name rather than trusting RFC 8020
+                // semantics from every server (RFC 9156 §2.3).
+                minimise = false;
+            } else {
+                // No zone cut at this label; reveal one more.
+                known_labels += 1;
+                minimise_steps += 1;
+            }
+        }
+    }
+
+    /// Recognises a referral: no answer, and NS records in the authority
+    /// section for a zone below `zone` that encloses `qname`.
+    fn referral(response: &Message, zone: &Name, qname: &Name) -> Option<Referral> {
+        if response.response_code() != ResponseCode::NoError || !response.answers().is_empty() {
+            return None;
+        }
+
+        let mut cut: Option<Name> = None;
+        let mut ns_names = Vec::new();
+        let mut ttl = u32::MAX;
+        for record in response.name_servers() {
+            let RData::NS(ns) = record.data() else {
+                continue;
+            };
+            let owner = record.name();
+            let in_bailiwick = owner.num_labels() > zone.num_labels()
+                && zone.zone_of(owner)
+                && owner.zone_of(qname);
+            if !in_bailiwick {
+                continue;
+            }
+            match &cut {
+                Some(existing) if existing != owner => continue,
+                Some(_) => {}
+                None => cut = Some(owner.to_lowercase()),
+            }
+            ns_names.push(ns.0.to_lowercase());
+            ttl = ttl.min(record.ttl());
+        }
+
+        cut.map(|zone| Referral {
+            zone,
+            ns_names,
+            ttl,
+        })
+    }
+
+    /// Nameserver addresses for a referral: glue the parent is authoritative
+    /// for first, otherwise a lookup of the (out-of-zone) nameserver names.
+    async fn referral_addresses(
+        &self,
+        lookup: &mut Lookup<'_>,
+        parent: &Name,
+        referral: &Referral,
+        response: &Message,
+        depth: u8,
+    ) -> Vec<SocketAddr> {
+        let mut addrs: Vec<SocketAddr> = response
+            .additionals()
+            .iter()
+            .filter(|r| parent.zone_of(r.name()) && referral.ns_names.contains(r.name()))
+            .filter_map(Self::record_ip)
+            .map(|ip| SocketAddr::new(ip, self.nameserver_port))
+            .collect();
+
+        if addrs.is_empty() && depth < MAX_DEPTH {
+            let candidates = referral
+                .ns_names
+                .iter()
+                // Without glue, nameservers inside the delegated zone are unreachable.
+                .filter(|ns| !referral.zone.zone_of(ns))
+                .take(MAX_NS_LOOKUPS);
+            for ns in candidates {
+                match self
+                    .resolve_name(lookup, ns.clone(), HickoryRecordType::A, depth + 1)
+                    .await
+                {
+                    Ok(message) => addrs.extend(
+                        message
+                            .answers()
+                            .iter()
+                            .filter_map(Self::record_ip)
+                            .map(|ip| SocketAddr::new(ip, self.nameserver_port)),
+                    ),
+                    Err(e) => debug!(nameserver = %ns, error = %e, "Nameserver lookup failed"),
+                }
+                if !addrs.is_empty() {
+                    break;
+                }
+            }
+        }
+
+        addrs.sort_by_key(SocketAddr::is_ipv6);
+        addrs.dedup();
+        addrs
+    }
+
+    /// Sends the query to each server in turn (shuffled, IPv4 first) until
+    /// one gives a usable answer.
+    async fn query_servers(
+        &self,
+        lookup: &mut Lookup<'_>,
+        servers: &[SocketAddr],
+        name: &Name,
+        qtype: HickoryRecordType,
+    ) -> Result<Message, DomainError> {
+        let mut order: SmallVec<[SocketAddr; 16]> = servers.iter().copied().collect();
+        fastrand::shuffle(&mut order);
+        order.sort_by_key(SocketAddr::is_ipv6);
+
+        let mut last_error = DomainError::TransportAllServersUnreachable;
+        for server in order {
+            let remaining = lookup.deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(DomainError::QueryTimeout);
+            }
+            if lookup.queries_left == 0 {
+                return Err(DomainError::InvalidDnsResponse(format!(
+                    "Recursion for {} exceeded {} queries",
+                    name, self.config.max_queries
+                )));
+            }
+            lookup.queries_left -= 1;
+
+            match self
+                .exchange(server, name, qtype, lookup.dnssec_ok, remaining)
+                .await
+            {
+                Ok(message)
+                    if matches!(
+                        message.response_code(),
+                        ResponseCode::NoError | ResponseCode::NXDomain
+                    ) =>
+                {
+                    lookup.last_server = Some(server);
+                    return Ok(message);
+                }
+                Ok(message) => {
+                    debug!(server = %server, name = %name, rcode = ?message.response_code(), "Nameserver refused, trying next");
+                    last_error = DomainError::InvalidDnsResponse(format!(
+                        "{} answered {} for {}",
+                        server,
+                        ResponseParser::rcode_to_status(message.response_code()),
+                        name
+                    ));
+                }
+                Err(e) => {
+                    debug!(server = %server, name = %name, error = %e, "Nameserver query failed, trying next");
+                    last_error = e;
+                }
+            }
+        }
+        Err(last_error)
+    }
+
+    /// One query/response exchange with a nameserver, retrying over TCP on
+    /// truncation.
+    async fn exchange(
+        &self,
+        server: SocketAddr,
+        name: &Name,
+        qtype: HickoryRecordType,
+        dnssec_ok: bool,
+        remaining: Duration,
+    ) -> Result<Message, DomainError> {
+        let wire_name = if self.config.case_randomization {
+            randomize_case(name)
+        } else {
+            name.clone()
+        };
+        let query = MessageBuilder::build_iterative_query(&wire_name, qtype, dnssec_ok)?;
+        let addr = UpstreamAddr::Resolved(server);
+        let timeout = remaining.min(SERVER_TIMEOUT);
+
+        let started = Instant::now();
+        let reply = UdpTransport::new(addr.clone())
+            .send(&query, timeout)
+            .await?;
+        let mut message = parse_message(&reply.bytes)?;
+        if message.truncated() {
+            let timeout = remaining.saturating_sub(started.elapsed());
+            let reply = TcpTransport::new(addr).send(&query, timeout).await?;
+            message = parse_message(&reply.bytes)?;
+        }
+
+        self.check_question(&message, &wire_name, qtype, server)?;
+        if self.config.case_randomization {
+            restore_case(&mut message, name);
+        }
+        Ok(message)
+    }
+
+    fn check_question(
+        &self,
+        message: &Message,
+        sent: &Name,
+        qtype: HickoryRecordType,
+        server: SocketAddr,
+    ) -> Result<(), DomainError> {
+        let echoed = message.queries().first().filter(|q| {
+            q.query_type() == qtype
+                && if self.config.case_randomization {
+                    q.name().eq_case(sent)
+                } else {
+                    q.name() == sent
+                }
+        });
+        if message.message_type() != MessageType::Response || echoed.is_none() {
+            warn!(server = %server, name = %sent, "Response question does not match query, discarding");
+            return Err(DomainError::InvalidDnsResponse(format!(
+                "Mismatched question in response from {}",
+                server
+            )));
+        }
+        Ok(())
+    }
+
+    /// Keeps the records owned by names at or below `zone`.
+    fn in_zone(records: Vec<Record>, zone: &Name) -> Vec<Record> {
+        records
+            .into_iter()
+            .filter(|r| zone.zone_of(r.name()))
+            .collect()
+    }
+
+    /// Follows CNAMEs for `start` within `records`. Returns the target that
+    /// still needs resolving, or `None` when the chain is complete.
+    fn chase_cname(records: &[Record], start: &Name, qtype: HickoryRecordType) -> Option<Name> {
+        if matches!(qtype, HickoryRecordType::CNAME | HickoryRecordType::ANY) {
+            return None;
+        }
+
+        let mut name = start.clone();
+        for _ in 0..=MAX_CNAME_HOPS {
+            if records
+                .iter()
+                .any(|r| r.record_type() == qtype && r.name() == &name)
+            {
+                return None;
+            }
+            let target = records.iter().find_map(|r| match r.data() {
+                RData::CNAME(cname) if r.name() == &name => Some(cname.0.clone()),
+                _ => None,
+            });
+            match target {
+                Some(target) => name = target,
+                None => break,
+            }
+        }
+        (name != *start).then_some(name)
+    }
+
+    fn assemble(
+        qname: Name,
+        qtype: HickoryRecordType,
+        rcode: ResponseCode,
+        answers: Vec<Record>,
+        authority: Vec<Record>,
+        dnssec_ok: bool,
+    ) -> Message {
+        let mut message = Message::new(0, MessageType::Response, OpCode::Query);
+        message
+            .set_recursion_desired(true)
+            .set_recursion_available(true)
+            .set_response_code(rcode)
+            .add_query(Query::query(qname, qtype))
+            .add_answers(answers)
+            .add_name_servers(authority);
+        let mut edns = Edns::new();
+        edns.set_max_payload(4096);
+        edns.set_dnssec_ok(dnssec_ok);
+        message.set_edns(edns);
+        message
+    }
+
+    fn record_ip(record: &Record) -> Option<IpAddr> {
+        match record.data() {
+            RData::A(a) => Some(IpAddr::V4(a.0)),
+            RData::AAAA(aaaa) => Some(IpAddr::V6(aaaa.0)),
+            _ => None,
+        }
+    }
+}
+
+fn parse_message(bytes: &[u8]) -> Result<Message, DomainError> {
+    Message::from_vec(bytes).map_err(|e| {
+        DomainError::InvalidDnsResponse(format!("Failed to parse nameserver response: {}", e))
+    })
+}
+
+/// Flips the case of each letter at random ("DNS 0x20"), adding entropy an
+/// off-path attacker has to guess on top of the message ID and port.
+pub fn randomize_case(name: &Name) -> Name {
+    let mixed: String = name
+        .to_ascii()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphabetic() && fastrand::bool() {
+                c.to_ascii_uppercase()
+            } else {
+                c.to_ascii_lowercase()
+            }
+        })
+        .collect();
+    Name::from_ascii(&mixed).unwrap_or_else(|_| name.clone())
+}
+
+/// Puts the original spelling back on records the server echoed with the
+/// randomised case, so clients never see mixed-case owner names.
+fn restore_case(message: &mut Message, name: &Name) {
+    let fix = |records: Vec<Record>| -> Vec<Record> {
+        records
+            .into_iter()
+            .map(|mut r| {
+                if r.name() == name {
+                    r.set_name(name.clone());
+                }
+                r
+            })
+            .collect()
+    };
+    let answers = fix(message.take_answers());
+    let authority = fix(message.take_name_servers());
+    let additionals = fix(message.take_additionals());
+    message.add_answers(answers);
+    message.add_name_servers(authority);
+    message.add_additionals(additionals);
+}
@@ -0,0 +1,7 @@
+pub mod iterative;
+pub mod ns_cache;
+pub mod root_hints;
+
+pub use iterative::{randomize_case, RecursiveAnswer, Recursor};
+pub use ns_cache::{Delegation, NsCache};
+pub use root_hints::root_hint_endpoints;
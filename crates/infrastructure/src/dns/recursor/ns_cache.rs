@@ -0,0 +1,117 @@
+use dashmap::DashMap;
+use hickory_proto::rr::Name;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::trace;
+
+/// Floor and ceiling applied to delegation TTLs so that a zero TTL does not
+/// defeat the cache and a huge one does not pin a stale delegation.
+const MIN_DELEGATION_TTL: u32 = 5;
+const MAX_DELEGATION_TTL: u32 = 86_400;
+
+#[derive(Debug, Clone)]
+struct DelegationEntry {
+    servers: Arc<[SocketAddr]>,
+    expires_at: Instant,
+}
+
+impl DelegationEntry {
+    fn is_expired(&self, now: Instant) -> bool {
+        now >= self.expires_at
+    }
+}
+
+/// A cached zone cut: the zone apex and the addresses of its nameservers.
+#[derive(Debug, Clone)]
+pub struct Delegation {
+    pub zone: Name,
+    pub servers: Arc<[SocketAddr]>,
+}
+
+/// Zone cut → nameserver address cache used by the recursor to skip the
+/// upper levels of the tree on subsequent lookups.
+///
+/// Keys are lowercase, fully-qualified zone names.
+pub struct NsCache {
+    entries: DashMap<Box<str>, DelegationEntry>,
+    max_entries: usize,
+}
+
+impl NsCache {
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            entries: DashMap::new(),
+            max_entries,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn insert(&self, zone: &Name, servers: Vec<SocketAddr>, ttl: u32) {
+        if servers.is_empty() || self.max_entries == 0 {
+            return;
+        }
+        if self.entries.len() >= self.max_entries {
+            self.evict_expired();
+            if self.entries.len() >= self.max_entries {
+                trace!(zone = %zone, "NS cache full, not caching delegation");
+                return;
+            }
+        }
+
+        let ttl = ttl.clamp(MIN_DELEGATION_TTL, MAX_DELEGATION_TTL);
+        self.entries.insert(
+            Self::key(zone),
+            DelegationEntry {
+                servers: Arc::from(servers),
+                expires_at: Instant::now() + Duration::from_secs(u64::from(ttl)),
+            },
+        );
+    }
+
+    /// Returns the deepest cached zone cut enclosing `name`, if any.
+    pub fn closest(&self, name: &Name) -> Option<Delegation> {
+        let now = Instant::now();
+        for labels in (1..=name.num_labels()).rev() {
+            let zone = name.trim_to(labels as usize);
+            let key = Self::key(&zone);
+            let Some(entry) = self.entries.get(key.as_ref()) else {
+                continue;
+            };
+            if entry.is_expired(now) {
+                drop(entry);
+                self.entries.remove(key.as_ref());
+                continue;
+            }
+            return Some(Delegation {
+                zone,
+                servers: Arc::clone(&entry.servers),
+            });
+        }
+        None
+    }
+
+    pub fn clear(&self) {
+        self.entries.clear();
+    }
+
+    fn evict_expired(&self) {
+        let now = Instant::now();
+        self.entries.retain(|_, entry| !entry.is_expired(now));
+    }
+
+    fn key(zone: &Name) -> Box<str> {
+        let mut key = zone.to_lowercase().to_ascii();
+        if !key.ends_with('.') {
+            key.push('.');
+        }
+        key.into_boxed_str()
+    }
+}
@@ -0,0 +1,80 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+/// IANA root server addresses, as published in `named.root`.
+const ROOT_SERVERS: [(&str, Ipv4Addr, Ipv6Addr); 13] = [
+    (
+        "a.root-servers.net",
+        Ipv4Addr::new(198, 41, 0, 4),
+        Ipv6Addr::new(0x2001, 0x503, 0xba3e, 0, 0, 0, 0x2, 0x30),
+    ),
+    (
+        "b.root-servers.net",
+        Ipv4Addr::new(170, 247, 170, 2),
+        Ipv6Addr::new(0x2801, 0x1b8, 0x10, 0, 0, 0, 0, 0xb),
+    ),
+    (
+        "c.root-servers.net",
+        Ipv4Addr::new(192, 33, 4, 12),
+        Ipv6Addr::new(0x2001, 0x500, 0x2, 0, 0, 0, 0, 0xc),
+    ),
+    (
+        "d.root-servers.net",
+        Ipv4Addr::new(199, 7, 91, 13),
+        Ipv6Addr::new(0x2001, 0x500, 0x2d, 0, 0, 0, 0, 0xd),
+    ),
+    (
+        "e.root-servers.net",
+        Ipv4Addr::new(192, 203, 230, 10),
+        Ipv6Addr::new(0x2001, 0x500, 0xa8, 0, 0, 0, 0, 0xe),
+    ),
+    (
+        "f.root-servers.net",
+        Ipv4Addr::new(192, 5, 5, 241),
+        Ipv6Addr::new(0x2001, 0x500, 0x2f, 0, 0, 0, 0, 0xf),
+    ),
+    (
+        "g.root-servers.net",
+        Ipv4Addr::new(192, 112, 36, 4),
+        Ipv6Addr::new(0x2001, 0x500, 0x12, 0, 0, 0, 0, 0xd0d),
+    ),
+    (
+        "h.root-servers.net",
+        Ipv4Addr::new(198, 97, 190, 53),
+        Ipv6Addr::new(0x2001, 0x500, 0x1, 0, 0, 0, 0, 0x53),
+    ),
+    (
+        "i.root-servers.net",
+        Ipv4Addr::new(192, 36, 148, 17),
+        Ipv6Addr::new(0x2001, 0x7fe, 0, 0, 0, 0, 0, 0x53),
+    ),
+    (
+        "j.root-servers.net",
+        Ipv4Addr::new(192, 58, 128, 30),
+        Ipv6Addr::new(0x2001, 0x503, 0xc27, 0, 0, 0, 0x2, 0x30),
+    ),
+    (
+        "k.root-servers.net",
+        Ipv4Addr::new(193, 0, 14, 129),
+        Ipv6Addr::new(0x2001, 0x7fd, 0, 0, 0, 0, 0, 0x1),
+    ),
+    (
+        "l.root-servers.net",
+        Ipv4Addr::new(199, 7, 83, 42),
+        Ipv6Addr::new(0x2001, 0x500, 0x9f, 0, 0, 0, 0, 0x42),
+    ),
+    (
+        "m.root-servers.net",
+        Ipv4Addr::new(202, 12, 27, 33),
+        Ipv6Addr::new(0x2001, 0xdc3, 0, 0, 0, 0, 0, 0x35),
+    ),
+];
+
+/// Built-in root hints as `udp://` endpoint strings, IPv4 first. Used as the
+/// server list of a `Recursive` pool that does not override them.
+pub fn root_hint_endpoints() -> Vec<String> {
+    let v4 = ROOT_SERVERS.iter().map(|(_, v4, _)| IpAddr::V4(*v4));
+    let v6 = ROOT_SERVERS.iter().map(|(_, _, v6)| IpAddr::V6(*v6));
+    v4.chain(v6)
+        .map(|ip| format!("udp://{}", SocketAddr::new(ip, 53)))
+        .collect()
+}
@@ -1,4 +1,6 @@
-use super::super::cache::{DnsCache, NegativeQueryTracker};
+use super::super::authority::{AcmeChallengeTable, LocalAuthority, ViewAuthorities};
+use super::super::cache::{AggressiveNsecCache, DnsCache, NegativeQueryTracker};
+use super::super::dnssec::{NegativeTrustAnchorTable, TrustAnchorStore};
 use super::super::load_balancer::PoolManager;
 use super::super::prefetch::PrefetchPredictor;
 use super::cache_layer::CachedResolver;
@@ -7,6 +9,8 @@ use super::core::CoreResolver;
 use super::dnssec_layer::DnssecResolver;
 use super::filtered_resolver::FilteredResolver;
 use super::filters::QueryFilters;
+use super::forwarding_rules::ForwardingRuleTable;
+use super::local_authority::LocalAuthorityResolver;
 use super::local_ptr::{LocalPtrResolver, PtrMap};
 use ferrous_dns_application::ports::DnsResolver;
 use std::sync::Arc;
@@ -22,6 +26,12 @@ pub struct ResolverBuilder {
     prefetch_predictor: Option<Arc<PrefetchPredictor>>,
     filters: Option<QueryFilters>,
     local_ptr_map: Option<Arc<PtrMap>>,
+    forwarding_rules: Option<Arc<ForwardingRuleTable>>,
+    local_authority: Option<Arc<LocalAuthority>>,
+    view_authorities: Option<Arc<ViewAuthorities>>,
+    acme_challenges: Option<Arc<AcmeChallengeTable>>,
+    trust_anchors: Option<TrustAnchorStore>,
+    negative_trust_anchors: Option<Arc<NegativeTrustAnchorTable>>,
 }
 
 impl ResolverBuilder {
@@ -36,6 +46,12 @@ impl ResolverBuilder {
             prefetch_predictor: None,
             filters: None,
             local_ptr_map: None,
+            forwarding_rules: None,
+            local_authority: None,
+            view_authorities: None,
+            acme_challenges: None,
+            trust_anchors: None,
+            negative_trust_anchors: None,
         }
     }
 
@@ -86,12 +102,56 @@ impl ResolverBuilder {
         self
     }
 
+    /// Routes queries matching a conditional forwarding rule to the rule's
+    /// pool or endpoint instead of the regular pool order.
+    pub fn with_forwarding_rules(mut self, table: Arc<ForwardingRuleTable>) -> Self {
+        self.forwarding_rules = Some(table);
+        self
+    }
+
+    /// Answers names inside local zones authoritatively, ahead of every
+    /// other layer.
+    pub fn with_local_authority(mut self, authority: Arc<LocalAuthority>) -> Self {
+        self.local_authority = Some(authority);
+        self
+    }
+
+    /// Answers names inside a split-horizon view's zones for queries scoped
+    /// to that view, ahead of the global local zones.
+    pub fn with_view_authorities(mut self, views: Arc<ViewAuthorities>) -> Self {
+        self.view_authorities = Some(views);
+        self
+    }
+
+    /// Answers the acme-dns challenge zone authoritatively, alongside the
+    /// local zones.
+    pub fn with_acme_challenges(mut self, table: Arc<AcmeChallengeTable>) -> Self {
+        self.acme_challenges = Some(table);
+        self
+    }
+
+    /// Validates against a shared anchor set instead of the built-in root
+    /// KSK, so RFC 5011 updates take effect without a rebuild.
+    pub fn with_trust_anchors(mut self, store: TrustAnchorStore) -> Self {
+        self.trust_anchors = Some(store);
+        self
+    }
+
+    /// Skips validation for names under the anchors in `table`
+    /// (RFC 7646). The table is read live, so API changes apply at once.
+    pub fn with_negative_trust_anchors(mut self, table: Arc<NegativeTrustAnchorTable>) -> Self {
+        self.negative_trust_anchors = Some(table);
+        self
+    }
+
     pub fn build(self) -> Arc<dyn DnsResolver> {
         info!(
             dnssec = self.config.dnssec_enabled,
             cache = self.cache.is_some(),
+            aggressive_nsec = self.config.aggressive_nsec,
             filters = self.filters.is_some(),
             local_ptr = self.local_ptr_map.is_some(),
+            local_zones = self.local_authority.is_some(),
             "Building DNS resolver"
         );
 
@@ -101,7 +161,8 @@ impl ResolverBuilder {
             self.config.dnssec_enabled,
         )
         .with_local_domain(self.local_domain)
-        .with_local_dns_server(self.local_dns_server);
+        .with_local_dns_server(self.local_dns_server)
+        .with_forwarding_rules(self.forwarding_rules.clone());
 
         let mut resolver: Arc<dyn DnsResolver> = Arc::new(core);
 
@@ -110,10 +171,12 @@ impl ResolverBuilder {
                 .dnssec_pool_manager
                 .clone()
                 .unwrap_or_else(|| self.pool_manager.clone());
-            resolver = Arc::new(DnssecResolver::new(
+            resolver = Arc::new(DnssecResolver::with_anchors(
                 resolver,
                 dnssec_pm,
                 self.config.query_timeout_ms,
+                self.trust_anchors.unwrap_or_default(),
+                self.negative_trust_anchors.unwrap_or_default(),
             ));
         }
 
@@ -132,17 +195,41 @@ impl ResolverBuilder {
                 cached = cached.with_prefetch(predictor);
             }
 
+            if self.config.aggressive_nsec && self.config.dnssec_enabled {
+                cached = cached.with_aggressive_nsec(Arc::new(AggressiveNsecCache::new()));
+            }
+
             resolver = Arc::new(cached);
         }
 
         if let Some(filters) = self.filters {
-            resolver = Arc::new(FilteredResolver::new(resolver, filters));
+            resolver = Arc::new(
+                FilteredResolver::new(resolver, filters)
+                    .with_forwarding_rules(self.forwarding_rules),
+            );
         }
 
         if let Some(map) = self.local_ptr_map {
             resolver = Arc::new(LocalPtrResolver::new(resolver, map));
         }
 
+        if self.local_authority.is_some()
+            || self.view_authorities.is_some()
+            || self.acme_challenges.is_some()
+        {
+            let authority = self
+                .local_authority
+                .unwrap_or_else(|| Arc::new(LocalAuthority::new()));
+            let mut layer = LocalAuthorityResolver::new(resolver, authority);
+            if let Some(views) = self.view_authorities {
+                layer = layer.with_view_authorities(views);
+            }
+            if let Some(table) = self.acme_challenges {
+                layer = layer.with_acme_challenges(table);
+            }
+            resolver = Arc::new(layer);
+        }
+
         info!("DNS resolver built successfully");
         resolver
     }
@@ -1,7 +1,8 @@
 use super::super::cache::key::CacheKey;
 use super::super::cache::negative_cache::clamp_negative_ttl;
 use super::super::cache::{
-    CachedAddresses, CachedData, DnsCacheAccess, DnssecStatus, NegativeQueryTracker,
+    AggressiveNsecCache, CachedAddresses, CachedData, DnsCacheAccess, DnssecStatus,
+    NegativeQueryTracker,
 };
 use super::super::prefetch::PrefetchPredictor;
 use async_trait::async_trait;
@@ -12,7 +13,8 @@ use std::cell::Cell;
 use std::sync::LazyLock;
 
 static EMPTY_ADDRESSES: LazyLock<Arc<Vec<IpAddr>>> = LazyLock::new(|| Arc::new(vec![]));
-use ferrous_dns_domain::{DnsQuery, DomainError, RecordType};
+use ferrous_dns_domain::{DnsQuery, DomainError, QueryScope, RecordType};
+use hickory_proto::op::{Message, ResponseCode};
 use rustc_hash::FxBuildHasher;
 use std::net::IpAddr;
 use std::sync::Arc;
@@ -56,6 +58,7 @@ pub struct CachedResolver {
     cache_ttl: u32,
     negative_ttl_tracker: Arc<NegativeQueryTracker>,
     prefetch_predictor: Option<Arc<PrefetchPredictor>>,
+    aggressive_nsec: Option<Arc<AggressiveNsecCache>>,
     inflight: Arc<DashMap<CacheKey, InflightSender, FxBuildHasher>>,
 }
 
@@ -73,6 +76,7 @@ impl CachedResolver {
             cache_ttl,
             negative_ttl_tracker,
             prefetch_predictor: None,
+            aggressive_nsec: None,
             // In-flight entries are transient — use caller-configured shard count
             // (default = cache_inflight_shards from TOML, typically cpus*2 next_power_of_two).
             inflight: Arc::new(DashMap::with_capacity_and_hasher_and_shard_amount(
@@ -88,9 +92,77 @@ impl CachedResolver {
         self
     }
 
+    /// Answers names inside cached NSEC/NSEC3 ranges without an upstream
+    /// query (RFC 8198). Only useful behind the DNSSEC layer, which marks
+    /// the negative answers whose ranges may be stored.
+    pub fn with_aggressive_nsec(mut self, cache: Arc<AggressiveNsecCache>) -> Self {
+        self.aggressive_nsec = Some(cache);
+        self
+    }
+
+    /// Only the shared partition keeps NSEC ranges: a view or a group with
+    /// its own upstream pools may see a different namespace than everyone else.
+    fn check_aggressive_nsec(&self, query: &DnsQuery) -> Option<DnsResolution> {
+        if !query.scope.is_shared() {
+            return None;
+        }
+        let denial = self
+            .aggressive_nsec
+            .as_ref()?
+            .synthesize(query.domain.as_ref(), query.record_type)?;
+        self.cache.record_aggressive_nsec_hit(denial.kind);
+        Some(DnsResolution {
+            addresses: Arc::clone(&EMPTY_ADDRESSES),
+            cache_hit: true,
+            local_dns: false,
+            dnssec_status: Some(DnssecStatus::Secure.as_str()),
+            cname_chain: Arc::clone(&EMPTY_CNAME_CHAIN),
+            upstream_server: None,
+            upstream_pool: None,
+            min_ttl: Some(denial.ttl),
+            negative_soa_ttl: Some(denial.ttl),
+            upstream_wire_data: Some(Bytes::from(denial.wire)),
+        })
+    }
+
+    /// Keeps the NSEC/NSEC3 ranges of a validated negative answer for
+    /// [`check_aggressive_nsec`](Self::check_aggressive_nsec).
+    fn store_denial(&self, resolution: &DnsResolution) {
+        let Some(aggressive) = &self.aggressive_nsec else {
+            return;
+        };
+        if resolution.dnssec_status != Some(DnssecStatus::Secure.as_str()) {
+            return;
+        }
+        let Some(message) = resolution
+            .upstream_wire_data
+            .as_deref()
+            .and_then(|wire| Message::from_vec(wire).ok())
+        else {
+            return;
+        };
+        let negative = match message.response_code() {
+            ResponseCode::NXDomain => true,
+            ResponseCode::NoError => message.answers().is_empty(),
+            _ => false,
+        };
+        if negative {
+            aggressive.store(&message);
+        }
+    }
+
     fn check_cache_str(&self, domain: &str, record_type: RecordType) -> Option<DnsResolution> {
+        self.check_cache_partitioned(domain, record_type, QueryScope::SHARED)
+    }
+
+    fn check_cache_partitioned(
+        &self,
+        domain: &str,
+        record_type: RecordType,
+        partition: QueryScope,
+    ) -> Option<DnsResolution> {
         self.cache
-            .get(domain, &record_type)
+            .get_partitioned(domain, &record_type, partition)
             .map(|(data, dnssec_status, remaining_ttl)| {
                 let dnssec_str = dnssec_status.map(|s| s.as_str());
                 match data {
@@ -147,14 +219,15 @@ impl CachedResolver {
     }
 
     fn check_cache(&self, query: &DnsQuery) -> Option<DnsResolution> {
-        self.check_cache_str(query.domain.as_ref(), query.record_type)
+        self.check_cache_partitioned(query.domain.as_ref(), query.record_type, query.scope)
     }
 
     fn insert_negative(&self, query: &DnsQuery) {
         let ttl = self.negative_ttl_tracker.record_and_get_ttl(&query.domain);
-        self.cache.insert(
+        self.cache.insert_partitioned(
             query.domain.as_ref(),
             query.record_type,
+            query.scope,
             CachedData::NegativeResponse,
             ttl,
             Some(DnssecStatus::Insecure),
@@ -163,15 +236,19 @@ impl CachedResolver {
 
     fn store_in_cache(&self, query: &DnsQuery, resolution: &DnsResolution) {
         if resolution.addresses.is_empty() {
+            if query.scope.is_shared() {
+                self.store_denial(resolution);
+            }
             if let Some(ref wire_data) = resolution.upstream_wire_data {
                 let ttl = resolution.min_ttl.unwrap_or(self.cache_ttl).max(1);
                 let dnssec_status = resolution
                     .dnssec_status
                     .and_then(|s| s.parse().ok())
                     .unwrap_or(DnssecStatus::Insecure);
-                self.cache.insert(
+                self.cache.insert_partitioned(
                     query.domain.as_ref(),
                     query.record_type,
+                    query.scope,
                     CachedData::WireData(wire_data.clone()),
                     ttl,
                     Some(dnssec_status),
@@ -181,9 +258,10 @@ impl CachedResolver {
                     .negative_soa_ttl
                     .map(clamp_negative_ttl)
                     .unwrap_or_else(|| self.negative_ttl_tracker.record_and_get_ttl(&query.domain));
-                self.cache.insert(
+                self.cache.insert_partitioned(
                     query.domain.as_ref(),
                     query.record_type,
+                    query.scope,
                     CachedData::NegativeResponse,
                     ttl,
                     Some(DnssecStatus::Insecure),
@@ -198,9 +276,10 @@ impl CachedResolver {
 
             let ttl = resolution.min_ttl.unwrap_or(self.cache_ttl);
 
-            self.cache.insert(
+            self.cache.insert_partitioned(
                 query.domain.as_ref(),
                 query.record_type,
+                query.scope,
                 CachedData::IpAddresses(CachedAddresses { addresses }),
                 ttl,
                 Some(dnssec_status),
@@ -216,12 +295,12 @@ impl CachedResolver {
             // per RFC 1035 — response_parser.rs:68-69 computes min across the chain).
             // DNSSEC status: inherited from the qname entry — never elevated.
             //
-            // TODO(bailiwick): RFC 2181 bailiwick check not implemented here —
-            // the response parser currently accepts any CNAME target. If upstream
-            // is untrusted and returns a cross-bailiwick CNAME, caching its target
-            // could poison direct lookups. Mitigation today: DNSSEC validation
-            // (when enabled) marks unverified responses accordingly, and we inherit
-            // that status. Revisit if upstream trust becomes an operational concern.
+            // Bailiwick: the recursor drops answer records outside the zone of
+            // the server that sent them and resolves out-of-zone CNAME targets
+            // itself, so recursive chains are safe to split. Forwarded chains
+            // are trusted as the configured upstream sent them; DNSSEC
+            // validation (when enabled) marks unverified responses accordingly,
+            // and we inherit that status.
             if let Some(final_target) = resolution.cname_chain.last() {
                 let target_name: &str = final_target.as_ref();
                 // Guard against accidental self-loop (qname == final target).
@@ -229,9 +308,10 @@ impl CachedResolver {
                 // case-insensitively to avoid writing an identical entry twice.
                 if !target_name.eq_ignore_ascii_case(query.domain.as_ref()) {
                     let target_addresses = Arc::clone(&resolution.addresses);
-                    self.cache.insert(
+                    self.cache.insert_partitioned(
                         target_name,
                         query.record_type,
+                        query.scope,
                         CachedData::IpAddresses(CachedAddresses {
                             addresses: target_addresses,
                         }),
@@ -446,7 +526,11 @@ impl DnsResolver for CachedResolver {
             };
         }
 
-        let key = CacheKey::new(query.domain.as_ref(), query.record_type);
+        if let Some(synthesized) = self.check_aggressive_nsec(query) {
+            return Ok(synthesized);
+        }
+
+        let key = CacheKey::partitioned(query.domain.as_ref(), query.record_type, query.scope);
         let (is_leader, rx) = self.register_or_join_inflight(&key);
 
         if !is_leader {
@@ -13,6 +13,10 @@ pub struct ResolverConfig {
     pub filters: QueryFiltersConfig,
 
     pub prefetch_enabled: bool,
+
+    /// Synthesise negative answers from validated NSEC/NSEC3 ranges
+    /// (RFC 8198). Needs both the cache and DNSSEC validation.
+    pub aggressive_nsec: bool,
 }
 
 #[derive(Clone)]
@@ -39,6 +43,7 @@ impl Default for ResolverConfig {
             server_hostname,
             filters: QueryFiltersConfig::default(),
             prefetch_enabled: false,
+            aggressive_nsec: false,
         }
     }
 }
@@ -79,6 +84,11 @@ impl ResolverConfig {
         self
     }
 
+    pub fn with_aggressive_nsec(mut self) -> Self {
+        self.aggressive_nsec = true;
+        self
+    }
+
     pub fn with_inflight_shards(mut self, shards: usize) -> Self {
         self.inflight_shards = shards;
         self
@@ -1,5 +1,6 @@
+use super::forwarding_rules::{ForwardingMatch, ForwardingRoute, ForwardingRuleTable};
 use crate::dns::forwarding::DnsForwarder;
-use crate::dns::load_balancer::PoolManager;
+use crate::dns::load_balancer::{PoolManager, UpstreamResult};
 use async_trait::async_trait;
 use ferrous_dns_application::ports::{DnsResolution, DnsResolver, EMPTY_CNAME_CHAIN};
 use ferrous_dns_domain::{DnsQuery, DomainError, PrivateIpFilter};
@@ -12,6 +13,7 @@ pub struct CoreResolver {
     dnssec_enabled: bool,
     local_domain_suffix: Option<(Arc<str>, Arc<str>)>,
     local_dns_server: Option<Arc<str>>,
+    forwarding_rules: Option<Arc<ForwardingRuleTable>>,
 }
 
 impl CoreResolver {
@@ -32,6 +34,7 @@ impl CoreResolver {
             dnssec_enabled,
             local_domain_suffix: None,
             local_dns_server: None,
+            forwarding_rules: None,
         }
     }
 
@@ -49,6 +52,11 @@ impl CoreResolver {
         self
     }
 
+    pub fn with_forwarding_rules(mut self, table: Option<Arc<ForwardingRuleTable>>) -> Self {
+        self.forwarding_rules = table;
+        self
+    }
+
     fn is_local_tld(&self, domain: &str) -> bool {
         let Some((suffix, exact)) = &self.local_domain_suffix else {
             return false;
@@ -104,6 +112,66 @@ impl CoreResolver {
         debug!(domain = %query.domain, "Local TLD query not in cache — returning NXDOMAIN");
         Err(DomainError::NxDomain)
     }
+
+    async fn resolve_forwarded(
+        &self,
+        query: &DnsQuery,
+        rule: ForwardingMatch,
+    ) -> Result<DnsResolution, DomainError> {
+        debug!(
+            domain = %query.domain,
+            rule = %rule.label,
+            "CoreResolver: conditional forwarding rule matched"
+        );
+
+        let result = match &rule.route {
+            ForwardingRoute::Pool(name) => {
+                self.pool_manager
+                    .query_pool(
+                        name,
+                        &query.domain,
+                        &query.record_type,
+                        self.query_timeout_ms,
+                        self.dnssec_enabled,
+                    )
+                    .await?
+            }
+            ForwardingRoute::Server(protocol) => {
+                self.pool_manager
+                    .query_endpoint(
+                        protocol,
+                        &rule.label,
+                        &query.domain,
+                        &query.record_type,
+                        self.query_timeout_ms,
+                        self.dnssec_enabled,
+                    )
+                    .await?
+            }
+        };
+
+        Ok(Self::upstream_resolution(result, rule.label))
+    }
+
+    fn upstream_resolution(result: UpstreamResult, pool: Arc<str>) -> DnsResolution {
+        let cname_chain = result.response.cname_chain;
+        DnsResolution {
+            addresses: Arc::new(result.response.addresses),
+            cache_hit: false,
+            local_dns: false,
+            dnssec_status: None,
+            cname_chain: if cname_chain.is_empty() {
+                Arc::clone(&EMPTY_CNAME_CHAIN)
+            } else {
+                cname_chain.into_iter().collect::<Arc<[_]>>()
+            },
+            upstream_server: Some(result.server_display),
+            upstream_pool: Some(pool),
+            min_ttl: result.response.min_ttl,
+            negative_soa_ttl: result.response.negative_soa_ttl,
+            upstream_wire_data: Some(result.response.raw_bytes),
+        }
+    }
 }
 
 #[async_trait]
@@ -115,6 +183,14 @@ impl DnsResolver for CoreResolver {
             "CoreResolver: performing upstream query"
         );
 
+        if let Some(rule) = self
+            .forwarding_rules
+            .as_ref()
+            .and_then(|t| t.find(&query.domain))
+        {
+            return self.resolve_forwarded(query, rule).await;
+        }
+
         if self.local_dns_server.is_some() && PrivateIpFilter::is_private_ptr_query(&query.domain) {
             return self.resolve_local_tld(query).await;
         }
@@ -130,39 +206,19 @@ impl DnsResolver for CoreResolver {
                 &query.record_type,
                 self.query_timeout_ms,
                 self.dnssec_enabled,
+                query.scope,
             )
             .await?;
 
-        let addresses = Arc::new(result.response.addresses);
-        let upstream_server = Some(result.server_display);
-        let cname_chain = result.response.cname_chain;
-        let min_ttl = result.response.min_ttl;
-        let negative_soa_ttl = result.response.negative_soa_ttl;
-        let raw_bytes = result.response.raw_bytes;
-
         debug!(
             domain = %query.domain,
             record_type = %query.record_type,
-            num_addresses = addresses.len(),
-            upstream = upstream_server.as_deref().unwrap_or("unknown"),
+            num_addresses = result.response.addresses.len(),
+            upstream = %result.server_display,
             "CoreResolver: query successful"
         );
 
-        Ok(DnsResolution {
-            addresses,
-            cache_hit: false,
-            local_dns: false,
-            dnssec_status: None,
-            cname_chain: if cname_chain.is_empty() {
-                Arc::clone(&EMPTY_CNAME_CHAIN)
-            } else {
-                cname_chain.into_iter().collect::<Arc<[_]>>()
-            },
-            upstream_server,
-            upstream_pool: Some(result.pool_name),
-            min_ttl,
-            negative_soa_ttl,
-            upstream_wire_data: Some(raw_bytes),
-        })
+        let pool = Arc::clone(&result.pool_name);
+        Ok(Self::upstream_resolution(result, pool))
     }
 }
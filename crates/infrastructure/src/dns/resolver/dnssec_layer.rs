@@ -1,4 +1,4 @@
-use super::super::dnssec::DnssecValidatorPool;
+use super::super::dnssec::{DnssecValidatorPool, NegativeTrustAnchorTable, TrustAnchorStore};
 use super::super::load_balancer::PoolManager;
 use async_trait::async_trait;
 use ferrous_dns_application::ports::{DnsResolution, DnsResolver};
@@ -17,6 +17,36 @@ impl DnssecResolver {
         inner: Arc<dyn DnsResolver>,
         pool_manager: Arc<PoolManager>,
         query_timeout_ms: u64,
+    ) -> Self {
+        Self::with_trust_store(
+            inner,
+            pool_manager,
+            query_timeout_ms,
+            TrustAnchorStore::new(),
+        )
+    }
+
+    pub fn with_trust_store(
+        inner: Arc<dyn DnsResolver>,
+        pool_manager: Arc<PoolManager>,
+        query_timeout_ms: u64,
+        trust_store: TrustAnchorStore,
+    ) -> Self {
+        Self::with_anchors(
+            inner,
+            pool_manager,
+            query_timeout_ms,
+            trust_store,
+            Arc::new(NegativeTrustAnchorTable::new()),
+        )
+    }
+
+    pub fn with_anchors(
+        inner: Arc<dyn DnsResolver>,
+        pool_manager: Arc<PoolManager>,
+        query_timeout_ms: u64,
+        trust_store: TrustAnchorStore,
+        negative_anchors: Arc<NegativeTrustAnchorTable>,
     ) -> Self {
         let pool_size = std::thread::available_parallelism()
             .map(|n| n.get())
@@ -26,8 +56,10 @@ impl DnssecResolver {
 
         Self {
             inner,
-            validator: Arc::new(DnssecValidatorPool::new(
+            validator: Arc::new(DnssecValidatorPool::with_anchors(
                 pool_manager,
+                trust_store,
+                negative_anchors,
                 query_timeout_ms,
                 pool_size,
             )),
@@ -48,7 +80,9 @@ impl DnsResolver for DnssecResolver {
             return Ok(resolution);
         }
 
-        if resolution.addresses.is_empty() {
+        // Negative answers and non-address types only carry their data in
+        // the wire message; they still need validating (NSEC/NSEC3 proofs).
+        if resolution.addresses.is_empty() && resolution.upstream_wire_data.is_none() {
             return Ok(resolution);
         }
 
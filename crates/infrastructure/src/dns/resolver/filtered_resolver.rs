@@ -1,4 +1,5 @@
 use super::filters::QueryFilters;
+use super::forwarding_rules::ForwardingRuleTable;
 use async_trait::async_trait;
 use ferrous_dns_application::ports::{DnsResolution, DnsResolver};
 use ferrous_dns_domain::{DnsQuery, DomainError, RecordType};
@@ -7,27 +8,55 @@ use std::sync::Arc;
 pub struct FilteredResolver {
     inner: Arc<dyn DnsResolver>,
     filters: QueryFilters,
+    forwarding_rules: Option<Arc<ForwardingRuleTable>>,
 }
 
 impl FilteredResolver {
     pub fn new(inner: Arc<dyn DnsResolver>, filters: QueryFilters) -> Self {
-        Self { inner, filters }
+        Self {
+            inner,
+            filters,
+            forwarding_rules: None,
+        }
+    }
+
+    /// Names covered by an explicit forwarding rule bypass the filters, so a
+    /// rule for e.g. `10.in-addr.arpa` is not dropped as a private PTR query.
+    pub fn with_forwarding_rules(mut self, table: Option<Arc<ForwardingRuleTable>>) -> Self {
+        self.forwarding_rules = table;
+        self
+    }
+
+    fn is_forwarded(&self, domain: &str) -> bool {
+        self.forwarding_rules
+            .as_ref()
+            .is_some_and(|t| t.matches(domain))
     }
 }
 
 #[async_trait]
 impl DnsResolver for FilteredResolver {
     fn try_cache(&self, query: &DnsQuery) -> Option<DnsResolution> {
+        if self.is_forwarded(&query.domain) {
+            return self.inner.try_cache(query);
+        }
         let filtered_query = self.filters.apply(query.clone()).ok()?;
         self.inner.try_cache(&filtered_query)
     }
 
     fn try_cache_str(&self, domain: &str, record_type: RecordType) -> Option<DnsResolution> {
+        if self.is_forwarded(domain) {
+            return self.inner.try_cache_str(domain, record_type);
+        }
         let transformed = self.filters.apply_str(domain)?;
         self.inner.try_cache_str(transformed.as_ref(), record_type)
     }
 
     async fn resolve(&self, query: &DnsQuery) -> Result<DnsResolution, DomainError> {
+        if self.is_forwarded(&query.domain) {
+            return self.inner.resolve(query).await;
+        }
+
         let filtered_query = self.filters.apply(query.clone())?;
 
         self.inner.resolve(&filtered_query).await
@@ -0,0 +1,127 @@
+use arc_swap::ArcSwap;
+use ferrous_dns_application::ports::ForwardingRuleStore;
+use ferrous_dns_domain::{DnsProtocol, ForwardingRule, ForwardingTarget};
+use rustc_hash::FxHashMap;
+use std::borrow::Cow;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+/// Upstream selected by a conditional forwarding rule.
+#[derive(Debug, Clone)]
+pub enum ForwardingRoute {
+    Pool(Arc<str>),
+    Server(Arc<DnsProtocol>),
+}
+
+/// Result of a rule lookup: the route plus the label recorded as
+/// `upstream_pool` in the query log.
+#[derive(Debug, Clone)]
+pub struct ForwardingMatch {
+    pub label: Arc<str>,
+    pub route: ForwardingRoute,
+}
+
+/// Lock-free, hot-swappable suffix table of conditional forwarding rules.
+///
+/// Lookups walk the query name label by label from the full name towards the
+/// root, so the first hit is always the longest matching suffix.
+pub struct ForwardingRuleTable {
+    rules: ArcSwap<FxHashMap<Box<str>, ForwardingMatch>>,
+}
+
+impl Default for ForwardingRuleTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ForwardingRuleTable {
+    pub fn new() -> Self {
+        Self {
+            rules: ArcSwap::from_pointee(FxHashMap::default()),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.rules.load().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.load().is_empty()
+    }
+
+    pub fn find(&self, domain: &str) -> Option<ForwardingMatch> {
+        self.with_match(domain, ForwardingMatch::clone)
+    }
+
+    /// Allocation-free variant of [`find`](Self::find) for callers that only
+    /// need to know whether a rule applies.
+    pub fn matches(&self, domain: &str) -> bool {
+        self.with_match(domain, |_| ()).is_some()
+    }
+
+    fn with_match<R>(&self, domain: &str, f: impl FnOnce(&ForwardingMatch) -> R) -> Option<R> {
+        let rules = self.rules.load();
+        if rules.is_empty() {
+            return None;
+        }
+
+        let normalized: Cow<'_, str> = if domain.bytes().any(|b| b.is_ascii_uppercase()) {
+            Cow::Owned(domain.to_ascii_lowercase())
+        } else {
+            Cow::Borrowed(domain)
+        };
+        let mut name = normalized.trim_end_matches('.');
+
+        loop {
+            if let Some(hit) = rules.get(name) {
+                return Some(f(hit));
+            }
+            match name.split_once('.') {
+                Some((_, parent)) => name = parent,
+                None => return None,
+            }
+        }
+    }
+
+    fn compile(rule: &ForwardingRule) -> Option<ForwardingMatch> {
+        let route = match &rule.target {
+            ForwardingTarget::Pool(name) => ForwardingRoute::Pool(Arc::clone(name)),
+            ForwardingTarget::Server(endpoint) => match endpoint.parse::<DnsProtocol>() {
+                Ok(protocol) => ForwardingRoute::Server(Arc::new(protocol)),
+                Err(e) => {
+                    warn!(
+                        domain = %rule.domain,
+                        endpoint = %endpoint,
+                        error = %e,
+                        "Skipping forwarding rule with invalid endpoint"
+                    );
+                    return None;
+                }
+            },
+        };
+        Some(ForwardingMatch {
+            label: Arc::from(format!("forward:{}", rule.domain)),
+            route,
+        })
+    }
+}
+
+impl ForwardingRuleStore for ForwardingRuleTable {
+    fn replace_rules(&self, rules: &[ForwardingRule]) {
+        let compiled: FxHashMap<Box<str>, ForwardingMatch> = rules
+            .iter()
+            .filter(|r| r.enabled)
+            .filter_map(|r| {
+                let key = ForwardingRule::normalize_domain(&r.domain);
+                Self::compile(r).map(|m| (key.into_boxed_str(), m))
+            })
+            .collect();
+
+        info!(
+            rules = compiled.len(),
+            "Conditional forwarding rules loaded"
+        );
+        self.rules.store(Arc::new(compiled));
+    }
+}
@@ -1,9 +1,12 @@
+use super::super::authority::{AcmeChallengeTable, LocalAuthority, ViewAuthorities};
 use super::super::cache::DnsCache;
+use super::super::dnssec::{NegativeTrustAnchorTable, TrustAnchorStore};
 use super::super::load_balancer::PoolManager;
 use super::super::prefetch::PrefetchPredictor;
 use super::builder::ResolverBuilder;
 use super::config::ResolverConfig;
 use super::filters::QueryFilters;
+use super::forwarding_rules::ForwardingRuleTable;
 use super::local_ptr::PtrMap;
 use async_trait::async_trait;
 use ferrous_dns_application::ports::{DnsResolution, DnsResolver, QueryLogRepository};
@@ -28,6 +31,12 @@ struct BuilderState {
     prefetch_predictor: Option<Arc<PrefetchPredictor>>,
     filters: Option<QueryFilters>,
     local_ptr_map: Option<Arc<PtrMap>>,
+    forwarding_rules: Option<Arc<ForwardingRuleTable>>,
+    local_authority: Option<Arc<LocalAuthority>>,
+    view_authorities: Option<Arc<ViewAuthorities>>,
+    acme_challenges: Option<Arc<AcmeChallengeTable>>,
+    trust_anchors: Option<TrustAnchorStore>,
+    negative_trust_anchors: Option<Arc<NegativeTrustAnchorTable>>,
 }
 
 impl HickoryDnsResolver {
@@ -54,6 +63,12 @@ impl HickoryDnsResolver {
             prefetch_predictor: None,
             filters: None,
             local_ptr_map: None,
+            forwarding_rules: None,
+            local_authority: None,
+            view_authorities: None,
+            acme_challenges: None,
+            trust_anchors: None,
+            negative_trust_anchors: None,
         };
 
         let inner = ResolverBuilder::new(pool_manager)
@@ -85,6 +100,13 @@ impl HickoryDnsResolver {
         self
     }
 
+    /// Enables RFC 8198 aggressive NSEC in the cache layer. Takes effect
+    /// with the next `with_cache` call, like `with_inflight_shards`.
+    pub fn with_aggressive_nsec(mut self, enabled: bool) -> Self {
+        self.builder_state.config.aggressive_nsec = enabled;
+        self
+    }
+
     pub fn with_query_filters(
         mut self,
         block_private_ptr: bool,
@@ -123,6 +145,47 @@ impl HickoryDnsResolver {
         self
     }
 
+    pub fn with_forwarding_rules(mut self, table: Arc<ForwardingRuleTable>) -> Self {
+        self.builder_state.forwarding_rules = Some(table);
+        self.rebuild();
+        self
+    }
+
+    /// Attaches the local zones so that names inside them are answered
+    /// authoritatively without upstream forwarding.
+    pub fn with_local_authority(mut self, authority: Arc<LocalAuthority>) -> Self {
+        self.builder_state.local_authority = Some(authority);
+        self.rebuild();
+        self
+    }
+
+    /// Attaches the zones of each split-horizon view.
+    pub fn with_view_authorities(mut self, views: Arc<ViewAuthorities>) -> Self {
+        self.builder_state.view_authorities = Some(views);
+        self.rebuild();
+        self
+    }
+
+    /// Attaches the acme-dns challenge zone.
+    pub fn with_acme_challenges(mut self, table: Arc<AcmeChallengeTable>) -> Self {
+        self.builder_state.acme_challenges = Some(table);
+        self.rebuild();
+        self
+    }
+
+    /// Shares the live trust anchor set with the DNSSEC validators.
+    pub fn with_trust_anchors(mut self, store: TrustAnchorStore) -> Self {
+        self.builder_state.trust_anchors = Some(store);
+        self.rebuild();
+        self
+    }
+
+    pub fn with_negative_trust_anchors(mut self, table: Arc<NegativeTrustAnchorTable>) -> Self {
+        self.builder_state.negative_trust_anchors = Some(table);
+        self.rebuild();
+        self
+    }
+
     fn rebuild(&mut self) {
         let mut builder = ResolverBuilder::new(self.builder_state.pool_manager.clone())
             .with_config(self.builder_state.config.clone())
@@ -149,6 +212,30 @@ impl HickoryDnsResolver {
             builder = builder.with_local_ptr_map(Arc::clone(map));
         }
 
+        if let Some(table) = &self.builder_state.forwarding_rules {
+            builder = builder.with_forwarding_rules(Arc::clone(table));
+        }
+
+        if let Some(authority) = &self.builder_state.local_authority {
+            builder = builder.with_local_authority(Arc::clone(authority));
+        }
+
+        if let Some(views) = &self.builder_state.view_authorities {
+            builder = builder.with_view_authorities(Arc::clone(views));
+        }
+
+        if let Some(table) = &self.builder_state.acme_challenges {
+            builder = builder.with_acme_challenges(Arc::clone(table));
+        }
+
+        if let Some(store) = &self.builder_state.trust_anchors {
+            builder = builder.with_trust_anchors(store.clone());
+        }
+
+        if let Some(table) = &self.builder_state.negative_trust_anchors {
+            builder = builder.with_negative_trust_anchors(Arc::clone(table));
+        }
+
         self.inner = builder.build();
     }
 }
@@ -0,0 +1,204 @@
+use crate::dns::authority::{
+    AcmeChallengeTable, AuthorityResponse, LocalAuthority, ViewAuthorities,
+};
+use crate::dns::forwarding::RecordTypeMapper;
+use async_trait::async_trait;
+use bytes::Bytes;
+use ferrous_dns_application::ports::{DnsResolution, DnsResolver, EMPTY_CNAME_CHAIN};
+use ferrous_dns_domain::{DnsQuery, DomainError, RecordType};
+use hickory_proto::op::{Message, ResponseCode};
+use hickory_proto::rr::rdata::{A, AAAA};
+use hickory_proto::rr::{Name, RData, Record};
+use std::net::IpAddr;
+use std::sync::Arc;
+use tracing::debug;
+
+/// DNS resolver layer that answers names inside local zones authoritatively.
+///
+/// Queries outside every zone pass straight through to the inner resolver.
+/// In-zone answers are never cached: the zone data is already in memory.
+/// A query scoped to a split-horizon view checks the view's zones first.
+/// The acme-dns challenge zone is answered here too, so CNAMEs from local
+/// zones into it resolve without leaving the server.
+pub struct LocalAuthorityResolver {
+    inner: Arc<dyn DnsResolver>,
+    authority: Arc<LocalAuthority>,
+    views: Arc<ViewAuthorities>,
+    acme_challenges: Option<Arc<AcmeChallengeTable>>,
+}
+
+impl LocalAuthorityResolver {
+    pub fn new(inner: Arc<dyn DnsResolver>, authority: Arc<LocalAuthority>) -> Self {
+        Self {
+            inner,
+            authority,
+            views: Arc::new(ViewAuthorities::default()),
+            acme_challenges: None,
+        }
+    }
+
+    pub fn with_view_authorities(mut self, views: Arc<ViewAuthorities>) -> Self {
+        self.views = views;
+        self
+    }
+
+    pub fn with_acme_challenges(mut self, table: Arc<AcmeChallengeTable>) -> Self {
+        self.acme_challenges = Some(table);
+        self
+    }
+
+    fn acme_answer(
+        &self,
+        domain: &str,
+        record_type: hickory_proto::rr::RecordType,
+    ) -> Option<AuthorityResponse> {
+        self.acme_challenges
+            .as_ref()
+            .and_then(|table| table.answer(domain, record_type))
+    }
+
+    fn published_challenge(
+        &self,
+        domain: &str,
+        record_type: hickory_proto::rr::RecordType,
+    ) -> Option<AuthorityResponse> {
+        self.acme_challenges
+            .as_ref()
+            .filter(|table| table.is_published(domain))
+            .and_then(|table| table.answer(domain, record_type))
+    }
+
+    fn view_authority(&self, view: Option<u32>) -> Option<&LocalAuthority> {
+        view.and_then(|id| self.views.get(&id)).map(|a| &**a)
+    }
+
+    /// Resolves a CNAME target that left the local zones through the inner
+    /// resolver and appends the result to the answer section.
+    async fn chase_external(
+        &self,
+        response: &mut AuthorityResponse,
+        target: Name,
+        original: &DnsQuery,
+    ) {
+        let target_domain = target.to_ascii().to_ascii_lowercase();
+        let target_domain = target_domain.trim_end_matches('.');
+        if let Some(challenge) = self.acme_answer(
+            target_domain,
+            RecordTypeMapper::to_hickory(&original.record_type),
+        ) {
+            response.answers.extend(challenge.answers);
+            if challenge.response_code == ResponseCode::NXDomain {
+                response.response_code = ResponseCode::NXDomain;
+            }
+            return;
+        }
+
+        let query = DnsQuery::new(Arc::from(target_domain), original.record_type)
+            .with_scope(original.scope);
+        match self.inner.resolve(&query).await {
+            Ok(resolution) if !resolution.addresses.is_empty() => {
+                let ttl = resolution.min_ttl.unwrap_or(60);
+                response
+                    .answers
+                    .extend(resolution.addresses.iter().map(|addr| {
+                        let rdata = match *addr {
+                            IpAddr::V4(ip) => RData::A(A(ip)),
+                            IpAddr::V6(ip) => RData::AAAA(AAAA(ip)),
+                        };
+                        Record::from_rdata(target.clone(), ttl, rdata)
+                    }));
+            }
+            Ok(resolution) => {
+                if let Some(message) = resolution
+                    .upstream_wire_data
+                    .as_deref()
+                    .and_then(|wire| Message::from_vec(wire).ok())
+                {
+                    response.answers.extend(message.answers().iter().cloned());
+                }
+            }
+            Err(DomainError::NxDomain) | Err(DomainError::LocalNxDomain) => {
+                response.response_code = ResponseCode::NXDomain;
+            }
+            Err(e) => {
+                debug!(target = %target, error = %e, "Local zone CNAME target did not resolve");
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl DnsResolver for LocalAuthorityResolver {
+    fn try_cache(&self, query: &DnsQuery) -> Option<DnsResolution> {
+        if self.authority.contains(&query.domain)
+            || self
+                .view_authority(query.scope.view)
+                .is_some_and(|view| view.contains(&query.domain))
+            || self
+                .acme_challenges
+                .as_ref()
+                .is_some_and(|table| table.contains(&query.domain))
+        {
+            return None;
+        }
+        self.inner.try_cache(query)
+    }
+
+    fn try_cache_str(&self, domain: &str, record_type: RecordType) -> Option<DnsResolution> {
+        if self.authority.contains(domain)
+            || self
+                .acme_challenges
+                .as_ref()
+                .is_some_and(|table| table.contains(domain))
+        {
+            return None;
+        }
+        self.inner.try_cache_str(domain, record_type)
+    }
+
+    async fn resolve(&self, query: &DnsQuery) -> Result<DnsResolution, DomainError> {
+        let hickory_type = RecordTypeMapper::to_hickory(&query.record_type);
+        // A pending DNS-01 record may sit inside a local zone, so it wins.
+        let Some(mut response) = self
+            .published_challenge(&query.domain, hickory_type)
+            .or_else(|| {
+                self.view_authority(query.scope.view)
+                    .and_then(|view| view.answer(&query.domain, hickory_type))
+            })
+            .or_else(|| self.authority.answer(&query.domain, hickory_type))
+            .or_else(|| self.acme_answer(&query.domain, hickory_type))
+        else {
+            return self.inner.resolve(query).await;
+        };
+
+        if let Some(target) = response.external_target.take() {
+            self.chase_external(&mut response, target, query).await;
+        }
+
+        debug!(
+            domain = %query.domain,
+            rcode = %response.response_code,
+            answers = response.answers.len(),
+            "LocalAuthorityResolver: answered from local zone"
+        );
+
+        let name = Name::from_ascii(format!("{}.", query.domain))
+            .map_err(|e| DomainError::InvalidDomainName(e.to_string()))?;
+        let wire = response.to_wire(&name, hickory_type).ok_or_else(|| {
+            DomainError::InvalidDnsResponse("failed to encode zone answer".into())
+        })?;
+
+        Ok(DnsResolution {
+            addresses: Arc::new(Vec::new()),
+            cache_hit: false,
+            local_dns: true,
+            dnssec_status: None,
+            cname_chain: Arc::clone(&EMPTY_CNAME_CHAIN),
+            upstream_server: None,
+            upstream_pool: None,
+            min_ttl: response.min_ttl(),
+            negative_soa_ttl: response.negative_soa_ttl(),
+            upstream_wire_data: Some(Bytes::from(wire)),
+        })
+    }
+}
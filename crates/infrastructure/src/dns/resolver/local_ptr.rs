@@ -81,6 +81,10 @@ impl PtrRecordRegistry for LocalPtrResolver {
     fn unregister(&self, ip: IpAddr) {
         self.map.remove(&ip);
     }
+
+    fn lookup(&self, ip: IpAddr) -> Option<Arc<str>> {
+        self.map.get(&ip).map(|entry| entry.0.clone())
+    }
 }
 
 #[async_trait]
@@ -5,7 +5,9 @@ pub mod core;
 pub mod dnssec_layer;
 pub mod filtered_resolver;
 pub mod filters;
+pub mod forwarding_rules;
 pub mod legacy;
+pub mod local_authority;
 pub mod local_ptr;
 
 pub use builder::ResolverBuilder;
@@ -15,5 +17,7 @@ pub use core::CoreResolver;
 pub use dnssec_layer::DnssecResolver;
 pub use filtered_resolver::FilteredResolver;
 pub use filters::QueryFilters;
+pub use forwarding_rules::{ForwardingMatch, ForwardingRoute, ForwardingRuleTable};
 pub use legacy::HickoryDnsResolver;
+pub use local_authority::LocalAuthorityResolver;
 pub use local_ptr::LocalPtrResolver;
@@ -0,0 +1,3 @@
+mod table;
+
+pub use table::DnsRewriteTable;
@@ -0,0 +1,139 @@
+use arc_swap::ArcSwap;
+use ferrous_dns_application::ports::{DnsRewrite, DnsRewriteStore};
+use ferrous_dns_domain::{DnsRewriteAnswer, DnsRewriteRcode, DnsRewriteRule, RecordType};
+use rustc_hash::FxHashMap;
+use std::borrow::Cow;
+use std::net::IpAddr;
+use std::sync::Arc;
+use tracing::info;
+
+/// All rewrites configured for one name in one group.
+#[derive(Debug, Default)]
+struct RewriteSet {
+    v4: Vec<IpAddr>,
+    v6: Vec<IpAddr>,
+    cname: Option<Arc<str>>,
+    rcode: Option<DnsRewriteRcode>,
+}
+
+impl RewriteSet {
+    fn add(&mut self, answer: &DnsRewriteAnswer) {
+        match answer {
+            DnsRewriteAnswer::Address(ip @ IpAddr::V4(_)) => self.v4.push(*ip),
+            DnsRewriteAnswer::Address(ip @ IpAddr::V6(_)) => self.v6.push(*ip),
+            DnsRewriteAnswer::Cname(target) => {
+                self.cname.get_or_insert_with(|| Arc::clone(target));
+            }
+            DnsRewriteAnswer::Rcode(rcode) => {
+                self.rcode.get_or_insert(*rcode);
+            }
+        }
+    }
+
+    fn rewrite(&self, record_type: RecordType) -> DnsRewrite {
+        if let Some(rcode) = self.rcode {
+            return DnsRewrite::Rcode(rcode);
+        }
+        if let Some(ref target) = self.cname {
+            return DnsRewrite::Cname(Arc::clone(target));
+        }
+        match record_type {
+            RecordType::A => DnsRewrite::Addresses(self.v4.clone()),
+            RecordType::AAAA => DnsRewrite::Addresses(self.v6.clone()),
+            _ => DnsRewrite::Addresses(Vec::new()),
+        }
+    }
+}
+
+type RewriteIndex = FxHashMap<Box<str>, FxHashMap<i64, RewriteSet>>;
+
+/// Lock-free, hot-swappable suffix table of DNS rewrite rules.
+///
+/// Lookups walk the query name label by label towards the root, so the
+/// rules on the longest matching suffix for the client's group apply.
+pub struct DnsRewriteTable {
+    rules: ArcSwap<RewriteIndex>,
+}
+
+impl Default for DnsRewriteTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DnsRewriteTable {
+    pub fn new() -> Self {
+        Self {
+            rules: ArcSwap::from_pointee(FxHashMap::default()),
+        }
+    }
+
+    /// Number of distinct rewritten names.
+    pub fn len(&self) -> usize {
+        self.rules.load().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.load().is_empty()
+    }
+
+    fn with_match<R>(
+        &self,
+        domain: &str,
+        group_id: i64,
+        f: impl FnOnce(&RewriteSet) -> R,
+    ) -> Option<R> {
+        let rules = self.rules.load();
+        if rules.is_empty() {
+            return None;
+        }
+
+        let normalized: Cow<'_, str> = if domain.bytes().any(|b| b.is_ascii_uppercase()) {
+            Cow::Owned(domain.to_ascii_lowercase())
+        } else {
+            Cow::Borrowed(domain)
+        };
+        let mut name = normalized.trim_end_matches('.');
+
+        loop {
+            if let Some(set) = rules.get(name).and_then(|groups| groups.get(&group_id)) {
+                return Some(f(set));
+            }
+            match name.split_once('.') {
+                Some((_, parent)) => name = parent,
+                None => return None,
+            }
+        }
+    }
+}
+
+impl DnsRewriteStore for DnsRewriteTable {
+    fn replace_rules(&self, rules: &[DnsRewriteRule]) {
+        let mut compiled: RewriteIndex = FxHashMap::default();
+        for rule in rules.iter().filter(|r| r.enabled) {
+            let key = DnsRewriteRule::normalize_domain(&rule.domain).into_boxed_str();
+            compiled
+                .entry(key)
+                .or_default()
+                .entry(rule.group_id)
+                .or_default()
+                .add(&rule.answer);
+        }
+
+        info!(names = compiled.len(), "DNS rewrite rules loaded");
+        self.rules.store(Arc::new(compiled));
+    }
+
+    fn rewrite_for(
+        &self,
+        domain: &str,
+        record_type: RecordType,
+        group_id: i64,
+    ) -> Option<DnsRewrite> {
+        self.with_match(domain, group_id, |set| set.rewrite(record_type))
+    }
+
+    fn has_rewrite(&self, domain: &str, group_id: i64) -> bool {
+        self.with_match(domain, group_id, |_| ()).is_some()
+    }
+}
@@ -1,29 +1,88 @@
+use crate::dns::dnstap::{DnstapWriter, SocketProtocol};
+use crate::dns::dynamic_update::DynamicUpdateHandler;
 use crate::dns::ede::{self, ExtendedDnsError};
 use crate::dns::forwarding::RecordTypeMapper;
 use bytes::Bytes;
+use ferrous_dns_application::services::ClientTransport;
 use ferrous_dns_application::use_cases::HandleDnsQueryUseCase;
-use ferrous_dns_domain::{DomainError, RecordType};
+use ferrous_dns_domain::{BlockingMode, BlockingResponse, DomainError, RecordType};
 use hickory_proto::op::{Edns, Message, MessageType, OpCode, ResponseCode};
 use hickory_proto::rr::rdata::opt::EdnsOption;
-use hickory_proto::rr::{RData, Record};
+use hickory_proto::rr::rdata::SOA;
+use hickory_proto::rr::{Name, RData, Record};
 use hickory_proto::serialize::binary::{BinEncodable, BinEncoder};
 use hickory_server::authority::MessageResponseBuilder;
 use hickory_server::server::{Request, RequestHandler, ResponseHandler, ResponseInfo};
 use std::borrow::Cow;
 use std::net::IpAddr;
 use std::sync::Arc;
+use std::time::SystemTime;
 use tracing::{debug, error, warn};
 
 const DEFAULT_TTL: u32 = 60;
+const BLOCKED_SOA_MNAME: &str = "localhost.";
+const BLOCKED_SOA_RNAME: &str = "hostmaster.localhost.";
+const BLOCKED_SOA_REFRESH: i32 = 3600;
+const BLOCKED_SOA_RETRY: i32 = 600;
+const BLOCKED_SOA_EXPIRE: i32 = 86400;
 
 #[derive(Clone)]
 pub struct DnsServerHandler {
     use_case: Arc<HandleDnsQueryUseCase>,
+    dynamic_update: Option<Arc<DynamicUpdateHandler>>,
+    dnstap: Option<Arc<DnstapWriter>>,
 }
 
 impl DnsServerHandler {
     pub fn new(use_case: Arc<HandleDnsQueryUseCase>) -> Self {
-        Self { use_case }
+        Self {
+            use_case,
+            dynamic_update: None,
+            dnstap: None,
+        }
+    }
+
+    /// Accepts RFC 2136 UPDATE messages. Without it, UPDATE is answered
+    /// with NOTIMP like any other non-query opcode.
+    pub fn with_dynamic_update(mut self, handler: Option<Arc<DynamicUpdateHandler>>) -> Self {
+        self.dynamic_update = handler;
+        self
+    }
+
+    /// Writes client exchanges to dnstap. The transports call
+    /// [`Self::tap_client_exchange`] with the bytes they received and sent.
+    pub fn with_dnstap(mut self, dnstap: Option<Arc<DnstapWriter>>) -> Self {
+        self.dnstap = dnstap;
+        self
+    }
+
+    /// Returns the time a client query was received, or `None` when client
+    /// exchanges are not tapped.
+    pub fn dnstap_query_time(&self) -> Option<SystemTime> {
+        self.dnstap.as_ref()?.client_query_time()
+    }
+
+    /// Emits a client query and the response sent for it. A no-op when
+    /// `query_time` is `None`.
+    pub fn tap_client_exchange(
+        &self,
+        protocol: SocketProtocol,
+        client_ip: IpAddr,
+        client_port: Option<u16>,
+        query: &[u8],
+        response: &[u8],
+        query_time: Option<SystemTime>,
+    ) {
+        if let (Some(dnstap), Some(query_time)) = (&self.dnstap, query_time) {
+            dnstap.client_exchange(
+                protocol,
+                client_ip,
+                client_port,
+                query,
+                response,
+                query_time,
+            );
+        }
     }
 
     /// Normalizes a domain received from Hickory for downstream use: strips the
@@ -40,6 +99,11 @@ impl DnsServerHandler {
         }
     }
 
+    /// Counts client queries read off the wire, before any parsing.
+    pub fn record_received(&self, transport: ClientTransport, messages: u64) {
+        self.use_case.metrics().record_received(transport, messages);
+    }
+
     pub fn try_fast_path(
         &self,
         domain: &str,
@@ -65,6 +129,14 @@ impl DnsServerHandler {
     pub async fn handle_raw_udp_fallback(&self, raw: &[u8], client_ip: IpAddr) -> Option<Vec<u8>> {
         let query_msg = Message::from_vec(raw).ok()?;
 
+        if query_msg.op_code() != OpCode::Query {
+            if let (OpCode::Update, Some(updates)) = (query_msg.op_code(), &self.dynamic_update) {
+                return updates.handle(raw, &query_msg, client_ip).await;
+            }
+            debug!(op_code = ?query_msg.op_code(), client = %client_ip, "Unsupported opcode");
+            return build_not_implemented_wire(&query_msg);
+        }
+
         let queries: Vec<_> = query_msg.queries().to_vec();
         let query_info = queries.first()?;
 
@@ -95,8 +167,25 @@ impl DnsServerHandler {
 
         let resolution = match self.use_case.execute(&dns_request).await {
             Ok(res) => res,
-            Err(ref e @ DomainError::Blocked)
-            | Err(ref e @ DomainError::DgaDomainDetected)
+            Err(ref e @ DomainError::Blocked) => {
+                let blocked = blocked_answer(
+                    self.use_case.blocking_response(client_ip),
+                    query_info.name(),
+                    hickory_rt,
+                    self.use_case.blocked_ttl(),
+                );
+                return build_answer_wire(
+                    query_id,
+                    rd,
+                    &queries,
+                    blocked.code,
+                    blocked.answer.as_slice(),
+                    blocked.authority.as_slice(),
+                    has_edns,
+                    ede::from_domain_error(e),
+                );
+            }
+            Err(ref e @ DomainError::DgaDomainDetected)
             | Err(ref e @ DomainError::DnsTunnelingDetected)
             | Err(ref e @ DomainError::DnsRateLimited)
             | Err(ref e @ DomainError::FilteredQuery(_)) => {
@@ -165,6 +254,9 @@ impl DnsServerHandler {
                     match Message::from_vec(wire_data) {
                         Ok(upstream_msg) => {
                             resp.set_response_code(upstream_msg.response_code());
+                            resp.set_authoritative(
+                                resolution.local_dns && upstream_msg.authoritative(),
+                            );
                             for record in upstream_msg.answers() {
                                 resp.add_answer(record.clone());
                             }
@@ -296,10 +388,18 @@ impl RequestHandler for DnsServerHandler {
             Ok(res) => res,
             Err(ref e @ DomainError::Blocked) => {
                 warn!(domain = %domain_ref, "Domain blocked");
-                return send_error_response(
+                let blocked = blocked_answer(
+                    self.use_case.blocking_response(client_ip),
+                    &query.name().clone().into(),
+                    hickory_record_type,
+                    self.use_case.blocked_ttl(),
+                );
+                return send_answer_response(
                     request,
                     &mut response_handle,
-                    ResponseCode::Refused,
+                    blocked.code,
+                    blocked.answer.as_slice(),
+                    blocked.authority.as_slice(),
                     ede::from_domain_error(e),
                 )
                 .await;
@@ -392,6 +492,8 @@ impl RequestHandler for DnsServerHandler {
                     let mut header = *request.header();
                     header.set_message_type(MessageType::Response);
                     header.set_recursion_available(true);
+                    header.set_response_code(message.response_code());
+                    header.set_authoritative(resolution.local_dns && message.authoritative());
                     let response = builder.build(
                         header,
                         answers.iter(),
@@ -471,6 +573,79 @@ fn encode_message(msg: &Message) -> Option<Vec<u8>> {
     Some(buf)
 }
 
+/// Response sent for a blocked query.
+#[derive(Debug, Clone)]
+pub struct BlockedAnswer {
+    pub code: ResponseCode,
+    pub answer: Option<Record>,
+    /// Synthetic SOA for NXDOMAIN/NODATA so resolvers can cache the negative
+    /// answer for the blocked TTL (RFC 2308 §3).
+    pub authority: Option<Record>,
+}
+
+/// Maps a blocking response to the rcode and optional A/AAAA answer sent for
+/// a blocked query. Non-address query types get NODATA in the IP modes.
+pub fn blocked_answer(
+    response: BlockingResponse,
+    name: &Name,
+    record_type: hickory_proto::rr::RecordType,
+    ttl: u32,
+) -> BlockedAnswer {
+    let rdata = match (response.mode, record_type) {
+        (BlockingMode::Refused, _) => {
+            return BlockedAnswer {
+                code: ResponseCode::Refused,
+                answer: None,
+                authority: None,
+            }
+        }
+        (BlockingMode::Nxdomain, _) => {
+            return BlockedAnswer {
+                code: ResponseCode::NXDomain,
+                answer: None,
+                authority: Some(blocked_soa(name, ttl)),
+            }
+        }
+        (_, hickory_proto::rr::RecordType::A) => response
+            .answer_ipv4()
+            .map(|ip| RData::A(hickory_proto::rr::rdata::A(ip))),
+        (_, hickory_proto::rr::RecordType::AAAA) => response
+            .answer_ipv6()
+            .map(|ip| RData::AAAA(hickory_proto::rr::rdata::AAAA(ip))),
+        _ => None,
+    };
+    match rdata {
+        Some(rdata) => BlockedAnswer {
+            code: ResponseCode::NoError,
+            answer: Some(Record::from_rdata(name.clone(), ttl, rdata)),
+            authority: None,
+        },
+        None => BlockedAnswer {
+            code: ResponseCode::NoError,
+            answer: None,
+            authority: Some(blocked_soa(name, ttl)),
+        },
+    }
+}
+
+fn blocked_soa(name: &Name, ttl: u32) -> Record {
+    let mname = Name::from_ascii(BLOCKED_SOA_MNAME).unwrap_or_else(|_| Name::root());
+    let rname = Name::from_ascii(BLOCKED_SOA_RNAME).unwrap_or_else(|_| Name::root());
+    Record::from_rdata(
+        name.clone(),
+        ttl,
+        RData::SOA(SOA::new(
+            mname,
+            rname,
+            1,
+            BLOCKED_SOA_REFRESH,
+            BLOCKED_SOA_RETRY,
+            BLOCKED_SOA_EXPIRE,
+            ttl,
+        )),
+    )
+}
+
 fn build_error_wire(
     id: u16,
     rd: bool,
@@ -478,6 +653,20 @@ fn build_error_wire(
     code: ResponseCode,
     has_edns: bool,
     ede: Option<ExtendedDnsError>,
+) -> Option<Vec<u8>> {
+    build_answer_wire(id, rd, queries, code, &[], &[], has_edns, ede)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn build_answer_wire(
+    id: u16,
+    rd: bool,
+    queries: &[hickory_proto::op::Query],
+    code: ResponseCode,
+    answers: &[Record],
+    authority: &[Record],
+    has_edns: bool,
+    ede: Option<ExtendedDnsError>,
 ) -> Option<Vec<u8>> {
     let mut resp = Message::new(id, MessageType::Response, OpCode::Query);
     resp.set_recursion_desired(rd);
@@ -486,6 +675,10 @@ fn build_error_wire(
     for q in queries {
         resp.add_query(q.clone());
     }
+    for answer in answers {
+        resp.add_answer(answer.clone());
+    }
+    resp.add_name_servers(authority.iter().cloned());
     if has_edns {
         let mut edns = Edns::new();
         edns.set_max_payload(4096);
@@ -504,6 +697,15 @@ fn build_error_wire(
     encode_message(&resp)
 }
 
+fn build_not_implemented_wire(request: &Message) -> Option<Vec<u8>> {
+    let mut resp = Message::new(request.id(), MessageType::Response, request.op_code());
+    resp.set_response_code(ResponseCode::NotImp);
+    for q in request.queries() {
+        resp.add_query(q.clone());
+    }
+    encode_message(&resp)
+}
+
 fn build_truncated_wire(
     id: u16,
     rd: bool,
@@ -546,7 +748,18 @@ async fn send_error_response<R: ResponseHandler>(
     code: ResponseCode,
     ede: Option<ExtendedDnsError>,
 ) -> ResponseInfo {
-    debug!(code = ?code, "Sending error response");
+    send_answer_response(request, response_handle, code, &[], &[], ede).await
+}
+
+async fn send_answer_response<R: ResponseHandler>(
+    request: &Request,
+    response_handle: &mut R,
+    code: ResponseCode,
+    answers: &[Record],
+    authority: &[Record],
+    ede: Option<ExtendedDnsError>,
+) -> ResponseInfo {
+    debug!(code = ?code, answers = answers.len(), "Sending response");
     let mut builder = MessageResponseBuilder::from_message_request(request);
     let mut header = *request.header();
     header.set_message_type(MessageType::Response);
@@ -569,7 +782,7 @@ async fn send_error_response<R: ResponseHandler>(
         }
     }
 
-    let response = builder.build(header, &[], &[], &[], &[]);
+    let response = builder.build(header, answers.iter(), authority.iter(), &[], &[]);
     match response_handle.send_response(response).await {
         Ok(info) => info,
         Err(e) => {
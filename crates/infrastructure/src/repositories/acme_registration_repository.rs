@@ -0,0 +1,175 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::AcmeRegistrationRepository;
+use ferrous_dns_domain::{AcmeRegistration, DomainError, NTA_TIMESTAMP_FORMAT};
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use tracing::{error, instrument, warn};
+
+type AcmeRegistrationRow = (i64, String, String, String, String, String, String, String);
+
+const ACME_REGISTRATION_COLUMNS: &str =
+    "id, username, password_hash, subdomain, allow_from, txt, created_at, updated_at";
+
+pub struct SqliteAcmeRegistrationRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteAcmeRegistrationRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_registration(row: AcmeRegistrationRow) -> AcmeRegistration {
+        let (id, username, password_hash, subdomain, allow_from, txt, created_at, updated_at) = row;
+        AcmeRegistration {
+            id: Some(id),
+            username: Arc::from(username.as_str()),
+            password_hash: Arc::from(password_hash.as_str()),
+            subdomain: Arc::from(subdomain.as_str()),
+            allow_from: Self::decode_list(id, "allow_from", &allow_from),
+            txt: Self::decode_list(id, "txt", &txt),
+            created_at: Some(created_at),
+            updated_at: Some(updated_at),
+        }
+    }
+
+    fn decode_list(id: i64, column: &str, json: &str) -> Vec<Arc<str>> {
+        serde_json::from_str::<Vec<String>>(json)
+            .map_err(|e| warn!(registration_id = id, column, error = %e, "Ignoring stored list"))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|value| Arc::from(value.as_str()))
+            .collect()
+    }
+
+    fn encode_list(values: &[Arc<str>]) -> Result<String, DomainError> {
+        let values: Vec<&str> = values.iter().map(|v| v.as_ref()).collect();
+        serde_json::to_string(&values).map_err(|e| DomainError::DatabaseError(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl AcmeRegistrationRepository for SqliteAcmeRegistrationRepository {
+    #[instrument(skip(self, password_hash))]
+    async fn create(
+        &self,
+        username: &str,
+        password_hash: &str,
+        subdomain: &str,
+        allow_from: &[Arc<str>],
+    ) -> Result<AcmeRegistration, DomainError> {
+        let now = chrono::Utc::now().format(NTA_TIMESTAMP_FORMAT).to_string();
+
+        let row = sqlx::query_as::<_, AcmeRegistrationRow>(&format!(
+            "INSERT INTO acme_registrations
+                 (username, password_hash, subdomain, allow_from, txt, created_at, updated_at)
+             VALUES (?, ?, ?, ?, '[]', ?, ?)
+             RETURNING {ACME_REGISTRATION_COLUMNS}"
+        ))
+        .bind(username)
+        .bind(password_hash)
+        .bind(subdomain)
+        .bind(Self::encode_list(allow_from)?)
+        .bind(&now)
+        .bind(&now)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to create ACME registration");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(Self::row_to_registration(row))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_by_id(&self, id: i64) -> Result<Option<AcmeRegistration>, DomainError> {
+        let row = sqlx::query_as::<_, AcmeRegistrationRow>(&format!(
+            "SELECT {ACME_REGISTRATION_COLUMNS} FROM acme_registrations WHERE id = ?"
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to query ACME registration by id");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(row.map(Self::row_to_registration))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_by_username(
+        &self,
+        username: &str,
+    ) -> Result<Option<AcmeRegistration>, DomainError> {
+        let row = sqlx::query_as::<_, AcmeRegistrationRow>(&format!(
+            "SELECT {ACME_REGISTRATION_COLUMNS} FROM acme_registrations WHERE username = ?"
+        ))
+        .bind(username)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to query ACME registration by username");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(row.map(Self::row_to_registration))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_all(&self) -> Result<Vec<AcmeRegistration>, DomainError> {
+        let rows = sqlx::query_as::<_, AcmeRegistrationRow>(&format!(
+            "SELECT {ACME_REGISTRATION_COLUMNS} FROM acme_registrations ORDER BY id ASC"
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to query all ACME registrations");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(rows.into_iter().map(Self::row_to_registration).collect())
+    }
+
+    #[instrument(skip(self, txt))]
+    async fn set_txt(&self, id: i64, txt: &[Arc<str>]) -> Result<(), DomainError> {
+        let now = chrono::Utc::now().format(NTA_TIMESTAMP_FORMAT).to_string();
+
+        let result =
+            sqlx::query("UPDATE acme_registrations SET txt = ?, updated_at = ? WHERE id = ?")
+                .bind(Self::encode_list(txt)?)
+                .bind(&now)
+                .bind(id)
+                .execute(&self.pool)
+                .await
+                .map_err(|e| {
+                    error!(error = %e, "Failed to update ACME challenge TXT");
+                    DomainError::DatabaseError(e.to_string())
+                })?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::AcmeRegistrationNotFound(id));
+        }
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(&self, id: i64) -> Result<(), DomainError> {
+        let result = sqlx::query("DELETE FROM acme_registrations WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to delete ACME registration");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::AcmeRegistrationNotFound(id));
+        }
+
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
+use super::list_source_fetches;
 use async_trait::async_trait;
 use ferrous_dns_application::ports::BlocklistSourceRepository;
-use ferrous_dns_domain::{BlocklistSource, DomainError};
+use ferrous_dns_domain::{BlocklistSource, DomainError, ListSourceStatus};
 use sqlx::{Row, SqlitePool};
 use std::sync::Arc;
 use tracing::{error, instrument};
@@ -11,6 +12,7 @@ type BlocklistSourceRow = (
     Option<String>,
     Option<String>,
     i64,
+    i64,
     String,
     String,
 );
@@ -24,8 +26,12 @@ impl SqliteBlocklistSourceRepository {
         Self { pool }
     }
 
-    fn row_to_source(row: BlocklistSourceRow, group_ids: Vec<i64>) -> BlocklistSource {
-        let (id, name, url, comment, enabled, created_at, updated_at) = row;
+    fn row_to_source(
+        row: BlocklistSourceRow,
+        group_ids: Vec<i64>,
+        status: Option<ListSourceStatus>,
+    ) -> BlocklistSource {
+        let (id, name, url, comment, enabled, observe_only, created_at, updated_at) = row;
         BlocklistSource {
             id: Some(id),
             name: Arc::from(name.as_str()),
@@ -33,8 +39,10 @@ impl SqliteBlocklistSourceRepository {
             group_ids,
             comment: comment.map(|s| Arc::from(s.as_str())),
             enabled: enabled != 0,
+            observe_only: observe_only != 0,
             created_at: Some(created_at),
             updated_at: Some(updated_at),
+            status,
         }
     }
 
@@ -64,6 +72,7 @@ impl BlocklistSourceRepository for SqliteBlocklistSourceRepository {
         group_ids: Vec<i64>,
         comment: Option<String>,
         enabled: bool,
+        observe_only: bool,
     ) -> Result<BlocklistSource, DomainError> {
         let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
 
@@ -76,15 +85,16 @@ impl BlocklistSourceRepository for SqliteBlocklistSourceRepository {
         })?;
 
         let row = sqlx::query_as::<_, BlocklistSourceRow>(
-            "INSERT INTO blocklist_sources (name, url, group_id, comment, enabled, created_at, updated_at)
-             VALUES (?, ?, ?, ?, ?, ?, ?)
-             RETURNING id, name, url, comment, enabled, created_at, updated_at",
+            "INSERT INTO blocklist_sources (name, url, group_id, comment, enabled, observe_only, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+             RETURNING id, name, url, comment, enabled, observe_only, created_at, updated_at",
         )
         .bind(&name)
         .bind(&url)
         .bind(legacy_group_id)
         .bind(&comment)
         .bind(if enabled { 1i64 } else { 0i64 })
+        .bind(if observe_only { 1i64 } else { 0i64 })
         .bind(&now)
         .bind(&now)
         .fetch_one(&mut *tx)
@@ -120,13 +130,13 @@ impl BlocklistSourceRepository for SqliteBlocklistSourceRepository {
             DomainError::DatabaseError(e.to_string())
         })?;
 
-        Ok(Self::row_to_source(row, group_ids))
+        Ok(Self::row_to_source(row, group_ids, None))
     }
 
     #[instrument(skip(self))]
     async fn get_by_id(&self, id: i64) -> Result<Option<BlocklistSource>, DomainError> {
         let row = sqlx::query_as::<_, BlocklistSourceRow>(
-            "SELECT id, name, url, comment, enabled, created_at, updated_at
+            "SELECT id, name, url, comment, enabled, observe_only, created_at, updated_at
              FROM blocklist_sources WHERE id = ?",
         )
         .bind(id)
@@ -141,7 +151,8 @@ impl BlocklistSourceRepository for SqliteBlocklistSourceRepository {
             None => Ok(None),
             Some(r) => {
                 let group_ids = self.fetch_group_ids(r.0).await?;
-                Ok(Some(Self::row_to_source(r, group_ids)))
+                let status = list_source_fetches::fetch_status(&self.pool, r.2.as_deref()).await?;
+                Ok(Some(Self::row_to_source(r, group_ids, status)))
             }
         }
     }
@@ -149,7 +160,7 @@ impl BlocklistSourceRepository for SqliteBlocklistSourceRepository {
     #[instrument(skip(self))]
     async fn get_all(&self) -> Result<Vec<BlocklistSource>, DomainError> {
         let rows = sqlx::query_as::<_, BlocklistSourceRow>(
-            "SELECT id, name, url, comment, enabled, created_at, updated_at
+            "SELECT id, name, url, comment, enabled, observe_only, created_at, updated_at
              FROM blocklist_sources ORDER BY name ASC",
         )
         .fetch_all(&self.pool)
@@ -162,7 +173,8 @@ impl BlocklistSourceRepository for SqliteBlocklistSourceRepository {
         let mut sources = Vec::with_capacity(rows.len());
         for row in rows {
             let group_ids = self.fetch_group_ids(row.0).await?;
-            sources.push(Self::row_to_source(row, group_ids));
+            let status = list_source_fetches::fetch_status(&self.pool, row.2.as_deref()).await?;
+            sources.push(Self::row_to_source(row, group_ids, status));
         }
         Ok(sources)
     }
@@ -176,6 +188,7 @@ impl BlocklistSourceRepository for SqliteBlocklistSourceRepository {
         group_ids: Option<Vec<i64>>,
         comment: Option<String>,
         enabled: Option<bool>,
+        observe_only: Option<bool>,
     ) -> Result<BlocklistSource, DomainError> {
         let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
 
@@ -193,6 +206,7 @@ impl BlocklistSourceRepository for SqliteBlocklistSourceRepository {
         let final_comment: Option<String> =
             comment.or_else(|| current.comment.as_ref().map(|s| s.to_string()));
         let final_enabled = enabled.unwrap_or(current.enabled);
+        let final_observe_only = observe_only.unwrap_or(current.observe_only);
         let legacy_group_id = final_group_ids.first().copied().unwrap_or(1);
 
         let mut tx = self.pool.begin().await.map_err(|e| {
@@ -202,15 +216,16 @@ impl BlocklistSourceRepository for SqliteBlocklistSourceRepository {
 
         let row = sqlx::query_as::<_, BlocklistSourceRow>(
             "UPDATE blocklist_sources
-             SET name = ?, url = ?, group_id = ?, comment = ?, enabled = ?, updated_at = ?
+             SET name = ?, url = ?, group_id = ?, comment = ?, enabled = ?, observe_only = ?, updated_at = ?
              WHERE id = ?
-             RETURNING id, name, url, comment, enabled, created_at, updated_at",
+             RETURNING id, name, url, comment, enabled, observe_only, created_at, updated_at",
         )
         .bind(&final_name)
         .bind(&final_url)
         .bind(legacy_group_id)
         .bind(&final_comment)
         .bind(if final_enabled { 1i64 } else { 0i64 })
+        .bind(if final_observe_only { 1i64 } else { 0i64 })
         .bind(&now)
         .bind(id)
         .fetch_optional(&mut *tx)
@@ -255,7 +270,8 @@ impl BlocklistSourceRepository for SqliteBlocklistSourceRepository {
             DomainError::DatabaseError(e.to_string())
         })?;
 
-        Ok(Self::row_to_source(row, final_group_ids))
+        let status = list_source_fetches::fetch_status(&self.pool, row.2.as_deref()).await?;
+        Ok(Self::row_to_source(row, final_group_ids, status))
     }
 
     #[instrument(skip(self))]
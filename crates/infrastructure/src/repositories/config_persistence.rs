@@ -374,6 +374,28 @@ pub fn save_config_to_file(config: &Config, path: &str) -> Result<(), ConfigErro
             str_array(&config.blocking.custom_blocked),
         );
         set_val(t, "whitelist", str_array(&config.blocking.whitelist));
+        set_val(
+            t,
+            "mode",
+            toml_edit::Value::from(config.blocking.mode.as_str()),
+        );
+        match config.blocking.custom_ipv4 {
+            Some(ip) => set_val(t, "custom_ipv4", toml_edit::Value::from(ip.to_string())),
+            None => {
+                t.remove("custom_ipv4");
+            }
+        }
+        match config.blocking.custom_ipv6 {
+            Some(ip) => set_val(t, "custom_ipv6", toml_edit::Value::from(ip.to_string())),
+            None => {
+                t.remove("custom_ipv6");
+            }
+        }
+        set_val(
+            t,
+            "blocked_ttl",
+            toml_edit::Value::from(config.blocking.blocked_ttl as i64),
+        );
     }
 
     // ── [logging] ───────────────────────────────────────────────────────
@@ -543,6 +565,26 @@ pub fn save_local_records_to_file(config: &Config, path: &str) -> Result<(), Con
         dns.insert("local_records", toml_edit::Item::ArrayOfTables(aot));
     }
 
+    if config.dns.dynamic_update_records.is_empty() {
+        dns.remove("dynamic_update_records");
+    } else {
+        let mut aot = toml_edit::ArrayOfTables::new();
+        for record in &config.dns.dynamic_update_records {
+            let mut table = toml_edit::Table::new();
+            table.insert("name", toml_edit::value(record.name.clone()));
+            table.insert("record_type", toml_edit::value(record.record_type.clone()));
+            table.insert("data", toml_edit::value(record.data.clone()));
+            if let Some(ttl) = record.ttl {
+                table.insert("ttl", toml_edit::value(ttl as i64));
+            }
+            aot.push(table);
+        }
+        dns.insert(
+            "dynamic_update_records",
+            toml_edit::Item::ArrayOfTables(aot),
+        );
+    }
+
     std::fs::write(path, doc.to_string())
         .map_err(|e| ConfigError::FileWrite(path.to_string(), e.to_string()))?;
     Ok(())
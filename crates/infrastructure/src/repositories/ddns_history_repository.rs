@@ -0,0 +1,119 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::DdnsHistoryRepository;
+use ferrous_dns_domain::{DdnsResult, DdnsUpdate, DomainError};
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use tracing::{error, instrument};
+
+type DdnsUpdateRow = (
+    i64,
+    String,
+    Option<String>,
+    String,
+    Option<i64>,
+    Option<String>,
+    String,
+);
+
+const DDNS_UPDATE_COLUMNS: &str = "id, hostname, ip, result, token_id, client_ip, created_at";
+
+pub struct SqliteDdnsHistoryRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteDdnsHistoryRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_update(row: DdnsUpdateRow) -> DdnsUpdate {
+        let (id, hostname, ip, result, token_id, client_ip, created_at) = row;
+        DdnsUpdate {
+            id: Some(id),
+            hostname: Arc::from(hostname.as_str()),
+            ip: ip.map(|s| Arc::from(s.as_str())),
+            result: result.parse().unwrap_or(DdnsResult::DnsErr),
+            token_id,
+            client_ip: client_ip.map(|s| Arc::from(s.as_str())),
+            created_at: Some(created_at),
+        }
+    }
+}
+
+#[async_trait]
+impl DdnsHistoryRepository for SqliteDdnsHistoryRepository {
+    #[instrument(skip(self, update), fields(hostname = %update.hostname))]
+    async fn record(&self, update: &DdnsUpdate) -> Result<(), DomainError> {
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        sqlx::query(
+            "INSERT INTO ddns_updates (hostname, ip, result, token_id, client_ip, created_at)
+             VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(update.hostname.as_ref())
+        .bind(update.ip.as_deref())
+        .bind(update.result.as_str())
+        .bind(update.token_id)
+        .bind(update.client_ip.as_deref())
+        .bind(&now)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to record DDNS update");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn get_recent(
+        &self,
+        hostname: Option<&str>,
+        limit: u32,
+    ) -> Result<Vec<DdnsUpdate>, DomainError> {
+        let rows = match hostname {
+            Some(hostname) => {
+                sqlx::query_as::<_, DdnsUpdateRow>(&format!(
+                    "SELECT {DDNS_UPDATE_COLUMNS} FROM ddns_updates
+                     WHERE hostname = ? ORDER BY id DESC LIMIT ?"
+                ))
+                .bind(hostname)
+                .bind(limit)
+                .fetch_all(&self.pool)
+                .await
+            }
+            None => {
+                sqlx::query_as::<_, DdnsUpdateRow>(&format!(
+                    "SELECT {DDNS_UPDATE_COLUMNS} FROM ddns_updates ORDER BY id DESC LIMIT ?"
+                ))
+                .bind(limit)
+                .fetch_all(&self.pool)
+                .await
+            }
+        }
+        .map_err(|e| {
+            error!(error = %e, "Failed to query DDNS history");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(rows.into_iter().map(Self::row_to_update).collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn prune(&self, keep: u32) -> Result<u64, DomainError> {
+        let result = sqlx::query(
+            "DELETE FROM ddns_updates
+             WHERE id <= (SELECT id FROM ddns_updates ORDER BY id DESC LIMIT 1 OFFSET ?)",
+        )
+        .bind(keep)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to prune DDNS history");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,187 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::DnsRewriteRuleRepository;
+use ferrous_dns_domain::{DnsRewriteAnswer, DnsRewriteRcode, DnsRewriteRule, DomainError};
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use tracing::{error, instrument, warn};
+
+type DnsRewriteRuleRow = (
+    i64,
+    String,
+    String,
+    i64,
+    i64,
+    Option<String>,
+    String,
+    String,
+);
+
+const DNS_REWRITE_RULE_COLUMNS: &str =
+    "id, domain, rewrite, group_id, enabled, comment, created_at, updated_at";
+
+pub struct SqliteDnsRewriteRuleRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteDnsRewriteRuleRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_rule(row: DnsRewriteRuleRow) -> DnsRewriteRule {
+        let (id, domain, rewrite, group_id, enabled, comment, created_at, updated_at) = row;
+        let answer = rewrite.parse::<DnsRewriteAnswer>().unwrap_or_else(|e| {
+            warn!(rewrite = %rewrite, error = %e, "Invalid DNS rewrite in DB, treating as REFUSED");
+            DnsRewriteAnswer::Rcode(DnsRewriteRcode::Refused)
+        });
+        DnsRewriteRule {
+            id: Some(id),
+            domain: Arc::from(domain.as_str()),
+            answer,
+            group_id,
+            enabled: enabled != 0,
+            comment: comment.map(|s| Arc::from(s.as_str())),
+            created_at: Some(created_at),
+            updated_at: Some(updated_at),
+        }
+    }
+
+    fn map_write_error(e: sqlx::Error, domain: &str, action: &str) -> DomainError {
+        if e.to_string().contains("UNIQUE constraint failed") {
+            DomainError::InvalidDnsRewriteRule(format!(
+                "The same rewrite for '{}' already exists in this group",
+                domain
+            ))
+        } else {
+            error!(error = %e, "Failed to {} DNS rewrite rule", action);
+            DomainError::DatabaseError(e.to_string())
+        }
+    }
+}
+
+#[async_trait]
+impl DnsRewriteRuleRepository for SqliteDnsRewriteRuleRepository {
+    #[instrument(skip(self))]
+    async fn create(
+        &self,
+        domain: String,
+        answer: DnsRewriteAnswer,
+        group_id: i64,
+        enabled: bool,
+        comment: Option<String>,
+    ) -> Result<DnsRewriteRule, DomainError> {
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        let row = sqlx::query_as::<_, DnsRewriteRuleRow>(&format!(
+            "INSERT INTO dns_rewrite_rules (domain, rewrite, group_id, enabled, comment, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?)
+             RETURNING {DNS_REWRITE_RULE_COLUMNS}"
+        ))
+        .bind(&domain)
+        .bind(answer.to_string())
+        .bind(group_id)
+        .bind(if enabled { 1i64 } else { 0i64 })
+        .bind(&comment)
+        .bind(&now)
+        .bind(&now)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| Self::map_write_error(e, &domain, "create"))?;
+
+        Ok(Self::row_to_rule(row))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_by_id(&self, id: i64) -> Result<Option<DnsRewriteRule>, DomainError> {
+        let row = sqlx::query_as::<_, DnsRewriteRuleRow>(&format!(
+            "SELECT {DNS_REWRITE_RULE_COLUMNS} FROM dns_rewrite_rules WHERE id = ?"
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to query DNS rewrite rule by id");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(row.map(Self::row_to_rule))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_all(&self) -> Result<Vec<DnsRewriteRule>, DomainError> {
+        let rows = sqlx::query_as::<_, DnsRewriteRuleRow>(&format!(
+            "SELECT {DNS_REWRITE_RULE_COLUMNS} FROM dns_rewrite_rules ORDER BY domain ASC, id ASC"
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to query all DNS rewrite rules");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(rows.into_iter().map(Self::row_to_rule).collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn update(
+        &self,
+        id: i64,
+        domain: Option<String>,
+        answer: Option<DnsRewriteAnswer>,
+        group_id: Option<i64>,
+        enabled: Option<bool>,
+        comment: Option<String>,
+    ) -> Result<DnsRewriteRule, DomainError> {
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        let current = self
+            .get_by_id(id)
+            .await?
+            .ok_or(DomainError::DnsRewriteRuleNotFound(id))?;
+
+        let final_domain = domain.unwrap_or_else(|| current.domain.to_string());
+        let final_answer = answer.unwrap_or(current.answer);
+        let final_group_id = group_id.unwrap_or(current.group_id);
+        let final_enabled = enabled.unwrap_or(current.enabled);
+        let final_comment: Option<String> =
+            comment.or_else(|| current.comment.as_ref().map(|s| s.to_string()));
+
+        let row = sqlx::query_as::<_, DnsRewriteRuleRow>(&format!(
+            "UPDATE dns_rewrite_rules
+             SET domain = ?, rewrite = ?, group_id = ?, enabled = ?, comment = ?, updated_at = ?
+             WHERE id = ?
+             RETURNING {DNS_REWRITE_RULE_COLUMNS}"
+        ))
+        .bind(&final_domain)
+        .bind(final_answer.to_string())
+        .bind(final_group_id)
+        .bind(if final_enabled { 1i64 } else { 0i64 })
+        .bind(&final_comment)
+        .bind(&now)
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| Self::map_write_error(e, &final_domain, "update"))?;
+
+        row.map(Self::row_to_rule)
+            .ok_or(DomainError::DnsRewriteRuleNotFound(id))
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(&self, id: i64) -> Result<(), DomainError> {
+        let result = sqlx::query("DELETE FROM dns_rewrite_rules WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to delete DNS rewrite rule");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::DnsRewriteRuleNotFound(id));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::ForwardingRuleRepository;
+use ferrous_dns_domain::{DomainError, ForwardingRule, ForwardingTarget};
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use tracing::{error, instrument, warn};
+
+type ForwardingRuleRow = (
+    i64,
+    String,
+    String,
+    String,
+    i64,
+    Option<String>,
+    String,
+    String,
+);
+
+const FORWARDING_RULE_COLUMNS: &str =
+    "id, domain, target_type, target, enabled, comment, created_at, updated_at";
+
+pub struct SqliteForwardingRuleRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteForwardingRuleRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_rule(row: ForwardingRuleRow) -> ForwardingRule {
+        let (id, domain, target_type, target, enabled, comment, created_at, updated_at) = row;
+        let target = ForwardingTarget::from_parts(&target_type, &target).unwrap_or_else(|| {
+            warn!(target_type = %target_type, "Invalid forwarding target type in DB, treating as server");
+            ForwardingTarget::Server(Arc::from(target.as_str()))
+        });
+        ForwardingRule {
+            id: Some(id),
+            domain: Arc::from(domain.as_str()),
+            target,
+            enabled: enabled != 0,
+            comment: comment.map(|s| Arc::from(s.as_str())),
+            created_at: Some(created_at),
+            updated_at: Some(updated_at),
+        }
+    }
+
+    fn map_write_error(e: sqlx::Error, domain: &str, action: &str) -> DomainError {
+        if e.to_string().contains("UNIQUE constraint failed") {
+            DomainError::InvalidForwardingRule(format!(
+                "Forwarding rule for '{}' already exists",
+                domain
+            ))
+        } else {
+            error!(error = %e, "Failed to {} forwarding rule", action);
+            DomainError::DatabaseError(e.to_string())
+        }
+    }
+}
+
+#[async_trait]
+impl ForwardingRuleRepository for SqliteForwardingRuleRepository {
+    #[instrument(skip(self))]
+    async fn create(
+        &self,
+        domain: String,
+        target: ForwardingTarget,
+        enabled: bool,
+        comment: Option<String>,
+    ) -> Result<ForwardingRule, DomainError> {
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        let row = sqlx::query_as::<_, ForwardingRuleRow>(&format!(
+            "INSERT INTO forwarding_rules (domain, target_type, target, enabled, comment, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?)
+             RETURNING {FORWARDING_RULE_COLUMNS}"
+        ))
+        .bind(&domain)
+        .bind(target.type_str())
+        .bind(target.value())
+        .bind(if enabled { 1i64 } else { 0i64 })
+        .bind(&comment)
+        .bind(&now)
+        .bind(&now)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| Self::map_write_error(e, &domain, "create"))?;
+
+        Ok(Self::row_to_rule(row))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_by_id(&self, id: i64) -> Result<Option<ForwardingRule>, DomainError> {
+        let row = sqlx::query_as::<_, ForwardingRuleRow>(&format!(
+            "SELECT {FORWARDING_RULE_COLUMNS} FROM forwarding_rules WHERE id = ?"
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to query forwarding rule by id");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(row.map(Self::row_to_rule))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_all(&self) -> Result<Vec<ForwardingRule>, DomainError> {
+        let rows = sqlx::query_as::<_, ForwardingRuleRow>(&format!(
+            "SELECT {FORWARDING_RULE_COLUMNS} FROM forwarding_rules ORDER BY domain ASC"
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to query all forwarding rules");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(rows.into_iter().map(Self::row_to_rule).collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn update(
+        &self,
+        id: i64,
+        domain: Option<String>,
+        target: Option<ForwardingTarget>,
+        enabled: Option<bool>,
+        comment: Option<String>,
+    ) -> Result<ForwardingRule, DomainError> {
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        let current = self
+            .get_by_id(id)
+            .await?
+            .ok_or(DomainError::ForwardingRuleNotFound(id))?;
+
+        let final_domain = domain.unwrap_or_else(|| current.domain.to_string());
+        let final_target = target.unwrap_or(current.target);
+        let final_enabled = enabled.unwrap_or(current.enabled);
+        let final_comment: Option<String> =
+            comment.or_else(|| current.comment.as_ref().map(|s| s.to_string()));
+
+        let row = sqlx::query_as::<_, ForwardingRuleRow>(&format!(
+            "UPDATE forwarding_rules
+             SET domain = ?, target_type = ?, target = ?, enabled = ?, comment = ?, updated_at = ?
+             WHERE id = ?
+             RETURNING {FORWARDING_RULE_COLUMNS}"
+        ))
+        .bind(&final_domain)
+        .bind(final_target.type_str())
+        .bind(final_target.value())
+        .bind(if final_enabled { 1i64 } else { 0i64 })
+        .bind(&final_comment)
+        .bind(&now)
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| Self::map_write_error(e, &final_domain, "update"))?;
+
+        row.map(Self::row_to_rule)
+            .ok_or(DomainError::ForwardingRuleNotFound(id))
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(&self, id: i64) -> Result<(), DomainError> {
+        let result = sqlx::query("DELETE FROM forwarding_rules WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to delete forwarding rule");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::ForwardingRuleNotFound(id));
+        }
+
+        Ok(())
+    }
+}
@@ -1,12 +1,43 @@
 use super::client_row_mapper::{row_to_client, ClientRow, CLIENT_SELECT};
 use async_trait::async_trait;
 use ferrous_dns_application::ports::GroupRepository;
-use ferrous_dns_domain::{Client, DomainError, Group};
+use ferrous_dns_domain::{BlockingMode, Client, DomainError, Group};
 use sqlx::SqlitePool;
+use std::net::{Ipv4Addr, Ipv6Addr};
 use std::sync::Arc;
-use tracing::{error, instrument};
+use tracing::{error, instrument, warn};
 
-type GroupRow = (i64, String, i64, Option<String>, i64, String, String);
+type GroupRow = (
+    i64,
+    String,
+    i64,
+    Option<String>,
+    i64,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    String,
+    String,
+);
+
+type GroupWithCountRow = (
+    i64,
+    String,
+    i64,
+    Option<String>,
+    i64,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    String,
+    String,
+    i64,
+);
+
+const GROUP_COLUMNS: &str = "id, name, enabled, comment, is_default, \
+     blocking_mode, blocking_ipv4, blocking_ipv6, upstream_pools, created_at, updated_at";
 
 pub struct SqliteGroupRepository {
     pool: SqlitePool,
@@ -18,7 +49,19 @@ impl SqliteGroupRepository {
     }
 
     fn row_to_group(row: GroupRow) -> Group {
-        let (id, name, enabled, comment, is_default, created_at, updated_at) = row;
+        let (
+            id,
+            name,
+            enabled,
+            comment,
+            is_default,
+            blocking_mode,
+            blocking_ipv4,
+            blocking_ipv6,
+            upstream_pools,
+            created_at,
+            updated_at,
+        ) = row;
 
         Group {
             id: Some(id),
@@ -26,6 +69,25 @@ impl SqliteGroupRepository {
             enabled: enabled != 0,
             comment: comment.map(|s| Arc::from(s.as_str())),
             is_default: is_default != 0,
+            blocking_mode: blocking_mode.and_then(|m| {
+                m.parse::<BlockingMode>()
+                    .map_err(|e| warn!(group_id = id, error = %e, "Ignoring stored blocking mode"))
+                    .ok()
+            }),
+            blocking_ipv4: blocking_ipv4.and_then(|ip| ip.parse::<Ipv4Addr>().ok()),
+            blocking_ipv6: blocking_ipv6.and_then(|ip| ip.parse::<Ipv6Addr>().ok()),
+            upstream_pools: upstream_pools
+                .and_then(|json| {
+                    serde_json::from_str::<Vec<String>>(&json)
+                        .map_err(
+                            |e| warn!(group_id = id, error = %e, "Ignoring stored upstream pools"),
+                        )
+                        .ok()
+                })
+                .unwrap_or_default()
+                .into_iter()
+                .map(|name| Arc::from(name.as_str()))
+                .collect(),
             created_at: Some(created_at),
             updated_at: Some(updated_at),
         }
@@ -38,11 +100,11 @@ impl GroupRepository for SqliteGroupRepository {
     async fn create(&self, name: String, comment: Option<String>) -> Result<Group, DomainError> {
         let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
 
-        let row = sqlx::query_as::<_, GroupRow>(
+        let row = sqlx::query_as::<_, GroupRow>(&format!(
             "INSERT INTO groups (name, enabled, comment, is_default, created_at, updated_at)
              VALUES (?, 1, ?, 0, ?, ?)
-             RETURNING id, name, enabled, comment, is_default, created_at, updated_at",
-        )
+             RETURNING {GROUP_COLUMNS}"
+        ))
         .bind(&name)
         .bind(&comment)
         .bind(&now)
@@ -63,10 +125,9 @@ impl GroupRepository for SqliteGroupRepository {
 
     #[instrument(skip(self))]
     async fn get_by_id(&self, id: i64) -> Result<Option<Group>, DomainError> {
-        let row = sqlx::query_as::<_, GroupRow>(
-            "SELECT id, name, enabled, comment, is_default, created_at, updated_at
-             FROM groups WHERE id = ?",
-        )
+        let row = sqlx::query_as::<_, GroupRow>(&format!(
+            "SELECT {GROUP_COLUMNS} FROM groups WHERE id = ?"
+        ))
         .bind(id)
         .fetch_optional(&self.pool)
         .await
@@ -80,10 +141,9 @@ impl GroupRepository for SqliteGroupRepository {
 
     #[instrument(skip(self))]
     async fn get_by_name(&self, name: &str) -> Result<Option<Group>, DomainError> {
-        let row = sqlx::query_as::<_, GroupRow>(
-            "SELECT id, name, enabled, comment, is_default, created_at, updated_at
-             FROM groups WHERE name = ?",
-        )
+        let row = sqlx::query_as::<_, GroupRow>(&format!(
+            "SELECT {GROUP_COLUMNS} FROM groups WHERE name = ?"
+        ))
         .bind(name)
         .fetch_optional(&self.pool)
         .await
@@ -97,10 +157,9 @@ impl GroupRepository for SqliteGroupRepository {
 
     #[instrument(skip(self))]
     async fn get_all(&self) -> Result<Vec<Group>, DomainError> {
-        let rows = sqlx::query_as::<_, GroupRow>(
-            "SELECT id, name, enabled, comment, is_default, created_at, updated_at
-             FROM groups ORDER BY is_default DESC, name ASC",
-        )
+        let rows = sqlx::query_as::<_, GroupRow>(&format!(
+            "SELECT {GROUP_COLUMNS} FROM groups ORDER BY is_default DESC, name ASC"
+        ))
         .fetch_all(&self.pool)
         .await
         .map_err(|e| {
@@ -113,8 +172,10 @@ impl GroupRepository for SqliteGroupRepository {
 
     #[instrument(skip(self))]
     async fn get_all_with_client_counts(&self) -> Result<Vec<(Group, u64)>, DomainError> {
-        let rows = sqlx::query_as::<_, (i64, String, i64, Option<String>, i64, String, String, i64)>(
-            "SELECT g.id, g.name, g.enabled, g.comment, g.is_default, g.created_at, g.updated_at,
+        let rows = sqlx::query_as::<_, GroupWithCountRow>(
+            "SELECT g.id, g.name, g.enabled, g.comment, g.is_default,
+                    g.blocking_mode, g.blocking_ipv4, g.blocking_ipv6, g.upstream_pools,
+                    g.created_at, g.updated_at,
                     COUNT(c.id) as client_count
              FROM groups g
              LEFT JOIN clients c ON c.group_id = g.id
@@ -131,16 +192,24 @@ impl GroupRepository for SqliteGroupRepository {
         Ok(rows
             .into_iter()
             .map(
-                |(id, name, enabled, comment, is_default, created_at, updated_at, count)| {
-                    let group = Group {
-                        id: Some(id),
-                        name: Arc::from(name.as_str()),
-                        enabled: enabled != 0,
-                        comment: comment.map(|s| Arc::from(s.as_str())),
-                        is_default: is_default != 0,
-                        created_at: Some(created_at),
-                        updated_at: Some(updated_at),
-                    };
+                |(
+                    id,
+                    name,
+                    enabled,
+                    comment,
+                    is_default,
+                    mode,
+                    ipv4,
+                    ipv6,
+                    pools,
+                    created_at,
+                    updated_at,
+                    count,
+                )| {
+                    let group = Self::row_to_group((
+                        id, name, enabled, comment, is_default, mode, ipv4, ipv6, pools,
+                        created_at, updated_at,
+                    ));
                     (group, count as u64)
                 },
             )
@@ -166,11 +235,11 @@ impl GroupRepository for SqliteGroupRepository {
         let final_enabled = enabled.unwrap_or(current.enabled);
         let final_comment = comment.or_else(|| current.comment.as_ref().map(|s| s.to_string()));
 
-        let row = sqlx::query_as::<_, GroupRow>(
+        let row = sqlx::query_as::<_, GroupRow>(&format!(
             "UPDATE groups SET name = ?, enabled = ?, comment = ?, updated_at = ?
              WHERE id = ?
-             RETURNING id, name, enabled, comment, is_default, created_at, updated_at",
-        )
+             RETURNING {GROUP_COLUMNS}"
+        ))
         .bind(&final_name)
         .bind(if final_enabled { 1 } else { 0 })
         .bind(&final_comment)
@@ -191,6 +260,68 @@ impl GroupRepository for SqliteGroupRepository {
             .ok_or(DomainError::GroupNotFound(id))
     }
 
+    #[instrument(skip(self))]
+    async fn update_blocking_mode(
+        &self,
+        id: i64,
+        mode: Option<BlockingMode>,
+        ipv4: Option<Ipv4Addr>,
+        ipv6: Option<Ipv6Addr>,
+    ) -> Result<Group, DomainError> {
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        let row = sqlx::query_as::<_, GroupRow>(&format!(
+            "UPDATE groups SET blocking_mode = ?, blocking_ipv4 = ?, blocking_ipv6 = ?, updated_at = ?
+             WHERE id = ?
+             RETURNING {GROUP_COLUMNS}"
+        ))
+        .bind(mode.map(|m| m.as_str()))
+        .bind(ipv4.map(|ip| ip.to_string()))
+        .bind(ipv6.map(|ip| ip.to_string()))
+        .bind(&now)
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to update group blocking mode");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        row.map(Self::row_to_group)
+            .ok_or(DomainError::GroupNotFound(id))
+    }
+
+    #[instrument(skip(self))]
+    async fn update_upstream_pools(&self, id: i64, pools: &[String]) -> Result<Group, DomainError> {
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        let pools_json = if pools.is_empty() {
+            None
+        } else {
+            Some(
+                serde_json::to_string(pools)
+                    .map_err(|e| DomainError::DatabaseError(e.to_string()))?,
+            )
+        };
+
+        let row = sqlx::query_as::<_, GroupRow>(&format!(
+            "UPDATE groups SET upstream_pools = ?, updated_at = ?
+             WHERE id = ?
+             RETURNING {GROUP_COLUMNS}"
+        ))
+        .bind(pools_json)
+        .bind(&now)
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to update group upstream pools");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        row.map(Self::row_to_group)
+            .ok_or(DomainError::GroupNotFound(id))
+    }
+
     #[instrument(skip(self))]
     async fn delete(&self, id: i64) -> Result<(), DomainError> {
         let result = sqlx::query("DELETE FROM groups WHERE id = ?")
@@ -0,0 +1,161 @@
+use ferrous_dns_domain::{DomainError, ListSourceStatus};
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use tracing::error;
+
+type StatusRow = (
+    String,
+    Option<String>,
+    Option<i64>,
+    i64,
+    i64,
+    Option<String>,
+);
+
+/// Last good download of a list URL, with the validators needed to
+/// revalidate it.
+#[derive(Debug, Clone)]
+pub(crate) struct CachedList {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    pub content: String,
+}
+
+/// Result of one download attempt, recorded against the URL.
+#[derive(Debug, Default)]
+pub(crate) struct FetchRecord {
+    /// Set only when the server returned a new body.
+    pub fresh: Option<CachedList>,
+    pub succeeded: bool,
+    pub http_status: Option<u16>,
+    pub entry_count: usize,
+    pub parse_errors: usize,
+    pub error: Option<String>,
+}
+
+fn now() -> String {
+    chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
+pub(crate) async fn fetch_status(
+    pool: &SqlitePool,
+    url: Option<&str>,
+) -> Result<Option<ListSourceStatus>, DomainError> {
+    let Some(url) = url else {
+        return Ok(None);
+    };
+
+    let row = sqlx::query_as::<_, StatusRow>(
+        "SELECT last_attempt_at, last_success_at, last_http_status, entry_count, parse_errors, last_error
+         FROM list_source_fetches WHERE url = ?",
+    )
+    .bind(url)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| {
+        error!(error = %e, "Failed to query list source status");
+        DomainError::DatabaseError(e.to_string())
+    })?;
+
+    Ok(row.map(
+        |(last_attempt_at, last_success_at, http_status, entry_count, parse_errors, last_error)| {
+            ListSourceStatus {
+                last_attempt_at,
+                last_success_at,
+                last_http_status: http_status.and_then(|s| u16::try_from(s).ok()),
+                entry_count: entry_count.max(0) as u64,
+                parse_errors: parse_errors.max(0) as u64,
+                last_error: last_error.map(|s| Arc::from(s.as_str())),
+            }
+        },
+    ))
+}
+
+pub(crate) async fn load_cached(
+    pool: &SqlitePool,
+    url: &str,
+) -> Result<Option<CachedList>, DomainError> {
+    let row = sqlx::query_as::<_, (Option<String>, Option<String>, Option<String>)>(
+        "SELECT etag, last_modified, content FROM list_source_fetches WHERE url = ?",
+    )
+    .bind(url)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+    Ok(row.and_then(|(etag, last_modified, content)| {
+        content.map(|content| CachedList {
+            etag,
+            last_modified,
+            content,
+        })
+    }))
+}
+
+/// Stores the outcome of a download. The cached copy and its validators
+/// are only replaced when `record.fresh` is set, and `last_success_at`
+/// only moves forward on success.
+pub(crate) async fn record_fetch(
+    pool: &SqlitePool,
+    url: &str,
+    record: &FetchRecord,
+) -> Result<(), DomainError> {
+    let now = now();
+    let success_at = record.succeeded.then(|| now.clone());
+    let (replace, etag, last_modified, content) = match &record.fresh {
+        Some(fresh) => (
+            1i64,
+            fresh.etag.as_deref(),
+            fresh.last_modified.as_deref(),
+            Some(fresh.content.as_str()),
+        ),
+        None => (0i64, None, None, None),
+    };
+
+    sqlx::query(
+        "INSERT INTO list_source_fetches
+             (url, etag, last_modified, content, last_attempt_at, last_success_at,
+              last_http_status, entry_count, parse_errors, last_error)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+         ON CONFLICT(url) DO UPDATE SET
+             etag             = CASE WHEN ?11 THEN excluded.etag ELSE etag END,
+             last_modified    = CASE WHEN ?11 THEN excluded.last_modified ELSE last_modified END,
+             content          = CASE WHEN ?11 THEN excluded.content ELSE content END,
+             last_attempt_at  = excluded.last_attempt_at,
+             last_success_at  = COALESCE(excluded.last_success_at, last_success_at),
+             last_http_status = excluded.last_http_status,
+             entry_count      = excluded.entry_count,
+             parse_errors     = excluded.parse_errors,
+             last_error       = excluded.last_error",
+    )
+    .bind(url)
+    .bind(etag)
+    .bind(last_modified)
+    .bind(content)
+    .bind(&now)
+    .bind(success_at)
+    .bind(record.http_status.map(i64::from))
+    .bind(record.entry_count as i64)
+    .bind(record.parse_errors as i64)
+    .bind(record.error.as_deref())
+    .bind(replace)
+    .execute(pool)
+    .await
+    .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+    Ok(())
+}
+
+/// Drops fetch state for URLs no blocklist or allowlist source uses any more.
+pub(crate) async fn prune_unreferenced(pool: &SqlitePool) -> Result<u64, DomainError> {
+    let result = sqlx::query(
+        "DELETE FROM list_source_fetches
+         WHERE url NOT IN (SELECT url FROM blocklist_sources WHERE url IS NOT NULL)
+           AND url NOT IN (SELECT url FROM whitelist_sources WHERE url IS NOT NULL)",
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+    Ok(result.rows_affected())
+}
@@ -0,0 +1,84 @@
+use crate::dns::authority::zone::AuthoritativeZone;
+use crate::dns::authority::{format_zone, parse_zone_file};
+use async_trait::async_trait;
+use ferrous_dns_application::ports::LocalZoneRepository;
+use ferrous_dns_domain::{DomainError, LocalZone, LocalZoneConfig};
+use std::path::PathBuf;
+use tracing::{error, instrument};
+
+/// Local zones stored as master files, as listed under `[[dns.local_zones]]`.
+pub struct FileLocalZoneRepository {
+    zones: Vec<LocalZoneConfig>,
+}
+
+impl FileLocalZoneRepository {
+    pub fn new(zones: Vec<LocalZoneConfig>) -> Self {
+        Self { zones }
+    }
+
+    fn config_for(&self, origin: &str) -> Option<&LocalZoneConfig> {
+        self.zones
+            .iter()
+            .find(|z| LocalZone::normalize_name(&z.origin) == origin)
+    }
+
+    async fn load(config: &LocalZoneConfig) -> Result<LocalZone, DomainError> {
+        let path = PathBuf::from(&config.file);
+        let origin = config.origin.clone();
+        tokio::task::spawn_blocking(move || parse_zone_file(&path, &origin))
+            .await
+            .map_err(|e| DomainError::IoError(e.to_string()))?
+            .map_err(DomainError::ConfigError)
+    }
+}
+
+#[async_trait]
+impl LocalZoneRepository for FileLocalZoneRepository {
+    #[instrument(skip(self))]
+    async fn get_all(&self) -> Result<Vec<LocalZone>, DomainError> {
+        let mut zones = Vec::with_capacity(self.zones.len());
+        for config in &self.zones {
+            match Self::load(config).await {
+                Ok(zone) => zones.push(zone),
+                Err(e) => error!(
+                    zone = %config.origin,
+                    file = %config.file,
+                    error = %e,
+                    "Failed to load local zone file"
+                ),
+            }
+        }
+        Ok(zones)
+    }
+
+    #[instrument(skip(self))]
+    async fn get(&self, origin: &str) -> Result<Option<LocalZone>, DomainError> {
+        match self.config_for(origin) {
+            Some(config) => Self::load(config).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self, zone), fields(zone = %zone.origin))]
+    async fn save(&self, zone: &LocalZone) -> Result<(), DomainError> {
+        let config = self
+            .config_for(&zone.origin)
+            .ok_or_else(|| DomainError::LocalZoneNotFound(zone.origin.to_string()))?;
+
+        AuthoritativeZone::compile(zone).map_err(DomainError::InvalidZoneRecord)?;
+
+        // Write a sibling file and rename it over the zone so a crash never
+        // leaves a half-written zone behind.
+        let path = PathBuf::from(&config.file);
+        let mut tmp = path.clone().into_os_string();
+        tmp.push(".tmp");
+        tokio::fs::write(&tmp, format_zone(zone))
+            .await
+            .map_err(|e| DomainError::IoError(format!("{}: {}", config.file, e)))?;
+        tokio::fs::rename(&tmp, &path)
+            .await
+            .map_err(|e| DomainError::IoError(format!("{}: {}", config.file, e)))?;
+
+        Ok(())
+    }
+}
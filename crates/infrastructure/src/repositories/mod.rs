@@ -1,3 +1,4 @@
+pub mod acme_registration_repository;
 pub mod blocked_service_repository;
 pub mod blocklist_repository;
 pub mod blocklist_source_repository;
@@ -7,12 +8,20 @@ pub mod client_subnet_repository;
 pub mod config_persistence;
 pub mod config_repository;
 pub mod custom_service_repository;
+pub mod ddns_history_repository;
+pub mod dns_rewrite_rule_repository;
+pub mod forwarding_rule_repository;
 pub mod group_repository;
+pub(crate) mod list_source_fetches;
+pub mod local_zone_repository;
 pub mod managed_domain_repository;
+pub mod negative_trust_anchor_repository;
 pub mod query_log_repository;
 pub mod regex_filter_repository;
 pub mod schedule_profile_repository;
 pub mod sqlite_safe_search_config_repository;
+pub mod trust_anchor_repository;
+pub mod tsig_key_repository;
 pub mod whitelist_repository;
 pub mod whitelist_source_repository;
 
@@ -20,6 +29,7 @@ pub mod api_token_repository;
 pub mod session_repository;
 pub mod user_repository;
 
+pub use acme_registration_repository::SqliteAcmeRegistrationRepository;
 pub use api_token_repository::SqliteApiTokenRepository;
 pub use blocked_service_repository::SqliteBlockedServiceRepository;
 pub use blocklist_source_repository::SqliteBlocklistSourceRepository;
@@ -28,12 +38,19 @@ pub use client_subnet_repository::SqliteClientSubnetRepository;
 pub use config_persistence::TomlConfigFilePersistence;
 pub use config_repository::TomlConfigRepository;
 pub use custom_service_repository::SqliteCustomServiceRepository;
+pub use ddns_history_repository::SqliteDdnsHistoryRepository;
+pub use dns_rewrite_rule_repository::SqliteDnsRewriteRuleRepository;
+pub use forwarding_rule_repository::SqliteForwardingRuleRepository;
 pub use group_repository::SqliteGroupRepository;
+pub use local_zone_repository::FileLocalZoneRepository;
 pub use managed_domain_repository::SqliteManagedDomainRepository;
+pub use negative_trust_anchor_repository::SqliteNegativeTrustAnchorRepository;
 pub use regex_filter_repository::SqliteRegexFilterRepository;
 pub use schedule_profile_repository::SqliteScheduleProfileRepository;
 pub use session_repository::SqliteSessionRepository;
 pub use sqlite_safe_search_config_repository::SqliteSafeSearchConfigRepository;
+pub use trust_anchor_repository::SqliteTrustAnchorRepository;
+pub use tsig_key_repository::SqliteTsigKeyRepository;
 pub use user_repository::SqliteUserRepository;
 pub use whitelist_repository::SqliteWhitelistRepository;
 pub use whitelist_source_repository::SqliteWhitelistSourceRepository;
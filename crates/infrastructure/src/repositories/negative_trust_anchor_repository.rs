@@ -0,0 +1,134 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::NegativeTrustAnchorRepository;
+use ferrous_dns_domain::{DomainError, NegativeTrustAnchor, NTA_TIMESTAMP_FORMAT};
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use tracing::{error, instrument};
+
+type NegativeTrustAnchorRow = (i64, String, Option<String>, Option<String>, String);
+
+const NEGATIVE_TRUST_ANCHOR_COLUMNS: &str = "id, domain, expires_at, comment, created_at";
+
+pub struct SqliteNegativeTrustAnchorRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteNegativeTrustAnchorRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_anchor(row: NegativeTrustAnchorRow) -> NegativeTrustAnchor {
+        let (id, domain, expires_at, comment, created_at) = row;
+        NegativeTrustAnchor {
+            id: Some(id),
+            domain: Arc::from(domain.as_str()),
+            expires_at,
+            comment: comment.map(|s| Arc::from(s.as_str())),
+            created_at: Some(created_at),
+        }
+    }
+}
+
+#[async_trait]
+impl NegativeTrustAnchorRepository for SqliteNegativeTrustAnchorRepository {
+    #[instrument(skip(self))]
+    async fn create(
+        &self,
+        domain: String,
+        expires_at: Option<String>,
+        comment: Option<String>,
+    ) -> Result<NegativeTrustAnchor, DomainError> {
+        let now = chrono::Utc::now().format(NTA_TIMESTAMP_FORMAT).to_string();
+
+        let row = sqlx::query_as::<_, NegativeTrustAnchorRow>(&format!(
+            "INSERT INTO negative_trust_anchors (domain, expires_at, comment, created_at)
+             VALUES (?, ?, ?, ?)
+             RETURNING {NEGATIVE_TRUST_ANCHOR_COLUMNS}"
+        ))
+        .bind(&domain)
+        .bind(&expires_at)
+        .bind(&comment)
+        .bind(&now)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            if e.to_string().contains("UNIQUE constraint failed") {
+                DomainError::InvalidNegativeTrustAnchor(format!(
+                    "Negative trust anchor for '{}' already exists",
+                    domain
+                ))
+            } else {
+                error!(error = %e, "Failed to create negative trust anchor");
+                DomainError::DatabaseError(e.to_string())
+            }
+        })?;
+
+        Ok(Self::row_to_anchor(row))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_by_id(&self, id: i64) -> Result<Option<NegativeTrustAnchor>, DomainError> {
+        let row = sqlx::query_as::<_, NegativeTrustAnchorRow>(&format!(
+            "SELECT {NEGATIVE_TRUST_ANCHOR_COLUMNS} FROM negative_trust_anchors WHERE id = ?"
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to query negative trust anchor by id");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(row.map(Self::row_to_anchor))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_all(&self) -> Result<Vec<NegativeTrustAnchor>, DomainError> {
+        let rows = sqlx::query_as::<_, NegativeTrustAnchorRow>(&format!(
+            "SELECT {NEGATIVE_TRUST_ANCHOR_COLUMNS} FROM negative_trust_anchors ORDER BY domain ASC"
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to query all negative trust anchors");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(rows.into_iter().map(Self::row_to_anchor).collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(&self, id: i64) -> Result<(), DomainError> {
+        let result = sqlx::query("DELETE FROM negative_trust_anchors WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to delete negative trust anchor");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::NegativeTrustAnchorNotFound(id));
+        }
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_expired(&self, now: &str) -> Result<u64, DomainError> {
+        let result = sqlx::query(
+            "DELETE FROM negative_trust_anchors WHERE expires_at IS NOT NULL AND expires_at <= ?",
+        )
+        .bind(now)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to delete expired negative trust anchors");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -44,6 +44,23 @@ pub fn days_ago_cutoff(days: u32) -> String {
         .to_string()
 }
 
+/// Hours since the Unix epoch, the bucket key of `blocklist_source_hits`.
+pub fn current_hour() -> i64 {
+    Utc::now().timestamp() / 3600
+}
+
+/// Encodes blocklist source ids for the `blocklist_source_ids` column.
+pub fn join_source_ids(ids: &[i64]) -> Option<String> {
+    if ids.is_empty() {
+        return None;
+    }
+    Some(ids.iter().map(i64::to_string).collect::<Vec<_>>().join(","))
+}
+
+fn split_source_ids(s: &str) -> Vec<i64> {
+    s.split(',').filter_map(|id| id.parse().ok()).collect()
+}
+
 fn to_static_dnssec(s: &str) -> Option<&'static str> {
     match s {
         "Secure" => Some("Secure"),
@@ -67,6 +84,7 @@ fn to_static_response_status(s: &str) -> Option<&'static str> {
         "RATE_LIMITED" => Some("RATE_LIMITED"),
         "RATE_LIMITED_TC" => Some("RATE_LIMITED_TC"),
         "SAFE_SEARCH" => Some("SAFE_SEARCH"),
+        "REWRITTEN" => Some("REWRITTEN"),
         _ => None,
     }
 }
@@ -102,6 +120,7 @@ pub fn row_to_query_log(row: SqliteRow) -> Option<QueryLog> {
                 "nxdomain_hijack" => Some(BlockSource::NxdomainHijack),
                 "response_ip_filter" => Some(BlockSource::ResponseIpFilter),
                 "dga_detection" => Some(BlockSource::DgaDetection),
+                "dns_rewrite" => Some(BlockSource::DnsRewrite),
                 _ => None,
             });
 
@@ -133,6 +152,12 @@ pub fn row_to_query_log(row: SqliteRow) -> Option<QueryLog> {
         query_source,
         group_id: row.get("group_id"),
         block_source,
+        blocklist_source_ids: row
+            .try_get::<Option<String>, _>("blocklist_source_ids")
+            .ok()
+            .flatten()
+            .map(|s| split_source_ids(&s))
+            .unwrap_or_default(),
     })
 }
 
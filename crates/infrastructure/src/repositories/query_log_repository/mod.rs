@@ -8,7 +8,9 @@ use ferrous_dns_application::ports::{
     PagedQueryResult, QueryLogRepository, TimeGranularity, TimelineBucket,
 };
 use ferrous_dns_domain::query_log::QueryLogFilter;
-use ferrous_dns_domain::{config::DatabaseConfig, DomainError, QueryLog, QueryStats};
+use ferrous_dns_domain::{
+    config::DatabaseConfig, BlocklistSourceHits, DomainError, QueryLog, QueryStats,
+};
 use sqlx::SqlitePool;
 use std::sync::atomic::{AtomicU64, Ordering};
 use timeline::TimelineCache;
@@ -22,6 +24,7 @@ pub struct SqliteQueryLogRepository {
     sender: mpsc::Sender<QueryLogEntry>,
     sample_rate: u32,
     sample_counter: AtomicU64,
+    dropped: AtomicU64,
     timeline_cache: TimelineCache,
 }
 
@@ -56,6 +59,7 @@ impl SqliteQueryLogRepository {
             sender,
             sample_rate: cfg.query_log_sample_rate,
             sample_counter: AtomicU64::new(0),
+            dropped: AtomicU64::new(0),
             timeline_cache: TimelineCache::new(),
         }
     }
@@ -79,6 +83,7 @@ impl QueryLogRepository for SqliteQueryLogRepository {
         match self.sender.try_send(entry) {
             Ok(()) => Ok(()),
             Err(mpsc::error::TrySendError::Full(_)) => {
+                self.dropped.fetch_add(1, Ordering::Relaxed);
                 warn!("Query log channel full, dropping entry");
                 Ok(())
             }
@@ -89,6 +94,10 @@ impl QueryLogRepository for SqliteQueryLogRepository {
         }
     }
 
+    fn dropped_entries(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
     async fn get_recent(
         &self,
         limit: u32,
@@ -161,7 +170,13 @@ impl QueryLogRepository for SqliteQueryLogRepository {
         reader::get_top_clients(&self.read_pool, limit, period_hours).await
     }
 
+    async fn get_blocklist_source_hits(&self) -> Result<Vec<BlocklistSourceHits>, DomainError> {
+        reader::get_blocklist_source_hits(&self.read_pool).await
+    }
+
     async fn delete_older_than(&self, days: u32) -> Result<u64, DomainError> {
-        reader::delete_older_than(&self.write_pool, days).await
+        let deleted = reader::delete_older_than(&self.write_pool, days).await?;
+        reader::prune_blocklist_source_hits(&self.write_pool).await?;
+        Ok(deleted)
     }
 }
@@ -1,10 +1,12 @@
 use super::helpers::{
-    days_ago_cutoff, get_uptime, hours_ago_cutoff, row_to_query_log, seconds_ago_cutoff,
+    current_hour, days_ago_cutoff, get_uptime, hours_ago_cutoff, row_to_query_log,
+    seconds_ago_cutoff,
 };
 use ferrous_dns_application::ports::PagedQueryResult;
 use ferrous_dns_domain::query_log::{QueryCategory, QueryLogFilter};
-use ferrous_dns_domain::{DomainError, QueryLog, QueryStats};
+use ferrous_dns_domain::{BlocklistSourceHits, DomainError, QueryLog, QueryStats};
 use sqlx::{Row, SqlitePool};
+use std::sync::Arc;
 use std::time::Duration;
 use tracing::{debug, error, info, instrument};
 
@@ -24,6 +26,7 @@ pub(super) async fn get_recent(
         "SELECT q.id, q.domain, q.record_type, q.client_ip, q.blocked, q.response_time_ms,
                 q.cache_hit, q.cache_refresh, q.dnssec_status, q.upstream_server,
                 q.upstream_pool, q.response_status, q.query_source, q.group_id, q.block_source,
+                q.blocklist_source_ids,
                 datetime(q.created_at) as created_at, c.hostname
          FROM query_log q
          LEFT JOIN clients c ON q.client_ip = c.ip_address
@@ -80,6 +83,7 @@ pub(super) async fn get_recent_paged(
         Some(QueryCategory::Upstream) => " AND q.cache_hit = 0 AND q.blocked = 0 AND (q.response_status IS NULL OR q.response_status NOT IN ('LOCAL_DNS', 'RATE_LIMITED', 'RATE_LIMITED_TC'))",
         Some(QueryCategory::RateLimited) => " AND q.response_status IN ('RATE_LIMITED', 'RATE_LIMITED_TC')",
         Some(QueryCategory::Malware) => " AND q.block_source IN ('dns_tunneling', 'dns_rebinding', 'nxdomain_hijack', 'response_ip_filter', 'dga_detection')",
+        Some(QueryCategory::WouldBlock) => " AND q.blocked = 0 AND q.blocklist_source_ids IS NOT NULL",
         None => "",
     };
 
@@ -131,6 +135,7 @@ pub(super) async fn get_recent_paged(
                     "SELECT q.id, q.domain, q.record_type, q.client_ip, q.blocked, q.response_time_ms,
                             q.cache_hit, q.cache_refresh, q.dnssec_status, q.upstream_server,
                             q.upstream_pool, q.response_status, q.query_source, q.group_id, q.block_source,
+                            q.blocklist_source_ids,
                             datetime(q.created_at) as created_at, c.hostname
                      FROM query_log q
                      LEFT JOIN clients c ON q.client_ip = c.ip_address
@@ -149,6 +154,7 @@ pub(super) async fn get_recent_paged(
                     "SELECT q.id, q.domain, q.record_type, q.client_ip, q.blocked, q.response_time_ms,
                             q.cache_hit, q.cache_refresh, q.dnssec_status, q.upstream_server,
                             q.upstream_pool, q.response_status, q.query_source, q.group_id, q.block_source,
+                            q.blocklist_source_ids,
                             datetime(q.created_at) as created_at, c.hostname
                      FROM query_log q
                      LEFT JOIN clients c ON q.client_ip = c.ip_address
@@ -599,3 +605,64 @@ pub(super) async fn delete_older_than(pool: &SqlitePool, days: u32) -> Result<u6
     );
     Ok(total_deleted)
 }
+
+/// Hourly hit buckets are kept for the longest reported window.
+const SOURCE_HITS_RETENTION_HOURS: i64 = 30 * 24;
+
+#[instrument(skip(pool))]
+pub(super) async fn get_blocklist_source_hits(
+    pool: &SqlitePool,
+) -> Result<Vec<BlocklistSourceHits>, DomainError> {
+    let hour = current_hour();
+    let rows = sqlx::query(
+        "SELECT bs.id, bs.name, bs.enabled,
+                COALESCE(SUM(CASE WHEN h.hour > ? THEN h.hits END), 0) AS hits_24h,
+                COALESCE(SUM(CASE WHEN h.hour > ? THEN h.hits END), 0) AS hits_7d,
+                COALESCE(SUM(h.hits), 0) AS hits_30d,
+                COUNT(DISTINCT CASE WHEN h.hour > ? THEN h.domain END) AS domains_24h,
+                COUNT(DISTINCT CASE WHEN h.hour > ? THEN h.domain END) AS domains_7d,
+                COUNT(DISTINCT h.domain) AS domains_30d
+         FROM blocklist_sources bs
+         LEFT JOIN blocklist_source_hits h ON h.source_id = bs.id AND h.hour > ?
+         GROUP BY bs.id
+         ORDER BY bs.id",
+    )
+    .bind(hour - 24)
+    .bind(hour - 7 * 24)
+    .bind(hour - 24)
+    .bind(hour - 7 * 24)
+    .bind(hour - SOURCE_HITS_RETENTION_HOURS)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        error!(error = %e, "Failed to fetch blocklist source hits");
+        DomainError::DatabaseError(e.to_string())
+    })?;
+
+    Ok(rows
+        .into_iter()
+        .map(|r| BlocklistSourceHits {
+            source_id: r.get("id"),
+            name: Arc::from(r.get::<String, _>("name").as_str()),
+            enabled: r.get::<i64, _>("enabled") != 0,
+            hits_24h: r.get::<i64, _>("hits_24h") as u64,
+            hits_7d: r.get::<i64, _>("hits_7d") as u64,
+            hits_30d: r.get::<i64, _>("hits_30d") as u64,
+            unique_domains_24h: r.get::<i64, _>("domains_24h") as u64,
+            unique_domains_7d: r.get::<i64, _>("domains_7d") as u64,
+            unique_domains_30d: r.get::<i64, _>("domains_30d") as u64,
+        })
+        .collect())
+}
+
+pub(super) async fn prune_blocklist_source_hits(pool: &SqlitePool) -> Result<u64, DomainError> {
+    let result = sqlx::query("DELETE FROM blocklist_source_hits WHERE hour <= ?")
+        .bind(current_hour() - SOURCE_HITS_RETENTION_HOURS)
+        .execute(pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to prune blocklist source hits");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+    Ok(result.rows_affected())
+}
@@ -1,13 +1,18 @@
+use super::helpers::{current_hour, join_source_ids};
 use compact_str::{CompactString, ToCompactString};
 use ferrous_dns_domain::QueryLog;
-use sqlx::SqlitePool;
+use rustc_hash::FxBuildHasher;
+use sqlx::{Sqlite, SqlitePool, Transaction};
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc;
 use tracing::{debug, error, info, warn};
 
-const COLS_PER_ROW: usize = 14;
+const COLS_PER_ROW: usize = 15;
 const ROWS_PER_CHUNK: usize = 999 / COLS_PER_ROW;
+const HIT_COLS_PER_ROW: usize = 4;
+const HIT_ROWS_PER_CHUNK: usize = 999 / HIT_COLS_PER_ROW;
 
 pub(super) struct QueryLogEntry {
     domain: CompactString,
@@ -24,6 +29,7 @@ pub(super) struct QueryLogEntry {
     query_source: CompactString,
     group_id: Option<i64>,
     block_source: Option<&'static str>,
+    blocklist_source_ids: Vec<i64>,
 }
 
 impl QueryLogEntry {
@@ -43,6 +49,7 @@ impl QueryLogEntry {
             query_source: CompactString::from(q.query_source.as_str()),
             group_id: q.group_id,
             block_source: q.block_source.map(|s| s.to_str()),
+            blocklist_source_ids: q.blocklist_source_ids.clone(),
         }
     }
 }
@@ -51,9 +58,10 @@ fn build_multi_insert_sql(n: usize) -> String {
     debug_assert!(n > 0 && n <= ROWS_PER_CHUNK);
     const HEADER: &str = "INSERT INTO query_log \
         (domain, record_type, client_ip, blocked, response_time_ms, cache_hit, \
-         cache_refresh, dnssec_status, upstream_server, upstream_pool, response_status, query_source, group_id, block_source) \
+         cache_refresh, dnssec_status, upstream_server, upstream_pool, response_status, query_source, group_id, block_source, \
+         blocklist_source_ids) \
         VALUES ";
-    const PLACEHOLDER: &str = "(?,?,?,?,?,?,?,?,?,?,?,?,?,?)";
+    const PLACEHOLDER: &str = "(?,?,?,?,?,?,?,?,?,?,?,?,?,?,?)";
     let mut sql = String::with_capacity(HEADER.len() + n * (PLACEHOLDER.len() + 1));
     sql.push_str(HEADER);
     for i in 0..n {
@@ -65,6 +73,50 @@ fn build_multi_insert_sql(n: usize) -> String {
     sql
 }
 
+fn build_hits_upsert_sql(n: usize) -> String {
+    debug_assert!(n > 0 && n <= HIT_ROWS_PER_CHUNK);
+    const HEADER: &str =
+        "INSERT INTO blocklist_source_hits (source_id, hour, domain, hits) VALUES ";
+    const FOOTER: &str =
+        " ON CONFLICT(source_id, hour, domain) DO UPDATE SET hits = hits + excluded.hits";
+    let mut sql = String::with_capacity(HEADER.len() + FOOTER.len() + n * 10);
+    sql.push_str(HEADER);
+    for i in 0..n {
+        if i > 0 {
+            sql.push(',');
+        }
+        sql.push_str("(?,?,?,?)");
+    }
+    sql.push_str(FOOTER);
+    sql
+}
+
+/// Adds the batch's blocked queries to the hourly per-source counters.
+async fn record_source_hits(tx: &mut Transaction<'_, Sqlite>, batch: &[QueryLogEntry]) {
+    let mut hits: HashMap<(i64, &str), i64, FxBuildHasher> = HashMap::with_hasher(FxBuildHasher);
+    for entry in batch {
+        for &source_id in &entry.blocklist_source_ids {
+            *hits.entry((source_id, entry.domain.as_str())).or_default() += 1;
+        }
+    }
+    if hits.is_empty() {
+        return;
+    }
+
+    let hour = current_hour();
+    let rows: Vec<_> = hits.into_iter().collect();
+    for chunk in rows.chunks(HIT_ROWS_PER_CHUNK) {
+        let sql = build_hits_upsert_sql(chunk.len());
+        let mut q = sqlx::query(&sql);
+        for ((source_id, domain), count) in chunk {
+            q = q.bind(source_id).bind(hour).bind(*domain).bind(count);
+        }
+        if let Err(e) = q.execute(&mut **tx).await {
+            warn!(error = %e, rows = chunk.len(), "Failed to record blocklist source hits");
+        }
+    }
+}
+
 pub(super) async fn flush_loop(
     pool: SqlitePool,
     mut receiver: mpsc::Receiver<QueryLogEntry>,
@@ -142,7 +194,8 @@ async fn flush_batch(pool: &SqlitePool, batch: &mut Vec<QueryLogEntry>) {
                 .bind(entry.response_status)
                 .bind(entry.query_source.as_str())
                 .bind(entry.group_id)
-                .bind(entry.block_source);
+                .bind(entry.block_source)
+                .bind(join_source_ids(&entry.blocklist_source_ids));
         }
         match q.execute(&mut *tx).await {
             Ok(r) => inserted += r.rows_affected() as usize,
@@ -153,6 +206,8 @@ async fn flush_batch(pool: &SqlitePool, batch: &mut Vec<QueryLogEntry>) {
         }
     }
 
+    record_source_hits(&mut tx, batch).await;
+
     match tx.commit().await {
         Ok(_) => {
             let elapsed = start.elapsed();
@@ -0,0 +1,197 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::TrustAnchorRepository;
+use ferrous_dns_domain::{
+    DomainError, TrustAnchor, TrustAnchorData, TrustAnchorSource, TrustAnchorState,
+};
+use sqlx::SqlitePool;
+use std::str::FromStr;
+use std::sync::Arc;
+use tracing::{error, instrument, warn};
+
+type TrustAnchorRow = (
+    i64,
+    String,
+    String,
+    i64,
+    i64,
+    Option<i64>,
+    Option<i64>,
+    Vec<u8>,
+    String,
+    String,
+    i64,
+    i64,
+    Option<i64>,
+    Option<i64>,
+);
+
+const TRUST_ANCHOR_COLUMNS: &str = "id, zone, record_type, key_tag, algorithm, flags, digest_type, key_data, state, source, managed, first_seen, last_seen, hold_down_until";
+
+pub struct SqliteTrustAnchorRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteTrustAnchorRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_anchor(row: TrustAnchorRow) -> Option<TrustAnchor> {
+        let (
+            id,
+            zone,
+            record_type,
+            key_tag,
+            algorithm,
+            flags,
+            digest_type,
+            key_data,
+            state,
+            source,
+            managed,
+            first_seen,
+            last_seen,
+            hold_down_until,
+        ) = row;
+
+        let data = match record_type.as_str() {
+            "DNSKEY" => TrustAnchorData::Dnskey {
+                flags: flags.unwrap_or(257) as u16,
+                algorithm: algorithm as u8,
+                public_key: Arc::from(key_data),
+            },
+            "DS" => TrustAnchorData::Ds {
+                key_tag: key_tag as u16,
+                algorithm: algorithm as u8,
+                digest_type: digest_type.unwrap_or(2) as u8,
+                digest: Arc::from(key_data),
+            },
+            other => {
+                warn!(id, record_type = %other, "Invalid trust anchor type in DB, skipping");
+                return None;
+            }
+        };
+
+        let state = TrustAnchorState::from_str(&state).unwrap_or_else(|_| {
+            warn!(id, state = %state, "Invalid trust anchor state in DB, treating as missing");
+            TrustAnchorState::Missing
+        });
+        let source = TrustAnchorSource::from_str(&source).unwrap_or(TrustAnchorSource::Config);
+
+        Some(TrustAnchor {
+            id: Some(id),
+            zone: Arc::from(zone.as_str()),
+            data,
+            state,
+            source,
+            managed: managed != 0,
+            first_seen,
+            last_seen,
+            hold_down_until,
+        })
+    }
+
+    async fn insert(&self, anchor: &TrustAnchor) -> Result<TrustAnchor, DomainError> {
+        let (flags, digest_type, key_data): (Option<i64>, Option<i64>, &[u8]) = match &anchor.data {
+            TrustAnchorData::Dnskey {
+                flags, public_key, ..
+            } => (Some(i64::from(*flags)), None, public_key),
+            TrustAnchorData::Ds {
+                digest_type,
+                digest,
+                ..
+            } => (None, Some(i64::from(*digest_type)), digest),
+        };
+
+        let row = sqlx::query_as::<_, TrustAnchorRow>(&format!(
+            "INSERT INTO trust_anchors (zone, record_type, key_tag, algorithm, flags, digest_type, key_data, state, source, managed, first_seen, last_seen, hold_down_until)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+             RETURNING {TRUST_ANCHOR_COLUMNS}"
+        ))
+        .bind(anchor.zone.as_ref())
+        .bind(anchor.data.type_str())
+        .bind(i64::from(anchor.key_tag()))
+        .bind(i64::from(anchor.data.algorithm()))
+        .bind(flags)
+        .bind(digest_type)
+        .bind(key_data)
+        .bind(anchor.state.as_str())
+        .bind(anchor.source.as_str())
+        .bind(if anchor.managed { 1i64 } else { 0i64 })
+        .bind(anchor.first_seen)
+        .bind(anchor.last_seen)
+        .bind(anchor.hold_down_until)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, zone = %anchor.zone, "Failed to insert trust anchor");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        Self::row_to_anchor(row)
+            .ok_or_else(|| DomainError::DatabaseError("Invalid trust anchor row".into()))
+    }
+}
+
+#[async_trait]
+impl TrustAnchorRepository for SqliteTrustAnchorRepository {
+    #[instrument(skip(self))]
+    async fn get_all(&self) -> Result<Vec<TrustAnchor>, DomainError> {
+        let rows = sqlx::query_as::<_, TrustAnchorRow>(&format!(
+            "SELECT {TRUST_ANCHOR_COLUMNS} FROM trust_anchors ORDER BY zone ASC, id ASC"
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to query trust anchors");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(rows.into_iter().filter_map(Self::row_to_anchor).collect())
+    }
+
+    #[instrument(skip(self, anchor), fields(zone = %anchor.zone))]
+    async fn save(&self, anchor: &TrustAnchor) -> Result<TrustAnchor, DomainError> {
+        let Some(id) = anchor.id else {
+            return self.insert(anchor).await;
+        };
+
+        let result = sqlx::query(
+            "UPDATE trust_anchors SET state = ?, last_seen = ?, hold_down_until = ? WHERE id = ?",
+        )
+        .bind(anchor.state.as_str())
+        .bind(anchor.last_seen)
+        .bind(anchor.hold_down_until)
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to update trust anchor");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::TrustAnchorNotFound(id));
+        }
+
+        Ok(anchor.clone())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(&self, id: i64) -> Result<(), DomainError> {
+        let result = sqlx::query("DELETE FROM trust_anchors WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to delete trust anchor");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::TrustAnchorNotFound(id));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::TsigKeyRepository;
+use ferrous_dns_domain::{DomainError, TsigKey, NTA_TIMESTAMP_FORMAT};
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use tracing::{error, instrument};
+
+type TsigKeyRow = (i64, String, String, String, String);
+
+const TSIG_KEY_COLUMNS: &str = "id, name, algorithm, secret, created_at";
+
+pub struct SqliteTsigKeyRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteTsigKeyRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_key(row: TsigKeyRow) -> TsigKey {
+        let (id, name, algorithm, secret, created_at) = row;
+        TsigKey {
+            id: Some(id),
+            name: Arc::from(name.as_str()),
+            algorithm: Arc::from(algorithm.as_str()),
+            secret: Arc::from(secret.as_str()),
+            created_at: Some(created_at),
+        }
+    }
+}
+
+#[async_trait]
+impl TsigKeyRepository for SqliteTsigKeyRepository {
+    #[instrument(skip(self, secret))]
+    async fn create(
+        &self,
+        name: String,
+        algorithm: String,
+        secret: String,
+    ) -> Result<TsigKey, DomainError> {
+        let now = chrono::Utc::now().format(NTA_TIMESTAMP_FORMAT).to_string();
+
+        let row = sqlx::query_as::<_, TsigKeyRow>(&format!(
+            "INSERT INTO tsig_keys (name, algorithm, secret, created_at)
+             VALUES (?, ?, ?, ?)
+             RETURNING {TSIG_KEY_COLUMNS}"
+        ))
+        .bind(&name)
+        .bind(&algorithm)
+        .bind(&secret)
+        .bind(&now)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            if e.to_string().contains("UNIQUE constraint failed") {
+                DomainError::InvalidTsigKey(format!("TSIG key '{}' already exists", name))
+            } else {
+                error!(error = %e, "Failed to create TSIG key");
+                DomainError::DatabaseError(e.to_string())
+            }
+        })?;
+
+        Ok(Self::row_to_key(row))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_by_id(&self, id: i64) -> Result<Option<TsigKey>, DomainError> {
+        let row = sqlx::query_as::<_, TsigKeyRow>(&format!(
+            "SELECT {TSIG_KEY_COLUMNS} FROM tsig_keys WHERE id = ?"
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to query TSIG key by id");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(row.map(Self::row_to_key))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_all(&self) -> Result<Vec<TsigKey>, DomainError> {
+        let rows = sqlx::query_as::<_, TsigKeyRow>(&format!(
+            "SELECT {TSIG_KEY_COLUMNS} FROM tsig_keys ORDER BY name ASC"
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to query all TSIG keys");
+            DomainError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(rows.into_iter().map(Self::row_to_key).collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(&self, id: i64) -> Result<(), DomainError> {
+        let result = sqlx::query("DELETE FROM tsig_keys WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to delete TSIG key");
+                DomainError::DatabaseError(e.to_string())
+            })?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::TsigKeyNotFound(id));
+        }
+
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
+use super::list_source_fetches;
 use async_trait::async_trait;
 use ferrous_dns_application::ports::WhitelistSourceRepository;
-use ferrous_dns_domain::{DomainError, WhitelistSource};
+use ferrous_dns_domain::{DomainError, ListSourceStatus, WhitelistSource};
 use sqlx::{Row, SqlitePool};
 use std::sync::Arc;
 use tracing::{error, instrument};
@@ -24,7 +25,11 @@ impl SqliteWhitelistSourceRepository {
         Self { pool }
     }
 
-    fn row_to_source(row: WhitelistSourceRow, group_ids: Vec<i64>) -> WhitelistSource {
+    fn row_to_source(
+        row: WhitelistSourceRow,
+        group_ids: Vec<i64>,
+        status: Option<ListSourceStatus>,
+    ) -> WhitelistSource {
         let (id, name, url, comment, enabled, created_at, updated_at) = row;
         WhitelistSource {
             id: Some(id),
@@ -35,6 +40,7 @@ impl SqliteWhitelistSourceRepository {
             enabled: enabled != 0,
             created_at: Some(created_at),
             updated_at: Some(updated_at),
+            status,
         }
     }
 
@@ -119,7 +125,7 @@ impl WhitelistSourceRepository for SqliteWhitelistSourceRepository {
             DomainError::DatabaseError(e.to_string())
         })?;
 
-        Ok(Self::row_to_source(row, group_ids))
+        Ok(Self::row_to_source(row, group_ids, None))
     }
 
     #[instrument(skip(self))]
@@ -140,7 +146,8 @@ impl WhitelistSourceRepository for SqliteWhitelistSourceRepository {
             None => Ok(None),
             Some(r) => {
                 let group_ids = self.fetch_group_ids(r.0).await?;
-                Ok(Some(Self::row_to_source(r, group_ids)))
+                let status = list_source_fetches::fetch_status(&self.pool, r.2.as_deref()).await?;
+                Ok(Some(Self::row_to_source(r, group_ids, status)))
             }
         }
     }
@@ -161,7 +168,8 @@ impl WhitelistSourceRepository for SqliteWhitelistSourceRepository {
         let mut sources = Vec::with_capacity(rows.len());
         for row in rows {
             let group_ids = self.fetch_group_ids(row.0).await?;
-            sources.push(Self::row_to_source(row, group_ids));
+            let status = list_source_fetches::fetch_status(&self.pool, row.2.as_deref()).await?;
+            sources.push(Self::row_to_source(row, group_ids, status));
         }
         Ok(sources)
     }
@@ -253,7 +261,8 @@ impl WhitelistSourceRepository for SqliteWhitelistSourceRepository {
             DomainError::DatabaseError(e.to_string())
         })?;
 
-        Ok(Self::row_to_source(row, final_group_ids))
+        let status = list_source_fetches::fetch_status(&self.pool, row.2.as_deref()).await?;
+        Ok(Self::row_to_source(row, final_group_ids, status))
     }
 
     #[instrument(skip(self))]
@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 use ferrous_dns_application::ports::HostnameResolver;
-use ferrous_dns_domain::{DomainError, RecordType};
+use ferrous_dns_domain::{DomainError, QueryScope, RecordType};
 use hickory_proto::rr::RData;
 use std::net::IpAddr;
 use std::sync::Arc;
@@ -99,7 +99,13 @@ impl HostnameResolver for PtrHostnameResolver {
         let domain_arc: Arc<str> = Arc::from(reverse_domain.as_str());
         match self
             .pool_manager
-            .query(&domain_arc, &RecordType::PTR, timeout_ms, false)
+            .query(
+                &domain_arc,
+                &RecordType::PTR,
+                timeout_ms,
+                false,
+                QueryScope::SHARED,
+            )
             .await
         {
             Ok(result) => {
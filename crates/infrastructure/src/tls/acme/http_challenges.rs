@@ -0,0 +1,34 @@
+use dashmap::DashMap;
+use std::sync::Arc;
+
+/// Key authorizations for pending HTTP-01 challenges, served by the web
+/// server at `/.well-known/acme-challenge/<token>`.
+#[derive(Default)]
+pub struct AcmeHttpChallenges {
+    tokens: DashMap<Box<str>, Arc<str>>,
+}
+
+impl AcmeHttpChallenges {
+    pub const PATH_PREFIX: &'static str = "/.well-known/acme-challenge/";
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn publish(&self, token: &str, key_authorization: &str) {
+        self.tokens
+            .insert(token.into(), Arc::from(key_authorization));
+    }
+
+    pub fn withdraw(&self, token: &str) {
+        self.tokens.remove(token);
+    }
+
+    pub fn get(&self, token: &str) -> Option<Arc<str>> {
+        self.tokens.get(token).map(|v| Arc::clone(v.value()))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+}
@@ -0,0 +1,462 @@
+mod account;
+mod http_challenges;
+
+pub use account::AccountKey;
+pub use http_challenges::AcmeHttpChallenges;
+
+use crate::dns::AcmeChallengeTable;
+use async_trait::async_trait;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use bytes::Bytes;
+use ferrous_dns_application::ports::{AcmeCertificateIssuer, IssuedCertificate};
+use ferrous_dns_domain::{AcmeChallengeType, AcmeConfig, DomainError};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+const JOSE_JSON: &str = "application/jose+json";
+const PEM_CHAIN: &str = "application/pem-certificate-chain";
+const BAD_NONCE_RETRIES: usize = 3;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Directory {
+    new_nonce: String,
+    new_account: String,
+    new_order: String,
+}
+
+#[derive(Deserialize)]
+struct Order {
+    status: String,
+    #[serde(default)]
+    authorizations: Vec<String>,
+    finalize: String,
+    certificate: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Identifier {
+    value: String,
+}
+
+#[derive(Deserialize)]
+struct Authorization {
+    status: String,
+    identifier: Identifier,
+    #[serde(default)]
+    challenges: Vec<Challenge>,
+}
+
+#[derive(Deserialize)]
+struct Challenge {
+    #[serde(rename = "type")]
+    kind: String,
+    url: String,
+    #[serde(default)]
+    token: String,
+}
+
+/// A challenge response published for the duration of an order.
+enum Published {
+    Http(String),
+    Dns(String),
+}
+
+struct AcmeResponse {
+    location: Option<String>,
+    body: Bytes,
+}
+
+impl AcmeResponse {
+    fn json<T: for<'de> Deserialize<'de>>(&self) -> Result<T, DomainError> {
+        serde_json::from_slice(&self.body)
+            .map_err(|e| order_failed(format!("Unexpected response from the CA: {e}")))
+    }
+}
+
+/// RFC 8555 client that orders certificates from an ACME CA, answering
+/// HTTP-01 challenges through the web server or DNS-01 challenges through
+/// the DNS server.
+pub struct AcmeClient {
+    http: reqwest::Client,
+    directory_url: String,
+    email: Option<String>,
+    account_key_path: String,
+    challenge: AcmeChallengeType,
+    http_challenges: Arc<AcmeHttpChallenges>,
+    dns_challenges: Arc<AcmeChallengeTable>,
+    poll_interval: Duration,
+    poll_attempts: u32,
+}
+
+impl AcmeClient {
+    pub fn new(
+        config: &AcmeConfig,
+        http_challenges: Arc<AcmeHttpChallenges>,
+        dns_challenges: Arc<AcmeChallengeTable>,
+    ) -> Result<Self, DomainError> {
+        let mut builder = reqwest::Client::builder()
+            .use_rustls_tls()
+            .user_agent("ferrous-dns/1.0 (acme)")
+            .timeout(Duration::from_secs(30));
+        if let Some(path) = &config.ca_cert_path {
+            let pem = std::fs::read(path)
+                .map_err(|e| DomainError::IoError(format!("Failed to read {path}: {e}")))?;
+            let root = reqwest::Certificate::from_pem(&pem)
+                .map_err(|e| DomainError::InvalidInput(format!("Invalid CA certificate: {e}")))?;
+            builder = builder.add_root_certificate(root);
+        }
+        let http = builder.build().map_err(|e| order_failed(e.to_string()))?;
+
+        Ok(Self {
+            http,
+            directory_url: config.directory_url.clone(),
+            email: config.email.clone(),
+            account_key_path: config.account_key_path.clone(),
+            challenge: config.challenge,
+            http_challenges,
+            dns_challenges,
+            poll_interval: Duration::from_secs(2),
+            poll_attempts: 90,
+        })
+    }
+
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    fn challenge_name(&self) -> &'static str {
+        match self.challenge {
+            AcmeChallengeType::Http01 => "http-01",
+            AcmeChallengeType::Dns01 => "dns-01",
+        }
+    }
+
+    async fn get_json<T: for<'de> Deserialize<'de>>(&self, url: &str) -> Result<T, DomainError> {
+        let response = self
+            .http
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| order_failed(format!("{url}: {e}")))?;
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| order_failed(format!("{url}: {e}")))?;
+        serde_json::from_slice(&body).map_err(|e| order_failed(format!("{url}: {e}")))
+    }
+
+    async fn run_order(
+        &self,
+        session: &mut Session<'_>,
+        domains: &[String],
+        published: &mut Vec<Published>,
+    ) -> Result<IssuedCertificate, DomainError> {
+        let identifiers: Vec<Value> = domains
+            .iter()
+            .map(|d| json!({ "type": "dns", "value": d }))
+            .collect();
+        let created = session
+            .post(
+                &session.directory.new_order.clone(),
+                Some(&json!({ "identifiers": identifiers })),
+            )
+            .await?;
+        let order_url = created
+            .location
+            .clone()
+            .ok_or_else(|| order_failed("Order has no Location".into()))?;
+        let order: Order = created.json()?;
+
+        let mut pending = Vec::new();
+        for authz_url in &order.authorizations {
+            let authz: Authorization = session.post(authz_url, None).await?.json()?;
+            if authz.status == "valid" {
+                continue;
+            }
+            let challenge = authz
+                .challenges
+                .iter()
+                .find(|c| c.kind == self.challenge_name())
+                .ok_or_else(|| {
+                    order_failed(format!(
+                        "CA offers no {} challenge for {}",
+                        self.challenge_name(),
+                        authz.identifier.value
+                    ))
+                })?;
+
+            let key_authorization = session.key.key_authorization(&challenge.token);
+            match self.challenge {
+                AcmeChallengeType::Http01 => {
+                    self.http_challenges
+                        .publish(&challenge.token, &key_authorization);
+                    published.push(Published::Http(challenge.token.clone()));
+                }
+                AcmeChallengeType::Dns01 => {
+                    let name = format!(
+                        "_acme-challenge.{}",
+                        authz.identifier.value.to_ascii_lowercase()
+                    );
+                    let digest = Sha256::digest(key_authorization.as_bytes());
+                    self.dns_challenges
+                        .publish_txt(&name, &URL_SAFE_NO_PAD.encode(digest));
+                    published.push(Published::Dns(name));
+                }
+            }
+            debug!(identifier = %authz.identifier.value, "ACME challenge published");
+            pending.push((authz_url.clone(), challenge.url.clone()));
+        }
+
+        for (_, challenge_url) in &pending {
+            session.post(challenge_url, Some(&json!({}))).await?;
+        }
+        for (authz_url, _) in &pending {
+            self.wait_for_authorization(session, authz_url).await?;
+        }
+        self.wait_for_order_ready(session, &order_url).await?;
+
+        let key_pair = rcgen::KeyPair::generate().map_err(|e| order_failed(e.to_string()))?;
+        let mut params = rcgen::CertificateParams::new(domains.to_vec())
+            .map_err(|e| order_failed(e.to_string()))?;
+        params.distinguished_name = rcgen::DistinguishedName::new();
+        let csr = params
+            .serialize_request(&key_pair)
+            .map_err(|e| order_failed(e.to_string()))?;
+        session
+            .post(
+                &order.finalize,
+                Some(&json!({ "csr": URL_SAFE_NO_PAD.encode(csr.der()) })),
+            )
+            .await?;
+
+        let certificate_url = self.wait_for_certificate(session, &order_url).await?;
+        let cert_pem = session.download(&certificate_url).await?;
+
+        Ok(IssuedCertificate {
+            cert_pem,
+            key_pem: key_pair.serialize_pem(),
+        })
+    }
+
+    async fn wait_for_authorization(
+        &self,
+        session: &mut Session<'_>,
+        url: &str,
+    ) -> Result<(), DomainError> {
+        for _ in 0..self.poll_attempts {
+            let authz: Authorization = session.post(url, None).await?.json()?;
+            match authz.status.as_str() {
+                "valid" => return Ok(()),
+                "pending" | "processing" => tokio::time::sleep(self.poll_interval).await,
+                other => {
+                    return Err(order_failed(format!(
+                        "Authorization for {} is {other}",
+                        authz.identifier.value
+                    )))
+                }
+            }
+        }
+        Err(order_failed("Timed out waiting for authorization".into()))
+    }
+
+    /// The CA only accepts a CSR once the order is `ready` (RFC 8555 §7.4),
+    /// which may lag behind the last authorization turning valid.
+    async fn wait_for_order_ready(
+        &self,
+        session: &mut Session<'_>,
+        order_url: &str,
+    ) -> Result<(), DomainError> {
+        for _ in 0..self.poll_attempts {
+            let order: Order = session.post(order_url, None).await?.json()?;
+            match order.status.as_str() {
+                "ready" => return Ok(()),
+                "pending" | "processing" => tokio::time::sleep(self.poll_interval).await,
+                other => return Err(order_failed(format!("Order is {other}"))),
+            }
+        }
+        Err(order_failed(
+            "Timed out waiting for the order to become ready".into(),
+        ))
+    }
+
+    async fn wait_for_certificate(
+        &self,
+        session: &mut Session<'_>,
+        order_url: &str,
+    ) -> Result<String, DomainError> {
+        for _ in 0..self.poll_attempts {
+            let order: Order = session.post(order_url, None).await?.json()?;
+            match (order.status.as_str(), order.certificate) {
+                ("valid", Some(url)) => return Ok(url),
+                ("pending" | "ready" | "processing" | "valid", _) => {
+                    tokio::time::sleep(self.poll_interval).await
+                }
+                (other, _) => return Err(order_failed(format!("Order is {other}"))),
+            }
+        }
+        Err(order_failed("Timed out waiting for the certificate".into()))
+    }
+
+    fn withdraw(&self, published: &[Published]) {
+        for entry in published {
+            match entry {
+                Published::Http(token) => self.http_challenges.withdraw(token),
+                Published::Dns(name) => self.dns_challenges.withdraw_txt(name),
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl AcmeCertificateIssuer for AcmeClient {
+    async fn issue(&self, domains: &[String]) -> Result<IssuedCertificate, DomainError> {
+        let key = AccountKey::load_or_create(&self.account_key_path)?;
+        let directory: Directory = self.get_json(&self.directory_url).await?;
+        let mut session = Session {
+            http: &self.http,
+            key,
+            directory,
+            nonce: None,
+            kid: None,
+        };
+
+        let mut payload = json!({ "termsOfServiceAgreed": true });
+        if let Some(email) = &self.email {
+            payload["contact"] = json!([format!("mailto:{email}")]);
+        }
+        let account = session
+            .post(&session.directory.new_account.clone(), Some(&payload))
+            .await?;
+        session.kid = Some(
+            account
+                .location
+                .ok_or_else(|| order_failed("Account has no Location".into()))?,
+        );
+
+        let mut published = Vec::new();
+        let result = self.run_order(&mut session, domains, &mut published).await;
+        self.withdraw(&published);
+
+        match &result {
+            Ok(_) => info!(domains = ?domains, "ACME order completed"),
+            Err(e) => warn!(domains = ?domains, error = %e, "ACME order failed"),
+        }
+        result
+    }
+}
+
+/// One conversation with the CA: the account key, the current nonce and,
+/// once registered, the account URL used as `kid`.
+struct Session<'a> {
+    http: &'a reqwest::Client,
+    key: AccountKey,
+    directory: Directory,
+    nonce: Option<String>,
+    kid: Option<String>,
+}
+
+impl Session<'_> {
+    async fn fresh_nonce(&mut self) -> Result<String, DomainError> {
+        if let Some(nonce) = self.nonce.take() {
+            return Ok(nonce);
+        }
+        let response = self
+            .http
+            .head(&self.directory.new_nonce)
+            .send()
+            .await
+            .map_err(|e| order_failed(format!("newNonce: {e}")))?;
+        replay_nonce(&response).ok_or_else(|| order_failed("CA returned no nonce".into()))
+    }
+
+    /// Signed POST; `None` sends a POST-as-GET.
+    async fn post(
+        &mut self,
+        url: &str,
+        payload: Option<&Value>,
+    ) -> Result<AcmeResponse, DomainError> {
+        self.send(url, payload, JOSE_JSON).await
+    }
+
+    async fn download(&mut self, url: &str) -> Result<String, DomainError> {
+        let response = self.send(url, None, PEM_CHAIN).await?;
+        String::from_utf8(response.body.to_vec())
+            .map_err(|_| order_failed("Certificate is not valid PEM".into()))
+    }
+
+    async fn send(
+        &mut self,
+        url: &str,
+        payload: Option<&Value>,
+        accept: &str,
+    ) -> Result<AcmeResponse, DomainError> {
+        let payload = payload.map(Value::to_string).unwrap_or_default();
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            let mut protected = json!({
+                "alg": "ES256",
+                "nonce": self.fresh_nonce().await?,
+                "url": url,
+            });
+            match &self.kid {
+                Some(kid) => protected["kid"] = json!(kid),
+                None => protected["jwk"] = self.key.jwk().clone(),
+            }
+            let body = self.key.sign(&protected, &payload)?;
+
+            let response = self
+                .http
+                .post(url)
+                .header(reqwest::header::CONTENT_TYPE, JOSE_JSON)
+                .header(reqwest::header::ACCEPT, accept)
+                .body(body.to_string())
+                .send()
+                .await
+                .map_err(|e| order_failed(format!("{url}: {e}")))?;
+
+            self.nonce = replay_nonce(&response);
+            let status = response.status();
+            let location = response
+                .headers()
+                .get(reqwest::header::LOCATION)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string);
+            let body = response
+                .bytes()
+                .await
+                .map_err(|e| order_failed(format!("{url}: {e}")))?;
+
+            if status.is_success() {
+                return Ok(AcmeResponse { location, body });
+            }
+
+            let problem: Value = serde_json::from_slice(&body).unwrap_or_default();
+            let kind = problem["type"].as_str().unwrap_or_default();
+            if kind.ends_with(":badNonce") && attempt < BAD_NONCE_RETRIES {
+                continue;
+            }
+            let detail = problem["detail"].as_str().unwrap_or(kind);
+            return Err(order_failed(format!("{url}: {status} {detail}")));
+        }
+    }
+}
+
+fn replay_nonce(response: &reqwest::Response) -> Option<String> {
+    response
+        .headers()
+        .get("replay-nonce")
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string)
+}
+
+fn order_failed(message: String) -> DomainError {
+    DomainError::AcmeOrderFailed(message)
+}
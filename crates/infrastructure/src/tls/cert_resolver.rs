@@ -0,0 +1,225 @@
+use arc_swap::ArcSwapOption;
+use ferrous_dns_application::ports::TlsCertificateReloader;
+use ferrous_dns_domain::DomainError;
+use rustls::crypto::CryptoProvider;
+use rustls::pki_types::CertificateDer;
+use rustls::server::{ClientHello, ResolvesServerCert};
+use rustls::sign::CertifiedKey;
+use sha2::{Digest, Sha256};
+use std::io::BufReader;
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+use tracing::{info, warn};
+
+/// Modification time and length of a file, `None` when it is unreadable.
+type FileStamp = Option<(SystemTime, u64)>;
+
+/// Serves the certificate last loaded from a cert/key file pair.
+///
+/// [`reload`](Self::reload) swaps in a new pair for subsequent handshakes;
+/// established connections keep the session they negotiated. Until a pair
+/// has loaded, handshakes are refused. The files' modification times are
+/// remembered so [`reload_if_changed`](Self::reload_if_changed) only reads
+/// them again after they change.
+#[derive(Debug)]
+pub struct ReloadableCertResolver {
+    cert_path: Box<str>,
+    key_path: Box<str>,
+    current: ArcSwapOption<CertifiedKey>,
+    stamp: Mutex<Option<[FileStamp; 2]>>,
+}
+
+impl ReloadableCertResolver {
+    /// A resolver with nothing loaded yet.
+    pub fn new(cert_path: &str, key_path: &str) -> Self {
+        Self {
+            cert_path: cert_path.into(),
+            key_path: key_path.into(),
+            current: ArcSwapOption::empty(),
+            stamp: Mutex::new(None),
+        }
+    }
+
+    pub fn cert_path(&self) -> &str {
+        &self.cert_path
+    }
+
+    pub fn key_path(&self) -> &str {
+        &self.key_path
+    }
+
+    pub fn is_loaded(&self) -> bool {
+        self.current.load().is_some()
+    }
+
+    /// SHA-256 fingerprint of the leaf certificate being served.
+    pub fn fingerprint(&self) -> Option<String> {
+        self.current
+            .load()
+            .as_ref()
+            .and_then(|key| key.cert.first().map(|der| sha256_fingerprint(der)))
+    }
+
+    /// Reads both files and swaps them in. On error the previous
+    /// certificate stays in use.
+    pub fn reload(&self) -> Result<(), DomainError> {
+        *self.stamp.lock().unwrap_or_else(|e| e.into_inner()) = Some(self.file_stamps());
+
+        let cert_pem = std::fs::read(&*self.cert_path).map_err(|e| {
+            DomainError::IoError(format!("Failed to read TLS cert {}: {e}", self.cert_path))
+        })?;
+        let key_pem = std::fs::read(&*self.key_path).map_err(|e| {
+            DomainError::IoError(format!("Failed to read TLS key {}: {e}", self.key_path))
+        })?;
+
+        let certified = certified_key_from_pem(&cert_pem, &key_pem)?;
+        self.current.store(Some(Arc::new(certified)));
+        info!(path = %self.cert_path, "TLS certificate loaded");
+        Ok(())
+    }
+
+    /// Reloads when either file changed since the last attempt. Returns
+    /// true when a new certificate was swapped in.
+    pub fn reload_if_changed(&self) -> Result<bool, DomainError> {
+        let stamps = self.file_stamps();
+        if *self.stamp.lock().unwrap_or_else(|e| e.into_inner()) == Some(stamps) {
+            return Ok(false);
+        }
+        self.reload().map(|()| true)
+    }
+
+    fn file_stamps(&self) -> [FileStamp; 2] {
+        [&*self.cert_path, &*self.key_path].map(|path| {
+            let meta = std::fs::metadata(path).ok()?;
+            Some((meta.modified().ok()?, meta.len()))
+        })
+    }
+
+    /// A rustls server config that resolves certificates through `self`.
+    pub fn server_config(self: &Arc<Self>) -> Arc<rustls::ServerConfig> {
+        let _ = crypto_provider();
+        let config = rustls::ServerConfig::builder()
+            .with_no_client_auth()
+            .with_cert_resolver(Arc::clone(self) as Arc<dyn ResolvesServerCert>);
+        Arc::new(config)
+    }
+}
+
+impl ResolvesServerCert for ReloadableCertResolver {
+    fn resolve(&self, _client_hello: ClientHello<'_>) -> Option<Arc<CertifiedKey>> {
+        self.current.load_full()
+    }
+}
+
+/// Every certificate resolver in use, keyed by file pair, so a renewal or
+/// upload reaches all listeners serving those files.
+#[derive(Default)]
+pub struct CertificateResolvers {
+    resolvers: Mutex<Vec<Arc<ReloadableCertResolver>>>,
+}
+
+impl CertificateResolvers {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The resolver for `cert_path`/`key_path`, shared between listeners
+    /// using the same files. A new resolver starts empty.
+    pub fn resolver(&self, cert_path: &str, key_path: &str) -> Arc<ReloadableCertResolver> {
+        let mut resolvers = self.resolvers.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(existing) = resolvers
+            .iter()
+            .find(|r| r.cert_path() == cert_path && r.key_path() == key_path)
+        {
+            return Arc::clone(existing);
+        }
+        let resolver = Arc::new(ReloadableCertResolver::new(cert_path, key_path));
+        resolvers.push(Arc::clone(&resolver));
+        resolver
+    }
+
+    pub fn all(&self) -> Vec<Arc<ReloadableCertResolver>> {
+        self.resolvers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+}
+
+impl TlsCertificateReloader for CertificateResolvers {
+    fn reload(&self, cert_path: &str, key_path: &str) -> Result<bool, DomainError> {
+        let matching: Vec<_> = self
+            .all()
+            .into_iter()
+            .filter(|r| r.cert_path() == cert_path && r.key_path() == key_path)
+            .collect();
+        matching.iter().try_for_each(|r| r.reload())?;
+        Ok(!matching.is_empty())
+    }
+
+    fn reload_changed(&self) -> usize {
+        self.all()
+            .iter()
+            .filter(|resolver| match resolver.reload_if_changed() {
+                Ok(changed) => changed,
+                Err(e) => {
+                    warn!(
+                        path = resolver.cert_path(),
+                        error = %e,
+                        "TLS certificate change rejected — keeping the previous certificate"
+                    );
+                    false
+                }
+            })
+            .count()
+    }
+
+    fn active_fingerprint(&self, cert_path: &str, key_path: &str) -> Option<String> {
+        self.all()
+            .iter()
+            .find(|r| r.cert_path() == cert_path && r.key_path() == key_path)
+            .and_then(|r| r.fingerprint())
+    }
+}
+
+/// SHA-256 of a DER certificate as colon-separated uppercase hex, the form
+/// `openssl x509 -fingerprint -sha256` prints.
+pub(crate) fn sha256_fingerprint(der: &[u8]) -> String {
+    Sha256::digest(der)
+        .iter()
+        .map(|byte| format!("{byte:02X}"))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+/// Parses a PEM chain and key and checks that they belong together.
+pub(crate) fn certified_key_from_pem(
+    cert_pem: &[u8],
+    key_pem: &[u8],
+) -> Result<CertifiedKey, DomainError> {
+    let certs: Vec<CertificateDer<'static>> = rustls_pemfile::certs(&mut BufReader::new(cert_pem))
+        .collect::<Result<_, _>>()
+        .map_err(|e| DomainError::InvalidInput(format!("Invalid certificate PEM: {e}")))?;
+    if certs.is_empty() {
+        return Err(DomainError::InvalidInput(
+            "No certificates found in PEM data".into(),
+        ));
+    }
+    let key = rustls_pemfile::private_key(&mut BufReader::new(key_pem))
+        .map_err(|e| DomainError::InvalidInput(format!("Invalid key PEM: {e}")))?
+        .ok_or_else(|| DomainError::InvalidInput("No private key found in PEM data".into()))?;
+
+    CertifiedKey::from_der(certs, key, &crypto_provider()).map_err(|e| match e {
+        rustls::Error::InconsistentKeys(_) => DomainError::InvalidInput(
+            "Private key does not match the certificate's public key".into(),
+        ),
+        other => DomainError::InvalidInput(format!("Unusable certificate or key: {other}")),
+    })
+}
+
+fn crypto_provider() -> Arc<CryptoProvider> {
+    let _ = rustls::crypto::aws_lc_rs::default_provider().install_default();
+    CryptoProvider::get_default()
+        .cloned()
+        .unwrap_or_else(|| Arc::new(rustls::crypto::aws_lc_rs::default_provider()))
+}
@@ -1,3 +1,9 @@
+pub mod acme;
+mod cert_resolver;
+
+pub use acme::{AcmeClient, AcmeHttpChallenges};
+pub use cert_resolver::{CertificateResolvers, ReloadableCertResolver};
+
 use ferrous_dns_application::ports::{TlsCertificateInfo, TlsCertificatePort};
 use ferrous_dns_domain::DomainError;
 use std::io::BufReader;
@@ -14,23 +20,26 @@ impl TlsCertificatePort for TlsCertificateService {
         let cert_exists = Path::new(cert_path).exists();
         let key_exists = Path::new(key_path).exists();
 
-        let (cert_subject, cert_not_after, cert_valid) = if cert_exists {
+        let parsed = if cert_exists {
             tokio::task::spawn_blocking({
                 let path = cert_path.to_string();
                 move || parse_cert_info(&path)
             })
             .await
-            .unwrap_or((None, None, false))
+            .unwrap_or_default()
         } else {
-            (None, None, false)
+            ParsedCertInfo::default()
         };
 
         TlsCertificateInfo {
             cert_exists,
             key_exists,
-            cert_subject,
-            cert_not_after,
-            cert_valid,
+            cert_subject: parsed.subject,
+            cert_not_after: parsed.not_after,
+            cert_valid: parsed.valid,
+            cert_not_after_unix: parsed.not_after_unix,
+            cert_dns_names: parsed.dns_names,
+            cert_fingerprint_sha256: parsed.fingerprint,
         }
     }
 
@@ -54,6 +63,7 @@ impl TlsCertificatePort for TlsCertificateService {
 
         validate_pem_cert(cert_data)?;
         validate_pem_key(key_data)?;
+        cert_resolver::certified_key_from_pem(cert_data, key_data)?;
 
         ensure_parent_dir(cert_path).await?;
         tokio::fs::write(cert_path, cert_data)
@@ -119,31 +129,57 @@ fn generate_self_signed_cert() -> Result<(String, String), String> {
     Ok((cert.pem(), key_pair.serialize_pem()))
 }
 
-fn parse_cert_info(cert_path: &str) -> (Option<String>, Option<String>, bool) {
+#[derive(Default)]
+struct ParsedCertInfo {
+    subject: Option<String>,
+    not_after: Option<String>,
+    not_after_unix: Option<i64>,
+    dns_names: Vec<String>,
+    fingerprint: Option<String>,
+    valid: bool,
+}
+
+fn parse_cert_info(cert_path: &str) -> ParsedCertInfo {
     let Ok(data) = std::fs::read(cert_path) else {
-        return (None, None, false);
+        return ParsedCertInfo::default();
     };
 
     let Ok(pems) = x509_parser::pem::Pem::iter_from_buffer(&data).collect::<Result<Vec<_>, _>>()
     else {
         warn!("Failed to parse PEM from {}", cert_path);
-        return (None, None, false);
+        return ParsedCertInfo::default();
     };
 
     let Some(pem) = pems.first() else {
-        return (None, None, false);
+        return ParsedCertInfo::default();
     };
 
     match x509_parser::parse_x509_certificate(&pem.contents) {
         Ok((_, cert)) => {
-            let subject = cert.subject().to_string();
-            let not_after = cert.validity().not_after.to_rfc2822().ok();
-            let valid = cert.validity().is_valid();
-            (Some(subject), not_after, valid)
+            let dns_names = match cert.subject_alternative_name() {
+                Ok(Some(san)) => san
+                    .value
+                    .general_names
+                    .iter()
+                    .filter_map(|name| match name {
+                        x509_parser::extensions::GeneralName::DNSName(dns) => Some(dns.to_string()),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => Vec::new(),
+            };
+            ParsedCertInfo {
+                subject: Some(cert.subject().to_string()),
+                not_after: cert.validity().not_after.to_rfc2822().ok(),
+                not_after_unix: Some(cert.validity().not_after.timestamp()),
+                dns_names,
+                fingerprint: Some(cert_resolver::sha256_fingerprint(&pem.contents)),
+                valid: cert.validity().is_valid(),
+            }
         }
         Err(e) => {
             warn!(error = %e, "Failed to parse X.509 certificate");
-            (None, None, false)
+            ParsedCertInfo::default()
         }
     }
 }
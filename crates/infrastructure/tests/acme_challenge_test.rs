@@ -0,0 +1,256 @@
+use async_trait::async_trait;
+use base64::Engine;
+use ferrous_dns_application::ports::{
+    AcmeChallengeStore, DnsResolution, DnsResolver, LocalZoneStore,
+};
+use ferrous_dns_domain::{AcmeRegistration, DnsQuery, DomainError, RecordType};
+use ferrous_dns_infrastructure::dns::authority::{parse_zone_text, LocalAuthority};
+use ferrous_dns_infrastructure::dns::resolver::LocalAuthorityResolver;
+use ferrous_dns_infrastructure::dns::AcmeChallengeTable;
+use hickory_proto::op::{Message, ResponseCode};
+use hickory_proto::rr::{RData, RecordType as HickoryRecordType};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+
+const ZONE: &str = "acme.example.com";
+const SUBDOMAIN: &str = "d420c923-bbd7-4056-ab64-c3ca54c9b3cf";
+const TXT_A: &str = "LHDhK3oGRvkiefQnx7OOczTY5Tic_xZ6HcMOc_gmtoM";
+const TXT_B: &str = "0123456789abcdefghijklmnopqrstuvwxyzABCDEFG";
+
+fn registration(subdomain: &str, txt: &[&str]) -> AcmeRegistration {
+    AcmeRegistration {
+        id: Some(1),
+        username: Arc::from("c36f50e8-4632-44f0-83fe-e070fef28a10"),
+        password_hash: Arc::from(""),
+        subdomain: Arc::from(subdomain),
+        allow_from: Vec::new(),
+        txt: txt.iter().map(|t| Arc::from(*t)).collect(),
+        created_at: None,
+        updated_at: None,
+    }
+}
+
+fn table(txt: &[&str]) -> AcmeChallengeTable {
+    let table = AcmeChallengeTable::new(ZONE, &format!("ns.{ZONE}"), 10);
+    table.replace_registrations(&[registration(SUBDOMAIN, txt)]);
+    table
+}
+
+fn txt_strings(records: &[hickory_proto::rr::Record]) -> Vec<String> {
+    records
+        .iter()
+        .filter_map(|record| match record.data() {
+            RData::TXT(txt) => Some(txt.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+// ── challenge table ───────────────────────────────────────────────────────────
+
+#[test]
+fn answers_txt_values_newest_first_with_short_ttl() {
+    let response = table(&[TXT_B, TXT_A])
+        .answer(&format!("{SUBDOMAIN}.{ZONE}"), HickoryRecordType::TXT)
+        .unwrap();
+
+    assert!(response.authoritative);
+    assert_eq!(response.response_code, ResponseCode::NoError);
+    assert_eq!(txt_strings(&response.answers), vec![TXT_B, TXT_A]);
+    assert!(response.answers.iter().all(|r| r.ttl() == 10));
+}
+
+#[test]
+fn subdomain_lookup_is_case_insensitive_on_registration() {
+    let table = AcmeChallengeTable::new(ZONE, &format!("ns.{ZONE}"), 10);
+    table.replace_registrations(&[registration(&SUBDOMAIN.to_uppercase(), &[TXT_A])]);
+
+    let response = table
+        .answer(&format!("{SUBDOMAIN}.{ZONE}"), HickoryRecordType::TXT)
+        .unwrap();
+    assert_eq!(txt_strings(&response.answers), vec![TXT_A]);
+}
+
+#[test]
+fn unknown_subdomain_is_nxdomain_with_soa() {
+    let response = table(&[TXT_A])
+        .answer(&format!("unknown.{ZONE}"), HickoryRecordType::TXT)
+        .unwrap();
+
+    assert_eq!(response.response_code, ResponseCode::NXDomain);
+    assert!(response.answers.is_empty());
+    assert_eq!(response.authority[0].record_type(), HickoryRecordType::SOA);
+}
+
+#[test]
+fn other_types_and_empty_registrations_are_nodata() {
+    let table = table(&[]);
+
+    let empty = table
+        .answer(&format!("{SUBDOMAIN}.{ZONE}"), HickoryRecordType::TXT)
+        .unwrap();
+    assert_eq!(empty.response_code, ResponseCode::NoError);
+    assert!(empty.answers.is_empty());
+    assert_eq!(empty.authority[0].record_type(), HickoryRecordType::SOA);
+}
+
+#[test]
+fn apex_serves_soa_and_ns() {
+    let table = table(&[TXT_A]);
+
+    let soa = table.answer(ZONE, HickoryRecordType::SOA).unwrap();
+    assert_eq!(soa.answers[0].record_type(), HickoryRecordType::SOA);
+
+    let ns = table.answer(ZONE, HickoryRecordType::NS).unwrap();
+    match ns.answers[0].data() {
+        RData::NS(ns) => assert_eq!(ns.0.to_ascii(), format!("ns.{ZONE}.")),
+        other => panic!("expected NS, got {other:?}"),
+    }
+}
+
+#[test]
+fn soa_serial_advances_on_every_reload() {
+    let table = table(&[TXT_A]);
+    let serial = |table: &AcmeChallengeTable| match table
+        .answer(ZONE, HickoryRecordType::SOA)
+        .unwrap()
+        .answers[0]
+        .data()
+    {
+        RData::SOA(soa) => soa.serial(),
+        other => panic!("expected SOA, got {other:?}"),
+    };
+
+    let before = serial(&table);
+    table.replace_registrations(&[registration(SUBDOMAIN, &[TXT_B])]);
+    assert!(serial(&table) > before);
+}
+
+#[test]
+fn names_outside_the_zone_and_disabled_table_are_not_answered() {
+    let table = table(&[TXT_A]);
+    assert!(!table.contains("example.com"));
+    assert!(!table.contains("notacme.example.com"));
+    assert!(table
+        .answer("www.example.com", HickoryRecordType::TXT)
+        .is_none());
+
+    let disabled = AcmeChallengeTable::disabled();
+    assert!(!disabled.contains(""));
+    assert!(disabled.answer(ZONE, HickoryRecordType::SOA).is_none());
+}
+
+// ── resolver layer ────────────────────────────────────────────────────────────
+
+struct NxInner;
+
+#[async_trait]
+impl DnsResolver for NxInner {
+    async fn resolve(&self, _query: &DnsQuery) -> Result<DnsResolution, DomainError> {
+        Err(DomainError::NxDomain)
+    }
+}
+
+const HOME_ZONE: &str = r#"
+$ORIGIN home.lan.
+$TTL 300
+@                  IN SOA   ns1 admin 2026101701 7200 3600 1209600 60
+@                  IN NS    ns1
+ns1                IN A     192.168.1.1
+_acme-challenge.nas IN CNAME d420c923-bbd7-4056-ab64-c3ca54c9b3cf.acme.example.com.
+"#;
+
+fn resolver(table: AcmeChallengeTable) -> LocalAuthorityResolver {
+    let authority = LocalAuthority::new();
+    authority.replace_zones(&[parse_zone_text(HOME_ZONE, None, "home.lan").unwrap()]);
+    LocalAuthorityResolver::new(Arc::new(NxInner), Arc::new(authority))
+        .with_acme_challenges(Arc::new(table))
+}
+
+async fn lookup_txt(resolver: &LocalAuthorityResolver, domain: &str) -> Message {
+    let resolution = resolver
+        .resolve(&DnsQuery::new(domain, RecordType::TXT))
+        .await
+        .unwrap();
+    Message::from_vec(resolution.upstream_wire_data.as_ref().unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn resolver_answers_challenge_zone_directly() {
+    let resolver = resolver(table(&[TXT_A]));
+
+    let message = lookup_txt(&resolver, &format!("{SUBDOMAIN}.{ZONE}")).await;
+
+    assert!(message.authoritative());
+    assert_eq!(txt_strings(message.answers()), vec![TXT_A]);
+    assert!(resolver
+        .try_cache_str(&format!("{SUBDOMAIN}.{ZONE}"), RecordType::TXT)
+        .is_none());
+}
+
+/// Plays the part of an ACME server validating DNS-01: it derives the
+/// expected digest from the token and account thumbprint (RFC 8555 §8.4),
+/// then looks up `_acme-challenge.<name>` and follows the CNAME into the
+/// challenge zone.
+#[tokio::test]
+async fn acme_validator_follows_cname_into_challenge_zone() {
+    let token = "evaGxfADs6pSRb2LAv9IZf17Dt3juxGJ-PCt92wr-oA";
+    let thumbprint = "NzbLsXh8uDCcd-6MNwXF4W_7noWXFZAfHkxZsRGC9Xs";
+    let digest = base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .encode(Sha256::digest(format!("{token}.{thumbprint}").as_bytes()));
+
+    let resolver = resolver(table(&[&digest]));
+    let message = lookup_txt(&resolver, "_acme-challenge.nas.home.lan").await;
+
+    assert_eq!(message.response_code(), ResponseCode::NoError);
+    assert_eq!(message.answers()[0].record_type(), HickoryRecordType::CNAME);
+    assert_eq!(txt_strings(message.answers()), vec![digest]);
+}
+
+#[tokio::test]
+async fn acme_validator_sees_nxdomain_before_registration() {
+    let table = AcmeChallengeTable::new(ZONE, &format!("ns.{ZONE}"), 10);
+    let resolver = resolver(table);
+
+    let message = lookup_txt(&resolver, "_acme-challenge.nas.home.lan").await;
+
+    assert_eq!(message.response_code(), ResponseCode::NXDomain);
+}
+
+// ── built-in ACME client (DNS-01) ─────────────────────────────────────────────
+
+#[test]
+fn published_dns01_values_are_answered_until_withdrawn() {
+    let table = AcmeChallengeTable::disabled();
+    let name = "_acme-challenge.dns.example.net";
+
+    table.publish_txt(name, TXT_A);
+    table.publish_txt(name, TXT_B);
+    table.publish_txt(name, TXT_A);
+    assert!(table.contains(name));
+
+    let response = table.answer(name, HickoryRecordType::TXT).unwrap();
+    assert!(response.authoritative);
+    assert_eq!(txt_strings(&response.answers), vec![TXT_A, TXT_B]);
+    assert!(response.answers.iter().all(|r| r.ttl() >= 1));
+
+    let nodata = table.answer(name, HickoryRecordType::A).unwrap();
+    assert_eq!(nodata.response_code, ResponseCode::NoError);
+    assert!(nodata.answers.is_empty());
+
+    table.withdraw_txt(name);
+    assert!(!table.contains(name));
+    assert!(table.answer(name, HickoryRecordType::TXT).is_none());
+}
+
+#[tokio::test]
+async fn published_dns01_value_overrides_local_zone() {
+    let table = AcmeChallengeTable::disabled();
+    table.publish_txt("_acme-challenge.ns1.home.lan", TXT_A);
+    let resolver = resolver(table);
+
+    let message = lookup_txt(&resolver, "_acme-challenge.ns1.home.lan").await;
+
+    assert_eq!(message.response_code(), ResponseCode::NoError);
+    assert_eq!(txt_strings(message.answers()), vec![TXT_A]);
+}
@@ -0,0 +1,264 @@
+use ferrous_dns_application::ports::AcmeCertificateIssuer;
+use ferrous_dns_domain::AcmeConfig;
+use ferrous_dns_infrastructure::dns::AcmeChallengeTable;
+use ferrous_dns_infrastructure::tls::{AcmeClient, AcmeHttpChallenges};
+use serde_json::json;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+const TOKEN: &str = "evaGxfADs6pSRb2LAv9IZf17Dt3juxGJ-PCt92wr-oA";
+
+/// A minimal RFC 8555 CA over plain HTTP. Signatures are not checked; the
+/// point is the order state machine. The order stays `pending` for
+/// `lag_polls` polls after its authorization turns valid, and finalizing
+/// before it is `ready` fails with `orderNotReady`, as Boulder does.
+struct MockCa {
+    base: String,
+    http_challenges: Arc<AcmeHttpChallenges>,
+    lag_polls: usize,
+    challenged: AtomicBool,
+    finalized: AtomicBool,
+    order_polls: AtomicUsize,
+    key_authorization: Mutex<Option<String>>,
+    cert_pem: String,
+}
+
+impl MockCa {
+    fn route(&self, method: &str, path: &str) -> (u16, Vec<(&'static str, String)>, String) {
+        let base = &self.base;
+        match (method, path) {
+            ("GET", "/dir") => (
+                200,
+                vec![],
+                json!({
+                    "newNonce": format!("{base}/nonce"),
+                    "newAccount": format!("{base}/account"),
+                    "newOrder": format!("{base}/order"),
+                })
+                .to_string(),
+            ),
+            ("HEAD", "/nonce") => (200, vec![], String::new()),
+            ("POST", "/account") => (
+                201,
+                vec![("Location", format!("{base}/acct/1"))],
+                json!({ "status": "valid" }).to_string(),
+            ),
+            ("POST", "/order") => (
+                201,
+                vec![("Location", format!("{base}/order/1"))],
+                self.order("pending"),
+            ),
+            ("POST", "/authz/1") => {
+                let status = if self.challenged.load(Ordering::SeqCst) {
+                    "valid"
+                } else {
+                    "pending"
+                };
+                let body = json!({
+                    "status": status,
+                    "identifier": { "type": "dns", "value": "dns.example.com" },
+                    "challenges": [
+                        { "type": "dns-01", "url": format!("{base}/chall/2"), "token": "other" },
+                        { "type": "http-01", "url": format!("{base}/chall/1"), "token": TOKEN },
+                    ],
+                });
+                (200, vec![], body.to_string())
+            }
+            ("POST", "/chall/1") => {
+                *self.key_authorization.lock().unwrap() = self
+                    .http_challenges
+                    .get(TOKEN)
+                    .map(|key_authorization| key_authorization.to_string());
+                self.challenged.store(true, Ordering::SeqCst);
+                (200, vec![], json!({ "status": "processing" }).to_string())
+            }
+            ("POST", "/order/1") => {
+                let status = if self.finalized.load(Ordering::SeqCst) {
+                    "valid"
+                } else if self.ready() {
+                    "ready"
+                } else {
+                    if self.challenged.load(Ordering::SeqCst) {
+                        self.order_polls.fetch_add(1, Ordering::SeqCst);
+                    }
+                    "pending"
+                };
+                (200, vec![], self.order(status))
+            }
+            ("POST", "/finalize/1") => {
+                if !self.ready() {
+                    let problem = json!({
+                        "type": "urn:ietf:params:acme:error:orderNotReady",
+                        "detail": "Order's status (\"pending\") is not acceptable for finalization",
+                    });
+                    return (403, vec![], problem.to_string());
+                }
+                self.finalized.store(true, Ordering::SeqCst);
+                (200, vec![], self.order("processing"))
+            }
+            ("POST", "/cert/1") => (200, vec![], self.cert_pem.clone()),
+            _ => (404, vec![], String::new()),
+        }
+    }
+
+    fn ready(&self) -> bool {
+        self.challenged.load(Ordering::SeqCst)
+            && self.order_polls.load(Ordering::SeqCst) >= self.lag_polls
+    }
+
+    fn order(&self, status: &str) -> String {
+        let base = &self.base;
+        let mut order = json!({
+            "status": status,
+            "authorizations": [format!("{base}/authz/1")],
+            "finalize": format!("{base}/finalize/1"),
+        });
+        if status == "valid" {
+            order["certificate"] = json!(format!("{base}/cert/1"));
+        }
+        order.to_string()
+    }
+
+    async fn serve(self: Arc<Self>, listener: TcpListener) {
+        loop {
+            let Ok((stream, _)) = listener.accept().await else {
+                return;
+            };
+            tokio::spawn(Arc::clone(&self).handle(stream));
+        }
+    }
+
+    async fn handle(self: Arc<Self>, mut stream: TcpStream) {
+        let mut request = Vec::new();
+        let mut buf = [0u8; 4096];
+        let header_end = loop {
+            let Ok(n) = stream.read(&mut buf).await else {
+                return;
+            };
+            if n == 0 {
+                return;
+            }
+            request.extend_from_slice(&buf[..n]);
+            if let Some(pos) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                break pos + 4;
+            }
+        };
+        let head = String::from_utf8_lossy(&request[..header_end]).to_string();
+        let content_length = head
+            .lines()
+            .find_map(|line| {
+                let (name, value) = line.split_once(':')?;
+                name.eq_ignore_ascii_case("content-length")
+                    .then(|| value.trim().parse::<usize>().ok())?
+            })
+            .unwrap_or(0);
+        while request.len() < header_end + content_length {
+            let Ok(n) = stream.read(&mut buf).await else {
+                return;
+            };
+            if n == 0 {
+                break;
+            }
+            request.extend_from_slice(&buf[..n]);
+        }
+
+        let mut parts = head.split_whitespace();
+        let method = parts.next().unwrap_or_default();
+        let path = parts.next().unwrap_or_default();
+        let (status, headers, body) = self.route(method, path);
+
+        let mut response = format!(
+            "HTTP/1.1 {status} X\r\nReplay-Nonce: nonce-{}\r\nContent-Length: {}\r\nConnection: close\r\n",
+            fastrand::u64(..),
+            body.len()
+        );
+        for (name, value) in headers {
+            response.push_str(&format!("{name}: {value}\r\n"));
+        }
+        response.push_str("\r\n");
+        if method != "HEAD" {
+            response.push_str(&body);
+        }
+        let _ = stream.write_all(response.as_bytes()).await;
+        let _ = stream.shutdown().await;
+    }
+}
+
+async fn start_ca(lag_polls: usize) -> (Arc<MockCa>, AcmeClient, tempfile::TempDir) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+
+    let key = rcgen::KeyPair::generate().unwrap();
+    let cert = rcgen::CertificateParams::new(vec!["dns.example.com".to_string()])
+        .unwrap()
+        .self_signed(&key)
+        .unwrap();
+
+    let http_challenges = Arc::new(AcmeHttpChallenges::new());
+    let ca = Arc::new(MockCa {
+        base: base.clone(),
+        http_challenges: Arc::clone(&http_challenges),
+        lag_polls,
+        challenged: AtomicBool::new(false),
+        finalized: AtomicBool::new(false),
+        order_polls: AtomicUsize::new(0),
+        key_authorization: Mutex::new(None),
+        cert_pem: cert.pem(),
+    });
+    tokio::spawn(Arc::clone(&ca).serve(listener));
+
+    let dir = tempfile::tempdir().unwrap();
+    let config = AcmeConfig {
+        enabled: true,
+        directory_url: format!("{base}/dir"),
+        domains: vec!["dns.example.com".to_string()],
+        accept_terms: true,
+        account_key_path: dir
+            .path()
+            .join("account.pem")
+            .to_string_lossy()
+            .into_owned(),
+        ..AcmeConfig::default()
+    };
+    let client = AcmeClient::new(
+        &config,
+        http_challenges,
+        Arc::new(AcmeChallengeTable::disabled()),
+    )
+    .unwrap()
+    .with_poll_interval(Duration::from_millis(5));
+
+    (ca, client, dir)
+}
+
+#[tokio::test]
+async fn waits_for_the_order_to_be_ready_before_finalizing() {
+    let (ca, client, _dir) = start_ca(3).await;
+
+    let issued = client
+        .issue(&["dns.example.com".to_string()])
+        .await
+        .unwrap();
+
+    assert_eq!(issued.cert_pem, ca.cert_pem);
+    assert!(issued.key_pem.contains("PRIVATE KEY"));
+    assert!(ca.finalized.load(Ordering::SeqCst));
+    assert!(ca.order_polls.load(Ordering::SeqCst) >= 3);
+}
+
+#[tokio::test]
+async fn http01_key_authorization_is_served_during_the_order_only() {
+    let (ca, client, _dir) = start_ca(0).await;
+
+    client
+        .issue(&["dns.example.com".to_string()])
+        .await
+        .unwrap();
+
+    let served = ca.key_authorization.lock().unwrap().clone().unwrap();
+    assert!(served.starts_with(&format!("{TOKEN}.")));
+    assert!(ca.http_challenges.is_empty());
+}
@@ -0,0 +1,114 @@
+use ferrous_dns_application::ports::AcmeRegistrationRepository;
+use ferrous_dns_domain::DomainError;
+use ferrous_dns_infrastructure::repositories::acme_registration_repository::SqliteAcmeRegistrationRepository;
+use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
+use std::sync::Arc;
+
+const TXT_A: &str = "LHDhK3oGRvkiefQnx7OOczTY5Tic_xZ6HcMOc_gmtoM";
+const TXT_B: &str = "0123456789abcdefghijklmnopqrstuvwxyzABCDEFG";
+
+async fn create_test_db() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::query(
+        "CREATE TABLE acme_registrations (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            username TEXT NOT NULL UNIQUE,
+            password_hash TEXT NOT NULL,
+            subdomain TEXT NOT NULL UNIQUE,
+            allow_from TEXT NOT NULL DEFAULT '[]',
+            txt TEXT NOT NULL DEFAULT '[]',
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    pool
+}
+
+#[tokio::test]
+async fn test_create_and_get_registration() {
+    let repo = SqliteAcmeRegistrationRepository::new(create_test_db().await);
+
+    let created = repo
+        .create("user-1", "hash-1", "sub-1", &[Arc::from("10.0.0.0/8")])
+        .await
+        .unwrap();
+
+    assert!(created.id.is_some());
+    assert_eq!(created.username.as_ref(), "user-1");
+    assert_eq!(created.password_hash.as_ref(), "hash-1");
+    assert_eq!(created.subdomain.as_ref(), "sub-1");
+    assert_eq!(created.allow_from, vec![Arc::<str>::from("10.0.0.0/8")]);
+    assert!(created.txt.is_empty());
+    assert!(created.created_at.is_some());
+
+    let by_id = repo.get_by_id(created.id.unwrap()).await.unwrap().unwrap();
+    assert_eq!(by_id.subdomain, created.subdomain);
+
+    let by_username = repo.get_by_username("user-1").await.unwrap().unwrap();
+    assert_eq!(by_username.id, created.id);
+    assert!(repo.get_by_username("nobody").await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_duplicate_subdomain_is_rejected() {
+    let repo = SqliteAcmeRegistrationRepository::new(create_test_db().await);
+
+    repo.create("user-1", "hash", "sub", &[]).await.unwrap();
+    let result = repo.create("user-2", "hash", "sub", &[]).await;
+
+    assert!(matches!(result, Err(DomainError::DatabaseError(_))));
+}
+
+#[tokio::test]
+async fn test_set_txt_round_trips_values_in_order() {
+    let repo = SqliteAcmeRegistrationRepository::new(create_test_db().await);
+    let created = repo.create("user-1", "hash", "sub", &[]).await.unwrap();
+    let id = created.id.unwrap();
+
+    repo.set_txt(id, &[Arc::from(TXT_B), Arc::from(TXT_A)])
+        .await
+        .unwrap();
+
+    let fetched = repo.get_by_id(id).await.unwrap().unwrap();
+    assert_eq!(
+        fetched.txt,
+        vec![Arc::<str>::from(TXT_B), Arc::<str>::from(TXT_A)]
+    );
+}
+
+#[tokio::test]
+async fn test_set_txt_and_delete_unknown_return_not_found() {
+    let repo = SqliteAcmeRegistrationRepository::new(create_test_db().await);
+
+    assert!(matches!(
+        repo.set_txt(7, &[Arc::from(TXT_A)]).await,
+        Err(DomainError::AcmeRegistrationNotFound(7))
+    ));
+    assert!(matches!(
+        repo.delete(7).await,
+        Err(DomainError::AcmeRegistrationNotFound(7))
+    ));
+}
+
+#[tokio::test]
+async fn test_get_all_and_delete() {
+    let repo = SqliteAcmeRegistrationRepository::new(create_test_db().await);
+    let first = repo.create("user-1", "hash", "sub-1", &[]).await.unwrap();
+    repo.create("user-2", "hash", "sub-2", &[]).await.unwrap();
+
+    assert_eq!(repo.get_all().await.unwrap().len(), 2);
+
+    repo.delete(first.id.unwrap()).await.unwrap();
+
+    let remaining = repo.get_all().await.unwrap();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].subdomain.as_ref(), "sub-2");
+}
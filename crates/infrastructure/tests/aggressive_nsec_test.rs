@@ -0,0 +1,374 @@
+use ferrous_dns_domain::RecordType;
+use ferrous_dns_infrastructure::dns::cache::AggressiveNsecCache;
+use ferrous_dns_infrastructure::dns::dnssec::DenialKind;
+use hickory_proto::dnssec::crypto::Ed25519SigningKey;
+use hickory_proto::dnssec::rdata::{DNSSECRData, DNSKEY, NSEC, NSEC3, RRSIG};
+use hickory_proto::dnssec::{
+    Algorithm, Nsec3HashAlgorithm, PublicKey, PublicKeyBuf, SigSigner, SigningKey,
+};
+use hickory_proto::op::{Message, MessageType, OpCode, Query, ResponseCode};
+use hickory_proto::rr::rdata::SOA;
+use hickory_proto::rr::{DNSClass, Name, RData, Record, RecordSet, RecordType as HRT};
+use std::str::FromStr;
+use std::time::Duration;
+use time::{Duration as TD, OffsetDateTime};
+
+const SALT: &[u8] = &[0xAB, 0xCD];
+
+struct Zone {
+    signer: SigSigner,
+}
+
+impl Zone {
+    fn new() -> Self {
+        let pkcs8 = Ed25519SigningKey::generate_pkcs8().unwrap();
+        let key = Ed25519SigningKey::from_pkcs8(&pkcs8).unwrap();
+        let public_key = key.to_public_key().unwrap().public_bytes().to_vec();
+        let dnskey = DNSKEY::with_flags(256, PublicKeyBuf::new(public_key, Algorithm::ED25519));
+        let signer = SigSigner::dnssec(
+            dnskey,
+            Box::new(key),
+            name("example.com."),
+            Duration::from_secs(7200),
+        );
+        Self { signer }
+    }
+
+    fn signed(&self, owner: &str, ttl: u32, rdata: RData) -> Vec<Record> {
+        let owner = name(owner);
+        let record = Record::from_rdata(owner.clone(), ttl, rdata);
+        let mut rrset = RecordSet::new(owner.clone(), record.record_type(), 0);
+        rrset.insert(record.clone(), 0);
+        let inception = OffsetDateTime::now_utc() - TD::minutes(5);
+        let rrsig = RRSIG::from_rrset(&rrset, DNSClass::IN, inception, &self.signer).unwrap();
+        vec![
+            record,
+            Record::from_rdata(owner, ttl, RData::DNSSEC(DNSSECRData::RRSIG(rrsig))),
+        ]
+    }
+
+    fn soa(&self, minimum: u32) -> Vec<Record> {
+        self.signed(
+            "example.com.",
+            3600,
+            RData::SOA(SOA::new(
+                name("ns1.example.com."),
+                name("hostmaster.example.com."),
+                1,
+                3600,
+                600,
+                86400,
+                minimum,
+            )),
+        )
+    }
+
+    fn nsec(&self, owner: &str, next: &str, types: &[HRT]) -> Vec<Record> {
+        let nsec = NSEC::new(name(next), types.iter().copied());
+        self.signed(owner, 3600, RData::DNSSEC(DNSSECRData::NSEC(nsec)))
+    }
+
+    fn nsec3_covering(&self, name: &str, opt_out: bool) -> Vec<Record> {
+        let hash = nsec3_hash(name);
+        let nsec3 = NSEC3::new(
+            Nsec3HashAlgorithm::SHA1,
+            opt_out,
+            1,
+            SALT.to_vec(),
+            adjacent(&hash, 1),
+            [HRT::A, HRT::RRSIG],
+        );
+        let owner = format!("{}.example.com.", base32hex(&adjacent(&hash, -1)));
+        self.signed(&owner, 3600, RData::DNSSEC(DNSSECRData::NSEC3(nsec3)))
+    }
+
+    fn nsec3_matching(&self, name: &str, types: &[HRT]) -> Vec<Record> {
+        let hash = nsec3_hash(name);
+        let nsec3 = NSEC3::new(
+            Nsec3HashAlgorithm::SHA1,
+            false,
+            1,
+            SALT.to_vec(),
+            adjacent(&hash, 1),
+            types.iter().copied(),
+        );
+        let owner = format!("{}.example.com.", base32hex(&hash));
+        self.signed(&owner, 3600, RData::DNSSEC(DNSSECRData::NSEC3(nsec3)))
+    }
+
+    /// The apex NSEC plus the span mail → www, which together deny every
+    /// name sorting between them and the `*.example.com` wildcard.
+    fn nxdomain_proof(&self) -> Vec<Record> {
+        concat(vec![
+            self.soa(300),
+            self.nsec("mail.example.com.", "www.example.com.", &[HRT::A]),
+            self.nsec(
+                "example.com.",
+                "mail.example.com.",
+                &[HRT::SOA, HRT::NS, HRT::NSEC, HRT::RRSIG],
+            ),
+        ])
+    }
+}
+
+fn name(s: &str) -> Name {
+    Name::from_str(s).unwrap()
+}
+
+fn nsec3_hash(s: &str) -> Vec<u8> {
+    Nsec3HashAlgorithm::SHA1
+        .hash(SALT, &name(s), 1)
+        .unwrap()
+        .as_ref()
+        .to_vec()
+}
+
+fn adjacent(hash: &[u8], step: i16) -> Vec<u8> {
+    let mut out = hash.to_vec();
+    let last = out.len() - 1;
+    out[last] = (i16::from(out[last]) + step).clamp(0, 255) as u8;
+    out
+}
+
+fn base32hex(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8] = b"0123456789abcdefghijklmnopqrstuv";
+    let mut out = String::new();
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    for &b in bytes {
+        buffer = (buffer << 8) | u32::from(b);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            out.push(ALPHABET[((buffer >> bits) & 31) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        out.push(ALPHABET[((buffer << (5 - bits)) & 31) as usize] as char);
+    }
+    out
+}
+
+fn concat(parts: Vec<Vec<Record>>) -> Vec<Record> {
+    parts.into_iter().flatten().collect()
+}
+
+fn negative_response(qname: &str, rcode: ResponseCode, authority: Vec<Record>) -> Message {
+    let mut message = Message::new(0, MessageType::Response, OpCode::Query);
+    message.set_response_code(rcode);
+    message.add_query(Query::query(name(qname), HRT::A));
+    message.add_name_servers(authority);
+    message
+}
+
+#[test]
+fn test_synthesizes_nxdomain_for_other_name_in_cached_range() {
+    let zone = Zone::new();
+    let cache = AggressiveNsecCache::new();
+    cache.store(&negative_response(
+        "nope.example.com.",
+        ResponseCode::NXDomain,
+        zone.nxdomain_proof(),
+    ));
+
+    let denial = cache
+        .synthesize("random123.example.com", RecordType::A)
+        .expect("name inside the cached range");
+    assert_eq!(denial.kind, DenialKind::NxDomain);
+
+    let message = Message::from_vec(&denial.wire).unwrap();
+    assert_eq!(message.response_code(), ResponseCode::NXDomain);
+    assert_eq!(message.queries()[0].name(), &name("random123.example.com."));
+    assert!(message.answers().is_empty());
+    let types: Vec<HRT> = message
+        .name_servers()
+        .iter()
+        .map(|r| r.record_type())
+        .collect();
+    assert!(types.contains(&HRT::SOA));
+    assert_eq!(types.iter().filter(|t| **t == HRT::NSEC).count(), 2);
+    assert_eq!(types.iter().filter(|t| **t == HRT::RRSIG).count(), 3);
+}
+
+#[test]
+fn test_ttl_is_capped_at_soa_minimum() {
+    let zone = Zone::new();
+    let cache = AggressiveNsecCache::new();
+    cache.store(&negative_response(
+        "nope.example.com.",
+        ResponseCode::NXDomain,
+        zone.nxdomain_proof(),
+    ));
+
+    let denial = cache
+        .synthesize("other.example.com", RecordType::A)
+        .unwrap();
+    assert!(denial.ttl <= 300);
+    let message = Message::from_vec(&denial.wire).unwrap();
+    assert!(message.name_servers().iter().all(|r| r.ttl() <= 300));
+}
+
+#[test]
+fn test_synthesizes_nodata_for_existing_name_without_type() {
+    let zone = Zone::new();
+    let cache = AggressiveNsecCache::new();
+    let authority = concat(vec![
+        zone.soa(300),
+        zone.nsec("mail.example.com.", "www.example.com.", &[HRT::A, HRT::MX]),
+    ]);
+    cache.store(&negative_response(
+        "mail.example.com.",
+        ResponseCode::NoError,
+        authority,
+    ));
+
+    let denial = cache
+        .synthesize("mail.example.com", RecordType::AAAA)
+        .expect("bitmap has no AAAA");
+    assert_eq!(denial.kind, DenialKind::NoData);
+    let message = Message::from_vec(&denial.wire).unwrap();
+    assert_eq!(message.response_code(), ResponseCode::NoError);
+
+    assert!(cache
+        .synthesize("mail.example.com", RecordType::MX)
+        .is_none());
+}
+
+#[test]
+fn test_name_outside_cached_ranges_is_not_synthesized() {
+    let zone = Zone::new();
+    let cache = AggressiveNsecCache::new();
+    cache.store(&negative_response(
+        "nope.example.com.",
+        ResponseCode::NXDomain,
+        zone.nxdomain_proof(),
+    ));
+
+    assert!(cache.synthesize("zzz.example.com", RecordType::A).is_none());
+    assert!(cache
+        .synthesize("nope.example.org", RecordType::A)
+        .is_none());
+}
+
+#[test]
+fn test_nsec3_opt_out_span_is_not_synthesized() {
+    let zone = Zone::new();
+    let cache = AggressiveNsecCache::new();
+    let authority = concat(vec![
+        zone.soa(300),
+        zone.nsec3_covering("nope.example.com.", true),
+    ]);
+    cache.store(&negative_response(
+        "nope.example.com.",
+        ResponseCode::NXDomain,
+        authority,
+    ));
+
+    assert!(cache
+        .synthesize("nope.example.com", RecordType::A)
+        .is_none());
+}
+
+#[test]
+fn test_response_without_soa_is_not_stored() {
+    let zone = Zone::new();
+    let cache = AggressiveNsecCache::new();
+    let authority = zone.nsec("mail.example.com.", "www.example.com.", &[HRT::A]);
+    cache.store(&negative_response(
+        "nope.example.com.",
+        ResponseCode::NXDomain,
+        authority,
+    ));
+
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn test_clear_drops_all_zones() {
+    let zone = Zone::new();
+    let cache = AggressiveNsecCache::new();
+    cache.store(&negative_response(
+        "nope.example.com.",
+        ResponseCode::NXDomain,
+        zone.nxdomain_proof(),
+    ));
+    assert_eq!(cache.len(), 1);
+
+    cache.clear();
+    assert!(cache
+        .synthesize("other.example.com", RecordType::A)
+        .is_none());
+}
+
+#[test]
+fn test_finds_the_covering_span_among_many() {
+    let zone = Zone::new();
+    let cache = AggressiveNsecCache::new();
+    for i in 0..500 {
+        let authority = concat(vec![
+            zone.soa(300),
+            zone.nsec(
+                "example.com.",
+                "0.example.com.",
+                &[HRT::SOA, HRT::NS, HRT::NSEC, HRT::RRSIG],
+            ),
+            zone.nsec(
+                &format!("n{i:03}.example.com."),
+                &format!("n{i:03}b.example.com."),
+                &[HRT::A],
+            ),
+        ]);
+        cache.store(&negative_response(
+            &format!("n{i:03}a.example.com."),
+            ResponseCode::NXDomain,
+            authority,
+        ));
+    }
+
+    let denial = cache
+        .synthesize("n123a.example.com", RecordType::A)
+        .expect("name inside a cached span");
+    assert_eq!(denial.kind, DenialKind::NxDomain);
+    let message = Message::from_vec(&denial.wire).unwrap();
+    let owners: Vec<Name> = message
+        .name_servers()
+        .iter()
+        .filter(|r| r.record_type() == HRT::NSEC)
+        .map(|r| r.name().clone())
+        .collect();
+    assert_eq!(owners.len(), 2);
+    assert!(owners.contains(&name("n123.example.com.")));
+
+    assert!(cache
+        .synthesize("n123c.example.com", RecordType::A)
+        .is_none());
+}
+
+#[test]
+fn test_synthesizes_nxdomain_from_nsec3_chain() {
+    let zone = Zone::new();
+    let cache = AggressiveNsecCache::new();
+    let authority = concat(vec![
+        zone.soa(300),
+        zone.nsec3_matching("example.com.", &[HRT::SOA, HRT::NS, HRT::RRSIG]),
+        zone.nsec3_covering("nope.example.com.", false),
+        zone.nsec3_covering("*.example.com.", false),
+    ]);
+    cache.store(&negative_response(
+        "nope.example.com.",
+        ResponseCode::NXDomain,
+        authority,
+    ));
+
+    let denial = cache
+        .synthesize("nope.example.com", RecordType::AAAA)
+        .expect("closest encloser proof is cached");
+    assert_eq!(denial.kind, DenialKind::NxDomain);
+    let message = Message::from_vec(&denial.wire).unwrap();
+    let nsec3 = message
+        .name_servers()
+        .iter()
+        .filter(|r| r.record_type() == HRT::NSEC3)
+        .count();
+    assert_eq!(nsec3, 3);
+}
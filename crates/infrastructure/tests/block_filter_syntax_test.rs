@@ -0,0 +1,395 @@
+use ferrous_dns_domain::{BlockSource, RecordType};
+use ferrous_dns_infrastructure::dns::block_filter::{
+    build_list_index, parse_list_line, parse_list_text, BlockIndex, ClientSpec, FilterRule,
+    ParsedEntry, QueryContext, RuleDirectory, RuleTarget, SourceBits, SourceIndex,
+    MANUAL_SOURCE_SET,
+};
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+const ADGUARD_DNS_FILTER: &str = include_str!("fixtures/blocklists/adguard_dns_filter.txt");
+const UBLOCK_STYLE: &str = include_str!("fixtures/blocklists/ublock_style.txt");
+
+const GROUP: i64 = 1;
+const OTHER_GROUP: i64 = 2;
+
+fn all_sources(count: usize) -> SourceBits {
+    (1..=count as SourceIndex).collect()
+}
+
+fn index_for(lists: &[&str], directory: &RuleDirectory) -> BlockIndex {
+    let entries: HashMap<SourceIndex, Vec<ParsedEntry>> = lists
+        .iter()
+        .enumerate()
+        .map(|(idx, text)| (idx as SourceIndex + 1, parse_list_text(text)))
+        .collect();
+    let mut masks = HashMap::new();
+    masks.insert(GROUP, all_sources(lists.len()));
+    masks.insert(OTHER_GROUP, all_sources(lists.len()));
+    build_list_index(&entries, &masks, directory)
+}
+
+fn ctx(record_type: RecordType, client: &str) -> QueryContext {
+    QueryContext {
+        record_type,
+        client_ip: client.parse::<IpAddr>().unwrap(),
+    }
+}
+
+fn rule(line: &str) -> Box<FilterRule> {
+    match parse_list_line(line) {
+        Some(ParsedEntry::Rule(rule)) => rule,
+        other => panic!("{line:?} should parse as a rule, got {other:?}"),
+    }
+}
+
+// ── Parsing ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn anchored_rule_covers_subdomains() {
+    assert_eq!(
+        parse_list_line("||doubleclick.net^"),
+        Some(ParsedEntry::Subtree("doubleclick.net".into()))
+    );
+    assert_eq!(
+        parse_list_line("|only-this.example^"),
+        Some(ParsedEntry::Exact("only-this.example".into()))
+    );
+    assert_eq!(
+        parse_list_line("||*.Wild.Example^"),
+        Some(ParsedEntry::Wildcard("*.wild.example".into()))
+    );
+}
+
+#[test]
+fn hosts_and_domain_lines_are_unchanged() {
+    assert_eq!(
+        parse_list_line("0.0.0.0 Ads.Example.com"),
+        Some(ParsedEntry::Exact("ads.example.com".into()))
+    );
+    assert_eq!(
+        parse_list_line("tracker.example.org"),
+        Some(ParsedEntry::Exact("tracker.example.org".into()))
+    );
+    assert_eq!(
+        parse_list_line("*.ads.example"),
+        Some(ParsedEntry::Wildcard("*.ads.example".into()))
+    );
+    assert_eq!(parse_list_line("127.0.0.1 localhost"), None);
+}
+
+#[test]
+fn exception_rule_is_kept() {
+    let parsed = rule("@@||s.youtube.com^");
+    assert!(parsed.exception);
+    assert!(parsed.is_plain_exception());
+    assert_eq!(parsed.target, RuleTarget::Subtree("s.youtube.com".into()));
+}
+
+#[test]
+fn modifiers_are_parsed() {
+    let parsed = rule("||metrics.example.com^$dnstype=AAAA|~A,important");
+    assert!(parsed.important);
+    let types = parsed.dnstype.unwrap();
+    assert_eq!(types.include, vec![RecordType::AAAA]);
+    assert_eq!(types.exclude, vec![RecordType::A]);
+
+    let parsed = rule(r"@@||a.example^$client='Frank\'s laptop'|10.0.0.0/8|~10.0.0.1");
+    let clients = parsed.client.unwrap();
+    assert_eq!(
+        clients.include,
+        vec![
+            ClientSpec::Name("frank's laptop".into()),
+            ClientSpec::Network("10.0.0.0/8".parse().unwrap()),
+        ]
+    );
+    assert_eq!(
+        clients.exclude,
+        vec![ClientSpec::Network("10.0.0.1".parse().unwrap())]
+    );
+
+    let parsed = rule("||xyz^$denyallow=Safe.xyz|docs.xyz");
+    assert_eq!(parsed.denyallow, vec!["safe.xyz", "docs.xyz"]);
+
+    let parsed = rule("||kids.example^$ctag=Kids|~guests");
+    let tags = parsed.ctag.unwrap();
+    assert_eq!(tags.include, vec!["kids"]);
+    assert_eq!(tags.exclude, vec!["guests"]);
+
+    assert!(rule("||a.example^$badfilter").badfilter);
+}
+
+#[test]
+fn browser_only_rules_are_skipped() {
+    for line in [
+        "||ads.example-cdn.net^$third-party",
+        "||example.org^$script,third-party",
+        "||tracker.example.org/pixel.gif^",
+        "example.com##.sponsored",
+        "##.ad-banner",
+        "||ad*.example.com^",
+        "||example.com^$dnstype=BOGUS",
+        "||example.com^$client=",
+        "||xyz^",
+        "[Adblock Plus 2.0]",
+    ] {
+        assert_eq!(parse_list_line(line), None, "{line:?} should be skipped");
+    }
+}
+
+// ── AdGuard DNS filter fixture ──────────────────────────────────────────────
+
+#[test]
+fn adguard_fixture_blocks_subtrees_and_honours_exceptions() {
+    let index = index_for(&[ADGUARD_DNS_FILTER], &RuleDirectory::default());
+
+    assert_eq!(
+        index.is_blocked("doubleclick.net", GROUP),
+        Some(BlockSource::Blocklist)
+    );
+    assert_eq!(
+        index.is_blocked("stats.g.doubleclick.net", GROUP),
+        Some(BlockSource::Blocklist)
+    );
+    assert_eq!(
+        index.is_blocked("googlesyndication.com", GROUP),
+        Some(BlockSource::Blocklist)
+    );
+    assert_eq!(
+        index.is_blocked("pagead2.googlesyndication.com", GROUP),
+        None
+    );
+    assert_eq!(
+        index.is_blocked("tpc.pagead2.googlesyndication.com", GROUP),
+        None
+    );
+    assert_eq!(index.is_blocked("ads.example-cdn.net", GROUP), None);
+    assert_eq!(index.is_blocked("tracker.example.org", GROUP), None);
+}
+
+#[test]
+fn adguard_fixture_important_beats_exception() {
+    let index = index_for(&[ADGUARD_DNS_FILTER], &RuleDirectory::default());
+    assert_eq!(
+        index.is_blocked("adservice.google.com", GROUP),
+        Some(BlockSource::Blocklist)
+    );
+}
+
+#[test]
+fn adguard_fixture_badfilter_disables_rule() {
+    let index = index_for(&[ADGUARD_DNS_FILTER], &RuleDirectory::default());
+    assert_eq!(index.is_blocked("0265331.com", GROUP), None);
+    assert_eq!(
+        index.is_blocked("03bb8ab8b6.com", GROUP),
+        Some(BlockSource::Blocklist)
+    );
+}
+
+#[test]
+fn adguard_fixture_dnstype_rules_need_query_context() {
+    let index = index_for(&[ADGUARD_DNS_FILTER], &RuleDirectory::default());
+    let client = "192.168.1.10";
+
+    assert!(index.needs_query_context("metrics.example.com"));
+    assert!(!index.needs_query_context("doubleclick.net"));
+    assert_eq!(index.is_blocked("metrics.example.com", GROUP), None);
+    assert_eq!(
+        index.is_blocked_for_query("metrics.example.com", GROUP, &ctx(RecordType::AAAA, client)),
+        Some(BlockSource::Blocklist)
+    );
+    assert_eq!(
+        index.is_blocked_for_query("metrics.example.com", GROUP, &ctx(RecordType::A, client)),
+        None
+    );
+
+    assert_eq!(
+        index.is_blocked_for_query("telemetry.example.com", GROUP, &ctx(RecordType::A, client)),
+        None
+    );
+    assert_eq!(
+        index.is_blocked_for_query(
+            "telemetry.example.com",
+            GROUP,
+            &ctx(RecordType::HTTPS, client)
+        ),
+        Some(BlockSource::Blocklist)
+    );
+
+    assert_eq!(
+        index.is_blocked_for_query("adtrack.example.net", GROUP, &ctx(RecordType::A, client)),
+        Some(BlockSource::Blocklist)
+    );
+    assert_eq!(
+        index.is_blocked_for_query("adtrack.example.net", GROUP, &ctx(RecordType::AAAA, client)),
+        None
+    );
+}
+
+#[test]
+fn adguard_fixture_ctag_matches_group_name() {
+    let mut directory = RuleDirectory::default();
+    directory.group_names.insert(GROUP, "Kids".into());
+    directory.group_names.insert(OTHER_GROUP, "Adults".into());
+    let index = index_for(&[ADGUARD_DNS_FILTER], &directory);
+
+    assert_eq!(
+        index.is_blocked("www.kids-video.example", GROUP),
+        Some(BlockSource::Blocklist)
+    );
+    assert_eq!(
+        index.is_blocked("www.kids-video.example", OTHER_GROUP),
+        None
+    );
+}
+
+#[test]
+fn adguard_fixture_denyallow_carves_out_names() {
+    let index = index_for(&[ADGUARD_DNS_FILTER], &RuleDirectory::default());
+
+    assert_eq!(
+        index.is_blocked("phishing.xyz", GROUP),
+        Some(BlockSource::Blocklist)
+    );
+    assert_eq!(index.is_blocked("safe.xyz", GROUP), None);
+    assert_eq!(index.is_blocked("www.docs.xyz", GROUP), None);
+    assert_eq!(index.is_blocked("docs.xyz", GROUP), None);
+}
+
+// ── uBlock-style fixture ────────────────────────────────────────────────────
+
+#[test]
+fn ublock_fixture_mixed_formats() {
+    let index = index_for(&[UBLOCK_STYLE], &RuleDirectory::default());
+
+    assert_eq!(
+        index.is_blocked("hosts.example.net", GROUP),
+        Some(BlockSource::Blocklist)
+    );
+    assert_eq!(
+        index.is_blocked("cdn.annoyances.example", GROUP),
+        Some(BlockSource::Blocklist)
+    );
+    assert_eq!(
+        index.is_blocked("a.wild.example", GROUP),
+        Some(BlockSource::Blocklist)
+    );
+    assert_eq!(index.is_blocked("wild.example", GROUP), None);
+    assert_eq!(index.is_blocked("only-this.example", GROUP), None);
+    assert_eq!(index.is_blocked("example.com", GROUP), None);
+    assert_eq!(index.is_blocked("example.org", GROUP), None);
+}
+
+#[test]
+fn ublock_fixture_client_rules() {
+    let mut directory = RuleDirectory::default();
+    directory.client_addresses.insert(
+        "living room tv".into(),
+        vec!["192.168.1.20".parse().unwrap()],
+    );
+    let index = index_for(&[UBLOCK_STYLE], &directory);
+
+    assert_eq!(
+        index.is_blocked_for_query(
+            "lan-only.example",
+            GROUP,
+            &ctx(RecordType::A, "192.168.1.20")
+        ),
+        Some(BlockSource::Blocklist)
+    );
+    assert_eq!(
+        index.is_blocked_for_query(
+            "lan-only.example",
+            GROUP,
+            &ctx(RecordType::A, "192.168.1.21")
+        ),
+        None
+    );
+
+    assert_eq!(
+        index.is_blocked_for_query(
+            "clean.annoyances.example",
+            GROUP,
+            &ctx(RecordType::A, "192.168.1.30")
+        ),
+        None
+    );
+    assert_eq!(
+        index.is_blocked_for_query(
+            "clean.annoyances.example",
+            GROUP,
+            &ctx(RecordType::A, "192.168.1.66")
+        ),
+        Some(BlockSource::Blocklist)
+    );
+    assert_eq!(
+        index.is_blocked_for_query(
+            "clean.annoyances.example",
+            GROUP,
+            &ctx(RecordType::A, "10.0.0.5")
+        ),
+        Some(BlockSource::Blocklist)
+    );
+
+    assert!(!index.needs_query_context("no-such-client.example"));
+    assert_eq!(index.is_blocked("no-such-client.example", GROUP), None);
+}
+
+// ── Cross-source behaviour ──────────────────────────────────────────────────
+
+#[test]
+fn exceptions_only_apply_to_groups_subscribed_to_the_source() {
+    let entries: HashMap<SourceIndex, Vec<ParsedEntry>> = [
+        (1, parse_list_text("||ads.example^")),
+        (2, parse_list_text("@@||ads.example^")),
+    ]
+    .into_iter()
+    .collect();
+    let mut masks = HashMap::new();
+    masks.insert(GROUP, [1].into_iter().collect());
+    masks.insert(OTHER_GROUP, [1, 2].into_iter().collect());
+    let index = build_list_index(&entries, &masks, &RuleDirectory::default());
+
+    assert_eq!(
+        index.is_blocked("ads.example", GROUP),
+        Some(BlockSource::Blocklist)
+    );
+    assert_eq!(index.is_blocked("ads.example", OTHER_GROUP), None);
+}
+
+#[test]
+fn badfilter_applies_across_sources() {
+    let index = index_for(
+        &[
+            "||ads.example^\n||keep.example^",
+            "||ads.example^$badfilter",
+        ],
+        &RuleDirectory::default(),
+    );
+    assert_eq!(index.is_blocked("ads.example", GROUP), None);
+    assert_eq!(
+        index.is_blocked("keep.example", GROUP),
+        Some(BlockSource::Blocklist)
+    );
+}
+
+#[test]
+fn important_exception_beats_important_block() {
+    let index = index_for(
+        &["||ads.example^$important\n@@||ads.example^$important"],
+        &RuleDirectory::default(),
+    );
+    assert_eq!(index.is_blocked("ads.example", GROUP), None);
+}
+
+#[test]
+fn list_exceptions_do_not_lift_manual_blocks() {
+    let index = index_for(&["@@||ads.example^"], &RuleDirectory::default());
+    index.bloom.set(&"ads.example");
+    index.exact.insert("ads.example".into(), MANUAL_SOURCE_SET);
+
+    assert_eq!(
+        index.is_blocked("ads.example", GROUP),
+        Some(BlockSource::Blocklist)
+    );
+}
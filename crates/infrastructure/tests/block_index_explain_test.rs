@@ -0,0 +1,189 @@
+use compact_str::CompactString;
+use fancy_regex::Regex;
+use ferrous_dns_application::ports::{TraceMatch, TraceRuleKind};
+use ferrous_dns_domain::RecordType;
+use ferrous_dns_infrastructure::dns::block_filter::{
+    build_list_index, parse_list_text, BlockIndex, ParsedEntry, QueryContext, RuleDirectory,
+    SourceBits, SourceIndex, MANUAL_SOURCE_SET,
+};
+use std::collections::HashMap;
+
+const GROUP: i64 = 1;
+const OTHER_GROUP: i64 = 2;
+
+const ADS_LIST: &str = "\
+0.0.0.0 ads.example
+||adnet.example^
+/banner/
+||typed.example^$dnstype=AAAA
+@@||cdn.adnet.example^
+";
+const EXTRA_LIST: &str = "ads.example\n";
+
+/// `ads-list` and `extra-list` are sources 1 and 2. `GROUP` is subscribed
+/// to both, `OTHER_GROUP` to `extra-list` only.
+fn index() -> BlockIndex {
+    let entries: HashMap<SourceIndex, Vec<ParsedEntry>> = [
+        (1, parse_list_text(ADS_LIST)),
+        (2, parse_list_text(EXTRA_LIST)),
+    ]
+    .into_iter()
+    .collect();
+    let mut groups = HashMap::new();
+    groups.insert(GROUP, [1, 2].into_iter().collect::<SourceBits>());
+    groups.insert(OTHER_GROUP, [2].into_iter().collect::<SourceBits>());
+    let mut index = build_list_index(&entries, &groups, &RuleDirectory::default());
+    index.source_names.insert(1, CompactString::new("ads-list"));
+    index
+        .source_names
+        .insert(2, CompactString::new("extra-list"));
+    index
+}
+
+fn matched(kind: TraceRuleKind, rule: &str, sources: &[&str]) -> TraceMatch {
+    TraceMatch {
+        kind,
+        rule: rule.to_string(),
+        sources: sources.iter().map(|s| s.to_string()).collect(),
+    }
+}
+
+#[test]
+fn test_exact_match_names_every_subscribed_source() {
+    let index = index();
+
+    let trace = index.explain("ads.example", GROUP, None);
+    assert_eq!(
+        trace.block_matches,
+        vec![matched(
+            TraceRuleKind::ListExact,
+            "ads.example",
+            &["ads-list", "extra-list"]
+        )]
+    );
+    assert_eq!(trace.group_sources, vec!["ads-list", "extra-list"]);
+
+    let trace = index.explain("ads.example", OTHER_GROUP, None);
+    assert_eq!(
+        trace.block_matches,
+        vec![matched(
+            TraceRuleKind::ListExact,
+            "ads.example",
+            &["extra-list"]
+        )]
+    );
+    assert_eq!(trace.group_sources, vec!["extra-list"]);
+}
+
+#[test]
+fn test_wildcard_and_exception_are_both_reported() {
+    let index = index();
+
+    let trace = index.explain("img.cdn.adnet.example", GROUP, None);
+    assert_eq!(
+        trace.block_matches,
+        vec![matched(
+            TraceRuleKind::ListWildcard,
+            "*.adnet.example",
+            &["ads-list"]
+        )]
+    );
+    assert_eq!(
+        trace.allow_matches,
+        vec![matched(
+            TraceRuleKind::ListException,
+            "@@*.cdn.adnet.example",
+            &["ads-list"]
+        )]
+    );
+    assert_eq!(index.is_blocked("img.cdn.adnet.example", GROUP), None);
+}
+
+#[test]
+fn test_pattern_reports_matched_text() {
+    let index = index();
+
+    let trace = index.explain("top-banner.news.example", GROUP, None);
+    assert_eq!(
+        trace.block_matches,
+        vec![matched(
+            TraceRuleKind::ListPattern,
+            "/banner/",
+            &["ads-list"]
+        )]
+    );
+}
+
+#[test]
+fn test_modifier_rule_needs_matching_query_context() {
+    let index = index();
+
+    let trace = index.explain("typed.example", GROUP, None);
+    assert!(trace.block_matches.is_empty());
+
+    let ctx = QueryContext {
+        record_type: RecordType::AAAA,
+        client_ip: "192.168.1.10".parse().unwrap(),
+    };
+    let trace = index.explain("typed.example", GROUP, Some(&ctx));
+    assert_eq!(
+        trace.block_matches,
+        vec![matched(
+            TraceRuleKind::ListRule,
+            "||typed.example^$dnstype=AAAA",
+            &["ads-list"]
+        )]
+    );
+}
+
+#[test]
+fn test_sources_outside_the_group_are_not_reported() {
+    let index = index();
+
+    let trace = index.explain("www.adnet.example", OTHER_GROUP, None);
+    assert!(trace.block_matches.is_empty());
+    assert!(trace.allow_matches.is_empty());
+}
+
+#[test]
+fn test_manual_allowlist_and_regex_entries() {
+    let mut index = index();
+    index.bloom.set(&"manual.example");
+    index
+        .exact
+        .insert("manual.example".into(), MANUAL_SOURCE_SET);
+    index
+        .allowlists
+        .global_wildcard
+        .insert_wildcard("adnet.example", 1);
+    index
+        .block_regex_patterns
+        .insert(GROUP, vec![Regex::new("(?i)^www\\.").unwrap()]);
+
+    let trace = index.explain("manual.example", GROUP, None);
+    assert_eq!(
+        trace.block_matches,
+        vec![matched(
+            TraceRuleKind::ManualBlocklist,
+            "manual.example",
+            &[]
+        )]
+    );
+
+    let trace = index.explain("www.adnet.example", GROUP, None);
+    assert_eq!(
+        trace.allow_matches,
+        vec![matched(TraceRuleKind::Allowlist, "*.adnet.example", &[])]
+    );
+    assert_eq!(
+        trace.block_matches,
+        vec![
+            matched(
+                TraceRuleKind::ListWildcard,
+                "*.adnet.example",
+                &["ads-list"]
+            ),
+            matched(TraceRuleKind::RegexFilter, "^www\\.", &[]),
+        ]
+    );
+}
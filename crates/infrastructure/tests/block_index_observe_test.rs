@@ -0,0 +1,73 @@
+use ferrous_dns_infrastructure::dns::block_filter::{
+    build_list_index, parse_list_text, BlockIndex, ParsedEntry, RuleDirectory, SourceBits,
+    SourceIndex,
+};
+use std::collections::HashMap;
+
+const GROUP: i64 = 1;
+const OTHER_GROUP: i64 = 2;
+
+const ENFORCED_LIST: &str = "ads.example\n@@||good.example^\n";
+const OBSERVED_LIST: &str = "tracker.example\n||good.example^\n";
+
+/// Source 1 is enforced for both groups; source 2 is observe-only for
+/// `GROUP`.
+fn index() -> BlockIndex {
+    let entries: HashMap<SourceIndex, Vec<ParsedEntry>> = [
+        (1, parse_list_text(ENFORCED_LIST)),
+        (2, parse_list_text(OBSERVED_LIST)),
+    ]
+    .into_iter()
+    .collect();
+    let enforced: SourceBits = [1].into_iter().collect();
+    let groups = HashMap::from([(GROUP, enforced.clone()), (OTHER_GROUP, enforced)]);
+    let mut index = build_list_index(&entries, &groups, &RuleDirectory::default());
+    index.set_observed_sources(&HashMap::from([(
+        GROUP,
+        [2].into_iter().collect::<SourceBits>(),
+    )]));
+    index.source_ids = HashMap::from([(1, 101), (2, 102)]);
+    index
+}
+
+#[test]
+fn test_observed_source_is_not_enforced() {
+    let index = index();
+
+    assert!(index.is_blocked("tracker.example", GROUP).is_none());
+    assert_eq!(
+        index.observed_source_ids("tracker.example", GROUP, None),
+        vec![102]
+    );
+    assert!(index
+        .blocking_source_ids("tracker.example", GROUP, None)
+        .is_empty());
+}
+
+#[test]
+fn test_enforced_source_still_blocks() {
+    let index = index();
+
+    assert!(index.is_blocked("ads.example", GROUP).is_some());
+    assert!(index
+        .observed_source_ids("ads.example", GROUP, None)
+        .is_empty());
+}
+
+#[test]
+fn test_enforced_exception_suppresses_observed_match() {
+    let index = index();
+
+    assert!(index
+        .observed_source_ids("cdn.good.example", GROUP, None)
+        .is_empty());
+}
+
+#[test]
+fn test_groups_without_observed_sources_record_nothing() {
+    let index = index();
+
+    assert!(index
+        .observed_source_ids("tracker.example", OTHER_GROUP, None)
+        .is_empty());
+}
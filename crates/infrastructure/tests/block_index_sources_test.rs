@@ -0,0 +1,139 @@
+use ferrous_dns_domain::BlockSource;
+use ferrous_dns_infrastructure::dns::block_filter::{
+    build_list_index, parse_list_text, BlockIndex, ParsedEntry, RuleDirectory, SourceBits,
+    SourceIndex, SourceSets, MANUAL_SOURCE, MANUAL_SOURCE_SET, NO_SOURCES,
+};
+use std::collections::HashMap;
+
+const SOURCES: SourceIndex = 200;
+
+const ALL: i64 = 1;
+const LOW: i64 = 2;
+const HIGH: i64 = 3;
+const NONE: i64 = 4;
+
+/// Source `n` lists `only-n.example` and `||sub-n.example^`; every source
+/// lists `shared.example`.
+fn index() -> BlockIndex {
+    let entries: HashMap<SourceIndex, Vec<ParsedEntry>> = (1..=SOURCES)
+        .map(|n| {
+            let text = format!("0.0.0.0 only-{n}.example\n||sub-{n}.example^\nshared.example\n");
+            (n, parse_list_text(&text))
+        })
+        .collect();
+
+    let mut groups = HashMap::new();
+    groups.insert(ALL, (1..=SOURCES).collect::<SourceBits>());
+    groups.insert(LOW, (1..=63).collect::<SourceBits>());
+    groups.insert(HIGH, (64..=SOURCES).collect::<SourceBits>());
+    groups.insert(NONE, SourceBits::new());
+    build_list_index(&entries, &groups, &RuleDirectory::default())
+}
+
+#[test]
+fn test_sources_beyond_63_are_loaded() {
+    let index = index();
+    for n in [1, 63, 64, 65, 128, SOURCES] {
+        assert_eq!(
+            index.is_blocked(&format!("only-{n}.example"), ALL),
+            Some(BlockSource::Blocklist),
+            "source {n}"
+        );
+        assert_eq!(
+            index.is_blocked(&format!("ads.sub-{n}.example"), ALL),
+            Some(BlockSource::Blocklist),
+            "source {n}"
+        );
+    }
+}
+
+#[test]
+fn test_groups_only_see_their_subscribed_sources() {
+    let index = index();
+
+    assert!(index.is_blocked("only-63.example", LOW).is_some());
+    assert!(index.is_blocked("only-64.example", LOW).is_none());
+    assert!(index.is_blocked("ads.sub-150.example", LOW).is_none());
+
+    assert!(index.is_blocked("only-63.example", HIGH).is_none());
+    assert!(index.is_blocked("only-64.example", HIGH).is_some());
+    assert!(index.is_blocked("ads.sub-150.example", HIGH).is_some());
+
+    assert!(index.is_blocked("shared.example", LOW).is_some());
+    assert!(index.is_blocked("shared.example", HIGH).is_some());
+    assert!(index.is_blocked("shared.example", NONE).is_none());
+    assert!(index.is_blocked("only-1.example", NONE).is_none());
+}
+
+#[test]
+fn test_unknown_group_gets_no_list_sources() {
+    let index = index();
+    assert!(index.is_blocked("shared.example", 99).is_none());
+}
+
+#[test]
+fn test_exception_from_high_source_applies_to_its_groups_only() {
+    let mut entries: HashMap<SourceIndex, Vec<ParsedEntry>> = (1..=SOURCES)
+        .map(|n| (n, parse_list_text(&format!("0.0.0.0 filler-{n}.example\n"))))
+        .collect();
+    entries.insert(1, parse_list_text("||ads.example^"));
+    entries.insert(150, parse_list_text("@@||ads.example^"));
+
+    let mut groups = HashMap::new();
+    groups.insert(LOW, [1].into_iter().collect::<SourceBits>());
+    groups.insert(ALL, [1, 150].into_iter().collect::<SourceBits>());
+    let index = build_list_index(&entries, &groups, &RuleDirectory::default());
+
+    assert_eq!(
+        index.is_blocked("ads.example", LOW),
+        Some(BlockSource::Blocklist)
+    );
+    assert_eq!(index.is_blocked("ads.example", ALL), None);
+    assert_eq!(index.is_blocked("cdn.ads.example", ALL), None);
+}
+
+#[test]
+fn test_source_sets_intern_equal_sets_once() {
+    let mut sets = SourceSets::new();
+    assert_eq!(sets.get(NO_SOURCES), &SourceBits::new());
+    assert!(sets.get(MANUAL_SOURCE_SET).contains(MANUAL_SOURCE));
+
+    let a = sets.singleton(70);
+    let ab = sets.with_source(a, 300);
+    let b = sets.singleton(300);
+    let ba = sets.with_source(b, 70);
+    assert_eq!(ab, ba);
+    assert_eq!(sets.with_source(ab, 70), ab);
+    assert_eq!(sets.get(ab).iter().collect::<Vec<_>>(), vec![70, 300]);
+
+    let mask = sets.group_mask(&[300].into_iter().collect());
+    assert!(mask.covers(MANUAL_SOURCE_SET));
+    assert!(mask.covers(ab));
+    assert!(mask.covers(b));
+    assert!(!mask.covers(a));
+    assert!(!mask.covers(NO_SOURCES));
+}
+
+#[test]
+fn test_blocking_source_ids_attribute_every_subscribed_source() {
+    let mut index = index();
+    index.source_ids = (1..=SOURCES).map(|n| (n, 1000 + n as i64)).collect();
+
+    assert_eq!(
+        index.blocking_source_ids("only-64.example", ALL, None),
+        vec![1064]
+    );
+    assert_eq!(
+        index.blocking_source_ids("ads.sub-150.example", HIGH, None),
+        vec![1150]
+    );
+    assert!(index
+        .blocking_source_ids("ads.sub-150.example", LOW, None)
+        .is_empty());
+
+    let low = index.blocking_source_ids("shared.example", LOW, None);
+    assert_eq!(low, (1001..=1063).collect::<Vec<i64>>());
+    assert!(index
+        .blocking_source_ids("shared.example", NONE, None)
+        .is_empty());
+}
@@ -0,0 +1,87 @@
+use ferrous_dns_domain::{BlockingMode, BlockingResponse};
+use ferrous_dns_infrastructure::dns::server::{blocked_answer, BlockedAnswer};
+use hickory_proto::op::ResponseCode;
+use hickory_proto::rr::{Name, RData, RecordType};
+use std::net::Ipv4Addr;
+use std::str::FromStr;
+
+const TTL: u32 = 42;
+
+fn response(mode: BlockingMode) -> BlockingResponse {
+    BlockingResponse {
+        mode,
+        ..Default::default()
+    }
+}
+
+fn name() -> Name {
+    Name::from_str("ads.example.com.").unwrap()
+}
+
+fn assert_negative_soa(blocked: &BlockedAnswer) {
+    let soa_record = blocked
+        .authority
+        .as_ref()
+        .expect("negative block response carries an SOA");
+    assert_eq!(soa_record.name(), &name());
+    assert_eq!(soa_record.ttl(), TTL);
+    let RData::SOA(soa) = soa_record.data() else {
+        panic!("authority record is not an SOA");
+    };
+    assert_eq!(soa.minimum(), TTL);
+}
+
+#[test]
+fn test_nxdomain_block_has_soa_with_blocked_ttl() {
+    let blocked = blocked_answer(
+        response(BlockingMode::Nxdomain),
+        &name(),
+        RecordType::A,
+        TTL,
+    );
+    assert_eq!(blocked.code, ResponseCode::NXDomain);
+    assert!(blocked.answer.is_none());
+    assert_negative_soa(&blocked);
+}
+
+#[test]
+fn test_nodata_block_has_soa_with_blocked_ttl() {
+    let blocked = blocked_answer(response(BlockingMode::Nodata), &name(), RecordType::A, TTL);
+    assert_eq!(blocked.code, ResponseCode::NoError);
+    assert!(blocked.answer.is_none());
+    assert_negative_soa(&blocked);
+}
+
+#[test]
+fn test_null_ip_block_of_non_address_type_has_soa() {
+    let blocked = blocked_answer(
+        response(BlockingMode::NullIp),
+        &name(),
+        RecordType::TXT,
+        TTL,
+    );
+    assert_eq!(blocked.code, ResponseCode::NoError);
+    assert!(blocked.answer.is_none());
+    assert_negative_soa(&blocked);
+}
+
+#[test]
+fn test_null_ip_block_answers_without_authority() {
+    let blocked = blocked_answer(response(BlockingMode::NullIp), &name(), RecordType::A, TTL);
+    assert_eq!(blocked.code, ResponseCode::NoError);
+    let answer = blocked.answer.expect("A query gets an address");
+    assert_eq!(answer.ttl(), TTL);
+    assert_eq!(
+        answer.data(),
+        &RData::A(hickory_proto::rr::rdata::A(Ipv4Addr::UNSPECIFIED))
+    );
+    assert!(blocked.authority.is_none());
+}
+
+#[test]
+fn test_refused_block_has_no_records() {
+    let blocked = blocked_answer(response(BlockingMode::Refused), &name(), RecordType::A, TTL);
+    assert_eq!(blocked.code, ResponseCode::Refused);
+    assert!(blocked.answer.is_none());
+    assert!(blocked.authority.is_none());
+}
@@ -0,0 +1,260 @@
+use arc_swap::ArcSwap;
+use chrono::{Duration, Utc};
+use ferrous_dns_application::ports::{
+    BlocklistSimulation, BlocklistSimulator, CandidateList, SimulatedClient, SimulatedDomain,
+};
+use ferrous_dns_infrastructure::dns::block_filter::{
+    build_list_index, parse_list_text, BlockIndex, RuleDirectory, SourceBits,
+};
+use ferrous_dns_infrastructure::dns::SqliteBlocklistSimulator;
+use sqlx::sqlite::SqlitePoolOptions;
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+const CANDIDATE_LIST: &str = "\
+||ads.example^
+safe.example
+@@||cdn.example^
+";
+
+async fn create_test_db() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE groups (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL UNIQUE,
+            is_default BOOLEAN NOT NULL DEFAULT 0
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE clients (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            ip_address TEXT NOT NULL UNIQUE,
+            hostname TEXT
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE query_log (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            domain TEXT NOT NULL,
+            record_type TEXT NOT NULL DEFAULT 'A',
+            client_ip TEXT NOT NULL DEFAULT '127.0.0.1',
+            blocked INTEGER NOT NULL DEFAULT 0,
+            query_source TEXT NOT NULL DEFAULT 'client',
+            group_id INTEGER,
+            block_source TEXT,
+            created_at DATETIME NOT NULL DEFAULT (datetime('now'))
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        "INSERT INTO groups (id, name, is_default) VALUES (1, 'Protected', 1), (2, 'Kids', 0)",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    pool
+}
+
+async fn log_query(
+    pool: &SqlitePool,
+    domain: &str,
+    client_ip: &str,
+    group_id: Option<i64>,
+    block_source: Option<&str>,
+    query_source: &str,
+    age: Duration,
+) {
+    let created_at = (Utc::now() - age).format("%Y-%m-%d %H:%M:%S").to_string();
+    sqlx::query(
+        "INSERT INTO query_log (domain, client_ip, group_id, blocked, block_source, query_source, created_at)
+         VALUES (?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(domain)
+    .bind(client_ip)
+    .bind(group_id)
+    .bind(block_source.is_some())
+    .bind(block_source)
+    .bind(query_source)
+    .bind(created_at)
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+/// The live index excepts `safe.example` for the default group.
+fn live_index() -> Arc<ArcSwap<BlockIndex>> {
+    let entries = HashMap::from([(1, parse_list_text("@@||safe.example^\n"))]);
+    let groups = HashMap::from([(1, [1].into_iter().collect::<SourceBits>())]);
+    let index = build_list_index(&entries, &groups, &RuleDirectory::default());
+    Arc::new(ArcSwap::from_pointee(index))
+}
+
+async fn seeded_simulator() -> SqliteBlocklistSimulator {
+    let pool = create_test_db().await;
+    let recent = Duration::hours(1);
+    let stale = Duration::days(3);
+    let rows = [
+        ("ads.example", "10.0.0.1", Some(1), None, "client", recent),
+        ("ads.example", "10.0.0.1", Some(1), None, "client", recent),
+        ("ads.example", "10.0.0.1", Some(1), None, "client", recent),
+        (
+            "tracker.ads.example",
+            "10.0.0.2",
+            None,
+            None,
+            "client",
+            recent,
+        ),
+        ("ads.example", "10.0.0.3", Some(2), None, "client", recent),
+        ("safe.example", "10.0.0.1", Some(1), None, "client", recent),
+        (
+            "cdn.example",
+            "10.0.0.2",
+            Some(1),
+            Some("blocklist"),
+            "client",
+            recent,
+        ),
+        ("ads.example", "10.0.0.1", Some(1), None, "internal", recent),
+        ("ads.example", "10.0.0.4", Some(1), None, "client", stale),
+    ];
+    for (domain, client_ip, group_id, block_source, query_source, age) in rows {
+        log_query(
+            &pool,
+            domain,
+            client_ip,
+            group_id,
+            block_source,
+            query_source,
+            age,
+        )
+        .await;
+    }
+
+    SqliteBlocklistSimulator::new(pool, live_index()).unwrap()
+}
+
+fn last_day(group_ids: Vec<i64>) -> BlocklistSimulation {
+    let to = Utc::now();
+    BlocklistSimulation {
+        list: CandidateList::Text(CANDIDATE_LIST.to_string()),
+        from: to - Duration::hours(24),
+        to,
+        group_ids,
+        limit: 100,
+    }
+}
+
+#[tokio::test]
+async fn test_reports_allowed_queries_the_list_would_block() {
+    let simulator = seeded_simulator().await;
+
+    let report = simulator.simulate(&last_day(Vec::new())).await.unwrap();
+
+    assert_eq!(report.entry_count, 3);
+    assert_eq!(report.queries_examined, 7);
+    assert_eq!(report.newly_blocked.queries, 5);
+    assert_eq!(report.newly_blocked.total_domains, 2);
+    assert_eq!(report.newly_blocked.total_clients, 3);
+    assert_eq!(
+        report.newly_blocked.domains,
+        vec![
+            SimulatedDomain {
+                domain: "ads.example".to_string(),
+                queries: 4,
+                clients: 2,
+            },
+            SimulatedDomain {
+                domain: "tracker.ads.example".to_string(),
+                queries: 1,
+                clients: 1,
+            },
+        ]
+    );
+    assert_eq!(
+        report.newly_blocked.clients[0],
+        SimulatedClient {
+            client_ip: "10.0.0.1".parse().unwrap(),
+            queries: 3,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_live_exceptions_are_not_reported_as_newly_blocked() {
+    let simulator = seeded_simulator().await;
+
+    let report = simulator.simulate(&last_day(Vec::new())).await.unwrap();
+
+    assert!(report
+        .newly_blocked
+        .domains
+        .iter()
+        .all(|d| d.domain != "safe.example"));
+}
+
+#[tokio::test]
+async fn test_list_exceptions_report_newly_unblocked_queries() {
+    let simulator = seeded_simulator().await;
+
+    let report = simulator.simulate(&last_day(Vec::new())).await.unwrap();
+
+    assert_eq!(report.newly_unblocked.queries, 1);
+    assert_eq!(report.newly_unblocked.domains[0].domain, "cdn.example");
+}
+
+#[tokio::test]
+async fn test_group_filter_limits_replayed_queries() {
+    let simulator = seeded_simulator().await;
+
+    let report = simulator.simulate(&last_day(vec![2])).await.unwrap();
+
+    assert_eq!(report.queries_examined, 1);
+    assert_eq!(report.newly_blocked.queries, 1);
+    assert_eq!(
+        report.newly_blocked.clients,
+        vec![SimulatedClient {
+            client_ip: "10.0.0.3".parse().unwrap(),
+            queries: 1,
+        }]
+    );
+    assert_eq!(report.newly_unblocked.queries, 0);
+}
+
+#[tokio::test]
+async fn test_limit_truncates_listed_domains_but_not_totals() {
+    let simulator = seeded_simulator().await;
+    let mut simulation = last_day(Vec::new());
+    simulation.limit = 1;
+
+    let report = simulator.simulate(&simulation).await.unwrap();
+
+    assert_eq!(report.newly_blocked.domains.len(), 1);
+    assert_eq!(report.newly_blocked.clients.len(), 1);
+    assert_eq!(report.newly_blocked.total_domains, 2);
+    assert_eq!(report.newly_blocked.queries, 5);
+}
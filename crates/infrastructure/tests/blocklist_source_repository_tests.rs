@@ -36,6 +36,7 @@ async fn create_test_db() -> SqlitePool {
             group_id    INTEGER NOT NULL DEFAULT 1 REFERENCES groups(id) ON DELETE RESTRICT,
             comment     TEXT,
             enabled     BOOLEAN NOT NULL DEFAULT 1,
+            observe_only INTEGER NOT NULL DEFAULT 0,
             created_at  DATETIME DEFAULT CURRENT_TIMESTAMP,
             updated_at  DATETIME DEFAULT CURRENT_TIMESTAMP
         )",
@@ -55,6 +56,24 @@ async fn create_test_db() -> SqlitePool {
     .await
     .unwrap();
 
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS list_source_fetches (
+            url              TEXT    PRIMARY KEY,
+            etag             TEXT,
+            last_modified    TEXT,
+            content          TEXT,
+            last_attempt_at  TEXT    NOT NULL,
+            last_success_at  TEXT,
+            last_http_status INTEGER,
+            entry_count      INTEGER NOT NULL DEFAULT 0,
+            parse_errors     INTEGER NOT NULL DEFAULT 0,
+            last_error       TEXT
+        )",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
     sqlx::query(
         "INSERT INTO groups (id, name, enabled, comment, is_default)
          VALUES (1, 'Protected', 1, 'Default group', 1)",
@@ -78,6 +97,7 @@ async fn test_create_and_get_source() {
             vec![1],
             Some("Ad blocking list".to_string()),
             true,
+            false,
         )
         .await
         .unwrap();
@@ -102,7 +122,7 @@ async fn test_create_without_url() {
     let repo = SqliteBlocklistSourceRepository::new(pool);
 
     let source = repo
-        .create("Manual List".to_string(), None, vec![1], None, true)
+        .create("Manual List".to_string(), None, vec![1], None, true, false)
         .await
         .unwrap();
 
@@ -122,7 +142,14 @@ async fn test_create_with_multiple_groups() {
     let repo = SqliteBlocklistSourceRepository::new(pool);
 
     let source = repo
-        .create("Shared List".to_string(), None, vec![1, 2], None, true)
+        .create(
+            "Shared List".to_string(),
+            None,
+            vec![1, 2],
+            None,
+            true,
+            false,
+        )
         .await
         .unwrap();
 
@@ -139,12 +166,26 @@ async fn test_create_unique_name_constraint() {
     let pool = create_test_db().await;
     let repo = SqliteBlocklistSourceRepository::new(pool);
 
-    repo.create("Duplicate Name".to_string(), None, vec![1], None, true)
-        .await
-        .unwrap();
+    repo.create(
+        "Duplicate Name".to_string(),
+        None,
+        vec![1],
+        None,
+        true,
+        false,
+    )
+    .await
+    .unwrap();
 
     let result = repo
-        .create("Duplicate Name".to_string(), None, vec![1], None, false)
+        .create(
+            "Duplicate Name".to_string(),
+            None,
+            vec![1],
+            None,
+            false,
+            false,
+        )
         .await;
 
     assert!(result.is_err());
@@ -170,13 +211,13 @@ async fn test_get_all_ordered_by_name() {
     let pool = create_test_db().await;
     let repo = SqliteBlocklistSourceRepository::new(pool);
 
-    repo.create("Zzz List".to_string(), None, vec![1], None, true)
+    repo.create("Zzz List".to_string(), None, vec![1], None, true, false)
         .await
         .unwrap();
-    repo.create("Aaa List".to_string(), None, vec![1], None, true)
+    repo.create("Aaa List".to_string(), None, vec![1], None, true, false)
         .await
         .unwrap();
-    repo.create("Mmm List".to_string(), None, vec![1], None, true)
+    repo.create("Mmm List".to_string(), None, vec![1], None, true, false)
         .await
         .unwrap();
 
@@ -202,13 +243,13 @@ async fn test_update_enabled_field() {
     let repo = SqliteBlocklistSourceRepository::new(pool);
 
     let source = repo
-        .create("Toggle List".to_string(), None, vec![1], None, true)
+        .create("Toggle List".to_string(), None, vec![1], None, true, false)
         .await
         .unwrap();
     let id = source.id.unwrap();
 
     let updated = repo
-        .update(id, None, None, None, None, Some(false))
+        .update(id, None, None, None, None, Some(false), None)
         .await
         .unwrap();
 
@@ -224,7 +265,7 @@ async fn test_update_name() {
     let repo = SqliteBlocklistSourceRepository::new(pool);
 
     let source = repo
-        .create("Old Name".to_string(), None, vec![1], None, true)
+        .create("Old Name".to_string(), None, vec![1], None, true, false)
         .await
         .unwrap();
 
@@ -236,6 +277,7 @@ async fn test_update_name() {
             None,
             None,
             None,
+            None,
         )
         .await
         .unwrap();
@@ -254,12 +296,20 @@ async fn test_update_group_ids() {
         .unwrap();
 
     let source = repo
-        .create("Group Test".to_string(), None, vec![1], None, true)
+        .create("Group Test".to_string(), None, vec![1], None, true, false)
         .await
         .unwrap();
 
     let updated = repo
-        .update(source.id.unwrap(), None, None, Some(vec![2]), None, None)
+        .update(
+            source.id.unwrap(),
+            None,
+            None,
+            Some(vec![2]),
+            None,
+            None,
+            None,
+        )
         .await
         .unwrap();
 
@@ -280,13 +330,13 @@ async fn test_update_assign_multiple_groups() {
         .unwrap();
 
     let source = repo
-        .create("Shared List".to_string(), None, vec![1], None, true)
+        .create("Shared List".to_string(), None, vec![1], None, true, false)
         .await
         .unwrap();
     let id = source.id.unwrap();
 
     let updated = repo
-        .update(id, None, None, Some(vec![1, 2]), None, None)
+        .update(id, None, None, Some(vec![1, 2]), None, None, None)
         .await
         .unwrap();
 
@@ -303,7 +353,9 @@ async fn test_update_not_found() {
     let pool = create_test_db().await;
     let repo = SqliteBlocklistSourceRepository::new(pool);
 
-    let result = repo.update(999, None, None, None, None, Some(false)).await;
+    let result = repo
+        .update(999, None, None, None, None, Some(false), None)
+        .await;
 
     assert!(result.is_err());
     let err_str = format!("{:?}", result.unwrap_err());
@@ -326,12 +378,13 @@ async fn test_update_clear_url() {
             vec![1],
             None,
             true,
+            false,
         )
         .await
         .unwrap();
 
     let updated = repo
-        .update(source.id.unwrap(), None, Some(None), None, None, None)
+        .update(source.id.unwrap(), None, Some(None), None, None, None, None)
         .await
         .unwrap();
 
@@ -344,7 +397,7 @@ async fn test_delete_success() {
     let repo = SqliteBlocklistSourceRepository::new(pool);
 
     let source = repo
-        .create("To Delete".to_string(), None, vec![1], None, true)
+        .create("To Delete".to_string(), None, vec![1], None, true, false)
         .await
         .unwrap();
     let id = source.id.unwrap();
@@ -366,7 +419,14 @@ async fn test_delete_cascades_pivot_rows() {
         .unwrap();
 
     let source = repo
-        .create("Multi Group List".to_string(), None, vec![1, 2], None, true)
+        .create(
+            "Multi Group List".to_string(),
+            None,
+            vec![1, 2],
+            None,
+            true,
+            false,
+        )
         .await
         .unwrap();
     let id = source.id.unwrap();
@@ -402,7 +462,7 @@ async fn test_fk_group_restricts_delete() {
     let pool = create_test_db().await;
     let repo = SqliteBlocklistSourceRepository::new(pool.clone());
 
-    repo.create("FK Test List".to_string(), None, vec![1], None, true)
+    repo.create("FK Test List".to_string(), None, vec![1], None, true, false)
         .await
         .unwrap();
 
@@ -415,3 +475,57 @@ async fn test_fk_group_restricts_delete() {
         "FK constraint should prevent group deletion"
     );
 }
+
+#[tokio::test]
+async fn test_source_reports_fetch_status_for_its_url() {
+    let pool = create_test_db().await;
+    let repo = SqliteBlocklistSourceRepository::new(pool.clone());
+
+    let created = repo
+        .create(
+            "Status List".to_string(),
+            Some("https://example.com/list.txt".to_string()),
+            vec![1],
+            None,
+            true,
+            false,
+        )
+        .await
+        .unwrap();
+    assert!(created.status.is_none());
+
+    sqlx::query(
+        "INSERT INTO list_source_fetches
+             (url, last_attempt_at, last_success_at, last_http_status, entry_count, parse_errors, last_error)
+         VALUES ('https://example.com/list.txt', '2026-03-14 10:00:00', '2026-03-13 10:00:00', 503, 1200, 3, 'HTTP 503')",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let id = created.id.unwrap();
+    let status = repo.get_by_id(id).await.unwrap().unwrap().status.unwrap();
+    assert_eq!(status.last_attempt_at, "2026-03-14 10:00:00");
+    assert_eq!(
+        status.last_success_at.as_deref(),
+        Some("2026-03-13 10:00:00")
+    );
+    assert_eq!(status.last_http_status, Some(503));
+    assert_eq!(status.entry_count, 1200);
+    assert_eq!(status.parse_errors, 3);
+    assert_eq!(status.last_error.as_deref(), Some("HTTP 503"));
+
+    let updated = repo
+        .update(
+            id,
+            None,
+            Some(Some("https://example.com/other.txt".to_string())),
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    assert!(updated.status.is_none());
+}
@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 use ferrous_dns_application::ports::{DnsResolution, DnsResolver};
-use ferrous_dns_domain::{DnsQuery, DomainError, RecordType};
+use ferrous_dns_domain::{DnsQuery, DomainError, QueryScope, RecordType};
 use ferrous_dns_infrastructure::dns::resolver::CachedResolver;
 use ferrous_dns_infrastructure::dns::{
     DnsCache, DnsCacheAccess, DnsCacheConfig, EvictionStrategy, NegativeQueryTracker,
@@ -60,6 +60,7 @@ fn make_query(domain: &str) -> DnsQuery {
     DnsQuery {
         domain: Arc::from(domain),
         record_type: RecordType::A,
+        scope: QueryScope::SHARED,
     }
 }
 
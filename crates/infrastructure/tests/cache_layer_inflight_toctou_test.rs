@@ -22,7 +22,7 @@
 
 use async_trait::async_trait;
 use ferrous_dns_application::ports::{DnsResolution, DnsResolver};
-use ferrous_dns_domain::{DnsQuery, DomainError, RecordType};
+use ferrous_dns_domain::{DnsQuery, DomainError, QueryScope, RecordType};
 use ferrous_dns_infrastructure::dns::resolver::CachedResolver;
 use ferrous_dns_infrastructure::dns::{
     CachedAddresses, CachedData, DnsCache, DnsCacheAccess, DnsCacheConfig, DnssecStatus,
@@ -152,6 +152,7 @@ fn make_query(domain: &str, record_type: RecordType) -> DnsQuery {
     DnsQuery {
         domain: Arc::from(domain),
         record_type,
+        scope: QueryScope::SHARED,
     }
 }
 
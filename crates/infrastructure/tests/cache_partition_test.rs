@@ -0,0 +1,292 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{DnsResolution, DnsResolver};
+use ferrous_dns_domain::{DnsQuery, DomainError, QueryScope, RecordType};
+use ferrous_dns_infrastructure::dns::resolver::CachedResolver;
+use ferrous_dns_infrastructure::dns::{
+    CachedAddresses, CachedData, DnsCache, DnsCacheAccess, DnsCacheConfig, DnssecStatus,
+    EvictionStrategy, NegativeQueryTracker,
+};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+fn make_cache() -> Arc<DnsCache> {
+    make_cache_with_refresh_threshold(0.75)
+}
+
+fn make_cache_with_refresh_threshold(refresh_threshold: f64) -> Arc<DnsCache> {
+    Arc::new(DnsCache::new(DnsCacheConfig {
+        max_entries: 1000,
+        eviction_strategy: EvictionStrategy::LRU,
+        min_threshold: 2.0,
+        refresh_threshold,
+        batch_eviction_percentage: 0.2,
+        adaptive_thresholds: false,
+        min_frequency: 0,
+        min_lfuk_score: 0.0,
+        shard_amount: 4,
+        access_window_secs: 7200,
+        eviction_sample_size: 8,
+        lfuk_k_value: 0.5,
+        refresh_sample_rate: 1.0,
+        min_ttl: 0,
+        max_ttl: 86_400,
+    }))
+}
+
+fn group(id: i64) -> QueryScope {
+    QueryScope {
+        view: None,
+        group: Some(id),
+    }
+}
+
+fn view(id: u32) -> QueryScope {
+    QueryScope {
+        view: Some(id),
+        group: None,
+    }
+}
+
+fn ip_data(ip: &str) -> CachedData {
+    CachedData::IpAddresses(CachedAddresses {
+        addresses: Arc::new(vec![ip.parse().unwrap()]),
+    })
+}
+
+fn addresses(hit: Option<(CachedData, Option<DnssecStatus>, Option<u32>)>) -> Option<Vec<IpAddr>> {
+    match hit?.0 {
+        CachedData::IpAddresses(entry) => Some(entry.addresses.to_vec()),
+        _ => None,
+    }
+}
+
+/// Answers with a different address per group so cross-partition hits are
+/// detectable.
+struct GroupAwareResolver;
+
+#[async_trait]
+impl DnsResolver for GroupAwareResolver {
+    async fn resolve(&self, query: &DnsQuery) -> Result<DnsResolution, DomainError> {
+        let ip = match query.scope.group {
+            Some(_) => "185.228.168.10",
+            None => "93.184.216.34",
+        };
+        Ok(DnsResolution::new(vec![ip.parse().unwrap()], false))
+    }
+}
+
+// ── DnsCache ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn partitioned_entry_is_invisible_to_shared_and_other_groups() {
+    let cache = make_cache();
+    cache.insert_partitioned(
+        "site.example",
+        RecordType::A,
+        group(5),
+        ip_data("10.0.0.5"),
+        300,
+        None,
+    );
+
+    assert!(cache.get("site.example", &RecordType::A).is_none());
+    assert!(cache
+        .get_partitioned("site.example", &RecordType::A, group(6))
+        .is_none());
+    assert_eq!(
+        addresses(cache.get_partitioned("site.example", &RecordType::A, group(5))),
+        Some(vec!["10.0.0.5".parse().unwrap()])
+    );
+}
+
+#[test]
+fn shared_entry_is_invisible_to_partitions() {
+    let cache = make_cache();
+    cache.insert(
+        "site.example",
+        RecordType::A,
+        ip_data("93.184.216.34"),
+        300,
+        None,
+    );
+
+    // Warm the thread-local L1 with the shared entry before the partitioned lookup.
+    assert!(cache.get("site.example", &RecordType::A).is_some());
+    assert!(cache
+        .get_partitioned("site.example", &RecordType::A, group(5))
+        .is_none());
+}
+
+#[test]
+fn same_name_holds_independent_answers_per_partition() {
+    let cache = make_cache();
+    cache.insert(
+        "Site.Example",
+        RecordType::A,
+        ip_data("93.184.216.34"),
+        300,
+        None,
+    );
+    cache.insert_partitioned(
+        "site.example",
+        RecordType::A,
+        group(5),
+        ip_data("10.0.0.5"),
+        300,
+        None,
+    );
+
+    for _ in 0..2 {
+        assert_eq!(
+            addresses(cache.get("site.example", &RecordType::A)),
+            Some(vec!["93.184.216.34".parse().unwrap()])
+        );
+        assert_eq!(
+            addresses(cache.get_partitioned("SITE.example", &RecordType::A, group(5))),
+            Some(vec!["10.0.0.5".parse().unwrap()])
+        );
+    }
+}
+
+#[test]
+fn negative_entries_are_partitioned() {
+    let cache = make_cache();
+    cache.insert_partitioned(
+        "blocked.example",
+        RecordType::A,
+        group(5),
+        CachedData::NegativeResponse,
+        300,
+        None,
+    );
+
+    assert!(cache.get("blocked.example", &RecordType::A).is_none());
+    assert!(matches!(
+        cache.get_partitioned("blocked.example", &RecordType::A, group(5)),
+        Some((CachedData::NegativeResponse, _, _))
+    ));
+}
+
+#[test]
+fn refresh_candidates_keep_their_partition() {
+    let cache = make_cache_with_refresh_threshold(0.0);
+    cache.insert_partitioned(
+        "refresh.example",
+        RecordType::A,
+        group(5),
+        ip_data("10.0.0.5"),
+        300,
+        None,
+    );
+
+    let candidates = cache.get_refresh_candidates();
+    let key = candidates
+        .iter()
+        .find(|k| k.domain == "refresh.example")
+        .expect("partitioned entry must be a refresh candidate");
+    assert_eq!(key.partition, group(5));
+
+    assert!(cache.refresh_record_partitioned(
+        "refresh.example",
+        &RecordType::A,
+        group(5),
+        Some(300),
+        ip_data("10.0.0.6"),
+        None,
+    ));
+    assert!(cache.get("refresh.example", &RecordType::A).is_none());
+    assert_eq!(
+        addresses(cache.get_partitioned("refresh.example", &RecordType::A, group(5))),
+        Some(vec!["10.0.0.6".parse().unwrap()])
+    );
+}
+
+#[test]
+fn view_partition_is_separate_from_its_groups() {
+    let cache = make_cache();
+    let office = view(0);
+    let office_kids = QueryScope {
+        view: Some(0),
+        group: Some(5),
+    };
+    cache.insert_partitioned(
+        "git.example",
+        RecordType::A,
+        office,
+        ip_data("10.0.0.5"),
+        300,
+        None,
+    );
+
+    assert!(cache.get("git.example", &RecordType::A).is_none());
+    assert!(cache
+        .get_partitioned("git.example", &RecordType::A, group(5))
+        .is_none());
+    assert!(cache
+        .get_partitioned("git.example", &RecordType::A, office_kids)
+        .is_none());
+    assert!(cache
+        .get_partitioned("git.example", &RecordType::A, view(1))
+        .is_none());
+    assert_eq!(
+        addresses(cache.get_partitioned("git.example", &RecordType::A, office)),
+        Some(vec!["10.0.0.5".parse().unwrap()])
+    );
+}
+
+#[test]
+fn permanent_local_records_are_visible_from_every_partition() {
+    let cache = make_cache();
+    cache.insert_permanent("nas.lan", RecordType::A, ip_data("192.168.1.20"), None);
+
+    for scope in [group(5), view(0)] {
+        assert_eq!(
+            addresses(cache.get_partitioned("nas.lan", &RecordType::A, scope)),
+            Some(vec!["192.168.1.20".parse().unwrap()])
+        );
+    }
+}
+
+// ── CachedResolver ───────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn cached_resolver_never_serves_one_groups_answer_to_another() {
+    let cache: Arc<dyn DnsCacheAccess> = make_cache();
+    let resolver = CachedResolver::new(
+        Arc::new(GroupAwareResolver) as Arc<dyn DnsResolver>,
+        Arc::clone(&cache),
+        300,
+        Arc::new(NegativeQueryTracker::new()),
+        4,
+    );
+
+    let kids = DnsQuery::new("video.example", RecordType::A).with_group(Some(3));
+    let filtered = resolver.resolve(&kids).await.unwrap();
+    assert!(!filtered.cache_hit);
+    assert_eq!(
+        *filtered.addresses,
+        vec!["185.228.168.10".parse::<IpAddr>().unwrap()]
+    );
+
+    assert!(resolver
+        .try_cache_str("video.example", RecordType::A)
+        .is_none());
+
+    let admin = DnsQuery::new("video.example", RecordType::A);
+    let plain = resolver.resolve(&admin).await.unwrap();
+    assert!(!plain.cache_hit);
+    assert_eq!(
+        *plain.addresses,
+        vec!["93.184.216.34".parse::<IpAddr>().unwrap()]
+    );
+
+    let other_group = DnsQuery::new("video.example", RecordType::A).with_group(Some(4));
+    assert!(resolver.try_cache(&other_group).is_none());
+
+    let again = resolver.resolve(&kids).await.unwrap();
+    assert!(again.cache_hit);
+    assert_eq!(
+        *again.addresses,
+        vec!["185.228.168.10".parse::<IpAddr>().unwrap()]
+    );
+}
@@ -20,7 +20,7 @@
 
 use async_trait::async_trait;
 use ferrous_dns_application::ports::{DnsResolution, DnsResolver};
-use ferrous_dns_domain::{DnsQuery, DomainError, RecordType};
+use ferrous_dns_domain::{DnsQuery, DomainError, QueryScope, RecordType};
 use ferrous_dns_infrastructure::dns::resolver::CachedResolver;
 use ferrous_dns_infrastructure::dns::{
     DnsCache, DnsCacheAccess, DnsCacheConfig, EvictionStrategy, NegativeQueryTracker,
@@ -90,6 +90,7 @@ fn make_query(domain: &str) -> DnsQuery {
     DnsQuery {
         domain: Arc::from(domain),
         record_type: RecordType::A,
+        scope: QueryScope::SHARED,
     }
 }
 
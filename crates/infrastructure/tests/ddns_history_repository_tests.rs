@@ -0,0 +1,121 @@
+use ferrous_dns_application::ports::DdnsHistoryRepository;
+use ferrous_dns_domain::{DdnsResult, DdnsUpdate};
+use ferrous_dns_infrastructure::repositories::ddns_history_repository::SqliteDdnsHistoryRepository;
+use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
+use std::sync::Arc;
+
+async fn create_test_db() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::query(
+        "CREATE TABLE ddns_updates (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            hostname TEXT NOT NULL,
+            ip TEXT,
+            result TEXT NOT NULL,
+            token_id INTEGER,
+            client_ip TEXT,
+            created_at TEXT NOT NULL
+        )",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    pool
+}
+
+fn update(hostname: &str, ip: Option<&str>, result: DdnsResult) -> DdnsUpdate {
+    DdnsUpdate {
+        id: None,
+        hostname: Arc::from(hostname),
+        ip: ip.map(Arc::from),
+        result,
+        token_id: Some(3),
+        client_ip: Some(Arc::from("198.51.100.7")),
+        created_at: None,
+    }
+}
+
+#[tokio::test]
+async fn test_record_and_get_recent() {
+    let repo = SqliteDdnsHistoryRepository::new(create_test_db().await);
+
+    repo.record(&update(
+        "nas.home.lan",
+        Some("192.168.1.50"),
+        DdnsResult::Good,
+    ))
+    .await
+    .unwrap();
+    repo.record(&update("nas.home.lan", None, DdnsResult::BadAuth))
+        .await
+        .unwrap();
+
+    let recent = repo.get_recent(None, 10).await.unwrap();
+    assert_eq!(recent.len(), 2);
+    assert_eq!(recent[0].result, DdnsResult::BadAuth);
+    assert_eq!(recent[0].ip, None);
+    assert_eq!(recent[1].result, DdnsResult::Good);
+    assert_eq!(recent[1].ip.as_deref(), Some("192.168.1.50"));
+    assert_eq!(recent[1].token_id, Some(3));
+    assert_eq!(recent[1].client_ip.as_deref(), Some("198.51.100.7"));
+    assert!(recent[1].id.is_some());
+    assert!(recent[1].created_at.is_some());
+}
+
+#[tokio::test]
+async fn test_get_recent_filters_by_hostname_and_limits() {
+    let repo = SqliteDdnsHistoryRepository::new(create_test_db().await);
+    for hostname in [
+        "nas.home.lan",
+        "tv.home.lan",
+        "nas.home.lan",
+        "nas.home.lan",
+    ] {
+        repo.record(&update(hostname, Some("192.168.1.50"), DdnsResult::NoChg))
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(
+        repo.get_recent(Some("nas.home.lan"), 10)
+            .await
+            .unwrap()
+            .len(),
+        3
+    );
+    assert_eq!(
+        repo.get_recent(Some("tv.home.lan"), 10)
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+    assert_eq!(repo.get_recent(None, 2).await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_prune_keeps_most_recent() {
+    let repo = SqliteDdnsHistoryRepository::new(create_test_db().await);
+    for i in 0..5 {
+        repo.record(&update(
+            &format!("host{i}.home.lan"),
+            Some("192.168.1.50"),
+            DdnsResult::Good,
+        ))
+        .await
+        .unwrap();
+    }
+
+    assert_eq!(repo.prune(2).await.unwrap(), 3);
+    let remaining = repo.get_recent(None, 10).await.unwrap();
+    assert_eq!(remaining.len(), 2);
+    assert_eq!(remaining[0].hostname.as_ref(), "host4.home.lan");
+    assert_eq!(remaining[1].hostname.as_ref(), "host3.home.lan");
+
+    assert_eq!(repo.prune(2).await.unwrap(), 0);
+}
@@ -334,7 +334,7 @@ fn test_refresh_candidates_includes_entries_within_access_window() {
 
     let candidates = cache.get_refresh_candidates();
     assert!(
-        candidates.iter().any(|(d, _)| d == "never-hit.com"),
+        candidates.iter().any(|k| k.domain == "never-hit.com"),
         "Entrada dentro da access window deve ser candidata mesmo sem hits; candidates={:?}",
         candidates
     );
@@ -360,7 +360,7 @@ fn test_refresh_candidates_with_recent_access() {
 
     let candidates = cache.get_refresh_candidates();
     assert!(
-        candidates.iter().any(|(d, _)| d == "popular.com"),
+        candidates.iter().any(|k| k.domain == "popular.com"),
         "Entrada com hit deve ser candidata; candidates={:?}",
         candidates
     );
@@ -382,7 +382,7 @@ fn test_refresh_candidates_access_window_zero_includes_same_tick_entries() {
 
     let candidates = cache_no_window.get_refresh_candidates();
     assert!(
-        candidates.iter().any(|(d, _)| d == "zero-window.com"),
+        candidates.iter().any(|k| k.domain == "zero-window.com"),
         "Entry inserida no mesmo tick deve ser candidata com window=0; candidates={:?}",
         candidates
     );
@@ -453,7 +453,7 @@ fn test_refresh_record_preserves_hit_count_for_subsequent_candidates() {
     // Antes do refresh: deve ser candidata
     let before = cache.get_refresh_candidates();
     assert!(
-        before.iter().any(|(d, _)| d == "keep-alive.com"),
+        before.iter().any(|k| k.domain == "keep-alive.com"),
         "Entrada deve ser candidata antes do refresh; candidates={:?}",
         before
     );
@@ -470,7 +470,7 @@ fn test_refresh_record_preserves_hit_count_for_subsequent_candidates() {
     // Depois do refresh_record: AINDA deve ser candidata porque hit_count foi preservado
     let after = cache.get_refresh_candidates();
     assert!(
-        after.iter().any(|(d, _)| d == "keep-alive.com"),
+        after.iter().any(|k| k.domain == "keep-alive.com"),
         "Entrada deve continuar candidata após refresh_record (hit_count preservado); candidates={:?}",
         after
     );
@@ -1338,7 +1338,7 @@ fn test_stale_record_is_refresh_candidate_after_client_access() {
 
     let candidates = cache.get_refresh_candidates();
     assert!(
-        candidates.iter().any(|(d, _)| d == "stale-refresh.com"),
+        candidates.iter().any(|k| k.domain == "stale-refresh.com"),
         "Stale record must appear in refresh candidates after get(); candidates={candidates:?}"
     );
 }
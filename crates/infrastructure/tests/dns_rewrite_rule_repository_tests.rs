@@ -0,0 +1,129 @@
+use ferrous_dns_application::ports::DnsRewriteRuleRepository;
+use ferrous_dns_domain::{DnsRewriteAnswer, DomainError};
+use ferrous_dns_infrastructure::repositories::dns_rewrite_rule_repository::SqliteDnsRewriteRuleRepository;
+use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
+
+async fn create_test_db() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::query(
+        "CREATE TABLE dns_rewrite_rules (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            domain TEXT NOT NULL,
+            rewrite TEXT NOT NULL,
+            group_id INTEGER NOT NULL DEFAULT 1,
+            enabled INTEGER NOT NULL DEFAULT 1,
+            comment TEXT,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL,
+            UNIQUE(domain, rewrite, group_id)
+        )",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    pool
+}
+
+fn answer(rewrite: &str) -> DnsRewriteAnswer {
+    rewrite.parse().unwrap()
+}
+
+#[tokio::test]
+async fn test_create_and_get_rewrite_rule() {
+    let repo = SqliteDnsRewriteRuleRepository::new(create_test_db().await);
+
+    let created = repo
+        .create(
+            "nas.example.org".to_string(),
+            answer("10.0.0.5"),
+            1,
+            true,
+            Some("NAS".to_string()),
+        )
+        .await
+        .unwrap();
+
+    assert!(created.id.is_some());
+    assert_eq!(created.answer.to_string(), "NOERROR;A;10.0.0.5");
+    assert_eq!(created.comment.as_deref(), Some("NAS"));
+
+    let fetched = repo.get_by_id(created.id.unwrap()).await.unwrap().unwrap();
+    assert_eq!(fetched.domain, created.domain);
+    assert_eq!(fetched.answer, created.answer);
+    assert_eq!(fetched.group_id, 1);
+}
+
+#[tokio::test]
+async fn test_same_rewrite_in_other_group_is_allowed() {
+    let repo = SqliteDnsRewriteRuleRepository::new(create_test_db().await);
+
+    repo.create("example.org".into(), answer("10.0.0.5"), 1, true, None)
+        .await
+        .unwrap();
+    repo.create("example.org".into(), answer("10.0.0.5"), 2, true, None)
+        .await
+        .unwrap();
+    let duplicate = repo
+        .create("example.org".into(), answer("10.0.0.5"), 1, true, None)
+        .await;
+
+    assert!(matches!(
+        duplicate,
+        Err(DomainError::InvalidDnsRewriteRule(_))
+    ));
+    assert_eq!(repo.get_all().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_update_keeps_unset_fields() {
+    let repo = SqliteDnsRewriteRuleRepository::new(create_test_db().await);
+    let created = repo
+        .create(
+            "example.org".into(),
+            answer("lan.example.net"),
+            1,
+            true,
+            Some("keep".into()),
+        )
+        .await
+        .unwrap();
+
+    let updated = repo
+        .update(
+            created.id.unwrap(),
+            None,
+            Some(answer("NXDOMAIN")),
+            None,
+            Some(false),
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(updated.domain.as_ref(), "example.org");
+    assert_eq!(updated.answer.to_string(), "NXDOMAIN;;");
+    assert!(!updated.enabled);
+    assert_eq!(updated.comment.as_deref(), Some("keep"));
+}
+
+#[tokio::test]
+async fn test_delete_rewrite_rule() {
+    let repo = SqliteDnsRewriteRuleRepository::new(create_test_db().await);
+    let created = repo
+        .create("example.org".into(), answer("10.0.0.5"), 1, true, None)
+        .await
+        .unwrap();
+
+    repo.delete(created.id.unwrap()).await.unwrap();
+
+    assert!(repo.get_all().await.unwrap().is_empty());
+    assert!(matches!(
+        repo.delete(created.id.unwrap()).await,
+        Err(DomainError::DnsRewriteRuleNotFound(_))
+    ));
+}
@@ -0,0 +1,123 @@
+use ferrous_dns_application::ports::{DnsRewrite, DnsRewriteStore};
+use ferrous_dns_domain::{DnsRewriteRcode, DnsRewriteRule, RecordType};
+use ferrous_dns_infrastructure::dns::DnsRewriteTable;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+fn rule(domain: &str, rewrite: &str, group_id: i64) -> DnsRewriteRule {
+    DnsRewriteRule::new(
+        None,
+        Arc::from(domain),
+        rewrite.parse().unwrap(),
+        group_id,
+        true,
+        None,
+    )
+}
+
+fn ip(s: &str) -> IpAddr {
+    s.parse().unwrap()
+}
+
+#[test]
+fn empty_table_matches_nothing() {
+    let table = DnsRewriteTable::new();
+    assert!(table.is_empty());
+    assert!(table.rewrite_for("example.org", RecordType::A, 1).is_none());
+    assert!(!table.has_rewrite("example.org", 1));
+}
+
+#[test]
+fn matches_exact_name_and_subdomains() {
+    let table = DnsRewriteTable::new();
+    table.replace_rules(&[rule("example.org", "10.0.0.5", 1)]);
+
+    assert!(table.has_rewrite("example.org", 1));
+    assert!(table.has_rewrite("WWW.Example.org.", 1));
+    assert!(!table.has_rewrite("notexample.org", 1));
+    assert!(!table.has_rewrite("org", 1));
+}
+
+#[test]
+fn rules_are_scoped_to_their_group() {
+    let table = DnsRewriteTable::new();
+    table.replace_rules(&[rule("example.org", "10.0.0.5", 2)]);
+
+    assert!(table.has_rewrite("example.org", 2));
+    assert!(!table.has_rewrite("example.org", 1));
+}
+
+#[test]
+fn addresses_follow_query_type() {
+    let table = DnsRewriteTable::new();
+    table.replace_rules(&[
+        rule("example.org", "NOERROR;A;10.0.0.5", 1),
+        rule("example.org", "NOERROR;A;10.0.0.6", 1),
+        rule("example.org", "NOERROR;AAAA;fd00::5", 1),
+    ]);
+
+    assert_eq!(
+        table.rewrite_for("example.org", RecordType::A, 1),
+        Some(DnsRewrite::Addresses(vec![ip("10.0.0.5"), ip("10.0.0.6")]))
+    );
+    assert_eq!(
+        table.rewrite_for("example.org", RecordType::AAAA, 1),
+        Some(DnsRewrite::Addresses(vec![ip("fd00::5")]))
+    );
+    assert_eq!(
+        table.rewrite_for("example.org", RecordType::MX, 1),
+        Some(DnsRewrite::Addresses(Vec::new()))
+    );
+}
+
+#[test]
+fn rcode_beats_cname_beats_addresses() {
+    let table = DnsRewriteTable::new();
+    table.replace_rules(&[
+        rule("a.example", "10.0.0.5", 1),
+        rule("a.example", "lan.example.net", 1),
+        rule("b.example", "lan.example.net", 1),
+        rule("b.example", "REFUSED", 1),
+    ]);
+
+    assert_eq!(
+        table.rewrite_for("a.example", RecordType::A, 1),
+        Some(DnsRewrite::Cname(Arc::from("lan.example.net")))
+    );
+    assert_eq!(
+        table.rewrite_for("b.example", RecordType::A, 1),
+        Some(DnsRewrite::Rcode(DnsRewriteRcode::Refused))
+    );
+}
+
+#[test]
+fn longest_suffix_wins() {
+    let table = DnsRewriteTable::new();
+    table.replace_rules(&[
+        rule("example.org", "10.0.0.1", 1),
+        rule("nas.example.org", "10.0.0.2", 1),
+    ]);
+
+    assert_eq!(
+        table.rewrite_for("disk.nas.example.org", RecordType::A, 1),
+        Some(DnsRewrite::Addresses(vec![ip("10.0.0.2")]))
+    );
+    assert_eq!(
+        table.rewrite_for("www.example.org", RecordType::A, 1),
+        Some(DnsRewrite::Addresses(vec![ip("10.0.0.1")]))
+    );
+}
+
+#[test]
+fn disabled_rules_are_ignored_and_replace_is_atomic() {
+    let table = DnsRewriteTable::new();
+    table.replace_rules(&[rule("example.org", "10.0.0.5", 1)]);
+    assert_eq!(table.len(), 1);
+
+    let mut disabled = rule("example.org", "10.0.0.5", 1);
+    disabled.enabled = false;
+    table.replace_rules(&[disabled]);
+
+    assert!(table.is_empty());
+    assert!(!table.has_rewrite("example.org", 1));
+}
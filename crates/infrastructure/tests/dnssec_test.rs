@@ -516,3 +516,705 @@ fn test_validation_result_as_str_bogus() {
 fn test_validation_result_as_str_indeterminate() {
     assert_eq!(ValidationResult::Indeterminate.as_str(), "Indeterminate");
 }
+
+// ============================================================================
+// Authenticated denial of existence (NSEC / NSEC3)
+// ============================================================================
+
+mod denial {
+    use super::*;
+    use ferrous_dns_domain::RecordType;
+    use ferrous_dns_infrastructure::dns::dnssec::validation::denial::verify_wildcard_expansion;
+    use ferrous_dns_infrastructure::dns::dnssec::{DnssecValidator, MAX_NSEC3_ITERATIONS};
+    use hickory_proto::dnssec::crypto::Ed25519SigningKey;
+    use hickory_proto::dnssec::rdata::{DNSSECRData, DNSKEY, NSEC, NSEC3, RRSIG};
+    use hickory_proto::dnssec::{
+        Algorithm, Nsec3HashAlgorithm, PublicKey, PublicKeyBuf, SigSigner, SigningKey,
+    };
+    use hickory_proto::op::{Message, MessageType, OpCode, Query, ResponseCode};
+    use hickory_proto::rr::rdata::{A, SOA};
+    use hickory_proto::rr::{DNSClass, Name, RData, Record, RecordSet, RecordType as HRT};
+    use std::net::Ipv4Addr;
+    use std::str::FromStr;
+    use time::{Duration as TD, OffsetDateTime};
+
+    const SALT: &[u8] = &[0xAB, 0xCD];
+
+    /// example.com. signed with a fresh Ed25519 key that the validator
+    /// already trusts.
+    struct SignedZone {
+        signer: SigSigner,
+        validator: DnssecValidator,
+    }
+
+    impl SignedZone {
+        fn new() -> Self {
+            let pkcs8 = Ed25519SigningKey::generate_pkcs8().unwrap();
+            let key = Ed25519SigningKey::from_pkcs8(&pkcs8).unwrap();
+            let public_key = key.to_public_key().unwrap().public_bytes().to_vec();
+            let dnskey = DNSKEY::with_flags(
+                256,
+                PublicKeyBuf::new(public_key.clone(), Algorithm::ED25519),
+            );
+            let signer = SigSigner::dnssec(
+                dnskey,
+                Box::new(key),
+                name("example.com."),
+                Duration::from_secs(7200),
+            );
+
+            let pool = UpstreamPool {
+                name: "test".into(),
+                strategy: UpstreamStrategy::Parallel,
+                priority: 1,
+                servers: vec!["udp://127.0.0.1:5353".into()],
+                weight: None,
+            };
+            let rt = tokio::runtime::Runtime::new().unwrap();
+            let pm = Arc::new(
+                rt.block_on(PoolManager::new(
+                    vec![pool],
+                    None,
+                    QueryEventEmitter::new_disabled(),
+                ))
+                .unwrap(),
+            );
+            let mut validator = DnssecValidator::with_trust_store(pm, TrustAnchorStore::empty());
+            validator.insert_zone_keys_for_test(
+                "example.com.",
+                vec![DnskeyRecord {
+                    flags: 256,
+                    protocol: 3,
+                    algorithm: 15,
+                    public_key,
+                }],
+            );
+
+            Self { signer, validator }
+        }
+
+        /// One RRset plus its RRSIG.
+        fn signed(&self, owner: &str, rdata: RData) -> Vec<Record> {
+            let owner = name(owner);
+            let record = Record::from_rdata(owner.clone(), 300, rdata);
+            let mut rrset = RecordSet::new(owner.clone(), record.record_type(), 0);
+            rrset.insert(record.clone(), 0);
+            let inception = OffsetDateTime::now_utc() - TD::minutes(5);
+            let rrsig = RRSIG::from_rrset(&rrset, DNSClass::IN, inception, &self.signer).unwrap();
+            vec![
+                record,
+                Record::from_rdata(owner, 300, RData::DNSSEC(DNSSECRData::RRSIG(rrsig))),
+            ]
+        }
+
+        fn soa(&self) -> Vec<Record> {
+            self.signed(
+                "example.com.",
+                RData::SOA(SOA::new(
+                    name("ns1.example.com."),
+                    name("hostmaster.example.com."),
+                    1,
+                    3600,
+                    600,
+                    86400,
+                    300,
+                )),
+            )
+        }
+
+        fn nsec(&self, owner: &str, next: &str, types: &[HRT]) -> Vec<Record> {
+            let nsec = NSEC::new(name(next), types.iter().copied());
+            self.signed(owner, RData::DNSSEC(DNSSECRData::NSEC(nsec)))
+        }
+
+        fn nsec3(
+            &self,
+            owner_hash: &[u8],
+            next_hash: &[u8],
+            iterations: u16,
+            opt_out: bool,
+            types: &[HRT],
+        ) -> Vec<Record> {
+            let nsec3 = NSEC3::new(
+                Nsec3HashAlgorithm::SHA1,
+                opt_out,
+                iterations,
+                SALT.to_vec(),
+                next_hash.to_vec(),
+                types.iter().copied(),
+            );
+            let owner = format!("{}.example.com.", base32hex(owner_hash));
+            self.signed(&owner, RData::DNSSEC(DNSSECRData::NSEC3(nsec3)))
+        }
+
+        /// An NSEC3 whose owner is the hash of `name`.
+        fn nsec3_matching(&self, name: &str, types: &[HRT]) -> Vec<Record> {
+            let hash = nsec3_hash(name, 1);
+            self.nsec3(&hash, &adjacent(&hash, 1), 1, false, types)
+        }
+
+        /// An NSEC3 whose span covers the hash of `name`.
+        fn nsec3_covering(&self, name: &str, iterations: u16, opt_out: bool) -> Vec<Record> {
+            let hash = nsec3_hash(name, iterations);
+            self.nsec3(
+                &adjacent(&hash, -1),
+                &adjacent(&hash, 1),
+                iterations,
+                opt_out,
+                &[HRT::A, HRT::RRSIG],
+            )
+        }
+
+        fn verify(
+            &self,
+            qname: &str,
+            qtype: RecordType,
+            rcode: ResponseCode,
+            authority: Vec<Record>,
+        ) -> ValidationResult {
+            let message = negative_response(qname, qtype, rcode, authority);
+            self.validator.verify_denial(qname, qtype, &message)
+        }
+    }
+
+    fn name(s: &str) -> Name {
+        Name::from_str(s).unwrap()
+    }
+
+    fn nsec3_hash(s: &str, iterations: u16) -> Vec<u8> {
+        Nsec3HashAlgorithm::SHA1
+            .hash(SALT, &name(s), iterations)
+            .unwrap()
+            .as_ref()
+            .to_vec()
+    }
+
+    /// The hash one step above or below `hash`, for tight NSEC3 spans.
+    fn adjacent(hash: &[u8], step: i16) -> Vec<u8> {
+        let mut out = hash.to_vec();
+        let last = out.len() - 1;
+        out[last] = (i16::from(out[last]) + step).clamp(0, 255) as u8;
+        out
+    }
+
+    fn base32hex(bytes: &[u8]) -> String {
+        const ALPHABET: &[u8] = b"0123456789abcdefghijklmnopqrstuv";
+        let mut out = String::new();
+        let mut buffer: u32 = 0;
+        let mut bits = 0;
+        for &b in bytes {
+            buffer = (buffer << 8) | u32::from(b);
+            bits += 8;
+            while bits >= 5 {
+                bits -= 5;
+                out.push(ALPHABET[((buffer >> bits) & 31) as usize] as char);
+            }
+        }
+        if bits > 0 {
+            out.push(ALPHABET[((buffer << (5 - bits)) & 31) as usize] as char);
+        }
+        out
+    }
+
+    fn negative_response(
+        qname: &str,
+        qtype: RecordType,
+        rcode: ResponseCode,
+        authority: Vec<Record>,
+    ) -> Message {
+        let mut message = Message::new(0, MessageType::Response, OpCode::Query);
+        message.set_response_code(rcode);
+        message.add_query(Query::query(
+            name(&format!("{}.", qname)),
+            HRT::from(qtype.to_u16()),
+        ));
+        message.add_name_servers(authority);
+        message
+    }
+
+    fn concat(parts: Vec<Vec<Record>>) -> Vec<Record> {
+        parts.into_iter().flatten().collect()
+    }
+
+    // --- NSEC ---------------------------------------------------------------
+
+    #[test]
+    fn test_nsec_nxdomain_with_wildcard_denial_is_secure() {
+        let zone = SignedZone::new();
+        let authority = concat(vec![
+            zone.soa(),
+            zone.nsec("mail.example.com.", "www.example.com.", &[HRT::A]),
+            zone.nsec(
+                "example.com.",
+                "mail.example.com.",
+                &[HRT::SOA, HRT::NS, HRT::NSEC, HRT::RRSIG],
+            ),
+        ]);
+        assert_eq!(
+            zone.verify(
+                "nope.example.com",
+                RecordType::A,
+                ResponseCode::NXDomain,
+                authority
+            ),
+            ValidationResult::Secure
+        );
+    }
+
+    #[test]
+    fn test_nsec_nxdomain_without_wildcard_denial_is_bogus() {
+        let zone = SignedZone::new();
+        let authority = concat(vec![
+            zone.soa(),
+            zone.nsec("mail.example.com.", "www.example.com.", &[HRT::A]),
+        ]);
+        assert_eq!(
+            zone.verify(
+                "nope.example.com",
+                RecordType::A,
+                ResponseCode::NXDomain,
+                authority
+            ),
+            ValidationResult::Bogus
+        );
+    }
+
+    #[test]
+    fn test_nsec_nxdomain_with_matching_nsec_is_bogus() {
+        let zone = SignedZone::new();
+        let authority = concat(vec![
+            zone.soa(),
+            zone.nsec("www.example.com.", "zzz.example.com.", &[HRT::A]),
+        ]);
+        assert_eq!(
+            zone.verify(
+                "www.example.com",
+                RecordType::A,
+                ResponseCode::NXDomain,
+                authority
+            ),
+            ValidationResult::Bogus
+        );
+    }
+
+    #[test]
+    fn test_nsec_nodata_is_secure() {
+        let zone = SignedZone::new();
+        let authority = concat(vec![
+            zone.soa(),
+            zone.nsec(
+                "www.example.com.",
+                "zzz.example.com.",
+                &[HRT::A, HRT::NSEC, HRT::RRSIG],
+            ),
+        ]);
+        assert_eq!(
+            zone.verify(
+                "www.example.com",
+                RecordType::AAAA,
+                ResponseCode::NoError,
+                authority
+            ),
+            ValidationResult::Secure
+        );
+    }
+
+    #[test]
+    fn test_nsec_nodata_with_type_in_bitmap_is_bogus() {
+        let zone = SignedZone::new();
+        let authority = concat(vec![
+            zone.soa(),
+            zone.nsec("www.example.com.", "zzz.example.com.", &[HRT::A, HRT::AAAA]),
+        ]);
+        assert_eq!(
+            zone.verify(
+                "www.example.com",
+                RecordType::AAAA,
+                ResponseCode::NoError,
+                authority
+            ),
+            ValidationResult::Bogus
+        );
+    }
+
+    #[test]
+    fn test_nsec_nodata_from_parent_side_of_delegation_is_bogus() {
+        let zone = SignedZone::new();
+        let authority = concat(vec![
+            zone.soa(),
+            zone.nsec("sub.example.com.", "www.example.com.", &[HRT::NS, HRT::DS]),
+        ]);
+        assert_eq!(
+            zone.verify(
+                "sub.example.com",
+                RecordType::A,
+                ResponseCode::NoError,
+                authority
+            ),
+            ValidationResult::Bogus
+        );
+    }
+
+    #[test]
+    fn test_nsec_nodata_for_empty_non_terminal_is_secure() {
+        let zone = SignedZone::new();
+        let authority = concat(vec![
+            zone.soa(),
+            zone.nsec("example.com.", "a.ent.example.com.", &[HRT::SOA, HRT::NS]),
+        ]);
+        assert_eq!(
+            zone.verify(
+                "ent.example.com",
+                RecordType::A,
+                ResponseCode::NoError,
+                authority
+            ),
+            ValidationResult::Secure
+        );
+    }
+
+    #[test]
+    fn test_nsec_wrap_around_covers_names_after_last_owner() {
+        let zone = SignedZone::new();
+        let authority = concat(vec![
+            zone.soa(),
+            zone.nsec(
+                "www.example.com.",
+                "example.com.",
+                &[HRT::A, HRT::NSEC, HRT::RRSIG],
+            ),
+        ]);
+        // `zzz` sorts after `www`, and `*.example.com.` before the first
+        // child, so the wrap-around span and the apex NSEC are both needed.
+        let authority = concat(vec![
+            authority,
+            zone.nsec("example.com.", "mail.example.com.", &[HRT::SOA, HRT::NS]),
+        ]);
+        assert_eq!(
+            zone.verify(
+                "zzz.example.com",
+                RecordType::A,
+                ResponseCode::NXDomain,
+                authority
+            ),
+            ValidationResult::Secure
+        );
+    }
+
+    #[test]
+    fn test_nsec_unsigned_proof_is_bogus() {
+        let zone = SignedZone::new();
+        let mut authority = concat(vec![
+            zone.soa(),
+            zone.nsec("mail.example.com.", "www.example.com.", &[HRT::A]),
+            zone.nsec("example.com.", "mail.example.com.", &[HRT::SOA, HRT::NS]),
+        ]);
+        authority
+            .retain(|r| !(r.record_type() == HRT::RRSIG && r.name() == &name("mail.example.com.")));
+        assert_eq!(
+            zone.verify(
+                "nope.example.com",
+                RecordType::A,
+                ResponseCode::NXDomain,
+                authority
+            ),
+            ValidationResult::Bogus
+        );
+    }
+
+    #[test]
+    fn test_nsec_tampered_proof_is_bogus() {
+        let zone = SignedZone::new();
+        let genuine = zone.nsec("mail.example.com.", "nnn.example.com.", &[HRT::A]);
+        // Widen the signed span so it also "covers" the query name.
+        let forged: Vec<Record> = genuine
+            .into_iter()
+            .map(|mut r| {
+                if r.record_type() == HRT::NSEC {
+                    r.set_data(RData::DNSSEC(DNSSECRData::NSEC(NSEC::new(
+                        name("www.example.com."),
+                        [HRT::A],
+                    ))));
+                }
+                r
+            })
+            .collect();
+        let authority = concat(vec![
+            zone.soa(),
+            forged,
+            zone.nsec("example.com.", "mail.example.com.", &[HRT::SOA, HRT::NS]),
+        ]);
+        assert_eq!(
+            zone.verify(
+                "nope.example.com",
+                RecordType::A,
+                ResponseCode::NXDomain,
+                authority
+            ),
+            ValidationResult::Bogus
+        );
+    }
+
+    #[test]
+    fn test_negative_answer_without_denial_records_is_bogus() {
+        let zone = SignedZone::new();
+        assert_eq!(
+            zone.verify(
+                "nope.example.com",
+                RecordType::A,
+                ResponseCode::NXDomain,
+                zone.soa()
+            ),
+            ValidationResult::Bogus
+        );
+    }
+
+    // --- NSEC3 --------------------------------------------------------------
+
+    #[test]
+    fn test_nsec3_nxdomain_closest_encloser_proof_is_secure() {
+        let zone = SignedZone::new();
+        let authority = concat(vec![
+            zone.soa(),
+            zone.nsec3_matching("example.com.", &[HRT::SOA, HRT::NS]),
+            zone.nsec3_covering("nope.example.com.", 1, false),
+            zone.nsec3_covering("*.example.com.", 1, false),
+        ]);
+        assert_eq!(
+            zone.verify(
+                "nope.example.com",
+                RecordType::A,
+                ResponseCode::NXDomain,
+                authority
+            ),
+            ValidationResult::Secure
+        );
+    }
+
+    #[test]
+    fn test_nsec3_nxdomain_deep_name_uses_closest_encloser() {
+        let zone = SignedZone::new();
+        let authority = concat(vec![
+            zone.soa(),
+            zone.nsec3_matching("www.example.com.", &[HRT::A]),
+            zone.nsec3_covering("a.www.example.com.", 1, false),
+            zone.nsec3_covering("*.www.example.com.", 1, false),
+        ]);
+        assert_eq!(
+            zone.verify(
+                "b.a.www.example.com",
+                RecordType::A,
+                ResponseCode::NXDomain,
+                authority
+            ),
+            ValidationResult::Secure
+        );
+    }
+
+    #[test]
+    fn test_nsec3_nxdomain_without_wildcard_denial_is_bogus() {
+        let zone = SignedZone::new();
+        let authority = concat(vec![
+            zone.soa(),
+            zone.nsec3_matching("example.com.", &[HRT::SOA, HRT::NS]),
+            zone.nsec3_covering("nope.example.com.", 1, false),
+        ]);
+        assert_eq!(
+            zone.verify(
+                "nope.example.com",
+                RecordType::A,
+                ResponseCode::NXDomain,
+                authority
+            ),
+            ValidationResult::Bogus
+        );
+    }
+
+    #[test]
+    fn test_nsec3_nxdomain_without_closest_encloser_is_bogus() {
+        let zone = SignedZone::new();
+        let authority = concat(vec![
+            zone.soa(),
+            zone.nsec3_covering("nope.example.com.", 1, false),
+            zone.nsec3_covering("*.example.com.", 1, false),
+        ]);
+        assert_eq!(
+            zone.verify(
+                "nope.example.com",
+                RecordType::A,
+                ResponseCode::NXDomain,
+                authority
+            ),
+            ValidationResult::Bogus
+        );
+    }
+
+    #[test]
+    fn test_nsec3_nodata_is_secure() {
+        let zone = SignedZone::new();
+        let authority = concat(vec![
+            zone.soa(),
+            zone.nsec3_matching("www.example.com.", &[HRT::A, HRT::RRSIG]),
+        ]);
+        assert_eq!(
+            zone.verify(
+                "www.example.com",
+                RecordType::AAAA,
+                ResponseCode::NoError,
+                authority
+            ),
+            ValidationResult::Secure
+        );
+    }
+
+    #[test]
+    fn test_nsec3_nodata_with_cname_in_bitmap_is_bogus() {
+        let zone = SignedZone::new();
+        let authority = concat(vec![
+            zone.soa(),
+            zone.nsec3_matching("www.example.com.", &[HRT::CNAME, HRT::RRSIG]),
+        ]);
+        assert_eq!(
+            zone.verify(
+                "www.example.com",
+                RecordType::AAAA,
+                ResponseCode::NoError,
+                authority
+            ),
+            ValidationResult::Bogus
+        );
+    }
+
+    #[test]
+    fn test_nsec3_opt_out_nxdomain_is_insecure() {
+        let zone = SignedZone::new();
+        let authority = concat(vec![
+            zone.soa(),
+            zone.nsec3_matching("example.com.", &[HRT::SOA, HRT::NS]),
+            zone.nsec3_covering("nope.example.com.", 1, true),
+            zone.nsec3_covering("*.example.com.", 1, false),
+        ]);
+        assert_eq!(
+            zone.verify(
+                "nope.example.com",
+                RecordType::A,
+                ResponseCode::NXDomain,
+                authority
+            ),
+            ValidationResult::Insecure
+        );
+    }
+
+    #[test]
+    fn test_nsec3_opt_out_ds_nodata_is_insecure() {
+        let zone = SignedZone::new();
+        let authority = concat(vec![
+            zone.soa(),
+            zone.nsec3_matching("example.com.", &[HRT::SOA, HRT::NS]),
+            zone.nsec3_covering("unsigned.example.com.", 1, true),
+        ]);
+        assert_eq!(
+            zone.verify(
+                "unsigned.example.com",
+                RecordType::DS,
+                ResponseCode::NoError,
+                authority
+            ),
+            ValidationResult::Insecure
+        );
+    }
+
+    #[test]
+    fn test_nsec3_iterations_above_limit_are_insecure() {
+        let zone = SignedZone::new();
+        let iterations = MAX_NSEC3_ITERATIONS + 1;
+        let authority = concat(vec![
+            zone.soa(),
+            zone.nsec3_covering("nope.example.com.", iterations, false),
+        ]);
+        assert_eq!(
+            zone.verify(
+                "nope.example.com",
+                RecordType::A,
+                ResponseCode::NXDomain,
+                authority
+            ),
+            ValidationResult::Insecure
+        );
+    }
+
+    #[test]
+    fn test_nsec3_unsigned_proof_is_bogus() {
+        let zone = SignedZone::new();
+        let mut authority = concat(vec![
+            zone.soa(),
+            zone.nsec3_matching("example.com.", &[HRT::SOA, HRT::NS]),
+            zone.nsec3_covering("nope.example.com.", 1, false),
+            zone.nsec3_covering("*.example.com.", 1, false),
+        ]);
+        authority.retain(|r| {
+            !(r.record_type() == HRT::RRSIG
+                && matches!(r.data(), RData::DNSSEC(DNSSECRData::RRSIG(sig))
+                    if sig.input().type_covered == HRT::NSEC3))
+        });
+        assert_eq!(
+            zone.verify(
+                "nope.example.com",
+                RecordType::A,
+                ResponseCode::NXDomain,
+                authority
+            ),
+            ValidationResult::Bogus
+        );
+    }
+
+    // --- Wildcard expansion -------------------------------------------------
+
+    #[test]
+    fn test_wildcard_expansion_with_nsec_proof_is_secure() {
+        let zone = SignedZone::new();
+        let authority = zone.nsec("ftp.example.com.", "mail.example.com.", &[HRT::A]);
+        assert_eq!(
+            verify_wildcard_expansion(
+                &name("host.example.com."),
+                &name("example.com."),
+                &name("example.com."),
+                &authority,
+            ),
+            ValidationResult::Secure
+        );
+    }
+
+    #[test]
+    fn test_wildcard_expansion_with_nsec3_proof_is_secure() {
+        let zone = SignedZone::new();
+        let authority = zone.nsec3_covering("host.example.com.", 1, false);
+        assert_eq!(
+            verify_wildcard_expansion(
+                &name("a.host.example.com."),
+                &name("example.com."),
+                &name("example.com."),
+                &authority,
+            ),
+            ValidationResult::Secure
+        );
+    }
+
+    #[test]
+    fn test_wildcard_expansion_without_proof_is_bogus() {
+        let answer = Record::from_rdata(
+            name("host.example.com."),
+            300,
+            RData::A(A(Ipv4Addr::new(192, 0, 2, 1))),
+        );
+        assert_eq!(
+            verify_wildcard_expansion(
+                &name("host.example.com."),
+                &name("example.com."),
+                &name("example.com."),
+                &[answer],
+            ),
+            ValidationResult::Bogus
+        );
+    }
+}
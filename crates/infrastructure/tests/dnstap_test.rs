@@ -0,0 +1,343 @@
+use ferrous_dns_application::ports::DnstapSink;
+use ferrous_dns_domain::{DnsProtocol, DnstapConfig, UpstreamAddr};
+use ferrous_dns_infrastructure::dns::dnstap::{DnstapWriter, SocketProtocol};
+use hickory_proto::op::{Message, MessageType, OpCode, Query, ResponseCode};
+use hickory_proto::rr::rdata::A;
+use hickory_proto::rr::{Name, RData, Record, RecordType};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::time::{Duration, SystemTime};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+const CONTENT_TYPE: &[u8] = b"protobuf:dnstap.Dnstap";
+const CONTROL_ACCEPT: u32 = 1;
+const CONTROL_START: u32 = 2;
+const CONTROL_STOP: u32 = 3;
+const CONTROL_READY: u32 = 4;
+const CONTROL_FINISH: u32 = 5;
+
+fn config(output: String) -> DnstapConfig {
+    DnstapConfig {
+        enabled: true,
+        output,
+        identity: Some("resolver-1".to_string()),
+        ..DnstapConfig::default()
+    }
+}
+
+const CLIENT_IP: IpAddr = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 10));
+
+/// Builds a query for `domain`, or with `rcode` a response carrying one A
+/// record, the way a client and the server would put them on the wire.
+fn wire_message(id: u16, domain: &str, rcode: Option<ResponseCode>) -> Vec<u8> {
+    let name = Name::from_ascii(domain).unwrap();
+    let message_type = match rcode {
+        Some(_) => MessageType::Response,
+        None => MessageType::Query,
+    };
+    let mut message = Message::new(id, message_type, OpCode::Query);
+    message.set_recursion_desired(true);
+    message.add_query(Query::query(name.clone(), RecordType::A));
+    if let Some(rcode) = rcode {
+        message.set_response_code(rcode);
+        message.add_answer(Record::from_rdata(
+            name,
+            300,
+            RData::A(A(Ipv4Addr::new(192, 0, 2, 1))),
+        ));
+    }
+    message.to_vec().unwrap()
+}
+
+// ── protobuf / frame helpers ──────────────────────────────────────────────────
+
+fn read_varint(buf: &[u8], pos: &mut usize) -> u64 {
+    let mut value = 0u64;
+    let mut shift = 0;
+    loop {
+        let byte = buf[*pos];
+        *pos += 1;
+        value |= ((byte & 0x7F) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return value;
+        }
+        shift += 7;
+    }
+}
+
+/// Decodes one protobuf message into `(field, varint or bytes)` pairs.
+fn decode_fields(buf: &[u8]) -> Vec<(u32, Result<u64, Vec<u8>>)> {
+    let mut fields = Vec::new();
+    let mut pos = 0;
+    while pos < buf.len() {
+        let key = read_varint(buf, &mut pos);
+        let field = (key >> 3) as u32;
+        match key & 0x7 {
+            0 => fields.push((field, Ok(read_varint(buf, &mut pos)))),
+            2 => {
+                let len = read_varint(buf, &mut pos) as usize;
+                fields.push((field, Err(buf[pos..pos + len].to_vec())));
+                pos += len;
+            }
+            5 => {
+                let bytes = [buf[pos], buf[pos + 1], buf[pos + 2], buf[pos + 3]];
+                fields.push((field, Ok(u32::from_le_bytes(bytes) as u64)));
+                pos += 4;
+            }
+            wire => panic!("unexpected wire type {wire}"),
+        }
+    }
+    fields
+}
+
+fn bytes_field(fields: &[(u32, Result<u64, Vec<u8>>)], field: u32) -> Option<Vec<u8>> {
+    fields
+        .iter()
+        .find(|(f, _)| *f == field)
+        .and_then(|(_, v)| v.clone().err())
+}
+
+fn varint_field(fields: &[(u32, Result<u64, Vec<u8>>)], field: u32) -> Option<u64> {
+    fields
+        .iter()
+        .find(|(f, _)| *f == field)
+        .and_then(|(_, v)| v.clone().ok())
+}
+
+/// Splits a Frame Streams byte stream into control types and data payloads.
+fn split_frames(stream: &[u8]) -> (Vec<u32>, Vec<Vec<u8>>) {
+    let mut controls = Vec::new();
+    let mut data = Vec::new();
+    let mut pos = 0;
+    while pos + 4 <= stream.len() {
+        let len = u32::from_be_bytes(stream[pos..pos + 4].try_into().unwrap()) as usize;
+        pos += 4;
+        if len == 0 {
+            let control_len = u32::from_be_bytes(stream[pos..pos + 4].try_into().unwrap()) as usize;
+            pos += 4;
+            controls.push(u32::from_be_bytes(stream[pos..pos + 4].try_into().unwrap()));
+            pos += control_len;
+        } else {
+            data.push(stream[pos..pos + len].to_vec());
+            pos += len;
+        }
+    }
+    (controls, data)
+}
+
+fn message_of(frame: &[u8]) -> Vec<(u32, Result<u64, Vec<u8>>)> {
+    let envelope = decode_fields(frame);
+    assert_eq!(varint_field(&envelope, 15), Some(1), "Dnstap.type");
+    decode_fields(&bytes_field(&envelope, 14).expect("Dnstap.message"))
+}
+
+async fn read_control(stream: &mut tokio::net::TcpStream) -> (u32, Vec<u8>) {
+    assert_eq!(stream.read_u32().await.unwrap(), 0);
+    let len = stream.read_u32().await.unwrap() as usize;
+    let mut body = vec![0u8; len];
+    stream.read_exact(&mut body).await.unwrap();
+    (u32::from_be_bytes(body[..4].try_into().unwrap()), body)
+}
+
+async fn write_control(stream: &mut tokio::net::TcpStream, control_type: u32) {
+    let mut frame = vec![0, 0, 0, 0];
+    frame.extend_from_slice(&4u32.to_be_bytes());
+    frame.extend_from_slice(&control_type.to_be_bytes());
+    stream.write_all(&frame).await.unwrap();
+}
+
+// ── file output ───────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_file_output_writes_client_pair_between_start_and_stop() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("dnstap.fstrm");
+    let writer = DnstapWriter::spawn(&config(format!("file:{}", path.display()))).unwrap();
+
+    let query = wire_message(0x1234, "example.com.", None);
+    let response = wire_message(0x1234, "example.com.", Some(ResponseCode::NoError));
+    let query_time = writer.client_query_time().unwrap();
+    writer.client_exchange(
+        SocketProtocol::Udp,
+        CLIENT_IP,
+        Some(53_000),
+        &query,
+        &response,
+        query_time,
+    );
+    drop(writer);
+
+    let mut contents = Vec::new();
+    for _ in 0..100 {
+        contents = tokio::fs::read(&path).await.unwrap_or_default();
+        if split_frames(&contents).0.contains(&CONTROL_STOP) {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+
+    let (controls, data) = split_frames(&contents);
+    assert_eq!(controls, vec![CONTROL_START, CONTROL_STOP]);
+    assert!(contents
+        .windows(CONTENT_TYPE.len())
+        .any(|window| window == CONTENT_TYPE));
+    assert_eq!(data.len(), 2);
+
+    let envelope = decode_fields(&data[0]);
+    assert_eq!(bytes_field(&envelope, 1), Some(b"resolver-1".to_vec()));
+
+    let client_query = message_of(&data[0]);
+    assert_eq!(varint_field(&client_query, 1), Some(5), "CLIENT_QUERY");
+    assert_eq!(varint_field(&client_query, 3), Some(1), "UDP");
+    assert_eq!(bytes_field(&client_query, 4), Some(vec![192, 168, 1, 10]));
+    assert_eq!(varint_field(&client_query, 6), Some(53_000));
+    assert!(varint_field(&client_query, 8).is_some(), "query_time_sec");
+    assert_eq!(bytes_field(&client_query, 10), Some(query));
+
+    let client_response = message_of(&data[1]);
+    assert_eq!(
+        varint_field(&client_response, 1),
+        Some(6),
+        "CLIENT_RESPONSE"
+    );
+    assert!(
+        varint_field(&client_response, 12).is_some(),
+        "response_time_sec"
+    );
+    assert_eq!(bytes_field(&client_response, 14), Some(response.clone()));
+    let wire = Message::from_vec(&response).unwrap();
+    assert_eq!(wire.id(), 0x1234);
+    assert_eq!(wire.answers().len(), 1);
+}
+
+#[tokio::test]
+async fn test_reopening_a_file_output_rotates_the_previous_stream() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("dnstap.fstrm");
+    std::fs::write(&path, b"previous run").unwrap();
+
+    let writer = DnstapWriter::spawn(&config(format!("file:{}", path.display()))).unwrap();
+    let query = wire_message(0x4321, "example.org.", None);
+    let response = wire_message(0x4321, "example.org.", Some(ResponseCode::NoError));
+    let query_time = writer.client_query_time().unwrap();
+    writer.client_exchange(
+        SocketProtocol::Tcp,
+        CLIENT_IP,
+        None,
+        &query,
+        &response,
+        query_time,
+    );
+    drop(writer);
+
+    let mut contents = Vec::new();
+    for _ in 0..100 {
+        contents = tokio::fs::read(&path).await.unwrap_or_default();
+        if contents.starts_with(&[0; 4]) && split_frames(&contents).0.contains(&CONTROL_STOP) {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    let (controls, data) = split_frames(&contents);
+    assert_eq!(controls, vec![CONTROL_START, CONTROL_STOP]);
+    assert_eq!(data.len(), 2);
+
+    let rotated: Vec<Vec<u8>> = std::fs::read_dir(dir.path())
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|p| p != &path)
+        .map(|p| std::fs::read(p).unwrap())
+        .collect();
+    assert_eq!(rotated, vec![b"previous run".to_vec()]);
+}
+
+#[tokio::test]
+async fn test_client_messages_can_be_disabled() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("dnstap.fstrm");
+    let writer = DnstapWriter::spawn(&DnstapConfig {
+        client_messages: false,
+        ..config(format!("file:{}", path.display()))
+    })
+    .unwrap();
+
+    assert!(writer.client_query_time().is_none());
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    assert_eq!(writer.written_messages(), 0);
+    assert_eq!(writer.dropped_messages(), 0);
+}
+
+// ── socket output ─────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_tcp_output_handshakes_and_streams_forwarder_messages() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let writer = DnstapWriter::spawn(&config(format!("tcp:{addr}"))).unwrap();
+
+    let (mut stream, _) = listener.accept().await.unwrap();
+    let (ready, body) = read_control(&mut stream).await;
+    assert_eq!(ready, CONTROL_READY);
+    assert!(body.ends_with(CONTENT_TYPE));
+    write_control(&mut stream, CONTROL_ACCEPT).await;
+    assert_eq!(read_control(&mut stream).await.0, CONTROL_START);
+
+    let upstream: SocketAddr = "192.0.2.53:53".parse().unwrap();
+    let protocol = DnsProtocol::Udp {
+        addr: UpstreamAddr::Resolved(upstream),
+    };
+    let query = wire_message(0x4242, "example.org.", None);
+    let response = wire_message(0x4242, "example.org.", Some(ResponseCode::NoError));
+    let query_time = writer.forwarder_query(&protocol, &query).unwrap();
+    writer.forwarder_response(&protocol, &query, &response, query_time);
+
+    let mut frames = Vec::new();
+    for _ in 0..2 {
+        let len = stream.read_u32().await.unwrap() as usize;
+        let mut payload = vec![0u8; len];
+        stream.read_exact(&mut payload).await.unwrap();
+        frames.push(message_of(&payload));
+    }
+
+    assert_eq!(varint_field(&frames[0], 1), Some(7), "FORWARDER_QUERY");
+    assert_eq!(varint_field(&frames[0], 3), Some(1), "UDP");
+    assert_eq!(bytes_field(&frames[0], 5), Some(vec![192, 0, 2, 53]));
+    assert_eq!(varint_field(&frames[0], 7), Some(53));
+    assert_eq!(bytes_field(&frames[0], 10), Some(query.clone()));
+    assert_eq!(varint_field(&frames[1], 1), Some(8), "FORWARDER_RESPONSE");
+    assert_eq!(bytes_field(&frames[1], 14), Some(response));
+
+    drop(writer);
+    assert_eq!(read_control(&mut stream).await.0, CONTROL_STOP);
+    write_control(&mut stream, CONTROL_FINISH).await;
+}
+
+#[tokio::test]
+async fn test_full_queue_drops_and_counts_messages() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    // Accept the connection but never complete the handshake, so nothing drains.
+    let writer = DnstapWriter::spawn(&DnstapConfig {
+        queue_capacity: 4,
+        ..config(format!("tcp:{addr}"))
+    })
+    .unwrap();
+    let _stalled = listener.accept().await.unwrap();
+
+    let query = wire_message(1, "example.com.", None);
+    let response = wire_message(1, "example.com.", Some(ResponseCode::NoError));
+    for _ in 0..10 {
+        writer.client_exchange(
+            SocketProtocol::Udp,
+            CLIENT_IP,
+            None,
+            &query,
+            &response,
+            SystemTime::now(),
+        );
+    }
+
+    assert_eq!(writer.written_messages(), 0);
+    assert!(writer.dropped_messages() >= 12);
+}
@@ -0,0 +1,357 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{ConfigRepository, TsigKeyStore};
+use ferrous_dns_application::use_cases::{ApplyDynamicUpdateUseCase, CreateLocalRecordUseCase};
+use ferrous_dns_domain::{Config, DomainError, TsigKey};
+use ferrous_dns_infrastructure::dns::{DynamicUpdateHandler, TsigKeyTable};
+use hickory_proto::dnssec::rdata::tsig::TsigAlgorithm;
+use hickory_proto::dnssec::rdata::DNSSECRData;
+use hickory_proto::dnssec::tsig::TSigner;
+use hickory_proto::op::{Message, MessageSignature, MessageType, OpCode, Query, ResponseCode};
+use hickory_proto::rr::rdata::{A, NULL};
+use hickory_proto::rr::{DNSClass, Name, RData, Record, RecordType};
+use hickory_proto::serialize::binary::{BinEncodable, BinEncoder};
+use std::net::{IpAddr, Ipv4Addr};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::RwLock;
+
+const ZONE: &str = "lan.example";
+const SECRET: &[u8] = b"0123456789abcdef0123456789abcdef";
+const SECRET_B64: &str = "MDEyMzQ1Njc4OWFiY2RlZjAxMjM0NTY3ODlhYmNkZWY=";
+const CLIENT: IpAddr = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2));
+
+struct NoopConfigRepository;
+
+#[async_trait]
+impl ConfigRepository for NoopConfigRepository {
+    async fn save_local_records(&self, _config: &Config) -> Result<(), DomainError> {
+        Ok(())
+    }
+}
+
+fn key(name: &str, algorithm: &str, secret: &str) -> TsigKey {
+    TsigKey {
+        id: Some(1),
+        name: Arc::from(name),
+        algorithm: Arc::from(algorithm),
+        secret: Arc::from(secret),
+        created_at: None,
+    }
+}
+
+fn handler() -> (DynamicUpdateHandler, Arc<RwLock<Config>>) {
+    let config = Arc::new(RwLock::new(Config::default()));
+    let repo: Arc<dyn ConfigRepository> = Arc::new(NoopConfigRepository);
+    let create = Arc::new(CreateLocalRecordUseCase::new(config.clone(), repo));
+    let use_case = Arc::new(ApplyDynamicUpdateUseCase::new(
+        config.clone(),
+        create,
+        &[ZONE.to_string()],
+    ));
+    let keys = Arc::new(TsigKeyTable::new());
+    keys.replace_keys(&[key("kea", "hmac-sha256", SECRET_B64)]);
+    (DynamicUpdateHandler::new(keys, use_case), config)
+}
+
+fn signer(key_name: &str) -> TSigner {
+    TSigner::new(
+        SECRET.to_vec(),
+        TsigAlgorithm::HmacSha256,
+        Name::from_ascii(key_name).unwrap(),
+        300,
+    )
+    .unwrap()
+}
+
+fn add_update(zone: &str, host: &str, ip: Ipv4Addr) -> Message {
+    let mut msg = Message::new(0x4242, MessageType::Query, OpCode::Update);
+    msg.add_query(Query::query(
+        Name::from_ascii(zone).unwrap(),
+        RecordType::SOA,
+    ));
+    msg.add_name_server(Record::from_rdata(
+        Name::from_ascii(host).unwrap(),
+        600,
+        RData::A(A(ip)),
+    ));
+    msg
+}
+
+fn now() -> u32 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as u32
+}
+
+fn encode(msg: &Message) -> Vec<u8> {
+    let mut buf = Vec::new();
+    msg.emit(&mut BinEncoder::new(&mut buf)).unwrap();
+    buf
+}
+
+/// The TSIG error field, read from the wire form of the response's TSIG
+/// RDATA (algorithm, time, fudge, MAC, original ID, then the error).
+fn tsig_error(resp: &Message) -> Option<u16> {
+    let MessageSignature::Tsig(ref record) = *resp.signature() else {
+        return None;
+    };
+    let tsig = record.data().as_dnssec().and_then(DNSSECRData::as_tsig)?;
+    let mut rdata = Vec::new();
+    tsig.emit(&mut BinEncoder::new(&mut rdata)).ok()?;
+    let mut algorithm = Vec::new();
+    tsig.algorithm()
+        .to_name()
+        .emit(&mut BinEncoder::new(&mut algorithm))
+        .ok()?;
+    let offset = algorithm.len() + 6 + 2 + 2 + tsig.mac().len() + 2;
+    Some(u16::from_be_bytes([rdata[offset], rdata[offset + 1]]))
+}
+
+// ── TsigKeyTable ─────────────────────────────────────────────────────────────
+
+#[test]
+fn table_looks_up_keys_case_insensitively() {
+    let table = TsigKeyTable::new();
+    assert!(table.is_empty());
+
+    table.replace_keys(&[key("kea.example", "hmac-sha256", SECRET_B64)]);
+
+    assert_eq!(table.len(), 1);
+    assert!(table
+        .get(&Name::from_str("KEA.Example.").unwrap())
+        .is_some());
+    assert!(table.get(&Name::from_str("other.").unwrap()).is_none());
+}
+
+#[test]
+fn table_skips_keys_with_invalid_secret() {
+    let table = TsigKeyTable::new();
+
+    table.replace_keys(&[
+        key("kea", "hmac-sha256", SECRET_B64),
+        key("broken", "hmac-sha256", "not base64!"),
+    ]);
+
+    assert_eq!(table.len(), 1);
+    assert!(table.get(&Name::from_str("broken").unwrap()).is_none());
+}
+
+// ── DynamicUpdateHandler ─────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn signed_update_adds_record_and_signs_response() {
+    let (handler, config) = handler();
+    let mut msg = add_update(ZONE, "laptop.lan.example.", Ipv4Addr::new(10, 0, 0, 5));
+    let mut verifier = msg.finalize(&signer("kea"), now()).unwrap().unwrap();
+    let raw = encode(&msg);
+    let request = Message::from_vec(&raw).unwrap();
+
+    let wire = handler.handle(&raw, &request, CLIENT).await.unwrap();
+
+    let resp = Message::from_vec(&wire).unwrap();
+    assert_eq!(resp.id(), 0x4242);
+    assert_eq!(resp.op_code(), OpCode::Update);
+    assert_eq!(resp.response_code(), ResponseCode::NoError);
+    assert!(verifier(&wire).is_ok(), "response MAC must verify");
+
+    let config = config.read().await;
+    assert_eq!(config.dns.local_records.len(), 1);
+    assert_eq!(config.dns.local_records[0].hostname, "laptop");
+    assert_eq!(config.dns.local_records[0].ip, "10.0.0.5");
+}
+
+#[tokio::test]
+async fn unsigned_update_is_refused() {
+    let (handler, config) = handler();
+    let msg = add_update(ZONE, "laptop.lan.example.", Ipv4Addr::new(10, 0, 0, 5));
+    let raw = encode(&msg);
+
+    let wire = handler.handle(&raw, &msg, CLIENT).await.unwrap();
+
+    let resp = Message::from_vec(&wire).unwrap();
+    assert_eq!(resp.response_code(), ResponseCode::Refused);
+    assert!(config.read().await.dns.local_records.is_empty());
+}
+
+#[tokio::test]
+async fn unknown_key_returns_badkey() {
+    let (handler, config) = handler();
+    let mut msg = add_update(ZONE, "laptop.lan.example.", Ipv4Addr::new(10, 0, 0, 5));
+    msg.finalize(&signer("unknown"), now()).unwrap();
+    let raw = encode(&msg);
+    let request = Message::from_vec(&raw).unwrap();
+
+    let wire = handler.handle(&raw, &request, CLIENT).await.unwrap();
+
+    let resp = Message::from_vec(&wire).unwrap();
+    assert_eq!(resp.response_code(), ResponseCode::NotAuth);
+    assert_eq!(tsig_error(&resp), Some(17));
+    assert!(config.read().await.dns.local_records.is_empty());
+}
+
+#[tokio::test]
+async fn tampered_update_returns_badsig() {
+    let (handler, config) = handler();
+    let mut msg = add_update(ZONE, "laptop.lan.example.", Ipv4Addr::new(10, 0, 0, 5));
+    msg.finalize(&signer("kea"), now()).unwrap();
+    let mut raw = encode(&msg);
+    // Flip the last octet of the A record's address.
+    let pos = raw.windows(4).position(|w| w == [10, 0, 0, 5]).unwrap();
+    raw[pos + 3] = 6;
+    let request = Message::from_vec(&raw).unwrap();
+
+    let wire = handler.handle(&raw, &request, CLIENT).await.unwrap();
+
+    let resp = Message::from_vec(&wire).unwrap();
+    assert_eq!(resp.response_code(), ResponseCode::NotAuth);
+    assert_eq!(tsig_error(&resp), Some(16));
+    assert!(config.read().await.dns.local_records.is_empty());
+}
+
+#[tokio::test]
+async fn stale_signature_returns_badtime() {
+    let (handler, config) = handler();
+    let mut msg = add_update(ZONE, "laptop.lan.example.", Ipv4Addr::new(10, 0, 0, 5));
+    msg.finalize(&signer("kea"), now() - 3600).unwrap();
+    let raw = encode(&msg);
+    let request = Message::from_vec(&raw).unwrap();
+
+    let wire = handler.handle(&raw, &request, CLIENT).await.unwrap();
+
+    let resp = Message::from_vec(&wire).unwrap();
+    assert_eq!(resp.response_code(), ResponseCode::NotAuth);
+    assert_eq!(tsig_error(&resp), Some(18));
+    assert!(config.read().await.dns.local_records.is_empty());
+}
+
+#[tokio::test]
+async fn update_for_other_zone_is_notauth() {
+    let (handler, _) = handler();
+    let mut msg = add_update(
+        "other.example.",
+        "laptop.other.example.",
+        Ipv4Addr::new(10, 0, 0, 5),
+    );
+    let mut verifier = msg.finalize(&signer("kea"), now()).unwrap().unwrap();
+    let raw = encode(&msg);
+    let request = Message::from_vec(&raw).unwrap();
+
+    let wire = handler.handle(&raw, &request, CLIENT).await.unwrap();
+
+    let resp = Message::from_vec(&wire).unwrap();
+    assert_eq!(resp.response_code(), ResponseCode::NotAuth);
+    assert_eq!(tsig_error(&resp), Some(0));
+    assert!(verifier(&wire).is_ok());
+}
+
+#[tokio::test]
+async fn delete_rrset_removes_record() {
+    let (handler, config) = handler();
+    let mut add = add_update(ZONE, "laptop.lan.example.", Ipv4Addr::new(10, 0, 0, 5));
+    add.finalize(&signer("kea"), now()).unwrap();
+    let raw = encode(&add);
+    handler
+        .handle(&raw, &Message::from_vec(&raw).unwrap(), CLIENT)
+        .await
+        .unwrap();
+    assert_eq!(config.read().await.dns.local_records.len(), 1);
+
+    let mut delete = Message::new(0x4343, MessageType::Query, OpCode::Update);
+    delete.add_query(Query::query(
+        Name::from_ascii(ZONE).unwrap(),
+        RecordType::SOA,
+    ));
+    let mut rr = Record::update0(
+        Name::from_ascii("laptop.lan.example.").unwrap(),
+        0,
+        RecordType::A,
+    );
+    rr.set_dns_class(DNSClass::ANY);
+    delete.add_name_server(rr);
+    delete.finalize(&signer("kea"), now()).unwrap();
+    let raw = encode(&delete);
+
+    let wire = handler
+        .handle(&raw, &Message::from_vec(&raw).unwrap(), CLIENT)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        Message::from_vec(&wire).unwrap().response_code(),
+        ResponseCode::NoError
+    );
+    assert!(config.read().await.dns.local_records.is_empty());
+}
+
+/// A DHCID record (RFC 4701) for `host`; hickory carries the type as
+/// opaque RDATA.
+fn dhcid(host: &str, ttl: u32, digest: &[u8]) -> Record {
+    Record::from_rdata(
+        Name::from_ascii(host).unwrap(),
+        ttl,
+        RData::Unknown {
+            code: RecordType::Unknown(49),
+            rdata: NULL::with(digest.to_vec()),
+        },
+    )
+}
+
+async fn send_signed(handler: &DynamicUpdateHandler, mut msg: Message) -> ResponseCode {
+    msg.finalize(&signer("kea"), now()).unwrap();
+    let raw = encode(&msg);
+    let wire = handler
+        .handle(&raw, &Message::from_vec(&raw).unwrap(), CLIENT)
+        .await
+        .unwrap();
+    Message::from_vec(&wire).unwrap().response_code()
+}
+
+/// Kea's default conflict resolution (RFC 4703 §5.3): the first update
+/// claims a free name with A + DHCID, a renewal to a new address is
+/// allowed only while the DHCID still matches.
+#[tokio::test]
+async fn kea_update_with_dhcid_claims_and_renews_the_name() {
+    let (handler, config) = handler();
+    let host = "laptop.lan.example.";
+
+    let mut claim = add_update(ZONE, host, Ipv4Addr::new(10, 0, 0, 5));
+    let mut unused = Record::update0(Name::from_ascii(host).unwrap(), 0, RecordType::ANY);
+    unused.set_dns_class(DNSClass::NONE);
+    claim.add_answer(unused);
+    claim.add_name_server(dhcid(host, 600, b"client-1"));
+    assert_eq!(send_signed(&handler, claim).await, ResponseCode::NoError);
+    {
+        let config = config.read().await;
+        assert_eq!(config.dns.local_records.len(), 1);
+        assert_eq!(config.dns.dynamic_update_records.len(), 1);
+        assert_eq!(config.dns.dynamic_update_records[0].record_type, "DHCID");
+    }
+
+    let renew = |ip: Ipv4Addr, digest: &[u8]| {
+        let mut msg = add_update(ZONE, host, ip);
+        msg.add_answer(dhcid(host, 0, digest));
+        let add = msg.take_name_servers();
+        let mut delete = Record::update0(Name::from_ascii(host).unwrap(), 0, RecordType::A);
+        delete.set_dns_class(DNSClass::ANY);
+        msg.add_name_server(delete).add_name_servers(add);
+        msg
+    };
+
+    let other_client = renew(Ipv4Addr::new(10, 0, 0, 9), b"client-2");
+    assert_eq!(
+        send_signed(&handler, other_client).await,
+        ResponseCode::NXRRSet
+    );
+
+    let same_client = renew(Ipv4Addr::new(10, 0, 0, 6), b"client-1");
+    assert_eq!(
+        send_signed(&handler, same_client).await,
+        ResponseCode::NoError
+    );
+    let config = config.read().await;
+    assert_eq!(config.dns.local_records.len(), 1);
+    assert_eq!(config.dns.local_records[0].ip, "10.0.0.6");
+    assert_eq!(config.dns.dynamic_update_records.len(), 1);
+}
@@ -0,0 +1,170 @@
+use ferrous_dns_application::ports::ForwardingRuleRepository;
+use ferrous_dns_domain::{DomainError, ForwardingTarget};
+use ferrous_dns_infrastructure::repositories::forwarding_rule_repository::SqliteForwardingRuleRepository;
+use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
+use std::sync::Arc;
+
+async fn create_test_db() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::query(
+        "CREATE TABLE forwarding_rules (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            domain TEXT NOT NULL UNIQUE,
+            target_type TEXT NOT NULL CHECK(target_type IN ('pool', 'server')),
+            target TEXT NOT NULL,
+            enabled INTEGER NOT NULL DEFAULT 1,
+            comment TEXT,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    pool
+}
+
+fn server(endpoint: &str) -> ForwardingTarget {
+    ForwardingTarget::Server(Arc::from(endpoint))
+}
+
+#[tokio::test]
+async fn test_create_and_get_forwarding_rule() {
+    let repo = SqliteForwardingRuleRepository::new(create_test_db().await);
+
+    let created = repo
+        .create(
+            "corp.example".to_string(),
+            server("tcp://10.1.1.53:53"),
+            true,
+            Some("Corporate DNS".to_string()),
+        )
+        .await
+        .unwrap();
+
+    assert!(created.id.is_some());
+    assert_eq!(created.domain.as_ref(), "corp.example");
+    assert_eq!(created.target, server("tcp://10.1.1.53:53"));
+    assert!(created.enabled);
+    assert_eq!(created.comment.as_deref(), Some("Corporate DNS"));
+
+    let fetched = repo.get_by_id(created.id.unwrap()).await.unwrap().unwrap();
+    assert_eq!(fetched.domain, created.domain);
+    assert_eq!(fetched.target, created.target);
+}
+
+#[tokio::test]
+async fn test_pool_target_round_trips() {
+    let repo = SqliteForwardingRuleRepository::new(create_test_db().await);
+
+    let created = repo
+        .create(
+            "consul".to_string(),
+            ForwardingTarget::Pool(Arc::from("internal")),
+            true,
+            None,
+        )
+        .await
+        .unwrap();
+
+    let fetched = repo.get_by_id(created.id.unwrap()).await.unwrap().unwrap();
+    assert_eq!(
+        fetched.target,
+        ForwardingTarget::Pool(Arc::from("internal"))
+    );
+}
+
+#[tokio::test]
+async fn test_duplicate_domain_is_rejected() {
+    let repo = SqliteForwardingRuleRepository::new(create_test_db().await);
+
+    repo.create("consul".to_string(), server("127.0.0.1:8600"), true, None)
+        .await
+        .unwrap();
+    let result = repo
+        .create("consul".to_string(), server("127.0.0.1:8601"), true, None)
+        .await;
+
+    assert!(matches!(result, Err(DomainError::InvalidForwardingRule(_))));
+}
+
+#[tokio::test]
+async fn test_get_all_orders_by_domain() {
+    let repo = SqliteForwardingRuleRepository::new(create_test_db().await);
+
+    for domain in ["consul", "10.in-addr.arpa", "corp.example"] {
+        repo.create(domain.to_string(), server("10.1.1.53:53"), true, None)
+            .await
+            .unwrap();
+    }
+
+    let all = repo.get_all().await.unwrap();
+    let domains: Vec<&str> = all.iter().map(|r| r.domain.as_ref()).collect();
+    assert_eq!(domains, vec!["10.in-addr.arpa", "consul", "corp.example"]);
+}
+
+#[tokio::test]
+async fn test_update_partial_fields() {
+    let repo = SqliteForwardingRuleRepository::new(create_test_db().await);
+    let created = repo
+        .create(
+            "corp.example".to_string(),
+            server("10.1.1.53:53"),
+            true,
+            Some("keep me".to_string()),
+        )
+        .await
+        .unwrap();
+    let id = created.id.unwrap();
+
+    let updated = repo
+        .update(
+            id,
+            None,
+            Some(ForwardingTarget::Pool(Arc::from("corp"))),
+            Some(false),
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(updated.domain.as_ref(), "corp.example");
+    assert_eq!(updated.target, ForwardingTarget::Pool(Arc::from("corp")));
+    assert!(!updated.enabled);
+    assert_eq!(updated.comment.as_deref(), Some("keep me"));
+}
+
+#[tokio::test]
+async fn test_update_missing_rule_returns_not_found() {
+    let repo = SqliteForwardingRuleRepository::new(create_test_db().await);
+
+    let result = repo.update(42, None, None, Some(false), None).await;
+
+    assert!(matches!(
+        result,
+        Err(DomainError::ForwardingRuleNotFound(42))
+    ));
+}
+
+#[tokio::test]
+async fn test_delete_forwarding_rule() {
+    let repo = SqliteForwardingRuleRepository::new(create_test_db().await);
+    let created = repo
+        .create("consul".to_string(), server("127.0.0.1:8600"), true, None)
+        .await
+        .unwrap();
+    let id = created.id.unwrap();
+
+    repo.delete(id).await.unwrap();
+
+    assert!(repo.get_by_id(id).await.unwrap().is_none());
+    assert!(matches!(
+        repo.delete(id).await,
+        Err(DomainError::ForwardingRuleNotFound(_))
+    ));
+}
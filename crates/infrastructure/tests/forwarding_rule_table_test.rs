@@ -0,0 +1,99 @@
+use ferrous_dns_application::ports::ForwardingRuleStore;
+use ferrous_dns_domain::{ForwardingRule, ForwardingTarget};
+use ferrous_dns_infrastructure::dns::resolver::{ForwardingRoute, ForwardingRuleTable};
+use std::sync::Arc;
+
+fn rule(domain: &str, target: ForwardingTarget, enabled: bool) -> ForwardingRule {
+    ForwardingRule::new(None, Arc::from(domain), target, enabled, None)
+}
+
+fn server(endpoint: &str) -> ForwardingTarget {
+    ForwardingTarget::Server(Arc::from(endpoint))
+}
+
+fn pool(name: &str) -> ForwardingTarget {
+    ForwardingTarget::Pool(Arc::from(name))
+}
+
+#[test]
+fn empty_table_matches_nothing() {
+    let table = ForwardingRuleTable::new();
+    assert!(table.is_empty());
+    assert!(table.find("corp.example").is_none());
+    assert!(!table.matches("corp.example"));
+}
+
+#[test]
+fn matches_exact_name_and_subdomains() {
+    let table = ForwardingRuleTable::new();
+    table.replace_rules(&[rule("corp.example", server("tcp://10.1.1.53:53"), true)]);
+
+    assert!(table.matches("corp.example"));
+    assert!(table.matches("host.corp.example"));
+    assert!(table.matches("a.b.corp.example"));
+    assert!(!table.matches("notcorp.example"));
+    assert!(!table.matches("example"));
+}
+
+#[test]
+fn longest_suffix_wins() {
+    let table = ForwardingRuleTable::new();
+    table.replace_rules(&[
+        rule("example", pool("public"), true),
+        rule("corp.example", pool("corp"), true),
+        rule("lab.corp.example", server("127.0.0.1:5353"), true),
+    ]);
+
+    let hit = table.find("db.lab.corp.example").unwrap();
+    assert_eq!(hit.label.as_ref(), "forward:lab.corp.example");
+    assert!(matches!(hit.route, ForwardingRoute::Server(_)));
+
+    let hit = table.find("www.corp.example").unwrap();
+    assert!(matches!(hit.route, ForwardingRoute::Pool(ref p) if p.as_ref() == "corp"));
+
+    let hit = table.find("other.example").unwrap();
+    assert!(matches!(hit.route, ForwardingRoute::Pool(ref p) if p.as_ref() == "public"));
+}
+
+#[test]
+fn lookup_is_case_insensitive_and_ignores_root_dot() {
+    let table = ForwardingRuleTable::new();
+    table.replace_rules(&[rule("Consul", server("127.0.0.1:8600"), true)]);
+
+    assert!(table.matches("web.service.CONSUL"));
+    assert!(table.matches("web.service.consul."));
+}
+
+#[test]
+fn reverse_zone_rule_matches_ptr_queries() {
+    let table = ForwardingRuleTable::new();
+    table.replace_rules(&[rule("10.in-addr.arpa", server("tcp://10.1.1.53:53"), true)]);
+
+    assert!(table.matches("4.3.2.10.in-addr.arpa"));
+    assert!(!table.matches("4.3.2.192.in-addr.arpa"));
+}
+
+#[test]
+fn disabled_and_invalid_rules_are_skipped() {
+    let table = ForwardingRuleTable::new();
+    table.replace_rules(&[
+        rule("off.example", server("10.0.0.1:53"), false),
+        rule("broken.example", server("not-an-endpoint"), true),
+        rule("on.example", server("10.0.0.1:53"), true),
+    ]);
+
+    assert_eq!(table.len(), 1);
+    assert!(!table.matches("off.example"));
+    assert!(!table.matches("broken.example"));
+    assert!(table.matches("on.example"));
+}
+
+#[test]
+fn replace_rules_swaps_whole_table() {
+    let table = ForwardingRuleTable::new();
+    table.replace_rules(&[rule("old.example", pool("a"), true)]);
+    table.replace_rules(&[rule("new.example", pool("b"), true)]);
+
+    assert!(!table.matches("old.example"));
+    assert!(table.matches("new.example"));
+}
@@ -1,4 +1,5 @@
 use ferrous_dns_application::ports::GroupRepository;
+use ferrous_dns_domain::{BlockingMode, DomainError};
 use ferrous_dns_infrastructure::repositories::group_repository::SqliteGroupRepository;
 use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
 
@@ -15,6 +16,10 @@ async fn create_test_db() -> SqlitePool {
             enabled BOOLEAN NOT NULL DEFAULT 1,
             comment TEXT,
             is_default BOOLEAN NOT NULL DEFAULT 0,
+            blocking_mode TEXT,
+            blocking_ipv4 TEXT,
+            blocking_ipv6 TEXT,
+            upstream_pools TEXT,
             created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
             updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
         )",
@@ -200,3 +205,93 @@ async fn test_get_clients_in_group() {
     assert_eq!(clients.len(), 1);
     assert_eq!(clients[0].ip_address.to_string(), "192.168.1.1");
 }
+
+#[tokio::test]
+async fn test_update_blocking_mode_roundtrip() {
+    let pool = create_test_db().await;
+    let repo = SqliteGroupRepository::new(pool);
+
+    let group = repo.create("Kids".to_string(), None).await.unwrap();
+    let id = group.id.unwrap();
+    assert!(group.blocking_mode.is_none());
+
+    let updated = repo
+        .update_blocking_mode(
+            id,
+            Some(BlockingMode::CustomIp),
+            Some("10.0.0.1".parse().unwrap()),
+            Some("fd00::1".parse().unwrap()),
+        )
+        .await
+        .unwrap();
+    assert_eq!(updated.blocking_mode, Some(BlockingMode::CustomIp));
+
+    let fetched = repo.get_by_id(id).await.unwrap().unwrap();
+    assert_eq!(fetched.blocking_mode, Some(BlockingMode::CustomIp));
+    assert_eq!(fetched.blocking_ipv4, Some("10.0.0.1".parse().unwrap()));
+    assert_eq!(fetched.blocking_ipv6, Some("fd00::1".parse().unwrap()));
+
+    let with_counts = repo.get_all_with_client_counts().await.unwrap();
+    let (kids, _) = with_counts.iter().find(|(g, _)| g.id == Some(id)).unwrap();
+    assert_eq!(kids.blocking_mode, Some(BlockingMode::CustomIp));
+
+    let cleared = repo
+        .update_blocking_mode(id, None, None, None)
+        .await
+        .unwrap();
+    assert!(cleared.blocking_mode.is_none());
+    assert!(cleared.blocking_ipv4.is_none());
+}
+
+#[tokio::test]
+async fn test_update_blocking_mode_unknown_group() {
+    let pool = create_test_db().await;
+    let repo = SqliteGroupRepository::new(pool);
+
+    let result = repo
+        .update_blocking_mode(999, Some(BlockingMode::Nxdomain), None, None)
+        .await;
+    assert!(matches!(result, Err(DomainError::GroupNotFound(999))));
+}
+
+#[tokio::test]
+async fn test_update_upstream_pools_roundtrip() {
+    let pool = create_test_db().await;
+    let repo = SqliteGroupRepository::new(pool);
+
+    let group = repo.create("Guests".to_string(), None).await.unwrap();
+    let id = group.id.unwrap();
+    assert!(group.upstream_pools.is_empty());
+
+    let updated = repo
+        .update_upstream_pools(id, &["cleanbrowsing".to_string(), "quad9".to_string()])
+        .await
+        .unwrap();
+    let names: Vec<&str> = updated.upstream_pools.iter().map(|p| p.as_ref()).collect();
+    assert_eq!(names, vec!["cleanbrowsing", "quad9"]);
+
+    let with_counts = repo.get_all_with_client_counts().await.unwrap();
+    let (guests, _) = with_counts.iter().find(|(g, _)| g.id == Some(id)).unwrap();
+    assert_eq!(guests.upstream_pools.len(), 2);
+
+    let cleared = repo.update_upstream_pools(id, &[]).await.unwrap();
+    assert!(cleared.upstream_pools.is_empty());
+    assert!(repo
+        .get_by_id(id)
+        .await
+        .unwrap()
+        .unwrap()
+        .upstream_pools
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_update_upstream_pools_unknown_group() {
+    let pool = create_test_db().await;
+    let repo = SqliteGroupRepository::new(pool);
+
+    let result = repo
+        .update_upstream_pools(999, &["quad9".to_string()])
+        .await;
+    assert!(matches!(result, Err(DomainError::GroupNotFound(999))));
+}
@@ -0,0 +1,90 @@
+use ferrous_dns_application::ports::GroupUpstreamStore;
+use ferrous_dns_domain::{
+    DomainError, Group, QueryScope, RecordType, UpstreamPool, UpstreamStrategy,
+};
+use ferrous_dns_infrastructure::dns::{GroupUpstreamTable, PoolManager, QueryEventEmitter};
+use std::sync::Arc;
+
+fn group(id: i64, pools: &[&str]) -> Group {
+    let mut group = Group::new(
+        Some(id),
+        Arc::from(format!("group-{id}")),
+        true,
+        None,
+        false,
+    );
+    group.upstream_pools = pools.iter().map(|p| Arc::from(*p)).collect();
+    group
+}
+
+#[test]
+fn empty_table_routes_nothing() {
+    let table = GroupUpstreamTable::new();
+    assert!(table.is_empty());
+    assert!(!table.has_pools(1));
+    assert!(table.pools(1).is_none());
+}
+
+#[test]
+fn only_groups_with_pools_are_routed() {
+    let table = GroupUpstreamTable::new();
+    table.replace_groups(&[
+        group(1, &[]),
+        group(2, &["cleanbrowsing", "quad9"]),
+        group(3, &["quad9"]),
+    ]);
+
+    assert_eq!(table.len(), 2);
+    assert!(!table.has_pools(1));
+    let pools = table.pools(2).unwrap();
+    let names: Vec<&str> = pools.iter().map(|p| p.as_ref()).collect();
+    assert_eq!(names, vec!["cleanbrowsing", "quad9"]);
+}
+
+#[test]
+fn replace_drops_previous_routes() {
+    let table = GroupUpstreamTable::new();
+    table.replace_groups(&[group(2, &["quad9"])]);
+    table.replace_groups(&[group(3, &["quad9"])]);
+
+    assert!(!table.has_pools(2));
+    assert!(table.has_pools(3));
+}
+
+#[tokio::test]
+async fn group_with_unknown_pools_fails_closed_instead_of_using_global_pools() {
+    let table = Arc::new(GroupUpstreamTable::new());
+    table.replace_groups(&[group(2, &["missing"])]);
+    let manager = PoolManager::new(
+        vec![UpstreamPool {
+            name: "plain".to_string(),
+            strategy: UpstreamStrategy::Failover,
+            priority: 1,
+            servers: vec!["udp://127.0.0.1:1".to_string()],
+            weight: None,
+        }],
+        None,
+        QueryEventEmitter::new_disabled(),
+    )
+    .await
+    .unwrap()
+    .with_group_upstreams(table);
+
+    let result = manager
+        .query(
+            &Arc::from("example.com"),
+            &RecordType::A,
+            200,
+            false,
+            QueryScope {
+                view: None,
+                group: Some(2),
+            },
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(DomainError::TransportAllServersUnreachable)
+    ));
+}
@@ -0,0 +1,224 @@
+use ferrous_dns_infrastructure::dns::block_filter::{
+    load_list_source, parse_list_text_counted, ParsedEntry,
+};
+use sqlx::{sqlite::SqlitePoolOptions, Row, SqlitePool};
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+const LIST: &str = "# ads\n0.0.0.0 ads.example.com\n||tracker.example.net^\nnot a rule\n";
+
+async fn create_test_db() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::query(
+        "CREATE TABLE list_source_fetches (
+            url              TEXT    PRIMARY KEY,
+            etag             TEXT,
+            last_modified    TEXT,
+            content          TEXT,
+            last_attempt_at  TEXT    NOT NULL,
+            last_success_at  TEXT,
+            last_http_status INTEGER,
+            entry_count      INTEGER NOT NULL DEFAULT 0,
+            parse_errors     INTEGER NOT NULL DEFAULT 0,
+            last_error       TEXT
+        )",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    pool
+}
+
+/// Serves one scripted HTTP response per connection and records the
+/// request heads it received.
+async fn spawn_server(responses: Vec<String>) -> (String, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/list.txt", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let seen = requests.clone();
+
+    tokio::spawn(async move {
+        for response in responses {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut head = Vec::new();
+            let mut buf = [0u8; 1024];
+            while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+                let n = stream.read(&mut buf).await.unwrap();
+                if n == 0 {
+                    break;
+                }
+                head.extend_from_slice(&buf[..n]);
+            }
+            seen.lock()
+                .unwrap()
+                .push(String::from_utf8_lossy(&head).to_ascii_lowercase());
+            stream.write_all(response.as_bytes()).await.unwrap();
+            stream.shutdown().await.ok();
+        }
+    });
+
+    (url, requests)
+}
+
+fn ok_response(body: &str, etag: &str) -> String {
+    format!(
+        "HTTP/1.1 200 OK\r\nETag: {etag}\r\nLast-Modified: Wed, 01 Jan 2026 00:00:00 GMT\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+fn status_response(status: &str) -> String {
+    format!("HTTP/1.1 {status}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+}
+
+struct StatusRow {
+    last_success_at: Option<String>,
+    http_status: Option<i64>,
+    entry_count: i64,
+    parse_errors: i64,
+    last_error: Option<String>,
+}
+
+async fn status(pool: &SqlitePool, url: &str) -> StatusRow {
+    let row = sqlx::query(
+        "SELECT last_success_at, last_http_status, entry_count, parse_errors, last_error
+         FROM list_source_fetches WHERE url = ?",
+    )
+    .bind(url)
+    .fetch_one(pool)
+    .await
+    .unwrap();
+    StatusRow {
+        last_success_at: row.get("last_success_at"),
+        http_status: row.get("last_http_status"),
+        entry_count: row.get("entry_count"),
+        parse_errors: row.get("parse_errors"),
+        last_error: row.get("last_error"),
+    }
+}
+
+#[test]
+fn test_parse_counted_skips_comments_and_hosts_aliases() {
+    let text = "! title\n[Adblock Plus 2.0]\n# comment\n\n127.0.0.1 localhost\n\
+                fe80::1%lo0 localhost\n0.0.0.0 ads.example.com\n||ads.example.org/path\n\
+                garbage line here\n";
+    let (entries, errors) = parse_list_text_counted(text);
+    assert_eq!(
+        entries,
+        vec![ParsedEntry::Exact("ads.example.com".to_string())]
+    );
+    assert_eq!(errors, 2);
+}
+
+#[tokio::test]
+async fn test_unchanged_list_is_revalidated_and_served_from_stored_copy() {
+    let pool = create_test_db().await;
+    let (url, requests) = spawn_server(vec![
+        ok_response(LIST, "\"v1\""),
+        status_response("304 Not Modified"),
+    ])
+    .await;
+    let client = reqwest::Client::new();
+
+    let first = load_list_source(&pool, &client, &url, "blocklist")
+        .await
+        .unwrap();
+    assert_eq!(first.len(), 2);
+    let row = status(&pool, &url).await;
+    assert_eq!(row.http_status, Some(200));
+    assert_eq!(row.entry_count, 2);
+    assert_eq!(row.parse_errors, 1);
+
+    let second = load_list_source(&pool, &client, &url, "blocklist")
+        .await
+        .unwrap();
+    assert_eq!(second, first);
+
+    let requests = requests.lock().unwrap().clone();
+    assert!(!requests[0].contains("if-none-match"));
+    assert!(requests[1].contains("if-none-match: \"v1\""));
+    assert!(requests[1].contains("if-modified-since: wed, 01 jan 2026 00:00:00 gmt"));
+
+    let row = status(&pool, &url).await;
+    assert_eq!(row.http_status, Some(304));
+    assert_eq!(row.entry_count, 2);
+    assert!(row.last_error.is_none());
+}
+
+#[tokio::test]
+async fn test_failed_fetch_falls_back_to_last_good_copy() {
+    let pool = create_test_db().await;
+    let (url, _) = spawn_server(vec![
+        ok_response(LIST, "\"v1\""),
+        status_response("503 Service Unavailable"),
+    ])
+    .await;
+    let client = reqwest::Client::new();
+
+    load_list_source(&pool, &client, &url, "blocklist")
+        .await
+        .unwrap();
+    let success_at = status(&pool, &url).await.last_success_at;
+
+    let entries = load_list_source(&pool, &client, &url, "blocklist")
+        .await
+        .expect("stored copy should be used");
+    assert_eq!(entries.len(), 2);
+
+    let row = status(&pool, &url).await;
+    assert_eq!(row.http_status, Some(503));
+    assert_eq!(row.last_error.as_deref(), Some("HTTP 503"));
+    assert_eq!(row.entry_count, 2);
+    assert_eq!(row.last_success_at, success_at);
+}
+
+#[tokio::test]
+async fn test_failed_first_fetch_records_error_without_entries() {
+    let pool = create_test_db().await;
+    let (url, _) = spawn_server(vec![status_response("404 Not Found")]).await;
+    let client = reqwest::Client::new();
+
+    assert!(load_list_source(&pool, &client, &url, "whitelist")
+        .await
+        .is_none());
+
+    let row = status(&pool, &url).await;
+    assert_eq!(row.http_status, Some(404));
+    assert_eq!(row.entry_count, 0);
+    assert!(row.last_success_at.is_none());
+    assert!(row.last_error.is_some());
+}
+
+#[tokio::test]
+async fn test_changed_list_replaces_stored_copy() {
+    let pool = create_test_db().await;
+    let (url, _) = spawn_server(vec![
+        ok_response(LIST, "\"v1\""),
+        ok_response("0.0.0.0 new.example.com\n", "\"v2\""),
+        status_response("500 Internal Server Error"),
+    ])
+    .await;
+    let client = reqwest::Client::new();
+
+    load_list_source(&pool, &client, &url, "blocklist")
+        .await
+        .unwrap();
+    load_list_source(&pool, &client, &url, "blocklist")
+        .await
+        .unwrap();
+
+    let fallback = load_list_source(&pool, &client, &url, "blocklist")
+        .await
+        .unwrap();
+    assert_eq!(
+        fallback,
+        vec![ParsedEntry::Exact("new.example.com".to_string())]
+    );
+}
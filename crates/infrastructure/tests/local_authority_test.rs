@@ -0,0 +1,412 @@
+use async_trait::async_trait;
+use ferrous_dns_application::ports::{
+    DnsResolution, DnsResolver, LocalZoneRepository, LocalZoneStore,
+};
+use ferrous_dns_domain::{DnsQuery, DomainError, LocalZoneConfig, RecordType};
+use ferrous_dns_infrastructure::dns::authority::{
+    format_zone, parse_zone_file, parse_zone_text, LocalAuthority,
+};
+use ferrous_dns_infrastructure::dns::resolver::LocalAuthorityResolver;
+use ferrous_dns_infrastructure::dns::ViewAuthorities;
+use ferrous_dns_infrastructure::repositories::FileLocalZoneRepository;
+use hickory_proto::op::{Message, ResponseCode};
+use hickory_proto::rr::{RData, RecordType as HickoryRecordType};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+const HOME_ZONE: &str = r#"
+$ORIGIN home.lan.
+$TTL 300
+@             IN SOA  ns1 admin 2026101701 7200 3600 1209600 60
+@             IN NS   ns1
+ns1           IN A    192.168.1.1
+nas           IN A    192.168.1.10
+nas           IN AAAA fd00::10
+www           IN CNAME nas
+alias         IN CNAME www
+cdn           IN CNAME cdn.example.com.
+@             IN MX   10 nas
+note          IN TXT  "hello \"world\"" "second"
+*.dev         IN A    192.168.1.50
+host.sub      IN A    192.168.1.60
+lab           IN NS   ns.lab
+ns.lab        IN A    192.168.2.1
+"#;
+
+fn authority() -> LocalAuthority {
+    let zone = parse_zone_text(HOME_ZONE, None, "home.lan").unwrap();
+    let authority = LocalAuthority::new();
+    authority.replace_zones(&[zone]);
+    authority
+}
+
+// ── zone file parsing ─────────────────────────────────────────────────────────
+
+#[test]
+fn parse_applies_origin_and_ttl() {
+    let zone = parse_zone_text(HOME_ZONE, None, "home.lan.").unwrap();
+    assert_eq!(zone.origin.as_ref(), "home.lan");
+    // The SOA is listed with its MINIMUM as TTL, the value negative
+    // answers are cached for.
+    assert!(zone.records[0].is_type("SOA"));
+    assert_eq!(zone.records[0].ttl, 60);
+    assert_eq!(zone.serial(), Some(2026101701));
+
+    let nas = zone
+        .records
+        .iter()
+        .find(|r| r.name.as_ref() == "nas.home.lan" && r.is_type("A"))
+        .unwrap();
+    assert_eq!(nas.ttl, 300);
+    assert_eq!(nas.data.as_ref(), "192.168.1.10");
+}
+
+#[test]
+fn parse_rejects_zone_without_soa() {
+    let result = parse_zone_text(
+        "$ORIGIN home.lan.\nnas 300 IN A 192.168.1.10\n",
+        None,
+        "home.lan",
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn parse_rejects_records_outside_zone() {
+    let text = format!("{}nas.example.com. 300 IN A 192.0.2.1\n", HOME_ZONE);
+    assert!(parse_zone_text(&text, None, "home.lan").is_err());
+}
+
+#[test]
+fn parse_follows_include_relative_to_zone_file() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("hosts.inc"),
+        "printer 300 IN A 192.168.1.30\n",
+    )
+    .unwrap();
+    let path = dir.path().join("home.lan.zone");
+    std::fs::write(
+        &path,
+        "$ORIGIN home.lan.\n\
+         @ 3600 IN SOA ns1 admin 1 7200 3600 1209600 60\n\
+         $INCLUDE hosts.inc\n",
+    )
+    .unwrap();
+
+    let zone = parse_zone_file(&path, "home.lan").unwrap();
+    assert!(zone
+        .records
+        .iter()
+        .any(|r| r.name.as_ref() == "printer.home.lan" && r.data.as_ref() == "192.168.1.30"));
+}
+
+#[test]
+fn format_round_trips_through_parser() {
+    let zone = parse_zone_text(HOME_ZONE, None, "home.lan").unwrap();
+    let text = format_zone(&zone);
+    let reparsed = parse_zone_text(&text, None, "home.lan").unwrap();
+    assert_eq!(zone.records, reparsed.records);
+}
+
+// ── authoritative answers ─────────────────────────────────────────────────────
+
+#[test]
+fn answers_address_with_aa() {
+    let response = authority()
+        .answer("nas.home.lan", HickoryRecordType::A)
+        .unwrap();
+    assert_eq!(response.response_code, ResponseCode::NoError);
+    assert!(response.authoritative);
+    assert_eq!(response.answers.len(), 1);
+    assert_eq!(response.answers[0].ttl(), 300);
+}
+
+#[test]
+fn names_outside_zones_are_not_answered() {
+    let authority = authority();
+    assert!(authority
+        .answer("example.com", HickoryRecordType::A)
+        .is_none());
+    assert!(!authority.contains("myhome.lan"));
+    assert!(authority.contains("anything.home.lan"));
+}
+
+#[test]
+fn nxdomain_carries_soa_with_negative_ttl() {
+    let response = authority()
+        .answer("missing.home.lan", HickoryRecordType::A)
+        .unwrap();
+    assert_eq!(response.response_code, ResponseCode::NXDomain);
+    assert!(response.answers.is_empty());
+    assert_eq!(response.authority.len(), 1);
+    assert_eq!(response.authority[0].record_type(), HickoryRecordType::SOA);
+    assert_eq!(response.negative_soa_ttl(), Some(60));
+}
+
+#[test]
+fn nodata_for_existing_name_and_empty_non_terminal() {
+    let authority = authority();
+    let response = authority
+        .answer("nas.home.lan", HickoryRecordType::MX)
+        .unwrap();
+    assert_eq!(response.response_code, ResponseCode::NoError);
+    assert!(response.answers.is_empty());
+    assert_eq!(response.negative_soa_ttl(), Some(60));
+
+    let response = authority
+        .answer("sub.home.lan", HickoryRecordType::A)
+        .unwrap();
+    assert_eq!(response.response_code, ResponseCode::NoError);
+    assert!(response.answers.is_empty());
+}
+
+#[test]
+fn wildcard_synthesizes_owner_name() {
+    let authority = authority();
+    let response = authority
+        .answer("app.dev.home.lan", HickoryRecordType::A)
+        .unwrap();
+    assert_eq!(response.answers.len(), 1);
+    assert_eq!(response.answers[0].name().to_ascii(), "app.dev.home.lan.");
+
+    // RFC 4592: the wildcard does not cover names below an existing node.
+    let response = authority
+        .answer("x.sub.home.lan", HickoryRecordType::A)
+        .unwrap();
+    assert_eq!(response.response_code, ResponseCode::NXDomain);
+}
+
+#[test]
+fn cname_chain_is_followed_inside_zone() {
+    let response = authority()
+        .answer("alias.home.lan", HickoryRecordType::A)
+        .unwrap();
+    let types: Vec<_> = response.answers.iter().map(|r| r.record_type()).collect();
+    assert_eq!(
+        types,
+        vec![
+            HickoryRecordType::CNAME,
+            HickoryRecordType::CNAME,
+            HickoryRecordType::A
+        ]
+    );
+    assert!(response.external_target.is_none());
+}
+
+#[test]
+fn cname_leaving_zone_reports_external_target() {
+    let response = authority()
+        .answer("cdn.home.lan", HickoryRecordType::A)
+        .unwrap();
+    assert_eq!(response.answers.len(), 1);
+    assert_eq!(
+        response.external_target.map(|n| n.to_ascii()),
+        Some("cdn.example.com.".to_string())
+    );
+}
+
+#[test]
+fn mx_answer_adds_target_addresses() {
+    let response = authority()
+        .answer("home.lan", HickoryRecordType::MX)
+        .unwrap();
+    assert_eq!(response.answers.len(), 1);
+    assert_eq!(response.additionals.len(), 2);
+}
+
+#[test]
+fn txt_strings_keep_quoting() {
+    let response = authority()
+        .answer("note.home.lan", HickoryRecordType::TXT)
+        .unwrap();
+    let RData::TXT(txt) = response.answers[0].data() else {
+        panic!("expected TXT");
+    };
+    assert_eq!(txt.txt_data().len(), 2);
+    assert_eq!(&*txt.txt_data()[0], b"hello \"world\"");
+}
+
+#[test]
+fn delegation_returns_referral_with_glue() {
+    let response = authority()
+        .answer("host.lab.home.lan", HickoryRecordType::A)
+        .unwrap();
+    assert!(!response.authoritative);
+    assert!(response.answers.is_empty());
+    assert_eq!(response.authority[0].record_type(), HickoryRecordType::NS);
+    assert_eq!(response.additionals.len(), 1);
+}
+
+// ── resolver layer ────────────────────────────────────────────────────────────
+
+struct MockInner;
+
+#[async_trait]
+impl DnsResolver for MockInner {
+    async fn resolve(&self, query: &DnsQuery) -> Result<DnsResolution, DomainError> {
+        match query.domain.as_ref() {
+            "cdn.example.com" => Ok(DnsResolution::new(
+                vec!["203.0.113.7".parse::<IpAddr>().unwrap()],
+                false,
+            )),
+            _ => Err(DomainError::NxDomain),
+        }
+    }
+}
+
+fn resolver() -> LocalAuthorityResolver {
+    LocalAuthorityResolver::new(Arc::new(MockInner), Arc::new(authority()))
+}
+
+fn wire_message(resolution: &DnsResolution) -> Message {
+    Message::from_vec(resolution.upstream_wire_data.as_ref().unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn resolver_answers_in_zone_queries_from_wire_data() {
+    let resolution = resolver()
+        .resolve(&DnsQuery::new("nas.home.lan", RecordType::AAAA))
+        .await
+        .unwrap();
+
+    assert!(resolution.local_dns);
+    let message = wire_message(&resolution);
+    assert!(message.authoritative());
+    assert_eq!(message.answers().len(), 1);
+}
+
+#[tokio::test]
+async fn resolver_returns_nxdomain_in_wire_rcode() {
+    let resolution = resolver()
+        .resolve(&DnsQuery::new("missing.home.lan", RecordType::A))
+        .await
+        .unwrap();
+
+    let message = wire_message(&resolution);
+    assert_eq!(message.response_code(), ResponseCode::NXDomain);
+    assert_eq!(resolution.negative_soa_ttl, Some(60));
+}
+
+#[tokio::test]
+async fn resolver_chases_external_cname_target() {
+    let resolution = resolver()
+        .resolve(&DnsQuery::new("cdn.home.lan", RecordType::A))
+        .await
+        .unwrap();
+
+    let message = wire_message(&resolution);
+    assert_eq!(message.answers().len(), 2);
+    assert_eq!(message.answers()[1].record_type(), HickoryRecordType::A);
+}
+
+#[tokio::test]
+async fn resolver_passes_other_names_through_and_bypasses_cache() {
+    let resolver = resolver();
+    let result = resolver
+        .resolve(&DnsQuery::new("example.org", RecordType::A))
+        .await;
+    assert!(matches!(result, Err(DomainError::NxDomain)));
+    assert!(resolver
+        .try_cache_str("nas.home.lan", RecordType::A)
+        .is_none());
+}
+
+const VPN_ZONE: &str = r#"
+$ORIGIN home.lan.
+$TTL 300
+@             IN SOA  ns1 admin 2026101701 7200 3600 1209600 60
+@             IN NS   ns1
+ns1           IN A    10.8.0.1
+nas           IN A    10.8.0.10
+"#;
+
+fn view_resolver() -> LocalAuthorityResolver {
+    let zone = parse_zone_text(VPN_ZONE, None, "home.lan").unwrap();
+    let vpn = LocalAuthority::new();
+    vpn.replace_zones(&[zone]);
+    let mut views = ViewAuthorities::default();
+    views.insert(0, Arc::new(vpn));
+    resolver().with_view_authorities(Arc::new(views))
+}
+
+fn first_address(resolution: &DnsResolution) -> RData {
+    wire_message(resolution).answers()[0].data().clone()
+}
+
+#[tokio::test]
+async fn view_zone_answers_queries_scoped_to_the_view() {
+    let resolver = view_resolver();
+
+    let in_view = resolver
+        .resolve(&DnsQuery::new("nas.home.lan", RecordType::A).with_view(Some(0)))
+        .await
+        .unwrap();
+    assert_eq!(first_address(&in_view).to_string(), "10.8.0.10");
+
+    let outside = resolver
+        .resolve(&DnsQuery::new("nas.home.lan", RecordType::A))
+        .await
+        .unwrap();
+    assert_eq!(first_address(&outside).to_string(), "192.168.1.10");
+
+    let other_view = resolver
+        .resolve(&DnsQuery::new("nas.home.lan", RecordType::A).with_view(Some(1)))
+        .await
+        .unwrap();
+    assert_eq!(first_address(&other_view).to_string(), "192.168.1.10");
+}
+
+#[tokio::test]
+async fn view_zone_names_bypass_cache_in_the_view() {
+    let resolver = view_resolver();
+    let scoped = DnsQuery::new("nas.home.lan", RecordType::A).with_view(Some(0));
+    assert!(resolver.try_cache(&scoped).is_none());
+}
+
+// ── file repository ───────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn file_repository_saves_and_reloads_zone() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("home.lan.zone");
+    std::fs::write(&path, HOME_ZONE).unwrap();
+
+    let repo = FileLocalZoneRepository::new(vec![LocalZoneConfig {
+        origin: "home.lan".to_string(),
+        file: path.display().to_string(),
+    }]);
+
+    let mut zone = repo.get("home.lan").await.unwrap().unwrap();
+    zone.bump_serial();
+    repo.save(&zone).await.unwrap();
+
+    let reloaded = repo.get("home.lan").await.unwrap().unwrap();
+    assert_eq!(reloaded.serial(), Some(2026101702));
+    assert_eq!(reloaded.records, zone.records);
+    assert!(repo.get("corp.lan").await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn file_repository_rejects_invalid_rdata() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("home.lan.zone");
+    std::fs::write(&path, HOME_ZONE).unwrap();
+
+    let repo = FileLocalZoneRepository::new(vec![LocalZoneConfig {
+        origin: "home.lan".to_string(),
+        file: path.display().to_string(),
+    }]);
+
+    let mut zone = repo.get("home.lan").await.unwrap().unwrap();
+    zone.records.push(ferrous_dns_domain::ZoneRecord::new(
+        "bad.home.lan",
+        300,
+        "A",
+        "not-an-address",
+    ));
+    let result = repo.save(&zone).await;
+
+    assert!(matches!(result, Err(DomainError::InvalidZoneRecord(_))));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), HOME_ZONE);
+}
@@ -9,7 +9,7 @@
 //! the configured `max_entries` is respected verbatim, with eviction kicking in
 //! only when that configured limit is actually reached.
 
-use ferrous_dns_domain::RecordType;
+use ferrous_dns_domain::{QueryScope, RecordType};
 use ferrous_dns_infrastructure::dns::cache::negative_cache::NegativeDnsCache;
 
 #[test]
@@ -35,7 +35,7 @@ fn should_evict_when_configured_limit_reached() {
 
     for i in 0..12 {
         let domain = format!("bad{i}.example.com");
-        cache.insert(&domain, RecordType::A, 600);
+        cache.insert(&domain, RecordType::A, QueryScope::SHARED, 600);
     }
 
     assert!(
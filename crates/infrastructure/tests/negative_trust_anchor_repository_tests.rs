@@ -0,0 +1,134 @@
+use ferrous_dns_application::ports::NegativeTrustAnchorRepository;
+use ferrous_dns_domain::DomainError;
+use ferrous_dns_infrastructure::repositories::negative_trust_anchor_repository::SqliteNegativeTrustAnchorRepository;
+use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
+
+async fn create_test_db() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::query(
+        "CREATE TABLE negative_trust_anchors (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            domain TEXT NOT NULL UNIQUE,
+            expires_at TEXT,
+            comment TEXT,
+            created_at TEXT NOT NULL
+        )",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    pool
+}
+
+#[tokio::test]
+async fn test_create_and_get_negative_trust_anchor() {
+    let repo = SqliteNegativeTrustAnchorRepository::new(create_test_db().await);
+
+    let created = repo
+        .create(
+            "broken.example".to_string(),
+            Some("2026-03-01 00:00:00".to_string()),
+            Some("Expired RRSIGs".to_string()),
+        )
+        .await
+        .unwrap();
+
+    assert!(created.id.is_some());
+    assert_eq!(created.domain.as_ref(), "broken.example");
+    assert_eq!(created.expires_at.as_deref(), Some("2026-03-01 00:00:00"));
+    assert_eq!(created.comment.as_deref(), Some("Expired RRSIGs"));
+    assert!(created.created_at.is_some());
+
+    let fetched = repo.get_by_id(created.id.unwrap()).await.unwrap().unwrap();
+    assert_eq!(fetched.domain, created.domain);
+    assert_eq!(fetched.expires_at, created.expires_at);
+}
+
+#[tokio::test]
+async fn test_duplicate_domain_is_rejected() {
+    let repo = SqliteNegativeTrustAnchorRepository::new(create_test_db().await);
+
+    repo.create("broken.example".to_string(), None, None)
+        .await
+        .unwrap();
+    let result = repo.create("broken.example".to_string(), None, None).await;
+
+    assert!(matches!(
+        result,
+        Err(DomainError::InvalidNegativeTrustAnchor(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_get_all_orders_by_domain() {
+    let repo = SqliteNegativeTrustAnchorRepository::new(create_test_db().await);
+
+    repo.create("zeta.example".to_string(), None, None)
+        .await
+        .unwrap();
+    repo.create("alpha.example".to_string(), None, None)
+        .await
+        .unwrap();
+
+    let all = repo.get_all().await.unwrap();
+    let domains: Vec<&str> = all.iter().map(|a| a.domain.as_ref()).collect();
+    assert_eq!(domains, vec!["alpha.example", "zeta.example"]);
+}
+
+#[tokio::test]
+async fn test_delete_negative_trust_anchor() {
+    let repo = SqliteNegativeTrustAnchorRepository::new(create_test_db().await);
+
+    let created = repo
+        .create("broken.example".to_string(), None, None)
+        .await
+        .unwrap();
+    let id = created.id.unwrap();
+
+    repo.delete(id).await.unwrap();
+    assert!(repo.get_by_id(id).await.unwrap().is_none());
+    assert!(matches!(
+        repo.delete(id).await,
+        Err(DomainError::NegativeTrustAnchorNotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_delete_expired_keeps_permanent_and_future_anchors() {
+    let repo = SqliteNegativeTrustAnchorRepository::new(create_test_db().await);
+
+    repo.create(
+        "old.example".to_string(),
+        Some("2026-01-01 00:00:00".to_string()),
+        None,
+    )
+    .await
+    .unwrap();
+    repo.create(
+        "later.example".to_string(),
+        Some("2026-06-01 00:00:00".to_string()),
+        None,
+    )
+    .await
+    .unwrap();
+    repo.create("forever.example".to_string(), None, None)
+        .await
+        .unwrap();
+
+    let deleted = repo.delete_expired("2026-03-01 00:00:00").await.unwrap();
+    assert_eq!(deleted, 1);
+
+    let remaining: Vec<String> = repo
+        .get_all()
+        .await
+        .unwrap()
+        .iter()
+        .map(|a| a.domain.to_string())
+        .collect();
+    assert_eq!(remaining, vec!["forever.example", "later.example"]);
+}
@@ -0,0 +1,97 @@
+use ferrous_dns_application::ports::NegativeTrustAnchorStore;
+use ferrous_dns_domain::{NegativeTrustAnchor, RecordType};
+use ferrous_dns_infrastructure::dns::dnssec::{
+    DnssecCache, NegativeTrustAnchorTable, ValidationResult,
+};
+use std::sync::Arc;
+
+fn anchor(domain: &str, expires_at: Option<&str>) -> NegativeTrustAnchor {
+    NegativeTrustAnchor::new(None, Arc::from(domain), expires_at.map(String::from), None)
+}
+
+// 2026-01-01 00:00:00 UTC
+const JAN_1: i64 = 1_767_225_600;
+
+#[test]
+fn empty_table_covers_nothing() {
+    let table = NegativeTrustAnchorTable::new();
+    assert!(table.is_empty());
+    assert!(!table.covers("broken.example"));
+}
+
+#[test]
+fn covers_exact_name_and_subdomains() {
+    let table = NegativeTrustAnchorTable::new();
+    table.replace_anchors(&[anchor("broken.example", None)]);
+
+    assert_eq!(table.len(), 1);
+    assert!(table.covers("broken.example"));
+    assert!(table.covers("www.broken.example"));
+    assert!(table.covers("a.b.broken.example."));
+    assert!(table.covers("WWW.Broken.Example"));
+    assert!(!table.covers("notbroken.example"));
+    assert!(!table.covers("example"));
+}
+
+#[test]
+fn expired_anchor_stops_applying() {
+    let table = NegativeTrustAnchorTable::new();
+    table.replace_anchors(&[anchor("broken.example", Some("2026-01-01 00:00:00"))]);
+
+    assert!(table.covers_at("broken.example", JAN_1 - 1));
+    assert!(!table.covers_at("broken.example", JAN_1));
+    assert!(!table.covers_at("www.broken.example", JAN_1 + 60));
+}
+
+#[test]
+fn unparseable_expiry_is_skipped() {
+    let table = NegativeTrustAnchorTable::new();
+    table.replace_anchors(&[
+        anchor("broken.example", Some("next tuesday")),
+        anchor("other.example", None),
+    ]);
+
+    assert_eq!(table.len(), 1);
+    assert!(!table.covers("broken.example"));
+    assert!(table.covers("other.example"));
+}
+
+#[test]
+fn replace_drops_previous_anchors() {
+    let table = NegativeTrustAnchorTable::new();
+    table.replace_anchors(&[anchor("broken.example", None)]);
+    table.replace_anchors(&[anchor("other.example", None)]);
+
+    assert!(!table.covers("broken.example"));
+    assert!(table.covers("other.example"));
+}
+
+#[test]
+fn dnssec_cache_reports_covered_names_as_insecure() {
+    let table = Arc::new(NegativeTrustAnchorTable::new());
+    let cache = DnssecCache::with_negative_anchors(table.clone());
+
+    cache.cache_validation(
+        "www.broken.example",
+        RecordType::A,
+        ValidationResult::Bogus,
+        300,
+    );
+    assert_eq!(
+        cache.get_validation("www.broken.example", RecordType::A),
+        Some(ValidationResult::Bogus)
+    );
+
+    table.replace_anchors(&[anchor("broken.example", None)]);
+    assert!(cache.is_negative_anchor("www.broken.example"));
+    assert_eq!(
+        cache.get_validation("www.broken.example", RecordType::A),
+        Some(ValidationResult::Insecure)
+    );
+
+    table.replace_anchors(&[]);
+    assert_eq!(
+        cache.get_validation("www.broken.example", RecordType::A),
+        Some(ValidationResult::Bogus)
+    );
+}
@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 use ferrous_dns_application::ports::{DnsResolution, DnsResolver};
-use ferrous_dns_domain::{DnsQuery, DomainError, RecordType};
+use ferrous_dns_domain::{DnsQuery, DomainError, QueryScope, RecordType};
 use ferrous_dns_infrastructure::dns::resolver::CachedResolver;
 use ferrous_dns_infrastructure::dns::{
     CachedData, DnsCache, DnsCacheAccess, DnsCacheConfig, EvictionStrategy, NegativeQueryTracker,
@@ -95,6 +95,7 @@ async fn test_soa_ttl_used_when_present() {
     let query = DnsQuery {
         domain: Arc::from("nxdomain.example.com"),
         record_type: RecordType::A,
+        scope: QueryScope::SHARED,
     };
     let _ = resolver.resolve(&query).await;
 
@@ -126,6 +127,7 @@ async fn test_soa_ttl_below_min_clamped_to_300() {
     let query = DnsQuery {
         domain: Arc::from("low-ttl.example.com"),
         record_type: RecordType::A,
+        scope: QueryScope::SHARED,
     };
     let _ = resolver.resolve(&query).await;
 
@@ -155,6 +157,7 @@ async fn test_soa_ttl_above_max_clamped_to_3600() {
     let query = DnsQuery {
         domain: Arc::from("high-ttl.example.com"),
         record_type: RecordType::A,
+        scope: QueryScope::SHARED,
     };
     let _ = resolver.resolve(&query).await;
 
@@ -185,6 +188,7 @@ async fn test_fallback_to_tracker_when_no_soa() {
     let query = DnsQuery {
         domain: Arc::from("no-soa.example.com"),
         record_type: RecordType::A,
+        scope: QueryScope::SHARED,
     };
     let _ = resolver.resolve(&query).await;
 
@@ -1,8 +1,12 @@
 use ferrous_dns_application::ports::{QueryLogRepository, TimeGranularity};
 use ferrous_dns_domain::config::DatabaseConfig;
-use ferrous_dns_domain::{QueryCategory, QueryLogFilter};
+use ferrous_dns_domain::{
+    BlockSource, QueryCategory, QueryLog, QueryLogFilter, QuerySource, RecordType,
+};
 use ferrous_dns_infrastructure::repositories::query_log_repository::SqliteQueryLogRepository;
 use sqlx::sqlite::SqlitePoolOptions;
+use std::sync::Arc;
+use std::time::Duration;
 
 async fn create_test_db() -> sqlx::SqlitePool {
     let pool = SqlitePoolOptions::new()
@@ -28,6 +32,7 @@ async fn create_test_db() -> sqlx::SqlitePool {
             query_source TEXT NOT NULL DEFAULT 'client',
             group_id INTEGER,
             block_source TEXT,
+            blocklist_source_ids TEXT,
             created_at DATETIME NOT NULL DEFAULT (datetime('now'))
         )
         "#,
@@ -58,6 +63,34 @@ async fn create_test_db() -> sqlx::SqlitePool {
     .await
     .unwrap();
 
+    sqlx::query(
+        r#"
+        CREATE TABLE blocklist_sources (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL UNIQUE,
+            enabled BOOLEAN NOT NULL DEFAULT 1
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE blocklist_source_hits (
+            source_id INTEGER NOT NULL,
+            hour INTEGER NOT NULL,
+            domain TEXT NOT NULL,
+            hits INTEGER NOT NULL DEFAULT 0,
+            PRIMARY KEY (source_id, hour, domain)
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
     pool
 }
 
@@ -1191,3 +1224,122 @@ async fn test_cursor_pagination_with_client_filter() {
     let ids2: Vec<_> = page2.queries.iter().filter_map(|q| q.id).collect();
     assert!(ids1.iter().all(|id| !ids2.contains(id)));
 }
+
+fn blocked_query(domain: &str, source_ids: Vec<i64>) -> QueryLog {
+    QueryLog {
+        id: None,
+        domain: Arc::from(domain),
+        record_type: RecordType::A,
+        client_ip: "192.168.1.10".parse().unwrap(),
+        client_hostname: None,
+        blocked: true,
+        response_time_us: Some(10),
+        cache_hit: false,
+        cache_refresh: false,
+        dnssec_status: None,
+        upstream_server: None,
+        upstream_pool: None,
+        response_status: Some("BLOCKED"),
+        timestamp: None,
+        query_source: QuerySource::Client,
+        group_id: Some(1),
+        block_source: Some(BlockSource::Blocklist),
+        blocklist_source_ids: source_ids,
+    }
+}
+
+fn fast_flush_config() -> DatabaseConfig {
+    DatabaseConfig {
+        query_log_flush_interval_ms: 10,
+        ..DatabaseConfig::default()
+    }
+}
+
+async fn insert_source(pool: &sqlx::SqlitePool, name: &str) -> i64 {
+    sqlx::query("INSERT INTO blocklist_sources (name) VALUES (?)")
+        .bind(name)
+        .execute(pool)
+        .await
+        .unwrap()
+        .last_insert_rowid()
+}
+
+#[tokio::test]
+async fn test_blocked_queries_record_source_ids_and_hits() {
+    let pool = create_test_db().await;
+    let first = insert_source(&pool, "first").await;
+    let second = insert_source(&pool, "second").await;
+    let unused = insert_source(&pool, "unused").await;
+    let repo = SqliteQueryLogRepository::new(
+        pool.clone(),
+        pool.clone(),
+        pool.clone(),
+        &fast_flush_config(),
+    );
+
+    repo.log_query(&blocked_query("ads.example", vec![first, second]))
+        .await
+        .unwrap();
+    repo.log_query(&blocked_query("ads.example", vec![first]))
+        .await
+        .unwrap();
+    repo.log_query(&blocked_query("tracker.example", vec![first]))
+        .await
+        .unwrap();
+
+    let mut logged = Vec::new();
+    for _ in 0..100 {
+        logged = repo.get_recent(10, 1.0).await.unwrap();
+        if logged.len() == 3 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert_eq!(logged.len(), 3);
+    let mut ids: Vec<Vec<i64>> = logged.into_iter().map(|q| q.blocklist_source_ids).collect();
+    ids.sort();
+    assert_eq!(ids, vec![vec![first], vec![first], vec![first, second]]);
+
+    let hits = repo.get_blocklist_source_hits().await.unwrap();
+    assert_eq!(hits.len(), 3);
+    assert_eq!(hits[0].source_id, first);
+    assert_eq!(hits[0].hits_24h, 3);
+    assert_eq!(hits[0].unique_domains_24h, 2);
+    assert_eq!(hits[1].source_id, second);
+    assert_eq!(hits[1].hits_30d, 1);
+    assert_eq!(hits[1].unique_domains_30d, 1);
+    assert_eq!(hits[2].source_id, unused);
+    assert_eq!(hits[2].hits_30d, 0);
+}
+
+#[tokio::test]
+async fn test_retention_prunes_source_hits_older_than_30_days() {
+    let pool = create_test_db().await;
+    let source = insert_source(&pool, "list").await;
+    let hour = chrono::Utc::now().timestamp() / 3600;
+    for (hours_ago, domain) in [(24 * 31, "old.example"), (24 * 29, "recent.example")] {
+        sqlx::query(
+            "INSERT INTO blocklist_source_hits (source_id, hour, domain, hits) VALUES (?, ?, ?, 1)",
+        )
+        .bind(source)
+        .bind(hour - hours_ago)
+        .bind(domain)
+        .execute(&pool)
+        .await
+        .unwrap();
+    }
+    let repo = SqliteQueryLogRepository::new(
+        pool.clone(),
+        pool.clone(),
+        pool.clone(),
+        &DatabaseConfig::default(),
+    );
+
+    repo.delete_older_than(7).await.unwrap();
+
+    let remaining: Vec<String> = sqlx::query_scalar("SELECT domain FROM blocklist_source_hits")
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+    assert_eq!(remaining, vec!["recent.example".to_string()]);
+}
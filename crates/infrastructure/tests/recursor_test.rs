@@ -0,0 +1,614 @@
+use ferrous_dns_domain::{QueryScope, RecordType, RecursorConfig, UpstreamPool, UpstreamStrategy};
+use ferrous_dns_infrastructure::dns::events::QueryEventEmitter;
+use ferrous_dns_infrastructure::dns::recursor::{randomize_case, root_hint_endpoints, Recursor};
+use ferrous_dns_infrastructure::dns::PoolManager;
+use hickory_proto::op::{Message, MessageType, OpCode, ResponseCode};
+use hickory_proto::rr::rdata::{A, CNAME, NS, SOA};
+use hickory_proto::rr::{Name, RData, Record, RecordType as HickoryRecordType};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use tokio::net::UdpSocket;
+
+// ── Stand-in authoritative servers ───────────────────────────────────────────
+
+fn name(s: &str) -> Name {
+    Name::from_str(s).unwrap()
+}
+
+/// One zone served by a loopback stand-in: its own records plus child
+/// delegations (child apex, nameserver name, optional glue).
+#[derive(Clone)]
+struct Zone {
+    apex: Name,
+    records: Vec<Record>,
+    delegations: Vec<(Name, Name, Option<Ipv4Addr>)>,
+    extra_answers: Vec<Record>,
+    mangle_case: bool,
+}
+
+impl Zone {
+    fn new(apex: &str) -> Self {
+        Self {
+            apex: name(apex),
+            records: Vec::new(),
+            delegations: Vec::new(),
+            extra_answers: Vec::new(),
+            mangle_case: false,
+        }
+    }
+
+    fn a(mut self, owner: &str, ip: [u8; 4]) -> Self {
+        self.records.push(Record::from_rdata(
+            name(owner),
+            300,
+            RData::A(A(Ipv4Addr::from(ip))),
+        ));
+        self
+    }
+
+    fn cname(mut self, owner: &str, target: &str) -> Self {
+        self.records.push(Record::from_rdata(
+            name(owner),
+            300,
+            RData::CNAME(CNAME(name(target))),
+        ));
+        self
+    }
+
+    fn delegate(mut self, child: &str, ns: &str, glue: Option<[u8; 4]>) -> Self {
+        self.delegations
+            .push((name(child), name(ns), glue.map(Ipv4Addr::from)));
+        self
+    }
+
+    /// Appends an A record for `owner` to every answer, wherever it lives,
+    /// like a server trying to poison the resolver's cache.
+    fn inject(mut self, owner: &str, ip: [u8; 4]) -> Self {
+        self.extra_answers.push(Record::from_rdata(
+            name(owner),
+            300,
+            RData::A(A(Ipv4Addr::from(ip))),
+        ));
+        self
+    }
+
+    /// Answers with a lowercased question, like a server that ignores 0x20.
+    fn mangle_case(mut self) -> Self {
+        self.mangle_case = true;
+        self
+    }
+
+    fn soa(&self) -> Record {
+        Record::from_rdata(
+            self.apex.clone(),
+            60,
+            RData::SOA(SOA::new(
+                self.apex.clone(),
+                name("hostmaster.invalid."),
+                1,
+                3600,
+                600,
+                86400,
+                60,
+            )),
+        )
+    }
+
+    fn respond(&self, query: &Message) -> Message {
+        let q = query.queries()[0].clone();
+        let qname = q.name().clone();
+        let mut response = Message::new(query.id(), MessageType::Response, OpCode::Query);
+        let mut echoed = q.clone();
+        if self.mangle_case {
+            echoed.set_name(qname.to_lowercase());
+        }
+        response.add_query(echoed);
+
+        if let Some((child, _, _)) = self
+            .delegations
+            .iter()
+            .find(|(child, _, _)| child.zone_of(&qname))
+        {
+            for (_, ns, glue) in self.delegations.iter().filter(|(c, _, _)| c == child) {
+                response.add_name_server(Record::from_rdata(
+                    child.clone(),
+                    3600,
+                    RData::NS(NS(ns.clone())),
+                ));
+                if let Some(ip) = glue {
+                    response.add_additional(Record::from_rdata(ns.clone(), 3600, RData::A(A(*ip))));
+                }
+            }
+            return response;
+        }
+
+        response.set_authoritative(true);
+        let at_name: Vec<&Record> = self.records.iter().filter(|r| r.name() == &qname).collect();
+        let answers: Vec<Record> = at_name
+            .iter()
+            .filter(|r| {
+                r.record_type() == q.query_type() || r.record_type() == HickoryRecordType::CNAME
+            })
+            .map(|r| {
+                let mut r = (*r).clone();
+                r.set_name(qname.clone());
+                r
+            })
+            .collect();
+        if !answers.is_empty() {
+            response.add_answers(answers);
+            response.add_answers(self.extra_answers.clone());
+        } else {
+            let exists = self.records.iter().any(|r| qname.zone_of(r.name())) || qname == self.apex;
+            if !exists {
+                response.set_response_code(ResponseCode::NXDomain);
+            }
+            response.add_name_server(self.soa());
+        }
+        response
+    }
+}
+
+struct StandIn {
+    seen: Arc<Mutex<Vec<(String, HickoryRecordType)>>>,
+}
+
+impl StandIn {
+    async fn start(addr: SocketAddr, zone: Zone) -> Self {
+        let socket = UdpSocket::bind(addr).await.unwrap();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&seen);
+        tokio::spawn(async move {
+            let mut buf = [0u8; 4096];
+            while let Ok((len, peer)) = socket.recv_from(&mut buf).await {
+                let Ok(query) = Message::from_vec(&buf[..len]) else {
+                    continue;
+                };
+                let q = &query.queries()[0];
+                log.lock()
+                    .unwrap()
+                    .push((q.name().to_ascii(), q.query_type()));
+                let bytes = zone.respond(&query).to_vec().unwrap();
+                let _ = socket.send_to(&bytes, peer).await;
+            }
+        });
+        Self { seen }
+    }
+
+    fn queries(&self) -> Vec<(String, HickoryRecordType)> {
+        self.seen.lock().unwrap().clone()
+    }
+
+    fn names(&self) -> Vec<String> {
+        self.queries()
+            .into_iter()
+            .map(|(n, _)| n.to_ascii_lowercase())
+            .collect()
+    }
+}
+
+/// A small tree on loopback, every server on the same port:
+///
+/// - 127.0.0.1  root        → delegates `com.` and `net.`
+/// - 127.0.0.2  com.        → delegates `example.com.` (with glue) and
+///   `glueless.com.` (to `ns.example.net.`, without glue)
+/// - 127.0.0.3  example.com.
+/// - 127.0.0.4  net.        → delegates `example.net.`
+/// - 127.0.0.5  example.net. and glueless.com.
+struct Tree {
+    port: u16,
+    root: StandIn,
+    com: StandIn,
+    example_com: StandIn,
+}
+
+impl Tree {
+    async fn start() -> Self {
+        Self::start_with(|zone| zone).await
+    }
+
+    async fn start_with(customize_leaf: impl Fn(Zone) -> Zone) -> Self {
+        let probe = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let port = probe.local_addr().unwrap().port();
+        drop(probe);
+        let at = |last: u8| SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, last)), port);
+
+        let root = Zone::new(".")
+            .delegate("com.", "a.gtld.test.", Some([127, 0, 0, 2]))
+            .delegate("net.", "b.gtld.test.", Some([127, 0, 0, 4]));
+        let com = Zone::new("com.")
+            .delegate("example.com.", "ns1.example.com.", Some([127, 0, 0, 3]))
+            .delegate("glueless.com.", "ns.example.net.", None);
+        let example_com = customize_leaf(
+            Zone::new("example.com.")
+                .a("www.example.com.", [192, 0, 2, 10])
+                .a("other.example.com.", [192, 0, 2, 11])
+                .a("ns1.example.com.", [127, 0, 0, 3])
+                .a("host.deep.sub.example.com.", [192, 0, 2, 12])
+                .cname("alias.example.com.", "web.example.net."),
+        );
+        let net =
+            Zone::new("net.").delegate("example.net.", "ns.example.net.", Some([127, 0, 0, 5]));
+        let example_net = Zone::new("example.net.")
+            .a("web.example.net.", [198, 51, 100, 7])
+            .a("ns.example.net.", [127, 0, 0, 5]);
+
+        let root = StandIn::start(at(1), root).await;
+        let com = StandIn::start(at(2), com).await;
+        let example_com = StandIn::start(at(3), example_com).await;
+        let _net = StandIn::start(at(4), net).await;
+        // example.net. and glueless.com. share a server, as shared hosting does.
+        let merged = Zone {
+            records: {
+                let mut r = example_net.records.clone();
+                r.push(Record::from_rdata(
+                    name("www.glueless.com."),
+                    300,
+                    RData::A(A(Ipv4Addr::new(203, 0, 113, 5))),
+                ));
+                r
+            },
+            ..example_net
+        };
+        let _example_net = StandIn::start(at(5), merged).await;
+
+        Self {
+            port,
+            root,
+            com,
+            example_com,
+        }
+    }
+
+    fn roots(&self) -> Vec<SocketAddr> {
+        vec![SocketAddr::from(([127, 0, 0, 1], self.port))]
+    }
+
+    fn recursor(&self, config: RecursorConfig) -> Recursor {
+        Recursor::new(config).with_nameserver_port(self.port)
+    }
+}
+
+fn addresses(answer: &ferrous_dns_infrastructure::dns::recursor::RecursiveAnswer) -> Vec<IpAddr> {
+    answer.response.addresses.clone()
+}
+
+// ── Tests ────────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_resolves_through_delegations() {
+    let tree = Tree::start().await;
+    let recursor = tree.recursor(RecursorConfig::default());
+
+    let answer = recursor
+        .resolve(
+            &tree.roots(),
+            "www.example.com",
+            &RecordType::A,
+            false,
+            3000,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(addresses(&answer), vec![IpAddr::from([192, 0, 2, 10])]);
+    assert_eq!(answer.response.rcode, ResponseCode::NoError);
+    assert_eq!(answer.server.ip(), IpAddr::from([127, 0, 0, 3]));
+}
+
+#[tokio::test]
+async fn test_qname_minimisation_hides_full_name_from_parents() {
+    let tree = Tree::start().await;
+    let recursor = tree.recursor(RecursorConfig::default());
+
+    recursor
+        .resolve(
+            &tree.roots(),
+            "host.deep.sub.example.com",
+            &RecordType::A,
+            false,
+            3000,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(tree.root.names(), vec!["com."]);
+    assert_eq!(tree.com.names(), vec!["example.com."]);
+    assert!(tree
+        .root
+        .queries()
+        .iter()
+        .all(|(_, qtype)| *qtype == HickoryRecordType::A));
+    // The leaf zone sees the labels revealed one at a time.
+    assert_eq!(
+        tree.example_com.names(),
+        vec![
+            "sub.example.com.",
+            "deep.sub.example.com.",
+            "host.deep.sub.example.com."
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_without_minimisation_full_name_goes_to_root() {
+    let tree = Tree::start().await;
+    let recursor = tree.recursor(RecursorConfig {
+        qname_minimization: false,
+        ..RecursorConfig::default()
+    });
+
+    recursor
+        .resolve(
+            &tree.roots(),
+            "www.example.com",
+            &RecordType::A,
+            false,
+            3000,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(tree.root.names(), vec!["www.example.com."]);
+}
+
+#[tokio::test]
+async fn test_nxdomain_is_returned_with_soa() {
+    let tree = Tree::start().await;
+    let recursor = tree.recursor(RecursorConfig::default());
+
+    let answer = recursor
+        .resolve(
+            &tree.roots(),
+            "missing.example.com",
+            &RecordType::A,
+            false,
+            3000,
+        )
+        .await
+        .unwrap();
+
+    assert!(answer.response.is_nxdomain());
+    assert_eq!(answer.response.negative_soa_ttl, Some(60));
+}
+
+#[tokio::test]
+async fn test_follows_cname_into_another_zone() {
+    let tree = Tree::start().await;
+    let recursor = tree.recursor(RecursorConfig::default());
+
+    let answer = recursor
+        .resolve(
+            &tree.roots(),
+            "alias.example.com",
+            &RecordType::A,
+            false,
+            3000,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(answer.response.cname_chain.len(), 1);
+    assert_eq!(addresses(&answer), vec![IpAddr::from([198, 51, 100, 7])]);
+}
+
+#[tokio::test]
+async fn test_out_of_zone_answers_are_not_trusted() {
+    let tree = Tree::start_with(|zone| zone.inject("web.example.net.", [6, 6, 6, 6])).await;
+    let recursor = tree.recursor(RecursorConfig::default());
+
+    let answer = recursor
+        .resolve(
+            &tree.roots(),
+            "alias.example.com",
+            &RecordType::A,
+            false,
+            3000,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(addresses(&answer), vec![IpAddr::from([198, 51, 100, 7])]);
+    assert_eq!(answer.server.ip(), IpAddr::from([127, 0, 0, 5]));
+}
+
+#[tokio::test]
+async fn test_resolves_glueless_nameserver() {
+    let tree = Tree::start().await;
+    let recursor = tree.recursor(RecursorConfig::default());
+
+    let answer = recursor
+        .resolve(
+            &tree.roots(),
+            "www.glueless.com",
+            &RecordType::A,
+            false,
+            3000,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(addresses(&answer), vec![IpAddr::from([203, 0, 113, 5])]);
+}
+
+#[tokio::test]
+async fn test_ns_cache_skips_upper_levels() {
+    let tree = Tree::start().await;
+    let recursor = tree.recursor(RecursorConfig::default());
+
+    recursor
+        .resolve(
+            &tree.roots(),
+            "www.example.com",
+            &RecordType::A,
+            false,
+            3000,
+        )
+        .await
+        .unwrap();
+    let root_queries = tree.root.queries().len();
+    let com_queries = tree.com.queries().len();
+    assert!(!recursor.ns_cache().is_empty());
+
+    let answer = recursor
+        .resolve(
+            &tree.roots(),
+            "other.example.com",
+            &RecordType::A,
+            false,
+            3000,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(addresses(&answer), vec![IpAddr::from([192, 0, 2, 11])]);
+    assert_eq!(tree.root.queries().len(), root_queries);
+    assert_eq!(tree.com.queries().len(), com_queries);
+}
+
+#[tokio::test]
+async fn test_case_randomisation_reaches_servers() {
+    let tree = Tree::start().await;
+    let recursor = tree.recursor(RecursorConfig {
+        qname_minimization: false,
+        ..RecursorConfig::default()
+    });
+
+    for _ in 0..8 {
+        recursor.ns_cache().clear();
+        recursor
+            .resolve(
+                &tree.roots(),
+                "www.example.com",
+                &RecordType::A,
+                false,
+                3000,
+            )
+            .await
+            .unwrap();
+    }
+
+    let sent: Vec<String> = tree.root.queries().into_iter().map(|(n, _)| n).collect();
+    assert!(
+        sent.iter().any(|n| n != &n.to_ascii_lowercase()),
+        "expected mixed case in {sent:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_case_mismatch_is_rejected() {
+    let tree = Tree::start_with(Zone::mangle_case).await;
+    let recursor = tree.recursor(RecursorConfig::default());
+
+    // A lowercased echo only passes when no letter was flipped, so retry
+    // until a flipped name was sent at least once.
+    let mut rejected = false;
+    for _ in 0..16 {
+        recursor.ns_cache().clear();
+        if recursor
+            .resolve(
+                &tree.roots(),
+                "www.example.com",
+                &RecordType::A,
+                false,
+                3000,
+            )
+            .await
+            .is_err()
+        {
+            rejected = true;
+            break;
+        }
+    }
+    assert!(rejected);
+}
+
+#[tokio::test]
+async fn test_case_mismatch_is_accepted_when_disabled() {
+    let tree = Tree::start_with(Zone::mangle_case).await;
+    let recursor = tree.recursor(RecursorConfig {
+        case_randomization: false,
+        ..RecursorConfig::default()
+    });
+
+    let answer = recursor
+        .resolve(
+            &tree.roots(),
+            "www.example.com",
+            &RecordType::A,
+            false,
+            3000,
+        )
+        .await
+        .unwrap();
+    assert_eq!(addresses(&answer), vec![IpAddr::from([192, 0, 2, 10])]);
+}
+
+#[tokio::test]
+async fn test_query_budget_is_enforced() {
+    let tree = Tree::start().await;
+    let recursor = tree.recursor(RecursorConfig {
+        max_queries: 2,
+        ..RecursorConfig::default()
+    });
+
+    let result = recursor
+        .resolve(
+            &tree.roots(),
+            "host.deep.sub.example.com",
+            &RecordType::A,
+            false,
+            3000,
+        )
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_recursive_pool_strategy() {
+    let tree = Tree::start().await;
+    let recursor = Arc::new(tree.recursor(RecursorConfig::default()));
+    let pool = UpstreamPool {
+        name: "recursive".to_string(),
+        strategy: UpstreamStrategy::Recursive,
+        priority: 1,
+        servers: vec![format!("udp://127.0.0.1:{}", tree.port)],
+        weight: None,
+    };
+    let manager = PoolManager::new(vec![pool], None, QueryEventEmitter::new_disabled())
+        .await
+        .unwrap()
+        .with_recursor(recursor);
+
+    let domain: Arc<str> = Arc::from("www.example.com");
+    let result = manager
+        .query(&domain, &RecordType::A, 3000, false, QueryScope::SHARED)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result.response.addresses,
+        vec![IpAddr::from([192, 0, 2, 10])]
+    );
+    assert_eq!(result.pool_name.as_ref(), "recursive");
+}
+
+// ── Helpers without network ──────────────────────────────────────────────────
+
+#[test]
+fn test_randomize_case_preserves_name() {
+    let original = name("www.example.com.");
+    for _ in 0..32 {
+        let mixed = randomize_case(&original);
+        assert_eq!(mixed, original);
+    }
+}
+
+#[test]
+fn test_root_hints_cover_all_root_servers() {
+    let hints = root_hint_endpoints();
+    assert_eq!(hints.len(), 26);
+    assert_eq!(hints[0], "udp://198.41.0.4:53");
+    assert!(hints.iter().all(|h| h.starts_with("udp://")));
+}
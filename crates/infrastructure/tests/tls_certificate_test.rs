@@ -0,0 +1,291 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use ferrous_dns_application::ports::{TlsCertificatePort, TlsCertificateReloader};
+use ferrous_dns_infrastructure::tls::acme::AccountKey;
+use ferrous_dns_infrastructure::tls::{
+    AcmeHttpChallenges, CertificateResolvers, TlsCertificateService,
+};
+use ring::signature::{UnparsedPublicKey, ECDSA_P256_SHA256_FIXED};
+use rustls::pki_types::{CertificateDer, ServerName};
+use serde_json::json;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tokio_rustls::{TlsAcceptor, TlsConnector};
+
+struct Issued {
+    cert_pem: String,
+    key_pem: String,
+    der: CertificateDer<'static>,
+}
+
+fn issue(name: &str) -> Issued {
+    let key = rcgen::KeyPair::generate().unwrap();
+    let cert = rcgen::CertificateParams::new(vec![name.to_string()])
+        .unwrap()
+        .self_signed(&key)
+        .unwrap();
+    Issued {
+        cert_pem: cert.pem(),
+        key_pem: key.serialize_pem(),
+        der: cert.der().clone(),
+    }
+}
+
+fn write(dir: &Path, issued: &Issued) -> (String, String) {
+    let cert_path = dir.join("cert.pem");
+    let key_path = dir.join("key.pem");
+    std::fs::write(&cert_path, &issued.cert_pem).unwrap();
+    std::fs::write(&key_path, &issued.key_pem).unwrap();
+    (
+        cert_path.to_string_lossy().into_owned(),
+        key_path.to_string_lossy().into_owned(),
+    )
+}
+
+/// Rewrites `path` with a modification time in the future, so the change
+/// is seen even on filesystems with coarse timestamps.
+fn rewrite(path: &str, contents: &str, age: u64) {
+    std::fs::write(path, contents).unwrap();
+    std::fs::File::options()
+        .write(true)
+        .open(path)
+        .unwrap()
+        .set_modified(SystemTime::now() + Duration::from_secs(age))
+        .unwrap();
+}
+
+/// Performs a handshake against `server` and returns the certificate it
+/// presented.
+async fn handshake(
+    server: Arc<rustls::ServerConfig>,
+    trusted: &CertificateDer<'static>,
+) -> CertificateDer<'static> {
+    let mut roots = rustls::RootCertStore::empty();
+    roots.add(trusted.clone()).unwrap();
+    let client = rustls::ClientConfig::builder()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+
+    let (client_io, server_io) = tokio::io::duplex(16 * 1024);
+    let acceptor = TlsAcceptor::from(server);
+    let server_task = tokio::spawn(async move { acceptor.accept(server_io).await.map(|_| ()) });
+
+    let stream = TlsConnector::from(Arc::new(client))
+        .connect(ServerName::try_from("dns.example.com").unwrap(), client_io)
+        .await
+        .unwrap();
+    server_task.await.unwrap().unwrap();
+
+    stream.get_ref().1.peer_certificates().unwrap()[0].clone()
+}
+
+// ── certificate resolver ──────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn reload_swaps_the_certificate_for_new_handshakes() {
+    let dir = tempfile::tempdir().unwrap();
+    let first = issue("dns.example.com");
+    let (cert_path, key_path) = write(dir.path(), &first);
+
+    let resolvers = CertificateResolvers::new();
+    let resolver = resolvers.resolver(&cert_path, &key_path);
+    resolver.reload().unwrap();
+    let server = resolver.server_config();
+    assert_eq!(handshake(server.clone(), &first.der).await, first.der);
+
+    let second = issue("dns.example.com");
+    write(dir.path(), &second);
+    resolvers.reload(&cert_path, &key_path).unwrap();
+
+    assert_eq!(handshake(server, &second.der).await, second.der);
+}
+
+#[test]
+fn listeners_on_the_same_files_share_a_resolver() {
+    let resolvers = CertificateResolvers::new();
+    let a = resolvers.resolver("/data/cert.pem", "/data/key.pem");
+    let b = resolvers.resolver("/data/cert.pem", "/data/key.pem");
+    let c = resolvers.resolver("/data/web.pem", "/data/web.key");
+
+    assert!(Arc::ptr_eq(&a, &b));
+    assert!(!Arc::ptr_eq(&a, &c));
+    assert_eq!(resolvers.all().len(), 2);
+    assert!(!a.is_loaded());
+}
+
+#[tokio::test]
+async fn mismatched_key_is_rejected_and_the_old_pair_kept() {
+    let dir = tempfile::tempdir().unwrap();
+    let good = issue("dns.example.com");
+    let (cert_path, key_path) = write(dir.path(), &good);
+    let resolvers = CertificateResolvers::new();
+    let resolver = resolvers.resolver(&cert_path, &key_path);
+    resolver.reload().unwrap();
+
+    let other = issue("dns.example.com");
+    std::fs::write(&key_path, &other.key_pem).unwrap();
+    let err = resolvers.reload(&cert_path, &key_path).unwrap_err();
+
+    assert!(err.to_string().contains("does not match"), "{err}");
+    assert_eq!(
+        handshake(resolver.server_config(), &good.der).await,
+        good.der
+    );
+}
+
+#[tokio::test]
+async fn changed_files_are_picked_up_by_the_watcher() {
+    let dir = tempfile::tempdir().unwrap();
+    let first = issue("dns.example.com");
+    let (cert_path, key_path) = write(dir.path(), &first);
+    let resolvers = CertificateResolvers::new();
+    let resolver = resolvers.resolver(&cert_path, &key_path);
+    resolver.reload().unwrap();
+
+    assert_eq!(resolvers.reload_changed(), 0);
+
+    let renewed = issue("dns.example.com");
+    rewrite(&cert_path, &renewed.cert_pem, 10);
+    rewrite(&key_path, &renewed.key_pem, 10);
+
+    assert_eq!(resolvers.reload_changed(), 1);
+    assert_eq!(resolvers.reload_changed(), 0);
+    assert_eq!(
+        handshake(resolver.server_config(), &renewed.der).await,
+        renewed.der
+    );
+}
+
+#[tokio::test]
+async fn watcher_keeps_serving_when_only_half_the_pair_changed() {
+    let dir = tempfile::tempdir().unwrap();
+    let first = issue("dns.example.com");
+    let (cert_path, key_path) = write(dir.path(), &first);
+    let resolvers = CertificateResolvers::new();
+    let resolver = resolvers.resolver(&cert_path, &key_path);
+    resolver.reload().unwrap();
+    let served = resolvers.active_fingerprint(&cert_path, &key_path);
+
+    let renewed = issue("dns.example.com");
+    rewrite(&cert_path, &renewed.cert_pem, 10);
+    assert_eq!(resolvers.reload_changed(), 0);
+    assert_eq!(resolvers.active_fingerprint(&cert_path, &key_path), served);
+    assert_eq!(
+        handshake(resolver.server_config(), &first.der).await,
+        first.der
+    );
+
+    rewrite(&key_path, &renewed.key_pem, 20);
+    assert_eq!(resolvers.reload_changed(), 1);
+    assert_ne!(resolvers.active_fingerprint(&cert_path, &key_path), served);
+}
+
+#[tokio::test]
+async fn save_rejects_a_key_from_another_certificate() {
+    let dir = tempfile::tempdir().unwrap();
+    let cert_path = dir.path().join("cert.pem");
+    let key_path = dir.path().join("key.pem");
+    let cert = issue("dns.example.com");
+    let other = issue("dns.example.com");
+
+    let err = TlsCertificateService
+        .save_certificates(
+            cert.cert_pem.as_bytes(),
+            other.key_pem.as_bytes(),
+            cert_path.to_str().unwrap(),
+            key_path.to_str().unwrap(),
+        )
+        .await
+        .unwrap_err();
+
+    assert!(err.to_string().contains("does not match"), "{err}");
+    assert!(!cert_path.exists());
+    assert!(!key_path.exists());
+}
+
+#[tokio::test]
+async fn status_fingerprint_matches_the_served_certificate() {
+    let dir = tempfile::tempdir().unwrap();
+    let issued = issue("dns.example.com");
+    let (cert_path, key_path) = write(dir.path(), &issued);
+    let resolvers = CertificateResolvers::new();
+    assert!(resolvers
+        .active_fingerprint(&cert_path, &key_path)
+        .is_none());
+    assert!(!resolvers.reload(&cert_path, &key_path).unwrap());
+
+    resolvers.resolver(&cert_path, &key_path);
+    assert!(resolvers.reload(&cert_path, &key_path).unwrap());
+
+    let status = TlsCertificateService
+        .get_status(&cert_path, &key_path)
+        .await;
+    let fingerprint = status.cert_fingerprint_sha256.unwrap();
+    assert_eq!(fingerprint.len(), 32 * 3 - 1);
+    assert_eq!(
+        resolvers.active_fingerprint(&cert_path, &key_path),
+        Some(fingerprint)
+    );
+    assert_eq!(status.cert_dns_names, vec!["dns.example.com"]);
+    assert!(status.cert_not_after_unix.is_some());
+}
+
+// ── ACME account ──────────────────────────────────────────────────────────────
+
+#[test]
+fn account_key_signs_verifiable_es256_jws() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("account.pem");
+    let key = AccountKey::load_or_create(path.to_str().unwrap()).unwrap();
+
+    let protected = json!({ "alg": "ES256", "nonce": "abc", "url": "https://ca/new-order" });
+    let jws = key.sign(&protected, r#"{"identifiers":[]}"#).unwrap();
+
+    let signing_input = format!(
+        "{}.{}",
+        jws["protected"].as_str().unwrap(),
+        jws["payload"].as_str().unwrap()
+    );
+    let signature = URL_SAFE_NO_PAD
+        .decode(jws["signature"].as_str().unwrap())
+        .unwrap();
+    assert_eq!(signature.len(), 64);
+
+    let jwk = key.jwk();
+    let mut point = vec![0x04];
+    point.extend(URL_SAFE_NO_PAD.decode(jwk["x"].as_str().unwrap()).unwrap());
+    point.extend(URL_SAFE_NO_PAD.decode(jwk["y"].as_str().unwrap()).unwrap());
+    UnparsedPublicKey::new(&ECDSA_P256_SHA256_FIXED, point)
+        .verify(signing_input.as_bytes(), &signature)
+        .unwrap();
+}
+
+#[test]
+fn account_key_is_created_once_and_reloaded() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("acme").join("account.pem");
+    let path = path.to_str().unwrap();
+
+    let created = AccountKey::load_or_create(path).unwrap();
+    let loaded = AccountKey::load_or_create(path).unwrap();
+
+    assert_eq!(created.thumbprint(), loaded.thumbprint());
+    assert_eq!(created.thumbprint().len(), 43);
+    assert_eq!(
+        loaded.key_authorization("tok"),
+        format!("tok.{}", created.thumbprint())
+    );
+}
+
+#[test]
+fn http01_tokens_are_served_until_withdrawn() {
+    let challenges = AcmeHttpChallenges::new();
+    challenges.publish("tok", "tok.thumb");
+
+    assert_eq!(challenges.get("tok").as_deref(), Some("tok.thumb"));
+    assert!(challenges.get("other").is_none());
+
+    challenges.withdraw("tok");
+    assert!(challenges.is_empty());
+}
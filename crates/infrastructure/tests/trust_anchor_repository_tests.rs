@@ -0,0 +1,158 @@
+use ferrous_dns_application::ports::TrustAnchorRepository;
+use ferrous_dns_domain::{
+    DomainError, TrustAnchor, TrustAnchorData, TrustAnchorSource, TrustAnchorState,
+};
+use ferrous_dns_infrastructure::repositories::trust_anchor_repository::SqliteTrustAnchorRepository;
+use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
+use std::sync::Arc;
+
+async fn create_test_db() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::query(
+        "CREATE TABLE trust_anchors (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            zone TEXT NOT NULL,
+            record_type TEXT NOT NULL CHECK(record_type IN ('DNSKEY', 'DS')),
+            key_tag INTEGER NOT NULL,
+            algorithm INTEGER NOT NULL,
+            flags INTEGER,
+            digest_type INTEGER,
+            key_data BLOB NOT NULL,
+            state TEXT NOT NULL CHECK(state IN ('add_pending', 'valid', 'missing', 'revoked', 'removed')),
+            source TEXT NOT NULL CHECK(source IN ('builtin', 'config', 'file', 'rfc5011')),
+            managed INTEGER NOT NULL DEFAULT 0,
+            first_seen INTEGER NOT NULL,
+            last_seen INTEGER,
+            hold_down_until INTEGER,
+            UNIQUE(zone, record_type, algorithm, key_data)
+        )",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    pool
+}
+
+fn ksk() -> TrustAnchorData {
+    TrustAnchorData::Dnskey {
+        flags: 257,
+        algorithm: 13,
+        public_key: Arc::from(&[0xA5u8; 64][..]),
+    }
+}
+
+fn ds() -> TrustAnchorData {
+    TrustAnchorData::Ds {
+        key_tag: 4711,
+        algorithm: 13,
+        digest_type: 2,
+        digest: Arc::from(&[0x3Au8; 32][..]),
+    }
+}
+
+#[tokio::test]
+async fn test_save_inserts_and_round_trips_dnskey() {
+    let repo = SqliteTrustAnchorRepository::new(create_test_db().await);
+
+    let saved = repo
+        .save(&TrustAnchor::new(
+            ".",
+            ksk(),
+            TrustAnchorSource::Builtin,
+            true,
+            100,
+        ))
+        .await
+        .unwrap();
+    assert!(saved.id.is_some());
+
+    let all = repo.get_all().await.unwrap();
+    assert_eq!(all.len(), 1);
+    assert_eq!(all[0].zone.as_ref(), ".");
+    assert_eq!(all[0].data, ksk());
+    assert_eq!(all[0].state, TrustAnchorState::Valid);
+    assert_eq!(all[0].source, TrustAnchorSource::Builtin);
+    assert!(all[0].managed);
+    assert_eq!(all[0].first_seen, 100);
+}
+
+#[tokio::test]
+async fn test_round_trips_ds() {
+    let repo = SqliteTrustAnchorRepository::new(create_test_db().await);
+    repo.save(&TrustAnchor::new(
+        "corp.internal",
+        ds(),
+        TrustAnchorSource::Config,
+        false,
+        0,
+    ))
+    .await
+    .unwrap();
+
+    let all = repo.get_all().await.unwrap();
+    assert_eq!(all[0].zone.as_ref(), "corp.internal.");
+    assert_eq!(all[0].data, ds());
+    assert_eq!(all[0].key_tag(), 4711);
+}
+
+#[tokio::test]
+async fn test_save_with_id_updates_state_and_timestamps() {
+    let repo = SqliteTrustAnchorRepository::new(create_test_db().await);
+    let mut anchor = repo
+        .save(&TrustAnchor::new(
+            ".",
+            ksk(),
+            TrustAnchorSource::Rfc5011,
+            true,
+            0,
+        ))
+        .await
+        .unwrap();
+
+    anchor.state = TrustAnchorState::Revoked;
+    anchor.last_seen = Some(500);
+    anchor.hold_down_until = Some(900);
+    repo.save(&anchor).await.unwrap();
+
+    let stored = &repo.get_all().await.unwrap()[0];
+    assert_eq!(stored.state, TrustAnchorState::Revoked);
+    assert_eq!(stored.last_seen, Some(500));
+    assert_eq!(stored.hold_down_until, Some(900));
+}
+
+#[tokio::test]
+async fn test_duplicate_key_is_rejected() {
+    let repo = SqliteTrustAnchorRepository::new(create_test_db().await);
+    let anchor = TrustAnchor::new(".", ksk(), TrustAnchorSource::Builtin, true, 0);
+    repo.save(&anchor).await.unwrap();
+
+    assert!(repo.save(&anchor).await.is_err());
+}
+
+#[tokio::test]
+async fn test_delete() {
+    let repo = SqliteTrustAnchorRepository::new(create_test_db().await);
+    let saved = repo
+        .save(&TrustAnchor::new(
+            ".",
+            ksk(),
+            TrustAnchorSource::Builtin,
+            true,
+            0,
+        ))
+        .await
+        .unwrap();
+    let id = saved.id.unwrap();
+
+    repo.delete(id).await.unwrap();
+    assert!(repo.get_all().await.unwrap().is_empty());
+    assert!(matches!(
+        repo.delete(id).await,
+        Err(DomainError::TrustAnchorNotFound(_))
+    ));
+}
@@ -0,0 +1,131 @@
+use ferrous_dns_application::ports::TrustAnchorStorePort;
+use ferrous_dns_domain::{
+    TrustAnchor, TrustAnchorData, TrustAnchorSource, TrustAnchorState, DNSKEY_FLAG_REVOKE,
+};
+use ferrous_dns_infrastructure::dns::dnssec::{parse_trust_anchors, TrustAnchorStore};
+
+const ROOT_KEY: &str = "\
+; // autotrust trust anchor file
+;;id: . 1
+.\t172800\tIN\tDNSKEY\t257 3 8 AwEAAaz/tAm8yTn4Mfeh5eyI96WSVexTBAvkMgJzkKTOiW1vkIbzxeF3+/4RgWOq7HrxRixHlFlExOLAJr5emLvN7SWXgnLh4+B5xQlNVz8Og8kvArMtNROxVQuCaSnIDdD5LKyWbRd2n9WGe2R8PzgCmr3EgVLrjyBxWezF0jLHwVN8efS3rCj/EWgvIWgb9tarpVUDK/b58Da+sqqls3eNbuv7pr+eoZG+SrDK6nWeL3c6H5Apxz7LjVc1uTIdsIXxuOLYA4/ilBmSVIzuDWfdRUfhHdY6+cn8HFRm+2hM8AnXGXws9555KrUB5qihylGa8subX2Nn6UwNR1AkUTV74bU= ;{id = 20326 (ksk), size = 2048b}
+";
+
+fn parse(text: &str) -> Vec<TrustAnchor> {
+    parse_trust_anchors(text, TrustAnchorSource::Config, false, 0).unwrap()
+}
+
+// ── parse_trust_anchors ───────────────────────────────────────────────────────
+
+#[test]
+fn test_parses_unbound_root_key() {
+    let anchors = parse(ROOT_KEY);
+    assert_eq!(anchors.len(), 1);
+    assert_eq!(anchors[0].zone.as_ref(), ".");
+    assert_eq!(anchors[0].key_tag(), 20326);
+    assert!(anchors[0].data.is_sep());
+}
+
+#[test]
+fn test_builtin_root_matches_published_key() {
+    let builtin = TrustAnchorStore::builtin_root_anchor(0);
+    assert!(builtin.data.same_key(&parse(ROOT_KEY)[0].data));
+    assert_eq!(builtin.key_tag(), 20326);
+}
+
+#[test]
+fn test_parses_ds_without_ttl_and_class() {
+    let anchors = parse(
+        "corp.internal. DS 4711 13 2 3A1F3A1F3A1F3A1F3A1F3A1F3A1F3A1F3A1F3A1F3A1F3A1F3A1F3A1F3A1F3A1F",
+    );
+    assert_eq!(anchors.len(), 1);
+    assert_eq!(anchors[0].zone.as_ref(), "corp.internal.");
+    match &anchors[0].data {
+        TrustAnchorData::Ds {
+            key_tag,
+            digest_type,
+            digest,
+            ..
+        } => {
+            assert_eq!(*key_tag, 4711);
+            assert_eq!(*digest_type, 2);
+            assert_eq!(digest.len(), 32);
+        }
+        other => panic!("expected DS, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_parses_parenthesised_record() {
+    let anchors = parse(
+        "$TTL 3600\ncorp.internal. IN DS 4711 13 2 (\n    3A1F3A1F3A1F3A1F3A1F3A1F3A1F3A1F ; first half\n    3A1F3A1F3A1F3A1F3A1F3A1F3A1F3A1F )\n",
+    );
+    assert_eq!(anchors.len(), 1);
+    assert_eq!(anchors[0].key_tag(), 4711);
+}
+
+#[test]
+fn test_rejects_other_record_types() {
+    let result = parse_trust_anchors(
+        "corp.internal. A 10.0.0.1",
+        TrustAnchorSource::Config,
+        false,
+        0,
+    );
+    assert!(result.is_err());
+}
+
+// ── TrustAnchorStore ──────────────────────────────────────────────────────────
+
+#[test]
+fn test_closest_zone_prefers_deepest_anchor() {
+    let store = TrustAnchorStore::empty();
+    let mut anchors = parse(ROOT_KEY);
+    anchors.extend(parse(
+        "corp.internal. DS 4711 13 2 3A1F3A1F3A1F3A1F3A1F3A1F3A1F3A1F3A1F3A1F3A1F3A1F3A1F3A1F3A1F3A1F",
+    ));
+    store.replace_anchors(&anchors);
+
+    assert_eq!(
+        store.closest_zone("host.corp.internal").as_deref(),
+        Some("corp.internal.")
+    );
+    assert_eq!(store.closest_zone("example.com").as_deref(), Some("."));
+    assert_eq!(store.len(), 2);
+}
+
+#[test]
+fn test_closest_zone_without_root_anchor() {
+    let store = TrustAnchorStore::empty();
+    store.replace_anchors(&parse(
+        "corp.internal. DS 4711 13 2 3A1F3A1F3A1F3A1F3A1F3A1F3A1F3A1F3A1F3A1F3A1F3A1F3A1F3A1F3A1F3A1F",
+    ));
+
+    assert!(store.closest_zone("example.com").is_none());
+}
+
+#[test]
+fn test_replace_keeps_only_trusted_keys() {
+    let store = TrustAnchorStore::empty();
+    let mut pending = TrustAnchorStore::builtin_root_anchor(0);
+    pending.state = TrustAnchorState::AddPending;
+    let mut missing = TrustAnchorStore::builtin_root_anchor(0);
+    missing.state = TrustAnchorState::Missing;
+    let mut revoked = parse(ROOT_KEY).remove(0);
+    if let TrustAnchorData::Dnskey { flags, .. } = &mut revoked.data {
+        *flags |= DNSKEY_FLAG_REVOKE;
+    }
+
+    store.replace_anchors(&[pending, missing, revoked]);
+    assert_eq!(store.len(), 1);
+}
+
+#[test]
+fn test_clones_share_updates() {
+    let store = TrustAnchorStore::empty();
+    let validator_copy = store.clone();
+
+    store.replace_anchors(&[TrustAnchorStore::builtin_root_anchor(0)]);
+
+    assert_eq!(validator_copy.len(), 1);
+    assert!(validator_copy.get_anchor(".").is_some());
+}
@@ -0,0 +1,119 @@
+use ferrous_dns_application::ports::TsigKeyRepository;
+use ferrous_dns_domain::DomainError;
+use ferrous_dns_infrastructure::repositories::tsig_key_repository::SqliteTsigKeyRepository;
+use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
+
+const SECRET: &str = "c2VjcmV0LXNlY3JldC1zZWNyZXQtc2VjcmV0";
+
+async fn create_test_db() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::query(
+        "CREATE TABLE tsig_keys (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL UNIQUE,
+            algorithm TEXT NOT NULL,
+            secret TEXT NOT NULL,
+            created_at TEXT NOT NULL
+        )",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    pool
+}
+
+#[tokio::test]
+async fn test_create_and_get_tsig_key() {
+    let repo = SqliteTsigKeyRepository::new(create_test_db().await);
+
+    let created = repo
+        .create(
+            "kea".to_string(),
+            "hmac-sha256".to_string(),
+            SECRET.to_string(),
+        )
+        .await
+        .unwrap();
+
+    assert!(created.id.is_some());
+    assert_eq!(created.name.as_ref(), "kea");
+    assert_eq!(created.algorithm.as_ref(), "hmac-sha256");
+    assert_eq!(created.secret.as_ref(), SECRET);
+    assert!(created.created_at.is_some());
+
+    let fetched = repo.get_by_id(created.id.unwrap()).await.unwrap().unwrap();
+    assert_eq!(fetched.name, created.name);
+    assert_eq!(fetched.secret, created.secret);
+}
+
+#[tokio::test]
+async fn test_duplicate_name_is_rejected() {
+    let repo = SqliteTsigKeyRepository::new(create_test_db().await);
+
+    repo.create(
+        "kea".to_string(),
+        "hmac-sha256".to_string(),
+        SECRET.to_string(),
+    )
+    .await
+    .unwrap();
+    let result = repo
+        .create(
+            "kea".to_string(),
+            "hmac-sha512".to_string(),
+            SECRET.to_string(),
+        )
+        .await;
+
+    assert!(matches!(result, Err(DomainError::InvalidTsigKey(_))));
+}
+
+#[tokio::test]
+async fn test_get_all_orders_by_name() {
+    let repo = SqliteTsigKeyRepository::new(create_test_db().await);
+
+    for name in ["kea", "external-dns"] {
+        repo.create(
+            name.to_string(),
+            "hmac-sha256".to_string(),
+            SECRET.to_string(),
+        )
+        .await
+        .unwrap();
+    }
+
+    let names: Vec<String> = repo
+        .get_all()
+        .await
+        .unwrap()
+        .iter()
+        .map(|k| k.name.to_string())
+        .collect();
+    assert_eq!(names, vec!["external-dns".to_string(), "kea".to_string()]);
+}
+
+#[tokio::test]
+async fn test_delete_tsig_key() {
+    let repo = SqliteTsigKeyRepository::new(create_test_db().await);
+    let created = repo
+        .create(
+            "kea".to_string(),
+            "hmac-sha256".to_string(),
+            SECRET.to_string(),
+        )
+        .await
+        .unwrap();
+
+    repo.delete(created.id.unwrap()).await.unwrap();
+
+    assert!(repo.get_by_id(created.id.unwrap()).await.unwrap().is_none());
+    assert!(matches!(
+        repo.delete(created.id.unwrap()).await,
+        Err(DomainError::TsigKeyNotFound(_))
+    ));
+}
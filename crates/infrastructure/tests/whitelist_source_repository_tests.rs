@@ -55,6 +55,24 @@ async fn create_test_db() -> SqlitePool {
     .await
     .unwrap();
 
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS list_source_fetches (
+            url              TEXT    PRIMARY KEY,
+            etag             TEXT,
+            last_modified    TEXT,
+            content          TEXT,
+            last_attempt_at  TEXT    NOT NULL,
+            last_success_at  TEXT,
+            last_http_status INTEGER,
+            entry_count      INTEGER NOT NULL DEFAULT 0,
+            parse_errors     INTEGER NOT NULL DEFAULT 0,
+            last_error       TEXT
+        )",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
     sqlx::query(
         "INSERT INTO groups (id, name, enabled, comment, is_default)
          VALUES (1, 'Protected', 1, 'Default group', 1)",
@@ -427,3 +445,55 @@ async fn test_fk_group_restricts_delete() {
         "FK constraint should prevent group deletion"
     );
 }
+
+#[tokio::test]
+async fn test_source_reports_fetch_status_for_its_url() {
+    let pool = create_test_db().await;
+    let repo = SqliteWhitelistSourceRepository::new(pool.clone());
+
+    let created = repo
+        .create(
+            "Status List".to_string(),
+            Some("https://example.com/list.txt".to_string()),
+            vec![1],
+            None,
+            true,
+        )
+        .await
+        .unwrap();
+    assert!(created.status.is_none());
+
+    sqlx::query(
+        "INSERT INTO list_source_fetches
+             (url, last_attempt_at, last_success_at, last_http_status, entry_count, parse_errors, last_error)
+         VALUES ('https://example.com/list.txt', '2026-03-14 10:00:00', '2026-03-13 10:00:00', 503, 1200, 3, 'HTTP 503')",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let id = created.id.unwrap();
+    let status = repo.get_by_id(id).await.unwrap().unwrap().status.unwrap();
+    assert_eq!(status.last_attempt_at, "2026-03-14 10:00:00");
+    assert_eq!(
+        status.last_success_at.as_deref(),
+        Some("2026-03-13 10:00:00")
+    );
+    assert_eq!(status.last_http_status, Some(503));
+    assert_eq!(status.entry_count, 1200);
+    assert_eq!(status.parse_errors, 3);
+    assert_eq!(status.last_error.as_deref(), Some("HTTP 503"));
+
+    let updated = repo
+        .update(
+            id,
+            None,
+            Some(Some("https://example.com/other.txt".to_string())),
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    assert!(updated.status.is_none());
+}
@@ -0,0 +1,71 @@
+use ferrous_dns_application::services::JobMetrics;
+use ferrous_dns_application::use_cases::RenewAcmeCertificateUseCase;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info};
+
+/// Periodically checks the ACME certificate and renews it once it nears
+/// expiry. The first check runs at startup, so a missing certificate is
+/// ordered right away.
+pub struct AcmeRenewalJob {
+    use_case: Arc<RenewAcmeCertificateUseCase>,
+    interval_secs: u64,
+    shutdown: CancellationToken,
+    metrics: Arc<JobMetrics>,
+}
+
+impl AcmeRenewalJob {
+    pub fn new(use_case: Arc<RenewAcmeCertificateUseCase>) -> Self {
+        Self {
+            use_case,
+            interval_secs: 43_200,
+            shutdown: CancellationToken::new(),
+            metrics: Arc::default(),
+        }
+    }
+
+    pub fn with_interval(mut self, interval_secs: u64) -> Self {
+        self.interval_secs = interval_secs;
+        self
+    }
+
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.shutdown = token;
+        self
+    }
+
+    pub fn with_metrics(mut self, metrics: Arc<JobMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    pub async fn start(self: Arc<Self>) {
+        info!(
+            interval_secs = self.interval_secs,
+            "Starting ACME renewal job"
+        );
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(self.interval_secs));
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+            loop {
+                tokio::select! {
+                    _ = self.shutdown.cancelled() => {
+                        info!("AcmeRenewalJob: shutting down");
+                        break;
+                    }
+                    _ = interval.tick() => {
+                        let _run = self.metrics.start("acme_renewal");
+                        match self.use_case.execute().await {
+                            Ok(false) => {}
+                            Ok(true) => info!("AcmeRenewalJob: certificate renewed"),
+                            Err(e) => error!(error = %e, "AcmeRenewalJob: renewal failed"),
+                        }
+                    }
+                }
+            }
+        });
+    }
+}
@@ -1,4 +1,5 @@
 use ferrous_dns_application::ports::BlockFilterEnginePort;
+use ferrous_dns_application::services::JobMetrics;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio_util::sync::CancellationToken;
@@ -8,6 +9,7 @@ pub struct BlocklistSyncJob {
     engine: Arc<dyn BlockFilterEnginePort>,
     interval_secs: u64,
     shutdown: CancellationToken,
+    metrics: Arc<JobMetrics>,
 }
 
 impl BlocklistSyncJob {
@@ -16,6 +18,7 @@ impl BlocklistSyncJob {
             engine,
             interval_secs: 86400,
             shutdown: CancellationToken::new(),
+            metrics: Arc::default(),
         }
     }
 
@@ -29,6 +32,11 @@ impl BlocklistSyncJob {
         self
     }
 
+    pub fn with_metrics(mut self, metrics: Arc<JobMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
     pub async fn start(self: Arc<Self>) {
         info!(
             interval_secs = self.interval_secs,
@@ -46,6 +54,7 @@ impl BlocklistSyncJob {
                         break;
                     }
                     _ = interval.tick() => {
+                        let _run = self.metrics.start("blocklist_sync");
                         info!("BlocklistSyncJob: reloading blocklist sources");
                         match self.engine.reload().await {
                             Ok(()) => info!("BlocklistSyncJob: reload completed successfully"),
@@ -1,4 +1,5 @@
 use ferrous_dns_application::ports::CacheMaintenancePort;
+use ferrous_dns_application::services::JobMetrics;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio_util::sync::CancellationToken;
@@ -12,6 +13,7 @@ pub struct CacheMaintenanceJob {
     refresh_interval_secs: u64,
     compaction_interval_secs: u64,
     shutdown: CancellationToken,
+    metrics: Arc<JobMetrics>,
 }
 
 impl CacheMaintenanceJob {
@@ -21,6 +23,7 @@ impl CacheMaintenanceJob {
             refresh_interval_secs: DEFAULT_REFRESH_INTERVAL_SECS,
             compaction_interval_secs: DEFAULT_COMPACTION_INTERVAL_SECS,
             shutdown: CancellationToken::new(),
+            metrics: Arc::default(),
         }
     }
 
@@ -35,6 +38,11 @@ impl CacheMaintenanceJob {
         self
     }
 
+    pub fn with_metrics(mut self, metrics: Arc<JobMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
     pub async fn start(self: Arc<Self>) {
         info!("Starting cache maintenance background jobs");
 
@@ -50,6 +58,7 @@ impl CacheMaintenanceJob {
                         break;
                     }
                     _ = interval.tick() => {
+                        let _run = refresh_job.metrics.start("cache_refresh");
                         match refresh_job.maintenance.run_refresh_cycle().await {
                             Ok(outcome) => {
                                 if outcome.candidates_found > 0 {
@@ -83,6 +92,7 @@ impl CacheMaintenanceJob {
                         break;
                     }
                     _ = interval.tick() => {
+                        let _run = compaction_job.metrics.start("cache_compaction");
                         match compaction_job.maintenance.run_compaction_cycle().await {
                             Ok(outcome) => {
                                 if outcome.entries_removed > 0 {
@@ -1,3 +1,4 @@
+use ferrous_dns_application::services::JobMetrics;
 use ferrous_dns_application::use_cases::{SyncArpCacheUseCase, SyncHostnamesUseCase};
 use std::sync::Arc;
 use std::time::Duration;
@@ -10,6 +11,7 @@ pub struct ClientSyncJob {
     arp_interval_secs: u64,
     hostname_interval_secs: u64,
     shutdown: CancellationToken,
+    metrics: Arc<JobMetrics>,
 }
 
 impl ClientSyncJob {
@@ -23,6 +25,7 @@ impl ClientSyncJob {
             arp_interval_secs: 60,
             hostname_interval_secs: 300,
             shutdown: CancellationToken::new(),
+            metrics: Arc::default(),
         }
     }
 
@@ -37,6 +40,11 @@ impl ClientSyncJob {
         self
     }
 
+    pub fn with_metrics(mut self, metrics: Arc<JobMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
     pub async fn start(self: Arc<Self>) {
         info!("Starting client sync background jobs");
 
@@ -52,6 +60,7 @@ impl ClientSyncJob {
                         break;
                     }
                     _ = interval.tick() => {
+                        let _run = arp_job.metrics.start("client_arp_sync");
                         if let Err(e) = arp_job.sync_arp.execute().await {
                             error!(error = %e, "ARP sync failed");
                         }
@@ -72,6 +81,7 @@ impl ClientSyncJob {
                         break;
                     }
                     _ = interval.tick() => {
+                        let _run = hostname_job.metrics.start("client_hostname_sync");
                         if let Err(e) = hostname_job.sync_hostnames.execute(50).await {
                             error!(error = %e, "Hostname sync failed");
                         }
@@ -1,7 +1,9 @@
+pub mod acme_renewal;
 pub mod blocklist_sync;
 pub mod cache_maintenance;
 pub mod client_sync;
 pub mod dga_eviction;
+pub mod negative_trust_anchor_cleanup;
 pub mod nxdomain_hijack_eviction;
 pub mod query_log_retention;
 pub mod response_ip_filter_eviction;
@@ -9,13 +11,17 @@ pub mod retention;
 pub mod runner;
 pub mod schedule_evaluator;
 pub mod session_cleanup;
+pub mod tls_certificate_watch;
+pub mod trust_anchor_refresh;
 pub mod tunneling_eviction;
 pub mod wal_checkpoint;
 
+pub use acme_renewal::AcmeRenewalJob;
 pub use blocklist_sync::BlocklistSyncJob;
 pub use cache_maintenance::CacheMaintenanceJob;
 pub use client_sync::ClientSyncJob;
 pub use dga_eviction::DgaEvictionJob;
+pub use negative_trust_anchor_cleanup::NegativeTrustAnchorCleanupJob;
 pub use nxdomain_hijack_eviction::NxdomainHijackEvictionJob;
 pub use query_log_retention::QueryLogRetentionJob;
 pub use response_ip_filter_eviction::ResponseIpFilterEvictionJob;
@@ -23,5 +29,7 @@ pub use retention::RetentionJob;
 pub use runner::JobRunner;
 pub use schedule_evaluator::ScheduleEvaluatorJob;
 pub use session_cleanup::SessionCleanupJob;
+pub use tls_certificate_watch::TlsCertificateWatchJob;
+pub use trust_anchor_refresh::TrustAnchorRefreshJob;
 pub use tunneling_eviction::TunnelingEvictionJob;
 pub use wal_checkpoint::WalCheckpointJob;
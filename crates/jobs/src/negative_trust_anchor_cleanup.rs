@@ -0,0 +1,70 @@
+use ferrous_dns_application::services::JobMetrics;
+use ferrous_dns_application::use_cases::CleanupExpiredNegativeTrustAnchorsUseCase;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, error, info};
+
+/// Periodically deletes expired negative trust anchors. They stop applying
+/// at expiry regardless; this keeps the database and API listing tidy.
+pub struct NegativeTrustAnchorCleanupJob {
+    use_case: Arc<CleanupExpiredNegativeTrustAnchorsUseCase>,
+    interval_secs: u64,
+    shutdown: CancellationToken,
+    metrics: Arc<JobMetrics>,
+}
+
+impl NegativeTrustAnchorCleanupJob {
+    pub fn new(use_case: Arc<CleanupExpiredNegativeTrustAnchorsUseCase>) -> Self {
+        Self {
+            use_case,
+            interval_secs: 300,
+            shutdown: CancellationToken::new(),
+            metrics: Arc::default(),
+        }
+    }
+
+    pub fn with_interval(mut self, interval_secs: u64) -> Self {
+        self.interval_secs = interval_secs;
+        self
+    }
+
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.shutdown = token;
+        self
+    }
+
+    pub fn with_metrics(mut self, metrics: Arc<JobMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    pub async fn start(self: Arc<Self>) {
+        info!(
+            interval_secs = self.interval_secs,
+            "Starting negative trust anchor cleanup job"
+        );
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(self.interval_secs));
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+            loop {
+                tokio::select! {
+                    _ = self.shutdown.cancelled() => {
+                        info!("NegativeTrustAnchorCleanupJob: shutting down");
+                        break;
+                    }
+                    _ = interval.tick() => {
+                        let _run = self.metrics.start("negative_trust_anchor_cleanup");
+                        match self.use_case.execute().await {
+                            Ok(0) => debug!("NegativeTrustAnchorCleanupJob: nothing expired"),
+                            Ok(deleted) => info!(deleted, "NegativeTrustAnchorCleanupJob: expired anchors removed"),
+                            Err(e) => error!(error = %e, "NegativeTrustAnchorCleanupJob: cleanup failed"),
+                        }
+                    }
+                }
+            }
+        });
+    }
+}
@@ -1,4 +1,5 @@
 use ferrous_dns_application::ports::NxdomainHijackProbeTarget;
+use ferrous_dns_application::services::JobMetrics;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio_util::sync::CancellationToken;
@@ -9,6 +10,7 @@ pub struct NxdomainHijackEvictionJob {
     detector: Arc<dyn NxdomainHijackProbeTarget>,
     interval_secs: u64,
     shutdown: CancellationToken,
+    metrics: Arc<JobMetrics>,
 }
 
 impl NxdomainHijackEvictionJob {
@@ -17,6 +19,7 @@ impl NxdomainHijackEvictionJob {
             detector,
             interval_secs: interval_secs.max(30),
             shutdown: CancellationToken::new(),
+            metrics: Arc::default(),
         }
     }
 
@@ -25,6 +28,11 @@ impl NxdomainHijackEvictionJob {
         self
     }
 
+    pub fn with_metrics(mut self, metrics: Arc<JobMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
     pub async fn start(self: Arc<Self>) {
         info!(
             interval_secs = self.interval_secs,
@@ -40,6 +48,7 @@ impl NxdomainHijackEvictionJob {
                         break;
                     }
                     _ = interval.tick() => {
+                        let _run = self.metrics.start("nxdomain_hijack_eviction");
                         self.detector.evict_stale_ips();
                         debug!(
                             hijack_ips = self.detector.hijack_ip_count(),
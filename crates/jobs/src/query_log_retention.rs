@@ -1,3 +1,4 @@
+use ferrous_dns_application::services::JobMetrics;
 use ferrous_dns_application::use_cases::CleanupOldQueryLogsUseCase;
 use std::sync::Arc;
 use std::time::Duration;
@@ -9,6 +10,7 @@ pub struct QueryLogRetentionJob {
     retention_days: u32,
     interval_secs: u64,
     shutdown: CancellationToken,
+    metrics: Arc<JobMetrics>,
 }
 
 impl QueryLogRetentionJob {
@@ -18,6 +20,7 @@ impl QueryLogRetentionJob {
             retention_days,
             interval_secs: 86400,
             shutdown: CancellationToken::new(),
+            metrics: Arc::default(),
         }
     }
 
@@ -31,6 +34,11 @@ impl QueryLogRetentionJob {
         self
     }
 
+    pub fn with_metrics(mut self, metrics: Arc<JobMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
     pub async fn start(self: Arc<Self>) {
         info!(
             retention_days = self.retention_days,
@@ -46,6 +54,7 @@ impl QueryLogRetentionJob {
                         break;
                     }
                     _ = interval.tick() => {
+                        let _run = self.metrics.start("query_log_retention");
                         match self.cleanup.execute(self.retention_days).await {
                             Ok(deleted) => {
                                 info!(deleted, "Query log retention cleanup completed");
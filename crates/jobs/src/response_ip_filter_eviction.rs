@@ -1,4 +1,5 @@
 use ferrous_dns_application::ports::ResponseIpFilterEvictionTarget;
+use ferrous_dns_application::services::JobMetrics;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio_util::sync::CancellationToken;
@@ -9,6 +10,7 @@ pub struct ResponseIpFilterEvictionJob {
     detector: Arc<dyn ResponseIpFilterEvictionTarget>,
     interval_secs: u64,
     shutdown: CancellationToken,
+    metrics: Arc<JobMetrics>,
 }
 
 impl ResponseIpFilterEvictionJob {
@@ -17,6 +19,7 @@ impl ResponseIpFilterEvictionJob {
             detector,
             interval_secs: interval_secs.max(30),
             shutdown: CancellationToken::new(),
+            metrics: Arc::default(),
         }
     }
 
@@ -25,6 +28,11 @@ impl ResponseIpFilterEvictionJob {
         self
     }
 
+    pub fn with_metrics(mut self, metrics: Arc<JobMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
     pub async fn start(self: Arc<Self>) {
         info!(
             interval_secs = self.interval_secs,
@@ -40,6 +48,7 @@ impl ResponseIpFilterEvictionJob {
                         break;
                     }
                     _ = interval.tick() => {
+                        let _run = self.metrics.start("response_ip_filter_eviction");
                         self.detector.evict_stale_ips();
                         debug!(
                             blocked_ips = self.detector.blocked_ip_count(),
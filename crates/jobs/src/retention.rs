@@ -1,3 +1,4 @@
+use ferrous_dns_application::services::JobMetrics;
 use ferrous_dns_application::use_cases::CleanupOldClientsUseCase;
 use std::sync::Arc;
 use std::time::Duration;
@@ -9,6 +10,7 @@ pub struct RetentionJob {
     retention_days: u32,
     interval_secs: u64,
     shutdown: CancellationToken,
+    metrics: Arc<JobMetrics>,
 }
 
 impl RetentionJob {
@@ -18,6 +20,7 @@ impl RetentionJob {
             retention_days,
             interval_secs: 86400,
             shutdown: CancellationToken::new(),
+            metrics: Arc::default(),
         }
     }
 
@@ -31,6 +34,11 @@ impl RetentionJob {
         self
     }
 
+    pub fn with_metrics(mut self, metrics: Arc<JobMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
     pub async fn start(self: Arc<Self>) {
         info!(
             retention_days = self.retention_days,
@@ -46,6 +54,7 @@ impl RetentionJob {
                         break;
                     }
                     _ = interval.tick() => {
+                        let _run = self.metrics.start("client_retention");
                         match self.cleanup.execute(self.retention_days).await {
                             Ok(deleted) => {
                                 info!(deleted, "Retention cleanup completed");
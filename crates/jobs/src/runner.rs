@@ -1,14 +1,17 @@
 use crate::{
-    BlocklistSyncJob, CacheMaintenanceJob, ClientSyncJob, DgaEvictionJob,
-    NxdomainHijackEvictionJob, QueryLogRetentionJob, ResponseIpFilterEvictionJob, RetentionJob,
-    ScheduleEvaluatorJob, SessionCleanupJob, TunnelingEvictionJob, WalCheckpointJob,
+    AcmeRenewalJob, BlocklistSyncJob, CacheMaintenanceJob, ClientSyncJob, DgaEvictionJob,
+    NegativeTrustAnchorCleanupJob, NxdomainHijackEvictionJob, QueryLogRetentionJob,
+    ResponseIpFilterEvictionJob, RetentionJob, ScheduleEvaluatorJob, SessionCleanupJob,
+    TlsCertificateWatchJob, TrustAnchorRefreshJob, TunnelingEvictionJob, WalCheckpointJob,
 };
+use ferrous_dns_application::services::JobMetrics;
 use std::sync::Arc;
 use tokio_util::sync::CancellationToken;
 use tracing::info;
 
 pub trait SpawnableJob: Send + 'static {
     fn with_cancellation(self, token: CancellationToken) -> Self;
+    fn with_metrics(self, metrics: Arc<JobMetrics>) -> Self;
     fn start_job(self: Arc<Self>) -> tokio::task::JoinHandle<()>;
 }
 
@@ -19,6 +22,10 @@ macro_rules! impl_spawnable_job {
                 self.with_cancellation(token)
             }
 
+            fn with_metrics(self, metrics: Arc<JobMetrics>) -> Self {
+                self.with_metrics(metrics)
+            }
+
             fn start_job(self: Arc<Self>) -> tokio::task::JoinHandle<()> {
                 tokio::spawn(async move { self.start().await })
             }
@@ -38,13 +45,25 @@ impl_spawnable_job!(TunnelingEvictionJob);
 impl_spawnable_job!(NxdomainHijackEvictionJob);
 impl_spawnable_job!(ResponseIpFilterEvictionJob);
 impl_spawnable_job!(DgaEvictionJob);
-
-fn spawn_job<J: SpawnableJob>(job: Option<J>, shutdown: &Option<CancellationToken>) {
+impl_spawnable_job!(TrustAnchorRefreshJob);
+impl_spawnable_job!(NegativeTrustAnchorCleanupJob);
+impl_spawnable_job!(AcmeRenewalJob);
+impl_spawnable_job!(TlsCertificateWatchJob);
+
+fn spawn_job<J: SpawnableJob>(
+    job: Option<J>,
+    shutdown: &Option<CancellationToken>,
+    metrics: &Option<Arc<JobMetrics>>,
+) {
     if let Some(job) = job {
         let job = match shutdown {
             Some(token) => job.with_cancellation(token.clone()),
             None => job,
         };
+        let job = match metrics {
+            Some(metrics) => job.with_metrics(Arc::clone(metrics)),
+            None => job,
+        };
         Arc::new(job).start_job();
     }
 }
@@ -62,7 +81,12 @@ pub struct JobRunner {
     nxdomain_hijack_eviction: Option<NxdomainHijackEvictionJob>,
     response_ip_filter_eviction: Option<ResponseIpFilterEvictionJob>,
     dga_eviction: Option<DgaEvictionJob>,
+    trust_anchor_refresh: Option<TrustAnchorRefreshJob>,
+    negative_trust_anchor_cleanup: Option<NegativeTrustAnchorCleanupJob>,
+    acme_renewal: Option<AcmeRenewalJob>,
+    tls_certificate_watch: Option<TlsCertificateWatchJob>,
     shutdown: Option<CancellationToken>,
+    metrics: Option<Arc<JobMetrics>>,
 }
 
 impl JobRunner {
@@ -80,7 +104,12 @@ impl JobRunner {
             nxdomain_hijack_eviction: None,
             response_ip_filter_eviction: None,
             dga_eviction: None,
+            trust_anchor_refresh: None,
+            negative_trust_anchor_cleanup: None,
+            acme_renewal: None,
+            tls_certificate_watch: None,
             shutdown: None,
+            metrics: None,
         }
     }
 
@@ -144,26 +173,67 @@ impl JobRunner {
         self
     }
 
+    pub fn with_trust_anchor_refresh(mut self, job: TrustAnchorRefreshJob) -> Self {
+        self.trust_anchor_refresh = Some(job);
+        self
+    }
+
+    pub fn with_negative_trust_anchor_cleanup(
+        mut self,
+        job: NegativeTrustAnchorCleanupJob,
+    ) -> Self {
+        self.negative_trust_anchor_cleanup = Some(job);
+        self
+    }
+
+    pub fn with_acme_renewal(mut self, job: AcmeRenewalJob) -> Self {
+        self.acme_renewal = Some(job);
+        self
+    }
+
+    pub fn with_tls_certificate_watch(mut self, job: TlsCertificateWatchJob) -> Self {
+        self.tls_certificate_watch = Some(job);
+        self
+    }
+
     pub fn with_shutdown_token(mut self, token: CancellationToken) -> Self {
         self.shutdown = Some(token);
         self
     }
 
+    /// Records the run durations of every job into `metrics`.
+    pub fn with_metrics(mut self, metrics: Arc<JobMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
     pub async fn start(self) {
         info!("Starting background job runner");
 
-        spawn_job(self.client_sync, &self.shutdown);
-        spawn_job(self.retention, &self.shutdown);
-        spawn_job(self.query_log_retention, &self.shutdown);
-        spawn_job(self.blocklist_sync, &self.shutdown);
-        spawn_job(self.wal_checkpoint, &self.shutdown);
-        spawn_job(self.cache_maintenance, &self.shutdown);
-        spawn_job(self.schedule_evaluator, &self.shutdown);
-        spawn_job(self.session_cleanup, &self.shutdown);
-        spawn_job(self.tunneling_eviction, &self.shutdown);
-        spawn_job(self.nxdomain_hijack_eviction, &self.shutdown);
-        spawn_job(self.response_ip_filter_eviction, &self.shutdown);
-        spawn_job(self.dga_eviction, &self.shutdown);
+        spawn_job(self.client_sync, &self.shutdown, &self.metrics);
+        spawn_job(self.retention, &self.shutdown, &self.metrics);
+        spawn_job(self.query_log_retention, &self.shutdown, &self.metrics);
+        spawn_job(self.blocklist_sync, &self.shutdown, &self.metrics);
+        spawn_job(self.wal_checkpoint, &self.shutdown, &self.metrics);
+        spawn_job(self.cache_maintenance, &self.shutdown, &self.metrics);
+        spawn_job(self.schedule_evaluator, &self.shutdown, &self.metrics);
+        spawn_job(self.session_cleanup, &self.shutdown, &self.metrics);
+        spawn_job(self.tunneling_eviction, &self.shutdown, &self.metrics);
+        spawn_job(self.nxdomain_hijack_eviction, &self.shutdown, &self.metrics);
+        spawn_job(
+            self.response_ip_filter_eviction,
+            &self.shutdown,
+            &self.metrics,
+        );
+        spawn_job(self.dga_eviction, &self.shutdown, &self.metrics);
+        spawn_job(self.trust_anchor_refresh, &self.shutdown, &self.metrics);
+        spawn_job(
+            self.negative_trust_anchor_cleanup,
+            &self.shutdown,
+            &self.metrics,
+        );
+        spawn_job(self.acme_renewal, &self.shutdown, &self.metrics);
+        spawn_job(self.tls_certificate_watch, &self.shutdown, &self.metrics);
 
         info!("All background jobs started");
     }
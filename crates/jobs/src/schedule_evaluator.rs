@@ -1,6 +1,7 @@
 use chrono::{Datelike, Timelike};
 use chrono_tz::Tz;
 use ferrous_dns_application::ports::{ScheduleProfileRepository, ScheduleStatePort};
+use ferrous_dns_application::services::JobMetrics;
 use ferrous_dns_domain::{evaluate_slots, GroupOverride, ScheduleAction};
 use std::collections::HashSet;
 use std::sync::Arc;
@@ -14,6 +15,7 @@ pub struct ScheduleEvaluatorJob {
     state: Arc<dyn ScheduleStatePort>,
     interval_secs: u64,
     shutdown: CancellationToken,
+    metrics: Arc<JobMetrics>,
     active_groups: Mutex<HashSet<i64>>,
 }
 
@@ -27,6 +29,7 @@ impl ScheduleEvaluatorJob {
             state,
             interval_secs: 60,
             shutdown: CancellationToken::new(),
+            metrics: Arc::default(),
             active_groups: Mutex::new(HashSet::new()),
         }
     }
@@ -41,6 +44,11 @@ impl ScheduleEvaluatorJob {
         self
     }
 
+    pub fn with_metrics(mut self, metrics: Arc<JobMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
     pub async fn start(self: Arc<Self>) {
         info!(
             interval_secs = self.interval_secs,
@@ -57,6 +65,7 @@ impl ScheduleEvaluatorJob {
                     break;
                 }
                 _ = interval.tick() => {
+                    let _run = self.metrics.start("schedule_evaluator");
                     self.state.sweep_expired();
                     self.evaluate_all_schedules().await;
                 }
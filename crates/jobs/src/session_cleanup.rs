@@ -1,4 +1,5 @@
 use ferrous_dns_application::ports::SessionRepository;
+use ferrous_dns_application::services::JobMetrics;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio_util::sync::CancellationToken;
@@ -9,6 +10,7 @@ pub struct SessionCleanupJob {
     session_repo: Arc<dyn SessionRepository>,
     interval_secs: u64,
     shutdown: CancellationToken,
+    metrics: Arc<JobMetrics>,
 }
 
 impl SessionCleanupJob {
@@ -17,6 +19,7 @@ impl SessionCleanupJob {
             session_repo,
             interval_secs: 3600,
             shutdown: CancellationToken::new(),
+            metrics: Arc::default(),
         }
     }
 
@@ -30,6 +33,11 @@ impl SessionCleanupJob {
         self
     }
 
+    pub fn with_metrics(mut self, metrics: Arc<JobMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
     pub async fn start(self: Arc<Self>) {
         info!(
             interval_secs = self.interval_secs,
@@ -44,6 +52,7 @@ impl SessionCleanupJob {
                     break;
                 }
                 _ = interval.tick() => {
+                    let _run = self.metrics.start("session_cleanup");
                     match self.session_repo.delete_expired().await {
                         Ok(count) => {
                             if count > 0 {
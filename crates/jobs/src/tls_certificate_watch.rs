@@ -0,0 +1,74 @@
+use ferrous_dns_application::ports::TlsCertificateReloader;
+use ferrous_dns_application::services::JobMetrics;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::info;
+
+/// Watches the TLS certificate and key files and swaps changed pairs into
+/// the running listeners, so certificates renewed by an external tool take
+/// effect without a restart.
+pub struct TlsCertificateWatchJob {
+    reloader: Arc<dyn TlsCertificateReloader>,
+    interval_secs: u64,
+    shutdown: CancellationToken,
+    metrics: Arc<JobMetrics>,
+}
+
+impl TlsCertificateWatchJob {
+    pub fn new(reloader: Arc<dyn TlsCertificateReloader>) -> Self {
+        Self {
+            reloader,
+            interval_secs: 30,
+            shutdown: CancellationToken::new(),
+            metrics: Arc::default(),
+        }
+    }
+
+    pub fn with_interval(mut self, interval_secs: u64) -> Self {
+        self.interval_secs = interval_secs;
+        self
+    }
+
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.shutdown = token;
+        self
+    }
+
+    pub fn with_metrics(mut self, metrics: Arc<JobMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    pub async fn start(self: Arc<Self>) {
+        info!(
+            interval_secs = self.interval_secs,
+            "Starting TLS certificate watch job"
+        );
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(self.interval_secs));
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            interval.tick().await;
+
+            loop {
+                tokio::select! {
+                    _ = self.shutdown.cancelled() => {
+                        info!("TlsCertificateWatchJob: shutting down");
+                        break;
+                    }
+                    _ = interval.tick() => {
+                        let _run = self.metrics.start("tls_certificate_watch");
+                        let reloader = Arc::clone(&self.reloader);
+                        let reloaded = tokio::task::spawn_blocking(move || reloader.reload_changed())
+                            .await
+                            .unwrap_or(0);
+                        if reloaded > 0 {
+                            info!(reloaded, "TlsCertificateWatchJob: certificates reloaded");
+                        }
+                    }
+                }
+            }
+        });
+    }
+}
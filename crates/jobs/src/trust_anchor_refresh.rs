@@ -0,0 +1,70 @@
+use ferrous_dns_application::services::JobMetrics;
+use ferrous_dns_application::use_cases::RefreshTrustAnchorsUseCase;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info};
+
+/// Periodically probes managed trust anchor zones for RFC 5011 key
+/// rollovers. The first probe runs at startup.
+pub struct TrustAnchorRefreshJob {
+    use_case: Arc<RefreshTrustAnchorsUseCase>,
+    interval_secs: u64,
+    shutdown: CancellationToken,
+    metrics: Arc<JobMetrics>,
+}
+
+impl TrustAnchorRefreshJob {
+    pub fn new(use_case: Arc<RefreshTrustAnchorsUseCase>) -> Self {
+        Self {
+            use_case,
+            interval_secs: 43_200,
+            shutdown: CancellationToken::new(),
+            metrics: Arc::default(),
+        }
+    }
+
+    pub fn with_interval(mut self, interval_secs: u64) -> Self {
+        self.interval_secs = interval_secs;
+        self
+    }
+
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.shutdown = token;
+        self
+    }
+
+    pub fn with_metrics(mut self, metrics: Arc<JobMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    pub async fn start(self: Arc<Self>) {
+        info!(
+            interval_secs = self.interval_secs,
+            "Starting trust anchor refresh job"
+        );
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(self.interval_secs));
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+            loop {
+                tokio::select! {
+                    _ = self.shutdown.cancelled() => {
+                        info!("TrustAnchorRefreshJob: shutting down");
+                        break;
+                    }
+                    _ = interval.tick() => {
+                        let _run = self.metrics.start("trust_anchor_refresh");
+                        match self.use_case.execute().await {
+                            Ok(0) => {}
+                            Ok(changed) => info!(changed, "TrustAnchorRefreshJob: trust anchors updated"),
+                            Err(e) => error!(error = %e, "TrustAnchorRefreshJob: refresh failed"),
+                        }
+                    }
+                }
+            }
+        });
+    }
+}
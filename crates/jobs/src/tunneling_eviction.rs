@@ -1,4 +1,5 @@
 use ferrous_dns_application::ports::TunnelingEvictionTarget;
+use ferrous_dns_application::services::JobMetrics;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio_util::sync::CancellationToken;
@@ -8,6 +9,7 @@ pub struct TunnelingEvictionJob {
     detector: Arc<dyn TunnelingEvictionTarget>,
     interval_secs: u64,
     shutdown: CancellationToken,
+    metrics: Arc<JobMetrics>,
 }
 
 impl TunnelingEvictionJob {
@@ -16,6 +18,7 @@ impl TunnelingEvictionJob {
             detector,
             interval_secs: interval_secs.max(30),
             shutdown: CancellationToken::new(),
+            metrics: Arc::default(),
         }
     }
 
@@ -24,6 +27,11 @@ impl TunnelingEvictionJob {
         self
     }
 
+    pub fn with_metrics(mut self, metrics: Arc<JobMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
     pub async fn start(self: Arc<Self>) {
         info!(
             interval_secs = self.interval_secs,
@@ -39,6 +47,7 @@ impl TunnelingEvictionJob {
                         break;
                     }
                     _ = interval.tick() => {
+                        let _run = self.metrics.start("tunneling_eviction");
                         self.detector.evict_stale();
                         debug!(
                             tracked = self.detector.tracked_count(),
@@ -1,3 +1,4 @@
+use ferrous_dns_application::services::JobMetrics;
 use sqlx::SqlitePool;
 use std::sync::Arc;
 use std::time::Duration;
@@ -8,6 +9,7 @@ pub struct WalCheckpointJob {
     pool: SqlitePool,
     interval_secs: u64,
     shutdown: CancellationToken,
+    metrics: Arc<JobMetrics>,
 }
 
 impl WalCheckpointJob {
@@ -16,6 +18,7 @@ impl WalCheckpointJob {
             pool,
             interval_secs,
             shutdown: CancellationToken::new(),
+            metrics: Arc::default(),
         }
     }
 
@@ -29,6 +32,11 @@ impl WalCheckpointJob {
         self
     }
 
+    pub fn with_metrics(mut self, metrics: Arc<JobMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
     pub async fn start(self: Arc<Self>) {
         info!(
             interval_secs = self.interval_secs,
@@ -44,6 +52,7 @@ impl WalCheckpointJob {
                         break;
                     }
                     _ = interval.tick() => {
+                        let _run = self.metrics.start("wal_checkpoint");
                         match sqlx::query("PRAGMA wal_checkpoint(PASSIVE)")
                             .execute(&self.pool)
                             .await
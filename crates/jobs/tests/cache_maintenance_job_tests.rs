@@ -1,4 +1,5 @@
 use ferrous_dns_application::ports::{CacheCompactionOutcome, CacheRefreshOutcome};
+use ferrous_dns_application::services::JobMetrics;
 use ferrous_dns_jobs::CacheMaintenanceJob;
 use std::sync::Arc;
 use tokio::time::{sleep, Duration};
@@ -161,3 +162,22 @@ async fn test_cache_maintenance_job_with_custom_intervals() {
         "Compaction should have run with custom interval"
     );
 }
+
+#[tokio::test]
+async fn test_cache_maintenance_job_records_runs_in_job_metrics() {
+    let mock = Arc::new(MockCacheMaintenancePort::new());
+    let metrics = Arc::new(JobMetrics::new());
+    let job = Arc::new(
+        CacheMaintenanceJob::new(mock)
+            .with_intervals(3600, 3600)
+            .with_metrics(Arc::clone(&metrics)),
+    );
+
+    job.start().await;
+
+    sleep(Duration::from_millis(50)).await;
+
+    let jobs: Vec<&str> = metrics.snapshot().iter().map(|(name, _)| *name).collect();
+    assert_eq!(jobs, vec!["cache_compaction", "cache_refresh"]);
+    assert!(metrics.snapshot().iter().all(|(_, stats)| stats.runs == 1));
+}
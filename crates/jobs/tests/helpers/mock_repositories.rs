@@ -6,7 +6,9 @@ use ferrous_dns_application::ports::{
     CacheStats, ClientRepository, HostnameResolver, QueryLogRepository, TimeGranularity,
     TimelineBucket,
 };
-use ferrous_dns_domain::{Client, ClientStats, DomainError, QueryLog, QueryStats, RecordType};
+use ferrous_dns_domain::{
+    BlocklistSourceHits, Client, ClientStats, DomainError, QueryLog, QueryStats, RecordType,
+};
 use std::collections::HashMap;
 use std::net::IpAddr;
 use std::sync::atomic::{AtomicU64, Ordering};
@@ -440,6 +442,7 @@ impl MockQueryLogRepository {
             query_source: Default::default(),
             group_id: None,
             block_source: None,
+            blocklist_source_ids: Vec::new(),
         };
         self.logs.write().await.push((log, timestamp.to_string()));
     }
@@ -574,6 +577,10 @@ impl QueryLogRepository for MockQueryLogRepository {
         self.delete_count.fetch_add(deleted, Ordering::Relaxed);
         Ok(deleted)
     }
+
+    async fn get_blocklist_source_hits(&self) -> Result<Vec<BlocklistSourceHits>, DomainError> {
+        Ok(Vec::new())
+    }
 }
 
 pub struct MockCacheMaintenancePort {
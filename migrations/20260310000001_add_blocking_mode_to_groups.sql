@@ -0,0 +1,4 @@
+ALTER TABLE groups ADD COLUMN blocking_mode TEXT
+    CHECK(blocking_mode IS NULL OR blocking_mode IN ('refused','nxdomain','nodata','null_ip','custom_ip'));
+ALTER TABLE groups ADD COLUMN blocking_ipv4 TEXT;
+ALTER TABLE groups ADD COLUMN blocking_ipv6 TEXT;
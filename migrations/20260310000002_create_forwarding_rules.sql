@@ -0,0 +1,10 @@
+CREATE TABLE forwarding_rules (
+    id          INTEGER PRIMARY KEY AUTOINCREMENT,
+    domain      TEXT    NOT NULL UNIQUE,
+    target_type TEXT    NOT NULL CHECK(target_type IN ('pool', 'server')),
+    target      TEXT    NOT NULL,
+    enabled     INTEGER NOT NULL DEFAULT 1,
+    comment     TEXT,
+    created_at  TEXT    NOT NULL,
+    updated_at  TEXT    NOT NULL
+);
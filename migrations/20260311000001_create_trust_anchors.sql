@@ -0,0 +1,17 @@
+CREATE TABLE trust_anchors (
+    id              INTEGER PRIMARY KEY AUTOINCREMENT,
+    zone            TEXT    NOT NULL,
+    record_type     TEXT    NOT NULL CHECK(record_type IN ('DNSKEY', 'DS')),
+    key_tag         INTEGER NOT NULL,
+    algorithm       INTEGER NOT NULL,
+    flags           INTEGER,
+    digest_type     INTEGER,
+    key_data        BLOB    NOT NULL,
+    state           TEXT    NOT NULL CHECK(state IN ('add_pending', 'valid', 'missing', 'revoked', 'removed')),
+    source          TEXT    NOT NULL CHECK(source IN ('builtin', 'config', 'file', 'rfc5011')),
+    managed         INTEGER NOT NULL DEFAULT 0,
+    first_seen      INTEGER NOT NULL,
+    last_seen       INTEGER,
+    hold_down_until INTEGER,
+    UNIQUE(zone, record_type, algorithm, key_data)
+);
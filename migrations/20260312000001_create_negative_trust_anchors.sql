@@ -0,0 +1,9 @@
+CREATE TABLE negative_trust_anchors (
+    id          INTEGER PRIMARY KEY AUTOINCREMENT,
+    domain      TEXT    NOT NULL UNIQUE,
+    expires_at  TEXT,
+    comment     TEXT,
+    created_at  TEXT    NOT NULL
+);
+
+CREATE INDEX idx_negative_trust_anchors_expires_at ON negative_trust_anchors(expires_at);
@@ -0,0 +1,13 @@
+CREATE TABLE dns_rewrite_rules (
+    id         INTEGER PRIMARY KEY AUTOINCREMENT,
+    domain     TEXT    NOT NULL,
+    rewrite    TEXT    NOT NULL,
+    group_id   INTEGER NOT NULL DEFAULT 1 REFERENCES groups(id) ON DELETE CASCADE,
+    enabled    INTEGER NOT NULL DEFAULT 1,
+    comment    TEXT,
+    created_at TEXT    NOT NULL,
+    updated_at TEXT    NOT NULL,
+    UNIQUE(domain, rewrite, group_id)
+);
+
+CREATE INDEX idx_dns_rewrite_rules_group_id ON dns_rewrite_rules(group_id);
@@ -0,0 +1,12 @@
+CREATE TABLE list_source_fetches (
+    url              TEXT    PRIMARY KEY,
+    etag             TEXT,
+    last_modified    TEXT,
+    content          TEXT,
+    last_attempt_at  TEXT    NOT NULL,
+    last_success_at  TEXT,
+    last_http_status INTEGER,
+    entry_count      INTEGER NOT NULL DEFAULT 0,
+    parse_errors     INTEGER NOT NULL DEFAULT 0,
+    last_error       TEXT
+);
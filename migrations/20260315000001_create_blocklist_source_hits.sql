@@ -0,0 +1,11 @@
+ALTER TABLE query_log ADD COLUMN blocklist_source_ids TEXT;
+
+CREATE TABLE blocklist_source_hits (
+    source_id INTEGER NOT NULL,
+    hour      INTEGER NOT NULL,
+    domain    TEXT    NOT NULL,
+    hits      INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (source_id, hour, domain)
+);
+
+CREATE INDEX idx_blocklist_source_hits_hour ON blocklist_source_hits(hour);
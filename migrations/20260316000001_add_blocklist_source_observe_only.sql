@@ -0,0 +1 @@
+ALTER TABLE blocklist_sources ADD COLUMN observe_only INTEGER NOT NULL DEFAULT 0;
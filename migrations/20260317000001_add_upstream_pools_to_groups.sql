@@ -0,0 +1 @@
+ALTER TABLE groups ADD COLUMN upstream_pools TEXT;
@@ -0,0 +1,7 @@
+CREATE TABLE tsig_keys (
+    id          INTEGER PRIMARY KEY AUTOINCREMENT,
+    name        TEXT    NOT NULL UNIQUE,
+    algorithm   TEXT    NOT NULL,
+    secret      TEXT    NOT NULL,
+    created_at  TEXT    NOT NULL
+);
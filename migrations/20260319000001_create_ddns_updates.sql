@@ -0,0 +1,11 @@
+CREATE TABLE ddns_updates (
+    id          INTEGER PRIMARY KEY AUTOINCREMENT,
+    hostname    TEXT    NOT NULL,
+    ip          TEXT,
+    result      TEXT    NOT NULL,
+    token_id    INTEGER,
+    client_ip   TEXT,
+    created_at  TEXT    NOT NULL
+);
+
+CREATE INDEX idx_ddns_updates_hostname ON ddns_updates(hostname, id);
@@ -0,0 +1,10 @@
+CREATE TABLE acme_registrations (
+    id             INTEGER PRIMARY KEY AUTOINCREMENT,
+    username       TEXT    NOT NULL UNIQUE,
+    password_hash  TEXT    NOT NULL,
+    subdomain      TEXT    NOT NULL UNIQUE,
+    allow_from     TEXT    NOT NULL DEFAULT '[]',
+    txt            TEXT    NOT NULL DEFAULT '[]',
+    created_at     TEXT    NOT NULL,
+    updated_at     TEXT    NOT NULL
+);
@@ -141,6 +141,7 @@
                 if (query.block_source === 'dga_detection') return '<span class="badge-malware">DGA Detection</span>';
                 if (query.response_status === 'RATE_LIMITED') return '<span class="badge-rate-limited">Rate Limited</span>';
                 if (query.response_status === 'RATE_LIMITED_TC') return '<span class="badge-rate-limited">Rate Limited (TC)</span>';
+                if (query.block_source === 'dns_rewrite') return 'DNS Rewrite';
                 if (query.cache_hit) return 'Cache';
                 if (query.block_source === 'blocklist') return 'Blocklist';
                 if (query.block_source === 'managed_domain') return 'Managed Domain';
@@ -72,7 +72,7 @@
             activeSessions: [],
             // TLS state
             webTls: { enabled: false, tls_cert_path: '', tls_key_path: '' },
-            tlsStatus: { cert_exists: false, key_exists: false, cert_valid: false, cert_subject: '', cert_not_after: '' },
+            tlsStatus: { cert_exists: false, key_exists: false, cert_valid: false, cert_subject: '', cert_not_after: '', cert_fingerprint_sha256: null, active_fingerprint_sha256: null },
             tlsCertFile: null,
             tlsKeyFile: null,
             // Backup state
@@ -382,6 +382,7 @@
                 if (strategy === 'Parallel') return 'background:rgba(168,85,247,0.12);color:#A855F7';
                 if (strategy === 'Failover') return 'background:rgba(59,130,246,0.12);color:#3B82F6';
                 if (strategy === 'Balanced') return 'background:rgba(16,185,129,0.12);color:#10B981';
+                if (strategy === 'Recursive') return 'background:rgba(245,158,11,0.12);color:#F59E0B';
                 return 'background:var(--bg-tertiary);color:var(--text-secondary)';
             },
             poolStatusColor(status) {
@@ -641,7 +642,7 @@
                         }
                         scheduleLucide(50);
                     } else {
-                        this.showAlert('error', data.message || 'Upload failed');
+                        this.showAlert('error', data.error || data.message || 'Upload failed');
                     }
                 } catch (e) { this.showAlert('error', 'Error: ' + e.message); }
             },